@@ -21,12 +21,14 @@ def format_option_file(option_files: List[str]):
         open(option_file, "w").write(str(option) + "\n")
 
 
-def generate_source_file(option_files: List[str], output_file: str):
+def generate_source_file(
+    option_files: List[str], output_file: str, long_help: bool = False
+):
     options = sorted(
         [Option.parse_file(option_file) for option_file in option_files],
         key=lambda option: option.name,
     )
-    src = generate_source.generate_source(options)
+    src = generate_source.generate_source(options, long_help)
     sys.stderr.write("Generate " + output_file + "\n")
     open(output_file, "w").write(src + "\n")
 
@@ -82,7 +84,9 @@ def main():
     format_option_file(option_files_hurlfmt)
 
     # Generate Source files
-    generate_source_file(option_files_hurl, "packages/hurl/src/cli/options/commands.rs")
+    generate_source_file(
+        option_files_hurl, "packages/hurl/src/cli/options/commands.rs", long_help=True
+    )
     generate_source_file(
         option_files_hurlfmt, "packages/hurlfmt/src/cli/options/commands.rs"
     )
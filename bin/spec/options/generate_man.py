@@ -67,7 +67,7 @@ def generate_man_option(option: Option) -> str:
 
     Returns:
         A man page formatted string for the option, including its name, value placeholder,
-        description, environment variables, and cli-only notice if applicable.
+        description, example, environment variables, and cli-only notice if applicable.
     """
     s = "####"
     if option.short:
@@ -78,6 +78,10 @@ def generate_man_option(option: Option) -> str:
     s += " {#%s}" % option.long.replace(".", "")
     s += "\n\n"
     s += option.description
+    if option.example:
+        s += "\n\n"
+        s += "Example:\n\n"
+        s += "```\n$ " + option.example + "\n```"
     if option.env_var:
         s += "\n\n"
         s += "Environment variables: " + option.env_var
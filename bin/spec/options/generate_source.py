@@ -1,5 +1,6 @@
 #!/usr/bin/env python3
 import os
+import re
 import sys
 
 from option import Option
@@ -31,7 +32,7 @@ COPYRIGHT = """/*
 SCRIPT_FILE = __file__[len(os.getcwd()) + 1 :]
 
 
-def generate_source(options: list[Option]) -> str:
+def generate_source(options: list[Option], long_help: bool = False) -> str:
     s = COPYRIGHT
     s += "\n" + "// Generated by " + SCRIPT_FILE + " - Do not modify"
     # s += "\nuse clap::{value_parser, ArgAction};"
@@ -45,11 +46,11 @@ def generate_source(options: list[Option]) -> str:
 }"""
 
     for option in options:
-        s += "\n\n" + generate_source_option(option)
+        s += "\n\n" + generate_source_option(option, long_help)
     return s
 
 
-def generate_source_option(option: Option) -> str:
+def generate_source_option(option: Option, long_help: bool = False) -> str:
     s = f"pub fn {option.name}() -> clap::Arg {{"
     s += f'\n    clap::Arg::new("{option.name}")'
     s += f'\n        .long("{option.long}")'
@@ -65,6 +66,8 @@ def generate_source_option(option: Option) -> str:
     if option.value_default is not None:
         help += " [default: " + option.value_default + "]"
     s += f'\n        .help("{help}")'
+    if long_help:
+        s += f'\n        .long_help("{escape(generate_long_help(option))}")'
     if option.help_heading is not None:
         s += f'\n        .help_heading("{option.help_heading}")'
     if option.conflict is not None:
@@ -84,6 +87,51 @@ def generate_source_option(option: Option) -> str:
     return s
 
 
+def generate_long_help(option: Option) -> str:
+    """Returns the long help of an option, used by `--help-full`: the option description as plain text,
+    followed by its default value, example and environment variables."""
+    s = plain_text(option.description) or option.help
+    if option.value_default is not None:
+        s += "\n\n[default: " + option.value_default + "]"
+    if option.example:
+        s += "\n\nExample:\n  $ " + option.example
+    if option.env_var:
+        s += "\n\nEnvironment variables: " + option.env_var
+    return s
+
+
+def plain_text(description: str) -> str:
+    """Converts a Markdown description to plain text: links are replaced by their text, inline code
+    marks are removed, lines of a paragraph are joined and code blocks are indented."""
+    lines = []
+    in_code = False
+    in_paragraph = False
+    for line in description.split("\n"):
+        if line.startswith("```"):
+            in_code = not in_code
+            in_paragraph = False
+            continue
+        if in_code:
+            lines.append("    " + line)
+            continue
+        line = re.sub(r"\[([^\]]*)\]\([^)]*\)", r"\1", line)
+        line = line.replace("`", "").strip()
+        if not line:
+            lines.append("")
+            in_paragraph = False
+        elif in_paragraph and not line.startswith(("- ", "* ")):
+            lines[-1] += " " + line
+        else:
+            lines.append(line)
+            in_paragraph = True
+    return "\n".join(lines).strip()
+
+
+def escape(s: str) -> str:
+    """Escapes a string to be used as a Rust string literal."""
+    return s.replace("\\", "\\\\").replace('"', '\\"').replace("\n", "\\n")
+
+
 def main():
     # Parse all options file given at the command line
     if len(sys.argv) < 2:
@@ -29,6 +29,7 @@ class Option:
     experimental: bool = False
     config_file: bool = False
     env_var: Optional[str] = None
+    example: Optional[str] = None
 
     def __str__(self):
         s = "name: " + self.name
@@ -63,6 +64,8 @@ class Option:
             s += "\nconfig_file: true"
         if self.env_var:
             s += "\nenv_var: " + self.env_var
+        if self.example:
+            s += "\nexample: " + self.example
         s += "\n---"
         s += "\n" + self.description
         return s
@@ -88,6 +91,7 @@ class Option:
         in_description = False
         config_file = False
         env_var = None
+        example = None
 
         for line in s.split("\n"):
             if line.startswith("---"):
@@ -168,6 +172,8 @@ class Option:
                         )
                 elif key == "env_var":
                     env_var = v.strip()
+                elif key == "example":
+                    example = v
                 else:
                     raise Exception(f"{name}: Invalid attribute " + key)
 
@@ -196,6 +202,7 @@ class Option:
             description=description.strip(),
             config_file=config_file,
             env_var=env_var,
+            example=example,
         )
 
     @staticmethod
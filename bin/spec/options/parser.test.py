@@ -51,6 +51,7 @@ value: HOST1:PORT1:HOST2:PORT2
 help: For a request to the given HOST1:PORT1 pair, connect to HOST2:PORT2 instead
 help_heading: HTTP options
 multi: append
+example: hurl --connect-to example.com:443:localhost:8443 test.hurl
 ---
 For a request to the given HOST1:PORT1 pair, connect to HOST2:PORT2 instead.
 """
@@ -69,6 +70,7 @@ For a request to the given HOST1:PORT1 pair, connect to HOST2:PORT2 instead.
                 conflict=None,
                 append=True,
                 deprecated=False,
+                example="hurl --connect-to example.com:443:localhost:8443 test.hurl",
                 description="For a request to the given HOST1:PORT1 pair, connect to HOST2:PORT2 instead.",
             ),
             option,
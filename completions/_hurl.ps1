@@ -21,11 +21,13 @@ Register-ArgumentCompleter -Native -CommandName 'hurl' -ScriptBlock {
     $completions = @(switch ($command) {
         'hurl'
          {[CompletionResult]::new('--aws-sigv4', 'aws-sigv4', [CompletionResultType]::ParameterName, 'Use AWS V4 signature authentication in the transfer')
+            [CompletionResult]::new('--body-print-limit', 'body-print-limit', [CompletionResultType]::ParameterName, 'Maximum number of chars printed for each body when a JSON or XML body assert fails')
             [CompletionResult]::new('--cacert', 'cacert', [CompletionResultType]::ParameterName, 'CA certificate to verify peer against (PEM format)')
             [CompletionResult]::new('--cert', 'cert', [CompletionResultType]::ParameterName, 'Client certificate file and password')
             [CompletionResult]::new('--key', 'key', [CompletionResultType]::ParameterName, 'Private key file name')
             [CompletionResult]::new('--color', 'color', [CompletionResultType]::ParameterName, 'Colorize output')
             [CompletionResult]::new('--compressed', 'compressed', [CompletionResultType]::ParameterName, 'Request compressed response (using deflate or gzip)')
+            [CompletionResult]::new('--concurrency', 'concurrency', [CompletionResultType]::ParameterName, 'Run a load test with NUM concurrent virtual users')
             [CompletionResult]::new('--connect-timeout', 'connect-timeout', [CompletionResultType]::ParameterName, 'Maximum time allowed for connection')
             [CompletionResult]::new('--connect-to', 'connect-to', [CompletionResultType]::ParameterName, 'For a request to the given HOST1:PORT1 pair, connect to HOST2:PORT2 instead')
             [CompletionResult]::new('--continue-on-error', 'continue-on-error', [CompletionResultType]::ParameterName, 'Continue executing requests even if an error occurs')
@@ -34,14 +36,24 @@ Register-ArgumentCompleter -Native -CommandName 'hurl' -ScriptBlock {
             [CompletionResult]::new('--curl', 'curl', [CompletionResultType]::ParameterName, 'Export each request to a list of curl commands')
             [CompletionResult]::new('--delay', 'delay', [CompletionResultType]::ParameterName, 'Sets delay before each request (aka sleep)')
             [CompletionResult]::new('--digest', 'digest', [CompletionResultType]::ParameterName, 'Tell Hurl to use HTTP Digest authentication')
+            [CompletionResult]::new('--dns-servers', 'dns-servers', [CompletionResultType]::ParameterName, 'Use the given comma separated list of DNS servers instead of the system default')
+            [CompletionResult]::new('--duration', 'duration', [CompletionResultType]::ParameterName, 'Duration of a load test')
+            [CompletionResult]::new('--env', 'env', [CompletionResultType]::ParameterName, 'Use the environment profile NAME, read from hurl.NAME.toml files')
             [CompletionResult]::new('--error-format', 'error-format', [CompletionResultType]::ParameterName, 'Control the format of error messages')
+            [CompletionResult]::new('--export-variables', 'export-variables', [CompletionResultType]::ParameterName, 'Write the variables to FILE after running the session')
             [CompletionResult]::new('--fail-with-body', 'fail-with-body', [CompletionResultType]::ParameterName, 'Output body response if there are any errors')
+            [CompletionResult]::new('--file-base', 'file-base', [CompletionResultType]::ParameterName, 'Resolve relative file paths from the file root or the Hurl file directory')
             [CompletionResult]::new('--file-root', 'file-root', [CompletionResultType]::ParameterName, 'Set root directory to import files [default: input file directory]')
+            [CompletionResult]::new('--file-root-mode', 'file-root-mode', [CompletionResultType]::ParameterName, 'Control the access to files outside the file root')
+            [CompletionResult]::new('--fixtures-dir', 'fixtures-dir', [CompletionResultType]::ParameterName, 'Set directory of content-addressed fixtures')
             [CompletionResult]::new('--location', 'location', [CompletionResultType]::ParameterName, 'Follow redirects')
             [CompletionResult]::new('--location-trusted', 'location-trusted', [CompletionResultType]::ParameterName, 'Follow redirects but allows sending the name + password to all hosts that the site may redirect to')
             [CompletionResult]::new('--from-entry', 'from-entry', [CompletionResultType]::ParameterName, 'Execute Hurl file from ENTRY_NUMBER (starting at 1)')
+            [CompletionResult]::new('--generate-asserts', 'generate-asserts', [CompletionResultType]::ParameterName, 'Rewrite input files with asserts generated from the responses')
+            [CompletionResult]::new('--generate-completion', 'generate-completion', [CompletionResultType]::ParameterName, 'Print a completion script for SHELL (bash, zsh, fish or powershell)')
             [CompletionResult]::new('--glob', 'glob', [CompletionResultType]::ParameterName, 'Specify input files that match the given GLOB. Multiple glob flags may be used')
             [CompletionResult]::new('--header', 'header', [CompletionResultType]::ParameterName, 'Pass custom header(s) to server')
+            [CompletionResult]::new('--hsts', 'hsts', [CompletionResultType]::ParameterName, 'Enable HSTS, using FILE as the HSTS cache')
             [CompletionResult]::new('--http1.0', 'http1.0', [CompletionResultType]::ParameterName, 'Tell Hurl to use HTTP version 1.0')
             [CompletionResult]::new('--http1.1', 'http1.1', [CompletionResultType]::ParameterName, 'Tell Hurl to use HTTP version 1.1')
             [CompletionResult]::new('--http2', 'http2', [CompletionResultType]::ParameterName, 'Tell Hurl to use HTTP version 2')
@@ -52,11 +64,16 @@ Register-ArgumentCompleter -Native -CommandName 'hurl' -ScriptBlock {
             [CompletionResult]::new('--ipv6', 'ipv6', [CompletionResultType]::ParameterName, 'Tell Hurl to use IPv6 addresses only when resolving host names, and not for example try IPv4')
             [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Maximum number of parallel jobs, 1 to disable parallel execution')
             [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Output each Hurl file result to JSON')
+            [CompletionResult]::new('--json-diff-limit', 'json-diff-limit', [CompletionResultType]::ParameterName, 'Maximum number of differences displayed when a JSON assert fails, 0 to disable')
             [CompletionResult]::new('--limit-rate', 'limit-rate', [CompletionResultType]::ParameterName, 'Specify the maximum transfer rate in bytes/second, for both downloads and uploads')
+            [CompletionResult]::new('--load-state', 'load-state', [CompletionResultType]::ParameterName, 'Load variables from a FILE written by --export-variables')
+            [CompletionResult]::new('--max-body-size', 'max-body-size', [CompletionResultType]::ParameterName, 'Maximum size in chars of a body written in a Hurl file')
+            [CompletionResult]::new('--max-entries', 'max-entries', [CompletionResultType]::ParameterName, 'Maximum number of entries of a Hurl file')
             [CompletionResult]::new('--max-filesize', 'max-filesize', [CompletionResultType]::ParameterName, 'Specify the maximum size in bytes of a file to download')
             [CompletionResult]::new('--max-redirs', 'max-redirs', [CompletionResultType]::ParameterName, 'Maximum number of redirects allowed, -1 for unlimited redirects')
             [CompletionResult]::new('--max-time', 'max-time', [CompletionResultType]::ParameterName, 'Maximum time allowed for the transfer')
             [CompletionResult]::new('--negotiate', 'negotiate', [CompletionResultType]::ParameterName, 'Tell Hurl to use Negotiate (SPNEGO) authentication')
+            [CompletionResult]::new('--nested-form-params', 'nested-form-params', [CompletionResultType]::ParameterName, 'Encode repeated form params names as arrays and percent-encode nested keys')
             [CompletionResult]::new('--netrc', 'netrc', [CompletionResultType]::ParameterName, 'Must read .netrc for username and password')
             [CompletionResult]::new('--netrc-file', 'netrc-file', [CompletionResultType]::ParameterName, 'Specify FILE for .netrc')
             [CompletionResult]::new('--netrc-optional', 'netrc-optional', [CompletionResultType]::ParameterName, 'Use either .netrc or the URL')
@@ -77,18 +94,30 @@ Register-ArgumentCompleter -Native -CommandName 'hurl' -ScriptBlock {
             [CompletionResult]::new('--progress-bar', 'progress-bar', [CompletionResultType]::ParameterName, 'Display a progress bar in test mode')
             [CompletionResult]::new('--proxy', 'proxy', [CompletionResultType]::ParameterName, 'Use proxy on given PROTOCOL/HOST/PORT')
             [CompletionResult]::new('--proxy-header', 'proxy-header', [CompletionResultType]::ParameterName, 'Extra header to include in the request when sending to a proxy')
+            [CompletionResult]::new('--proxy-user', 'proxy-user', [CompletionResultType]::ParameterName, 'Specify the user name and password to use for proxy authentication')
             [CompletionResult]::new('--repeat', 'repeat', [CompletionResultType]::ParameterName, 'Repeat the input files sequence NUM times, -1 for infinite loop')
+            [CompletionResult]::new('--replay-dir', 'replay-dir', [CompletionResultType]::ParameterName, 'Write Hurl files reproducing the requests of failed entries to DIR')
+            [CompletionResult]::new('--report-allure', 'report-allure', [CompletionResultType]::ParameterName, 'Generate Allure results to DIR')
+            [CompletionResult]::new('--report-deterministic', 'report-deterministic', [CompletionResultType]::ParameterName, 'Write reproducible reports, without timestamps and durations')
             [CompletionResult]::new('--report-html', 'report-html', [CompletionResultType]::ParameterName, 'Generate HTML report to DIR')
             [CompletionResult]::new('--report-json', 'report-json', [CompletionResultType]::ParameterName, 'Generate JSON report to DIR')
             [CompletionResult]::new('--report-junit', 'report-junit', [CompletionResultType]::ParameterName, 'Write a JUnit XML report to FILE')
+            [CompletionResult]::new('--report-pretty', 'report-pretty', [CompletionResultType]::ParameterName, 'Write a human-readable report to FILE')
             [CompletionResult]::new('--report-tap', 'report-tap', [CompletionResultType]::ParameterName, 'Write a TAP report to FILE')
             [CompletionResult]::new('--resolve', 'resolve', [CompletionResultType]::ParameterName, 'Provide a custom address for a specific HOST and PORT pair')
             [CompletionResult]::new('--retry', 'retry', [CompletionResultType]::ParameterName, 'Maximum number of retries, 0 for no retries, -1 for unlimited retries')
             [CompletionResult]::new('--retry-interval', 'retry-interval', [CompletionResultType]::ParameterName, 'Interval in milliseconds before a retry')
+            [CompletionResult]::new('--retry-on-capture-failure', 'retry-on-capture-failure', [CompletionResultType]::ParameterName, 'Retry entries when a capture is null or has no value')
+            [CompletionResult]::new('--sandbox', 'sandbox', [CompletionResultType]::ParameterName, 'Restrict the access of Hurl files to the file system')
             [CompletionResult]::new('--secret', 'secret', [CompletionResultType]::ParameterName, 'Define a variable which value is secret')
             [CompletionResult]::new('--secrets-file', 'secrets-file', [CompletionResultType]::ParameterName, 'Define a secrets file in which you define your secrets')
+            [CompletionResult]::new('--snapshot-dir', 'snapshot-dir', [CompletionResultType]::ParameterName, 'Record and compare response bodies to snapshots in DIR')
+            [CompletionResult]::new('--snapshot-mask', 'snapshot-mask', [CompletionResultType]::ParameterName, 'Replace values matching REGEX in snapshots')
+            [CompletionResult]::new('--snapshot-update', 'snapshot-update', [CompletionResultType]::ParameterName, 'Overwrite snapshots with the current response bodies')
+            [CompletionResult]::new('--soft-asserts', 'soft-asserts', [CompletionResultType]::ParameterName, 'Report all failed asserts of an entry and keep running')
             [CompletionResult]::new('--ssl-no-revoke', 'ssl-no-revoke', [CompletionResultType]::ParameterName, '(Windows) Tell Hurl to disable certificate revocation checks')
             [CompletionResult]::new('--test', 'test', [CompletionResultType]::ParameterName, 'Activate test mode (use parallel execution)')
+            [CompletionResult]::new('--throughput', 'throughput', [CompletionResultType]::ParameterName, 'Run a load test with a target of RPS requests per second')
             [CompletionResult]::new('--to-entry', 'to-entry', [CompletionResultType]::ParameterName, 'Execute Hurl file to ENTRY_NUMBER (starting at 1)')
             [CompletionResult]::new('--unix-socket', 'unix-socket', [CompletionResultType]::ParameterName, '(HTTP) Connect through this Unix domain socket, instead of using the network')
             [CompletionResult]::new('--user', 'user', [CompletionResultType]::ParameterName, 'Add basic Authentication header to each request')
@@ -96,8 +125,11 @@ Register-ArgumentCompleter -Native -CommandName 'hurl' -ScriptBlock {
             [CompletionResult]::new('--variable', 'variable', [CompletionResultType]::ParameterName, 'Define a variable')
             [CompletionResult]::new('--variables-file', 'variables-file', [CompletionResultType]::ParameterName, 'Define a properties file in which you define your variables')
             [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Turn on verbose (alias to --verbosity verbose)')
+            [CompletionResult]::new('--verbose-dir', 'verbose-dir', [CompletionResultType]::ParameterName, 'Write verbose logs of failed entries to files in DIR')
             [CompletionResult]::new('--verbosity', 'verbosity', [CompletionResultType]::ParameterName, 'Set verbosity level for debug log')
             [CompletionResult]::new('--very-verbose', 'very-verbose', [CompletionResultType]::ParameterName, 'Turn on very verbose output, including HTTP response and libcurl logs (alias to --verbosity debug)')
+            [CompletionResult]::new('--wait-for', 'wait-for', [CompletionResultType]::ParameterName, 'Wait for URL to be ready before running')
+            [CompletionResult]::new('--wait-timeout', 'wait-timeout', [CompletionResultType]::ParameterName, 'Maximum time to wait for --wait-for URL')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--version', 'version', [CompletionResultType]::ParameterName, 'Print version')
             break
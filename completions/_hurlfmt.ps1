@@ -23,10 +23,13 @@ Register-ArgumentCompleter -Native -CommandName 'hurlfmt' -ScriptBlock {
          {[CompletionResult]::new('--check', 'check', [CompletionResultType]::ParameterName, 'Run in check mode')
             [CompletionResult]::new('--color', 'color', [CompletionResultType]::ParameterName, 'Colorize Output')
             [CompletionResult]::new('--in-place', 'in-place', [CompletionResultType]::ParameterName, 'Modify files in place')
-            [CompletionResult]::new('--in', 'in', [CompletionResultType]::ParameterName, 'Specify input format: hurl or curl')
+            [CompletionResult]::new('--dir', 'dir', [CompletionResultType]::ParameterName, 'Export all Hurl files of DIR (html output only)')
+            [CompletionResult]::new('--in', 'in', [CompletionResultType]::ParameterName, 'Specify input format: hurl, curl or json')
             [CompletionResult]::new('--no-color', 'no-color', [CompletionResultType]::ParameterName, 'Do not colorize output')
             [CompletionResult]::new('--output', 'output', [CompletionResultType]::ParameterName, 'Write to FILE instead of stdout')
-            [CompletionResult]::new('--out', 'out', [CompletionResultType]::ParameterName, 'Specify output format: hurl, json or html')
+            [CompletionResult]::new('--output-dir', 'output-dir', [CompletionResultType]::ParameterName, 'Write exported HTML pages to DIR')
+            [CompletionResult]::new('--out', 'out', [CompletionResultType]::ParameterName, 'Specify output format: hurl, json, html, md, python or js')
+            [CompletionResult]::new('--pretty', 'pretty', [CompletionResultType]::ParameterName, 'Pretty-print Hurl files: align colons, sort options and reflow long JSON bodies')
             [CompletionResult]::new('--standalone', 'standalone', [CompletionResultType]::ParameterName, 'Standalone HTML')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--version', 'version', [CompletionResultType]::ParameterName, 'Print version')
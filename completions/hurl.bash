@@ -4,7 +4,7 @@ _hurl()
     cur="${COMP_WORDS[COMP_CWORD]}"
 
     if [[ $cur == -* ]]; then
-        COMPREPLY=($(compgen -W '--aws-sigv4 --cacert --cert --key --color --compressed --connect-timeout --connect-to --continue-on-error --cookie --cookie-jar --curl --delay --digest --error-format --fail-with-body --file-root --location --location-trusted --from-entry --glob --header --http1.0 --http1.1 --http2 --http3 --include --insecure --ipv4 --ipv6 --jobs --json --limit-rate --max-filesize --max-redirs --max-time --negotiate --netrc --netrc-file --netrc-optional --no-assert --no-color --no-cookie-store --no-header --no-jsonpath-coercion --no-output --no-pretty --no-proxy --ntlm --output --parallel --path-as-is --pinnedpubkey --pretty --progress-bar --proxy --proxy-header --repeat --report-html --report-json --report-junit --report-tap --resolve --retry --retry-interval --secret --secrets-file --ssl-no-revoke --test --to-entry --unix-socket --user --user-agent --variable --variables-file --verbose --verbosity --very-verbose --help --version' -- "$cur"))
+        COMPREPLY=($(compgen -W '--aws-sigv4 --body-print-limit --cacert --cert --key --color --compressed --concurrency --connect-timeout --connect-to --continue-on-error --cookie --cookie-jar --curl --delay --digest --dns-servers --duration --env --error-format --export-variables --fail-with-body --file-base --file-root --file-root-mode --fixtures-dir --location --location-trusted --from-entry --generate-asserts --generate-completion --glob --header --hsts --http1.0 --http1.1 --http2 --http3 --include --insecure --ipv4 --ipv6 --jobs --json --json-diff-limit --limit-rate --load-state --max-body-size --max-entries --max-filesize --max-redirs --max-time --negotiate --nested-form-params --netrc --netrc-file --netrc-optional --no-assert --no-color --no-cookie-store --no-header --no-jsonpath-coercion --no-output --no-pretty --no-proxy --ntlm --output --parallel --path-as-is --pinnedpubkey --pretty --progress-bar --proxy --proxy-header --proxy-user --repeat --replay-dir --report-allure --report-deterministic --report-html --report-json --report-junit --report-pretty --report-tap --resolve --retry --retry-interval --retry-on-capture-failure --sandbox --secret --secrets-file --snapshot-dir --snapshot-mask --snapshot-update --soft-asserts --ssl-no-revoke --test --throughput --to-entry --unix-socket --user --user-agent --variable --variables-file --verbose --verbose-dir --verbosity --very-verbose --wait-for --wait-timeout --help --version' -- "$cur"))
         return
     fi
     # Generate filenames by default
@@ -1,9 +1,11 @@
 complete -c hurl -l aws-sigv4 -d 'Use AWS V4 signature authentication in the transfer'
+complete -c hurl -l body-print-limit -d 'Maximum number of chars printed for each body when a JSON or XML body assert fails'
 complete -c hurl -l cacert -d 'CA certificate to verify peer against (PEM format)'
 complete -c hurl -l cert -d 'Client certificate file and password'
 complete -c hurl -l key -d 'Private key file name'
 complete -c hurl -l color -d 'Colorize output'
 complete -c hurl -l compressed -d 'Request compressed response (using deflate or gzip)'
+complete -c hurl -l concurrency -d 'Run a load test with NUM concurrent virtual users'
 complete -c hurl -l connect-timeout -d 'Maximum time allowed for connection'
 complete -c hurl -l connect-to -d 'For a request to the given HOST1:PORT1 pair, connect to HOST2:PORT2 instead'
 complete -c hurl -l continue-on-error -d 'Continue executing requests even if an error occurs'
@@ -12,14 +14,24 @@ complete -c hurl -l cookie-jar -d 'Write cookies to FILE after running the sessi
 complete -c hurl -l curl -d 'Export each request to a list of curl commands'
 complete -c hurl -l delay -d 'Sets delay before each request (aka sleep)'
 complete -c hurl -l digest -d 'Tell Hurl to use HTTP Digest authentication'
+complete -c hurl -l dns-servers -d 'Use the given comma separated list of DNS servers instead of the system default'
+complete -c hurl -l duration -d 'Duration of a load test'
+complete -c hurl -l env -d 'Use the environment profile NAME, read from hurl.NAME.toml files'
 complete -c hurl -l error-format -d 'Control the format of error messages'
+complete -c hurl -l export-variables -d 'Write the variables to FILE after running the session'
 complete -c hurl -l fail-with-body -d 'Output body response if there are any errors'
+complete -c hurl -l file-base -d 'Resolve relative file paths from the file root or the Hurl file directory'
 complete -c hurl -l file-root -d 'Set root directory to import files [default: input file directory]'
+complete -c hurl -l file-root-mode -d 'Control the access to files outside the file root'
+complete -c hurl -l fixtures-dir -d 'Set directory of content-addressed fixtures'
 complete -c hurl -l location -d 'Follow redirects'
 complete -c hurl -l location-trusted -d 'Follow redirects but allows sending the name + password to all hosts that the site may redirect to'
 complete -c hurl -l from-entry -d 'Execute Hurl file from ENTRY_NUMBER (starting at 1)'
+complete -c hurl -l generate-asserts -d 'Rewrite input files with asserts generated from the responses'
+complete -c hurl -l generate-completion -d 'Print a completion script for SHELL (bash, zsh, fish or powershell)'
 complete -c hurl -l glob -d 'Specify input files that match the given GLOB. Multiple glob flags may be used'
 complete -c hurl -l header -d 'Pass custom header(s) to server'
+complete -c hurl -l hsts -d 'Enable HSTS, using FILE as the HSTS cache'
 complete -c hurl -l http1.0 -d 'Tell Hurl to use HTTP version 1.0'
 complete -c hurl -l http1.1 -d 'Tell Hurl to use HTTP version 1.1'
 complete -c hurl -l http2 -d 'Tell Hurl to use HTTP version 2'
@@ -30,11 +42,16 @@ complete -c hurl -l ipv4 -d 'Tell Hurl to use IPv4 addresses only when resolving
 complete -c hurl -l ipv6 -d 'Tell Hurl to use IPv6 addresses only when resolving host names, and not for example try IPv4'
 complete -c hurl -l jobs -d 'Maximum number of parallel jobs, 1 to disable parallel execution'
 complete -c hurl -l json -d 'Output each Hurl file result to JSON'
+complete -c hurl -l json-diff-limit -d 'Maximum number of differences displayed when a JSON assert fails, 0 to disable'
 complete -c hurl -l limit-rate -d 'Specify the maximum transfer rate in bytes/second, for both downloads and uploads'
+complete -c hurl -l load-state -d 'Load variables from a FILE written by --export-variables'
+complete -c hurl -l max-body-size -d 'Maximum size in chars of a body written in a Hurl file'
+complete -c hurl -l max-entries -d 'Maximum number of entries of a Hurl file'
 complete -c hurl -l max-filesize -d 'Specify the maximum size in bytes of a file to download'
 complete -c hurl -l max-redirs -d 'Maximum number of redirects allowed, -1 for unlimited redirects'
 complete -c hurl -l max-time -d 'Maximum time allowed for the transfer'
 complete -c hurl -l negotiate -d 'Tell Hurl to use Negotiate (SPNEGO) authentication'
+complete -c hurl -l nested-form-params -d 'Encode repeated form params names as arrays and percent-encode nested keys'
 complete -c hurl -l netrc -d 'Must read .netrc for username and password'
 complete -c hurl -l netrc-file -d 'Specify FILE for .netrc'
 complete -c hurl -l netrc-optional -d 'Use either .netrc or the URL'
@@ -55,18 +72,30 @@ complete -c hurl -l pretty -d 'Prettify JSON response output'
 complete -c hurl -l progress-bar -d 'Display a progress bar in test mode'
 complete -c hurl -l proxy -d 'Use proxy on given PROTOCOL/HOST/PORT'
 complete -c hurl -l proxy-header -d 'Extra header to include in the request when sending to a proxy'
+complete -c hurl -l proxy-user -d 'Specify the user name and password to use for proxy authentication'
 complete -c hurl -l repeat -d 'Repeat the input files sequence NUM times, -1 for infinite loop'
+complete -c hurl -l replay-dir -d 'Write Hurl files reproducing the requests of failed entries to DIR'
+complete -c hurl -l report-allure -d 'Generate Allure results to DIR'
+complete -c hurl -l report-deterministic -d 'Write reproducible reports, without timestamps and durations'
 complete -c hurl -l report-html -d 'Generate HTML report to DIR'
 complete -c hurl -l report-json -d 'Generate JSON report to DIR'
 complete -c hurl -l report-junit -d 'Write a JUnit XML report to FILE'
+complete -c hurl -l report-pretty -d 'Write a human-readable report to FILE'
 complete -c hurl -l report-tap -d 'Write a TAP report to FILE'
 complete -c hurl -l resolve -d 'Provide a custom address for a specific HOST and PORT pair'
 complete -c hurl -l retry -d 'Maximum number of retries, 0 for no retries, -1 for unlimited retries'
 complete -c hurl -l retry-interval -d 'Interval in milliseconds before a retry'
+complete -c hurl -l retry-on-capture-failure -d 'Retry entries when a capture is null or has no value'
+complete -c hurl -l sandbox -d 'Restrict the access of Hurl files to the file system'
 complete -c hurl -l secret -d 'Define a variable which value is secret'
 complete -c hurl -l secrets-file -d 'Define a secrets file in which you define your secrets'
+complete -c hurl -l snapshot-dir -d 'Record and compare response bodies to snapshots in DIR'
+complete -c hurl -l snapshot-mask -d 'Replace values matching REGEX in snapshots'
+complete -c hurl -l snapshot-update -d 'Overwrite snapshots with the current response bodies'
+complete -c hurl -l soft-asserts -d 'Report all failed asserts of an entry and keep running'
 complete -c hurl -l ssl-no-revoke -d '(Windows) Tell Hurl to disable certificate revocation checks'
 complete -c hurl -l test -d 'Activate test mode (use parallel execution)'
+complete -c hurl -l throughput -d 'Run a load test with a target of RPS requests per second'
 complete -c hurl -l to-entry -d 'Execute Hurl file to ENTRY_NUMBER (starting at 1)'
 complete -c hurl -l unix-socket -d '(HTTP) Connect through this Unix domain socket, instead of using the network'
 complete -c hurl -l user -d 'Add basic Authentication header to each request'
@@ -74,8 +103,11 @@ complete -c hurl -l user-agent -d 'Specify the User-Agent string to send to the
 complete -c hurl -l variable -d 'Define a variable'
 complete -c hurl -l variables-file -d 'Define a properties file in which you define your variables'
 complete -c hurl -l verbose -d 'Turn on verbose (alias to --verbosity verbose)'
+complete -c hurl -l verbose-dir -d 'Write verbose logs of failed entries to files in DIR'
 complete -c hurl -l verbosity -d 'Set verbosity level for debug log'
 complete -c hurl -l very-verbose -d 'Turn on very verbose output, including HTTP response and libcurl logs (alias to --verbosity debug)'
+complete -c hurl -l wait-for -d 'Wait for URL to be ready before running'
+complete -c hurl -l wait-timeout -d 'Maximum time to wait for --wait-for URL'
 complete -c hurl -l help -d 'Print help'
 complete -c hurl -l version -d 'Print version'
 
@@ -4,7 +4,7 @@ _hurlfmt()
     cur="${COMP_WORDS[COMP_CWORD]}"
 
     if [[ $cur == -* ]]; then
-        COMPREPLY=($(compgen -W '--check --color --in-place --in --no-color --output --out --standalone --help --version' -- "$cur"))
+        COMPREPLY=($(compgen -W '--check --color --in-place --dir --in --no-color --output --output-dir --out --pretty --standalone --help --version' -- "$cur"))
         return
     fi
     # Generate filenames by default
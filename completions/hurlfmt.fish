@@ -1,10 +1,13 @@
 complete -c hurlfmt -l check -d 'Run in check mode'
 complete -c hurlfmt -l color -d 'Colorize Output'
 complete -c hurlfmt -l in-place -d 'Modify files in place'
-complete -c hurlfmt -l in -d 'Specify input format: hurl or curl'
+complete -c hurlfmt -l dir -d 'Export all Hurl files of DIR (html output only)'
+complete -c hurlfmt -l in -d 'Specify input format: hurl, curl or json'
 complete -c hurlfmt -l no-color -d 'Do not colorize output'
 complete -c hurlfmt -l output -d 'Write to FILE instead of stdout'
-complete -c hurlfmt -l out -d 'Specify output format: hurl, json or html'
+complete -c hurlfmt -l output-dir -d 'Write exported HTML pages to DIR'
+complete -c hurlfmt -l out -d 'Specify output format: hurl, json, html, md, python or js'
+complete -c hurlfmt -l pretty -d 'Pretty-print Hurl files: align colons, sort options and reflow long JSON bodies'
 complete -c hurlfmt -l standalone -d 'Standalone HTML'
 complete -c hurlfmt -l help -d 'Print help'
 complete -c hurlfmt -l version -d 'Print version'
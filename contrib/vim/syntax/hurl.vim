@@ -27,10 +27,10 @@ syntax match section "\[Options\]"
 syntax match section "\[BasicAuth\]"
 
 syntax keyword operator == != > >= < <= not
-syntax keyword query body bytes certificate cookie duration header ip jsonpath md5 redirects regex sha256 status url variable version xpath
-syntax keyword predicate startsWith endsWith matches exists isBoolean isCollection isEmpty isFloat isInteger isIsoDate isNumber isString isIpv4 isIpv6
+syntax keyword query body bytes certificate continueReceived cookie crc32 csv duration durationMean durationPercentile earlyHints header hstsUpgraded ip jsonpath md5 proxyStatus redirects regex sha1 sha256 sha512 status timings url variable version versionNegotiated xpath
+syntax keyword predicate constantTimeEquals startsWith endsWith matches exists isBoolean isCollection isEmpty isFloat isInteger isIsoDate isNumber isString isIpv4 isIpv6 includesJson ignoring
 syntax match predicate "contains"
-syntax keyword filter base64Decode base64Encode base64UrlSafeDecode base64UrlSafeEncode count daysAfterNow daysBeforeNow decode first format htmlEscape htmlUnescape jsonpath last location nth replace replaceRegex regex split toDate toFloat toHex toInt toString urlDecode urlEncode urlQueryParam xpath
+syntax keyword filter addDays addSeconds base64Decode base64Encode base64UrlSafeDecode base64UrlSafeEncode brotliDecompress bytesSlice canonicalize count daysAfterNow daysBeforeNow decode first format fromHex gunzip htmlEscape htmlUnescape jq jsonpath jwtHeader jwtPayload last location nth parseJson replace replaceRegex regex split toDate toFloat toHex toInt toJson toString toTimestamp urlDecode urlEncode urlQueryParam xmlEscape xmlUnescape xpath zstdDecompress
 syntax match escapeNumberSign "\\#"
 syntax match escapeQuote "\\\""
 syntax region string start='"' end='"'  contains=escapeQuote
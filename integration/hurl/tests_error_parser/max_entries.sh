@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --max-entries 2 tests_error_parser/max_entries.hurl
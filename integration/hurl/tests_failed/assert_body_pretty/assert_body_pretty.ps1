@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl --no-color tests_failed/assert_body_pretty/assert_body_pretty.hurl
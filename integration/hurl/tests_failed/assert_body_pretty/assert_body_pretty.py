@@ -0,0 +1,7 @@
+from app import app
+from flask import Response
+
+
+@app.route("/error-assert-body-pretty")
+def error_assert_body_pretty():
+    return Response('{"age":3,"name":"Fido"}', mimetype="application/json")
@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --no-color tests_failed/assert_body_pretty/assert_body_pretty.hurl
@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl --secret token=s3cr3t tests_failed/assert_constant_time_equal/assert_constant_time_equal.hurl
@@ -0,0 +1,7 @@
+from app import app
+from flask import Response
+
+
+@app.route("/error-assert-constant-time-equal")
+def error_assert_constant_time_equal():
+    return Response(headers={"X-Token": "s3cr3T"})
@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl --json-diff-limit 1 tests_failed/assert_json_diff/assert_json_diff.hurl
@@ -0,0 +1,11 @@
+from app import app
+from flask import jsonify
+
+
+@app.route("/assert-json-diff")
+def assert_json_diff():
+    return jsonify(
+        id=1,
+        cart={"items": [{"id": 1, "price": 10}, {"id": 2, "price": 12}], "total": 22},
+        owner="Bob",
+    )
@@ -0,0 +1,15 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+if (Test-Path -Path build/replay_dir) {
+    Remove-Item -Recurse -Force build/replay_dir
+}
+
+# Only the request of the failed entry is saved, with secrets redacted.
+$ErrorActionPreference = 'Continue'
+hurl --secret token=abc123 --replay-dir build/replay_dir tests_failed/replay_dir/replay_dir.hurl
+
+(Get-ChildItem -Name build/replay_dir)
+Get-Content build/replay_dir/tests_failed_replay_dir_replay_dir.hurl.entry-2.hurl | Select-String -Pattern '^(Accept|User-Agent):' -NotMatch | ForEach-Object { $_.Line }
+
+exit 4
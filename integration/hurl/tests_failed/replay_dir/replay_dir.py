@@ -0,0 +1,11 @@
+from app import app
+
+
+@app.route("/replay-dir/ok")
+def replay_dir_ok():
+    return "Hello"
+
+
+@app.route("/replay-dir/ko", methods=["POST"])
+def replay_dir_ko():
+    return "Hello"
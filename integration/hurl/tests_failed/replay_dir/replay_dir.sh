@@ -0,0 +1,13 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+rm -rf build/replay_dir
+
+# Only the request of the failed entry is saved, with secrets redacted.
+set +eo pipefail
+hurl --secret token=abc123 --replay-dir build/replay_dir tests_failed/replay_dir/replay_dir.hurl
+
+ls build/replay_dir
+grep -v -e '^Accept:' -e '^User-Agent:' build/replay_dir/tests_failed_replay_dir_replay_dir.hurl.entry-2.hurl
+
+exit 4
@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl --sandbox --continue-on-error tests_failed/sandbox/sandbox.hurl
@@ -0,0 +1,6 @@
+from app import app
+
+
+@app.route("/sandbox")
+def sandbox():
+    return "Hello World!"
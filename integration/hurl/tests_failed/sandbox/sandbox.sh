@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --sandbox --continue-on-error tests_failed/sandbox/sandbox.hurl
@@ -0,0 +1,5 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+# The project file beneath the current directory is ignored with --sandbox.
+hurl --sandbox tests_failed/sandbox_project_file/sandbox_project_file.hurl
@@ -0,0 +1,6 @@
+from app import app
+
+
+@app.route("/sandbox-project-file", methods=["POST"])
+def sandbox_project_file():
+    return "Hello World!"
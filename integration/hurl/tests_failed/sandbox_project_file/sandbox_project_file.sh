@@ -0,0 +1,5 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+# The project file beneath the current directory is ignored with --sandbox.
+hurl --sandbox tests_failed/sandbox_project_file/sandbox_project_file.hurl
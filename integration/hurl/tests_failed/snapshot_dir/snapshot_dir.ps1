@@ -0,0 +1,16 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+if (Test-Path -Path build/snapshot_dir) {
+    Remove-Item -Recurse -Force build/snapshot_dir
+}
+$mask = '\d{4}-\d{2}-\d{2}T[0-9:.]+'
+
+# First run records the snapshot, second run compares the response body to it.
+hurl --no-output --variable name=Bob --snapshot-dir build/snapshot_dir --snapshot-mask $mask tests_failed/snapshot_dir/snapshot_dir.hurl
+hurl --no-output --variable name=Bob --snapshot-dir build/snapshot_dir --snapshot-mask $mask tests_failed/snapshot_dir/snapshot_dir.hurl
+Get-Content build/snapshot_dir/tests_failed_snapshot_dir_snapshot_dir.hurl.entry-1.snap
+
+# The response body has changed and doesn't match its snapshot anymore.
+$ErrorActionPreference = 'Continue'
+hurl --no-output --variable name=Alice --snapshot-dir build/snapshot_dir --snapshot-mask $mask tests_failed/snapshot_dir/snapshot_dir.hurl
@@ -0,0 +1,13 @@
+from datetime import datetime
+
+from app import app
+from flask import jsonify, request
+
+
+@app.route("/snapshot-dir/users/1")
+def snapshot_dir_user():
+    return jsonify(
+        name=request.args.get("name"),
+        id=1,
+        updated=datetime.now().isoformat(),
+    )
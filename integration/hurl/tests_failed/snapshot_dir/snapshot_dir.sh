@@ -0,0 +1,14 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+rm -rf build/snapshot_dir
+mask='\d{4}-\d{2}-\d{2}T[0-9:.]+'
+
+# First run records the snapshot, second run compares the response body to it.
+hurl --no-output --variable name=Bob --snapshot-dir build/snapshot_dir --snapshot-mask "$mask" tests_failed/snapshot_dir/snapshot_dir.hurl
+hurl --no-output --variable name=Bob --snapshot-dir build/snapshot_dir --snapshot-mask "$mask" tests_failed/snapshot_dir/snapshot_dir.hurl
+cat build/snapshot_dir/tests_failed_snapshot_dir_snapshot_dir.hurl.entry-1.snap
+
+# The response body has changed and doesn't match its snapshot anymore.
+set +eo pipefail
+hurl --no-output --variable name=Alice --snapshot-dir build/snapshot_dir --snapshot-mask "$mask" tests_failed/snapshot_dir/snapshot_dir.hurl
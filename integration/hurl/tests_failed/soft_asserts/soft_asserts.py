@@ -0,0 +1,7 @@
+from app import app
+from flask import jsonify
+
+
+@app.route("/soft-asserts")
+def soft_asserts():
+    return jsonify(name="Bob", count=1)
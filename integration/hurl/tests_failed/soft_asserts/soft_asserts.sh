@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl tests_failed/soft_asserts/soft_asserts.hurl
@@ -0,0 +1,16 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+if (Test-Path -Path build/verbose_dir) {
+    Remove-Item -Recurse -Force build/verbose_dir
+}
+
+# Only the verbose logs of the failed entry are written, and no verbose logs are
+# written to the standard error.
+$ErrorActionPreference = 'Continue'
+hurl --verbose-dir build/verbose_dir tests_failed/verbose_dir/verbose_dir.hurl
+
+(Get-ChildItem -Name build/verbose_dir)
+(Select-String -Path build/verbose_dir/tests_failed_verbose_dir_verbose_dir.hurl.entry-2.log -Pattern '> GET http://localhost:8000/verbose-dir/ko' -SimpleMatch).Count
+
+exit 4
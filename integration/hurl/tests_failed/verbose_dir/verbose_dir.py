@@ -0,0 +1,11 @@
+from app import app
+
+
+@app.route("/verbose-dir/ok")
+def verbose_dir_ok():
+    return "Hello"
+
+
+@app.route("/verbose-dir/ko")
+def verbose_dir_ko():
+    return "Hello"
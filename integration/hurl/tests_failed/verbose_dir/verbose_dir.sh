@@ -0,0 +1,14 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+rm -rf build/verbose_dir
+
+# Only the verbose logs of the failed entry are written, and no verbose logs are
+# written to the standard error.
+set +eo pipefail
+hurl --verbose-dir build/verbose_dir tests_failed/verbose_dir/verbose_dir.hurl
+
+ls build/verbose_dir
+grep -c '> GET http://localhost:8000/verbose-dir/ko' build/verbose_dir/tests_failed_verbose_dir_verbose_dir.hurl.entry-2.log
+
+exit 4
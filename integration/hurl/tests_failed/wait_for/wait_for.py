@@ -0,0 +1,7 @@
+from app import app
+from flask import Response
+
+
+@app.route("/wait-for/unavailable")
+def wait_for_unavailable():
+    return Response("Unavailable", status=503)
@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --wait-for http://localhost:8000/wait-for/unavailable --wait-timeout 2s tests_failed/wait_for/wait_for.hurl
@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl --variable field=grantedAt tests_ok/assert_body_ignoring/assert_body_ignoring.hurl
@@ -0,0 +1,19 @@
+from app import app
+from flask import Response
+
+
+@app.route("/assert-body-ignoring")
+def assert_body_ignoring():
+    return Response(
+        """{
+  "id": 1234,
+  "name": "Bob",
+  "createdAt": "2026-10-16T08:12:45Z",
+  "roles": [
+    {"name": "admin", "grantedAt": "2026-10-16T08:12:45Z"},
+    {"name": "user", "grantedAt": "2026-10-16T08:12:46Z"}
+  ]
+}
+""",
+        mimetype="application/json",
+    )
@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --variable field=grantedAt tests_ok/assert_body_ignoring/assert_body_ignoring.hurl
@@ -0,0 +1,7 @@
+from app import app
+from flask import Response
+
+
+@app.route("/assert-constant-time-equal")
+def assert_constant_time_equal():
+    return Response("signature: 4f2a", headers={"X-Token": "s3cr3t"})
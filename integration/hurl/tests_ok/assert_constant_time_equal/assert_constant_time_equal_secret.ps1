@@ -0,0 +1,28 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+if (Test-Path -Path build/assert_constant_time_equal_secret) {
+    Remove-Item -Recurse build/assert_constant_time_equal_secret
+}
+New-Item -ItemType Directory -Force -Path build/assert_constant_time_equal_secret | Out-Null
+
+hurl --very-verbose `
+    --no-output `
+    --report-json build/assert_constant_time_equal_secret/report-json `
+    tests_ok/assert_constant_time_equal/assert_constant_time_equal_secret.hurl `
+    2>build/assert_constant_time_equal_secret/verbose.err
+
+$secrets = @("s3cr3t", "signature: 4f2a")
+
+$files = @(Get-ChildItem -Filter *.json build/assert_constant_time_equal_secret/report-json)
+$files += @(Get-ChildItem build/assert_constant_time_equal_secret/verbose.err)
+
+foreach ($secret in $secrets) {
+    foreach ($file in $files) {
+        if (Get-Content $file | Select-String -CaseSensitive $secret) {
+            echo "Secret <$secret> have leaked in $file"
+            Get-Content $file
+            exit 1
+        }
+    }
+}
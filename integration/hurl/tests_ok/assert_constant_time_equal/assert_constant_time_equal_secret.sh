@@ -0,0 +1,27 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+rm -rf build/assert_constant_time_equal_secret
+mkdir -p build/assert_constant_time_equal_secret
+
+hurl --very-verbose \
+    --no-output \
+    --report-json build/assert_constant_time_equal_secret/report-json \
+    tests_ok/assert_constant_time_equal/assert_constant_time_equal_secret.hurl \
+    2>build/assert_constant_time_equal_secret/verbose.err
+
+secrets=("s3cr3t" "signature: 4f2a")
+
+files=$(find build/assert_constant_time_equal_secret/report-json/*.json \
+  build/assert_constant_time_equal_secret/verbose.err
+)
+
+for secret in "${secrets[@]}"; do
+  for file in $files; do
+    if grep -q "$secret" "$file"; then
+        echo "Secret <$secret> have leaked in $file"
+        cat "$file"
+        exit 1
+    fi
+  done
+done
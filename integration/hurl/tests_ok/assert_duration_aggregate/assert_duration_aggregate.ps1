@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl tests_ok/assert_duration_aggregate/assert_duration_aggregate.hurl
@@ -0,0 +1,6 @@
+from app import app
+
+
+@app.route("/assert-duration-aggregate")
+def assert_duration_aggregate():
+    return "Hello World!"
@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl tests_ok/assert_duration_aggregate/assert_duration_aggregate.hurl
@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl --variable city=Paris tests_ok/assert_include_json/assert_include_json.hurl
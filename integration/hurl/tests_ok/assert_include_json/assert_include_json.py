@@ -0,0 +1,17 @@
+from app import app
+from flask import Response
+
+
+@app.route("/assert-include-json")
+def assert_include_json():
+    return Response(
+        """{
+  "id": 1,
+  "name": "Bob",
+  "roles": ["admin", "user"],
+  "address": {"street": "1 rue de la Paix", "city": "Paris", "zip": "75001"},
+  "orders": [{"id": 1, "total": 10.5}, {"id": 2, "total": 20}]
+}
+""",
+        mimetype="application/json",
+    )
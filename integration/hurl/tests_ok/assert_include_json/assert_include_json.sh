@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --variable city=Paris tests_ok/assert_include_json/assert_include_json.hurl
@@ -0,0 +1,6 @@
+from app import app
+
+
+@app.route("/assert-timings")
+def assert_timings():
+    return "Hello World!"
@@ -0,0 +1,10 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+if (Test-Path build/fixtures) {
+    Remove-Item -Recurse build/fixtures
+}
+New-Item -ItemType Directory -Force -Path build/fixtures | Out-Null
+Copy-Item tests_ok/body_fixture/manifest.json build/fixtures/
+
+hurl --fixtures-dir build/fixtures tests_ok/body_fixture/body_fixture.hurl
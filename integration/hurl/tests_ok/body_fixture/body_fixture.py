@@ -0,0 +1,13 @@
+from app import app
+from flask import request
+
+
+@app.route("/body-fixture", methods=["POST"])
+def body_fixture():
+    assert request.data == b"Hello World!"
+    return ""
+
+
+@app.route("/body-fixture/hello.txt")
+def body_fixture_hello():
+    return "Hello World!"
@@ -0,0 +1,7 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -rf build/fixtures
+mkdir -p build/fixtures
+cp tests_ok/body_fixture/manifest.json build/fixtures/
+
+hurl --fixtures-dir build/fixtures tests_ok/body_fixture/body_fixture.hurl
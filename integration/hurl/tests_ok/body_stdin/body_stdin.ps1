@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+echo "Hello World!" | hurl tests_ok/body_stdin/body_stdin.hurl
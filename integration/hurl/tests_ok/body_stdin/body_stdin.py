@@ -0,0 +1,10 @@
+from app import app
+from flask import request
+
+
+@app.route("/body-stdin", methods=["PUT"])
+def body_stdin():
+    assert request.headers["Transfer-Encoding"] == "chunked"
+    # The trailing newline depends on the shell.
+    assert request.get_data().rstrip() == b"Hello World!"
+    return ""
@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+echo "Hello World!" | hurl tests_ok/body_stdin/body_stdin.hurl
@@ -0,0 +1,10 @@
+from app import app
+from flask import Response
+
+
+@app.route("/capture-fallback")
+def capture_fallback():
+    return Response(
+        """{"name": "Bob", "tags": ["a", "b"]}""",
+        mimetype="application/json",
+    )
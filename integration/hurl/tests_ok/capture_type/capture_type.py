@@ -0,0 +1,11 @@
+from app import app
+from flask import Response
+
+
+@app.route("/capture-type")
+def capture_type():
+    return Response(
+        """{"id": "42", "total": 10, "ratio": 3.0, "paid": "true", "code": 200}""",
+        headers={"X-Count": "7"},
+        mimetype="application/json",
+    )
@@ -37,6 +37,13 @@ def charset_latin1_uppercase_key():
     return resp
 
 
+@app.route("/charset/latin1/wrong-charset")
+def charset_latin1_wrong_charset():
+    resp = make_response("<p>café</p>".encode("latin1"))
+    resp.headers["Content-Type"] = "text/html; charset=utf-8"
+    return resp
+
+
 @app.route("/charset/gb2312")
 def charset_gb2312():
     resp = make_response("<p>你好世界</p>".encode("gb2312"))
@@ -0,0 +1,46 @@
+import hashlib
+
+from app import app
+from flask import request
+
+
+def read_chunked_body():
+    """Reads the chunked body of the request, and returns its data and its trailers.
+
+    The trailers are not exposed by the development server, so the body is read from the raw
+    request stream.
+    """
+    stream = request.environ["wsgi.input"]._rfile
+    data = b""
+    while True:
+        size = int(stream.readline().strip(), 16)
+        if size == 0:
+            break
+        data += stream.read(size)
+        stream.readline()
+    trailers = {}
+    while True:
+        line = stream.readline().strip()
+        if not line:
+            break
+        name, value = line.decode().split(":", 1)
+        trailers[name] = value.strip()
+    return data, trailers
+
+
+@app.route("/chunked/trailers", methods=["POST"])
+def chunked_trailers():
+    assert request.headers["Transfer-Encoding"] == "chunked"
+    assert request.headers["Trailer"] == "X-Checksum, X-Status"
+    data, trailers = read_chunked_body()
+    assert data == b"Hello World!"
+    assert trailers["X-Checksum"] == hashlib.sha256(data).hexdigest()
+    assert trailers["X-Status"] == "done"
+    return ""
+
+
+@app.route("/chunked", methods=["POST"])
+def chunked():
+    assert request.headers["Transfer-Encoding"] == "chunked"
+    assert request.get_data() == b"Hello World!"
+    return ""
@@ -0,0 +1,11 @@
+from datetime import datetime, timedelta, timezone
+
+from app import app
+from flask import jsonify
+
+
+@app.route("/clock-skew")
+def clock_skew():
+    # Simulates a server whose clock is 30 seconds ahead.
+    now = datetime.now(timezone.utc) + timedelta(seconds=30)
+    return jsonify(now=now.isoformat())
@@ -0,0 +1,19 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+# shellcheck source=/dev/null
+source <(hurl --generate-completion bash)
+
+test () {
+    unset COMPREPLY
+    export COMP_WORDS=( hurl "$@" )
+    export COMP_CWORD=$(( ${#COMP_WORDS[@]} -1 ))   # last parameter
+    _hurl
+    echo "${COMPREPLY[*]}"
+}
+
+test --generate-comp
+test --error-format ""
+test --verbosity de
+test --variables-file tests_ok/completion/generate_completion.env --variable ""
+test --report-junit tests_ok/completion/generate_completion.e
@@ -0,0 +1,8 @@
+from app import app
+from flask import request
+
+
+@app.route("/cookie_file_option_restored")
+def cookie_file_option_restored():
+    assert "cookie1" not in request.cookies
+    return ""
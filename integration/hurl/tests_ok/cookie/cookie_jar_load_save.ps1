@@ -0,0 +1,8 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+if (Test-Path build/cookie_jar_load_save.txt) {
+    Remove-Item build/cookie_jar_load_save.txt
+}
+hurl --cookie-jar-load tests_ok/cookie/cookie_file.cookies --cookie-jar-save build/cookie_jar_load_save.txt tests_ok/cookie/cookie_file.hurl
+Write-Host (Get-Content build/cookie_jar_load_save.txt -Raw) -NoNewLine
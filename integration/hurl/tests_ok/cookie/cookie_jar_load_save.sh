@@ -0,0 +1,6 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+rm -f build/cookie_jar_load_save.txt
+hurl --cookie-jar-load tests_ok/cookie/cookie_file.cookies --cookie-jar-save build/cookie_jar_load_save.txt tests_ok/cookie/cookie_file.hurl
+cat build/cookie_jar_load_save.txt
@@ -0,0 +1,22 @@
+from app import app
+from flask import redirect, request
+
+
+@app.route("/defaults/redirect")
+def defaults_redirect():
+    assert request.headers.get("header-a") == "foo"
+    assert request.headers.get("header-b") == "bar"
+    return redirect("/defaults/hello/Bob")
+
+
+@app.route("/defaults/hello/<name>")
+def defaults_hello(name):
+    assert request.headers.get("header-a") == "foo"
+    return f"Hello {name}!"
+
+
+@app.route("/defaults/override")
+def defaults_override():
+    assert request.headers.get("header-a") == "foo"
+    assert request.headers.get("header-b") == "baz"
+    return redirect("/defaults/hello/Alice")
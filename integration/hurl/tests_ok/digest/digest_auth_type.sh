@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --verbose tests_ok/digest/digest_auth_type.hurl
@@ -0,0 +1,20 @@
+from app import app
+from flask import request
+
+
+@app.route("/early-hints")
+def early_hints():
+    # Interim responses are written directly to the client socket, before the final response.
+    sock = request.environ["werkzeug.socket"]
+    sock.sendall(
+        b"HTTP/1.1 103 Early Hints\r\n"
+        b"Link: </style.css>; rel=preload; as=style\r\n"
+        b"Link: </script.js>; rel=preload; as=script\r\n"
+        b"\r\n"
+    )
+    return "Hello"
+
+
+@app.route("/early-hints/none")
+def early_hints_none():
+    return "Hello"
@@ -0,0 +1,6 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+# Options of hurl.staging.toml override the ones of hurl.toml.
+Set-Location tests_ok/env_profile
+hurl --env staging env_profile.hurl
@@ -0,0 +1,10 @@
+from app import app
+from flask import request
+
+
+@app.route("/env-profile")
+def env_profile():
+    assert request.headers["Authorization"] == "Bearer s3cr3t"
+    assert request.args["name"] == "Alice"
+    assert request.args["id"] == "2"
+    return ""
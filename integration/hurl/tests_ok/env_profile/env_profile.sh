@@ -0,0 +1,6 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+# Options of hurl.staging.toml override the ones of hurl.toml.
+cd tests_ok/env_profile
+hurl --env staging env_profile.hurl
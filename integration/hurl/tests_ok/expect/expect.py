@@ -8,3 +8,11 @@ def expect():
     s = request.data.decode("utf-8")
     assert s == """data"""
     return ""
+
+
+@app.route("/expect/none", methods=["POST"])
+def expect_none():
+    assert "Expect" not in request.headers
+    s = request.data.decode("utf-8")
+    assert s == """data"""
+    return ""
@@ -0,0 +1,28 @@
+from app import app
+from flask import Response, request
+
+TOKEN = "7a3f1c9e"
+
+
+@app.route("/export-capture/login", methods=["POST"])
+def export_capture_login():
+    assert request.form["user"] == "bob"
+    assert request.form["password"] == "secret"
+    return Response(
+        '{"token":"7a3f1c9e","id":42,"session":"abcd"}',
+        mimetype="application/json",
+    )
+
+
+@app.route("/export-capture/users/<int:user_id>")
+def export_capture_profile(user_id):
+    assert request.headers["Authorization"] == f"Bearer {TOKEN}"
+    assert user_id == 42
+    return Response('{"name":"bob"}', mimetype="application/json")
+
+
+@app.route("/export-capture/users/<int:user_id>/orders")
+def export_capture_orders(user_id):
+    assert request.headers["Authorization"] == f"Bearer {TOKEN}"
+    assert user_id == 42
+    return Response('{"orders":[1,2]}', mimetype="application/json")
@@ -0,0 +1,7 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --no-output \
+  tests_ok/export_capture/export_capture_login.hurl \
+  tests_ok/export_capture/export_capture_orders.hurl \
+  tests_ok/export_capture/export_capture_profile.hurl
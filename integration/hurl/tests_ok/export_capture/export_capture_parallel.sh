@@ -0,0 +1,8 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+# The exporting file is given last, but is run first.
+hurl --no-output --parallel --jobs 2 \
+  tests_ok/export_capture/export_capture_orders.hurl \
+  tests_ok/export_capture/export_capture_profile.hurl \
+  tests_ok/export_capture/export_capture_login.hurl
@@ -0,0 +1,8 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+if (Test-Path build/export_variables.json) {
+    Remove-Item build/export_variables.json
+}
+hurl --no-output --variable name=bob --export-variables build/export_variables.json tests_ok/export_variables/export_variables.hurl
+Write-Host (Get-Content build/export_variables.json -Raw) -NoNewLine
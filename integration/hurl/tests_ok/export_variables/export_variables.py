@@ -0,0 +1,21 @@
+from app import app
+from flask import Response, request
+
+TOKEN = "d41b8e07"
+
+
+@app.route("/export-variables/login", methods=["POST"])
+def export_variables_login():
+    assert request.form["user"] == "bob"
+    assert request.form["password"] == "secret"
+    return Response(
+        '{"token":"d41b8e07","id":42,"password":"secret"}',
+        mimetype="application/json",
+    )
+
+
+@app.route("/export-variables/users/<int:user_id>")
+def export_variables_profile(user_id):
+    assert request.headers["Authorization"] == f"Bearer {TOKEN}"
+    assert user_id == 42
+    return Response('{"name":"bob"}', mimetype="application/json")
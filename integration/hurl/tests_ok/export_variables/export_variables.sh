@@ -0,0 +1,6 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+rm -f build/export_variables.json
+hurl --no-output --variable name=bob --export-variables build/export_variables.json tests_ok/export_variables/export_variables.hurl
+cat build/export_variables.json
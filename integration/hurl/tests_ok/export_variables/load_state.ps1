@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl --no-output --load-state tests_ok/export_variables/state.json --from-entry 2 tests_ok/export_variables/export_variables.hurl
@@ -0,0 +1,19 @@
+import json
+
+from app import app
+from flask import request
+
+
+@app.route("/expression", methods=["POST"])
+def expression_post():
+    assert request.args.get("page") == "2"
+    assert request.headers["X-Name"] == "user-42"
+    assert request.headers["X-Plan"] == "free"
+    data = json.loads(request.data)
+    assert data == {"quantity": 8, "ratio": 1.5, "express": True, "remainder": 1}
+    return json.dumps({"count": 30})
+
+
+@app.route("/expression/<int:id>")
+def expression_get(id):
+    return json.dumps({"id": id, "even": id % 2 == 0})
@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl --file-root tests_ok --file-base file tests_ok/file_base/file_base.hurl
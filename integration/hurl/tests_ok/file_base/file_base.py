@@ -0,0 +1,8 @@
+from app import app
+from flask import request
+
+
+@app.route("/file-base", methods=["POST"])
+def file_base():
+    assert request.data == b"Hello World!"
+    return ""
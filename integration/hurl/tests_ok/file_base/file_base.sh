@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --file-root tests_ok --file-base file tests_ok/file_base/file_base.hurl
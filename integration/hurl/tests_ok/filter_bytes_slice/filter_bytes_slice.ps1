@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl --variable start=0 tests_ok/filter_bytes_slice/filter_bytes_slice.hurl
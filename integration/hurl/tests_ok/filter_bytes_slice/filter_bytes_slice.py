@@ -0,0 +1,15 @@
+from app import app
+from flask import Response
+
+
+@app.route("/filter-bytes-slice")
+def filter_bytes_slice():
+    return Response(
+        b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR\xde\xad\xbe\xef",
+        mimetype="application/octet-stream",
+    )
+
+
+@app.route("/filter-bytes-slice-string")
+def filter_bytes_slice_string():
+    return Response("café", mimetype="text/plain")
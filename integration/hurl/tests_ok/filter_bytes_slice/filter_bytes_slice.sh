@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --variable start=0 tests_ok/filter_bytes_slice/filter_bytes_slice.hurl
@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl --variable ttl=3600 tests_ok/filter_date/filter_date.hurl
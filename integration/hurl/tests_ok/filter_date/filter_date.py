@@ -0,0 +1,14 @@
+from datetime import datetime, timedelta, timezone
+
+from app import app
+from flask import jsonify
+
+
+@app.route("/filter-date")
+def filter_date():
+    expiry = datetime.now(timezone.utc) + timedelta(days=3)
+    return jsonify(
+        published="2026-02-25T10:00:00Z",
+        issued_at="2026-02-25T10:00:00Z",
+        expiry=expiry.isoformat(),
+    )
@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --variable ttl=3600 tests_ok/filter_date/filter_date.hurl
@@ -0,0 +1,33 @@
+import gzip
+
+from app import app
+from flask import Response
+
+
+@app.route("/filter-decompress/gzip")
+def filter_decompress_gzip():
+    # The payload is compressed twice: once as an artifact, once with `Content-Encoding`.
+    artifact = gzip.compress(b'{"status": "ok"}')
+    return Response(
+        gzip.compress(artifact),
+        headers={"Content-Encoding": "gzip"},
+        mimetype="application/octet-stream",
+    )
+
+
+@app.route("/filter-decompress/brotli")
+def filter_decompress_brotli():
+    # "Hello World!" stored in a Brotli uncompressed meta-block
+    return Response(
+        bytes.fromhex("b00010") + b"Hello World!" + bytes.fromhex("03"),
+        mimetype="application/octet-stream",
+    )
+
+
+@app.route("/filter-decompress/zstd")
+def filter_decompress_zstd():
+    # echo -n "Hello World!" | zstd
+    return Response(
+        bytes.fromhex("28b52ffd045861000048656c6c6f20576f726c6421914d7f3e"),
+        mimetype="application/octet-stream",
+    )
@@ -0,0 +1,7 @@
+from app import app
+from flask import jsonify
+
+
+@app.route("/filter-hex")
+def filter_hex():
+    return jsonify(mac="0A:1B:2C:3D:4E:5F", checksum="deadbeef")
@@ -0,0 +1,11 @@
+from app import app
+
+
+@app.route("/filter-jq")
+def filter_jq():
+    return """[
+  {"name": "Dune", "category": "novel", "price": 10},
+  {"name": "Watchmen", "category": "comics", "price": 20},
+  {"name": "Emma", "category": "novel", "price": 5.5},
+  {"name": "Cosmos", "category": "science", "price": 6.5}
+]"""
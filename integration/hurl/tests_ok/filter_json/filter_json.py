@@ -0,0 +1,11 @@
+from app import app
+from flask import Response
+
+
+@app.route("/filter-json")
+def filter_json():
+    return Response(
+        """{"user": {"name": "Bob", "id": 1}, "payload": "[1, 2, 3]"}""",
+        headers={"X-Metadata": '{"version": "1.2.0", "tags": ["a", "b"]}'},
+        mimetype="application/json",
+    )
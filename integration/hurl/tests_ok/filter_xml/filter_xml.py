@@ -0,0 +1,17 @@
+from app import app
+from flask import Response
+
+
+@app.route("/filter-xml")
+def filter_xml():
+    return Response(
+        """<?xml version="1.0" encoding="utf-8"?>
+<user   role='admin' id="1">
+    <!-- A user -->
+    <name>Bob &amp; Alice</name>
+    <tags/>
+</user>
+""",
+        headers={"X-Title": "&lt;It&apos;s &#34;great&#x22;&gt;"},
+        mimetype="application/xml",
+    )
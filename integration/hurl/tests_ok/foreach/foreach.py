@@ -0,0 +1,24 @@
+# coding=utf-8
+from app import app
+from flask import jsonify, request
+
+count = 0
+
+
+@app.route("/foreach/users")
+def foreach_users():
+    global count
+    count = 0
+    return jsonify(users=[{"id": 1}, {"id": 2}, {"id": 3}], empty=[])
+
+
+@app.route("/foreach/users/<int:user_id>")
+def foreach_user(user_id):
+    global count
+    count = count + 1
+    return jsonify(id=user_id, index=int(request.args.get("index")))
+
+
+@app.route("/foreach/count")
+def foreach_count():
+    return str(count)
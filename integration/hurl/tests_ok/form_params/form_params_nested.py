@@ -0,0 +1,22 @@
+from app import app
+from flask import request
+
+
+@app.route("/form-params-nested", methods=["POST"])
+def form_params_nested():
+    assert (
+        request.get_data()
+        == b"user[name]=Bob&user[address][city]=Paris&tag[]=a&tag[]=b&first%20name=Bob"
+    )
+    assert request.form["user[name]"] == "Bob"
+    assert request.form["user[address][city]"] == "Paris"
+    assert request.form.getlist("tag[]") == ["a", "b"]
+    assert request.form["first name"] == "Bob"
+    return ""
+
+
+@app.route("/form-params-as-is", methods=["POST"])
+def form_params_as_is():
+    assert request.get_data() == b"tag=a&tag=b"
+    assert request.form.getlist("tag") == ["a", "b"]
+    return ""
@@ -16,3 +16,18 @@ def function():
     assert re.match(date_pattern, date_str)
 
     return ""
+
+
+@app.route("/function-call")
+def function_call():
+    uuid = request.args.get("uuid")
+    uuid_pattern = "^[0-9a-f]{8}-[0-9a-f]{4}-7[0-9a-f]{3}-[0-9a-f]{4}-[0-9a-f]{12}$"
+    assert re.match(uuid_pattern, uuid)
+
+    number = int(request.args.get("number"))
+    assert 1 <= number <= 10
+
+    year = request.args.get("year")
+    assert re.match("^[0-9]{4}$", year)
+
+    return ""
@@ -0,0 +1,9 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+# We test that --generate-asserts rewrites a copy of the Hurl file with asserts from the responses.
+
+Copy-Item tests_ok/generate_asserts/generate_asserts.hurl build/generate_asserts.hurl
+
+hurl --generate-asserts build/generate_asserts.hurl
+Write-Host (Get-Content build/generate_asserts.hurl -Raw) -NoNewLine
@@ -0,0 +1,14 @@
+from app import app
+from flask import jsonify
+
+
+@app.route("/generate-asserts/users", methods=["POST"])
+def generate_asserts_create_user():
+    user = {"id": 42, "name": "Bob", "roles": ["admin"], "address": {"city": "Paris"}}
+    return jsonify(user), 201
+
+
+@app.route("/generate-asserts/users/<int:id>")
+def generate_asserts_get_user(id):
+    user = {"id": id, "name": "Bob", "active": True}
+    return jsonify(user)
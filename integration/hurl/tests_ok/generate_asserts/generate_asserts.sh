@@ -0,0 +1,9 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+# We test that --generate-asserts rewrites a copy of the Hurl file with asserts from the responses.
+
+cp tests_ok/generate_asserts/generate_asserts.hurl build/generate_asserts.hurl
+
+hurl --generate-asserts build/generate_asserts.hurl
+cat build/generate_asserts.hurl
@@ -0,0 +1,24 @@
+import base64
+import hashlib
+import hmac
+
+from app import app
+from flask import request
+
+
+@app.route("/hmac-signature", methods=["POST"])
+def hmac_signature():
+    timestamp = request.headers["X-Timestamp"]
+    body_sha256 = hashlib.sha256(request.data).hexdigest()
+    canonical = f"POST\n/hmac-signature\npage=1\n{timestamp}\n{body_sha256}"
+    expected = hmac.new(b"secret", canonical.encode(), hashlib.sha256).hexdigest()
+    assert request.headers["X-Signature"] == expected
+    return ""
+
+
+@app.route("/hmac-signature/form", methods=["POST"])
+def hmac_signature_form():
+    canonical = "POST /hmac-signature/form name=Bob"
+    digest = hmac.new(b"secret", canonical.encode(), hashlib.sha512).digest()
+    assert request.headers["Authorization"] == base64.b64encode(digest).decode()
+    return ""
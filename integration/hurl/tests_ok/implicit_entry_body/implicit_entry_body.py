@@ -0,0 +1,20 @@
+from app import app
+from flask import Response
+
+
+@app.route("/implicit-entry-body/users", methods=["POST"])
+def implicit_entry_body_create_user():
+    return Response(
+        '{"id": 42, "name": "Bob", "lastLogin": null}',
+        status=201,
+        headers={"Location": "/implicit-entry-body/users/42"},
+        mimetype="application/json",
+    )
+
+
+@app.route("/implicit-entry-body/users/42")
+def implicit_entry_body_get_user():
+    return Response(
+        '{"id": 42, "name": "Bob", "lastLogin": "2026-10-16T08:12:45Z"}',
+        mimetype="application/json",
+    )
@@ -0,0 +1,16 @@
+from app import app
+from flask import request
+
+
+@app.route("/implicit-variables/<int:index>")
+def implicit_variables(index):
+    assert request.headers["X-Entry-Index"] == str(index)
+    return request.headers["X-Hurl-File"]
+
+
+@app.route("/implicit-variables/attempt")
+def implicit_variables_attempt():
+    # Succeed only on the third attempt
+    if request.headers["X-Attempt"] == "3":
+        return "", 200
+    return "", 500
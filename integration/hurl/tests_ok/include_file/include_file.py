@@ -0,0 +1,14 @@
+from app import app
+from flask import jsonify, request
+
+
+@app.route("/include-file/login", methods=["POST"])
+def include_file_login():
+    assert request.form["user"] == "bob"
+    return jsonify(token="abc123")
+
+
+@app.route("/include-file/profile")
+def include_file_profile():
+    assert request.headers["Authorization"] == "Bearer abc123"
+    return jsonify(user="bob")
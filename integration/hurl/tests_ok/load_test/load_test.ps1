@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl --concurrency 2 --throughput 20 --duration 1s tests_ok/load_test/load_test.hurl
@@ -0,0 +1,6 @@
+from app import app
+
+
+@app.route("/load-test")
+def load_test():
+    return "Hello"
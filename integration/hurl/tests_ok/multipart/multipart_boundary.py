@@ -0,0 +1,25 @@
+from app import app
+from flask import request
+
+
+def assert_multipart():
+    assert request.form["key1"] == "value1"
+
+    upload1 = request.files["upload1"]
+    assert upload1.filename == "data.txt"
+    assert upload1.content_type == "text/xml"
+    assert upload1.headers["Content-Transfer-Encoding"] == "binary"
+    assert upload1.read() == b"Hello World!"
+
+
+@app.route("/multipart-part-headers", methods=["POST"])
+def multipart_part_headers():
+    assert_multipart()
+    return ""
+
+
+@app.route("/multipart-boundary", methods=["POST"])
+def multipart_boundary():
+    assert request.headers["Content-Type"] == "multipart/form-data; boundary=AaB03x"
+    assert_multipart()
+    return ""
@@ -0,0 +1,47 @@
+from app import app
+from flask import request
+
+
+@app.route("/multipart-nested", methods=["POST"])
+def multipart_nested():
+    data = request.get_data(as_text=True)
+    assert "Content-Type: multipart/mixed; boundary=" in data
+    assert "Content-ID: <attachments>" in data
+    assert "Content-Transfer-Encoding: binary" in data
+    assert request.form["key1"] == "value1"
+    attachments = request.form["attachments"]
+    assert (
+        'Content-Disposition: form-data; name="upload1"; filename="data.txt"'
+        in attachments
+    )
+    assert "Hello World!" in attachments
+    assert (
+        'Content-Disposition: form-data; name="upload2"; filename="data.html"'
+        in attachments
+    )
+    return ""
+
+
+@app.route("/multipart-nested-boundary", methods=["POST"])
+def multipart_nested_boundary():
+    assert request.headers["Content-Type"] == "multipart/form-data; boundary=AaB03x"
+    assert request.get_data() == (
+        b"--AaB03x\r\n"
+        b'Content-Disposition: form-data; name="key1"\r\n'
+        b"\r\n"
+        b"value1\r\n"
+        b"--AaB03x\r\n"
+        b'Content-Disposition: form-data; name="attachments"\r\n'
+        b"Content-Type: multipart/mixed; boundary=2_AaB03x\r\n"
+        b"Content-ID: <attachments>\r\n"
+        b"\r\n"
+        b"--2_AaB03x\r\n"
+        b'Content-Disposition: form-data; name="upload1"; filename="data.txt"\r\n'
+        b"Content-Type: text/plain\r\n"
+        b"\r\n"
+        b"Hello World!\r\n"
+        b"--2_AaB03x--\r\n"
+        b"\r\n"
+        b"--AaB03x--\r\n"
+    )
+    return ""
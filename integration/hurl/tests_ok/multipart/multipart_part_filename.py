@@ -0,0 +1,17 @@
+from app import app
+from flask import request
+
+
+@app.route("/multipart-part-filename", methods=["POST"])
+def multipart_part_filename():
+    upload1 = request.files["upload1"]
+    assert upload1.filename == "report.txt"
+    assert upload1.mimetype == "text/plain"
+    assert upload1.mimetype_params["charset"] == "iso-8859-1"
+    assert upload1.read() == b"Hello World!"
+
+    upload2 = request.files["upload2"]
+    assert upload2.filename == "my report.csv"
+    assert upload2.content_type == "text/csv"
+    assert upload2.read() == b"Hello World!"
+    return ""
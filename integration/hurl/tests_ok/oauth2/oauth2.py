@@ -0,0 +1,48 @@
+import base64
+
+from app import app
+from flask import request
+
+rotating_token_count = 0
+
+
+def check_client_credentials():
+    credentials = base64.b64encode(b"my-app:secret").decode()
+    assert request.headers["Authorization"] == f"Basic {credentials}"
+    assert request.form["grant_type"] == "client_credentials"
+
+
+@app.route("/oauth2/token", methods=["POST"])
+def oauth2_token():
+    check_client_credentials()
+    return {"access_token": "abcdef", "token_type": "Bearer", "expires_in": 3600}
+
+
+@app.route("/oauth2/resource")
+def oauth2_resource():
+    assert request.headers["Authorization"] == "Bearer abcdef"
+    return "You are authorized"
+
+
+@app.route("/oauth2/explicit-header")
+def oauth2_explicit_header():
+    assert request.headers["Authorization"] == "Bearer 123456"
+    return "You are authorized"
+
+
+# Each token request returns a new token: only even tokens are accepted so the first token
+# is rejected and must be refreshed.
+@app.route("/oauth2/rotating-token", methods=["POST"])
+def oauth2_rotating_token():
+    global rotating_token_count
+    check_client_credentials()
+    rotating_token_count += 1
+    return {"access_token": f"token-{rotating_token_count}", "token_type": "Bearer"}
+
+
+@app.route("/oauth2/rotating-resource")
+def oauth2_rotating_resource():
+    token = request.headers["Authorization"].removeprefix("Bearer token-")
+    if int(token) % 2 == 1:
+        return "Invalid token", 401
+    return "You are authorized"
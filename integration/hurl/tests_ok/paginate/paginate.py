@@ -0,0 +1,33 @@
+# coding=utf-8
+from app import app
+from flask import jsonify, request
+
+count = 0
+
+
+@app.route("/paginate/items")
+def paginate_items():
+    global count
+    page = int(request.args.get("page", "1"))
+    count = 1 if page == 1 else count + 1
+    if page == 1:
+        # A relative URL is resolved against the URL of the request.
+        next_page = "/paginate/items?page=2"
+    elif page == 2:
+        next_page = "http://localhost:8000/paginate/items?page=3"
+    else:
+        next_page = None
+    return jsonify(page=page, items=[page * 10, page * 10 + 1], next=next_page)
+
+
+@app.route("/paginate/endless")
+def paginate_endless():
+    global count
+    page = int(request.args.get("page", "1"))
+    count = 1 if page == 1 else count + 1
+    return jsonify(page=page, next=f"/paginate/endless?page={page + 1}")
+
+
+@app.route("/paginate/count")
+def paginate_count():
+    return str(count)
@@ -101,4 +101,5 @@ hurl --ipv4 --parallel --test `
   tests_failed/template/template_variable_not_renderable.hurl
 $ErrorActionPreference = 'Stop'
 
-Write-Host (Get-Content build/parallel-all.txt -Raw) -NoNewLine
+# We only keep the top-level test lines: subtests details are tested in tests_ok/tap.
+Get-Content build/parallel-all.txt | Where-Object { $_ -notmatch '^( |#)' } | ForEach-Object { Write-Host $_ }
@@ -100,7 +100,8 @@ hurl --ipv4 --parallel --test \
 
 ret=$?
 
-cat build/parallel-all.txt
+# We only keep the top-level test lines: subtests details are tested in tests_ok/tap.
+grep -v -e '^ ' -e '^#' build/parallel-all.txt
 
 # We use the exit code of the Hurl command (and not the cat command)
 exit $ret
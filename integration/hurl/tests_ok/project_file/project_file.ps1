@@ -0,0 +1,6 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+# Options of hurl.toml are overridden by the command line.
+Set-Location tests_ok/project_file
+hurl --variable name=Bob project_file.hurl
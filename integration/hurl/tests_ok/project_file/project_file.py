@@ -0,0 +1,10 @@
+from app import app
+from flask import request
+
+
+@app.route("/project-file")
+def project_file():
+    assert request.headers["X-Project"] == "hurl"
+    assert request.args["name"] == "Bob"
+    assert request.args["id"] == "42"
+    return ""
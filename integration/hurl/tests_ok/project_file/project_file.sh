@@ -0,0 +1,6 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+# Options of hurl.toml are overridden by the command line.
+cd tests_ok/project_file
+hurl --variable name=Bob project_file.hurl
@@ -0,0 +1,6 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+# Hurl files beneath api/ use the options of api/.hurlrc, in addition to hurl.toml options.
+Set-Location tests_ok/project_file_nested
+hurl --test project_file_nested.hurl api/api.hurl
@@ -0,0 +1,8 @@
+from app import app
+from flask import request
+
+
+@app.route("/project-file-nested")
+def project_file_nested():
+    assert request.headers["X-Suite"] == "root"
+    return request.args["env"]
@@ -0,0 +1,6 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+# Hurl files beneath api/ use the options of api/.hurlrc, in addition to hurl.toml options.
+cd tests_ok/project_file_nested
+hurl --test project_file_nested.hurl api/api.hurl
@@ -0,0 +1,18 @@
+from app import app
+from flask import Response
+
+
+@app.route("/query-csv")
+def query_csv():
+    return Response(
+        'name,price,comment\r\nbanana,1.2,\r\napple,2,"red, green"\r\ncherry,10.5,"the ""best"""\r\n',
+        mimetype="text/csv",
+    )
+
+
+@app.route("/query-csv-no-header")
+def query_csv_no_header():
+    return Response(
+        "banana,1.2\napple,2\n",
+        content_type="text/csv; header=absent",
+    )
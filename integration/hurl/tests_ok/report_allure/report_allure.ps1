@@ -0,0 +1,13 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/report_allure) {
+    Remove-Item -Recurse build/report_allure
+}
+
+# test.2.hurl is KO but we want the script to continue until the end
+$ErrorActionPreference = 'Continue'
+hurl --test --report-allure build/report_allure tests_ok/report_allure/test.1.hurl tests_ok/report_allure/test.2.hurl
+$ErrorActionPreference = 'Stop'
+
+# Results files are named with random UUIDs, we only keep the tests and steps status.
+Get-Content build/report_allure/*-result.json | Select-String -Pattern '"(fullName|name)":"[^"]*","status":"[a-z]*"' -AllMatches | ForEach-Object { $_.Matches.Value } | Sort-Object -CaseSensitive
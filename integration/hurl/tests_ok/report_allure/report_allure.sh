@@ -0,0 +1,11 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -rf build/report_allure
+
+# test.2.hurl is KO but we want the script to continue until the end
+set +eo pipefail
+hurl --test --report-allure build/report_allure tests_ok/report_allure/test.1.hurl tests_ok/report_allure/test.2.hurl
+set -Eeuo pipefail
+
+# Results files are named with random UUIDs, we only keep the tests and steps status.
+cat build/report_allure/*-result.json | grep -o -E '"(fullName|name)":"[^"]*","status":"[a-z]*"' | LC_ALL=C sort
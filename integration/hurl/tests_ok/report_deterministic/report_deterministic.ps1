@@ -0,0 +1,10 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/report_deterministic) {
+    Remove-Item -Recurse build/report_deterministic
+}
+
+hurl --test --report-junit build/report_deterministic/report.xml --report-deterministic tests_ok/report_deterministic/test.1.hurl
+
+# Durations are zeroed in a deterministic report.
+Get-Content build/report_deterministic/report.xml | Select-String -Pattern '<testcase [^>]*>|<property name="[^"]*:entry[^>]*>' -AllMatches | ForEach-Object { $_.Matches.Value }
@@ -0,0 +1,8 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -rf build/report_deterministic
+
+hurl --test --report-junit build/report_deterministic/report.xml --report-deterministic tests_ok/report_deterministic/test.1.hurl
+
+# Durations are zeroed in a deterministic report.
+grep -o -E '<testcase [^>]*>|<property name="[^"]*:entry[^>]*>' build/report_deterministic/report.xml
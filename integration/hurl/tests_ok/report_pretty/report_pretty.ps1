@@ -0,0 +1,12 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/report_pretty/result.txt) {
+    Remove-Item build/report_pretty/result.txt
+}
+
+# test.2.hurl is KO but we want the script to continue until the end
+$ErrorActionPreference = 'Continue'
+hurl --test --report-pretty build/report_pretty/result.txt tests_ok/report_pretty/test.1.hurl tests_ok/report_pretty/test.2.hurl
+$ErrorActionPreference = 'Stop'
+
+Write-Host (Get-Content build/report_pretty/result.txt -Raw) -NoNewLine
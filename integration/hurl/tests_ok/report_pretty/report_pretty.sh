@@ -0,0 +1,10 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -f build/report_pretty/result.txt
+
+# test.2.hurl is KO but we want the script to continue until the end
+set +eo pipefail
+hurl --test --report-pretty build/report_pretty/result.txt tests_ok/report_pretty/test.1.hurl tests_ok/report_pretty/test.2.hurl
+set -Eeuo pipefail
+
+cat build/report_pretty/result.txt
@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl --resolve '*.staging.example.org:8000:127.0.0.1' tests_ok/resolve/resolve_wildcard.hurl
@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --resolve '*.staging.example.org:8000:127.0.0.1' tests_ok/resolve/resolve_wildcard.hurl
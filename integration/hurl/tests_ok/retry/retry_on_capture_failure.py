@@ -0,0 +1,26 @@
+from app import app
+from flask import jsonify, request
+
+job_attempts = 0
+
+
+@app.route("/retry/job", methods=["POST"])
+def retry_job_create():
+    global job_attempts
+    job_attempts = 0
+    return jsonify(id=1, state="RUNNING"), 201
+
+
+@app.route("/retry/job/1")
+def retry_job_get():
+    global job_attempts
+    job_attempts += 1
+    if job_attempts < 3:
+        return jsonify(state="RUNNING", report=None)
+    return jsonify(state="COMPLETED", report="report.pdf")
+
+
+@app.route("/retry/job/1/report")
+def retry_job_report():
+    assert job_attempts == 3
+    return request.args.get("name")
@@ -0,0 +1,30 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+if (Test-Path -Path build/secret_encoded) {
+    Remove-Item -Recurse build/secret_encoded
+}
+New-Item -ItemType Directory -Force -Path build/secret_encoded | Out-Null
+
+hurl --very-verbose `
+    --secret 'password=p@ss word&1' `
+    --curl build/secret_encoded/curl.txt `
+    --report-json build/secret_encoded/report-json `
+    tests_ok/secret_encoded/secret_encoded.hurl 2>build/secret_encoded/stderr.txt
+
+# Raw, percent-encoded and Base64 encoded credentials values:
+$secrets = @("p@ss word&1", "p%40ss%20word%261", "Ym9iOnBAc3Mgd29yZCYx")
+
+$files = @(Get-ChildItem -Filter *.json build/secret_encoded/report-json)
+$files += @(Get-ChildItem build/secret_encoded/curl.txt)
+$files += @(Get-ChildItem build/secret_encoded/stderr.txt)
+
+foreach ($secret in $secrets) {
+    foreach ($file in $files) {
+        if (Get-Content $file | Select-String -CaseSensitive -SimpleMatch $secret) {
+            echo "Secret <$secret> have leaked in $file"
+            Get-Content $file
+            exit 1
+        }
+    }
+}
@@ -0,0 +1,13 @@
+from app import app
+from flask import request
+
+
+@app.route("/secret-encoded", methods=["GET", "POST"])
+def secret_encoded():
+    if request.method == "GET":
+        assert request.args["password"] == "p@ss word&1"
+        assert request.authorization.username == "bob"
+        assert request.authorization.password == "p@ss word&1"
+    else:
+        assert request.form["password"] == "p@ss word&1"
+    return ""
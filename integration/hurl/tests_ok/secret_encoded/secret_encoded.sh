@@ -0,0 +1,29 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+rm -rf build/secret_encoded
+mkdir -p build/secret_encoded
+
+hurl --very-verbose \
+    --secret 'password=p@ss word&1' \
+    --curl build/secret_encoded/curl.txt \
+    --report-json build/secret_encoded/report-json \
+    tests_ok/secret_encoded/secret_encoded.hurl 2>build/secret_encoded/stderr.txt
+
+# Raw, percent-encoded and Base64 encoded credentials values:
+secrets=("p@ss word&1" "p%40ss%20word%261" "Ym9iOnBAc3Mgd29yZCYx")
+
+files=$(find build/secret_encoded/report-json/*.json \
+  build/secret_encoded/curl.txt \
+  build/secret_encoded/stderr.txt
+)
+
+for secret in "${secrets[@]}"; do
+  for file in $files; do
+    if grep -q -F "$secret" "$file"; then
+        echo "Secret <$secret> have leaked in $file"
+        cat "$file"
+        exit 1
+    fi
+  done
+done
@@ -0,0 +1,27 @@
+# coding=utf-8
+from app import app
+from flask import Response
+
+counter = 0
+
+
+@app.route("/skip-if/init")
+def skip_if_init():
+    global counter
+    counter = 0
+    return Response(
+        '{"created":true,"deleted":false}', mimetype="application/json"
+    )
+
+
+@app.route("/skip-if/increment")
+def skip_if_increment():
+    global counter
+    counter = counter + 1
+    return str(counter)
+
+
+@app.route("/skip-if/get")
+def skip_if_get():
+    global counter
+    return str(counter)
@@ -0,0 +1,6 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl --variables-file tests_ok/variables_file_structured/variables.yaml `
+     --variables-file tests_ok/variables_file_structured/variables.json `
+     tests_ok/variables_file_structured/variables_file_structured.hurl
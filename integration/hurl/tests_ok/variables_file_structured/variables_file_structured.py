@@ -0,0 +1,25 @@
+import json
+
+from app import app
+from flask import request
+
+
+@app.route("/variables-file-structured/<name>")
+def variables_file_structured_get(name):
+    limit = int(request.args.get("limit"))
+    return json.dumps(
+        {
+            "name": name,
+            "age": 42,
+            "roles": ["admin", "dev"],
+            "tags": ["a", "b", "c"],
+            "limit": limit,
+        }
+    )
+
+
+@app.route("/variables-file-structured", methods=["POST"])
+def variables_file_structured_post():
+    user = json.loads(request.data)
+    assert user == {"name": "Bob", "age": 42, "roles": ["admin", "dev"]}
+    return ""
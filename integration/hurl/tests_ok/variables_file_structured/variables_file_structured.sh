@@ -0,0 +1,6 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --variables-file tests_ok/variables_file_structured/variables.yaml \
+     --variables-file tests_ok/variables_file_structured/variables.json \
+     tests_ok/variables_file_structured/variables_file_structured.hurl
@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl --wait-for http://localhost:8000/wait-for/health --wait-timeout 10s tests_ok/wait_for/wait_for.hurl
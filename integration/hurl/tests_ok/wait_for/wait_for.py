@@ -0,0 +1,19 @@
+from app import app
+from flask import Response
+
+wait_for_attempts = 0
+
+
+@app.route("/wait-for/health")
+def wait_for_health():
+    global wait_for_attempts
+    wait_for_attempts += 1
+    if wait_for_attempts < 3:
+        return Response("Starting", status=503)
+    return Response("OK")
+
+
+@app.route("/wait-for")
+def wait_for():
+    assert wait_for_attempts >= 3
+    return "Hello World!"
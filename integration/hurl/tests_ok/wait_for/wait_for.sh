@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --wait-for http://localhost:8000/wait-for/health --wait-timeout 10s tests_ok/wait_for/wait_for.hurl
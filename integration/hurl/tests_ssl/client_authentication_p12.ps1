@@ -0,0 +1,7 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+# Hurl file not supported on Windows
+#hurl tests_ssl/client_authentication_p12.hurl
+$ErrorActionPreference = 'Continue'
+exit 255
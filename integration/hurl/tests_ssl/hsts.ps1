@@ -0,0 +1,8 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+# Does not work without --ssl-no-revoke
+# hurl --cacert tests_ssl/certs/server/cert.pem `
+#      --hsts build/hsts.txt `
+#      tests_ssl/hsts.hurl
+exit 255
@@ -0,0 +1,7 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+rm -f build/hsts.txt
+hurl --cacert tests_ssl/certs/server/cert.pem \
+     --hsts build/hsts.txt \
+     tests_ssl/hsts.hurl
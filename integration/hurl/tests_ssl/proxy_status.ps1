@@ -0,0 +1,7 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+# Does not work without --ssl-no-revoke
+# hurl --cacert tests_ssl/certs/server/cert.pem `
+#      tests_ssl/proxy_status.hurl
+exit 255
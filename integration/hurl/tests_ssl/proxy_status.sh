@@ -0,0 +1,5 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --cacert tests_ssl/certs/server/cert.pem \
+     tests_ssl/proxy_status.hurl
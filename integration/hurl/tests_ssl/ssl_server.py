@@ -14,6 +14,13 @@ def hello():
     return "Hello World!"
 
 
+@app1.route("/hsts")
+def hsts():
+    return flask.Response(
+        "Hello World!", headers={"Strict-Transport-Security": "max-age=31536000"}
+    )
+
+
 def start_server(port, cert_file, use_client_certificate_authentication):
     ssl_context = get_ssl_context(cert_file, use_client_certificate_authentication)
     app1.run(port=port, ssl_context=ssl_context)
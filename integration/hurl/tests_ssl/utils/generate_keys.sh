@@ -14,6 +14,7 @@ openssl genrsa -out certs/client/key.pem 2048
 openssl req -new -key certs/client/key.pem -sha256 -out certs/client/csr.pem -subj "/C=US/ST=Denial/L=Springfield/O=Dis/CN=client"
 openssl x509 -req -in certs/client/csr.pem -CA certs/ca/cert.pem -CAkey certs/ca/key.pem -CAcreateserial -out certs/client/cert.pem -days 825 -sha256
 openssl rsa -aes256 -in certs/client/key.pem -passout pass:foobar -out certs/client/encrypted.key.pem
+openssl pkcs12 -export -in certs/client/cert.pem -inkey certs/client/key.pem -passout pass:foobar -out certs/client/cert.p12
 
 # Server
 openssl genrsa -out certs/server/key.pem 2048
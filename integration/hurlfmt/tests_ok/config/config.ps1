@@ -0,0 +1,5 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+Set-Location tests_ok/config
+hurlfmt config.hurl
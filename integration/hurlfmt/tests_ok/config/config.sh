@@ -0,0 +1,5 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+cd tests_ok/config
+hurlfmt config.hurl
@@ -0,0 +1,9 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+if (Test-Path build/export_dir) {
+    Remove-Item -Recurse build/export_dir
+}
+hurlfmt --out html --dir tests_ok/export_dir --output-dir build/export_dir
+Write-Host (Get-Content build/export_dir/index.html -Raw) -NoNewLine
+(Get-ChildItem build/export_dir/auth).Name
@@ -0,0 +1,7 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+rm -rf build/export_dir
+hurlfmt --out html --dir tests_ok/export_dir --output-dir build/export_dir
+cat build/export_dir/index.html
+ls build/export_dir/auth
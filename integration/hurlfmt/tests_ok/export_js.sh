@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurlfmt --out js tests_ok/export_code.hurl
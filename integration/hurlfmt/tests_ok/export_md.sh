@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurlfmt --out md tests_ok/export_md.hurl
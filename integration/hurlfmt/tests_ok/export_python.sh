@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurlfmt --out python tests_ok/export_code.hurl
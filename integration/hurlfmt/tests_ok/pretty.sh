@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurlfmt --pretty tests_ok/pretty.hurl
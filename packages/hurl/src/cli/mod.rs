@@ -19,8 +19,10 @@ mod error;
 mod logger;
 pub(crate) mod options;
 mod summary;
+mod wait;
 
 pub(crate) use self::error::CliError;
 pub(crate) use self::logger::BaseLogger;
 pub(crate) use self::options::OutputType;
-pub(crate) use self::summary::summary;
+pub(crate) use self::summary::{load_summary, summary};
+pub(crate) use self::wait::wait_for;
@@ -28,12 +28,16 @@ use hurl::pretty::PrettyMode;
 use hurl::runner::Value;
 use hurl_core::input::Input;
 use hurl_core::types::{BytesPerSec, Count, DurationUnit};
+use regex::Regex;
 
+use super::completion::{self, Shell};
 use super::context::RunContext;
 use super::variables::TypeKind;
 use super::variables_file::VariablesFile;
 use super::{CliOptions, commands, duration, get_version, secret};
-use super::{CliOptionsError, ErrorFormat, HttpVersion, IpResolve, Output, variables};
+use super::{
+    CliOptionsError, ErrorFormat, FileBase, FileRootMode, HttpVersion, IpResolve, Output, variables,
+};
 use super::{OutputType, Verbosity};
 
 /// Parses the command line arguments given a `context` and default options values.
@@ -52,17 +56,23 @@ pub fn parse_cli_args(
     let mut command = clap::Command::new("hurl")
         .version(get_version())
         .disable_colored_help(true)
+        // The implicit help flag is replaced by `-h, --help` and `--help-full` so that `--help`
+        // stays short while options have a long help.
+        .disable_help_flag(true)
         .styles(styles)
         .about("Hurl, run and test HTTP requests with plain text")
         // HTTP options
         .arg(commands::aws_sigv4())
+        .arg(commands::body_print_limit())
         .arg(commands::cacert_file())
         .arg(commands::client_cert_file())
         .arg(commands::compressed())
         .arg(commands::connect_timeout())
         .arg(commands::connect_to())
         .arg(commands::digest())
+        .arg(commands::dns_servers())
         .arg(commands::header())
+        .arg(commands::hsts())
         .arg(commands::http10())
         .arg(commands::http11())
         .arg(commands::http2())
@@ -79,6 +89,7 @@ pub fn parse_cli_args(
         .arg(commands::max_redirects())
         .arg(commands::max_time())
         .arg(commands::negotiate())
+        .arg(commands::nested_form_params())
         .arg(commands::no_cookie_store())
         .arg(commands::no_header())
         .arg(commands::no_proxy())
@@ -87,6 +98,7 @@ pub fn parse_cli_args(
         .arg(commands::pinned_pub_key())
         .arg(commands::proxy())
         .arg(commands::proxy_header())
+        .arg(commands::proxy_user())
         .arg(commands::resolve())
         .arg(commands::ssl_no_revoke())
         .arg(commands::unix_socket())
@@ -99,6 +111,7 @@ pub fn parse_cli_args(
         .arg(commands::fail_with_body())
         .arg(commands::include())
         .arg(commands::json())
+        .arg(commands::json_diff_limit())
         .arg(commands::no_color())
         .arg(commands::no_output())
         .arg(commands::no_pretty())
@@ -108,36 +121,64 @@ pub fn parse_cli_args(
         .arg(commands::verbose())
         .arg(commands::very_verbose())
         .arg(commands::verbosity())
+        .arg(commands::verbose_dir())
+        .arg(commands::replay_dir())
         // Run options
+        .arg(commands::concurrency())
         .arg(commands::continue_on_error())
         .arg(commands::delay())
+        .arg(commands::duration())
+        .arg(commands::env())
+        .arg(commands::export_variables())
         .arg(commands::from_entry())
+        .arg(commands::generate_asserts())
         .arg(commands::jobs())
+        .arg(commands::load_state())
+        .arg(commands::max_body_size())
+        .arg(commands::max_entries())
         .arg(commands::no_assert())
         .arg(commands::no_jsonpath_coercion())
         .arg(commands::parallel())
         .arg(commands::repeat())
         .arg(commands::retry())
         .arg(commands::retry_interval())
+        .arg(commands::retry_on_capture_failure())
+        .arg(commands::sandbox())
         .arg(commands::secret())
         .arg(commands::secrets_file())
+        .arg(commands::snapshot_dir())
+        .arg(commands::snapshot_mask())
+        .arg(commands::snapshot_update())
+        .arg(commands::soft_asserts())
         .arg(commands::test())
+        .arg(commands::throughput())
         .arg(commands::to_entry())
         .arg(commands::variable())
         .arg(commands::variables_file())
+        .arg(commands::wait_for())
+        .arg(commands::wait_timeout())
         // Report options
+        .arg(commands::report_allure())
+        .arg(commands::report_deterministic())
         .arg(commands::report_html())
         .arg(commands::report_json())
         .arg(commands::report_junit())
+        .arg(commands::report_pretty())
         .arg(commands::report_tap())
         // Other options
         .arg(commands::cookies_input_file())
         .arg(commands::cookies_output_file())
+        .arg(commands::file_base())
         .arg(commands::file_root())
+        .arg(commands::file_root_mode())
+        .arg(commands::fixtures_dir())
+        .arg(commands::generate_completion())
         .arg(commands::glob())
         .arg(commands::netrc())
         .arg(commands::netrc_file())
-        .arg(commands::netrc_optional());
+        .arg(commands::netrc_optional())
+        .arg(help())
+        .arg(help_full());
 
     let arg_matches = command.try_get_matches_from_mut(std::env::args_os());
     let arg_matches = match arg_matches {
@@ -150,6 +191,13 @@ pub fn parse_cli_args(
         }
     };
 
+    // Completion scripts are generated from the command line definition, without running anything.
+    if let Some(shell) = arg_matches.get_one::<String>("generate_completion") {
+        let shell = Shell::from_str(shell)?;
+        let script = completion::generate(&command, shell);
+        return Err(CliOptionsError::DisplayCompletion(script));
+    }
+
     // If we've no file input (either from the standard input or from the command line arguments),
     // we just print help and exit.
     if !has_input_files(&arg_matches, context) {
@@ -170,6 +218,25 @@ pub fn parse_cli_args(
     Ok(options)
 }
 
+/// Returns the argument displaying help, with the short description of each option.
+fn help() -> clap::Arg {
+    clap::Arg::new("help")
+        .short('h')
+        .long("help")
+        .help("Print help")
+        .help_heading("Other options")
+        .action(clap::ArgAction::Help)
+}
+
+/// Returns the argument displaying full help, with the description and examples of each option.
+fn help_full() -> clap::Arg {
+    clap::Arg::new("help_full")
+        .long("help-full")
+        .help("Print help, with the description and an example of each option")
+        .help_heading("Other options")
+        .action(clap::ArgAction::HelpLong)
+}
+
 /// Parse command line arguments from `arg_matches`
 /// given a run `context` and `default_options`.
 fn parse_arg_matches(
@@ -177,13 +244,16 @@ fn parse_arg_matches(
     context: &RunContext,
     default_options: CliOptions,
 ) -> Result<CliOptions, CliOptionsError> {
+    let allure_dir = allure_dir(arg_matches, default_options.allure_dir);
     let aws_sigv4 = aws_sigv4(arg_matches, default_options.aws_sigv4);
+    let body_print_limit = body_print_limit(arg_matches, default_options.body_print_limit);
     let cacert_file = cacert_file(arg_matches, default_options.cacert_file)?;
     let client_cert_file = client_cert_file(arg_matches, default_options.client_cert_file)?;
     let client_key_file = client_key_file(arg_matches, default_options.client_key_file)?;
     let color_stdout = color(arg_matches, default_options.color_stdout);
     let color_stderr = color(arg_matches, default_options.color_stderr);
     let compressed = compressed(arg_matches, default_options.compressed);
+    let concurrency = concurrency(arg_matches, default_options.concurrency);
     let connect_timeout = connect_timeout(arg_matches, default_options.connect_timeout)?;
     let connects_to = connects_to(arg_matches, default_options.connects_to);
     let continue_on_error = continue_on_error(arg_matches, default_options.continue_on_error);
@@ -192,14 +262,24 @@ fn parse_arg_matches(
     let curl_file = curl_file(arg_matches, default_options.curl_file);
     let delay = delay(arg_matches, default_options.delay)?;
     let digest = digest(arg_matches, default_options.digest);
+    let dns_servers = dns_servers(arg_matches, default_options.dns_servers);
+    let duration = duration(arg_matches, default_options.duration)?;
+    let env = env(arg_matches, default_options.env)?;
     let error_format = error_format(arg_matches, default_options.error_format)?;
+    let export_variables_file =
+        export_variables_file(arg_matches, default_options.export_variables_file);
     let fail_with_body = fail_with_body(arg_matches, default_options.fail_with_body);
+    let file_base = file_base(arg_matches, default_options.file_base)?;
     let file_root = file_root(arg_matches, default_options.file_root);
+    let file_root_mode = file_root_mode(arg_matches, default_options.file_root_mode)?;
+    let fixtures_dir = fixtures_dir(arg_matches, default_options.fixtures_dir);
     let follow_location = follow_location(arg_matches, default_options.follow_location);
     let follow_location_trusted =
         follow_location_trusted(arg_matches, default_options.follow_location_trusted);
     let from_entry = from_entry(arg_matches, default_options.from_entry);
+    let generate_asserts = generate_asserts(arg_matches, default_options.generate_asserts);
     let headers = headers(arg_matches, default_options.headers);
+    let hsts_file = hsts_file(arg_matches, default_options.hsts_file);
     let html_dir = html_dir(arg_matches, default_options.html_dir)?;
     let http_version = http_version(arg_matches, default_options.http_version);
     let no_assert = no_assert(arg_matches, default_options.no_assert);
@@ -210,9 +290,12 @@ fn parse_arg_matches(
     let insecure = insecure(arg_matches, default_options.insecure);
     let ip_resolve = ip_resolve(arg_matches, default_options.ip_resolve);
     let jobs = jobs(arg_matches, default_options.jobs);
+    let json_diff_limit = json_diff_limit(arg_matches, default_options.json_diff_limit);
     let json_report_dir = json_report_dir(arg_matches, default_options.json_report_dir)?;
     let junit_file = junit_file(arg_matches, default_options.junit_file);
     let limit_rate = limit_rate(arg_matches, default_options.limit_rate);
+    let max_body_size = max_body_size(arg_matches, default_options.max_body_size);
+    let max_entries = max_entries(arg_matches, default_options.max_entries);
     let max_filesize = max_filesize(arg_matches, default_options.max_filesize);
     let max_redirect = max_redirect(arg_matches, default_options.max_redirect)?;
     let negotiate = negotiate(arg_matches, default_options.negotiate);
@@ -228,18 +311,32 @@ fn parse_arg_matches(
     let pinned_pub_key = pinned_pub_key(arg_matches, default_options.pinned_pub_key);
     let progress_bar = progress_bar(arg_matches, context, default_options.progress_bar);
     let pretty = pretty(arg_matches, default_options.pretty);
+    let pretty_file = pretty_file(arg_matches, default_options.pretty_file);
     let proxy = proxy(arg_matches, default_options.proxy);
     let proxy_headers = proxy_headers(arg_matches, default_options.proxy_headers);
+    let proxy_user = proxy_user(arg_matches, default_options.proxy_user);
     let output = output(arg_matches, default_options.output);
     let output_type = output_type(arg_matches, default_options.output_type);
     let repeat = repeat(arg_matches, default_options.repeat)?;
+    let replay_dir = replay_dir(arg_matches, default_options.replay_dir);
+    let report_deterministic =
+        report_deterministic(arg_matches, default_options.report_deterministic);
     let resolves = resolves(arg_matches, default_options.resolves);
     let retry = retry(arg_matches, default_options.retry)?;
     let retry_interval = retry_interval(arg_matches, default_options.retry_interval)?;
+    let retry_on_capture_failure =
+        retry_on_capture_failure(arg_matches, default_options.retry_on_capture_failure);
+    let sandbox = sandbox(arg_matches, default_options.sandbox);
     let secrets = secret(arg_matches, default_options.secrets)?;
+    let snapshot_dir = snapshot_dir(arg_matches, default_options.snapshot_dir);
+    let snapshot_masks = snapshot_masks(arg_matches, default_options.snapshot_masks)?;
+    let snapshot_update = snapshot_update(arg_matches, default_options.snapshot_update);
+    let soft_asserts = soft_asserts(arg_matches, default_options.soft_asserts);
     let ssl_no_revoke = ssl_no_revoke(arg_matches, default_options.ssl_no_revoke);
+    let nested_form_params = nested_form_params(arg_matches, default_options.nested_form_params);
     let tap_file = tap_file(arg_matches, default_options.tap_file);
     let test = test(arg_matches, default_options.test);
+    let throughput = throughput(arg_matches, default_options.throughput);
     let timeout = timeout(arg_matches, default_options.timeout)?;
     let to_entry = to_entry(arg_matches, default_options.to_entry);
     let unix_socket = unix_socket(arg_matches, default_options.unix_socket);
@@ -247,15 +344,21 @@ fn parse_arg_matches(
     let user_agent = user_agent(arg_matches, default_options.user_agent);
     let variables = variables(arg_matches, default_options.variables)?;
     let verbosity = verbosity(arg_matches, default_options.verbosity);
+    let verbose_dir = verbose_dir(arg_matches, default_options.verbose_dir);
+    let wait_for = wait_for(arg_matches, default_options.wait_for);
+    let wait_timeout = wait_timeout(arg_matches, default_options.wait_timeout)?;
 
     Ok(CliOptions {
+        allure_dir,
         aws_sigv4,
+        body_print_limit,
         cacert_file,
         client_cert_file,
         client_key_file,
         color_stdout,
         color_stderr,
         compressed,
+        concurrency,
         connect_timeout,
         connects_to,
         continue_on_error,
@@ -264,22 +367,34 @@ fn parse_arg_matches(
         curl_file,
         delay,
         digest,
+        dns_servers,
+        duration,
+        env,
         error_format,
+        export_variables_file,
         fail_with_body,
+        file_base,
         file_root,
+        file_root_mode,
+        fixtures_dir,
         follow_location,
         follow_location_trusted,
         from_entry,
+        generate_asserts,
         headers,
+        hsts_file,
         html_dir,
         http_version,
         include,
         input_files,
         insecure,
         ip_resolve,
+        json_diff_limit,
         json_report_dir,
         junit_file,
         limit_rate,
+        max_body_size,
+        max_entries,
         max_filesize,
         max_redirect,
         negotiate,
@@ -296,19 +411,31 @@ fn parse_arg_matches(
         pinned_pub_key,
         parallel,
         pretty,
+        pretty_file,
         progress_bar,
         proxy,
         proxy_headers,
+        proxy_user,
         output,
         output_type,
         repeat,
+        replay_dir,
+        report_deterministic,
         resolves,
         retry,
         retry_interval,
+        retry_on_capture_failure,
+        sandbox,
         secrets,
+        snapshot_dir,
+        snapshot_masks,
+        snapshot_update,
+        soft_asserts,
         ssl_no_revoke,
+        nested_form_params,
         tap_file,
         test,
+        throughput,
         timeout,
         to_entry,
         unix_socket,
@@ -316,6 +443,9 @@ fn parse_arg_matches(
         user_agent,
         variables,
         verbosity,
+        verbose_dir,
+        wait_for,
+        wait_timeout,
         jobs,
     })
 }
@@ -340,10 +470,22 @@ fn cacert_file(
     }
 }
 
+fn allure_dir(arg_matches: &ArgMatches, default_value: Option<PathBuf>) -> Option<PathBuf> {
+    get::<String>(arg_matches, "report_allure")
+        .map(PathBuf::from)
+        .or(default_value)
+}
+
 fn aws_sigv4(arg_matches: &ArgMatches, default_value: Option<String>) -> Option<String> {
     get::<String>(arg_matches, "aws_sigv4").or(default_value)
 }
 
+fn body_print_limit(arg_matches: &ArgMatches, default_value: usize) -> usize {
+    get::<u64>(arg_matches, "body_print_limit")
+        .map(|limit| limit as usize)
+        .unwrap_or(default_value)
+}
+
 fn client_cert_file(
     arg_matches: &ArgMatches,
     default_value: Option<String>,
@@ -435,6 +577,12 @@ fn curl_file(arg_matches: &ArgMatches, default_value: Option<PathBuf>) -> Option
         .or(default_value)
 }
 
+fn concurrency(arg_matches: &ArgMatches, default_value: Option<usize>) -> Option<usize> {
+    get::<u32>(arg_matches, "concurrency")
+        .map(|n| n as usize)
+        .or(default_value)
+}
+
 fn delay(arg_matches: &ArgMatches, default_value: Duration) -> Result<Duration, CliOptionsError> {
     match get::<String>(arg_matches, "delay") {
         Some(s) => duration::duration_from_str(&s, DurationUnit::MilliSecond),
@@ -450,6 +598,41 @@ fn digest(arg_matches: &ArgMatches, default_value: bool) -> bool {
     }
 }
 
+fn dns_servers(arg_matches: &ArgMatches, default_value: Option<String>) -> Option<String> {
+    get::<String>(arg_matches, "dns_servers").or(default_value)
+}
+
+fn duration(
+    arg_matches: &ArgMatches,
+    default_value: Duration,
+) -> Result<Duration, CliOptionsError> {
+    match get::<String>(arg_matches, "duration") {
+        Some(s) => duration::duration_from_str(&s, DurationUnit::Second),
+        None => Ok(default_value),
+    }
+}
+
+fn env(
+    arg_matches: &ArgMatches,
+    default_value: Option<String>,
+) -> Result<Option<String>, CliOptionsError> {
+    match get::<String>(arg_matches, "env") {
+        // The environment name is used in a file name, so we only accept simple names.
+        Some(name)
+            if name.is_empty()
+                || !name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') =>
+        {
+            Err(CliOptionsError::Error(format!(
+                "Invalid value '{name}' for env, only letters, digits, '-' and '_' are allowed"
+            )))
+        }
+        Some(name) => Ok(Some(name)),
+        None => Ok(default_value),
+    }
+}
+
 fn error_format(
     arg_matches: &ArgMatches,
     default_value: ErrorFormat,
@@ -460,6 +643,15 @@ fn error_format(
     }
 }
 
+fn export_variables_file(
+    arg_matches: &ArgMatches,
+    default_value: Option<PathBuf>,
+) -> Option<PathBuf> {
+    get::<String>(arg_matches, "export_variables")
+        .map(PathBuf::from)
+        .or(default_value)
+}
+
 fn fail_with_body(arg_matches: &ArgMatches, default_value: bool) -> bool {
     if has_flag(arg_matches, "fail_with_body") {
         true
@@ -468,10 +660,34 @@ fn fail_with_body(arg_matches: &ArgMatches, default_value: bool) -> bool {
     }
 }
 
+fn file_base(
+    arg_matches: &ArgMatches,
+    default_value: FileBase,
+) -> Result<FileBase, CliOptionsError> {
+    match get::<String>(arg_matches, "file_base") {
+        Some(value) => FileBase::from_str(&value),
+        None => Ok(default_value),
+    }
+}
+
 fn file_root(arg_matches: &ArgMatches, default_value: Option<String>) -> Option<String> {
     get::<String>(arg_matches, "file_root").or(default_value)
 }
 
+fn file_root_mode(
+    arg_matches: &ArgMatches,
+    default_value: FileRootMode,
+) -> Result<FileRootMode, CliOptionsError> {
+    match get::<String>(arg_matches, "file_root_mode") {
+        Some(value) => FileRootMode::from_str(&value),
+        None => Ok(default_value),
+    }
+}
+
+fn fixtures_dir(arg_matches: &ArgMatches, default_value: Option<String>) -> Option<String> {
+    get::<String>(arg_matches, "fixtures_dir").or(default_value)
+}
+
 fn follow_location(arg_matches: &ArgMatches, default_value: bool) -> bool {
     if has_flag(arg_matches, "follow_location") || has_flag(arg_matches, "follow_location_trusted")
     {
@@ -495,6 +711,14 @@ fn from_entry(arg_matches: &ArgMatches, default_value: Option<usize>) -> Option<
         .or(default_value)
 }
 
+fn generate_asserts(arg_matches: &ArgMatches, default_value: bool) -> bool {
+    if has_flag(arg_matches, "generate_asserts") {
+        true
+    } else {
+        default_value
+    }
+}
+
 fn headers(arg_matches: &ArgMatches, default_value: Vec<String>) -> Vec<String> {
     let mut headers = default_value;
     if let Some(header) = get_strings(arg_matches, "header") {
@@ -503,6 +727,10 @@ fn headers(arg_matches: &ArgMatches, default_value: Vec<String>) -> Vec<String>
     headers
 }
 
+fn hsts_file(arg_matches: &ArgMatches, default_value: Option<String>) -> Option<String> {
+    get::<String>(arg_matches, "hsts").or(default_value)
+}
+
 fn html_dir(
     arg_matches: &ArgMatches,
     default_value: Option<PathBuf>,
@@ -548,7 +776,7 @@ fn http_version(
 }
 
 fn no_assert(arg_matches: &ArgMatches, default_value: bool) -> bool {
-    if has_flag(arg_matches, "no_assert") {
+    if has_flag(arg_matches, "no_assert") || has_flag(arg_matches, "generate_asserts") {
         true
     } else {
         default_value
@@ -655,6 +883,12 @@ fn jobs(arg_matches: &ArgMatches, default_value: Option<usize>) -> Option<usize>
         .or(default_value)
 }
 
+fn json_diff_limit(arg_matches: &ArgMatches, default_value: usize) -> usize {
+    get::<u64>(arg_matches, "json_diff_limit")
+        .map(|limit| limit as usize)
+        .unwrap_or(default_value)
+}
+
 fn json_report_dir(
     arg_matches: &ArgMatches,
     default_value: Option<PathBuf>,
@@ -694,6 +928,18 @@ fn limit_rate(arg_matches: &ArgMatches, default_value: Option<BytesPerSec>) -> O
         .or(default_value)
 }
 
+fn max_body_size(arg_matches: &ArgMatches, default_value: usize) -> usize {
+    get::<u64>(arg_matches, "max_body_size")
+        .map(|size| size as usize)
+        .unwrap_or(default_value)
+}
+
+fn max_entries(arg_matches: &ArgMatches, default_value: usize) -> usize {
+    get::<u64>(arg_matches, "max_entries")
+        .map(|max| max as usize)
+        .unwrap_or(default_value)
+}
+
 fn max_filesize(arg_matches: &ArgMatches, default_value: Option<u64>) -> Option<u64> {
     get::<u64>(arg_matches, "max_filesize").or(default_value)
 }
@@ -786,7 +1032,10 @@ fn output(arg_matches: &ArgMatches, default_value: Option<Output>) -> Option<Out
 fn output_type(arg_matches: &ArgMatches, default_value: OutputType) -> OutputType {
     if has_flag(arg_matches, "json") {
         OutputType::Json
-    } else if has_flag(arg_matches, "no_output") || has_flag(arg_matches, "test") {
+    } else if has_flag(arg_matches, "no_output")
+        || has_flag(arg_matches, "test")
+        || has_flag(arg_matches, "generate_asserts")
+    {
         OutputType::NoOutput
     } else {
         default_value
@@ -823,6 +1072,12 @@ fn pretty(arg_matches: &ArgMatches, default_value: PrettyMode) -> PrettyMode {
     default_value
 }
 
+fn pretty_file(arg_matches: &ArgMatches, default_value: Option<PathBuf>) -> Option<PathBuf> {
+    get::<String>(arg_matches, "report_pretty")
+        .map(PathBuf::from)
+        .or(default_value)
+}
+
 fn progress_bar(arg_matches: &ArgMatches, context: &RunContext, default_value: bool) -> bool {
     if has_flag(arg_matches, "progress_bar") {
         return true;
@@ -846,6 +1101,10 @@ fn proxy_headers(arg_matches: &ArgMatches, default_value: Vec<String>) -> Vec<St
     proxy_headers
 }
 
+fn proxy_user(arg_matches: &ArgMatches, default_value: Option<String>) -> Option<String> {
+    get::<String>(arg_matches, "proxy_user").or(default_value)
+}
+
 fn repeat(
     arg_matches: &ArgMatches,
     default_value: Option<Count>,
@@ -862,6 +1121,20 @@ fn repeat(
     }
 }
 
+fn replay_dir(arg_matches: &ArgMatches, default_value: Option<PathBuf>) -> Option<PathBuf> {
+    get::<String>(arg_matches, "replay_dir")
+        .map(PathBuf::from)
+        .or(default_value)
+}
+
+fn report_deterministic(arg_matches: &ArgMatches, default_value: bool) -> bool {
+    if has_flag(arg_matches, "report_deterministic") {
+        true
+    } else {
+        default_value
+    }
+}
+
 fn resolves(arg_matches: &ArgMatches, default_value: Vec<String>) -> Vec<String> {
     get_strings(arg_matches, "resolve").unwrap_or(default_value)
 }
@@ -892,6 +1165,22 @@ fn retry_interval(
     }
 }
 
+fn retry_on_capture_failure(arg_matches: &ArgMatches, default_value: bool) -> bool {
+    if has_flag(arg_matches, "retry_on_capture_failure") {
+        true
+    } else {
+        default_value
+    }
+}
+
+fn sandbox(arg_matches: &ArgMatches, default_value: bool) -> bool {
+    if has_flag(arg_matches, "sandbox") {
+        true
+    } else {
+        default_value
+    }
+}
+
 fn secret(
     matches: &ArgMatches,
     default_value: HashMap<String, String>,
@@ -923,6 +1212,46 @@ fn secret(
     Ok(all_secrets)
 }
 
+fn snapshot_dir(arg_matches: &ArgMatches, default_value: Option<PathBuf>) -> Option<PathBuf> {
+    get::<String>(arg_matches, "snapshot_dir")
+        .map(PathBuf::from)
+        .or(default_value)
+}
+
+fn snapshot_masks(
+    arg_matches: &ArgMatches,
+    default_value: Vec<String>,
+) -> Result<Vec<String>, CliOptionsError> {
+    let mut masks = default_value;
+    if let Some(values) = get_strings(arg_matches, "snapshot_mask") {
+        masks.extend(values);
+    }
+    for mask in &masks {
+        if Regex::new(mask).is_err() {
+            return Err(CliOptionsError::Error(format!(
+                "Invalid regex <{mask}> for --snapshot-mask option"
+            )));
+        }
+    }
+    Ok(masks)
+}
+
+fn snapshot_update(arg_matches: &ArgMatches, default_value: bool) -> bool {
+    if has_flag(arg_matches, "snapshot_update") {
+        true
+    } else {
+        default_value
+    }
+}
+
+fn soft_asserts(arg_matches: &ArgMatches, default_value: bool) -> bool {
+    if has_flag(arg_matches, "soft_asserts") {
+        true
+    } else {
+        default_value
+    }
+}
+
 fn ssl_no_revoke(arg_matches: &ArgMatches, default_value: bool) -> bool {
     if has_flag(arg_matches, "ssl_no_revoke") {
         true
@@ -931,6 +1260,14 @@ fn ssl_no_revoke(arg_matches: &ArgMatches, default_value: bool) -> bool {
     }
 }
 
+fn nested_form_params(arg_matches: &ArgMatches, default_value: bool) -> bool {
+    if has_flag(arg_matches, "nested_form_params") {
+        true
+    } else {
+        default_value
+    }
+}
+
 fn tap_file(arg_matches: &ArgMatches, default_value: Option<PathBuf>) -> Option<PathBuf> {
     get::<String>(arg_matches, "report_tap")
         .map(PathBuf::from)
@@ -945,6 +1282,10 @@ fn test(arg_matches: &ArgMatches, default_value: bool) -> bool {
     }
 }
 
+fn throughput(arg_matches: &ArgMatches, default_value: Option<u32>) -> Option<u32> {
+    get::<u32>(arg_matches, "throughput").or(default_value)
+}
+
 fn timeout(arg_matches: &ArgMatches, default_value: Duration) -> Result<Duration, CliOptionsError> {
     match get::<String>(arg_matches, "max_time") {
         Some(s) => duration::duration_from_str(&s, DurationUnit::Second),
@@ -980,6 +1321,15 @@ fn variables(
     // Variables are typed, based on their values.
     let type_kind = TypeKind::Inferred;
 
+    // Add variables from a state file, written by a previous run:
+    if let Some(filename) = get_string(matches, "load_state") {
+        let vars = VariablesFile::open_state(Path::new(&filename))?;
+        for var in vars {
+            let (name, value) = var?;
+            variables.insert(name.to_string(), value);
+        }
+    }
+
     // Add variables from files:
     if let Some(filenames) = get_strings(matches, "variables_file") {
         for f in &filenames {
@@ -1016,6 +1366,26 @@ fn verbosity(arg_matches: &ArgMatches, default_value: Option<Verbosity>) -> Opti
     }
 }
 
+fn verbose_dir(arg_matches: &ArgMatches, default_value: Option<PathBuf>) -> Option<PathBuf> {
+    get::<String>(arg_matches, "verbose_dir")
+        .map(PathBuf::from)
+        .or(default_value)
+}
+
+fn wait_for(arg_matches: &ArgMatches, default_value: Option<String>) -> Option<String> {
+    get::<String>(arg_matches, "wait_for").or(default_value)
+}
+
+fn wait_timeout(
+    arg_matches: &ArgMatches,
+    default_value: Duration,
+) -> Result<Duration, CliOptionsError> {
+    match get::<String>(arg_matches, "wait_timeout") {
+        Some(s) => duration::duration_from_str(&s, DurationUnit::Second),
+        None => Ok(default_value),
+    }
+}
+
 /// Returns a list of path names from the command line options `matches`.
 fn glob_files(matches: &ArgMatches) -> Result<Vec<Input>, CliOptionsError> {
     let mut all_files = vec![];
@@ -31,15 +31,28 @@ pub fn aws_sigv4() -> clap::Arg {
         .long("aws-sigv4")
         .value_name("PROVIDER1[:PROVIDER2[:REGION[:SERVICE]]]")
         .help("Use AWS V4 signature authentication in the transfer")
+        .long_help("Generate an Authorization header with an AWS SigV4 signature.\n\nUse -u, --user to specify Access Key Id (username) and Secret Key (password).\n\nTo use temporary session credentials (e.g. for an AWS IAM Role), add the X-Amz-Security-Token header containing the session token.\n\nExample:\n  $ hurl --aws-sigv4 aws:amz:eu-central-1:sts --user $AWS_ACCESS_KEY_ID:$AWS_SECRET_ACCESS_KEY sts.hurl")
         .help_heading("HTTP options")
         .num_args(1)
 }
 
+pub fn body_print_limit() -> clap::Arg {
+    clap::Arg::new("body_print_limit")
+        .long("body-print-limit")
+        .value_name("CHARS")
+        .value_parser(clap::value_parser!(u64))
+        .help("Maximum number of chars printed for each body when a JSON or XML body assert fails [default: 2048]")
+        .long_help("Maximum number of chars printed for each body when an implicit JSON or XML body assert fails.\n\nWhen an implicit body assert fails on a JSON or XML body, actual and expected bodies are pretty-printed, so that a minified body can be compared with the expected one. A marker is inserted under the first difference, and each body is truncated around this difference to this maximum size.\n\n[default: 2048]\n\nExample:\n  $ hurl --test --body-print-limit 8192 test.hurl")
+        .help_heading("Output options")
+        .num_args(1)
+}
+
 pub fn cacert_file() -> clap::Arg {
     clap::Arg::new("cacert_file")
         .long("cacert")
         .value_name("FILE")
         .help("CA certificate to verify peer against (PEM format)")
+        .long_help("Specifies the certificate file for peer verification. The file may contain multiple CA certificates and must be in PEM format. Normally Hurl is built to use a default file for this, so this option is typically used to alter that default file.\n\nExample:\n  $ hurl --cacert ca.pem test.hurl")
         .help_heading("HTTP options")
         .num_args(1)
 }
@@ -50,6 +63,7 @@ pub fn client_cert_file() -> clap::Arg {
         .short('E')
         .value_name("CERTIFICATE[:PASSWORD]")
         .help("Client certificate file and password")
+        .long_help("Client certificate file and password.\n\nSee also --key.\n\nExample:\n  $ hurl --cert client.pem:password --key client.key test.hurl")
         .help_heading("HTTP options")
         .num_args(1)
 }
@@ -59,6 +73,7 @@ pub fn client_key_file() -> clap::Arg {
         .long("key")
         .value_name("KEY")
         .help("Private key file name")
+        .long_help("Private key file name.\n\nExample:\n  $ hurl --cert client.pem --key client.key test.hurl")
         .help_heading("HTTP options")
         .num_args(1)
 }
@@ -67,6 +82,7 @@ pub fn color() -> clap::Arg {
     clap::Arg::new("color")
         .long("color")
         .help("Colorize output")
+        .long_help("Colorize standard output and standard error.\n\nBy default, Hurl outputs a prettified and colorized response. When redirected through pipes, standard streams are not colorized and color can be forced with this option.\n\nExample:\n  $ hurl --color test.hurl | less -R\n\nEnvironment variables: HURL_COLOR")
         .help_heading("Output options")
         .conflicts_with("no_color")
         .action(clap::ArgAction::SetTrue)
@@ -76,15 +92,28 @@ pub fn compressed() -> clap::Arg {
     clap::Arg::new("compressed")
         .long("compressed")
         .help("Request compressed response (using deflate or gzip)")
+        .long_help("Request a compressed response using one of the algorithms br, gzip, deflate and automatically decompress the content.\n\nExample:\n  $ hurl --compressed test.hurl\n\nEnvironment variables: HURL_COMPRESSED")
         .help_heading("HTTP options")
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn concurrency() -> clap::Arg {
+    clap::Arg::new("concurrency")
+        .long("concurrency")
+        .value_name("NUM")
+        .value_parser(clap::value_parser!(u32).range(1..))
+        .help("Run a load test with NUM concurrent virtual users")
+        .long_help("Run a load test with NUM concurrent virtual users. In load test mode, a single Hurl file is replayed continuously by each virtual user for the time given by --duration. Latencies and error rates of the requests are collected, and a summary report is displayed on standard error at the end of the run.\n\nIf not set with --throughput, a single virtual user is used.\n\nExample:\n  $ hurl --concurrency 10 --duration 30s test.hurl")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
 pub fn connect_timeout() -> clap::Arg {
     clap::Arg::new("connect_timeout")
         .long("connect-timeout")
         .value_name("SECONDS")
         .help("Maximum time allowed for connection [default: 300]")
+        .long_help("Maximum time in seconds that you allow Hurl's connection to take.\n\nYou can specify time units in the connect timeout expression. Set Hurl to use a connect timeout of 20 seconds with --connect-timeout 20s or set it to 35,000 milliseconds with --connect-timeout 35000ms. No spaces allowed.\n\nSee also -m, --max-time.\n\n[default: 300]\n\nExample:\n  $ hurl --connect-timeout 20s test.hurl\n\nEnvironment variables: HURL_CONNECT_TIMEOUT")
         .help_heading("HTTP options")
         .num_args(1)
 }
@@ -94,6 +123,7 @@ pub fn connect_to() -> clap::Arg {
         .long("connect-to")
         .value_name("HOST1:PORT1:HOST2:PORT2")
         .help("For a request to the given HOST1:PORT1 pair, connect to HOST2:PORT2 instead")
+        .long_help("For a request to the given HOST1:PORT1 pair, connect to HOST2:PORT2 instead. This option can be used several times in a command line.\n\nSee also --resolve.\n\nExample:\n  $ hurl --connect-to example.com:443:localhost:8443 test.hurl")
         .help_heading("HTTP options")
         .num_args(1)
         .action(clap::ArgAction::Append)
@@ -103,6 +133,7 @@ pub fn continue_on_error() -> clap::Arg {
     clap::Arg::new("continue_on_error")
         .long("continue-on-error")
         .help("Continue executing requests even if an error occurs")
+        .long_help("Continue executing requests to the end of the Hurl file even when an assert error occurs. By default, Hurl exits after an assert error in the HTTP response.\n\nNote that this option does not affect the behavior with multiple input Hurl files.\n\nAll the input files are executed independently. The result of one file does not affect the execution of the other Hurl files.\n\nExample:\n  $ hurl --continue-on-error test.hurl\n\nEnvironment variables: HURL_CONTINUE_ON_ERROR")
         .help_heading("Run options")
         .action(clap::ArgAction::SetTrue)
 }
@@ -111,8 +142,10 @@ pub fn cookies_input_file() -> clap::Arg {
     clap::Arg::new("cookies_input_file")
         .long("cookie")
         .short('b')
+        .alias("cookie-jar-load")
         .value_name("FILE")
         .help("Read cookies from FILE")
+        .long_help("Read cookies from FILE (using the Netscape cookie file format).\n\nCombined with -c, --cookie-jar, you can simulate a cookie storage between successive Hurl runs. This option can also be used as --cookie-jar-load FILE.\n\nCookies can also be read from a file for a single request with cookie-file in an [Options] section.\n\nExample:\n  $ hurl --cookie cookies.txt test.hurl")
         .help_heading("Other options")
         .num_args(1)
 }
@@ -121,8 +154,10 @@ pub fn cookies_output_file() -> clap::Arg {
     clap::Arg::new("cookies_output_file")
         .long("cookie-jar")
         .short('c')
+        .alias("cookie-jar-save")
         .value_name("FILE")
         .help("Write cookies to FILE after running the session")
+        .long_help("Write cookies to FILE after running the session. The file will be written using the Netscape cookie file format.\n\nCombined with -b, --cookie, you can simulate a cookie storage between successive Hurl runs. This option can also be used as --cookie-jar-save FILE.\n\nExample:\n  $ hurl --cookie-jar cookies.txt test.hurl")
         .help_heading("Other options")
         .num_args(1)
 }
@@ -132,6 +167,7 @@ pub fn curl() -> clap::Arg {
         .long("curl")
         .value_name("FILE")
         .help("Export each request to a list of curl commands")
+        .long_help("Export each request to a list of curl commands.\n\nExample:\n  $ hurl --curl commands.txt test.hurl")
         .help_heading("Output options")
         .num_args(1)
 }
@@ -141,6 +177,7 @@ pub fn delay() -> clap::Arg {
         .long("delay")
         .value_name("MILLISECONDS")
         .help("Sets delay before each request (aka sleep) [default: 0]")
+        .long_help("Sets delay before each request (aka sleep). The delay is not applied to requests that have been retried because of --retry. See --retry-interval to space retried requests.\n\nYou can specify time units in the delay expression. Set Hurl to use a delay of 2 seconds with --delay 2s or set it to 500 milliseconds with --delay 500ms. Supported time units: ms, s, m, h. No spaces allowed.\n\n[default: 0]\n\nExample:\n  $ hurl --delay 1s test.hurl\n\nEnvironment variables: HURL_DELAY")
         .help_heading("Run options")
         .num_args(1)
 }
@@ -149,33 +186,109 @@ pub fn digest() -> clap::Arg {
     clap::Arg::new("digest")
         .long("digest")
         .help("Tell Hurl to use HTTP Digest authentication")
+        .long_help("Tell Hurl to use HTTP Digest authentication\n\nExample:\n  $ hurl --digest --user bob:secret test.hurl")
         .help_heading("HTTP options")
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn dns_servers() -> clap::Arg {
+    clap::Arg::new("dns_servers")
+        .long("dns-servers")
+        .value_name("ADDRESSES")
+        .help("Use the given comma separated list of DNS servers instead of the system default")
+        .long_help("Use the given comma separated list of DNS servers instead of the system default. Addresses are IPv4 or IPv6, with an optional port (for instance 1.1.1.1,8.8.8.8:53). This option requires a libcurl built with the c-ares resolver.\n\nExample:\n  $ hurl --dns-servers 1.1.1.1,8.8.8.8 test.hurl")
+        .help_heading("HTTP options")
+        .num_args(1)
+}
+
+pub fn duration() -> clap::Arg {
+    clap::Arg::new("duration")
+        .long("duration")
+        .value_name("SECONDS")
+        .help("Duration of a load test [default: 10]")
+        .long_help("Duration of a load test, see --concurrency and --throughput. When the duration is elapsed, the virtual users complete their current run of the Hurl file, then stop.\n\nYou can specify time units in the duration expression. Set Hurl to use a duration of 1 minute with --duration 1m or set it to 500 milliseconds with --duration 500ms. Supported time units: ms, s, m, h. No spaces allowed.\n\n[default: 10]\n\nExample:\n  $ hurl --concurrency 10 --duration 30s test.hurl")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
+pub fn env() -> clap::Arg {
+    clap::Arg::new("env")
+        .long("env")
+        .value_name("NAME")
+        .help("Use the environment profile NAME, read from hurl.NAME.toml files")
+        .long_help("Use the environment profile NAME. Besides the project files hurl.toml, the profile files hurl.NAME.toml are read, with the same syntax. Options, variables and secrets files of a profile file override the ones of the project file of the same directory.\n\nProfiles allow to switch between environments (dev, staging, prod etc...) with a single option.\n\nExample:\n  $ hurl --env staging --test tests/")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
 pub fn error_format() -> clap::Arg {
     clap::Arg::new("error_format")
         .long("error-format")
         .value_name("FORMAT")
         .value_parser(["short", "long"])
         .help("Control the format of error messages [default: short]")
+        .long_help("Control the format of error message (short by default or long).  When using long, the response body is logged when there are errors.\n\n[default: short]\n\nExample:\n  $ hurl --error-format long test.hurl\n\nEnvironment variables: HURL_ERROR_FORMAT")
         .help_heading("Output options")
         .num_args(1)
 }
 
+pub fn export_variables() -> clap::Arg {
+    clap::Arg::new("export_variables")
+        .long("export-variables")
+        .value_name("FILE")
+        .help("Write the variables to FILE after running the session")
+        .long_help("Write the variables to FILE after running the session, as a JSON object. Captured values are written along the variables defined on the command line; secret variables are not written.\n\nCombined with --load-state, you can resume a session with --from-entry without redefining the previously captured variables.\n\nExample:\n  $ hurl --export-variables state.json test.hurl")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
 pub fn fail_with_body() -> clap::Arg {
     clap::Arg::new("fail_with_body")
         .long("fail-with-body")
         .help("Output body response if there are any errors")
+        .long_help("Output body response on standard output if there are any errors. By default, Hurl outputs the last body response on standard output only when a run is successful.\n\nExample:\n  $ hurl --fail-with-body test.hurl\n\nEnvironment variables: HURL_FAIL_WITH_BODY")
         .help_heading("Output options")
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn file_base() -> clap::Arg {
+    clap::Arg::new("file_base")
+        .long("file-base")
+        .value_name("BASE")
+        .value_parser(["root", "file"])
+        .help("Resolve relative file paths from the file root or the Hurl file directory [default: root]")
+        .long_help("Set the base directory of relative file paths: the file root (root by default) or the directory of each Hurl file (file). With file, a test tree can use --file-root as a sandbox for all its files, while each Hurl file refers to files relatively to its own directory. File accesses are always checked against the file root.\n\n[default: root]\n\nExample:\n  $ hurl --test --file-root tests --file-base file tests")
+        .help_heading("Other options")
+        .num_args(1)
+}
+
 pub fn file_root() -> clap::Arg {
     clap::Arg::new("file_root")
         .long("file-root")
         .value_name("DIR")
         .help("Set root directory to import files [default: input file directory]")
+        .long_help("Set root directory to import files in Hurl. This is used for files in multipart form data, request body and response output. When it is not explicitly defined, files are relative to the Hurl file's directory.\n\nExample:\n  $ hurl --file-root fixtures test.hurl")
+        .help_heading("Other options")
+        .num_args(1)
+}
+
+pub fn file_root_mode() -> clap::Arg {
+    clap::Arg::new("file_root_mode")
+        .long("file-root-mode")
+        .value_name("MODE")
+        .value_parser(["strict", "permissive"])
+        .help("Control the access to files outside the file root [default: strict]")
+        .long_help("Control the access to files outside the file root (strict by default or permissive). With strict, files must be under the file root: neither parent directories (..) nor symbolic links can be used to access a file outside the file root. With permissive, files can be read and written anywhere, the file root being only used to resolve relative paths.\n\n[default: strict]\n\nExample:\n  $ hurl --file-root-mode permissive test.hurl")
+        .help_heading("Other options")
+        .num_args(1)
+}
+
+pub fn fixtures_dir() -> clap::Arg {
+    clap::Arg::new("fixtures_dir")
+        .long("fixtures-dir")
+        .value_name("DIR")
+        .help("Set directory of content-addressed fixtures")
+        .long_help("Set the directory of content-addressed fixtures, used by file,sha256:<HASH>; bodies. A fixture is stored in DIR under its SHA-256 digest. If a fixture is missing, its URL is looked up in DIR/manifest.json, then it is downloaded and stored in DIR. The digest of a fixture is always verified.\n\nExample:\n  $ hurl --fixtures-dir fixtures test.hurl")
         .help_heading("Other options")
         .num_args(1)
 }
@@ -185,6 +298,7 @@ pub fn follow_location() -> clap::Arg {
         .long("location")
         .short('L')
         .help("Follow redirects")
+        .long_help("Follow redirect. To limit the amount of redirects to follow use the --max-redirs option\n\nExample:\n  $ hurl --location test.hurl\n\nEnvironment variables: HURL_LOCATION")
         .help_heading("HTTP options")
         .action(clap::ArgAction::SetTrue)
 }
@@ -193,6 +307,7 @@ pub fn follow_location_trusted() -> clap::Arg {
     clap::Arg::new("follow_location_trusted")
         .long("location-trusted")
         .help("Follow redirects but allows sending the name + password to all hosts that the site may redirect to")
+        .long_help("Like -L, --location, but allows sending the name + password to all hosts that the site may redirect to. This may or may not introduce a security breach if the site redirects you to a site to which you send your authentication info (which is plaintext in the case of HTTP Basic authentication).\n\nExample:\n  $ hurl --location-trusted --user bob:secret test.hurl\n\nEnvironment variables: HURL_LOCATION_TRUSTED")
         .help_heading("HTTP options")
         .action(clap::ArgAction::SetTrue)
 }
@@ -203,15 +318,37 @@ pub fn from_entry() -> clap::Arg {
         .value_name("ENTRY_NUMBER")
         .value_parser(clap::value_parser!(u32).range(1..))
         .help("Execute Hurl file from ENTRY_NUMBER (starting at 1)")
+        .long_help("Execute Hurl file from ENTRY_NUMBER (starting at 1).\n\nExample:\n  $ hurl --from-entry 3 test.hurl")
         .help_heading("Run options")
         .num_args(1)
 }
 
+pub fn generate_asserts() -> clap::Arg {
+    clap::Arg::new("generate_asserts")
+        .long("generate-asserts")
+        .help("Rewrite input files with asserts generated from the responses")
+        .long_help("Run the input files without checking their asserts, and rewrite them with asserts generated from the actual responses: status code, Content-Type and Location headers, and top-level fields of JSON bodies. Existing captures are kept, existing asserts are replaced. This option is useful to bootstrap regression tests from a working API.\n\nExample:\n  $ hurl --generate-asserts test.hurl")
+        .help_heading("Run options")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn generate_completion() -> clap::Arg {
+    clap::Arg::new("generate_completion")
+        .long("generate-completion")
+        .value_name("SHELL")
+        .value_parser(["bash", "zsh", "fish", "powershell"])
+        .help("Print a completion script for SHELL (bash, zsh, fish or powershell)")
+        .long_help("Print a shell completion script for SHELL and exit. SHELL can be bash, zsh, fish or powershell.\n\nBesides options names, the generated script completes option values: the possible values of options like --error-format, files and directories for report options, and variable names for --variable, read from the files given with --variables-file.\n\nFor instance, source <(hurl --generate-completion bash) enables completion in the current bash session.\n\nExample:\n  $ hurl --generate-completion zsh > ~/.zfunc/_hurl")
+        .help_heading("Other options")
+        .num_args(1)
+}
+
 pub fn glob() -> clap::Arg {
     clap::Arg::new("glob")
         .long("glob")
         .value_name("GLOB")
         .help("Specify input files that match the given GLOB. Multiple glob flags may be used")
+        .long_help("Specify input files that match the given glob pattern.\n\nMultiple glob flags may be used. This flag supports common Unix glob patterns like *, ? and []. However, to avoid your shell accidentally expanding glob patterns before Hurl handles them, you must use single quotes or double quotes around each pattern.\n\nExample:\n  $ hurl --test --glob 'tests/**/*.hurl'")
         .help_heading("Other options")
         .num_args(1)
         .action(clap::ArgAction::Append)
@@ -223,16 +360,28 @@ pub fn header() -> clap::Arg {
         .short('H')
         .value_name("NAME:VALUE")
         .help("Pass custom header(s) to server")
+        .long_help("Add an extra header to include in information sent. Can be used several times in a command.\n\nDo not add newlines or carriage returns.\n\nExample:\n  $ hurl --header 'Accept-Language: fr' test.hurl\n\nEnvironment variables: HURL_HEADER='name1:value1|name2:value2' (headers are separated by |)")
         .help_heading("HTTP options")
         .num_args(1)
         .action(clap::ArgAction::Append)
 }
 
+pub fn hsts() -> clap::Arg {
+    clap::Arg::new("hsts")
+        .long("hsts")
+        .value_name("FILE")
+        .help("Enable HSTS, using FILE as the HSTS cache")
+        .long_help("Enable HSTS (HTTP Strict Transport Security) and use FILE as a persistent HSTS cache. The cache is read before the first request, and hosts sending a Strict-Transport-Security header over HTTPS are added to it. Plain HTTP requests to a cached host are upgraded to HTTPS. The cache is written back to FILE at the end of each Hurl file run; FILE is created if it doesn't exist.\n\nWhether a request has been upgraded can be checked with the hstsUpgraded query.\n\nExample:\n  $ hurl --hsts build/hsts.txt test.hurl")
+        .help_heading("HTTP options")
+        .num_args(1)
+}
+
 pub fn http10() -> clap::Arg {
     clap::Arg::new("http10")
         .long("http1.0")
         .short('0')
         .help("Tell Hurl to use HTTP version 1.0")
+        .long_help("Tells Hurl to use HTTP version 1.0 instead of using its internally preferred HTTP version.\n\nExample:\n  $ hurl --http1.0 test.hurl\n\nEnvironment variables: HURL_HTTP10")
         .help_heading("HTTP options")
         .action(clap::ArgAction::SetTrue)
 }
@@ -241,6 +390,7 @@ pub fn http11() -> clap::Arg {
     clap::Arg::new("http11")
         .long("http1.1")
         .help("Tell Hurl to use HTTP version 1.1")
+        .long_help("Tells Hurl to use HTTP version 1.1.\n\nExample:\n  $ hurl --http1.1 test.hurl\n\nEnvironment variables: HURL_HTTP11")
         .help_heading("HTTP options")
         .action(clap::ArgAction::SetTrue)
 }
@@ -249,6 +399,7 @@ pub fn http2() -> clap::Arg {
     clap::Arg::new("http2")
         .long("http2")
         .help("Tell Hurl to use HTTP version 2")
+        .long_help("Tells Hurl to use HTTP version 2. For HTTPS, this means Hurl negotiates HTTP/2 in the TLS handshake. Hurl does this by default. For HTTP, this means Hurl attempts to upgrade the request to HTTP/2 using the Upgrade: request header.\n\nExample:\n  $ hurl --http2 test.hurl\n\nEnvironment variables: HURL_HTTP2")
         .help_heading("HTTP options")
         .action(clap::ArgAction::SetTrue)
 }
@@ -257,6 +408,7 @@ pub fn http3() -> clap::Arg {
     clap::Arg::new("http3")
         .long("http3")
         .help("Tell Hurl to use HTTP version 3")
+        .long_help("Tells Hurl to try HTTP version 3 to the host in the URL, but fallback to earlier HTTP versions if the HTTP/3 connection establishment fails. HTTP/3 is only available for HTTPS and not for HTTP URLs.\n\nExample:\n  $ hurl --http3 test.hurl\n\nEnvironment variables: HURL_HTTP3")
         .help_heading("HTTP options")
         .action(clap::ArgAction::SetTrue)
 }
@@ -266,6 +418,9 @@ pub fn include() -> clap::Arg {
         .long("include")
         .short('i')
         .help("Include the HTTP headers in the output")
+        .long_help(
+            "Include the HTTP headers in the output\n\nExample:\n  $ hurl --include test.hurl",
+        )
         .help_heading("Output options")
         .action(clap::ArgAction::SetTrue)
 }
@@ -275,6 +430,7 @@ pub fn insecure() -> clap::Arg {
         .long("insecure")
         .short('k')
         .help("Allow insecure SSL connections")
+        .long_help("This option explicitly allows Hurl to perform \"insecure\" SSL connections and transfers.\n\nExample:\n  $ hurl --insecure test.hurl\n\nEnvironment variables: HURL_INSECURE")
         .help_heading("HTTP options")
         .action(clap::ArgAction::SetTrue)
 }
@@ -284,6 +440,7 @@ pub fn ipv4() -> clap::Arg {
         .long("ipv4")
         .short('4')
         .help("Tell Hurl to use IPv4 addresses only when resolving host names, and not for example try IPv6")
+        .long_help("This option tells Hurl to use IPv4 addresses only when resolving host names, and not for example try IPv6.\n\nExample:\n  $ hurl --ipv4 test.hurl\n\nEnvironment variables: HURL_IPV4")
         .help_heading("HTTP options")
         .action(clap::ArgAction::SetTrue)
 }
@@ -293,6 +450,7 @@ pub fn ipv6() -> clap::Arg {
         .long("ipv6")
         .short('6')
         .help("Tell Hurl to use IPv6 addresses only when resolving host names, and not for example try IPv4")
+        .long_help("This option tells Hurl to use IPv6 addresses only when resolving host names, and not for example try IPv4.\n\nExample:\n  $ hurl --ipv6 test.hurl\n\nEnvironment variables: HURL_IPV6")
         .help_heading("HTTP options")
         .action(clap::ArgAction::SetTrue)
 }
@@ -303,6 +461,7 @@ pub fn jobs() -> clap::Arg {
         .value_name("NUM")
         .value_parser(clap::value_parser!(u32).range(1..))
         .help("Maximum number of parallel jobs, 1 to disable parallel execution")
+        .long_help("Maximum number of parallel jobs in parallel mode. Default value corresponds (in most cases) to the current amount of CPUs. Set to 1 to disable parallel execution of files.\n\nSee also --parallel.\n\nExample:\n  $ hurl --test --jobs 8 *.hurl\n\nEnvironment variables: HURL_JOBS")
         .help_heading("Run options")
         .num_args(1)
 }
@@ -311,27 +470,73 @@ pub fn json() -> clap::Arg {
     clap::Arg::new("json")
         .long("json")
         .help("Output each Hurl file result to JSON")
+        .long_help("Output each Hurl file result to JSON. The format is very closed to HAR format.\n\nExample:\n  $ hurl --json *.hurl > results.json")
         .help_heading("Output options")
         .conflicts_with("no_output")
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn json_diff_limit() -> clap::Arg {
+    clap::Arg::new("json_diff_limit")
+        .long("json-diff-limit")
+        .value_name("NUM")
+        .value_parser(clap::value_parser!(u64))
+        .help("Maximum number of differences displayed when a JSON assert fails, 0 to disable [default: 10]")
+        .long_help("Maximum number of differences displayed when a JSON equality assert fails, 0 to disable.\n\nWhen an equality assert fails on JSON objects or arrays (for instance `body == file,expected.json;`), the differing nodes are reported with their JSONPath, like `$.items[3].price: expected 10 actual 12`, instead of the whole actual and expected values. Only the first differences are displayed.\n\n[default: 10]\n\nExample:\n  $ hurl --test --json-diff-limit 50 test.hurl")
+        .help_heading("Output options")
+        .num_args(1)
+}
+
 pub fn limit_rate() -> clap::Arg {
     clap::Arg::new("limit_rate")
         .long("limit-rate")
         .value_name("SPEED")
         .value_parser(clap::value_parser!(u64))
         .help("Specify the maximum transfer rate in bytes/second, for both downloads and uploads")
+        .long_help("Specify the maximum transfer rate you want Hurl to use, for both downloads and uploads. This feature is useful if you have a limited pipe and you would like your transfer not to use your entire bandwidth. To make it slower than it otherwise would be. The given speed is measured in bytes/second.\n\nExample:\n  $ hurl --limit-rate 100K test.hurl\n\nEnvironment variables: HURL_LIMIT_RATE")
         .help_heading("HTTP options")
         .num_args(1)
 }
 
+pub fn load_state() -> clap::Arg {
+    clap::Arg::new("load_state")
+        .long("load-state")
+        .value_name("FILE")
+        .help("Load variables from a FILE written by --export-variables")
+        .long_help("Load variables from FILE, a JSON object written by --export-variables.\n\nVariables defined with --variable or --variables-file take precedence over the variables of FILE.\n\nExample:\n  $ hurl --load-state state.json --from-entry 4 test.hurl")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
+pub fn max_body_size() -> clap::Arg {
+    clap::Arg::new("max_body_size")
+        .long("max-body-size")
+        .value_name("CHARS")
+        .value_parser(clap::value_parser!(u64))
+        .help("Maximum size in chars of a body written in a Hurl file [default: 10000000]")
+        .long_help("Maximum size in chars of a request or response body written in a Hurl file. A Hurl file with a larger body is not parsed and an error is reported: large bodies can be read from a file with a file body (file,<filename>;) instead.\n\n[default: 10000000]\n\nExample:\n  $ hurl --max-body-size 50000000 test.hurl")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
+pub fn max_entries() -> clap::Arg {
+    clap::Arg::new("max_entries")
+        .long("max-entries")
+        .value_name("NUM")
+        .value_parser(clap::value_parser!(u64))
+        .help("Maximum number of entries of a Hurl file [default: 100000]")
+        .long_help("Maximum number of entries of a Hurl file. A Hurl file with more entries is not parsed and an error is reported.\n\n[default: 100000]\n\nExample:\n  $ hurl --max-entries 500000 test.hurl")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
 pub fn max_filesize() -> clap::Arg {
     clap::Arg::new("max_filesize")
         .long("max-filesize")
         .value_name("BYTES")
         .value_parser(clap::value_parser!(u64))
         .help("Specify the maximum size in bytes of a file to download")
+        .long_help("Specify the maximum size in bytes of a file to download. If the file requested is larger than this value, the transfer does not start.\n\nExample:\n  $ hurl --max-filesize 1000000 test.hurl\n\nEnvironment variables: HURL_MAX_FILESIZE")
         .help_heading("HTTP options")
         .num_args(1)
 }
@@ -342,6 +547,7 @@ pub fn max_redirects() -> clap::Arg {
         .value_name("NUM")
         .value_parser(clap::value_parser!(i32))
         .help("Maximum number of redirects allowed, -1 for unlimited redirects [default: 50]")
+        .long_help("Set maximum number of redirection-followings allowed\n\nBy default, the limit is set to 50 redirections. Set this option to -1 to make it unlimited.\n\n[default: 50]\n\nExample:\n  $ hurl --location --max-redirs 5 test.hurl\n\nEnvironment variables: HURL_MAX_REDIRS")
         .help_heading("HTTP options")
         .num_args(1)
         .allow_negative_numbers(true)
@@ -353,6 +559,7 @@ pub fn max_time() -> clap::Arg {
         .short('m')
         .value_name("SECONDS")
         .help("Maximum time allowed for the transfer [default: 300]")
+        .long_help("Maximum time in seconds that you allow a request/response to take. This is the standard timeout.\n\nYou can specify time units in the maximum time expression. Set Hurl to use a maximum time of 20 seconds with --max-time 20s or set it to 35,000 milliseconds with --max-time 35000ms. No spaces allowed.\n\nSee also --connect-timeout.\n\n[default: 300]\n\nExample:\n  $ hurl --max-time 30s test.hurl\n\nEnvironment variables: HURL_MAX_TIME")
         .help_heading("HTTP options")
         .num_args(1)
 }
@@ -361,6 +568,7 @@ pub fn negotiate() -> clap::Arg {
     clap::Arg::new("negotiate")
         .long("negotiate")
         .help("Tell Hurl to use Negotiate (SPNEGO) authentication")
+        .long_help("Tell Hurl to use Negotiate (SPNEGO) authentication.\n\nExample:\n  $ hurl --negotiate --user : test.hurl")
         .help_heading("HTTP options")
         .action(clap::ArgAction::SetTrue)
 }
@@ -370,6 +578,7 @@ pub fn netrc() -> clap::Arg {
         .long("netrc")
         .short('n')
         .help("Must read .netrc for username and password")
+        .long_help("Scan the .netrc file in the user's home directory for the username and password.\n\nSee also --netrc-file and --netrc-optional.\n\nExample:\n  $ hurl --netrc test.hurl")
         .help_heading("Other options")
         .conflicts_with("netrc_file")
         .conflicts_with("netrc_optional")
@@ -381,6 +590,7 @@ pub fn netrc_file() -> clap::Arg {
         .long("netrc-file")
         .value_name("FILE")
         .help("Specify FILE for .netrc")
+        .long_help("Like --netrc, but provide the path to the netrc file.\n\nSee also --netrc-optional.\n\nExample:\n  $ hurl --netrc-file ~/.netrc-hurl test.hurl")
         .help_heading("Other options")
         .conflicts_with("netrc")
         .num_args(1)
@@ -390,6 +600,7 @@ pub fn netrc_optional() -> clap::Arg {
     clap::Arg::new("netrc_optional")
         .long("netrc-optional")
         .help("Use either .netrc or the URL")
+        .long_help("Similar to --netrc, but make the .netrc usage optional.\n\nSee also --netrc-file.\n\nExample:\n  $ hurl --netrc-optional test.hurl")
         .help_heading("Other options")
         .conflicts_with("netrc")
         .action(clap::ArgAction::SetTrue)
@@ -399,6 +610,7 @@ pub fn no_assert() -> clap::Arg {
     clap::Arg::new("no_assert")
         .long("no-assert")
         .help("Ignore asserts defined in the Hurl file")
+        .long_help("Ignore all asserts defined in the Hurl file.\n\nExample:\n  $ hurl --no-assert test.hurl\n\nEnvironment variables: HURL_NO_ASSERT")
         .help_heading("Run options")
         .action(clap::ArgAction::SetTrue)
 }
@@ -407,6 +619,7 @@ pub fn no_color() -> clap::Arg {
     clap::Arg::new("no_color")
         .long("no-color")
         .help("Do not colorize output")
+        .long_help("Do not colorize standard output nor standard error.\n\nExample:\n  $ hurl --no-color test.hurl\n\nEnvironment variables: HURL_NO_COLOR NO_COLOR")
         .help_heading("Output options")
         .conflicts_with("color")
         .action(clap::ArgAction::SetTrue)
@@ -416,6 +629,7 @@ pub fn no_cookie_store() -> clap::Arg {
     clap::Arg::new("no_cookie_store")
         .long("no-cookie-store")
         .help("Do not use cookie store between requests")
+        .long_help("Do not use cookie storage for requests/responses in a file. By default, requests in the same Hurl file share cookie storage, this option deactivates cookie engine.\n\nExample:\n  $ hurl --no-cookie-store test.hurl\n\nEnvironment variables: HURL_NO_COOKIE_STORE")
         .help_heading("HTTP options")
         .conflicts_with("cookies_input_file")
         .action(clap::ArgAction::SetTrue)
@@ -426,6 +640,7 @@ pub fn no_header() -> clap::Arg {
         .long("no-header")
         .value_name("NAME")
         .help("Remove header(s) sent to server")
+        .long_help("Remove a header from information sent. Can be used several times in a command.\n\nExample:\n  $ hurl --no-header User-Agent test.hurl\n\nEnvironment variables: HURL_NO_HEADER='name1|name2' (names are separated by |)")
         .help_heading("HTTP options")
         .num_args(1)
         .action(clap::ArgAction::Append)
@@ -435,6 +650,7 @@ pub fn no_jsonpath_coercion() -> clap::Arg {
     clap::Arg::new("no_jsonpath_coercion")
         .long("no-jsonpath-coercion")
         .help("Disable JSONPath result coercion")
+        .long_help("Disable JSONPath result coercion.\n\nBy default, when JSONPath coercion is enabled, empty JSONPath results are returned as no value and single JSONPath results are returned as a scalar value. With this option, JSONPath results are always returned as arrays.\n\nExample:\n  $ hurl --no-jsonpath-coercion test.hurl\n\nEnvironment variables: HURL_NO_JSONPATH_COERCION")
         .help_heading("Run options")
         .action(clap::ArgAction::SetTrue)
 }
@@ -443,6 +659,7 @@ pub fn no_output() -> clap::Arg {
     clap::Arg::new("no_output")
         .long("no-output")
         .help("Suppress output. By default, Hurl outputs the body of the last response")
+        .long_help("Suppress output. By default, Hurl outputs the body of the last response.\n\nExample:\n  $ hurl --no-output test.hurl\n\nEnvironment variables: HURL_NO_OUTPUT")
         .help_heading("Output options")
         .conflicts_with("json")
         .action(clap::ArgAction::SetTrue)
@@ -452,6 +669,7 @@ pub fn no_pretty() -> clap::Arg {
     clap::Arg::new("no_pretty")
         .long("no-pretty")
         .help("Do not prettify response output")
+        .long_help("Do not prettify response output for supported content type (JSON only for the moment). By default, output is prettified if standard output is a terminal.\n\nExample:\n  $ hurl --no-pretty test.hurl\n\nEnvironment variables: HURL_NO_PRETTY")
         .help_heading("Output options")
         .conflicts_with("pretty")
         .action(clap::ArgAction::SetTrue)
@@ -463,6 +681,7 @@ pub fn no_proxy() -> clap::Arg {
         .alias("noproxy")
         .value_name("HOST(S)")
         .help("List of hosts which do not use proxy")
+        .long_help("Comma-separated list of hosts which do not use a proxy.\n\nExample:\n  $ hurl --proxy my.proxy:8012 --no-proxy localhost,example.org test.hurl\n\nEnvironment variables: no_proxy")
         .help_heading("HTTP options")
         .num_args(1)
 }
@@ -471,6 +690,7 @@ pub fn ntlm() -> clap::Arg {
     clap::Arg::new("ntlm")
         .long("ntlm")
         .help("Tell Hurl to use NTLM authentication")
+        .long_help("Tell Hurl to use NTLM authentication\n\nExample:\n  $ hurl --ntlm --user bob:secret test.hurl")
         .help_heading("HTTP options")
         .action(clap::ArgAction::SetTrue)
 }
@@ -481,6 +701,7 @@ pub fn output() -> clap::Arg {
         .short('o')
         .value_name("FILE")
         .help("Write to FILE instead of stdout")
+        .long_help("Write output to FILE instead of stdout. Use '-' for stdout in [Options] sections.\n\nExample:\n  $ hurl --output response.json test.hurl")
         .help_heading("Output options")
         .num_args(1)
 }
@@ -489,6 +710,7 @@ pub fn parallel() -> clap::Arg {
     clap::Arg::new("parallel")
         .long("parallel")
         .help("Run files in parallel (default in test mode)")
+        .long_help("Run files in parallel.\n\nEach Hurl file is executed in its own worker thread, without sharing anything with the other workers. The default run mode is sequential. Parallel execution is by default in --test mode.\n\nSee also --jobs.\n\nExample:\n  $ hurl --parallel *.hurl")
         .help_heading("Run options")
         .action(clap::ArgAction::SetTrue)
 }
@@ -497,6 +719,7 @@ pub fn path_as_is() -> clap::Arg {
     clap::Arg::new("path_as_is")
         .long("path-as-is")
         .help("Tell Hurl to not handle sequences of /../ or /./ in the given URL path")
+        .long_help("Tell Hurl to not handle sequences of /../ or /./ in the given URL path. Normally Hurl will squash or merge them according to standards but with this option set you tell it not to do that.\n\nExample:\n  $ hurl --path-as-is test.hurl")
         .help_heading("HTTP options")
         .action(clap::ArgAction::SetTrue)
 }
@@ -506,6 +729,7 @@ pub fn pinned_pub_key() -> clap::Arg {
         .long("pinnedpubkey")
         .value_name("HASHES")
         .help("Public key to verify peer against")
+        .long_help("When negotiating a TLS or SSL connection, the server sends a certificate indicating its identity. A public key is extracted from this certificate and if it does not exactly match the public key provided to this option, Hurl aborts the connection before sending or receiving any data.\n\nExample:\n  $ hurl --pinnedpubkey sha256//YhKJKSzoTt2b5FP18fvpHo7fJYqQCjAa3HWY3tvRMwE= test.hurl")
         .help_heading("HTTP options")
         .num_args(1)
 }
@@ -514,6 +738,7 @@ pub fn pretty() -> clap::Arg {
     clap::Arg::new("pretty")
         .long("pretty")
         .help("Prettify JSON response output")
+        .long_help("Prettify response output for supported content type (JSON only for the moment). By default, JSON response is prettified if standard output is a terminal, and colorized, see--no-color to format without color.\n\nExample:\n  $ hurl --pretty test.hurl\n\nEnvironment variables: HURL_PRETTY")
         .help_heading("Output options")
         .action(clap::ArgAction::SetTrue)
 }
@@ -522,6 +747,7 @@ pub fn progress_bar() -> clap::Arg {
     clap::Arg::new("progress_bar")
         .long("progress-bar")
         .help("Display a progress bar in test mode")
+        .long_help("Display a progress bar in test mode. The progress bar is displayed only in interactive TTYs. This option forces the progress bar to be displayed even in non-interactive TTYs.\n\nExample:\n  $ hurl --test --progress-bar *.hurl")
         .help_heading("Output options")
         .action(clap::ArgAction::SetTrue)
 }
@@ -532,6 +758,7 @@ pub fn proxy() -> clap::Arg {
         .short('x')
         .value_name("[PROTOCOL://]HOST[:PORT]")
         .help("Use proxy on given PROTOCOL/HOST/PORT")
+        .long_help("Use the specified proxy.\n\nExample:\n  $ hurl --proxy my.proxy:8012 test.hurl\n\nEnvironment variables: http_proxy https_proxy all_proxy")
         .help_heading("HTTP options")
         .num_args(1)
 }
@@ -541,27 +768,69 @@ pub fn proxy_header() -> clap::Arg {
         .long("proxy-header")
         .value_name("HEADER")
         .help("Extra header to include in the request when sending to a proxy")
+        .long_help("Extra header to include in the request when sending to a proxy. These headers are only applied when a proxy is involved; if Hurl connects directly to the target server, they are ignored.\n\nExample:\n  $ hurl --proxy my.proxy:8012 --proxy-header 'Proxy-Authorization: Basic Ym9iOnNlY3JldA==' test.hurl")
         .help_heading("HTTP options")
         .num_args(1)
         .action(clap::ArgAction::Append)
 }
 
+pub fn proxy_user() -> clap::Arg {
+    clap::Arg::new("proxy_user")
+        .long("proxy-user")
+        .value_name("USER:PASSWORD")
+        .help("Specify the user name and password to use for proxy authentication")
+        .long_help("Specify the user name and password to use for proxy authentication. The credentials are only sent to the proxy, not to the target server.\n\nExample:\n  $ hurl --proxy my.proxy:8012 --proxy-user bob:secret test.hurl")
+        .help_heading("HTTP options")
+        .num_args(1)
+}
+
 pub fn repeat() -> clap::Arg {
     clap::Arg::new("repeat")
         .long("repeat")
         .value_name("NUM")
         .value_parser(clap::value_parser!(i32))
         .help("Repeat the input files sequence NUM times, -1 for infinite loop")
+        .long_help("Repeat the input files sequence NUM times, -1 for infinite loop. Given a.hurl, b.hurl, c.hurl as input, repeat two times will run a.hurl, b.hurl, c.hurl, a.hurl, b.hurl, c.hurl.\n\nExample:\n  $ hurl --repeat 10 test.hurl")
         .help_heading("Run options")
         .num_args(1)
         .allow_negative_numbers(true)
 }
 
+pub fn replay_dir() -> clap::Arg {
+    clap::Arg::new("replay_dir")
+        .long("replay-dir")
+        .value_name("DIR")
+        .help("Write Hurl files reproducing the requests of failed entries to DIR")
+        .long_help("Write a standalone Hurl file reproducing the request of each failed entry to DIR.\n\nThe request is written exactly as it has been sent, and saved to DIR/<FILE>.entry-<INDEX>.hurl, where <FILE> is the path of the Hurl file with path separators replaced by _. Variables are resolved, headers added by Hurl (user agent, cookies etc...) are written explicitly and options having an impact on the transfer (location, insecure, resolve etc...) are kept. Secret values are replaced by a placeholder of the secret name, so the request can be replayed with hurl --secret NAME=VALUE DIR/<FILE>.entry-<INDEX>.hurl.\n\nExample:\n  $ hurl --test --replay-dir build/replay *.hurl")
+        .help_heading("Output options")
+        .num_args(1)
+}
+
+pub fn report_allure() -> clap::Arg {
+    clap::Arg::new("report_allure")
+        .long("report-allure")
+        .value_name("DIR")
+        .help("Generate Allure results to DIR")
+        .long_help("Generate Allure results in DIR, to be rendered with the Allure tools. Each Hurl file is a test result whose steps are the entries of the file; requests and responses are attached to each step.\n\nIf the DIR already contains Allure results, the new test results are added to it.\n\nExample:\n  $ hurl --test --report-allure build/allure-results *.hurl")
+        .help_heading("Report options")
+        .num_args(1)
+}
+
+pub fn report_deterministic() -> clap::Arg {
+    clap::Arg::new("report_deterministic")
+        .long("report-deterministic")
+        .help("Write reproducible reports, without timestamps and durations")
+        .long_help("Write reproducible reports, that can be compared with golden files. In the HTML, JSON, JUnit and Allure reports, timestamps and durations are zeroed, generated identifiers are derived from the reported content, and the HTML report rows are sorted by file name.\n\nExample:\n  $ hurl --test --report-junit build/report.xml --report-deterministic *.hurl")
+        .help_heading("Report options")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn report_html() -> clap::Arg {
     clap::Arg::new("report_html")
         .long("report-html")
         .value_name("DIR")
         .help("Generate HTML report to DIR")
+        .long_help("Generate HTML report in DIR.\n\nIf the HTML report already exists, it will be updated with the new test results.\n\nExample:\n  $ hurl --test --report-html build/report *.hurl")
         .help_heading("Report options")
         .num_args(1)
 }
@@ -571,6 +840,7 @@ pub fn report_json() -> clap::Arg {
         .long("report-json")
         .value_name("DIR")
         .help("Generate JSON report to DIR")
+        .long_help("Generate JSON report in DIR.\n\nIf the JSON report already exists, it will be updated with the new test results.\n\nExample:\n  $ hurl --test --report-json build/report *.hurl")
         .help_heading("Report options")
         .num_args(1)
 }
@@ -580,6 +850,17 @@ pub fn report_junit() -> clap::Arg {
         .long("report-junit")
         .value_name("FILE")
         .help("Write a JUnit XML report to FILE")
+        .long_help("Generate JUnit File.\n\nIf the FILE report already exists, it will be updated with the new test results.\n\nExample:\n  $ hurl --test --report-junit build/report.xml *.hurl")
+        .help_heading("Report options")
+        .num_args(1)
+}
+
+pub fn report_pretty() -> clap::Arg {
+    clap::Arg::new("report_pretty")
+        .long("report-pretty")
+        .value_name("FILE")
+        .help("Write a human-readable report to FILE")
+        .long_help("Generate a human-readable report, in a Cucumber/Gherkin style. Each Hurl file is a feature, with one scenario per entry: the request is followed by the asserts results (✓ or ✗).\n\nIf the FILE report already exists, the new test results are appended to it.\n\nExample:\n  $ hurl --test --report-pretty build/report.txt *.hurl")
         .help_heading("Report options")
         .num_args(1)
 }
@@ -589,6 +870,7 @@ pub fn report_tap() -> clap::Arg {
         .long("report-tap")
         .value_name("FILE")
         .help("Write a TAP report to FILE")
+        .long_help("Generate TAP report (version 14). Each Hurl file is a subtest, with one test point per entry.\n\nIf the FILE report already exists, it will be updated with the new test results.\n\nExample:\n  $ hurl --test --report-tap build/report.txt *.hurl")
         .help_heading("Report options")
         .num_args(1)
 }
@@ -598,6 +880,7 @@ pub fn resolve() -> clap::Arg {
         .long("resolve")
         .value_name("HOST:PORT:ADDR")
         .help("Provide a custom address for a specific HOST and PORT pair")
+        .long_help("Provide a custom address for a specific host and port pair. Using this, you can make the Hurl requests(s) use a specified address and prevent the otherwise normally resolved address to be used. Consider it a sort of /etc/hosts alternative provided on the command line.\n\nHOST can be a wildcard: *.example.com matches any subdomain of example.com and * matches any host, so a whole environment can be redirected to a single address. A mapping of an explicit host takes precedence over a wildcard mapping. This option can be used several times in a command line.\n\nExample:\n  $ hurl --resolve '*.staging.example.com:443:127.0.0.1' test.hurl")
         .help_heading("HTTP options")
         .num_args(1)
         .action(clap::ArgAction::Append)
@@ -609,6 +892,7 @@ pub fn retry() -> clap::Arg {
         .value_name("NUM")
         .value_parser(clap::value_parser!(i32))
         .help("Maximum number of retries, 0 for no retries, -1 for unlimited retries")
+        .long_help("Maximum number of retries, 0 for no retries, -1 for unlimited retries. Retry happens if any error occurs (asserts, captures, runtimes etc...).\n\nExample:\n  $ hurl --retry 5 test.hurl\n\nEnvironment variables: HURL_RETRY")
         .help_heading("Run options")
         .num_args(1)
         .allow_negative_numbers(true)
@@ -619,15 +903,35 @@ pub fn retry_interval() -> clap::Arg {
         .long("retry-interval")
         .value_name("MILLISECONDS")
         .help("Interval in milliseconds before a retry [default: 1000]")
+        .long_help("Duration in milliseconds between each retry. Default is 1000 ms.\n\nYou can specify time units in the retry interval expression. Set Hurl to use a retry interval of 2 seconds with --retry-interval 2s or set it to 500 milliseconds with --retry-interval 500ms. No spaces allowed.\n\n[default: 1000]\n\nExample:\n  $ hurl --retry 5 --retry-interval 2s test.hurl\n\nEnvironment variables: HURL_RETRY_INTERVAL")
         .help_heading("Run options")
         .num_args(1)
 }
 
+pub fn retry_on_capture_failure() -> clap::Arg {
+    clap::Arg::new("retry_on_capture_failure")
+        .long("retry-on-capture-failure")
+        .help("Retry entries when a capture is null or has no value")
+        .long_help("Consider a capture with a null value, or an optional capture without value, as failed. Captures are evaluated again on each retry, so combined with --retry, an entry is retried until its captures succeed: a field can be polled until it appears, before being captured.\n\nExample:\n  $ hurl --retry 10 --retry-on-capture-failure test.hurl")
+        .help_heading("Run options")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn sandbox() -> clap::Arg {
+    clap::Arg::new("sandbox")
+        .long("sandbox")
+        .help("Restrict the access of Hurl files to the file system")
+        .long_help("Run untrusted Hurl files in a sandbox. In sandbox mode, Hurl files can not write files (with output option), files can only be read under the file root (see --file-root), and missing fixtures are not downloaded. The .netrc file, Unix sockets, and proxies or OAuth 2.0 token endpoints with a scheme other than HTTP or HTTPS can't be used in [Options] sections.\n\nOptions of the command line are trusted and are not restricted.\n\nExample:\n  $ hurl --sandbox --file-root contrib --test contrib")
+        .help_heading("Run options")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn secret() -> clap::Arg {
     clap::Arg::new("secret")
         .long("secret")
         .value_name("NAME=VALUE")
         .help("Define a variable which value is secret")
+        .long_help("Define secret value to be redacted from logs and report. When defined, secrets can be used as variable everywhere variables are used.\n\nExample:\n  $ hurl --secret token=$API_TOKEN test.hurl\n\nEnvironment variables: HURL_SECRET_name")
         .help_heading("Run options")
         .num_args(1)
         .action(clap::ArgAction::Append)
@@ -638,15 +942,65 @@ pub fn secrets_file() -> clap::Arg {
         .long("secrets-file")
         .value_name("FILE")
         .help("Define a secrets file in which you define your secrets")
+        .long_help("Define a secrets file in which you define your secrets\n\nEach secret is defined as name=value exactly as with --secret option.\n\nNote that defining a secret twice produces an error.\n\nExample:\n  $ hurl --secrets-file secrets.env test.hurl")
         .help_heading("Run options")
         .num_args(1)
         .action(clap::ArgAction::Append)
 }
 
+pub fn snapshot_dir() -> clap::Arg {
+    clap::Arg::new("snapshot_dir")
+        .long("snapshot-dir")
+        .value_name("DIR")
+        .help("Record and compare response bodies to snapshots in DIR")
+        .long_help("Record the last response body of each entry to a snapshot file in DIR, and compare the response bodies of the next runs to these snapshots.\n\nSnapshots are saved to DIR/<FILE>.entry-<INDEX>.snap, where <FILE> is the path of the Hurl file with path separators replaced by _. On the first run, a snapshot is recorded for each successful entry; on the next runs, an entry fails if its response body differs from its snapshot, and the differing lines are displayed. JSON bodies are pretty-printed with sorted keys before being recorded, so snapshots are stable. Use --snapshot-mask to ignore volatile values and --snapshot-update to record new snapshots.\n\nExample:\n  $ hurl --test --snapshot-dir snapshots *.hurl")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
+pub fn snapshot_mask() -> clap::Arg {
+    clap::Arg::new("snapshot_mask")
+        .long("snapshot-mask")
+        .value_name("REGEX")
+        .help("Replace values matching REGEX in snapshots")
+        .long_help("Replace the values matching REGEX by <masked> in response bodies, before they're recorded and compared to snapshots. Masks can be used to ignore volatile values, like identifiers or dates. Can be used several times in a command.\n\nExample:\n  $ hurl --test --snapshot-dir snapshots --snapshot-mask '\\d{4}-\\d{2}-\\d{2}' *.hurl")
+        .help_heading("Run options")
+        .num_args(1)
+        .action(clap::ArgAction::Append)
+}
+
+pub fn snapshot_update() -> clap::Arg {
+    clap::Arg::new("snapshot_update")
+        .long("snapshot-update")
+        .help("Overwrite snapshots with the current response bodies")
+        .long_help("Record the response bodies of successful entries to their snapshots, overwriting existing snapshots instead of comparing them. Use this option after an intended change of the responses.\n\nExample:\n  $ hurl --test --snapshot-dir snapshots --snapshot-update *.hurl")
+        .help_heading("Run options")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn soft_asserts() -> clap::Arg {
+    clap::Arg::new("soft_asserts")
+        .long("soft-asserts")
+        .help("Report all failed asserts of an entry and keep running")
+        .long_help("Evaluate all the asserts of an entry, even if the status code or a capture fails, and report their failures together. Once a response has been received, failed asserts don't stop the run: the next entries are run, and the run is still considered as failed. Contrary to --continue-on-error, HTTP errors still stop the run.\n\nExample:\n  $ hurl --soft-asserts test.hurl")
+        .help_heading("Run options")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn ssl_no_revoke() -> clap::Arg {
     clap::Arg::new("ssl_no_revoke")
         .long("ssl-no-revoke")
         .help("(Windows) Tell Hurl to disable certificate revocation checks")
+        .long_help("(Windows) This option tells Hurl to disable certificate revocation checks. WARNING: this option loosens the SSL security, and by using this flag you ask for exactly that.\n\nExample:\n  $ hurl --ssl-no-revoke test.hurl")
+        .help_heading("HTTP options")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn nested_form_params() -> clap::Arg {
+    clap::Arg::new("nested_form_params")
+        .long("nested-form-params")
+        .help("Encode repeated form params names as arrays and percent-encode nested keys")
+        .long_help("Encode the names of the form params as arrays and nested keys. By default, the names of the form params are sent as is. With this option, the names are percent-encoded, keeping the brackets of nested keys (like user[address][city]), and repeated names are sent as arrays (tag[]=a&tag[]=b).\n\nExample:\n  $ hurl --nested-form-params test.hurl")
         .help_heading("HTTP options")
         .action(clap::ArgAction::SetTrue)
 }
@@ -655,16 +1009,29 @@ pub fn test() -> clap::Arg {
     clap::Arg::new("test")
         .long("test")
         .help("Activate test mode (use parallel execution)")
+        .long_help("Activate test mode: with this, the HTTP response is not outputted anymore, progress is reported for each Hurl file tested, and a text summary is displayed when all files have been run.\n\nIn test mode, files are executed in parallel. To run test in a sequential way use --jobs 1.\n\nSee also --jobs.\n\nExample:\n  $ hurl --test *.hurl\n\nEnvironment variables: HURL_TEST")
         .help_heading("Run options")
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn throughput() -> clap::Arg {
+    clap::Arg::new("throughput")
+        .long("throughput")
+        .value_name("RPS")
+        .value_parser(clap::value_parser!(u32).range(1..))
+        .help("Run a load test with a target of RPS requests per second")
+        .long_help("Run a load test with a target of RPS requests per second, shared by all the virtual users. Requests are evenly spaced to match the target rate. If the server responses are too slow to keep up with the target, the effective rate is lower: use --concurrency to add more virtual users.\n\nExample:\n  $ hurl --throughput 50 --concurrency 4 --duration 1m test.hurl")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
 pub fn to_entry() -> clap::Arg {
     clap::Arg::new("to_entry")
         .long("to-entry")
         .value_name("ENTRY_NUMBER")
         .value_parser(clap::value_parser!(u32).range(1..))
         .help("Execute Hurl file to ENTRY_NUMBER (starting at 1)")
+        .long_help("Execute Hurl file to ENTRY_NUMBER (starting at 1). Ignore the remaining of the file. It is useful for debugging a session.\n\nExample:\n  $ hurl --to-entry 2 test.hurl")
         .help_heading("Run options")
         .num_args(1)
 }
@@ -674,6 +1041,7 @@ pub fn unix_socket() -> clap::Arg {
         .long("unix-socket")
         .value_name("PATH")
         .help("(HTTP) Connect through this Unix domain socket, instead of using the network")
+        .long_help("(HTTP) Connect through this Unix domain socket, instead of using the network. On Linux, a PATH starting with @ (like @docker) is an abstract socket name.\n\nExample:\n  $ hurl --unix-socket /var/run/docker.sock test.hurl")
         .help_heading("HTTP options")
         .num_args(1)
 }
@@ -684,6 +1052,7 @@ pub fn user() -> clap::Arg {
         .short('u')
         .value_name("USER:PASSWORD")
         .help("Add basic Authentication header to each request")
+        .long_help("Add basic Authentication header to each request.\n\nExample:\n  $ hurl --user bob:secret test.hurl\n\nEnvironment variables: HURL_USER")
         .help_heading("HTTP options")
         .num_args(1)
 }
@@ -694,6 +1063,7 @@ pub fn user_agent() -> clap::Arg {
         .short('A')
         .value_name("NAME")
         .help("Specify the User-Agent string to send to the HTTP server")
+        .long_help("Specify the User-Agent string to send to the HTTP server.\n\nExample:\n  $ hurl --user-agent 'MyBot/1.0' test.hurl\n\nEnvironment variables: HURL_USER_AGENT")
         .help_heading("HTTP options")
         .num_args(1)
 }
@@ -703,6 +1073,7 @@ pub fn variable() -> clap::Arg {
         .long("variable")
         .value_name("NAME=VALUE")
         .help("Define a variable")
+        .long_help("Define variable (name/value) to be used in Hurl templates.\n\nExample:\n  $ hurl --variable host=localhost:8000 --variable id=42 test.hurl\n\nEnvironment variables: HURL_VARIABLE_name")
         .help_heading("Run options")
         .num_args(1)
         .action(clap::ArgAction::Append)
@@ -713,6 +1084,7 @@ pub fn variables_file() -> clap::Arg {
         .long("variables-file")
         .value_name("FILE")
         .help("Define a properties file in which you define your variables")
+        .long_help("Set properties file in which your define your variables.\n\nEach variable is defined as name=value exactly as with --variable option.\n\nA variables file with a .json, .yaml or .yml extension is a JSON object or a YAML mapping, whose members are the variables. Nested objects and arrays are kept as structured values.\n\nNote that defining a variable twice produces an error.\n\nExample:\n  $ hurl --variables-file vars.env test.hurl")
         .help_heading("Run options")
         .num_args(1)
         .action(clap::ArgAction::Append)
@@ -723,24 +1095,57 @@ pub fn verbose() -> clap::Arg {
         .long("verbose")
         .short('v')
         .help("Turn on verbose (alias to --verbosity verbose)")
+        .long_help("Turn on verbose output on standard error stream. Useful for debugging.\n\nA line starting with '>' means data sent by Hurl. A line staring with '<' means data received by Hurl. A line starting with '*' means additional info provided by Hurl.\n\nIf you only want HTTP headers in the output, -i, --include might be the option you're looking for.\n\nExample:\n  $ hurl --verbose test.hurl\n\nEnvironment variables: HURL_VERBOSE")
         .help_heading("Output options")
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn verbose_dir() -> clap::Arg {
+    clap::Arg::new("verbose_dir")
+        .long("verbose-dir")
+        .value_name("DIR")
+        .help("Write verbose logs of failed entries to files in DIR")
+        .long_help("Write the verbose logs of each failed entry to a file in DIR, instead of the standard error.\n\nEach entry is run in verbose mode (or very verbose mode with --very-verbose), and its logs are saved to DIR/<FILE>.entry-<INDEX>.log only if the entry fails, where <FILE> is the path of the Hurl file with path separators replaced by _. Successful entries don't produce any file, and verbose logs are never written to the standard error, so CI outputs stay clean while debug data of failures is preserved. Errors and warnings are still displayed on the standard error.\n\nExample:\n  $ hurl --test --verbose-dir build/logs *.hurl")
+        .help_heading("Output options")
+        .num_args(1)
+}
+
 pub fn verbosity() -> clap::Arg {
     clap::Arg::new("verbosity")
         .long("verbosity")
         .value_name("LEVEL")
         .value_parser(["brief", "verbose", "debug"])
         .help("Set verbosity level for debug log")
+        .long_help("Set the verbosity level for debug logs on standard error stream (brief, verbose or debug)\n\nIf you only want HTTP headers in the output, -i, --include might be the option you're looking for. -v, --verbose is an alias for --verbosity verbose --very-verbose is an alias for --verbosity debug\n\nExample:\n  $ hurl --verbosity debug test.hurl\n\nEnvironment variables: HURL_VERBOSITY")
         .help_heading("Output options")
         .num_args(1)
 }
 
+pub fn wait_for() -> clap::Arg {
+    clap::Arg::new("wait_for")
+        .long("wait-for")
+        .value_name("URL")
+        .help("Wait for URL to be ready before running")
+        .long_help("Wait for URL to respond with a successful status code (2xx) before running the Hurl files. URL is requested every second until it's ready or until the wait timeout is reached (see --wait-timeout), in which case Hurl exits with an error. Useful to wait for a service started with Docker Compose to be healthy.\n\nExample:\n  $ hurl --wait-for http://localhost:8080/health --test tests")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
+pub fn wait_timeout() -> clap::Arg {
+    clap::Arg::new("wait_timeout")
+        .long("wait-timeout")
+        .value_name("SECONDS")
+        .help("Maximum time to wait for --wait-for URL [default: 60]")
+        .long_help("Maximum time in seconds to wait for the URL given with --wait-for to be ready.\n\nYou can specify time units in the wait timeout expression. Set Hurl to wait up to 2 minutes with --wait-timeout 2m or 90 seconds with --wait-timeout 90s. No spaces allowed.\n\n[default: 60]\n\nExample:\n  $ hurl --wait-for http://localhost:8080/health --wait-timeout 2m --test tests")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
 pub fn very_verbose() -> clap::Arg {
     clap::Arg::new("very_verbose")
         .long("very-verbose")
         .help("Turn on very verbose output, including HTTP response and libcurl logs (alias to --verbosity debug)")
+        .long_help("Turn on more verbose output on standard error stream.\n\nIn contrast to  --verbose option, this option outputs the full HTTP body request and response on standard error. In addition, lines starting with '**' are libcurl debug logs.\n\nExample:\n  $ hurl --very-verbose test.hurl\n\nEnvironment variables: HURL_VERY_VERBOSE")
         .help_heading("Output options")
         .action(clap::ArgAction::SetTrue)
 }
@@ -0,0 +1,540 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Generation of shell completion scripts from the Hurl command line definition.
+//!
+//! Contrary to the static completion files of `completions/`, the generated scripts also complete
+//! options values: possible values, files, directories and variables names read from the
+//! variables files of the command line.
+use std::fmt::Write;
+use std::str::FromStr;
+
+use super::CliOptionsError;
+
+/// Shells for which a completion script can be generated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+    Powershell,
+}
+
+impl FromStr for Shell {
+    type Err = CliOptionsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bash" => Ok(Shell::Bash),
+            "zsh" => Ok(Shell::Zsh),
+            "fish" => Ok(Shell::Fish),
+            "powershell" => Ok(Shell::Powershell),
+            _ => {
+                let message = format!("Invalid shell {s}");
+                Err(CliOptionsError::Error(message))
+            }
+        }
+    }
+}
+
+/// How the value of an option is completed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum ValueHint {
+    /// The option is a flag, without value.
+    Flag,
+    /// The value is free, nothing to complete.
+    Any,
+    /// The value is one of these possible values.
+    Values(Vec<String>),
+    /// The value is a file path.
+    File,
+    /// The value is a directory path.
+    Dir,
+    /// The value is a variable, whose names are read from the variables files.
+    Variable,
+}
+
+/// A command line option, as seen by the completion scripts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct CompletionOption {
+    long: String,
+    short: Option<char>,
+    aliases: Vec<String>,
+    help: String,
+    hint: ValueHint,
+    /// Can this option be used several times?
+    multiple: bool,
+}
+
+impl CompletionOption {
+    /// Returns all the names of this option, including the leading dashes.
+    fn names(&self) -> Vec<String> {
+        let mut names = vec![format!("--{}", self.long)];
+        names.extend(self.aliases.iter().map(|a| format!("--{a}")));
+        if let Some(short) = self.short {
+            names.push(format!("-{short}"));
+        }
+        names
+    }
+}
+
+/// Name of the option used to define variables files.
+const VARIABLES_FILE: &str = "--variables-file";
+
+/// Regex extracting the variable names of a variables file, in sed basic syntax.
+const VARIABLE_SED: &str = r"s/^[[:space:]]*\([^#=[:space:]][^=]*\)=.*/\1=/p";
+
+/// Returns the completion script of `command` for this `shell`.
+pub fn generate(command: &clap::Command, shell: Shell) -> String {
+    let name = command.get_name();
+    let options = get_options(command);
+    match shell {
+        Shell::Bash => generate_bash(name, &options),
+        Shell::Zsh => generate_zsh(name, &options),
+        Shell::Fish => generate_fish(name, &options),
+        Shell::Powershell => generate_powershell(name, &options),
+    }
+}
+
+/// Returns the options of `command` sorted by long name, followed by help and version.
+fn get_options(command: &clap::Command) -> Vec<CompletionOption> {
+    // Building the command adds the implicit help and version options.
+    let mut command = command.clone();
+    command.build();
+    let mut options = command
+        .get_arguments()
+        .filter_map(|arg| {
+            let long = arg.get_long()?;
+            Some(CompletionOption {
+                long: long.to_string(),
+                short: arg.get_short(),
+                aliases: arg
+                    .get_all_aliases()
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|a| a.to_string())
+                    .collect(),
+                help: arg.get_help().map(|h| h.to_string()).unwrap_or_default(),
+                hint: value_hint(arg),
+                multiple: matches!(arg.get_action(), clap::ArgAction::Append),
+            })
+        })
+        .collect::<Vec<_>>();
+    options.sort_by(|a, b| a.long.cmp(&b.long));
+    options.sort_by_key(|o| matches!(o.long.as_str(), "help" | "version"));
+    options
+}
+
+fn value_hint(arg: &clap::Arg) -> ValueHint {
+    if !arg.get_action().takes_values() {
+        return ValueHint::Flag;
+    }
+    let values = arg.get_possible_values();
+    if !values.is_empty() {
+        let values = values.iter().map(|v| v.get_name().to_string()).collect();
+        return ValueHint::Values(values);
+    }
+    if arg.get_id() == "variable" {
+        return ValueHint::Variable;
+    }
+    let value_name = arg
+        .get_value_names()
+        .and_then(|names| names.first())
+        .map(|name| name.to_string())
+        .unwrap_or_default();
+    match value_name.as_str() {
+        "FILE" | "PATH" | "KEY" | "CERTIFICATE[:PASSWORD]" => ValueHint::File,
+        "DIR" => ValueHint::Dir,
+        _ => ValueHint::Any,
+    }
+}
+
+fn generate_bash(name: &str, options: &[CompletionOption]) -> String {
+    let mut cases = String::new();
+    for option in options {
+        let action = match &option.hint {
+            ValueHint::Flag => continue,
+            ValueHint::Any => "return".to_string(),
+            ValueHint::Values(values) => {
+                format!(
+                    "COMPREPLY=($(compgen -W '{}' -- \"$cur\"))\n            return",
+                    values.join(" ")
+                )
+            }
+            ValueHint::File => {
+                "COMPREPLY=($(compgen -f -- \"$cur\"))\n            return".to_string()
+            }
+            ValueHint::Dir => {
+                "COMPREPLY=($(compgen -d -- \"$cur\"))\n            return".to_string()
+            }
+            ValueHint::Variable => format!(
+                "compopt -o nospace 2>/dev/null || true\n            COMPREPLY=($(compgen -W \"$(_{name}_variables)\" -- \"$cur\"))\n            return"
+            ),
+        };
+        _ = write!(
+            cases,
+            "        {})\n            {action}\n            ;;\n",
+            option.names().join("|")
+        );
+    }
+    let all_options = options
+        .iter()
+        .flat_map(|o| {
+            let mut names = vec![format!("--{}", o.long)];
+            names.extend(o.aliases.iter().map(|a| format!("--{a}")));
+            names
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    format!(
+        r#"# {name}(1) completion                            -*- shell-script -*-
+_{name}_variables()
+{{
+    local i
+    for ((i = 1; i < ${{#COMP_WORDS[@]}} - 1; i++)); do
+        if [[ ${{COMP_WORDS[i]}} == {VARIABLES_FILE} ]]; then
+            sed -n '{VARIABLE_SED}' "${{COMP_WORDS[i+1]}}" 2>/dev/null
+        fi
+    done
+}}
+
+_{name}()
+{{
+    cur="${{COMP_WORDS[COMP_CWORD]}}"
+    prev="${{COMP_WORDS[COMP_CWORD-1]}}"
+
+    case "$prev" in
+{cases}    esac
+
+    if [[ $cur == -* ]]; then
+        COMPREPLY=($(compgen -W '{all_options}' -- "$cur"))
+        return
+    fi
+    # Generate filenames by default
+    COMPREPLY=($(compgen -f "$cur" | sort))
+}} &&
+    complete -F _{name} {name}
+# ex: filetype=sh
+"#
+    )
+}
+
+fn generate_zsh(name: &str, options: &[CompletionOption]) -> String {
+    let mut specs = String::new();
+    for option in options {
+        let help = option
+            .help
+            .replace('\'', "'\\''")
+            .replace('[', "\\[")
+            .replace(']', "\\]");
+        let action = match &option.hint {
+            ValueHint::Flag => String::new(),
+            ValueHint::Any => ": :".to_string(),
+            ValueHint::Values(values) => format!(": :({})", values.join(" ")),
+            ValueHint::File => ": :_files".to_string(),
+            ValueHint::Dir => ": :_files -/".to_string(),
+            ValueHint::Variable => format!(": :_{name}_variables"),
+        };
+        let names = option.names();
+        let cardinality = if option.multiple { "*" } else { "" };
+        let spec = if names.len() > 1 {
+            format!(
+                "'({}){cardinality}'{{{}}}'[{help}]{action}'",
+                names.join(" "),
+                names.join(",")
+            )
+        } else {
+            format!("'{cardinality}{}[{help}]{action}'", names[0])
+        };
+        _ = writeln!(specs, "        {spec} \\");
+    }
+
+    format!(
+        r#"#compdef {name}
+
+autoload -U is-at-least
+
+_{name}_variables() {{
+    local i file
+    local -a variables
+    for (( i = 1; i < CURRENT - 1; i++ )); do
+        if [[ ${{words[i]}} == {VARIABLES_FILE} ]]; then
+            file=${{~words[i+1]}}
+            [[ -r $file ]] && variables+=(${{(f)"$(sed -n '{VARIABLE_SED}' $file)"}})
+        fi
+    done
+    compadd -S '' -- $variables
+}}
+
+_{name}() {{
+    typeset -A opt_args
+    typeset -a _arguments_options
+    local ret=1
+
+    if is-at-least 5.2; then
+        _arguments_options=(-s -S -C)
+    else
+        _arguments_options=(-s -C)
+    fi
+
+    local context curcontext="$curcontext" state line
+    _arguments "${{_arguments_options[@]}}" \
+{specs}        '*:file:_files' \
+        && ret=0
+}}
+
+if [ "$funcstack[1]" = "_{name}" ]; then
+    _{name} "$@"
+else
+    compdef _{name} {name}
+fi
+"#
+    )
+}
+
+fn generate_fish(name: &str, options: &[CompletionOption]) -> String {
+    let mut script = format!(
+        r#"function __{name}_variables
+    set -l tokens (commandline -opc)
+    for i in (seq (count $tokens))
+        if test "$tokens[$i]" = {VARIABLES_FILE}
+            set -l file $tokens[(math $i + 1)]
+            test -r "$file"; and string replace -rf '^\s*([^#=\s][^=]*)=.*' '$1=' <$file
+        end
+    end
+end
+
+"#
+    );
+    for option in options {
+        let mut line = format!("complete -c {name} -l {}", option.long);
+        if let Some(short) = option.short {
+            _ = write!(line, " -s {short}");
+        }
+        match &option.hint {
+            ValueHint::Flag => {}
+            ValueHint::Any => line.push_str(" -r -f"),
+            ValueHint::Values(values) => {
+                _ = write!(line, " -r -f -a '{}'", values.join(" "));
+            }
+            ValueHint::File => line.push_str(" -r -F"),
+            ValueHint::Dir => line.push_str(" -r -f -a '(__fish_complete_directories)'"),
+            ValueHint::Variable => {
+                _ = write!(line, " -r -f -a '(__{name}_variables)'");
+            }
+        }
+        _ = writeln!(line, " -d '{}'", option.help.replace('\'', "\\'"));
+        script.push_str(&line);
+        for alias in &option.aliases {
+            let alias_line = line.replacen(
+                &format!(" -l {} ", option.long),
+                &format!(" -l {alias} "),
+                1,
+            );
+            script.push_str(&alias_line);
+        }
+    }
+    script
+}
+
+fn generate_powershell(name: &str, options: &[CompletionOption]) -> String {
+    let mut cases = String::new();
+    for option in options {
+        let action = match &option.hint {
+            ValueHint::Flag => continue,
+            // Returning no completion falls back to the default path completion.
+            ValueHint::Any | ValueHint::File | ValueHint::Dir => "return".to_string(),
+            ValueHint::Values(values) => values
+                .iter()
+                .map(|v| format!("'{v}'"))
+                .collect::<Vec<_>>()
+                .join(", "),
+            ValueHint::Variable => format!(
+                r#"for ($i = 1; $i -lt $elements.Count - 1; $i++) {{
+                    if ($elements[$i] -eq '{VARIABLES_FILE}' -and (Test-Path $elements[$i + 1])) {{
+                        Get-Content $elements[$i + 1] |
+                            Where-Object {{ $_ -match '^\s*([^#=\s][^=]*)=' }} |
+                            ForEach-Object {{ "$($Matches[1])=" }}
+                    }}
+                }}"#
+            ),
+        };
+        let names = option
+            .names()
+            .iter()
+            .map(|n| format!("'{n}'"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        _ = writeln!(
+            cases,
+            "            {{ $_ -in {names} }} {{\n                {action}\n            }}"
+        );
+    }
+    let mut completions = String::new();
+    for option in options {
+        let help = option.help.replace('\'', "''");
+        for long in std::iter::once(&option.long).chain(option.aliases.iter()) {
+            _ = writeln!(
+                completions,
+                "        [CompletionResult]::new('--{long}', '{long}', [CompletionResultType]::ParameterName, '{help}')"
+            );
+        }
+    }
+
+    format!(
+        r#"using namespace System.Management.Automation
+using namespace System.Management.Automation.Language
+
+Register-ArgumentCompleter -Native -CommandName '{name}' -ScriptBlock {{
+    param($wordToComplete, $commandAst, $cursorPosition)
+
+    $elements = @($commandAst.CommandElements | ForEach-Object {{ $_.ToString() }})
+    $previous = if ($wordToComplete) {{ $elements[-2] }} else {{ $elements[-1] }}
+
+    $values = @(switch ($previous) {{
+{cases}    }})
+    if ($values.Count -gt 0) {{
+        $values.Where{{ $_ -like "$wordToComplete*" }} | ForEach-Object {{
+            [CompletionResult]::new($_, $_, [CompletionResultType]::ParameterValue, $_)
+        }}
+        return
+    }}
+
+    $completions = @(
+{completions}    )
+    $completions.Where{{ $_.CompletionText -like "$wordToComplete*" }} |
+        Sort-Object -Property ListItemText
+}}
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command() -> clap::Command {
+        clap::Command::new("hurl")
+            .version("1.0.0")
+            .arg(
+                clap::Arg::new("error_format")
+                    .long("error-format")
+                    .value_name("FORMAT")
+                    .value_parser(["short", "long"])
+                    .help("Control the format of error messages")
+                    .num_args(1),
+            )
+            .arg(
+                clap::Arg::new("cookies_input_file")
+                    .long("cookie")
+                    .short('b')
+                    .value_name("FILE")
+                    .num_args(1),
+            )
+            .arg(
+                clap::Arg::new("report_html")
+                    .long("report-html")
+                    .value_name("DIR")
+                    .num_args(1),
+            )
+            .arg(
+                clap::Arg::new("variable")
+                    .long("variable")
+                    .value_name("NAME=VALUE")
+                    .action(clap::ArgAction::Append)
+                    .num_args(1),
+            )
+            .arg(
+                clap::Arg::new("insecure")
+                    .long("insecure")
+                    .short('k')
+                    .action(clap::ArgAction::SetTrue),
+            )
+    }
+
+    #[test]
+    fn test_get_options() {
+        let options = get_options(&command());
+        let hints = options
+            .iter()
+            .map(|o| (o.long.as_str(), o.hint.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            hints,
+            vec![
+                ("cookie", ValueHint::File),
+                (
+                    "error-format",
+                    ValueHint::Values(vec!["short".to_string(), "long".to_string()])
+                ),
+                ("insecure", ValueHint::Flag),
+                ("report-html", ValueHint::Dir),
+                ("variable", ValueHint::Variable),
+                ("help", ValueHint::Flag),
+                ("version", ValueHint::Flag),
+            ]
+        );
+        assert_eq!(options[0].names(), vec!["--cookie", "-b"]);
+    }
+
+    #[test]
+    fn test_generate_bash() {
+        let script = generate(&command(), Shell::Bash);
+        assert!(script.contains(
+            "        --error-format)\n            COMPREPLY=($(compgen -W 'short long' -- \"$cur\"))"
+        ));
+        assert!(script.contains("        --report-html)\n            COMPREPLY=($(compgen -d"));
+        assert!(script.contains("        --cookie|-b)\n            COMPREPLY=($(compgen -f"));
+        assert!(script.contains("$(_hurl_variables)"));
+        assert!(script.contains(
+            "compgen -W '--cookie --error-format --insecure --report-html --variable --help --version'"
+        ));
+        assert!(!script.contains("--insecure|-k)"));
+    }
+
+    #[test]
+    fn test_generate_zsh() {
+        let script = generate(&command(), Shell::Zsh);
+        assert!(
+            script.contains(
+                "'--error-format[Control the format of error messages]: :(short long)' \\"
+            )
+        );
+        assert!(script.contains("'(--cookie -b)'{--cookie,-b}'[]: :_files' \\"));
+        assert!(script.contains("'--report-html[]: :_files -/' \\"));
+        assert!(script.contains("'*--variable[]: :_hurl_variables' \\"));
+    }
+
+    #[test]
+    fn test_generate_fish() {
+        let script = generate(&command(), Shell::Fish);
+        assert!(
+            script.contains("complete -c hurl -l error-format -r -f -a 'short long' -d 'Control the format of error messages'\n")
+        );
+        assert!(script.contains("complete -c hurl -l insecure -s k -d ''\n"));
+        assert!(script.contains("complete -c hurl -l variable -r -f -a '(__hurl_variables)'"));
+    }
+
+    #[test]
+    fn test_generate_powershell() {
+        let script = generate(&command(), Shell::Powershell);
+        assert!(script.contains("{ $_ -in '--error-format' } {\n                'short', 'long'"));
+        assert!(script.contains("{ $_ -in '--cookie', '-b' } {\n                return"));
+        assert!(script.contains("'--variables-file'"));
+    }
+}
@@ -26,6 +26,7 @@ use crate::cli::options::config_file::primitives::{
     expect_no_value, parse_value, parse_value_separator,
 };
 use crate::cli::options::duration;
+use crate::cli::options::variables::{self, TypeKind};
 
 use super::{CliOptions, CliOptionsError, IpResolve, OutputType, Verbosity};
 use hurl_core::types::{BytesPerSec, Count};
@@ -218,6 +219,18 @@ fn parse_option(reader: &mut Reader, options: &mut CliOptions) -> Result<(), Con
             options.user_agent = Some(value);
             Ok(())
         }
+        "variable" => {
+            parse_value_separator(reader)?;
+            save = reader.cursor();
+            let value = parse_value(reader)?;
+            let (name, value) =
+                variables::parse(&value, TypeKind::Inferred).map_err(|e| match e {
+                    CliOptionsError::Error(message) => ConfigFileError::new(save.pos, &message),
+                    e => ConfigFileError::new(save.pos, &e.to_string()),
+                })?;
+            options.variables.insert(name, value);
+            Ok(())
+        }
         "color" => {
             expect_no_value(reader)?;
             options.color_stdout = true;
@@ -242,6 +255,7 @@ mod tests {
 
     use super::*;
     use crate::cli::options::Verbosity;
+    use crate::runner::{Number, Value};
     use hurl_core::reader::Pos;
 
     #[test]
@@ -292,6 +306,28 @@ mod tests {
         assert_eq!(reader.cursor().pos, Pos::new(1, 18));
     }
 
+    #[test]
+    fn test_parse_option_variable() {
+        let mut reader = Reader::new("--variable env=api\n--variable count=2\n");
+        let mut options = CliOptions::default();
+        assert!(parse_option(&mut reader, &mut options).is_ok());
+        assert!(parse_option(&mut reader, &mut options).is_ok());
+        assert_eq!(
+            options.variables.get("env"),
+            Some(&Value::String("api".to_string()))
+        );
+        assert_eq!(
+            options.variables.get("count"),
+            Some(&Value::Number(Number::Integer(2)))
+        );
+
+        let mut reader = Reader::new("--variable env\n");
+        let mut options = CliOptions::default();
+        let err = parse_option(&mut reader, &mut options).unwrap_err();
+        assert_eq!(err.pos, Pos::new(1, 12));
+        assert_eq!(err.message, "Missing value for variable env!");
+    }
+
     #[test]
     fn test_parse_option_with_value_with_quotes() {
         let mut reader = Reader::new("--user-agent=\"Mozilla/5.0 A\"");
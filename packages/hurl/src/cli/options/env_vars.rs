@@ -512,6 +512,7 @@ fn err_from_cli_err(error: CliOptionsError, env: &'static str) -> CliOptionsErro
     match error {
         CliOptionsError::DisplayHelp(_) => error,
         CliOptionsError::DisplayVersion(_) => error,
+        CliOptionsError::DisplayCompletion(_) => error,
         CliOptionsError::NoInput(_) => error,
         CliOptionsError::Error(message) => {
             let message = format!("{message} ({env} environment variable)");
@@ -22,6 +22,7 @@ use std::path::PathBuf;
 pub enum CliOptionsError {
     DisplayHelp(String),
     DisplayVersion(String),
+    DisplayCompletion(String),
     NoInput(String),
     Error(String),
     InvalidInputFile(PathBuf),
@@ -58,6 +59,7 @@ impl fmt::Display for CliOptionsError {
         match self {
             CliOptionsError::DisplayHelp(message) => write!(f, "{message}"),
             CliOptionsError::DisplayVersion(message) => write!(f, "{message}"),
+            CliOptionsError::DisplayCompletion(message) => write!(f, "{message}"),
             CliOptionsError::NoInput(message) => write!(f, "{message}"),
             CliOptionsError::Error(message) => write!(f, "error: {message}"),
             CliOptionsError::InvalidInputFile(path) => write!(
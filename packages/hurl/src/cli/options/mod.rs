@@ -17,16 +17,19 @@
  */
 mod args;
 mod commands;
+mod completion;
 mod config_file;
 mod context;
 mod duration;
 mod env_vars;
 mod error;
+mod project_file;
 mod secret;
 mod variables;
 mod variables_file;
 
 use std::collections::HashMap;
+use std::env;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::time::Duration;
@@ -37,8 +40,10 @@ use hurl::pretty::PrettyMode;
 use hurl::runner::Output;
 use hurl::util::logger;
 use hurl::util::logger::{LoggerOptions, LoggerOptionsBuilder};
+use hurl::util::path;
 use hurl::util::path::ContextDir;
 use hurl_core::input::{Input, InputKind};
+use hurl_core::parser::ParserLimits;
 use hurl_core::types::{BytesPerSec, Count};
 
 use crate::cli::CliError;
@@ -49,13 +54,16 @@ pub use error::CliOptionsError;
 /// Represents the list of all options that can be used in Hurl command line.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CliOptions {
+    pub allure_dir: Option<PathBuf>,
     pub aws_sigv4: Option<String>,
+    pub body_print_limit: usize,
     pub cacert_file: Option<String>,
     pub client_cert_file: Option<String>,
     pub client_key_file: Option<String>,
     pub color_stdout: bool,
     pub color_stderr: bool,
     pub compressed: bool,
+    pub concurrency: Option<usize>,
     pub connect_timeout: Duration,
     pub connects_to: Vec<String>,
     pub continue_on_error: bool,
@@ -64,13 +72,22 @@ pub struct CliOptions {
     pub curl_file: Option<PathBuf>,
     pub delay: Duration,
     pub digest: bool,
+    pub dns_servers: Option<String>,
+    pub duration: Duration,
+    pub env: Option<String>,
     pub error_format: ErrorFormat,
+    pub export_variables_file: Option<PathBuf>,
     pub fail_with_body: bool,
+    pub file_base: FileBase,
     pub file_root: Option<String>,
+    pub file_root_mode: FileRootMode,
+    pub fixtures_dir: Option<String>,
     pub follow_location: bool,
     pub follow_location_trusted: bool,
     pub from_entry: Option<usize>,
+    pub generate_asserts: bool,
     pub headers: Vec<String>,
+    pub hsts_file: Option<String>,
     pub html_dir: Option<PathBuf>,
     pub http_version: Option<HttpVersion>,
     pub no_assert: bool,
@@ -80,9 +97,12 @@ pub struct CliOptions {
     pub insecure: bool,
     pub ip_resolve: Option<IpResolve>,
     pub jobs: Option<usize>,
+    pub json_diff_limit: usize,
     pub json_report_dir: Option<PathBuf>,
     pub junit_file: Option<PathBuf>,
     pub limit_rate: Option<BytesPerSec>,
+    pub max_body_size: usize,
+    pub max_entries: usize,
     pub max_filesize: Option<u64>,
     pub max_redirect: Count,
     pub negotiate: bool,
@@ -99,17 +119,29 @@ pub struct CliOptions {
     pub path_as_is: bool,
     pub pinned_pub_key: Option<String>,
     pub pretty: PrettyMode,
+    pub pretty_file: Option<PathBuf>,
     pub progress_bar: bool,
     pub proxy: Option<String>,
     pub proxy_headers: Vec<String>,
+    pub proxy_user: Option<String>,
     pub repeat: Option<Count>,
+    pub replay_dir: Option<PathBuf>,
+    pub report_deterministic: bool,
     pub resolves: Vec<String>,
     pub retry: Option<Count>,
     pub retry_interval: Duration,
+    pub retry_on_capture_failure: bool,
+    pub sandbox: bool,
     pub secrets: HashMap<String, String>,
+    pub snapshot_dir: Option<PathBuf>,
+    pub snapshot_masks: Vec<String>,
+    pub snapshot_update: bool,
+    pub soft_asserts: bool,
     pub ssl_no_revoke: bool,
+    pub nested_form_params: bool,
     pub tap_file: Option<PathBuf>,
     pub test: bool,
+    pub throughput: Option<u32>,
     pub timeout: Duration,
     pub to_entry: Option<usize>,
     pub unix_socket: Option<String>,
@@ -117,6 +149,9 @@ pub struct CliOptions {
     pub user_agent: Option<String>,
     pub variables: HashMap<String, Value>,
     pub verbosity: Option<Verbosity>,
+    pub verbose_dir: Option<PathBuf>,
+    pub wait_for: Option<String>,
+    pub wait_timeout: Duration,
 }
 
 /// Log verbosity level
@@ -177,6 +212,62 @@ impl From<ErrorFormat> for logger::ErrorFormat {
     }
 }
 
+/// Base directory of relative file paths: the file root or the Hurl file directory.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FileBase {
+    Root,
+    File,
+}
+
+impl FromStr for FileBase {
+    type Err = CliOptionsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "root" => Ok(FileBase::Root),
+            "file" => Ok(FileBase::File),
+            _ => {
+                let message =
+                    format!("Invalid value '{s}' for file-base [possible values: root, file]");
+                Err(CliOptionsError::Error(message))
+            }
+        }
+    }
+}
+
+/// Policy of file accesses outside the file root: strict or permissive.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FileRootMode {
+    Strict,
+    Permissive,
+}
+
+impl FromStr for FileRootMode {
+    type Err = CliOptionsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "strict" => Ok(FileRootMode::Strict),
+            "permissive" => Ok(FileRootMode::Permissive),
+            _ => {
+                let message = format!(
+                    "Invalid value '{s}' for file-root-mode [possible values: strict, permissive]"
+                );
+                Err(CliOptionsError::Error(message))
+            }
+        }
+    }
+}
+
+impl From<FileRootMode> for path::FileRootMode {
+    fn from(value: FileRootMode) -> Self {
+        match value {
+            FileRootMode::Strict => path::FileRootMode::Strict,
+            FileRootMode::Permissive => path::FileRootMode::Permissive,
+        }
+    }
+}
+
 /// Requested HTTP version.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum HttpVersion {
@@ -228,14 +319,107 @@ fn get_version() -> String {
 /// Parse the Hurl CLI options and returns a [`CliOptions`] result, given a run `context`
 /// (environment variables).
 pub fn parse(context: &RunContext) -> Result<CliOptions, CliOptionsError> {
+    let project_files = env::current_dir()
+        .map(|dir| project_file::find_project_files(&dir))
+        .unwrap_or_default();
+    parse_with_project_files(context, &project_files)
+}
+
+/// Parse the Hurl CLI options, given a run `context` and a list of `project_files` ordered from
+/// the lowest to the highest priority.
+///
+/// When an environment is selected with `--env`, options are parsed a second time, with the
+/// profile files of this environment (`hurl.<env>.toml`) added to the project files.
+fn parse_with_project_files(
+    context: &RunContext,
+    project_files: &[PathBuf],
+) -> Result<CliOptions, CliOptionsError> {
+    let options = parse_with_files(context, project_files)?;
+    let Some(env) = &options.env else {
+        return Ok(options);
+    };
+    let profile_files = project_file::with_profile_files(project_files, env);
+    if profile_files.len() == project_files.len() {
+        return Err(CliOptionsError::Error(format!(
+            "Environment {env} has no profile file {}",
+            project_file::profile_file_name(env)
+        )));
+    }
+    parse_with_files(context, &profile_files)
+}
+
+/// Parse the Hurl CLI options, given a run `context` and a list of `project_files`, from the lowest
+/// to the highest priority source.
+fn parse_with_files(
+    context: &RunContext,
+    project_files: &[PathBuf],
+) -> Result<CliOptions, CliOptionsError> {
     let options = CliOptions::default();
     let options = context::init_options(context, options);
     let options = config_file::parse_config_file(context.config_file_path(), options)?;
+    let options = project_file::parse_project_files(project_files, options)?;
     let options = env_vars::parse_env_vars(context, options)?;
     let options = args::parse_cli_args(context, options)?;
     Ok(options)
 }
 
+/// Options of the input files of a run.
+///
+/// The options of an input file take into account the project files (`hurl.toml` and `.hurlrc`)
+/// of the input file directory and its parents, the closest file having the highest priority.
+/// Input files sharing the same project files share the same options.
+///
+/// In sandbox mode, input files are untrusted, and so are the project files of their directories:
+/// only the project files of the current directory and its parents are used.
+pub struct InputOptions<'ctx> {
+    context: &'ctx RunContext,
+    current_dir: PathBuf,
+    /// Options of the run, parsed from the current directory.
+    options: CliOptions,
+    project_files: Vec<PathBuf>,
+    /// Options by list of project files.
+    cache: HashMap<Vec<PathBuf>, CliOptions>,
+}
+
+impl<'ctx> InputOptions<'ctx> {
+    /// Creates a new instance, given the run `context`, the current directory and the run `options`.
+    pub fn new(context: &'ctx RunContext, current_dir: &Path, options: &CliOptions) -> Self {
+        InputOptions {
+            context,
+            current_dir: current_dir.to_path_buf(),
+            options: options.clone(),
+            project_files: project_file::find_project_files(current_dir),
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Returns the options to use for this `input` file.
+    pub fn get(&mut self, input: &Input) -> Result<&CliOptions, CliError> {
+        let InputKind::File(path) = input.kind() else {
+            return Ok(&self.options);
+        };
+        // A project file found beneath the current directory could widen the file root, or read
+        // and write files anywhere: it's ignored in sandbox mode.
+        if self.options.sandbox {
+            return Ok(&self.options);
+        }
+        let path = self.current_dir.join(path);
+        let Some(dir) = path.parent() else {
+            return Ok(&self.options);
+        };
+        let project_files = project_file::find_project_files(dir);
+        if project_files == self.project_files {
+            return Ok(&self.options);
+        }
+        if !self.cache.contains_key(&project_files) {
+            let options = parse_with_project_files(self.context, &project_files)
+                .map_err(|e| CliError::InvalidOption(e.to_string()))?;
+            self.cache.insert(project_files.clone(), options);
+        }
+        Ok(&self.cache[&project_files])
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum OutputType {
     /// The last HTTP response body of a Hurl file is outputted on standard output.
@@ -249,13 +433,16 @@ pub enum OutputType {
 impl Default for CliOptions {
     fn default() -> Self {
         CliOptions {
+            allure_dir: None,
             aws_sigv4: None,
+            body_print_limit: 2048,
             cacert_file: None,
             client_cert_file: None,
             client_key_file: None,
             color_stdout: false,
             color_stderr: false,
             compressed: false,
+            concurrency: None,
             connect_timeout: Duration::from_secs(300),
             connects_to: Vec::new(),
             continue_on_error: false,
@@ -264,13 +451,22 @@ impl Default for CliOptions {
             curl_file: None,
             delay: Duration::from_millis(0),
             digest: false,
+            dns_servers: None,
+            duration: Duration::from_secs(10),
+            env: None,
             error_format: ErrorFormat::Short,
+            export_variables_file: None,
             fail_with_body: false,
+            file_base: FileBase::Root,
             file_root: None,
+            file_root_mode: FileRootMode::Strict,
+            fixtures_dir: None,
             follow_location: false,
             follow_location_trusted: false,
             from_entry: None,
+            generate_asserts: false,
             headers: Vec::new(),
+            hsts_file: None,
             html_dir: None,
             http_version: None,
             no_assert: false,
@@ -280,9 +476,12 @@ impl Default for CliOptions {
             insecure: false,
             ip_resolve: None,
             jobs: None,
+            json_diff_limit: 10,
             json_report_dir: None,
             junit_file: None,
             limit_rate: None,
+            max_body_size: ParserLimits::default().max_body_size,
+            max_entries: ParserLimits::default().max_entries,
             max_filesize: None,
             max_redirect: Count::Finite(50),
             negotiate: false,
@@ -299,17 +498,29 @@ impl Default for CliOptions {
             path_as_is: false,
             pinned_pub_key: None,
             pretty: PrettyMode::None,
+            pretty_file: None,
             progress_bar: false,
             proxy: None,
             proxy_headers: Vec::new(),
+            proxy_user: None,
             repeat: None,
+            replay_dir: None,
+            report_deterministic: false,
             resolves: Vec::new(),
             retry: None,
             retry_interval: Duration::from_millis(1000),
+            retry_on_capture_failure: false,
+            sandbox: false,
             secrets: HashMap::new(),
+            snapshot_dir: None,
+            snapshot_masks: Vec::new(),
+            snapshot_update: false,
+            soft_asserts: false,
             ssl_no_revoke: false,
+            nested_form_params: false,
             tap_file: None,
             test: false,
+            throughput: None,
             timeout: Duration::from_secs(300),
             to_entry: None,
             unix_socket: None,
@@ -317,11 +528,19 @@ impl Default for CliOptions {
             user_agent: None,
             variables: HashMap::new(),
             verbosity: None,
+            verbose_dir: None,
+            wait_for: None,
+            wait_timeout: Duration::from_secs(60),
         }
     }
 }
 
 impl CliOptions {
+    /// Returns `true` if Hurl is run in load test mode (with `--concurrency` or `--throughput`).
+    pub fn is_load_test(&self) -> bool {
+        self.concurrency.is_some() || self.throughput.is_some()
+    }
+
     /// Converts this instance of [`CliOptions`] to an instance of [`RunnerOptions`]
     pub fn to_runner_options(
         &self,
@@ -329,6 +548,7 @@ impl CliOptions {
         current_dir: &Path,
     ) -> Result<RunnerOptions, CliError> {
         let aws_sigv4 = self.aws_sigv4.clone();
+        let body_print_limit = self.body_print_limit;
         let cacert_file = self.cacert_file.clone();
         let client_cert_file = self.client_cert_file.clone();
         let client_key_file = self.client_key_file.clone();
@@ -343,11 +563,24 @@ impl CliOptions {
             },
         };
         let color_stdout = self.color_stdout;
-        let context_dir = ContextDir::new(current_dir, file_root);
+        let mut context_dir = ContextDir::new(current_dir, file_root)
+            .with_file_root_mode(self.file_root_mode.into())
+            .with_sandbox(self.sandbox);
+        // Relative file paths can be resolved from each Hurl file directory, while accesses are
+        // still checked against the file root.
+        if let (FileBase::File, InputKind::File(path)) = (self.file_base, filename.kind())
+            && let Some(base_dir) = path.parent()
+        {
+            context_dir = context_dir.with_base_dir(base_dir);
+        }
+        if let Some(fixtures_dir) = &self.fixtures_dir {
+            context_dir = context_dir.with_fixtures_dir(Path::new(fixtures_dir));
+        }
         let continue_on_error = self.continue_on_error;
         let cookie_input_file = self.cookie_input_file.clone();
         let delay = self.delay;
         let digest = self.digest;
+        let dns_servers = self.dns_servers.clone();
         let fail_with_body = self.fail_with_body;
         let follow_location = self.follow_location;
         let follow_location_trusted = self.follow_location_trusted;
@@ -373,6 +606,7 @@ impl CliOptions {
             }
         }
 
+        let hsts_file = self.hsts_file.clone();
         let http_version = match self.http_version {
             Some(version) => version.into(),
             None => RequestedHttpVersion::default(),
@@ -382,7 +616,9 @@ impl CliOptions {
             Some(ip) => ip.into(),
             None => http::IpResolve::default(),
         };
+        let json_diff_limit = self.json_diff_limit;
         let max_filesize = self.max_filesize;
+        let parser_limits = self.parser_limits();
         // Like curl, we don't differentiate upload and download limit rate, we have
         // only one option.
         let max_recv_speed = self.limit_rate;
@@ -418,10 +654,18 @@ impl CliOptions {
                 }
             }
         }
+        let proxy_user = self.proxy_user.clone();
+        let replay_dir = self.replay_dir.clone();
         let resolves = self.resolves.clone();
         let retry = self.retry;
         let retry_interval = self.retry_interval;
+        let retry_on_capture_failure = self.retry_on_capture_failure;
+        let snapshot_dir = self.snapshot_dir.clone();
+        let snapshot_masks = self.snapshot_masks.clone();
+        let snapshot_update = self.snapshot_update;
+        let soft_asserts = self.soft_asserts;
         let ssl_no_revoke = self.ssl_no_revoke;
+        let nested_form_params = self.nested_form_params;
         let negotiate = self.negotiate;
         let ntlm = self.ntlm;
         let timeout = self.timeout;
@@ -430,14 +674,17 @@ impl CliOptions {
         let use_cookie_store = !self.no_cookie_store;
         let user = self.user.clone();
         let user_agent = self.user_agent.clone();
+        let verbose_dir = self.verbose_dir.clone();
 
         Ok(RunnerOptionsBuilder::new()
             .aws_sigv4(aws_sigv4)
+            .body_print_limit(body_print_limit)
             .cacert_file(cacert_file)
             .client_cert_file(client_cert_file)
             .client_key_file(client_key_file)
             .delay(delay)
             .digest(digest)
+            .dns_servers(dns_servers)
             .color_stdout(color_stdout)
             .compressed(compressed)
             .connect_timeout(connect_timeout)
@@ -449,9 +696,11 @@ impl CliOptions {
             .follow_location(follow_location)
             .from_entry(from_entry)
             .headers(headers)
+            .hsts_file(hsts_file)
             .http_version(http_version)
             .insecure(insecure)
             .ip_resolve(ip_resolve)
+            .json_diff_limit(json_diff_limit)
             .max_filesize(max_filesize)
             .max_recv_speed(max_recv_speed)
             .max_redirect(max_redirect)
@@ -466,24 +715,43 @@ impl CliOptions {
             .no_headers(no_headers)
             .ntlm(ntlm)
             .output(output)
+            .parser_limits(parser_limits)
             .path_as_is(path_as_is)
             .pinned_pub_key(pinned_pub_key)
             .pretty(pretty)
             .proxy(proxy)
             .proxy_headers(proxy_headers)
+            .proxy_user(proxy_user)
+            .replay_dir(replay_dir)
             .resolves(&resolves)
             .retry(retry)
             .retry_interval(retry_interval)
+            .retry_on_capture_failure(retry_on_capture_failure)
+            .snapshot_dir(snapshot_dir)
+            .snapshot_masks(&snapshot_masks)
+            .snapshot_update(snapshot_update)
+            .soft_asserts(soft_asserts)
             .ssl_no_revoke(ssl_no_revoke)
+            .nested_form_params(nested_form_params)
             .timeout(timeout)
             .to_entry(to_entry)
             .unix_socket(unix_socket)
             .use_cookie_store(use_cookie_store)
             .user(user)
             .user_agent(user_agent)
+            .verbose_dir(verbose_dir)
             .build())
     }
 
+    /// Returns the limits of the parser of Hurl files.
+    pub fn parser_limits(&self) -> ParserLimits {
+        ParserLimits {
+            max_entries: self.max_entries,
+            max_body_size: self.max_body_size,
+            ..ParserLimits::default()
+        }
+    }
+
     /// Converts this instance of [`ClipOptions`] to an instance of [`LoggerOptions`]
     pub fn to_logger_options(&self) -> LoggerOptions {
         let verbosity = self.verbosity.map(|v| match v {
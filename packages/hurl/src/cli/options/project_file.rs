@@ -0,0 +1,501 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Project config file `hurl.toml`.
+//!
+//! A project config file provides default options for all the Hurl runs of a project. It is
+//! looked up in the current directory and its parents:
+//!
+//! ```toml
+//! [options]
+//! location = true
+//! max-redirs = 10
+//! delay = "100ms"
+//! header = ["Accept-Language: fr"]
+//! variables-file = ["vars.env"]
+//! report-junit = "build/report.xml"
+//!
+//! [variables]
+//! host = "localhost:8000"
+//! ```
+//!
+//! Relative paths are resolved against the directory of the project file. Options of the project
+//! file can be overridden by environment variables and command line options.
+//!
+//! Project files can be nested: a project file applies to all the Hurl files beneath its
+//! directory, and the closest project file wins. A directory can also use a `.hurlrc` file, with
+//! the syntax of the user config file (one command line option per line).
+//!
+//! Environment profiles are project files named `hurl.<env>.toml`, placed beside a project file.
+//! With `--env <env>`, a profile file is parsed after the project files of its directory, so
+//! environment specific options, variables and secrets files override the base ones.
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use hurl_core::types::{Count, DurationUnit};
+use serde::Deserialize;
+
+use super::config_file;
+use super::variables::TypeKind;
+use super::variables_file::VariablesFile;
+use super::{
+    CliOptions, CliOptionsError, ErrorFormat, FileBase, FileRootMode, OutputType, Verbosity,
+    duration,
+};
+use crate::runner::{Number, Value};
+
+/// File name of a project config file.
+pub const PROJECT_FILE_NAME: &str = "hurl.toml";
+
+/// File name of a project config file using the user config file syntax.
+pub const RC_FILE_NAME: &str = ".hurlrc";
+
+/// Returns the file name of the profile file of the environment `env`.
+pub fn profile_file_name(env: &str) -> String {
+    format!("hurl.{env}.toml")
+}
+
+/// Content of a project config file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProjectFile {
+    #[serde(default)]
+    options: ProjectOptions,
+    #[serde(default)]
+    variables: HashMap<String, toml::Value>,
+}
+
+/// Options of a project config file, named as their command line counterparts.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+struct ProjectOptions {
+    color: Option<bool>,
+    compressed: Option<bool>,
+    connect_timeout: Option<DurationValue>,
+    continue_on_error: Option<bool>,
+    delay: Option<DurationValue>,
+    error_format: Option<String>,
+    fail_with_body: Option<bool>,
+    file_base: Option<String>,
+    file_root: Option<String>,
+    file_root_mode: Option<String>,
+    fixtures_dir: Option<String>,
+    header: Vec<String>,
+    insecure: Option<bool>,
+    jobs: Option<usize>,
+    location: Option<bool>,
+    location_trusted: Option<bool>,
+    max_redirs: Option<i32>,
+    max_time: Option<DurationValue>,
+    no_assert: Option<bool>,
+    no_output: Option<bool>,
+    parallel: Option<bool>,
+    report_html: Option<String>,
+    report_json: Option<String>,
+    report_junit: Option<String>,
+    report_tap: Option<String>,
+    retry: Option<i32>,
+    retry_interval: Option<DurationValue>,
+    secrets_file: Vec<String>,
+    test: Option<bool>,
+    user_agent: Option<String>,
+    variables_file: Vec<String>,
+    verbose: Option<bool>,
+    very_verbose: Option<bool>,
+}
+
+/// A duration, either a number in the option default unit or a string with a unit (`"2s"`).
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum DurationValue {
+    Number(u64),
+    String(String),
+}
+
+/// Returns the paths of the project config files, looked up in `dir` and its parents.
+///
+/// Files are ordered from the farthest to the closest to `dir`, so that options of the closest
+/// files override the others when files are parsed in order. In the same directory, `.hurlrc`
+/// comes after `hurl.toml`.
+pub fn find_project_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = dir
+        .ancestors()
+        .flat_map(|dir| [dir.join(RC_FILE_NAME), dir.join(PROJECT_FILE_NAME)])
+        .filter(|path| path.is_file())
+        .collect::<Vec<_>>();
+    files.reverse();
+    files
+}
+
+/// Returns the project files `project_files` with the profile files of the environment `env`.
+///
+/// The profile file of a directory comes just after the project files of this directory.
+pub fn with_profile_files(project_files: &[PathBuf], env: &str) -> Vec<PathBuf> {
+    let mut files = vec![];
+    for (index, path) in project_files.iter().enumerate() {
+        files.push(path.clone());
+        let dir = path.parent();
+        let next_dir = project_files.get(index + 1).and_then(|p| p.parent());
+        if let Some(dir) = dir
+            && next_dir != Some(dir)
+        {
+            let profile_file = dir.join(profile_file_name(env));
+            if profile_file.is_file() {
+                files.push(profile_file);
+            }
+        }
+    }
+    files
+}
+
+/// Parses options from the project config files `project_file_paths`, in order, using
+/// `default_options` for default values.
+pub fn parse_project_files(
+    project_file_paths: &[PathBuf],
+    default_options: CliOptions,
+) -> Result<CliOptions, CliOptionsError> {
+    let mut options = default_options;
+    for path in project_file_paths {
+        options = if path.ends_with(RC_FILE_NAME) {
+            config_file::parse_config_file(Some(path), options)?
+        } else {
+            parse_project_file(Some(path), options)?
+        };
+    }
+    Ok(options)
+}
+
+/// Parses options from the project config file `project_file_path`, using `default_options` for
+/// default values.
+pub fn parse_project_file(
+    project_file_path: Option<&Path>,
+    default_options: CliOptions,
+) -> Result<CliOptions, CliOptionsError> {
+    let Some(project_file_path) = project_file_path else {
+        return Ok(default_options);
+    };
+    let content = std::fs::read_to_string(project_file_path).map_err(|e| {
+        CliOptionsError::Error(format!(
+            "Failed to read project file {}: {}",
+            project_file_path.display(),
+            e
+        ))
+    })?;
+    let root_dir = project_file_path.parent().unwrap_or(Path::new(""));
+    parse_project(&content, root_dir, default_options).map_err(|message| {
+        CliOptionsError::Error(format!("{}: {}", project_file_path.display(), message))
+    })
+}
+
+/// Parses a project config `content` using `default_options`. Relative paths are resolved
+/// against `root_dir`.
+fn parse_project(
+    content: &str,
+    root_dir: &Path,
+    default_options: CliOptions,
+) -> Result<CliOptions, String> {
+    let project = toml::from_str::<ProjectFile>(content).map_err(|e| e.to_string())?;
+    let path = |s: &str| root_dir.join(s);
+    let opts = project.options;
+    let mut options = default_options;
+
+    if let Some(color) = opts.color {
+        options.color_stdout = color;
+        options.color_stderr = color;
+    }
+    if let Some(compressed) = opts.compressed {
+        options.compressed = compressed;
+    }
+    if let Some(value) = opts.connect_timeout {
+        options.connect_timeout = value.to_duration("connect-timeout", DurationUnit::Second)?;
+    }
+    if let Some(continue_on_error) = opts.continue_on_error {
+        options.continue_on_error = continue_on_error;
+    }
+    if let Some(value) = opts.delay {
+        options.delay = value.to_duration("delay", DurationUnit::MilliSecond)?;
+    }
+    if let Some(error_format) = opts.error_format {
+        options.error_format = error_format
+            .parse::<ErrorFormat>()
+            .map_err(|_| "option error-format must be short or long".to_string())?;
+    }
+    if let Some(fail_with_body) = opts.fail_with_body {
+        options.fail_with_body = fail_with_body;
+    }
+    if let Some(file_base) = opts.file_base {
+        options.file_base = file_base
+            .parse::<FileBase>()
+            .map_err(|_| "option file-base must be root or file".to_string())?;
+    }
+    if let Some(file_root) = opts.file_root {
+        options.file_root = Some(path(&file_root).display().to_string());
+    }
+    if let Some(file_root_mode) = opts.file_root_mode {
+        options.file_root_mode = file_root_mode
+            .parse::<FileRootMode>()
+            .map_err(|_| "option file-root-mode must be strict or permissive".to_string())?;
+    }
+    if let Some(fixtures_dir) = opts.fixtures_dir {
+        options.fixtures_dir = Some(path(&fixtures_dir).display().to_string());
+    }
+    options.headers.extend(opts.header);
+    if let Some(insecure) = opts.insecure {
+        options.insecure = insecure;
+    }
+    if let Some(jobs) = opts.jobs {
+        options.jobs = Some(jobs);
+    }
+    if let Some(location) = opts.location {
+        options.follow_location = location;
+    }
+    if let Some(location_trusted) = opts.location_trusted {
+        options.follow_location = options.follow_location || location_trusted;
+        options.follow_location_trusted = location_trusted;
+    }
+    if let Some(max_redirs) = opts.max_redirs {
+        options.max_redirect = Count::try_from(max_redirs)
+            .map_err(|_| "option max-redirs must be an integer >= -1".to_string())?;
+    }
+    if let Some(value) = opts.max_time {
+        options.timeout = value.to_duration("max-time", DurationUnit::Second)?;
+    }
+    if let Some(no_assert) = opts.no_assert {
+        options.no_assert = no_assert;
+    }
+    if opts.no_output == Some(true) {
+        options.output_type = OutputType::NoOutput;
+    }
+    if let Some(parallel) = opts.parallel {
+        options.parallel = parallel;
+    }
+    if let Some(dir) = opts.report_html {
+        options.html_dir = Some(path(&dir));
+    }
+    if let Some(dir) = opts.report_json {
+        options.json_report_dir = Some(path(&dir));
+    }
+    if let Some(file) = opts.report_junit {
+        options.junit_file = Some(path(&file));
+    }
+    if let Some(file) = opts.report_tap {
+        options.tap_file = Some(path(&file));
+    }
+    if let Some(retry) = opts.retry {
+        let retry = Count::try_from(retry)
+            .map_err(|_| "option retry must be an integer >= -1".to_string())?;
+        options.retry = Some(retry);
+    }
+    if let Some(value) = opts.retry_interval {
+        options.retry_interval = value.to_duration("retry-interval", DurationUnit::MilliSecond)?;
+    }
+    // Secrets of a project file override the ones of the previous project files.
+    for file in &opts.secrets_file {
+        let secrets =
+            VariablesFile::open(&path(file), TypeKind::String).map_err(|e| e.to_string())?;
+        for secret in secrets {
+            let (name, value) = secret.map_err(|e| e.to_string())?;
+            if let Value::String(value) = value {
+                options.secrets.insert(name, value);
+            }
+        }
+    }
+    if let Some(test) = opts.test {
+        options.test = test;
+    }
+    if let Some(user_agent) = opts.user_agent {
+        options.user_agent = Some(user_agent);
+    }
+    if opts.verbose == Some(true) {
+        options.verbosity = Some(Verbosity::Verbose);
+    }
+    if opts.very_verbose == Some(true) {
+        options.verbosity = Some(Verbosity::Debug);
+    }
+
+    for file in &opts.variables_file {
+        let vars =
+            VariablesFile::open(&path(file), TypeKind::Inferred).map_err(|e| e.to_string())?;
+        for var in vars {
+            let (name, value) = var.map_err(|e| e.to_string())?;
+            options.variables.insert(name, value);
+        }
+    }
+    for (name, value) in project.variables {
+        let value = match value {
+            toml::Value::String(s) => Value::String(s),
+            toml::Value::Integer(n) => Value::Number(Number::Integer(n)),
+            toml::Value::Float(f) => Value::Number(Number::Float(f)),
+            toml::Value::Boolean(b) => Value::Bool(b),
+            _ => {
+                return Err(format!(
+                    "variable {name} must be a string, a number or a boolean"
+                ));
+            }
+        };
+        options.variables.insert(name, value);
+    }
+    Ok(options)
+}
+
+impl DurationValue {
+    fn to_duration(
+        &self,
+        name: &str,
+        default_unit: DurationUnit,
+    ) -> Result<std::time::Duration, String> {
+        let value = match self {
+            DurationValue::Number(n) => n.to_string(),
+            DurationValue::String(s) => s.clone(),
+        };
+        duration::duration_from_str(&value, default_unit)
+            .map_err(|_| format!("option {name} has an invalid duration"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_project() {
+        let content = r#"
+# Project defaults
+[options]
+location = true
+max-redirs = 5
+delay = "2s"
+connect-timeout = 10
+header = ["Accept-Language: fr"]
+report-junit = "build/report.xml"
+test = true
+very-verbose = true
+
+[variables]
+host = "localhost:8000"
+id = 42
+"#;
+        let options = parse_project(content, Path::new("project"), CliOptions::default()).unwrap();
+        assert!(options.follow_location);
+        assert_eq!(options.max_redirect, Count::Finite(5));
+        assert_eq!(options.delay, Duration::from_secs(2));
+        assert_eq!(options.connect_timeout, Duration::from_secs(10));
+        assert_eq!(options.headers, vec!["Accept-Language: fr".to_string()]);
+        assert_eq!(
+            options.junit_file,
+            Some(PathBuf::from("project/build/report.xml"))
+        );
+        assert!(options.test);
+        assert_eq!(options.verbosity, Some(Verbosity::Debug));
+        assert_eq!(
+            options.variables.get("host"),
+            Some(&Value::String("localhost:8000".to_string()))
+        );
+        assert_eq!(
+            options.variables.get("id"),
+            Some(&Value::Number(Number::Integer(42)))
+        );
+    }
+
+    #[test]
+    fn test_find_project_files() {
+        let root = std::env::temp_dir().join("hurl_test_find_project_files");
+        let dir = root.join("api").join("users");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(root.join(PROJECT_FILE_NAME), "").unwrap();
+        std::fs::write(root.join("api").join(RC_FILE_NAME), "").unwrap();
+        std::fs::write(root.join("api").join(PROJECT_FILE_NAME), "").unwrap();
+
+        let files = find_project_files(&dir);
+        let files = files
+            .iter()
+            .filter(|f| f.starts_with(&root))
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            files,
+            vec![
+                root.join(PROJECT_FILE_NAME),
+                root.join("api").join(PROJECT_FILE_NAME),
+                root.join("api").join(RC_FILE_NAME),
+            ]
+        );
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_with_profile_files() {
+        let root = std::env::temp_dir().join("hurl_test_with_profile_files");
+        let api = root.join("api");
+        std::fs::create_dir_all(&api).unwrap();
+        std::fs::write(root.join(PROJECT_FILE_NAME), "").unwrap();
+        std::fs::write(root.join("hurl.staging.toml"), "").unwrap();
+        std::fs::write(api.join(PROJECT_FILE_NAME), "").unwrap();
+        std::fs::write(api.join(RC_FILE_NAME), "").unwrap();
+        std::fs::write(api.join("hurl.staging.toml"), "").unwrap();
+        let project_files = vec![
+            root.join(PROJECT_FILE_NAME),
+            api.join(PROJECT_FILE_NAME),
+            api.join(RC_FILE_NAME),
+        ];
+
+        assert_eq!(
+            with_profile_files(&project_files, "staging"),
+            vec![
+                root.join(PROJECT_FILE_NAME),
+                root.join("hurl.staging.toml"),
+                api.join(PROJECT_FILE_NAME),
+                api.join(RC_FILE_NAME),
+                api.join("hurl.staging.toml"),
+            ]
+        );
+        assert_eq!(with_profile_files(&project_files, "prod"), project_files);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_parse_project_error() {
+        let error = parse_project(
+            "[options]\nfoo = true\n",
+            Path::new(""),
+            CliOptions::default(),
+        )
+        .unwrap_err();
+        assert!(error.starts_with("unknown field `foo`"));
+
+        let error = parse_project(
+            "[options]\ndelay = \"abc\"\n",
+            Path::new(""),
+            CliOptions::default(),
+        )
+        .unwrap_err();
+        assert_eq!(error, "option delay has an invalid duration");
+
+        let error = parse_project(
+            "[variables]\nids = [1, 2]\n",
+            Path::new(""),
+            CliOptions::default(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error,
+            "variable ids must be a string, a number or a boolean"
+        );
+    }
+}
@@ -37,6 +37,13 @@ pub fn add_secret(
     let Value::String(value) = value else {
         panic!("Secrets must be string");
     };
+    // An empty secret can't be redacted.
+    if value.is_empty() {
+        return Err(CliOptionsError::Error(format!(
+            "secret '{}' can't be empty",
+            &name
+        )));
+    }
     secrets.insert(name.to_string(), value);
     Ok(())
 }
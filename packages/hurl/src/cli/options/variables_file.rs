@@ -17,11 +17,14 @@
  */
 use crate::cli::options::variables::TypeKind;
 use crate::cli::options::{CliOptionsError, variables};
-use hurl::runner::Value;
+use hurl::runner::{Number, Value};
+use hurl_core::ast::is_variable_reserved;
 use std::fs::File;
 use std::io::{BufRead, BufReader, Lines};
 use std::iter::Enumerate;
 use std::path::{Path, PathBuf};
+use std::{fs, vec};
+use yaml_rust2::{Yaml, YamlLoader};
 
 /// Represents a variables file, in the form of:
 ///
@@ -31,16 +34,34 @@ use std::path::{Path, PathBuf};
 /// var_3=baz
 /// ```
 ///
+/// A variables file with a `.json`, `.yaml` or `.yml` extension is a JSON object or a YAML mapping,
+/// whose members are the variables. Nested objects and arrays are structured values:
+///
+/// ```yaml
+/// host: example.net
+/// user:
+///   name: Bob
+///   roles: [admin, dev]
+/// ```
+///
 /// [`VariablesFile`] is an iterator that returns a tuple ([`String`], [`Value`]) on each iteration.
 pub struct VariablesFile {
-    /// Iterator on this variables file lines.
-    lines: Enumerate<Lines<BufReader<File>>>,
+    /// Variables of this file.
+    variables: Variables,
     /// Path of this variables file.
     path: PathBuf,
     /// How do we type variables?
     type_kind: TypeKind,
 }
 
+/// Variables of a variables file.
+enum Variables {
+    /// Lines of a properties file, parsed on each iteration.
+    Properties(Enumerate<Lines<BufReader<File>>>),
+    /// Variables of a JSON or YAML file, parsed when the file is opened.
+    Structured(vec::IntoIter<(String, Value)>),
+}
+
 impl VariablesFile {
     /// Opens the variables file at `path`.
     /// Each variable will be typed: either variable type are inferred from their value, or variable
@@ -53,25 +74,100 @@ impl VariablesFile {
             )));
         }
 
-        let Ok(file) = File::open(path) else {
-            let error = CliOptionsError::Error(format!("Error opening {}", path.display()));
-            return Err(error);
+        let extension = path.extension().and_then(|e| e.to_str());
+        let variables = match extension {
+            Some("json") | Some("yaml") | Some("yml") => {
+                let Ok(content) = fs::read_to_string(path) else {
+                    let error = CliOptionsError::Error(format!("Error opening {}", path.display()));
+                    return Err(error);
+                };
+                let variables = if extension == Some("json") {
+                    parse_json(&content)
+                } else {
+                    parse_yaml(&content)
+                };
+                let variables = variables.map_err(|message| {
+                    CliOptionsError::Error(format!("{} in {}", message, path.display()))
+                })?;
+                Variables::Structured(variables.into_iter())
+            }
+            _ => {
+                let Ok(file) = File::open(path) else {
+                    let error = CliOptionsError::Error(format!("Error opening {}", path.display()));
+                    return Err(error);
+                };
+                Variables::Properties(BufReader::new(file).lines().enumerate())
+            }
         };
-        let lines = BufReader::new(file).lines().enumerate();
         Ok(VariablesFile {
-            lines,
+            variables,
             path: path.to_path_buf(),
             type_kind,
         })
     }
+
+    /// Opens the state file at `path`, a JSON object of variables written by `--export-variables`.
+    ///
+    /// Contrary to [`VariablesFile::open`], the file is always parsed as JSON, whatever its
+    /// extension.
+    pub fn open_state(path: &Path) -> Result<Self, CliOptionsError> {
+        let Ok(content) = fs::read_to_string(path) else {
+            return Err(CliOptionsError::Error(format!(
+                "State file {} can not be read",
+                path.display()
+            )));
+        };
+        let variables = parse_json(&content).map_err(|message| {
+            CliOptionsError::Error(format!("{} in {}", message, path.display()))
+        })?;
+        Ok(VariablesFile {
+            variables: Variables::Structured(variables.into_iter()),
+            path: path.to_path_buf(),
+            type_kind: TypeKind::Inferred,
+        })
+    }
+
+    /// Returns the next variable of a JSON or YAML file, checking its name and its type.
+    fn next_structured(&self, name: String, value: Value) -> Result<(String, Value), String> {
+        if is_variable_reserved(&name) {
+            return Err(format!(
+                "Variable {name} conflicts with the {name} function, use a different name."
+            ));
+        }
+        if self.type_kind == TypeKind::Inferred {
+            return Ok((name, value));
+        }
+        let value = match value {
+            Value::String(s) => s,
+            Value::Bool(b) => b.to_string(),
+            Value::Number(n) => n.to_string(),
+            _ => {
+                return Err(format!(
+                    "Variable {name} in {} must be a string, a number or a boolean",
+                    self.path.display()
+                ));
+            }
+        };
+        Ok((name, Value::String(value)))
+    }
 }
 
 impl Iterator for VariablesFile {
     type Item = Result<(String, Value), CliOptionsError>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        let lines = match &mut self.variables {
+            Variables::Properties(lines) => lines,
+            Variables::Structured(variables) => {
+                let (name, value) = variables.next()?;
+                let variable = self
+                    .next_structured(name, value)
+                    .map_err(CliOptionsError::Error);
+                return Some(variable);
+            }
+        };
         loop {
-            let (index, line) = self.lines.next()?;
+            let (index, line) = lines.next()?;
             let line = match line {
                 Ok(s) => s,
                 Err(_) => {
@@ -96,6 +192,68 @@ impl Iterator for VariablesFile {
     }
 }
 
+/// Parses the variables of a JSON file `content`, a JSON object.
+fn parse_json(content: &str) -> Result<Vec<(String, Value)>, String> {
+    let json = serde_json::from_str::<serde_json::Value>(content)
+        .map_err(|e| format!("Invalid JSON ({e})"))?;
+    let serde_json::Value::Object(members) = json else {
+        return Err("Variables must be defined in a JSON object".to_string());
+    };
+    let variables = members
+        .iter()
+        .map(|(name, value)| (name.clone(), Value::from_json(value)))
+        .collect();
+    Ok(variables)
+}
+
+/// Parses the variables of a YAML file `content`, a YAML mapping.
+///
+/// An empty file has no variables.
+fn parse_yaml(content: &str) -> Result<Vec<(String, Value)>, String> {
+    let docs = YamlLoader::load_from_str(content).map_err(|e| format!("Invalid YAML ({e})"))?;
+    let Some(doc) = docs.first() else {
+        return Ok(vec![]);
+    };
+    let Value::Object(variables) = from_yaml(doc)? else {
+        return Err("Variables must be defined in a YAML mapping".to_string());
+    };
+    Ok(variables)
+}
+
+/// Converts a YAML node to a [`Value`].
+fn from_yaml(yaml: &Yaml) -> Result<Value, String> {
+    let value = match yaml {
+        Yaml::Real(_) => match yaml.as_f64() {
+            Some(f) => Value::Number(Number::Float(f)),
+            None => return Err(format!("Invalid YAML number {yaml:?}")),
+        },
+        Yaml::Integer(i) => Value::Number(Number::Integer(*i)),
+        Yaml::String(s) => Value::String(s.clone()),
+        Yaml::Boolean(b) => Value::Bool(*b),
+        Yaml::Array(items) => {
+            let items = items.iter().map(from_yaml).collect::<Result<_, _>>()?;
+            Value::List(items)
+        }
+        Yaml::Hash(entries) => {
+            let mut members = vec![];
+            for (key, value) in entries {
+                let key = match key {
+                    Yaml::String(s) => s.clone(),
+                    Yaml::Integer(i) => i.to_string(),
+                    Yaml::Boolean(b) => b.to_string(),
+                    _ => return Err(format!("Invalid YAML key {key:?}")),
+                };
+                members.push((key, from_yaml(value)?));
+            }
+            Value::Object(members)
+        }
+        Yaml::Null => Value::Null,
+        Yaml::Alias(_) => return Err("YAML aliases are not supported".to_string()),
+        Yaml::BadValue => return Err("Invalid YAML value".to_string()),
+    };
+    Ok(value)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::cli::options::variables_file::{TypeKind, VariablesFile};
@@ -149,4 +307,123 @@ id=123
             ]
         );
     }
+
+    #[test]
+    fn test_json_inferred() {
+        let path = temp_file("file3.json");
+        let content = r#"{
+  "host": "example.net",
+  "id": 123,
+  "user": {"name": "Bob", "roles": ["admin", "dev"]}
+}
+"#;
+        fs::write(&path, content).unwrap();
+        let file = VariablesFile::open(&path, TypeKind::Inferred).unwrap();
+        let vars = file.collect::<Vec<_>>();
+        assert_eq!(
+            vars,
+            vec![
+                Ok(("host".to_string(), Value::String("example.net".to_string()))),
+                Ok(("id".to_string(), Value::Number(Number::Integer(123)))),
+                Ok((
+                    "user".to_string(),
+                    Value::Object(vec![
+                        ("name".to_string(), Value::String("Bob".to_string())),
+                        (
+                            "roles".to_string(),
+                            Value::List(vec![
+                                Value::String("admin".to_string()),
+                                Value::String("dev".to_string()),
+                            ])
+                        ),
+                    ])
+                )),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_yaml_inferred() {
+        let path = temp_file("file4.yaml");
+        let content = r#"# With some comments
+host: example.net
+ratio: 0.5
+enabled: true
+user:
+  name: Bob
+  roles: [admin, dev]
+"#;
+        fs::write(&path, content).unwrap();
+        let file = VariablesFile::open(&path, TypeKind::Inferred).unwrap();
+        let vars = file.collect::<Vec<_>>();
+        assert_eq!(
+            vars,
+            vec![
+                Ok(("host".to_string(), Value::String("example.net".to_string()))),
+                Ok(("ratio".to_string(), Value::Number(Number::Float(0.5)))),
+                Ok(("enabled".to_string(), Value::Bool(true))),
+                Ok((
+                    "user".to_string(),
+                    Value::Object(vec![
+                        ("name".to_string(), Value::String("Bob".to_string())),
+                        (
+                            "roles".to_string(),
+                            Value::List(vec![
+                                Value::String("admin".to_string()),
+                                Value::String("dev".to_string()),
+                            ])
+                        ),
+                    ])
+                )),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_yaml_string() {
+        let path = temp_file("file5.yml");
+        let content = r#"token: abc
+id: 123
+user:
+  name: Bob
+"#;
+        fs::write(&path, content).unwrap();
+        let file = VariablesFile::open(&path, TypeKind::String).unwrap();
+        let vars = file.collect::<Vec<_>>();
+        assert_eq!(vars.len(), 3);
+        assert_eq!(
+            vars[0],
+            Ok(("token".to_string(), Value::String("abc".to_string())))
+        );
+        assert_eq!(
+            vars[1],
+            Ok(("id".to_string(), Value::String("123".to_string())))
+        );
+        assert!(vars[2].is_err());
+    }
+
+    #[test]
+    fn test_json_not_an_object() {
+        let path = temp_file("file6.json");
+        fs::write(&path, "[1, 2, 3]").unwrap();
+        assert!(VariablesFile::open(&path, TypeKind::Inferred).is_err());
+    }
+
+    #[test]
+    fn test_state() {
+        // A state file is parsed as JSON, whatever its extension.
+        let path = temp_file("file7.state");
+        fs::write(&path, r#"{"id": 123, "token": "abc"}"#).unwrap();
+        let file = VariablesFile::open_state(&path).unwrap();
+        let vars = file.collect::<Vec<_>>();
+        assert_eq!(
+            vars,
+            vec![
+                Ok(("id".to_string(), Value::Number(Number::Integer(123)))),
+                Ok(("token".to_string(), Value::String("abc".to_string()))),
+            ]
+        );
+
+        assert!(VariablesFile::open_state(&temp_file("file8.state")).is_err());
+    }
 }
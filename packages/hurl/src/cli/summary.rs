@@ -17,10 +17,19 @@
  */
 use std::time::Duration;
 
+use hurl::load::stats::LoadStats;
+use hurl_core::error::DisplaySourceError;
+
 use crate::HurlRun;
 
+const SEPARATOR: &str =
+    "--------------------------------------------------------------------------------";
+
 /// Returns the text summary of this Hurl `runs`.
 ///
+/// If some runs have failed, the summary starts with a block listing the first error of each
+/// failed entry, so failures can be spotted without scrolling through the whole logs.
+///
 /// This is used in `--test`mode.
 pub fn summary(runs: &[HurlRun], duration: Duration) -> String {
     let total_files = runs.len();
@@ -38,14 +47,143 @@ pub fn summary(runs: &[HurlRun], duration: Duration) -> String {
     let failed = total_files - success_files;
     let failed_percent = 100.0 * failed as f32 / total_files as f32;
     let formatted_duration = format_duration(duration);
-    format!(
-        "--------------------------------------------------------------------------------\n\
+
+    let mut s = String::new();
+    let failures = failures(runs);
+    if !failures.is_empty() {
+        s.push_str(&format!("{SEPARATOR}\nFailures:\n{failures}"));
+    }
+    s.push_str(&format!(
+        "{SEPARATOR}\n\
              Executed files:    {total_files}\n\
              Executed requests: {total_requests} ({requests_rate:.1}/s)\n\
              Succeeded files:   {success_files} ({success_percent:.1}%)\n\
              Failed files:      {failed} ({failed_percent:.1}%)\n\
              Duration:          {duration_in_ms} ms ({formatted_duration})\n"
-    )
+    ));
+    s
+}
+
+/// Returns the list of failures of this Hurl `runs`, one line per failed entry.
+///
+/// Each line has the source location and the description of the first error of the entry, for
+/// instance `tests/foo.hurl:8:1: Assert body value (entry 2)`.
+fn failures(runs: &[HurlRun]) -> String {
+    let mut s = String::new();
+    for run in runs.iter() {
+        let mut failed_entries = vec![];
+        for (error, entry) in run.hurl_result.errors() {
+            // We only display the first error of each entry.
+            if failed_entries.contains(&entry.entry_index) {
+                continue;
+            }
+            failed_entries.push(entry.entry_index);
+
+            // Errors of included entries are located in their own file.
+            let filename = match &entry.source_file {
+                Some(file) => file.input.to_string(),
+                None => run.filename.to_string(),
+            };
+            let line = error.source_info.start.line;
+            let column = error.source_info.start.column;
+            let description = error.description();
+            let index = entry.entry_index;
+            s.push_str(&format!(
+                "  {filename}:{line}:{column}: {description} (entry {index})\n"
+            ));
+        }
+    }
+    s
+}
+
+/// Maximum width of a bar in the latency histogram of a load test summary.
+const HISTOGRAM_WIDTH: usize = 40;
+
+/// Returns the text summary of a load test, given its `stats`, the number of virtual users
+/// `concurrency` and the target `throughput` in requests per second.
+///
+/// This is used in load test mode (`--concurrency`, `--throughput`).
+pub fn load_summary(stats: &LoadStats, concurrency: usize, throughput: Option<u32>) -> String {
+    let duration_in_ms = stats.duration.as_millis() as f64;
+    let formatted_duration = format_duration(stats.duration);
+    let throughput = match throughput {
+        Some(rps) => format!("{rps}/s"),
+        None => "unlimited".to_string(),
+    };
+    let runs = stats.runs;
+    let runs_rate = rate(runs, duration_in_ms);
+    let failed_runs = stats.failed_runs();
+    let failed_runs_percent = percent(failed_runs, runs);
+    let requests = stats.requests();
+    let requests_rate = rate(requests, duration_in_ms);
+    let failed_requests = stats.failed_requests;
+    let failed_requests_percent = percent(failed_requests, requests);
+
+    let mut s = format!(
+        "{SEPARATOR}\n\
+             Virtual users:     {concurrency}\n\
+             Target throughput: {throughput}\n\
+             Executed runs:     {runs} ({runs_rate:.1}/s)\n\
+             Failed runs:       {failed_runs} ({failed_runs_percent:.1}%)\n\
+             Executed requests: {requests} ({requests_rate:.1}/s)\n\
+             Failed requests:   {failed_requests} ({failed_requests_percent:.1}%)\n\
+             Duration:          {duration_in_ms} ms ({formatted_duration})\n"
+    );
+    if requests == 0 {
+        return s;
+    }
+    let latencies = [
+        ("min", stats.min()),
+        ("mean", stats.mean()),
+        ("p50", stats.percentile(50)),
+        ("p90", stats.percentile(90)),
+        ("p95", stats.percentile(95)),
+        ("p99", stats.percentile(99)),
+        ("max", stats.max()),
+    ]
+    .iter()
+    .map(|(name, latency)| {
+        let latency = latency.unwrap_or_default().as_millis();
+        format!("{name} {latency} ms")
+    })
+    .collect::<Vec<_>>()
+    .join(", ");
+    s.push_str(&format!("Latency:           {latencies}\n"));
+    s.push_str("Latency histogram:\n");
+    let histogram = stats.histogram();
+    let max_count = histogram.iter().map(|b| b.count).max().unwrap_or(0);
+    for bucket in histogram.iter() {
+        let bar_width = (bucket.count * HISTOGRAM_WIDTH).div_ceil(max_count);
+        let bar = "#".repeat(bar_width);
+        let count = bucket.count;
+        let bound = match bucket.upper_bound {
+            Some(upper_bound) => format!("<= {:>5} ms", upper_bound.as_millis()),
+            None => format!(">  {:>5} ms", bucket.lower_bound.as_millis()),
+        };
+        let line = format!("  {bound}: {count:>7} {bar}");
+        s.push_str(line.trim_end());
+        s.push('\n');
+    }
+    s
+}
+
+/// Returns the rate per second of `count` events during `duration_in_ms` milliseconds.
+fn rate(count: usize, duration_in_ms: f64) -> f64 {
+    // Guard against a zero duration to avoid producing `inf` or `NaN` in the rate.
+    if duration_in_ms > 0.0 {
+        1000.0 * (count as f64) / duration_in_ms
+    } else {
+        0.0
+    }
+}
+
+/// Returns the percentage of `count` in `total`.
+fn percent(count: usize, total: usize) -> f64 {
+    if total > 0 {
+        100.0 * count as f64 / total as f64
+    } else {
+        0.0
+    }
 }
 
 /// Returns a formatted duration string (h:m:s:ms).
@@ -79,7 +217,7 @@ fn requests_count(runs: &[HurlRun]) -> usize {
 pub mod tests {
     use super::*;
     use hurl::http::CurlCmd;
-    use hurl::runner::{EntryResult, HurlResult};
+    use hurl::runner::{EntryResult, HurlResult, RunnerError, RunnerErrorKind};
     use hurl_core::ast::SourceInfo;
     use hurl_core::input::Input;
     use hurl_core::reader::Pos;
@@ -98,6 +236,7 @@ pub mod tests {
                 transfer_duration: Duration::from_millis(0),
                 compressed: false,
                 curl_cmd: CurlCmd::default(),
+                source_file: None,
             };
             HurlRun {
                 content: String::new(),
@@ -149,4 +288,112 @@ pub mod tests {
              Duration:          3661111 ms (1h:1m:1s:111ms)\n"
         );
     }
+
+    #[test]
+    fn create_run_summary_with_failures() {
+        let errors = vec![
+            RunnerError::new(
+                SourceInfo::new(Pos::new(4, 0), Pos::new(4, 0)),
+                RunnerErrorKind::AssertFailure {
+                    actual: "integer <10>".to_string(),
+                    expected: "integer <12>".to_string(),
+                    type_mismatch: false,
+                    json_diff: None,
+                },
+                true,
+            ),
+            RunnerError::new(
+                SourceInfo::new(Pos::new(5, 0), Pos::new(5, 0)),
+                RunnerErrorKind::AssertFailure {
+                    actual: "string <Apple>".to_string(),
+                    expected: "string <Lemon>".to_string(),
+                    type_mismatch: false,
+                    json_diff: None,
+                },
+                true,
+            ),
+        ];
+        let entries = vec![
+            EntryResult {
+                entry_index: Index::new(1),
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 18)),
+                ..Default::default()
+            },
+            EntryResult {
+                entry_index: Index::new(2),
+                source_info: SourceInfo::new(Pos::new(3, 1), Pos::new(3, 18)),
+                errors,
+                ..Default::default()
+            },
+        ];
+        let runs = vec![HurlRun {
+            content: String::new(),
+            filename: Input::new("foo.hurl"),
+            hurl_result: HurlResult {
+                entries,
+                success: false,
+                ..Default::default()
+            },
+        }];
+        let duration = Duration::from_millis(128);
+        let s = summary(&runs, duration);
+        assert_eq!(
+            s,
+            "--------------------------------------------------------------------------------\n\
+             Failures:\n  \
+               foo.hurl:4:0: Assert failure (entry 2)\n\
+             --------------------------------------------------------------------------------\n\
+             Executed files:    1\n\
+             Executed requests: 0 (0.0/s)\n\
+             Succeeded files:   0 (0.0%)\n\
+             Failed files:      1 (100.0%)\n\
+             Duration:          128 ms (0h:0m:0s:128ms)\n"
+        );
+    }
+
+    #[test]
+    fn create_load_summary() {
+        let stats = LoadStats {
+            runs: 3,
+            assert_failed_runs: 1,
+            runtime_failed_runs: 0,
+            failed_requests: 1,
+            latencies: [3, 4, 8, 45, 45, 45]
+                .iter()
+                .map(|ms| Duration::from_millis(*ms))
+                .collect(),
+            duration: Duration::from_millis(2000),
+        };
+        let s = load_summary(&stats, 2, Some(10));
+        assert_eq!(
+            s,
+            "--------------------------------------------------------------------------------\n\
+             Virtual users:     2\n\
+             Target throughput: 10/s\n\
+             Executed runs:     3 (1.5/s)\n\
+             Failed runs:       1 (33.3%)\n\
+             Executed requests: 6 (3.0/s)\n\
+             Failed requests:   1 (16.7%)\n\
+             Duration:          2000 ms (0h:0m:2s:0ms)\n\
+             Latency:           min 3 ms, mean 25 ms, p50 8 ms, p90 45 ms, p95 45 ms, p99 45 ms, max 45 ms\n\
+             Latency histogram:\n  \
+               <=     5 ms:       2 ###########################\n  \
+               <=    10 ms:       1 ##############\n  \
+               <=    20 ms:       0\n  \
+               <=    50 ms:       3 ########################################\n"
+        );
+
+        let s = load_summary(&LoadStats::new(), 1, None);
+        assert_eq!(
+            s,
+            "--------------------------------------------------------------------------------\n\
+             Virtual users:     1\n\
+             Target throughput: unlimited\n\
+             Executed runs:     0 (0.0/s)\n\
+             Failed runs:       0 (0.0%)\n\
+             Executed requests: 0 (0.0/s)\n\
+             Failed requests:   0 (0.0%)\n\
+             Duration:          0 ms (0h:0m:0s:0ms)\n"
+        );
+    }
 }
@@ -0,0 +1,78 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Waits for a service to be ready before running Hurl files (see `--wait-for`).
+use std::thread;
+use std::time::{Duration, Instant};
+
+use curl::easy::Easy;
+
+use crate::cli::BaseLogger;
+
+/// Interval between two requests to the URL we're waiting for.
+const WAIT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Waits for `url` to respond with a successful status code (2xx), at most `timeout`.
+///
+/// `url` is requested every second. If `insecure` is `true`, server certificates are not verified.
+/// Returns an error message if `url` is not ready before `timeout`, or if `url` is not valid.
+pub fn wait_for(
+    url: &str,
+    timeout: Duration,
+    insecure: bool,
+    logger: &BaseLogger,
+) -> Result<(), String> {
+    logger.debug(&format!("Waiting for {url} (timeout: {timeout:?})"));
+    let start = Instant::now();
+    loop {
+        let remaining = timeout.saturating_sub(start.elapsed());
+        match request(url, remaining, insecure) {
+            Ok(status) if (200..300).contains(&status) => {
+                logger.debug(&format!("{url} is ready (status: {status})"));
+                return Ok(());
+            }
+            Ok(status) => logger.debug(&format!("{url} is not ready (status: {status})")),
+            Err(e) if e.is_url_malformed() || e.is_unsupported_protocol() => {
+                return Err(format!("invalid URL <{url}>: {}", e.description()));
+            }
+            Err(e) => logger.debug(&format!("{url} is not ready ({})", e.description())),
+        }
+        if start.elapsed() + WAIT_INTERVAL >= timeout {
+            return Err(format!("<{url}> is not ready after {timeout:?}"));
+        }
+        thread::sleep(WAIT_INTERVAL);
+    }
+}
+
+/// Sends a GET request to `url` and returns the response status code.
+fn request(url: &str, timeout: Duration, insecure: bool) -> Result<u32, curl::Error> {
+    let mut easy = Easy::new();
+    easy.url(url)?;
+    // A zero timeout means no timeout for libcurl.
+    easy.timeout(timeout.max(Duration::from_millis(1)))?;
+    if insecure {
+        easy.ssl_verify_peer(false)?;
+        easy.ssl_verify_host(false)?;
+    }
+    {
+        // The response body is not used.
+        let mut transfer = easy.transfer();
+        transfer.write_function(|data| Ok(data.len()))?;
+        transfer.perform()?;
+    }
+    easy.response_code()
+}
@@ -0,0 +1,316 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Generation of asserts from the actual responses of a Hurl run, used to bootstrap regression
+//! suites from a working API.
+use hurl_core::ast::{Entry, Response, SectionValue};
+use hurl_core::parser;
+use serde_json::Value;
+
+use crate::http::{CONTENT_TYPE, LOCATION, Response as HttpResponse};
+use crate::runner::HurlResult;
+
+/// Response headers for which an assert is generated.
+const ASSERTED_HEADERS: [&str; 2] = [CONTENT_TYPE, LOCATION];
+
+/// Returns the Hurl file `content` rewritten with asserts generated from the actual responses of
+/// its run `hurl_result`.
+///
+/// For each executed entry, the response status, the `Content-Type` and `Location` headers, and the
+/// top-level fields of a JSON body are asserted. Existing captures are kept, while existing asserts
+/// and body are replaced. Entries that haven't been executed are left unchanged. The file is printed
+/// with the same formatting as `hurlfmt`.
+pub fn generate_asserts(content: &str, hurl_result: &HurlResult) -> Result<String, String> {
+    let mut hurl_file =
+        parser::parse_hurl_file(content).map_err(|_| "invalid Hurl file".to_string())?;
+    // Entries results are indexed on the entries to be run, with included entries resolved: we
+    // can't map them to the entries of this file if it has includes.
+    let has_includes =
+        !hurl_file.includes.is_empty() || hurl_file.entries.iter().any(|e| !e.includes.is_empty());
+    if has_includes {
+        return Err("asserts can't be generated for a file with includes".to_string());
+    }
+
+    for (index, entry) in hurl_file.entries.iter_mut().enumerate() {
+        // An entry can be run multiple times (retry, repeat): the last run is used.
+        let response = hurl_result
+            .entries
+            .iter()
+            .rev()
+            .find(|e| e.entry_index.to_zero_based() == index)
+            .and_then(|e| e.calls.last())
+            .map(|c| &c.response);
+        if let Some(response) = response {
+            let generated = generated_response(response)?;
+            update_entry(entry, generated);
+        }
+    }
+    let config = hurlfmt::linter::LintConfig::default();
+    Ok(hurlfmt::linter::lint_hurl_file(&hurl_file, &config))
+}
+
+/// Replaces the response of `entry` by a `generated` response, keeping the version and the captures
+/// of the existing response.
+fn update_entry(entry: &mut Entry, mut generated: Response) {
+    if let Some(existing) = entry.response.take() {
+        let captures = existing
+            .sections
+            .into_iter()
+            .filter(|s| matches!(s.value, SectionValue::Captures(_)));
+        generated.sections = captures.chain(generated.sections).collect();
+        generated.line_terminators = existing.line_terminators;
+        generated.version = existing.version;
+    }
+    entry.response = Some(generated);
+}
+
+/// Returns a Hurl response section, with asserts generated from an HTTP `response`.
+fn generated_response(response: &HttpResponse) -> Result<Response, String> {
+    let mut s = format!("GET http://localhost\nHTTP {}\n", response.status);
+    for name in ASSERTED_HEADERS {
+        if let Some(header) = response.headers.get(name) {
+            s.push_str(&format!("{name}: {}\n", escape(&header.value, false)));
+        }
+    }
+    let asserts = json_asserts(response);
+    if !asserts.is_empty() {
+        s.push_str("[Asserts]\n");
+        for assert in asserts {
+            s.push_str(&assert);
+            s.push('\n');
+        }
+    }
+
+    let generated = parser::parse_hurl_file(&s)
+        .map_err(|_| format!("invalid generated response:\n{s}"))?
+        .entries
+        .into_iter()
+        .next()
+        .and_then(|e| e.response);
+    generated.ok_or_else(|| format!("invalid generated response:\n{s}"))
+}
+
+/// Returns asserts on the top-level fields of a JSON `response` body.
+fn json_asserts(response: &HttpResponse) -> Vec<String> {
+    if !response.is_json() {
+        return vec![];
+    }
+    let Ok(text) = response.text() else {
+        return vec![];
+    };
+    let Ok(value) = serde_json::from_str::<Value>(&text) else {
+        return vec![];
+    };
+    match value {
+        Value::Object(fields) => fields
+            .iter()
+            .filter_map(|(key, value)| {
+                let path = json_path(key)?;
+                let predicate = predicate(value)?;
+                Some(format!("jsonpath \"{}\" {predicate}", escape(&path, true)))
+            })
+            .collect(),
+        Value::Array(items) => vec![format!("jsonpath \"$\" count == {}", items.len())],
+        _ => vec![],
+    }
+}
+
+/// Returns the JSONPath expression of a top-level field `key`, or `None` if the key can't be
+/// expressed simply.
+fn json_path(key: &str) -> Option<String> {
+    let mut chars = key.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if is_identifier {
+        Some(format!("$.{key}"))
+    } else if key.contains(['\'', '\\']) {
+        None
+    } else {
+        Some(format!("$['{key}']"))
+    }
+}
+
+/// Returns the predicate asserting a JSON `value`, or `None` if the value can't be asserted.
+fn predicate(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => Some("== null".to_string()),
+        Value::Bool(value) => Some(format!("== {value}")),
+        // Numbers with an exponent are not valid Hurl numbers.
+        Value::Number(value) => {
+            let value = value.to_string();
+            if value.contains(['e', 'E']) {
+                None
+            } else {
+                Some(format!("== {value}"))
+            }
+        }
+        Value::String(value) => Some(format!("== \"{}\"", escape(value, true))),
+        Value::Array(items) => Some(format!("count == {}", items.len())),
+        Value::Object(_) => Some("isObject".to_string()),
+    }
+}
+
+/// Escapes a string `s` to be used in a Hurl file, in a quoted string if `quoted` is `true`, or in
+/// an unquoted value otherwise.
+fn escape(s: &str, quoted: bool) -> String {
+    let mut escaped = String::new();
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' if quoted => escaped.push_str("\\\""),
+            '#' if !quoted => escaped.push_str("\\#"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            // A `{{` sequence starts a placeholder.
+            '{' if chars.peek() == Some(&'{') => escaped.push_str("\\u{7b}"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{{{:x}}}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use hurl_core::types::Index;
+
+    use super::*;
+    use crate::http::{Call, Header, HeaderVec, HttpVersion, Request, Url};
+    use crate::runner::EntryResult;
+
+    fn json_response(status: u32, body: &str) -> HttpResponse {
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Type", "application/json"));
+        headers.push(Header::new("Server", "Flask"));
+        let url = "http://localhost".parse::<Url>().unwrap();
+        HttpResponse::new(
+            HttpVersion::Http11,
+            status,
+            headers,
+            body.as_bytes().to_vec(),
+            Duration::from_millis(0),
+            url,
+            None,
+            Default::default(),
+            None,
+            false,
+            vec![],
+            Default::default(),
+        )
+    }
+
+    fn hurl_result(responses: &[(usize, HttpResponse)]) -> HurlResult {
+        let url = "http://localhost".parse::<Url>().unwrap();
+        let entries = responses
+            .iter()
+            .map(|(index, response)| EntryResult {
+                entry_index: Index::new(*index),
+                calls: vec![Call {
+                    request: Request::new("GET", url.clone(), HeaderVec::new(), vec![]),
+                    response: response.clone(),
+                    timings: Default::default(),
+                }],
+                ..Default::default()
+            })
+            .collect();
+        HurlResult {
+            entries,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_generate_asserts() {
+        let content = r#"# Get a user
+GET http://localhost:8000/users/1
+
+POST http://localhost:8000/users
+{"name": "Bob"}
+HTTP 200
+[Captures]
+id: jsonpath "$.id"
+[Asserts]
+jsonpath "$.name" == "Alice"
+
+GET http://localhost:8000/users/{{id}}
+"#;
+        let result = hurl_result(&[
+            (
+                1,
+                json_response(
+                    200,
+                    r#"{"id": 1, "name": "A \"quoted\" {{name}}", "tags": ["a", "b"], "address": {}, "first-name": null, "score": 1.5e3}"#,
+                ),
+            ),
+            (2, json_response(201, r#"{"id": 2, "name": "Bob"}"#)),
+            (2, json_response(201, r#"{"id": 3, "name": "Bob"}"#)),
+        ]);
+        assert_eq!(
+            generate_asserts(content, &result).unwrap(),
+            r#"# Get a user
+GET http://localhost:8000/users/1
+HTTP 200
+Content-Type: application/json
+[Asserts]
+jsonpath "$.address" isObject
+jsonpath "$['first-name']" == null
+jsonpath "$.id" == 1
+jsonpath "$.name" == "A \"quoted\" \u{7b}{name}}"
+jsonpath "$.tags" count == 2
+
+POST http://localhost:8000/users
+{"name": "Bob"}
+HTTP 201
+Content-Type: application/json
+[Captures]
+id: jsonpath "$.id"
+[Asserts]
+jsonpath "$.id" == 3
+jsonpath "$.name" == "Bob"
+
+GET http://localhost:8000/users/{{id}}
+"#
+        );
+    }
+
+    #[test]
+    fn test_generate_asserts_with_includes() {
+        let content = "include \"login.hurl\"\nGET http://localhost:8000/users\n";
+        let result = hurl_result(&[(1, json_response(200, "[]"))]);
+        assert!(generate_asserts(content, &result).is_err());
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("a#b\"c\\d", false), "a\\#b\"c\\\\d");
+        assert_eq!(escape("a#b\"c\\d", true), "a#b\\\"c\\\\d");
+        assert_eq!(escape("{{x}}\n", true), "\\u{7b}{x}}\\n");
+    }
+
+    #[test]
+    fn test_json_path() {
+        assert_eq!(json_path("name"), Some("$.name".to_string()));
+        assert_eq!(json_path("first-name"), Some("$['first-name']".to_string()));
+        assert_eq!(json_path("it's"), None);
+    }
+}
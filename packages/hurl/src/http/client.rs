@@ -16,6 +16,8 @@
  *
  */
 use std::collections::HashMap;
+use std::io;
+use std::io::Read;
 use std::str;
 use std::str::FromStr;
 use std::time::Instant;
@@ -26,6 +28,7 @@ use chrono::Utc;
 use curl::easy::{List, NetRc, SslOpt};
 use curl::{Error, Version, easy};
 use hurl_core::types::Count;
+use uuid::Uuid;
 
 use super::call::Call;
 use super::certificate::Certificate;
@@ -36,17 +39,20 @@ use super::easy_ext;
 use super::error::HttpError;
 use super::header::{
     ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, COOKIE, EXPECT, Header, HeaderVec, LOCATION,
-    USER_AGENT,
+    TRAILER, TRANSFER_ENCODING, USER_AGENT,
 };
+use super::hmac::HmacSignature;
 use super::ip::IpAddr;
+use super::multipart::encode_multipart;
 use super::options::{ClientOptions, Verbosity};
 use super::param::Param;
 use super::request::{
     CredentialForwarding, FollowLocation, IpResolve, Request, RequestedHttpVersion,
 };
 use super::request_cookie::RequestCookie;
-use super::request_spec::{Body, FileParam, Method, MultipartParam, RequestSpec};
-use super::response::{HttpVersion, Response};
+use super::request_spec::{Body, FileParam, Method, MultipartParam, NestedParam, RequestSpec};
+use super::resolve;
+use super::response::{HttpVersion, InterimResponse, Response};
 use super::timings::Timings;
 use super::url::Url;
 
@@ -67,6 +73,8 @@ pub struct Client {
     http3: bool,
     /// Certificates cache to get SSL certificates on reused libcurl connections.
     certificates: HashMap<i64, Certificate>,
+    /// Trailers of the current request, read by libcurl at the end of the transfer.
+    trailers: Option<Box<easy_ext::Trailers>>,
 }
 
 impl Client {
@@ -79,6 +87,7 @@ impl Client {
             http2: version.feature_http2(),
             http3: version.feature_http3(),
             certificates: HashMap::new(),
+            trailers: None,
         }
     }
 
@@ -100,7 +109,7 @@ impl Client {
         // response.
         let mut redirect_count = 0;
         loop {
-            let call = self.execute(&request_spec, &options, logger)?;
+            let call = self.execute_with_fallback(&request_spec, &mut options, logger)?;
             // If we don't follow redirection, we can early exit here.
             if matches!(options.follow_location, FollowLocation::No) {
                 calls.push(call);
@@ -176,6 +185,37 @@ impl Client {
         Ok(calls)
     }
 
+    /// Executes an HTTP request `request_spec`, without following redirection and returns a
+    /// pair of [`Call`].
+    ///
+    /// If HTTP/3 is requested with `http3_fallback` option and HTTP/3 can not be negotiated, the
+    /// request is retried over HTTP/2. In this case, `options` is updated so any subsequent
+    /// request (i.e. following a redirection) is also made over HTTP/2.
+    fn execute_with_fallback(
+        &mut self,
+        request_spec: &RequestSpec,
+        options: &mut ClientOptions,
+        logger: &mut Logger,
+    ) -> Result<Call, HttpError> {
+        match self.execute(request_spec, options, logger) {
+            Err(error)
+                if options.http3_fallback
+                    && options.http_version == RequestedHttpVersion::Http3
+                    && error.is_http3_failure() =>
+            {
+                logger.warning(&format!(
+                    "HTTP/3 can not be negotiated ({}), falling back to HTTP/2",
+                    error.message()
+                ));
+                options.http_version = RequestedHttpVersion::Http2;
+                // The new request must not reuse a connection established for HTTP/3.
+                options.allow_reuse = false;
+                self.execute(request_spec, options, logger)
+            }
+            result => result,
+        }
+    }
+
     /// Executes an HTTP request `request_spec`, without following redirection and returns a
     /// pair of [`Call`].
     pub fn execute(
@@ -197,7 +237,8 @@ impl Client {
         let mut request_headers = HeaderVec::new();
         let mut response_headers = HeaderVec::new();
         let mut status_lines = None;
-        let has_body_data = !request_spec.body.bytes().is_empty()
+        let mut interim_responses = vec![];
+        let has_body_data = !request_spec.body.is_empty()
             || !request_spec.form.is_empty()
             || !request_spec.multipart.is_empty();
 
@@ -207,6 +248,13 @@ impl Client {
         // of key-value.
         let mut request_body = Vec::<u8>::new();
         let mut response_body = Vec::<u8>::new();
+        // A chunked body is read from this buffer by the transfer read function.
+        let chunked_bytes = if is_streamed(request_spec, options) {
+            request_spec.body.bytes()
+        } else {
+            vec![]
+        };
+        let mut chunked_body = chunked_bytes.as_slice();
 
         {
             let mut transfer = self.handle.transfer();
@@ -252,7 +300,17 @@ impl Client {
 
                     for line in &lines {
                         if line.starts_with("HTTP/") {
-                            // A new status line indicates start of a new HTTP response.
+                            // A new status line indicates start of a new HTTP response: the
+                            // previous response, if informational (`1xx`), is kept as an interim
+                            // response.
+                            if let Some(status) = status_lines.as_deref().and_then(parse_status)
+                                && (100..200).contains(&status)
+                            {
+                                interim_responses.push(InterimResponse {
+                                    status,
+                                    headers: response_headers.clone(),
+                                });
+                            }
                             response_headers.clear();
                             let line = line.trim();
                             logger.debug_important("Response:");
@@ -295,8 +353,31 @@ impl Client {
                 response_body.extend(data);
                 Ok(data.len())
             })?;
+            // A body read from the standard input is streamed to libcurl, chunk by chunk, without
+            // being materialized. A chunked body is also streamed, so libcurl can send the
+            // trailers after its last chunk.
+            if request_spec.body == Body::Stdin {
+                transfer.read_function(|into| {
+                    io::stdin().read(into).map_err(|_| easy::ReadError::Abort)
+                })?;
+            } else if is_streamed(request_spec, options) {
+                transfer.read_function(|into| Ok(chunked_body.read(into).unwrap_or(0)))?;
+            }
+            // The progress function is regularly called by libcurl during the transfer: returning
+            // `false` aborts the transfer with `CURLE_ABORTED_BY_CALLBACK`.
+            if let Some(token) = &options.cancellation_token {
+                transfer.progress_function(|_, _, _, _| !token.is_cancelled())?;
+            }
 
             if let Err(e) = transfer.perform() {
+                if e.is_aborted_by_callback()
+                    && options
+                        .cancellation_token
+                        .as_ref()
+                        .is_some_and(|t| t.is_cancelled())
+                {
+                    return Err(HttpError::Cancelled);
+                }
                 let code = e.code() as i32; // due to windows build
                 let description = match e.extra_description() {
                     None => e.description().to_string(),
@@ -333,6 +414,8 @@ impl Client {
 
         let url = Url::from_str(&url)?;
         let ip_addr = self.primary_ip()?;
+        let proxy_status = self.proxy_status()?;
+        let hsts_upgraded = self.hsts_upgraded(&url, options)?;
         let request = Request::new(
             &method.to_string(),
             url.clone(),
@@ -348,6 +431,10 @@ impl Client {
             url,
             certificate,
             ip_addr,
+            proxy_status,
+            hsts_upgraded,
+            interim_responses,
+            timings.clone(),
         );
 
         if verbose {
@@ -418,12 +505,20 @@ impl Client {
         // Activates the access of certificates info chain after a transfer has been executed.
         self.handle.certinfo(true)?;
 
+        // Progress callbacks are used to abort a transfer when the run is cancelled.
+        // See <https://curl.se/libcurl/c/CURLOPT_NOPROGRESS.html>
+        if options.cancellation_token.is_some() {
+            self.handle.progress(true)?;
+        }
+
         if !options.connects_to.is_empty() {
             let connects = to_list(&options.connects_to)?;
             self.handle.connect_to(connects)?;
         }
-        if !options.resolves.is_empty() {
-            let resolves = to_list(&options.resolves)?;
+        // Wildcard host mappings are rewritten to the concrete mapping of the requested host.
+        let resolves = resolve::host_resolves(&options.resolves, &request_spec.url);
+        if !resolves.is_empty() {
+            let resolves = to_list(&resolves)?;
             self.handle.resolve(resolves)?;
         }
         self.handle.ssl_verify_host(!options.insecure)?;
@@ -432,31 +527,46 @@ impl Client {
             self.handle.cainfo(cacert_file)?;
             self.handle.ssl_cert_type("PEM")?;
         }
+        // The client certificate password can be given with the certificate file (`cert.pem:password`)
+        // or with a distinct option, the former taking precedence.
+        let mut client_cert_password = options.client_cert_password.clone();
+        let client_cert_type = options.client_cert_type.to_string();
         if let Some(client_cert_file) = &options.client_cert_file {
-            match parse_cert_password(client_cert_file) {
-                (cert, Some(password)) => {
-                    self.handle.ssl_cert(cert)?;
-                    self.handle.key_password(&password)?;
-                }
-                (cert, None) => {
-                    self.handle.ssl_cert(cert)?;
-                }
+            let (cert, password) = parse_cert_password(client_cert_file);
+            self.handle.ssl_cert(cert)?;
+            if password.is_some() {
+                client_cert_password = password;
             }
-            self.handle.ssl_cert_type("PEM")?;
+            self.handle.ssl_cert_type(&client_cert_type)?;
         }
         if let Some(client_key_file) = &options.client_key_file {
             self.handle.ssl_key(client_key_file)?;
-            self.handle.ssl_cert_type("PEM")?;
+            self.handle.ssl_cert_type(&client_cert_type)?;
+        }
+        if let Some(password) = &client_cert_password {
+            self.handle.key_password(password)?;
         }
         self.handle.path_as_is(options.path_as_is)?;
         if let Some(proxy) = &options.proxy {
             self.handle.proxy(proxy)?;
         }
+        if let Some(proxy_user) = &options.proxy_user {
+            match proxy_user.split_once(':') {
+                Some((username, password)) => {
+                    self.handle.proxy_username(username)?;
+                    self.handle.proxy_password(password)?;
+                }
+                None => self.handle.proxy_username(proxy_user)?,
+            }
+        }
         if let Some(no_proxy) = &options.no_proxy {
             self.handle.noproxy(no_proxy)?;
         }
         if let Some(unix_socket) = &options.unix_socket {
-            self.handle.unix_socket(unix_socket)?;
+            self.set_unix_socket(unix_socket)?;
+        }
+        if let Some(dns_servers) = &options.dns_servers {
+            self.handle.dns_servers(dns_servers)?;
         }
         if let Some(filename) = &options.netrc_file {
             easy_ext::netrc_file(&mut self.handle, filename)?;
@@ -470,6 +580,9 @@ impl Client {
         } else if options.netrc {
             self.handle.netrc(NetRc::Required)?;
         }
+        if let Some(filename) = &options.hsts_file {
+            easy_ext::hsts_file(&mut self.handle, filename)?;
+        }
         self.handle.timeout(options.timeout)?;
         self.handle.connect_timeout(options.connect_timeout)?;
         if let Some(max_filesize) = options.max_filesize {
@@ -507,17 +620,41 @@ impl Client {
         self.set_cookies(&request_spec.cookies)?;
         self.set_form(&request_spec.form)?;
         self.set_multipart(&request_spec.multipart)?;
-        let request_spec_body = &request_spec.body.bytes();
-        self.set_body(request_spec_body)?;
+        self.set_body(&request_spec.body, is_streamed(request_spec, options))?;
 
         let mut headers = request_spec.headers.clone();
         headers.extend(&options.headers);
+        // The size of a streamed body is unknown, so it is sent with chunked transfer encoding.
+        if (request_spec.body == Body::Stdin || options.is_chunked())
+            && !headers.contains_key(TRANSFER_ENCODING)
+        {
+            headers.push(Header::new(TRANSFER_ENCODING, "chunked"));
+        }
+        // The trailers sent after the body are announced in a `Trailer` header.
+        if !options.trailers.is_empty() && !headers.contains_key(TRAILER) {
+            let names = options
+                .trailers
+                .iter()
+                .map(|h| h.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            headers.push(Header::new(TRAILER, &names));
+        }
+        if let Some(hmac_signature) = &options.hmac_signature {
+            let signature =
+                HmacSignature::from_str(hmac_signature).map_err(HttpError::InvalidHmacSignature)?;
+            let canonical_string =
+                self.hmac_canonical_string(&signature, request_spec, &url, &headers);
+            logger.debug(&format!("HMAC canonical string: {canonical_string:?}"));
+            headers.push(signature.sign(&canonical_string));
+        }
         self.set_headers(
             &headers,
             request_spec.implicit_content_type.as_deref(),
             options,
         )?;
         self.set_proxy_headers(&options.proxy_headers)?;
+        self.set_trailers(&options.trailers)?;
 
         if let Some(aws_sigv4) = &options.aws_sigv4
             && let Err(e) = self.handle.aws_sigv4(aws_sigv4.as_str())
@@ -561,6 +698,26 @@ impl Client {
         }
     }
 
+    /// Returns the canonical string of this request, signed with the HMAC `signature`.
+    ///
+    /// The signature is computed just before the request is sent, with the effective `url` and
+    /// `headers` of the request.
+    fn hmac_canonical_string(
+        &mut self,
+        signature: &HmacSignature,
+        request_spec: &RequestSpec,
+        url: &str,
+        headers: &HeaderVec,
+    ) -> String {
+        // Form params are URL encoded by libcurl, we sign the same body.
+        let body = if request_spec.form.is_empty() {
+            request_spec.body.bytes()
+        } else {
+            self.url_encode_params(&request_spec.form).into_bytes()
+        };
+        signature.canonical_string(&request_spec.method, url, headers, &body)
+    }
+
     /// Sets HTTP method.
     fn set_method(&mut self, method: &Method) -> Result<(), HttpError> {
         self.handle.custom_request(method.to_string().as_str())?;
@@ -601,10 +758,16 @@ impl Client {
         // When Hurl explicitly sets `Expect:` to remove the header, libcurl will generate
         // `SignedHeaders` that include `expect` even though the header is not present, causing
         // some APIs to reject the request.
-        // Therefore, we only remove this header when not in aws_sigv4 mode.
-        if !headers.contains_key(EXPECT) && options.aws_sigv4.is_none() {
-            // We remove default Expect headers added by curl because we want to explicitly manage this header.
-            headers_to_remove.push(EXPECT);
+        // Therefore, we only remove this header when not in aws_sigv4 mode, unless the user
+        // explicitly disables it.
+        if !headers.contains_key(EXPECT) {
+            match options.expect_continue {
+                Some(true) => headers_to_add.push(Header::new(EXPECT, "100-continue")),
+                Some(false) => headers_to_remove.push(EXPECT),
+                // We remove default Expect headers added by curl because we want to explicitly manage this header.
+                None if options.aws_sigv4.is_none() => headers_to_remove.push(EXPECT),
+                None => {}
+            }
         }
 
         if !headers.contains_key(USER_AGENT) {
@@ -708,13 +871,45 @@ impl Client {
                     MultipartParam::FileParam(FileParam {
                         name,
                         filename,
+                        part_filename,
                         data,
                         content_type,
-                    }) => form
-                        .part(name)
-                        .buffer(filename, data.clone())
-                        .content_type(content_type)
-                        .add()?,
+                        headers,
+                    }) => {
+                        let filename = part_filename.as_ref().unwrap_or(filename);
+                        let mut part = form.part(name);
+                        part.buffer(filename, data.clone())
+                            .content_type(content_type);
+                        if !headers.is_empty() {
+                            let mut list = List::new();
+                            for header in headers {
+                                list.append(&header.to_string())?;
+                            }
+                            part.content_header(list);
+                        }
+                        part.add()?;
+                    }
+                    MultipartParam::NestedParam(NestedParam {
+                        name,
+                        content_type,
+                        headers,
+                        params,
+                    }) => {
+                        // libcurl can't build nested parts, so their body is encoded by Hurl.
+                        let boundary = Uuid::new_v4().simple().to_string();
+                        let data = encode_multipart(params, &boundary);
+                        let content_type = format!("{content_type}; boundary={boundary}");
+                        let mut part = form.part(name);
+                        part.contents(&data).content_type(&content_type);
+                        if !headers.is_empty() {
+                            let mut list = List::new();
+                            for header in headers {
+                                list.append(&header.to_string())?;
+                            }
+                            part.content_header(list);
+                        }
+                        part.add()?;
+                    }
                 }
             }
             self.handle.httppost(form)?;
@@ -723,10 +918,52 @@ impl Client {
     }
 
     /// Sets request body.
-    fn set_body(&mut self, data: &[u8]) -> Result<(), HttpError> {
+    ///
+    /// A `streamed` body has no data here: it is read by the transfer read function.
+    fn set_body(&mut self, body: &Body, streamed: bool) -> Result<(), HttpError> {
+        if streamed {
+            self.handle.post(true)?;
+            return Ok(());
+        }
+        let data = body.bytes();
         if !data.is_empty() {
             self.handle.post(true)?;
-            self.handle.post_fields_copy(data)?;
+            self.handle.post_fields_copy(&data)?;
+        }
+        Ok(())
+    }
+
+    /// Sets the trailers sent after a chunked request body.
+    ///
+    /// The trailers are kept by the client until the next request, as libcurl reads them at the
+    /// end of the transfer.
+    fn set_trailers(&mut self, trailers: &HeaderVec) -> Result<(), HttpError> {
+        self.trailers = None;
+        if trailers.is_empty() {
+            return Ok(());
+        }
+        let lines = trailers.iter().map(Header::to_string).collect::<Vec<_>>();
+        let trailers = Box::new(easy_ext::Trailers::new(&lines)?);
+        easy_ext::trailers(&mut self.handle, &trailers)?;
+        self.trailers = Some(trailers);
+        Ok(())
+    }
+
+    /// Sets the Unix domain socket used for the transfer.
+    ///
+    /// A socket prefixed with `@` (like `@docker`) is a Linux abstract socket, anything else is
+    /// a filesystem path.
+    fn set_unix_socket(&mut self, unix_socket: &str) -> Result<(), HttpError> {
+        match unix_socket.strip_prefix('@') {
+            #[cfg(target_os = "linux")]
+            Some(name) => self.handle.abstract_unix_socket(name.as_bytes())?,
+            #[cfg(not(target_os = "linux"))]
+            Some(_) => {
+                return Err(HttpError::UnsupportedAbstractUnixSocket(
+                    unix_socket.to_string(),
+                ));
+            }
+            None => self.handle.unix_socket(unix_socket)?,
         }
         Ok(())
     }
@@ -774,6 +1011,27 @@ impl Client {
         }
     }
 
+    /// Get the status code of the last proxy response to a CONNECT request from libcurl
+    fn proxy_status(&mut self) -> Result<Option<u32>, HttpError> {
+        // libcurl returns 0 if no CONNECT request has been sent to a proxy.
+        match self.handle.http_connectcode()? {
+            0 => Ok(None),
+            code => Ok(Some(code)),
+        }
+    }
+
+    /// Returns `true` if the request to `url` has been upgraded from HTTP to HTTPS by the HSTS cache.
+    fn hsts_upgraded(&mut self, url: &Url, options: &ClientOptions) -> Result<bool, HttpError> {
+        if options.hsts_file.is_none() || url.scheme() != "http" {
+            return Ok(false);
+        }
+        let upgraded = self
+            .handle
+            .effective_url()?
+            .is_some_and(|effective_url| effective_url.starts_with("https://"));
+        Ok(upgraded)
+    }
+
     /// Retrieves an optional location to follow
     ///
     /// You need:
@@ -820,6 +1078,14 @@ impl Client {
         self.handle.cookie_list(&cookie.to_netscape()).unwrap();
     }
 
+    /// Replaces the cookie storage with `cookies`.
+    pub fn set_cookie_storage(&mut self, cookies: &[Cookie]) {
+        self.handle.cookie_list("ALL").unwrap();
+        for cookie in cookies {
+            self.handle.cookie_list(&cookie.to_netscape()).unwrap();
+        }
+    }
+
     /// Clears cookie storage (experimental).
     pub fn clear_cookie_storage(&mut self, logger: &mut Logger) {
         logger.debug("Clear cookie storage (experimental)");
@@ -836,6 +1102,23 @@ impl Client {
         logger: &mut Logger,
     ) -> CurlCmd {
         let cookies = self.cookie_store(logger);
+        // The HMAC signature header is computed by the client, we add it to the exported request
+        // so the curl command is signed too. An invalid signature configuration is reported when
+        // the request is executed.
+        let signature = options
+            .hmac_signature
+            .as_ref()
+            .and_then(|s| HmacSignature::from_str(s).ok());
+        if let Some(signature) = signature {
+            let url = self.generate_url(&request_spec.url, &request_spec.querystring);
+            let mut headers = request_spec.headers.clone();
+            headers.extend(&options.headers);
+            let canonical_string =
+                self.hmac_canonical_string(&signature, request_spec, &url, &headers);
+            let mut request_spec = request_spec.clone();
+            request_spec.headers.push(signature.sign(&canonical_string));
+            return CurlCmd::new(&request_spec, &cookies, context_dir, output, options);
+        }
         CurlCmd::new(request_spec, &cookies, context_dir, output, options)
     }
 
@@ -937,6 +1220,17 @@ impl HeaderVec {
     }
 }
 
+/// Returns `true` if the body of `request_spec` is streamed to libcurl by the transfer read
+/// function, instead of being set upfront.
+///
+/// Form and multipart bodies are never streamed: libcurl encodes them itself.
+fn is_streamed(request_spec: &RequestSpec, options: &ClientOptions) -> bool {
+    request_spec.body == Body::Stdin
+        || (options.is_chunked()
+            && request_spec.form.is_empty()
+            && request_spec.multipart.is_empty())
+}
+
 /// Decodes optionally HTTP lines as text with UTF-8 or ISO-8859-1 encoding.
 ///
 /// The HTTP lines comes from libcurl as an array of bytes (\r\n separator).
@@ -986,6 +1280,11 @@ fn decode_lines(data: &[u8]) -> Vec<String> {
     lines
 }
 
+/// Parses the status code of a response status `line` (like `HTTP/1.1 103 Early Hints`).
+fn parse_status(line: &str) -> Option<u32> {
+    line.split_whitespace().nth(1)?.parse().ok()
+}
+
 /// Converts a list of [`String`] to a libcurl's list of strings.
 fn to_list(items: &[String]) -> Result<List, Error> {
     let mut list = List::new();
@@ -1095,6 +1394,14 @@ mod tests {
         assert_eq!(lines.get(2).unwrap().as_str(), "");
     }
 
+    #[test]
+    fn test_parse_status() {
+        assert_eq!(parse_status("HTTP/1.1 103 Early Hints"), Some(103));
+        assert_eq!(parse_status("HTTP/2 200"), Some(200));
+        assert_eq!(parse_status("HTTP/1.1"), None);
+        assert_eq!(parse_status("HTTP/1.1 abc"), None);
+    }
+
     #[test]
     fn test_redirect_method() {
         // Status of the response to be redirected | method of the original request | method of the new request
@@ -1227,6 +1534,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn command_line_with_hmac_signature() {
+        let mut client = Client::new();
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("X-Timestamp", "1767225600"));
+        let request = RequestSpec {
+            method: Method("GET".to_string()),
+            url: Url::from_str("https://example.org/hello?page=1").unwrap(),
+            headers,
+            ..Default::default()
+        };
+        let context_dir = ContextDir::default();
+        let options = ClientOptions {
+            hmac_signature: Some(
+                "sha256:X-Signature:secret:{method} {path} {query} {header:X-Timestamp}"
+                    .to_string(),
+            ),
+            ..Default::default()
+        };
+
+        let logger_options = LoggerOptionsBuilder::default().build();
+        let stderr = Stderr::new(WriteMode::Immediate);
+        let mut logger = Logger::new(&logger_options, stderr, &[]);
+
+        let cmd = client.curl_command_line(&request, &context_dir, None, &options, &mut logger);
+        assert_eq!(
+            cmd.to_string(),
+            "curl \
+         --header 'X-Timestamp: 1767225600' \
+         --header 'X-Signature: ac558c0ef7bb1714ae86246902dec683c2e4d482874269f08b18de4cf259e167' \
+         'https://example.org/hello?page=1'"
+        );
+    }
+
     #[test]
     fn parse_cert_option() {
         assert_eq!(parse_cert_password("foobar"), ("foobar".to_string(), None));
@@ -18,6 +18,7 @@
 use core::fmt;
 use std::collections::HashMap;
 use std::path::Path;
+use std::time::Duration;
 
 use hurl_core::types::Count;
 
@@ -25,11 +26,14 @@ use crate::runner::Output;
 use crate::util::path::ContextDir;
 
 use super::cookie_store::CookieStore;
-use super::header::{CONTENT_TYPE, Header, HeaderVec};
+use super::header::{CONTENT_TYPE, EXPECT, Header, HeaderVec, TRANSFER_ENCODING};
 use super::options::ClientOptions;
 use super::param::Param;
-use super::request::{CredentialForwarding, FollowLocation, IpResolve, RequestedHttpVersion};
-use super::request_spec::{Body, FileParam, Method, MultipartParam, RequestSpec};
+use super::request::{
+    ClientCertType, CredentialForwarding, FollowLocation, IpResolve, RequestedHttpVersion,
+};
+use super::request_spec::{Body, FileParam, Method, MultipartParam, NestedParam, RequestSpec};
+use super::resolve;
 
 /// Represents a curl command, with arguments.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -64,11 +68,29 @@ impl CurlCmd {
     ) -> Self {
         let mut args = vec!["curl".to_string()];
 
+        // curl doesn't support wildcard host mappings, we export the mapping of the requested host.
+        let options = &ClientOptions {
+            resolves: resolve::host_resolves(&options.resolves, &request_spec.url),
+            ..options.clone()
+        };
+
         let mut params = method_params(request_spec, options.follow_location);
         args.append(&mut params);
 
         let mut headers = request_spec.headers.clone();
         headers.extend(&options.headers);
+        // curl has no option to send trailers: a chunked body is only exported with its header.
+        if options.is_chunked() && !headers.contains_key(TRANSFER_ENCODING) {
+            headers.push(Header::new(TRANSFER_ENCODING, "chunked"));
+        }
+        let mut no_headers = options.no_headers.clone();
+        if !headers.contains_key(EXPECT) {
+            match options.expect_continue {
+                Some(true) => headers.push(Header::new(EXPECT, "100-continue")),
+                Some(false) => no_headers.push(EXPECT.to_string()),
+                None => {}
+            }
+        }
         // We need to filter added headers that have been explicitly removed by user
         headers.retain(|h| !options.no_headers.iter().any(|name| h.name_eq(name)));
         let mut params = headers_params(
@@ -78,7 +100,7 @@ impl CurlCmd {
         );
         args.append(&mut params);
 
-        let mut params = no_headers_params(&options.no_headers);
+        let mut params = no_headers_params(&no_headers);
         args.append(&mut params);
 
         let mut params = body_params(request_spec, context_dir);
@@ -101,7 +123,7 @@ impl CurlCmd {
 fn method_params(request_spec: &RequestSpec, follow_location: FollowLocation) -> Vec<String> {
     let has_body = !request_spec.multipart.is_empty()
         || !request_spec.form.is_empty()
-        || !request_spec.body.bytes().is_empty();
+        || !request_spec.body.is_empty();
     request_spec.method.curl_args(has_body, follow_location)
 }
 
@@ -130,7 +152,7 @@ fn headers_params(
             args.push("--header".to_string());
             args.push(format!("'{CONTENT_TYPE}: {content_type}'"));
         }
-    } else if !body.bytes().is_empty() {
+    } else if !body.is_empty() {
         match body {
             Body::Text(_) => {
                 args.push("--header".to_string());
@@ -140,7 +162,7 @@ fn headers_params(
                 args.push("--header".to_string());
                 args.push(format!("'{CONTENT_TYPE}: application/octet-stream'"));
             }
-            Body::File(_, _) => {
+            Body::File(_, _) | Body::Stdin => {
                 args.push("--header".to_string());
                 args.push(format!("'{CONTENT_TYPE}:'"));
             }
@@ -159,6 +181,22 @@ fn no_headers_params(no_headers: &[String]) -> Vec<String> {
     args
 }
 
+/// Pushes the curl args of multipart `params` to `args`.
+///
+/// The parts of a nested param are enclosed between the nested param arg `name=(` and a closing
+/// `=)` arg (see <https://everything.curl.dev/http/post/multipart.html>).
+fn multipart_args(params: &[MultipartParam], context_dir: &ContextDir, args: &mut Vec<String>) {
+    for param in params {
+        args.push("--form".to_string());
+        args.push(format!("'{}'", param.curl_arg(context_dir)));
+        if let MultipartParam::NestedParam(NestedParam { params, .. }) = param {
+            multipart_args(params, context_dir, args);
+            args.push("--form".to_string());
+            args.push("'=)'".to_string());
+        }
+    }
+}
+
 /// Returns the curl args corresponding to the request body, from a request spec.
 fn body_params(request_spec: &RequestSpec, context_dir: &ContextDir) -> Vec<String> {
     let mut args = vec![];
@@ -167,12 +205,9 @@ fn body_params(request_spec: &RequestSpec, context_dir: &ContextDir) -> Vec<Stri
         args.push("--data".to_string());
         args.push(format!("'{}'", param.curl_arg_escape()));
     }
-    for param in request_spec.multipart.iter() {
-        args.push("--form".to_string());
-        args.push(format!("'{}'", param.curl_arg(context_dir)));
-    }
+    multipart_args(&request_spec.multipart, context_dir, &mut args);
 
-    if request_spec.body.bytes().is_empty() {
+    if request_spec.body.is_empty() {
         return args;
     }
 
@@ -194,7 +229,7 @@ fn body_params(request_spec: &RequestSpec, context_dir: &ContextDir) -> Vec<Stri
     // In summary: if the payload is a file (@foo.bin), we must use --data-binary option in
     // order to curl to not process the data sent.
     let param = match request_spec.body {
-        Body::File(_, _) => "--data-binary",
+        Body::File(_, _) | Body::Stdin => "--data-binary",
         _ => "--data",
     };
     args.push(param.to_string());
@@ -379,11 +414,31 @@ impl MultipartParam {
             MultipartParam::FileParam(FileParam {
                 name,
                 filename,
+                part_filename,
                 content_type,
+                headers,
                 ..
             }) => {
                 let path = context_dir.resolved_path(Path::new(filename));
-                let value = format!("@{};type={}", path.to_string_lossy(), content_type);
+                let mut value = format!("@{};type={}", path.to_string_lossy(), content_type);
+                if let Some(part_filename) = part_filename {
+                    value.push_str(&format!(";filename=\"{part_filename}\""));
+                }
+                for header in headers {
+                    value.push_str(&format!(";headers=\"{header}\""));
+                }
+                format!("{name}={value}")
+            }
+            MultipartParam::NestedParam(NestedParam {
+                name,
+                content_type,
+                headers,
+                ..
+            }) => {
+                let mut value = format!("(;type={content_type}");
+                for header in headers {
+                    value.push_str(&format!(";headers=\"{header}\""));
+                }
                 format!("{name}={value}")
             }
         }
@@ -399,6 +454,7 @@ impl Body {
                 let path = context_dir.resolved_path(Path::new(filename));
                 format!("'@{}'", path.to_string_lossy())
             }
+            Body::Stdin => "'@-'".to_string(),
         }
     }
 }
@@ -420,6 +476,14 @@ impl ClientOptions {
             args.push("--cert".to_string());
             args.push(client_cert_file.clone());
         }
+        if self.client_cert_type != ClientCertType::default() {
+            args.push("--cert-type".to_string());
+            args.push(self.client_cert_type.to_string());
+        }
+        if let Some(ref client_cert_password) = self.client_cert_password {
+            args.push("--pass".to_string());
+            args.push(format!("'{client_cert_password}'"));
+        }
         if let Some(ref client_key_file) = self.client_key_file {
             args.push("--key".to_string());
             args.push(client_key_file.clone());
@@ -429,7 +493,7 @@ impl ClientOptions {
         }
         if self.connect_timeout != ClientOptions::default().connect_timeout {
             args.push("--connect-timeout".to_string());
-            args.push(self.connect_timeout.as_secs().to_string());
+            args.push(duration_secs(self.connect_timeout));
         }
         for connect in self.connects_to.iter() {
             args.push("--connect-to".to_string());
@@ -442,6 +506,14 @@ impl ClientOptions {
         if self.digest {
             args.push("--digest".to_string());
         }
+        if let Some(ref dns_servers) = self.dns_servers {
+            args.push("--dns-servers".to_string());
+            args.push(dns_servers.clone());
+        }
+        if let Some(filename) = &self.hsts_file {
+            args.push("--hsts".to_string());
+            args.push(format!("'{filename}'"));
+        }
         match self.http_version {
             RequestedHttpVersion::Default => {}
             RequestedHttpVersion::Http10 => args.push("--http1.0".to_string()),
@@ -486,7 +558,7 @@ impl ClientOptions {
         }
         if self.timeout != ClientOptions::default().timeout {
             args.push("--max-time".to_string());
-            args.push(self.timeout.as_secs().to_string());
+            args.push(duration_secs(self.timeout));
         }
         if self.negotiate {
             args.push("--negotiate".to_string());
@@ -501,6 +573,10 @@ impl ClientOptions {
         if self.netrc {
             args.push("--netrc".to_string());
         }
+        if let Some(ref no_proxy) = self.no_proxy {
+            args.push("--noproxy".to_string());
+            args.push(format!("'{no_proxy}'"));
+        }
         if self.ntlm {
             args.push("--ntlm".to_string());
         }
@@ -518,6 +594,10 @@ impl ClientOptions {
         for header in self.proxy_headers.iter() {
             args.append(&mut header.curl_args("--proxy-header"));
         }
+        if let Some(ref proxy_user) = self.proxy_user {
+            args.push("--proxy-user".to_string());
+            args.push(format!("'{proxy_user}'"));
+        }
         for resolve in self.resolves.iter() {
             args.push("--resolve".to_string());
             args.push(resolve.clone());
@@ -526,8 +606,16 @@ impl ClientOptions {
             args.push("--ssl-no-revoke".to_string());
         }
         if let Some(ref unix_socket) = self.unix_socket {
-            args.push("--unix-socket".to_string());
-            args.push(format!("'{unix_socket}'"));
+            match unix_socket.strip_prefix('@') {
+                Some(name) => {
+                    args.push("--abstract-unix-socket".to_string());
+                    args.push(format!("'{name}'"));
+                }
+                None => {
+                    args.push("--unix-socket".to_string());
+                    args.push(format!("'{unix_socket}'"));
+                }
+            }
         }
         if let Some(ref user) = self.user {
             args.push("--user".to_string());
@@ -541,6 +629,16 @@ impl ClientOptions {
     }
 }
 
+/// Returns a `duration` in seconds, as accepted by curl timeout options. curl supports decimal
+/// values, so sub-second durations (like `500ms`) are not truncated.
+fn duration_secs(duration: Duration) -> String {
+    if duration.subsec_nanos() == 0 {
+        duration.as_secs().to_string()
+    } else {
+        duration.as_secs_f64().to_string()
+    }
+}
+
 fn escape_url(s: &str) -> String {
     percent_encoding::percent_encode(s.as_bytes(), percent_encoding::NON_ALPHANUMERIC).to_string()
 }
@@ -701,16 +799,25 @@ mod tests {
             allow_reuse: true,
             aws_sigv4: None,
             cacert_file: None,
+            cancellation_token: None,
+            chunked: false,
             client_cert_file: None,
+            client_cert_password: None,
+            client_cert_type: ClientCertType::Pem,
             client_key_file: None,
             compressed: true,
             connect_timeout: Duration::from_secs(20),
             connects_to: vec!["example.com:443:host-47.example.com:443".to_string()],
             cookie_input_file: Some("cookie_file".to_string()),
             digest: false,
+            dns_servers: Some("1.1.1.1,8.8.8.8".to_string()),
+            expect_continue: None,
             follow_location: FollowLocation::Follow(CredentialForwarding::OnlyInitialHost),
             headers,
+            hmac_signature: None,
+            hsts_file: Some("/var/run/hsts.txt".to_string()),
             http_version: RequestedHttpVersion::Http10,
+            http3_fallback: false,
             insecure: true,
             ip_resolve: IpResolve::IpV6,
             max_filesize: None,
@@ -727,6 +834,7 @@ mod tests {
             pinned_pub_key: None,
             proxy: Some("localhost:3128".to_string()),
             proxy_headers,
+            proxy_user: Some("proxy:secret".to_string()),
             no_proxy: None,
             resolves: vec![
                 "foo.com:80:192.168.0.1".to_string(),
@@ -734,6 +842,7 @@ mod tests {
             ],
             ssl_no_revoke: false,
             timeout: Duration::from_secs(10),
+            trailers: HeaderVec::new(),
             unix_socket: Some("/var/run/example.sock".to_string()),
             use_cookie_store: true,
             user: Some("user:password".to_string()),
@@ -752,6 +861,8 @@ mod tests {
         --connect-timeout 20 \
         --connect-to example.com:443:host-47.example.com:443 \
         --cookie cookie_file \
+        --dns-servers 1.1.1.1,8.8.8.8 \
+        --hsts '/var/run/hsts.txt' \
         --http1.0 \
         --insecure \
         --ipv6 \
@@ -766,6 +877,7 @@ mod tests {
         --path-as-is \
         --proxy 'localhost:3128' \
         --proxy-header 'X-Foo: foo' \
+        --proxy-user 'proxy:secret' \
         --resolve foo.com:80:192.168.0.1 \
         --resolve bar.com:443:127.0.0.1 \
         --unix-socket '/var/run/example.sock' \
@@ -795,6 +907,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn abstract_unix_socket() {
+        let request = RequestSpec {
+            method: Method("GET".to_string()),
+            url: Url::from_str("http://localhost/hello").unwrap(),
+            ..Default::default()
+        };
+
+        let context_dir = ContextDir::default();
+        let cookies = CookieStore::new();
+        let options = ClientOptions {
+            unix_socket: Some("@docker".to_string()),
+            ..Default::default()
+        };
+        let output = None;
+
+        let cmd = CurlCmd::new(&request, &cookies, &context_dir, output.as_ref(), &options);
+        assert_eq!(
+            cmd.to_string(),
+            "curl --abstract-unix-socket 'docker' 'http://localhost/hello'"
+        );
+    }
+
+    #[test]
+    fn sub_second_timeouts_and_no_proxy() {
+        let request = RequestSpec {
+            method: Method("GET".to_string()),
+            url: Url::from_str("http://localhost:8000/hello").unwrap(),
+            ..Default::default()
+        };
+
+        let context_dir = ContextDir::default();
+        let cookies = CookieStore::new();
+        let options = ClientOptions {
+            connect_timeout: Duration::from_millis(1500),
+            no_proxy: Some("localhost,example.com".to_string()),
+            proxy: Some("localhost:3128".to_string()),
+            timeout: Duration::from_millis(500),
+            ..Default::default()
+        };
+
+        let cmd = CurlCmd::new(&request, &cookies, &context_dir, None, &options);
+        assert_eq!(
+            cmd.to_string(),
+            "curl \
+        --connect-timeout 1.5 \
+        --max-time 0.5 \
+        --noproxy 'localhost,example.com' \
+        --proxy 'localhost:3128' \
+        'http://localhost:8000/hello'"
+        );
+    }
+
     #[test]
     fn url_with_curl_glob() {
         let request = RequestSpec {
@@ -1013,6 +1178,112 @@ mod tests {
         );
     }
 
+    #[test]
+    fn post_stdin() {
+        let request = RequestSpec {
+            method: Method("POST".to_string()),
+            url: Url::from_str("http://localhost:8000/upload").unwrap(),
+            body: Body::Stdin,
+            ..Default::default()
+        };
+
+        let context_dir = ContextDir::default();
+        let cookie_store = CookieStore::new();
+        let options = ClientOptions::default();
+        let output = None;
+
+        let cmd = CurlCmd::new(
+            &request,
+            &cookie_store,
+            &context_dir,
+            output.as_ref(),
+            &options,
+        );
+        assert_eq!(
+            cmd.to_string(),
+            "curl \
+            --header 'Content-Type:' \
+            --data-binary '@-' \
+            'http://localhost:8000/upload'"
+        );
+    }
+
+    #[test]
+    fn post_chunked_with_trailers() {
+        let request = RequestSpec {
+            method: Method("POST".to_string()),
+            url: Url::from_str("http://localhost:8000/upload").unwrap(),
+            body: Body::Text("Hello".to_string()),
+            ..Default::default()
+        };
+
+        let context_dir = ContextDir::default();
+        let cookie_store = CookieStore::new();
+        let mut trailers = HeaderVec::new();
+        trailers.push(Header::new("X-Checksum", "8b1a9953"));
+        let options = ClientOptions {
+            trailers,
+            ..Default::default()
+        };
+        let output = None;
+
+        let cmd = CurlCmd::new(
+            &request,
+            &cookie_store,
+            &context_dir,
+            output.as_ref(),
+            &options,
+        );
+        assert_eq!(
+            cmd.to_string(),
+            "curl \
+            --header 'Transfer-Encoding: chunked' \
+            --header 'Content-Type:' \
+            --data 'Hello' \
+            'http://localhost:8000/upload'"
+        );
+    }
+
+    #[test]
+    fn post_expect_continue() {
+        let request = RequestSpec {
+            method: Method("POST".to_string()),
+            url: Url::from_str("http://localhost:8000/upload").unwrap(),
+            body: Body::Text("Hello".to_string()),
+            ..Default::default()
+        };
+
+        let context_dir = ContextDir::default();
+        let cookie_store = CookieStore::new();
+        let options = ClientOptions {
+            expect_continue: Some(true),
+            ..Default::default()
+        };
+        let cmd = CurlCmd::new(&request, &cookie_store, &context_dir, None, &options);
+        assert_eq!(
+            cmd.to_string(),
+            "curl \
+            --header 'Expect: 100-continue' \
+            --header 'Content-Type:' \
+            --data 'Hello' \
+            'http://localhost:8000/upload'"
+        );
+
+        let options = ClientOptions {
+            expect_continue: Some(false),
+            ..Default::default()
+        };
+        let cmd = CurlCmd::new(&request, &cookie_store, &context_dir, None, &options);
+        assert_eq!(
+            cmd.to_string(),
+            "curl \
+            --header 'Content-Type:' \
+            --header 'Expect:' \
+            --data 'Hello' \
+            'http://localhost:8000/upload'"
+        );
+    }
+
     #[test]
     fn test_encode_byte() {
         assert_eq!(encode_byte(1), "\\x01".to_string());
@@ -1070,6 +1341,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn multipart_param_curl_args() {
+        let context_dir = ContextDir::new(Path::new("/tmp"), Path::new("/tmp"));
+        assert_eq!(
+            MultipartParam::FileParam(FileParam {
+                name: "upload1".to_string(),
+                filename: "data.xml".to_string(),
+                part_filename: None,
+                data: vec![],
+                content_type: "text/xml".to_string(),
+                headers: vec![Header::new("Content-Transfer-Encoding", "binary")],
+            })
+            .curl_arg(&context_dir),
+            "upload1=@/tmp/data.xml;type=text/xml;headers=\"Content-Transfer-Encoding: binary\""
+                .to_string()
+        );
+        assert_eq!(
+            MultipartParam::FileParam(FileParam {
+                name: "upload2".to_string(),
+                filename: "data.txt".to_string(),
+                part_filename: Some("report.txt".to_string()),
+                data: vec![],
+                content_type: "text/plain; charset=iso-8859-1".to_string(),
+                headers: vec![],
+            })
+            .curl_arg(&context_dir),
+            "upload2=@/tmp/data.txt;type=text/plain; charset=iso-8859-1;filename=\"report.txt\""
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn multipart_nested_param_curl_args() {
+        let context_dir = ContextDir::new(Path::new("/tmp"), Path::new("/tmp"));
+        let params = vec![MultipartParam::NestedParam(NestedParam {
+            name: "parts".to_string(),
+            content_type: "multipart/mixed".to_string(),
+            headers: vec![Header::new("Content-ID", "<parts>")],
+            params: vec![MultipartParam::Param(Param::new("key1", "value1"))],
+        })];
+        let mut args = vec![];
+        multipart_args(&params, &context_dir, &mut args);
+        assert_eq!(
+            args,
+            vec![
+                "--form",
+                "'parts=(;type=multipart/mixed;headers=\"Content-ID: <parts>\"'",
+                "--form",
+                "'key1=value1'",
+                "--form",
+                "'=)'",
+            ]
+        );
+    }
+
     #[test]
     fn test_encode_body() {
         let current_dir = Path::new("/tmp");
@@ -15,13 +15,16 @@
  * limitations under the License.
  *
  */
-use std::ffi::{CStr, CString};
+use std::ffi::{CStr, CString, c_int, c_long, c_void};
 use std::ptr;
 use std::time::Duration;
 
 use curl::Error;
 use curl::easy::Easy;
-use curl_sys::{CURLINFO, CURLOPT_NETRC_FILE, curl_certinfo, curl_off_t, curl_slist};
+use curl_sys::{
+    CURLINFO, CURLOPT_NETRC_FILE, CURLOPTTYPE_FUNCTIONPOINT, CURLOPTTYPE_LONG,
+    CURLOPTTYPE_OBJECTPOINT, CURLoption, curl_certinfo, curl_off_t, curl_slist,
+};
 
 /// Some definitions not present in curl-sys
 const CURLINFO_OFF_T: CURLINFO = 0x600000;
@@ -34,6 +37,15 @@ const CURLINFO_STARTTRANSFER_TIME_T: CURLINFO = CURLINFO_OFF_T + 54;
 const CURLINFO_APPCONNECT_TIME_T: CURLINFO = CURLINFO_OFF_T + 56;
 const CURLINFO_CONN_ID: CURLINFO = CURLINFO_OFF_T + 64;
 
+const CURLOPT_HSTS_CTRL: CURLoption = CURLOPTTYPE_LONG + 299;
+const CURLOPT_HSTS: CURLoption = CURLOPTTYPE_OBJECTPOINT + 300;
+const CURLHSTS_ENABLE: c_long = 1;
+
+const CURLOPT_TRAILERFUNCTION: CURLoption = CURLOPTTYPE_FUNCTIONPOINT + 283;
+const CURLOPT_TRAILERDATA: CURLoption = CURLOPTTYPE_OBJECTPOINT + 284;
+const CURL_TRAILERFUNC_OK: c_int = 0;
+const CURL_TRAILERFUNC_ABORT: c_int = 1;
+
 /// Represents certificate information.
 /// `data` has format "name:content";
 #[derive(Clone)]
@@ -216,6 +228,66 @@ pub fn netrc_file(easy: &mut Easy, filename: &str) -> Result<(), Error> {
     })
 }
 
+/// Enables HSTS and uses `filename` as a persistent HSTS cache.
+///
+/// The cache is read before the first transfer and written back when the handle is closed.
+/// Corresponds to [`CURLOPT_HSTS_CTRL`] and [`CURLOPT_HSTS`].
+pub fn hsts_file(easy: &mut Easy, filename: &str) -> Result<(), Error> {
+    cvt(easy, unsafe {
+        curl_sys::curl_easy_setopt(easy.raw(), CURLOPT_HSTS_CTRL, CURLHSTS_ENABLE)
+    })?;
+    let filename = CString::new(filename)?;
+    cvt(easy, unsafe {
+        curl_sys::curl_easy_setopt(easy.raw(), CURLOPT_HSTS, filename.as_ptr())
+    })
+}
+
+/// Trailers sent after a chunked request body, as `name: value` lines.
+#[derive(Debug)]
+pub struct Trailers {
+    lines: Vec<CString>,
+}
+
+impl Trailers {
+    /// Creates trailers from `name: value` lines.
+    pub fn new(lines: &[String]) -> Result<Self, Error> {
+        let lines = lines
+            .iter()
+            .map(|line| CString::new(line.as_str()))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Trailers { lines })
+    }
+}
+
+/// Sends `trailers` after the last chunk of a chunked request body.
+///
+/// `trailers` are read by libcurl at the end of the transfer, so they must not be moved or dropped
+/// before the transfer is performed. Corresponds to [`CURLOPT_TRAILERFUNCTION`] and
+/// [`CURLOPT_TRAILERDATA`].
+pub fn trailers(easy: &mut Easy, trailers: &Trailers) -> Result<(), Error> {
+    let callback: extern "C" fn(*mut *mut curl_slist, *mut c_void) -> c_int = trailer_callback;
+    cvt(easy, unsafe {
+        curl_sys::curl_easy_setopt(easy.raw(), CURLOPT_TRAILERFUNCTION, callback)
+    })?;
+    let data = trailers as *const Trailers as *mut c_void;
+    cvt(easy, unsafe {
+        curl_sys::curl_easy_setopt(easy.raw(), CURLOPT_TRAILERDATA, data)
+    })
+}
+
+/// Appends the trailers given by `userdata` to the libcurl `list` of trailers.
+extern "C" fn trailer_callback(list: *mut *mut curl_slist, userdata: *mut c_void) -> c_int {
+    let trailers = unsafe { &*(userdata as *const Trailers) };
+    for line in &trailers.lines {
+        let new_list = unsafe { curl_sys::curl_slist_append(*list, line.as_ptr()) };
+        if new_list.is_null() {
+            return CURL_TRAILERFUNC_ABORT;
+        }
+        unsafe { *list = new_list };
+    }
+    CURL_TRAILERFUNC_OK
+}
+
 /// Converts an instance of libcurl linked list [`curl_slist`] to a vec of [`String`].
 fn to_list(slist: *mut curl_slist) -> Vec<String> {
     let mut data = vec![];
@@ -17,8 +17,15 @@
  */
 use super::request::RequestedHttpVersion;
 
+/// libcurl error for a failure in the HTTP/3 layer (`CURLE_HTTP3`).
+const CURLE_HTTP3: i32 = 95;
+/// libcurl error for a QUIC connection failure (`CURLE_QUIC_CONNECT_ERROR`).
+const CURLE_QUIC_CONNECT_ERROR: i32 = 96;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum HttpError {
+    /// The transfer has been aborted by a cancellation token.
+    Cancelled,
     CouldNotParseCookieExpires(String),
     CouldNotParseResponse,
     CouldNotUncompressResponse {
@@ -44,6 +51,10 @@ pub enum HttpError {
         description: String,
     },
     UnsupportedHttpVersion(RequestedHttpVersion),
+    /// Abstract Unix sockets are only available on Linux (socket name)
+    UnsupportedAbstractUnixSocket(String),
+    /// HMAC signature configuration is invalid (reason)
+    InvalidHmacSignature(String),
     /// Request URL is invalid (URL and reason)
     InvalidUrl(String, String),
     /// The maximum response size has been exceeded.
@@ -72,11 +83,13 @@ impl HttpError {
     pub fn description(&self) -> String {
         match self {
             HttpError::AllowedResponseSizeExceeded(_) => "HTTP connection".to_string(),
+            HttpError::Cancelled => "HTTP connection".to_string(),
             HttpError::CouldNotParseCookieExpires(_) => "HTTP connection".to_string(),
             HttpError::CouldNotParseResponse => "HTTP connection".to_string(),
             HttpError::CouldNotUncompressResponse { .. } => "Decompression error".to_string(),
             HttpError::InvalidCharset { .. } => "Invalid charset".to_string(),
             HttpError::InvalidDecoding { .. } => "Invalid decoding".to_string(),
+            HttpError::InvalidHmacSignature(_) => "Invalid HMAC signature".to_string(),
             HttpError::InvalidUrl(..) => "Invalid URL".to_string(),
             HttpError::Libcurl { .. } => "HTTP connection".to_string(),
             HttpError::LibcurlUnknownOption { .. } => "HTTP connection".to_string(),
@@ -84,6 +97,19 @@ impl HttpError {
             HttpError::TooManyRedirect => "HTTP connection".to_string(),
             HttpError::UnsupportedContentEncoding { .. } => "Decompression error".to_string(),
             HttpError::UnsupportedHttpVersion(_) => "Unsupported HTTP version".to_string(),
+            HttpError::UnsupportedAbstractUnixSocket(_) => "HTTP connection".to_string(),
+        }
+    }
+
+    /// Returns `true` if this error is a failure to negotiate HTTP/3: QUIC connection or HTTP/3
+    /// layer errors, or HTTP/3 not being supported by libcurl.
+    pub fn is_http3_failure(&self) -> bool {
+        match self {
+            HttpError::Libcurl { code, .. } => {
+                *code == CURLE_HTTP3 || *code == CURLE_QUIC_CONNECT_ERROR
+            }
+            HttpError::UnsupportedHttpVersion(version) => *version == RequestedHttpVersion::Http3,
+            _ => false,
         }
     }
 
@@ -92,6 +118,7 @@ impl HttpError {
             HttpError::AllowedResponseSizeExceeded(max_size) => {
                 format!("exceeded the maximum allowed file size ({max_size} bytes)")
             }
+            HttpError::Cancelled => "the transfer has been cancelled".to_string(),
             HttpError::CouldNotParseCookieExpires(value) => {
                 format!("could not parse Cookie Expires attribute value <{value}>")
             }
@@ -105,6 +132,9 @@ impl HttpError {
             HttpError::InvalidDecoding { charset } => {
                 format!("could not decode response body with charset '{charset}'")
             }
+            HttpError::InvalidHmacSignature(reason) => {
+                format!("invalid hmac-signature option ({reason})")
+            }
             HttpError::InvalidUrl(url, reason) => {
                 format!("invalid URL <{url}> ({reason})").to_string()
             }
@@ -121,6 +151,9 @@ impl HttpError {
             HttpError::UnsupportedContentEncoding { description } => {
                 format!("compression {description} is not supported").to_string()
             }
+            HttpError::UnsupportedAbstractUnixSocket(socket) => {
+                format!("abstract Unix socket <{socket}> is only supported on Linux")
+            }
         }
     }
 }
@@ -30,10 +30,16 @@ pub const CONTENT_ENCODING: &str = "Content-Encoding";
 pub const CONTENT_TYPE: &str = "Content-Type";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Expect>
 pub const EXPECT: &str = "Expect";
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Link>
+pub const LINK: &str = "Link";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Location>
 pub const LOCATION: &str = "Location";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Set-Cookie>
 pub const SET_COOKIE: &str = "Set-Cookie";
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Trailer>
+pub const TRAILER: &str = "Trailer";
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Transfer-Encoding>
+pub const TRANSFER_ENCODING: &str = "Transfer-Encoding";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/User-Agent>
 pub const USER_AGENT: &str = "User-Agent";
 
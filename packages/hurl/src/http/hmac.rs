@@ -0,0 +1,308 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! HMAC request signature (see <https://datatracker.ietf.org/doc/html/rfc2104>).
+//!
+//! A signature is configured with a string `ALGORITHM:HEADER:SECRET:TEMPLATE`, for instance
+//! `sha256:X-Signature:s3cr3t:{method}\n{path}\n{body_sha256}`. The template is rendered with the
+//! request about to be sent to build a canonical string, which is signed with the secret. The
+//! signature is then added to the request in the header `HEADER`.
+use std::str::FromStr;
+
+use base64::Engine;
+use base64::engine::general_purpose;
+use sha2::Digest;
+
+use super::header::{Header, HeaderVec};
+use super::request_spec::Method;
+
+/// Hash function used to compute an HMAC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HmacAlgorithm {
+    Sha256,
+    Sha512,
+}
+
+/// Encoding of the signature in the HTTP header.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignatureEncoding {
+    Base64,
+    Hex,
+}
+
+/// Placeholders supported in a canonical string template.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Literal(String),
+    Method,
+    Url,
+    Path,
+    Query,
+    Body,
+    BodySha256,
+    Header(String),
+}
+
+/// An HMAC signature configuration.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HmacSignature {
+    algorithm: HmacAlgorithm,
+    encoding: SignatureEncoding,
+    header: String,
+    secret: String,
+    template: Vec<Token>,
+}
+
+impl FromStr for HmacSignature {
+    type Err = String;
+
+    /// Parses an HMAC signature configuration `ALGORITHM:HEADER:SECRET:TEMPLATE`.
+    ///
+    /// `ALGORITHM` is one of `sha256`, `sha512`, `sha256-base64` or `sha512-base64` (signatures
+    /// are hex encoded by default).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(4, ':');
+        let (Some(algorithm), Some(header), Some(secret), Some(template)) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err("expecting ALGORITHM:HEADER:SECRET:TEMPLATE".to_string());
+        };
+        let (algorithm, encoding) = match algorithm {
+            "sha256" => (HmacAlgorithm::Sha256, SignatureEncoding::Hex),
+            "sha512" => (HmacAlgorithm::Sha512, SignatureEncoding::Hex),
+            "sha256-base64" => (HmacAlgorithm::Sha256, SignatureEncoding::Base64),
+            "sha512-base64" => (HmacAlgorithm::Sha512, SignatureEncoding::Base64),
+            _ => {
+                return Err(format!(
+                    "algorithm <{algorithm}> is not supported, expecting sha256, sha512, sha256-base64 or sha512-base64"
+                ));
+            }
+        };
+        let header = header.trim();
+        if header.is_empty() {
+            return Err("header name can not be empty".to_string());
+        }
+        Ok(HmacSignature {
+            algorithm,
+            encoding,
+            header: header.to_string(),
+            secret: secret.to_string(),
+            template: parse_template(template)?,
+        })
+    }
+}
+
+impl HmacSignature {
+    /// Returns the canonical string of a request, built from the template of this signature.
+    ///
+    /// `url` is the URL effectively requested (including query string parameters), `headers` the
+    /// request headers and `body` the request body bytes.
+    pub fn canonical_string(
+        &self,
+        method: &Method,
+        url: &str,
+        headers: &HeaderVec,
+        body: &[u8],
+    ) -> String {
+        let parsed_url = url::Url::parse(url).ok();
+        let path = parsed_url.as_ref().map(|u| u.path()).unwrap_or_default();
+        let query = parsed_url
+            .as_ref()
+            .and_then(|u| u.query())
+            .unwrap_or_default();
+        let mut s = String::new();
+        for token in &self.template {
+            match token {
+                Token::Literal(value) => s.push_str(value),
+                Token::Method => s.push_str(&method.0),
+                Token::Url => s.push_str(url),
+                Token::Path => s.push_str(path),
+                Token::Query => s.push_str(query),
+                Token::Body => s.push_str(&String::from_utf8_lossy(body)),
+                Token::BodySha256 => s.push_str(&to_hex(&sha2::Sha256::digest(body))),
+                Token::Header(name) => {
+                    let values = headers.values(name);
+                    s.push_str(&values.join(","));
+                }
+            }
+        }
+        s
+    }
+
+    /// Signs this `canonical_string` and returns the HTTP header holding the signature.
+    pub fn sign(&self, canonical_string: &str) -> Header {
+        let key = self.secret.as_bytes();
+        let message = canonical_string.as_bytes();
+        let mac = match self.algorithm {
+            HmacAlgorithm::Sha256 => hmac::<sha2::Sha256>(key, message, 64),
+            HmacAlgorithm::Sha512 => hmac::<sha2::Sha512>(key, message, 128),
+        };
+        let value = match self.encoding {
+            SignatureEncoding::Base64 => general_purpose::STANDARD.encode(mac),
+            SignatureEncoding::Hex => to_hex(&mac),
+        };
+        Header::new(&self.header, &value)
+    }
+}
+
+/// Parses a canonical string template, made of literal text and `{name}` placeholders.
+fn parse_template(template: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = vec![];
+    let mut literal = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        literal.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('}') else {
+            return Err(format!("missing closing brace in template <{template}>"));
+        };
+        let name = &rest[start + 1..start + end];
+        let token = match name {
+            "method" => Token::Method,
+            "url" => Token::Url,
+            "path" => Token::Path,
+            "query" => Token::Query,
+            "body" => Token::Body,
+            "body_sha256" => Token::BodySha256,
+            _ => match name.strip_prefix("header:") {
+                Some(header) if !header.is_empty() => Token::Header(header.to_string()),
+                _ => return Err(format!("unknown placeholder <{{{name}}}> in template")),
+            },
+        };
+        if !literal.is_empty() {
+            tokens.push(Token::Literal(std::mem::take(&mut literal)));
+        }
+        tokens.push(token);
+        rest = &rest[start + end + 1..];
+    }
+    literal.push_str(rest);
+    if !literal.is_empty() {
+        tokens.push(Token::Literal(literal));
+    }
+    Ok(tokens)
+}
+
+/// Computes the HMAC of `message` with this `key`, using the hash function `D` of `block_size`
+/// bytes.
+fn hmac<D: Digest>(key: &[u8], message: &[u8], block_size: usize) -> Vec<u8> {
+    let mut block = if key.len() > block_size {
+        D::digest(key).to_vec()
+    } else {
+        key.to_vec()
+    };
+    block.resize(block_size, 0);
+
+    let mut inner = D::new();
+    inner.update(block.iter().map(|b| b ^ 0x36).collect::<Vec<_>>());
+    inner.update(message);
+    let inner = inner.finalize();
+
+    let mut outer = D::new();
+    outer.update(block.iter().map(|b| b ^ 0x5c).collect::<Vec<_>>());
+    outer.update(inner);
+    outer.finalize().to_vec()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hmac_signature() {
+        let signature =
+            HmacSignature::from_str("sha256:X-Signature:secret:{method} {path}\n{header:Date}")
+                .unwrap();
+        assert_eq!(signature.algorithm, HmacAlgorithm::Sha256);
+        assert_eq!(signature.encoding, SignatureEncoding::Hex);
+        assert_eq!(signature.header, "X-Signature");
+        assert_eq!(signature.secret, "secret");
+        assert_eq!(
+            signature.template,
+            vec![
+                Token::Method,
+                Token::Literal(" ".to_string()),
+                Token::Path,
+                Token::Literal("\n".to_string()),
+                Token::Header("Date".to_string()),
+            ]
+        );
+
+        assert_eq!(
+            HmacSignature::from_str("md5:X-Signature:secret:{body}").unwrap_err(),
+            "algorithm <md5> is not supported, expecting sha256, sha512, sha256-base64 or sha512-base64"
+        );
+        assert_eq!(
+            HmacSignature::from_str("sha256:X-Signature:secret").unwrap_err(),
+            "expecting ALGORITHM:HEADER:SECRET:TEMPLATE"
+        );
+        assert_eq!(
+            HmacSignature::from_str("sha256:X-Signature:secret:{foo}").unwrap_err(),
+            "unknown placeholder <{foo}> in template"
+        );
+    }
+
+    #[test]
+    fn test_canonical_string() {
+        let signature = HmacSignature::from_str(
+            "sha256:X-Signature:secret:{method}\n{path}\n{query}\n{header:X-Date}\n{body}",
+        )
+        .unwrap();
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("X-Date", "20260101"));
+        assert_eq!(
+            signature.canonical_string(
+                &Method("POST".to_string()),
+                "https://example.org/api/users?page=2",
+                &headers,
+                b"{\"name\":\"Bob\"}",
+            ),
+            "POST\n/api/users\npage=2\n20260101\n{\"name\":\"Bob\"}"
+        );
+    }
+
+    #[test]
+    fn test_sign() {
+        // Test vectors from <https://datatracker.ietf.org/doc/html/rfc4231#section-4.3>
+        let signature = HmacSignature::from_str("sha256:X-Signature:Jefe:{body}").unwrap();
+        assert_eq!(
+            signature.sign("what do ya want for nothing?"),
+            Header::new(
+                "X-Signature",
+                "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+            )
+        );
+        let signature = HmacSignature::from_str("sha512:X-Signature:Jefe:{body}").unwrap();
+        assert_eq!(
+            signature.sign("what do ya want for nothing?"),
+            Header::new(
+                "X-Signature",
+                "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea2505549758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737"
+            )
+        );
+        let signature = HmacSignature::from_str("sha256-base64:X-Signature:Jefe:{body}").unwrap();
+        assert_eq!(
+            signature.sign("what do ya want for nothing?"),
+            Header::new(
+                "X-Signature",
+                "W9zBRr9gdU5qBCQmCJV1x1oAPwidJzmDnexYuWTsOEM="
+            )
+        );
+    }
+}
@@ -67,6 +67,20 @@ pub fn charset(mime_type: &str) -> Option<String> {
     None
 }
 
+/// Extracts the CSV `header` parameter (`present` or `absent`) from mime-type String.
+///
+/// See <https://www.rfc-editor.org/rfc/rfc4180#section-3>
+pub fn csv_header(mime_type: &str) -> Option<String> {
+    let parts = mime_type.trim().split(';');
+    for part in parts {
+        let param = part.trim().split('=').collect::<Vec<_>>();
+        if param.len() == 2 && param[0].trim().eq_ignore_ascii_case("header") {
+            return Some(param[1].trim().to_lowercase());
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -96,6 +110,19 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_csv_header() {
+        assert_eq!(csv_header("text/csv"), None);
+        assert_eq!(
+            csv_header("text/csv; charset=utf-8; header=present"),
+            Some("present".to_string())
+        );
+        assert_eq!(
+            csv_header("text/csv; HEADER=Absent"),
+            Some("absent".to_string())
+        );
+    }
+
     // Dataset for mimetypes issued from <https://www.iana.org/assignments/media-types/media-types.xhtml#application>
 
     #[test]
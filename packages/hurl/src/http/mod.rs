@@ -26,16 +26,20 @@ pub use self::cookie_store::{Cookie, CookieStore};
 pub use self::curl_cmd::CurlCmd;
 pub(crate) use self::error::HttpError;
 pub use self::header::{
-    ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, COOKIE, EXPECT, Header, HeaderVec, USER_AGENT,
+    ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, COOKIE, EXPECT, Header, HeaderVec, LOCATION,
+    USER_AGENT,
 };
+pub(crate) use self::multipart::encode_multipart;
 pub(crate) use self::options::{ClientOptions, Verbosity};
 pub(crate) use self::param::Param;
 pub use self::request::{
-    CredentialForwarding, FollowLocation, IpResolve, Request, RequestedHttpVersion,
+    ClientCertType, CredentialForwarding, FollowLocation, IpResolve, Request, RequestedHttpVersion,
 };
 pub(crate) use self::request_cookie::RequestCookie;
-pub(crate) use self::request_spec::{Body, FileParam, Method, MultipartParam, RequestSpec};
-pub use self::response::{HttpVersion, Response};
+pub(crate) use self::request_spec::{
+    Body, FileParam, Method, MultipartParam, NestedParam, RequestSpec,
+};
+pub use self::response::{HttpVersion, InterimResponse, Response};
 pub use self::response_cookie::{CookieAttribute, ResponseCookie};
 #[cfg(test)]
 pub use self::tests::*;
@@ -53,13 +57,17 @@ mod easy_ext;
 mod error;
 mod header;
 mod headers_helper;
+mod hmac;
 mod ip;
-mod mimetype;
+pub(crate) mod mimetype;
+mod multipart;
 mod options;
 mod param;
 mod request;
 mod request_cookie;
 mod request_spec;
+mod request_spec_debug;
+mod resolve;
 mod response;
 mod response_cookie;
 mod response_debug;
@@ -0,0 +1,177 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use super::header::CONTENT_TYPE;
+use super::param::Param;
+use super::request_spec::{FileParam, MultipartParam, NestedParam};
+
+/// Encodes a list of multipart form data `params` to a body, using `boundary` as the delimiter
+/// of each part (see <https://datatracker.ietf.org/doc/html/rfc7578>).
+///
+/// libcurl always generates a random boundary, so this encoding is used when the boundary is
+/// explicitly set by the user, and for the body of nested parts. A nested part is delimited by
+/// a boundary derived from `boundary` and its index, that never contains the `boundary` delimiter.
+pub fn encode_multipart(params: &[MultipartParam], boundary: &str) -> Vec<u8> {
+    let mut data = vec![];
+    for (index, param) in params.iter().enumerate() {
+        data.extend_from_slice(format!("--{boundary}\r\n").as_bytes());
+        match param {
+            MultipartParam::Param(Param { name, value }) => {
+                let name = escape_field(name);
+                data.extend_from_slice(
+                    format!("Content-Disposition: form-data; name=\"{name}\"\r\n\r\n").as_bytes(),
+                );
+                data.extend_from_slice(value.as_bytes());
+            }
+            MultipartParam::FileParam(FileParam {
+                name,
+                filename,
+                part_filename,
+                data: file_data,
+                content_type,
+                headers,
+            }) => {
+                let name = escape_field(name);
+                let filename = escape_field(part_filename.as_ref().unwrap_or(filename));
+                data.extend_from_slice(
+                    format!(
+                        "Content-Disposition: form-data; name=\"{name}\"; filename=\"{filename}\"\r\n"
+                    )
+                    .as_bytes(),
+                );
+                data.extend_from_slice(format!("{CONTENT_TYPE}: {content_type}\r\n").as_bytes());
+                for header in headers {
+                    data.extend_from_slice(format!("{header}\r\n").as_bytes());
+                }
+                data.extend_from_slice(b"\r\n");
+                data.extend_from_slice(file_data);
+            }
+            MultipartParam::NestedParam(NestedParam {
+                name,
+                content_type,
+                headers,
+                params,
+            }) => {
+                let name = escape_field(name);
+                let nested_boundary = format!("{}_{boundary}", index + 1);
+                data.extend_from_slice(
+                    format!("Content-Disposition: form-data; name=\"{name}\"\r\n").as_bytes(),
+                );
+                data.extend_from_slice(
+                    format!("{CONTENT_TYPE}: {content_type}; boundary={nested_boundary}\r\n")
+                        .as_bytes(),
+                );
+                for header in headers {
+                    data.extend_from_slice(format!("{header}\r\n").as_bytes());
+                }
+                data.extend_from_slice(b"\r\n");
+                data.extend_from_slice(&encode_multipart(params, &nested_boundary));
+            }
+        }
+        data.extend_from_slice(b"\r\n");
+    }
+    data.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+    data
+}
+
+/// Escapes a name or a filename of a `Content-Disposition` header, the same way as libcurl does.
+fn escape_field(value: &str) -> String {
+    value
+        .replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::Header;
+
+    #[test]
+    fn test_encode_multipart() {
+        let params = vec![
+            MultipartParam::Param(Param::new("key1", "value1")),
+            MultipartParam::FileParam(FileParam {
+                name: "upload1".to_string(),
+                filename: "data.xml".to_string(),
+                part_filename: None,
+                data: b"<a/>".to_vec(),
+                content_type: "text/xml".to_string(),
+                headers: vec![Header::new("Content-Transfer-Encoding", "binary")],
+            }),
+        ];
+        assert_eq!(
+            String::from_utf8(encode_multipart(&params, "MyBoundary")).unwrap(),
+            "--MyBoundary\r\n\
+             Content-Disposition: form-data; name=\"key1\"\r\n\
+             \r\n\
+             value1\r\n\
+             --MyBoundary\r\n\
+             Content-Disposition: form-data; name=\"upload1\"; filename=\"data.xml\"\r\n\
+             Content-Type: text/xml\r\n\
+             Content-Transfer-Encoding: binary\r\n\
+             \r\n\
+             <a/>\r\n\
+             --MyBoundary--\r\n"
+        );
+    }
+
+    #[test]
+    fn test_encode_multipart_nested() {
+        let params = vec![
+            MultipartParam::Param(Param::new("key1", "value1")),
+            MultipartParam::NestedParam(NestedParam {
+                name: "parts".to_string(),
+                content_type: "multipart/mixed".to_string(),
+                headers: vec![Header::new("Content-ID", "<parts>")],
+                params: vec![MultipartParam::FileParam(FileParam {
+                    name: "file1".to_string(),
+                    filename: "a.txt".to_string(),
+                    part_filename: None,
+                    data: b"Hello".to_vec(),
+                    content_type: "text/plain".to_string(),
+                    headers: vec![],
+                })],
+            }),
+        ];
+        assert_eq!(
+            String::from_utf8(encode_multipart(&params, "MyBoundary")).unwrap(),
+            "--MyBoundary\r\n\
+             Content-Disposition: form-data; name=\"key1\"\r\n\
+             \r\n\
+             value1\r\n\
+             --MyBoundary\r\n\
+             Content-Disposition: form-data; name=\"parts\"\r\n\
+             Content-Type: multipart/mixed; boundary=2_MyBoundary\r\n\
+             Content-ID: <parts>\r\n\
+             \r\n\
+             --2_MyBoundary\r\n\
+             Content-Disposition: form-data; name=\"file1\"; filename=\"a.txt\"\r\n\
+             Content-Type: text/plain\r\n\
+             \r\n\
+             Hello\r\n\
+             --2_MyBoundary--\r\n\
+             \r\n\
+             --MyBoundary--\r\n"
+        );
+    }
+
+    #[test]
+    fn test_escape_field() {
+        assert_eq!(escape_field("a\"b\r\nc"), "a%22b%0D%0Ac");
+    }
+}
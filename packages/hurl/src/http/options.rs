@@ -20,7 +20,8 @@ use std::time::Duration;
 use hurl_core::types::{BytesPerSec, Count};
 
 use super::header::HeaderVec;
-use super::request::{FollowLocation, IpResolve, RequestedHttpVersion};
+use super::request::{ClientCertType, FollowLocation, IpResolve, RequestedHttpVersion};
+use crate::util::cancel::CancellationToken;
 
 #[derive(Debug, Clone)]
 pub struct ClientOptions {
@@ -31,16 +32,34 @@ pub struct ClientOptions {
     pub allow_reuse: bool,
     pub aws_sigv4: Option<String>,
     pub cacert_file: Option<String>,
+    /// Token aborting the current transfer when cancelled.
+    pub cancellation_token: Option<CancellationToken>,
+    /// Sends the request body with a chunked transfer encoding (implied by `trailers`).
+    pub chunked: bool,
     pub client_cert_file: Option<String>,
+    /// Password of the client certificate (or of its private key).
+    pub client_cert_password: Option<String>,
+    pub client_cert_type: ClientCertType,
     pub client_key_file: Option<String>,
     pub compressed: bool,
     pub connect_timeout: Duration,
     pub connects_to: Vec<String>,
     pub cookie_input_file: Option<String>,
     pub digest: bool,
+    /// Comma separated list of DNS servers, see <https://curl.se/libcurl/c/CURLOPT_DNS_SERVERS.html>.
+    pub dns_servers: Option<String>,
+    /// Forces (`true`) or suppresses (`false`) the `Expect: 100-continue` request header. By
+    /// default, the header added by libcurl for large bodies is removed.
+    pub expect_continue: Option<bool>,
     pub follow_location: FollowLocation,
     pub headers: HeaderVec,
+    /// HMAC signature configuration `ALGORITHM:HEADER:SECRET:TEMPLATE`, used to sign each request.
+    pub hmac_signature: Option<String>,
+    /// HSTS cache file, read and updated by libcurl, see <https://curl.se/libcurl/c/CURLOPT_HSTS.html>.
+    pub hsts_file: Option<String>,
     pub http_version: RequestedHttpVersion,
+    /// Retries the request over HTTP/2 when HTTP/3 (QUIC) can not be negotiated.
+    pub http3_fallback: bool,
     pub insecure: bool,
     pub ip_resolve: IpResolve,
     pub max_filesize: Option<u64>,
@@ -58,9 +77,12 @@ pub struct ClientOptions {
     pub pinned_pub_key: Option<String>,
     pub proxy: Option<String>,
     pub proxy_headers: HeaderVec,
+    pub proxy_user: Option<String>,
     pub resolves: Vec<String>,
     pub ssl_no_revoke: bool,
     pub timeout: Duration,
+    /// Trailers sent after a chunked request body, see <https://curl.se/libcurl/c/CURLOPT_TRAILERFUNCTION.html>.
+    pub trailers: HeaderVec,
     pub unix_socket: Option<String>,
     pub use_cookie_store: bool,
     pub user: Option<String>,
@@ -68,6 +90,14 @@ pub struct ClientOptions {
     pub verbosity: Option<Verbosity>,
 }
 
+impl ClientOptions {
+    /// Returns `true` if the request body is sent with a chunked transfer encoding, trailers being
+    /// only sent after a chunked body.
+    pub fn is_chunked(&self) -> bool {
+        self.chunked || !self.trailers.is_empty()
+    }
+}
+
 // FIXME/ we could implement copy here
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Verbosity {
@@ -82,16 +112,25 @@ impl Default for ClientOptions {
             allow_reuse: true,
             aws_sigv4: None,
             cacert_file: None,
+            cancellation_token: None,
+            chunked: false,
             client_cert_file: None,
+            client_cert_password: None,
+            client_cert_type: ClientCertType::default(),
             client_key_file: None,
             compressed: false,
             connect_timeout: Duration::from_secs(300),
             connects_to: Vec::new(),
             cookie_input_file: None,
             digest: false,
+            dns_servers: None,
+            expect_continue: None,
             follow_location: FollowLocation::default(),
             headers: HeaderVec::new(),
+            hmac_signature: None,
+            hsts_file: None,
             http_version: RequestedHttpVersion::default(),
+            http3_fallback: false,
             insecure: false,
             ip_resolve: IpResolve::default(),
             max_filesize: None,
@@ -109,9 +148,11 @@ impl Default for ClientOptions {
             pinned_pub_key: None,
             proxy: None,
             proxy_headers: HeaderVec::new(),
+            proxy_user: None,
             resolves: Vec::new(),
             ssl_no_revoke: false,
             timeout: Duration::from_secs(300),
+            trailers: HeaderVec::new(),
             unix_socket: None,
             use_cookie_store: true,
             user: None,
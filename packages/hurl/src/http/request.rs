@@ -71,6 +71,27 @@ pub enum IpResolve {
     IpV6,
 }
 
+/// Format of a client certificate file.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum ClientCertType {
+    #[default]
+    Pem,
+    Der,
+    /// PKCS#12 bundle, holding the certificate and its private key.
+    P12,
+}
+
+impl fmt::Display for ClientCertType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match self {
+            ClientCertType::Pem => "PEM",
+            ClientCertType::Der => "DER",
+            ClientCertType::P12 => "P12",
+        };
+        write!(f, "{value}")
+    }
+}
+
 /// Do the HTTP client follow redirection, or not?
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
 pub enum FollowLocation {
@@ -17,7 +17,7 @@
  */
 use core::fmt;
 
-use super::header::HeaderVec;
+use super::header::{Header, HeaderVec};
 use super::param::Param;
 use super::request_cookie::RequestCookie;
 use super::url::Url;
@@ -64,14 +64,30 @@ pub struct Method(pub String);
 pub enum MultipartParam {
     Param(Param),
     FileParam(FileParam),
+    NestedParam(NestedParam),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct FileParam {
     pub name: String,
     pub filename: String,
+    /// Filename sent in the part `Content-Disposition` header, instead of `filename`.
+    pub part_filename: Option<String>,
     pub data: Vec<u8>,
     pub content_type: String,
+    /// Custom headers of this part (like `Content-Transfer-Encoding`).
+    pub headers: Vec<Header>,
+}
+
+/// A part which is itself a multipart body (like `multipart/mixed`), made of nested `params`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NestedParam {
+    pub name: String,
+    /// Content type of the part (like `multipart/mixed`), without its boundary.
+    pub content_type: String,
+    /// Custom headers of this part (like `Content-ID`).
+    pub headers: Vec<Header>,
+    pub params: Vec<MultipartParam>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -79,14 +95,29 @@ pub enum Body {
     Text(String),
     Binary(Vec<u8>),
     File(Vec<u8>, String),
+    /// Body read from the standard input, and streamed while the request is sent.
+    Stdin,
 }
 
 impl Body {
+    /// Returns the bytes of this body. As a streamed body is not materialized, the bytes of a
+    /// [`Body::Stdin`] body are empty.
     pub fn bytes(&self) -> Vec<u8> {
         match self {
             Body::Text(s) => s.as_bytes().to_vec(),
             Body::Binary(bs) => bs.clone(),
             Body::File(bs, _) => bs.clone(),
+            Body::Stdin => vec![],
+        }
+    }
+
+    /// Returns `true` if there is no data to send for this body.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Body::Text(s) => s.is_empty(),
+            Body::Binary(bs) => bs.is_empty(),
+            Body::File(bs, _) => bs.is_empty(),
+            Body::Stdin => false,
         }
     }
 }
@@ -102,16 +133,39 @@ impl fmt::Display for MultipartParam {
         match self {
             MultipartParam::Param(param) => write!(f, "{param}"),
             MultipartParam::FileParam(param) => write!(f, "{param}"),
+            MultipartParam::NestedParam(param) => write!(f, "{param}"),
         }
     }
 }
 
+impl fmt::Display for NestedParam {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.name, self.content_type)?;
+        for header in &self.headers {
+            write!(f, "; {header}")?;
+        }
+        for param in &self.params {
+            for line in param.to_string().lines() {
+                write!(f, "\n  {line}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
 impl fmt::Display for FileParam {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
             "{}: file,{}; {}",
             self.name, self.filename, self.content_type
-        )
+        )?;
+        if let Some(part_filename) = &self.part_filename {
+            write!(f, "; filename={part_filename}")?;
+        }
+        for header in &self.headers {
+            write!(f, "; {header}")?;
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,227 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::text::{Style, StyledString};
+
+use super::header::Header;
+use super::request_spec::{MultipartParam, RequestSpec};
+
+impl RequestSpec {
+    /// Returns this request spec as Hurl lines, styled like the Hurl term formatter.
+    ///
+    /// Lines are built from the request spec itself, not by parsing its textual representation:
+    /// some parts (like cookies) are not valid Hurl once evaluated and couldn't be highlighted.
+    pub fn hurl_lines(&self) -> Vec<StyledString> {
+        let mut lines = vec![];
+
+        let mut line = StyledString::new();
+        line.push_with(&self.method.to_string(), Style::new().yellow());
+        line.push(" ");
+        line.push_with(&self.url.raw(), Style::new().green());
+        lines.push(line);
+
+        for header in &self.headers {
+            lines.push(key_value_line("", &header.name, ": ", &header.value));
+        }
+        if !self.querystring.is_empty() {
+            lines.push(section_line("[Query]"));
+            for param in &self.querystring {
+                lines.push(key_value_line("", &param.name, ": ", &param.value));
+            }
+        }
+        if !self.form.is_empty() {
+            lines.push(section_line("[Form]"));
+            for param in &self.form {
+                lines.push(key_value_line("", &param.name, ": ", &param.value));
+            }
+        }
+        if !self.multipart.is_empty() {
+            lines.push(section_line("[Multipart]"));
+            for param in &self.multipart {
+                push_multipart_lines(param, "", &mut lines);
+            }
+        }
+        if !self.cookies.is_empty() {
+            lines.push(section_line("[Cookies]"));
+            for cookie in &self.cookies {
+                lines.push(key_value_line("", &cookie.name, "=", &cookie.value));
+            }
+        }
+        lines
+    }
+}
+
+fn section_line(name: &str) -> StyledString {
+    let mut line = StyledString::new();
+    line.push_with(name, Style::new().magenta());
+    line
+}
+
+fn key_value_line(indent: &str, key: &str, separator: &str, value: &str) -> StyledString {
+    let mut line = StyledString::new();
+    line.push(indent);
+    line.push_with(key, Style::new().green());
+    line.push(separator);
+    line.push_with(value, Style::new().green());
+    line
+}
+
+/// Appends the part `headers` to `line`, each one prefixed by `; `.
+fn push_part_headers(headers: &[Header], line: &mut StyledString) {
+    for header in headers {
+        line.push("; ");
+        line.push_with(&header.name, Style::new().green());
+        line.push(": ");
+        line.push_with(&header.value, Style::new().green());
+    }
+}
+
+/// Appends the lines of a multipart `param` to `lines`, nested parts being indented.
+fn push_multipart_lines(param: &MultipartParam, indent: &str, lines: &mut Vec<StyledString>) {
+    match param {
+        MultipartParam::Param(param) => {
+            lines.push(key_value_line(indent, &param.name, ": ", &param.value));
+        }
+        MultipartParam::FileParam(param) => {
+            let mut line = StyledString::new();
+            line.push(indent);
+            line.push_with(&param.name, Style::new().green());
+            line.push(": file,");
+            line.push_with(&param.filename, Style::new().green());
+            line.push("; ");
+            line.push_with(&param.content_type, Style::new().green());
+            if let Some(part_filename) = &param.part_filename {
+                line.push("; filename=");
+                line.push_with(part_filename, Style::new().green());
+            }
+            push_part_headers(&param.headers, &mut line);
+            lines.push(line);
+        }
+        MultipartParam::NestedParam(param) => {
+            let mut line = StyledString::new();
+            line.push(indent);
+            line.push_with(&param.name, Style::new().green());
+            line.push(": ");
+            line.push_with(&param.content_type, Style::new().green());
+            push_part_headers(&param.headers, &mut line);
+            lines.push(line);
+            let indent = format!("{indent}  ");
+            for param in &param.params {
+                push_multipart_lines(param, &indent, lines);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::text::Format;
+
+    use super::*;
+    use crate::http::{FileParam, HeaderVec, Method, NestedParam, Param, RequestCookie};
+
+    fn request_spec() -> RequestSpec {
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("User-Agent", "hurl"));
+        RequestSpec {
+            method: Method("POST".to_string()),
+            url: "http://localhost:8000/upload".parse().unwrap(),
+            headers,
+            multipart: vec![
+                MultipartParam::Param(Param::new("key1", "value1")),
+                MultipartParam::FileParam(FileParam {
+                    name: "upload1".to_string(),
+                    filename: "data.txt".to_string(),
+                    part_filename: Some("renamed.txt".to_string()),
+                    data: b"Hello World!".to_vec(),
+                    content_type: "text/plain".to_string(),
+                    headers: vec![],
+                }),
+                MultipartParam::NestedParam(NestedParam {
+                    name: "batch".to_string(),
+                    content_type: "multipart/mixed".to_string(),
+                    headers: vec![Header::new("Content-ID", "1")],
+                    params: vec![MultipartParam::Param(Param::new("key2", "value2"))],
+                }),
+            ],
+            cookies: vec![
+                RequestCookie {
+                    name: "cookie1".to_string(),
+                    value: "valueA".to_string(),
+                },
+                RequestCookie {
+                    name: "cookie2".to_string(),
+                    value: "valueB".to_string(),
+                },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn hurl_lines_with_cookies_and_multipart() {
+        let lines = request_spec()
+            .hurl_lines()
+            .iter()
+            .map(|l| l.to_string(Format::Plain))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            vec![
+                "POST http://localhost:8000/upload",
+                "User-Agent: hurl",
+                "[Multipart]",
+                "key1: value1",
+                "upload1: file,data.txt; text/plain; filename=renamed.txt",
+                "batch: multipart/mixed; Content-ID: 1",
+                "  key2: value2",
+                "[Cookies]",
+                "cookie1=valueA",
+                "cookie2=valueB",
+            ]
+        );
+    }
+
+    #[test]
+    fn hurl_lines_with_cookies_and_multipart_colored() {
+        hurl_core::text::init_crate_colored();
+
+        let lines = request_spec()
+            .hurl_lines()
+            .iter()
+            .map(|l| l.to_string(Format::Ansi))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lines[0],
+            "\u{1b}[33mPOST\u{1b}[0m \u{1b}[32mhttp://localhost:8000/upload\u{1b}[0m"
+        );
+        assert_eq!(lines[2], "\u{1b}[35m[Multipart]\u{1b}[0m");
+        assert_eq!(
+            lines[4],
+            "\u{1b}[32mupload1\u{1b}[0m: file,\u{1b}[32mdata.txt\u{1b}[0m; \u{1b}[32mtext/plain\u{1b}[0m; filename=\u{1b}[32mrenamed.txt\u{1b}[0m"
+        );
+        assert_eq!(
+            lines[6],
+            "  \u{1b}[32mkey2\u{1b}[0m: \u{1b}[32mvalue2\u{1b}[0m"
+        );
+        assert_eq!(lines[7], "\u{1b}[35m[Cookies]\u{1b}[0m");
+        assert_eq!(
+            lines[8],
+            "\u{1b}[32mcookie1\u{1b}[0m=\u{1b}[32mvalueA\u{1b}[0m"
+        );
+    }
+}
@@ -0,0 +1,136 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Host mappings given with `--resolve`.
+//!
+//! A host mapping `[+]HOST:PORT:ADDR[,ADDR]...` is passed as is to libcurl, except when `HOST` is
+//! a wildcard (`*.example.com`, or `*` for any host). libcurl can't match such a host, so wildcard
+//! mappings are rewritten, before each request, to the concrete mapping of the requested host.
+use super::url::Url;
+
+/// Returns the host mappings to use for a request to `url`, given the user `resolves`.
+///
+/// Mappings without wildcard are kept unchanged. A wildcard mapping matching the host and the
+/// port of `url` is rewritten with the host of `url`, other wildcard mappings are dropped.
+/// Rewritten mappings come first, so an explicit mapping of the same host takes precedence.
+pub fn host_resolves(resolves: &[String], url: &Url) -> Vec<String> {
+    let mut rewritten = vec![];
+    let mut others = vec![];
+    for resolve in resolves {
+        let (prefix, mapping) = match resolve.strip_prefix('+') {
+            Some(mapping) => ("+", mapping),
+            None => ("", resolve.as_str()),
+        };
+        let Some((host, port, addresses)) = split_mapping(mapping) else {
+            others.push(resolve.clone());
+            continue;
+        };
+        if !host.starts_with('*') {
+            others.push(resolve.clone());
+            continue;
+        }
+        let Some(domain) = url.domain() else {
+            continue;
+        };
+        let url_port = url.port().map(|p| p.to_string());
+        if Some(port) == url_port.as_deref() && host_matches(host, domain) {
+            rewritten.push(format!("{prefix}{domain}:{port}:{addresses}"));
+        }
+    }
+    rewritten.append(&mut others);
+    rewritten
+}
+
+/// Splits a host mapping `HOST:PORT:ADDR[,ADDR]...` into its host, port and addresses.
+fn split_mapping(mapping: &str) -> Option<(&str, &str, &str)> {
+    let (host, rest) = mapping.split_once(':')?;
+    let (port, addresses) = rest.split_once(':')?;
+    Some((host, port, addresses))
+}
+
+/// Returns `true` if the wildcard `pattern` matches `domain`.
+///
+/// `*` matches any domain, and `*.example.com` matches any subdomain of `example.com`, but not
+/// `example.com` itself. Matching is case-insensitive.
+fn host_matches(pattern: &str, domain: &str) -> bool {
+    if pattern == "*" {
+        return true;
+    }
+    let Some(suffix) = pattern.strip_prefix('*') else {
+        return false;
+    };
+    let suffix = suffix.to_ascii_lowercase();
+    let domain = domain.to_ascii_lowercase();
+    suffix.starts_with('.') && domain.len() > suffix.len() && domain.ends_with(&suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_host_matches() {
+        assert!(host_matches("*", "example.com"));
+        assert!(host_matches("*.example.com", "api.example.com"));
+        assert!(host_matches("*.example.com", "v1.api.example.com"));
+        assert!(host_matches("*.Example.com", "API.example.com"));
+        assert!(!host_matches("*.example.com", "example.com"));
+        assert!(!host_matches("*.example.com", "api.example.org"));
+        assert!(!host_matches("*example.com", "myexample.com"));
+    }
+
+    #[test]
+    fn test_host_resolves_without_wildcard() {
+        let url = "https://api.example.com/users".parse::<Url>().unwrap();
+        let resolves = strings(&["foo.com:80:192.168.0.1", "-bar.com:443"]);
+        assert_eq!(host_resolves(&resolves, &url), resolves);
+    }
+
+    #[test]
+    fn test_host_resolves_with_wildcard() {
+        let url = "https://api.staging.example.com/users"
+            .parse::<Url>()
+            .unwrap();
+        let resolves = strings(&[
+            "foo.com:80:192.168.0.1",
+            "*.staging.example.com:443:10.0.0.5",
+            "*.staging.example.com:80:10.0.0.6",
+            "+*.prod.example.com:443:10.0.0.7",
+        ]);
+        assert_eq!(
+            host_resolves(&resolves, &url),
+            strings(&[
+                "api.staging.example.com:443:10.0.0.5",
+                "foo.com:80:192.168.0.1",
+            ])
+        );
+
+        let url = "http://localhost:8000/hello".parse::<Url>().unwrap();
+        let resolves = strings(&["+*:8000:127.0.0.1,::1"]);
+        assert_eq!(
+            host_resolves(&resolves, &url),
+            strings(&["+localhost:8000:127.0.0.1,::1"])
+        );
+
+        let url = "http://127.0.0.1:8000/hello".parse::<Url>().unwrap();
+        assert!(host_resolves(&resolves, &url).is_empty());
+    }
+}
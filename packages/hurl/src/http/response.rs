@@ -19,8 +19,9 @@ use std::fmt;
 use std::time::Duration;
 
 use super::certificate::Certificate;
-use super::header::HeaderVec;
+use super::header::{HeaderVec, LINK};
 use super::ip::IpAddr;
+use super::timings::Timings;
 use super::url::Url;
 
 /// Represents a runtime HTTP response.
@@ -36,6 +37,14 @@ pub struct Response {
     /// The end-user certificate, in the response certificate chain
     pub certificate: Option<Certificate>,
     pub ip_addr: IpAddr,
+    /// The status code of the proxy response to a CONNECT request, if any
+    pub proxy_status: Option<u32>,
+    /// Whether the request has been upgraded from HTTP to HTTPS by the HSTS cache
+    pub hsts_upgraded: bool,
+    /// Interim (informational `1xx`) responses received before this response
+    pub interim_responses: Vec<InterimResponse>,
+    /// Timings of the transfer (name lookup, connect etc...)
+    pub timings: Timings,
 }
 
 impl Response {
@@ -50,6 +59,10 @@ impl Response {
         url: Url,
         certificate: Option<Certificate>,
         ip_addr: IpAddr,
+        proxy_status: Option<u32>,
+        hsts_upgraded: bool,
+        interim_responses: Vec<InterimResponse>,
+        timings: Timings,
     ) -> Self {
         Response {
             version,
@@ -60,6 +73,10 @@ impl Response {
             url,
             certificate,
             ip_addr,
+            proxy_status,
+            hsts_upgraded,
+            interim_responses,
+            timings,
         }
     }
 
@@ -67,6 +84,28 @@ impl Response {
     pub fn has_trailing_newline(&self) -> bool {
         self.body.ends_with(b"\n")
     }
+
+    /// Returns `true` if an interim `100 Continue` response has been received before this response.
+    pub fn continue_received(&self) -> bool {
+        self.interim_responses.iter().any(|r| r.status == 100)
+    }
+
+    /// Returns the values of the `Link` headers of the interim `103 Early Hints` responses received
+    /// before this response.
+    pub fn early_hints(&self) -> Vec<&str> {
+        self.interim_responses
+            .iter()
+            .filter(|r| r.status == 103)
+            .flat_map(|r| r.headers.values(LINK))
+            .collect()
+    }
+}
+
+/// Represents an interim (informational `1xx`) HTTP response, received before the final response.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InterimResponse {
+    pub status: u32,
+    pub headers: HeaderVec,
 }
 
 /// Represents the HTTP version of a HTTP transaction.
@@ -109,6 +148,10 @@ mod tests {
             url: "http://localhost".parse().unwrap(),
             certificate: None,
             ip_addr: Default::default(),
+            proxy_status: None,
+            hsts_upgraded: false,
+            interim_responses: vec![],
+            timings: Default::default(),
         };
         assert_eq!(response.headers.values("Content-Length"), vec!["12"]);
         assert!(response.headers.values("Unknown").is_empty());
@@ -124,6 +167,10 @@ mod tests {
             url: "http://localhost".parse().unwrap(),
             certificate: None,
             ip_addr: Default::default(),
+            proxy_status: None,
+            hsts_upgraded: false,
+            interim_responses: vec![],
+            timings: Default::default(),
         }
     }
 
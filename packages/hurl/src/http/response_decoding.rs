@@ -22,6 +22,8 @@
 /// See https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Encoding
 use std::io::prelude::*;
 
+use encoding_rs::Encoding;
+
 use super::error::HttpError;
 use super::mimetype;
 use super::response::Response;
@@ -66,13 +68,22 @@ impl ContentEncoding {
 impl Response {
     /// Returns response body as text.
     pub fn text(&self) -> Result<String, HttpError> {
+        let character_encoding = self.headers.character_encoding()?;
+        self.text_with_encoding(character_encoding)
+    }
+
+    /// Returns response body as text, decoded with `character_encoding` whatever the charset of
+    /// the `Content-Type` header.
+    pub fn text_with_encoding(
+        &self,
+        character_encoding: &'static Encoding,
+    ) -> Result<String, HttpError> {
         let content_encodings = self.headers.content_encoding()?;
         let body = if content_encodings.is_empty() {
             &self.body
         } else {
             &self.uncompress_body()?
         };
-        let character_encoding = self.headers.character_encoding()?;
         match character_encoding.decode_without_bom_handling_and_without_replacement(body) {
             Some(s) => Ok(s.to_string()),
             None => Err(HttpError::InvalidDecoding {
@@ -91,6 +102,15 @@ impl Response {
         self.headers.content_type().is_some_and(mimetype::is_json)
     }
 
+    /// Returns true if the first record of this response body is a CSV header.
+    ///
+    /// The header is assumed to be present, unless the `Content-Type` response header explicitly
+    /// sets `header=absent`.
+    pub fn has_csv_header(&self) -> bool {
+        let header = self.headers.content_type().and_then(mimetype::csv_header);
+        header.as_deref() != Some("absent")
+    }
+
     /// Returns true if response is a XML response.
     pub fn is_xml(&self) -> bool {
         self.headers.content_type().is_some_and(mimetype::is_xml)
@@ -173,6 +193,10 @@ pub mod tests {
             url: "http://localhost".parse().unwrap(),
             certificate: None,
             ip_addr: Default::default(),
+            proxy_status: None,
+            hsts_upgraded: false,
+            interim_responses: vec![],
+            timings: Default::default(),
         }
     }
 
@@ -32,6 +32,10 @@ fn default_response() -> Response {
         url: Url::from_str("http://localhost").unwrap(),
         certificate: None,
         ip_addr: Default::default(),
+        proxy_status: None,
+        hsts_upgraded: false,
+        interim_responses: vec![],
+        timings: Default::default(),
     }
 }
 
@@ -0,0 +1,169 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! jq
+//! as defined in <https://jqlang.org/manual/>
+//!
+//! jq programs are compiled and run with the [jaq](https://github.com/01mf02/jaq) engine, with
+//! its standard library. Values are produced lazily, so generators like `limit(3; range(1e18))`
+//! or `first(repeat(1))` are evaluated without producing their whole output.
+use std::fmt;
+
+use jaq_core::load::{Arena, File, Loader};
+use jaq_core::{Compiler, Ctx, Filter, Native, RcIter};
+use jaq_json::Val;
+
+/// Maximum number of values produced by a jq program.
+const MAX_OUTPUTS: usize = 100_000;
+
+/// A compiled jq program.
+pub struct Program {
+    filter: Filter<Native<Val>>,
+}
+
+/// Error raised while parsing or compiling a jq program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError;
+
+/// Error raised while evaluating a jq program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EvalError {
+    message: String,
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Parses and compiles a jq program.
+pub fn parse(s: &str) -> Result<Program, ParseError> {
+    let loader = Loader::new(jaq_std::defs().chain(jaq_json::defs()));
+    let arena = Arena::default();
+    let file = File { code: s, path: () };
+    let modules = loader.load(&arena, file).map_err(|_| ParseError)?;
+    let filter = Compiler::default()
+        .with_funs(jaq_std::funs().chain(jaq_json::funs()))
+        .compile(modules)
+        .map_err(|_| ParseError)?;
+    Ok(Program { filter })
+}
+
+impl Program {
+    /// Evaluates this program against an `input` and returns the values produced.
+    ///
+    /// The evaluation stops at the first error.
+    pub fn eval(&self, input: &serde_json::Value) -> Result<Vec<serde_json::Value>, EvalError> {
+        let inputs = RcIter::new(core::iter::empty());
+        let outputs = self
+            .filter
+            .run((Ctx::new([], &inputs), Val::from(input.clone())));
+        let mut values = vec![];
+        for output in outputs {
+            if values.len() == MAX_OUTPUTS {
+                let message = format!("more than {MAX_OUTPUTS} values produced");
+                return Err(EvalError { message });
+            }
+            match output {
+                Ok(value) => values.push(serde_json::Value::from(value)),
+                Err(error) => {
+                    let message = error.to_string();
+                    return Err(EvalError { message });
+                }
+            }
+        }
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn eval(program: &str, input: serde_json::Value) -> Result<Vec<serde_json::Value>, EvalError> {
+        parse(program).unwrap().eval(&input)
+    }
+
+    #[test]
+    fn test_eval() {
+        let input = json!({"items": [{"price": 10}, {"price": 32}]});
+        assert_eq!(
+            eval("[.items[].price] | add", input.clone()),
+            Ok(vec![json!(42)])
+        );
+        assert_eq!(
+            eval(".items[].price", input.clone()),
+            Ok(vec![json!(10), json!(32)])
+        );
+        assert_eq!(
+            eval(
+                "def double: . * 2; .items | map(.price | double)",
+                input.clone()
+            ),
+            Ok(vec![json!([20, 64])])
+        );
+        assert_eq!(
+            eval(".items[0].price |= . + 1 | [paths] | length", input),
+            Ok(vec![json!(5)])
+        );
+    }
+
+    #[test]
+    fn test_eval_lazy_generators() {
+        assert_eq!(
+            eval("limit(3; range(1e18))", json!(null)),
+            Ok(vec![json!(0), json!(1), json!(2)])
+        );
+        assert_eq!(eval("first(repeat(1))", json!(null)), Ok(vec![json!(1)]));
+        assert_eq!(
+            eval("range(1e18)", json!(null)).unwrap_err().to_string(),
+            "more than 100000 values produced"
+        );
+    }
+
+    #[test]
+    fn test_eval_big_numbers() {
+        let input: serde_json::Value =
+            serde_json::from_str(r#"{"id": 12345678901234567890123}"#).unwrap();
+        assert_eq!(eval(".id", input.clone()), Ok(vec![input["id"].clone()]));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(parse(".items |").is_err());
+        assert!(parse("unknown_function").is_err());
+    }
+
+    #[test]
+    fn test_eval_error() {
+        assert_eq!(
+            eval(".items + 1", json!({"items": []}))
+                .unwrap_err()
+                .to_string(),
+            "cannot calculate [] + 1"
+        );
+        assert_eq!(
+            eval("1, error(\"boom\"), 2", json!(null))
+                .unwrap_err()
+                .to_string(),
+            "\"boom\""
+        );
+    }
+}
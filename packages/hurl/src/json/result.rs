@@ -31,7 +31,10 @@ use crate::http::{
     Call, Certificate, Cookie, Header, HttpVersion, Param, Request, RequestCookie, Response,
     ResponseCookie, Timings,
 };
-use crate::runner::{AssertResult, CaptureResult, EntryResult, HurlResult};
+use crate::report::deterministic;
+use crate::runner::{
+    AssertResult, CaptureResult, EntryResult, HurlResult, RunnerErrorKind, Variable,
+};
 use crate::util::redacted::Redact;
 
 impl HurlResult {
@@ -49,7 +52,45 @@ impl HurlResult {
         response_dir: Option<&Path>,
         secrets: &[&str],
     ) -> Result<serde_json::Value, io::Error> {
-        let result = HurlResultJson::from_result(self, content, filename, response_dir, secrets)?;
+        let result = HurlResultJson::from_result(
+            self,
+            content,
+            filename,
+            response_dir,
+            false,
+            secrets,
+            Schema::V1,
+        )?;
+        let value = serde_json::to_value(result)?;
+        Ok(value)
+    }
+
+    /// Serializes an [`HurlResult`] to a JSON representation, using the version 2 of the JSON
+    /// schema.
+    ///
+    /// The version 2 is a superset of the version 1 produced by [`HurlResult::to_json`], adding
+    /// the resolved variables at the end of the run, the response body sizes and the expected and
+    /// actual values of asserts. The HTTP responses are saved in `response_dir`, under a name
+    /// derived from their body if `deterministic` is set, or randomly generated otherwise.
+    /// `secrets` strings are redacted from the JSON fields, and the values of secret variables are
+    /// not serialized.
+    pub fn to_json_v2(
+        &self,
+        content: &str,
+        filename: &Input,
+        response_dir: &Path,
+        deterministic: bool,
+        secrets: &[&str],
+    ) -> Result<serde_json::Value, io::Error> {
+        let result = HurlResultJson::from_result(
+            self,
+            content,
+            filename,
+            Some(response_dir),
+            deterministic,
+            secrets,
+            Schema::V2,
+        )?;
         let value = serde_json::to_value(result)?;
         Ok(value)
     }
@@ -62,7 +103,17 @@ impl HurlResult {
     }
 }
 
+/// Version of the JSON schema used to serialize an [`HurlResult`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Schema {
+    V1,
+    V2,
+}
+
 /// These structures represent the JSON schema used to serialize an [`HurlResult`] to JSON.
+///
+/// Fields specific to the version 2 of the schema are optional, so a version 1 JSON can still be
+/// deserialized.
 #[derive(Deserialize, Serialize)]
 struct HurlResultJson {
     filename: String,
@@ -70,6 +121,10 @@ struct HurlResultJson {
     success: bool,
     time: u64,
     cookies: Vec<CookieJson>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    variables: Option<Vec<VariableJson>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<u32>,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -113,6 +168,18 @@ struct AssertJson {
     #[serde(skip_serializing_if = "Option::is_none")]
     message: Option<String>,
     line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actual: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct VariableJson {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<serde_json::Value>,
+    secret: bool,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -134,6 +201,8 @@ struct ResponseJson {
     certificate: Option<CertificateJson>,
     #[serde(skip_serializing_if = "Option::is_none")]
     body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body_size: Option<usize>,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -211,24 +280,50 @@ impl HurlResultJson {
         content: &str,
         filename: &Input,
         response_dir: Option<&Path>,
+        deterministic: bool,
         secrets: &[&str],
+        schema: Schema,
     ) -> Result<Self, io::Error> {
         let entries = result
             .entries
             .iter()
-            .map(|e| EntryResultJson::from_entry(e, content, filename, response_dir, secrets))
+            .map(|e| {
+                EntryResultJson::from_entry(
+                    e,
+                    content,
+                    filename,
+                    response_dir,
+                    deterministic,
+                    secrets,
+                    schema,
+                )
+            })
             .collect::<Result<Vec<_>, _>>()?;
         let cookies = result
             .cookie_store
             .cookies()
             .map(|c| CookieJson::from_cookie(c, secrets))
             .collect::<Vec<_>>();
+        let (variables, version) = match schema {
+            Schema::V1 => (None, None),
+            Schema::V2 => {
+                let mut variables = result
+                    .variables
+                    .iter()
+                    .map(|(name, v)| VariableJson::from_variable(name, v, secrets))
+                    .collect::<Vec<_>>();
+                variables.sort_by(|a, b| a.name.cmp(&b.name));
+                (Some(variables), Some(2))
+            }
+        };
         Ok(HurlResultJson {
             filename: filename.to_string(),
             entries,
             success: result.success,
             time: result.duration.as_millis() as u64,
             cookies,
+            variables,
+            version,
         })
     }
 }
@@ -239,12 +334,14 @@ impl EntryResultJson {
         content: &str,
         filename: &Input,
         response_dir: Option<&Path>,
+        deterministic: bool,
         secrets: &[&str],
+        schema: Schema,
     ) -> Result<Self, io::Error> {
         let calls = entry
             .calls
             .iter()
-            .map(|c| CallJson::from_call(c, response_dir, secrets))
+            .map(|c| CallJson::from_call(c, response_dir, deterministic, secrets, schema))
             .collect::<Result<Vec<_>, _>>()?;
         let captures = entry
             .captures
@@ -254,7 +351,9 @@ impl EntryResultJson {
         let asserts = entry
             .asserts
             .iter()
-            .map(|a| AssertJson::from_assert(a, content, filename, entry.source_info, secrets))
+            .map(|a| {
+                AssertJson::from_assert(a, content, filename, entry.source_info, secrets, schema)
+            })
             .collect::<Vec<_>>();
         Ok(EntryResultJson {
             index: entry.entry_index.get(),
@@ -286,10 +385,18 @@ impl CallJson {
     fn from_call(
         call: &Call,
         response_dir: Option<&Path>,
+        deterministic: bool,
         secrets: &[&str],
+        schema: Schema,
     ) -> Result<Self, io::Error> {
         let request = RequestJson::from_request(&call.request, secrets);
-        let response = ResponseJson::from_response(&call.response, response_dir, secrets)?;
+        let response = ResponseJson::from_response(
+            &call.response,
+            response_dir,
+            deterministic,
+            secrets,
+            schema,
+        )?;
         let timings = TimingsJson::from_timings(&call.timings);
         Ok(CallJson {
             request,
@@ -331,7 +438,9 @@ impl ResponseJson {
     fn from_response(
         response: &Response,
         response_dir: Option<&Path>,
+        deterministic: bool,
         secrets: &[&str],
+        schema: Schema,
     ) -> Result<Self, io::Error> {
         let http_version = match response.version {
             HttpVersion::Http10 => "HTTP/1.0",
@@ -369,13 +478,17 @@ impl ResponseJson {
                 // ```
                 // we want the `body` field to reference the relative path of a response compared
                 // to `report.json`.
-                let file = write_response(response, response_dir)?;
+                let file = write_response(response, response_dir, deterministic)?;
                 let parent = response_dir.components().next_back().unwrap();
                 let parent: &Path = parent.as_ref();
                 Some(format!("{}/{}", parent.display(), file.display()))
             }
             None => None,
         };
+        let body_size = match schema {
+            Schema::V1 => None,
+            Schema::V2 => Some(response.body.len()),
+        };
         Ok(ResponseJson {
             http_version: http_version.to_string(),
             status: response.status,
@@ -383,6 +496,7 @@ impl ResponseJson {
             cookies,
             certificate,
             body,
+            body_size,
         })
     }
 }
@@ -479,6 +593,7 @@ impl AssertJson {
         filename: &Input,
         entry_src_info: SourceInfo,
         secrets: &[&str],
+        schema: Schema,
     ) -> Self {
         let message = a.to_runner_error().map(|err| {
             err.render(
@@ -489,16 +604,92 @@ impl AssertJson {
             )
         });
         let message = message.map(|m| m.redact(secrets));
+        let (actual, expected) = match schema {
+            Schema::V1 => (None, None),
+            Schema::V2 => {
+                let (actual, expected) = assert_values(a);
+                (
+                    actual.map(|s| s.redact(secrets)),
+                    expected.map(|s| s.redact(secrets)),
+                )
+            }
+        };
         AssertJson {
             success: a.to_runner_error().is_none(),
             message,
             line: a.line(),
+            actual,
+            expected,
+        }
+    }
+}
+
+/// Returns the actual and expected values of an assert `a`, if they can be computed.
+///
+/// For explicit asserts, the expected value is only known when the predicate has failed.
+fn assert_values(a: &AssertResult) -> (Option<String>, Option<String>) {
+    match a {
+        AssertResult::ImplicitVersion {
+            actual, expected, ..
+        } => (Some(actual.clone()), Some(expected.clone())),
+        AssertResult::ImplicitStatus {
+            actual, expected, ..
+        } => (Some(actual.to_string()), Some(expected.to_string())),
+        AssertResult::ImplicitHeader {
+            actual, expected, ..
+        } => (actual.as_ref().ok().cloned(), Some(expected.clone())),
+        AssertResult::ImplicitBody {
+            actual, expected, ..
+        } => (
+            actual.as_ref().ok().map(|v| v.to_string()),
+            expected.as_ref().ok().map(|v| v.to_string()),
+        ),
+        AssertResult::Explicit {
+            actual,
+            predicate_result,
+            ..
+        } => {
+            let actual = match actual {
+                Ok(Some(value)) => Some(value.to_string()),
+                _ => None,
+            };
+            let expected = match predicate_result {
+                Some(Err(e)) => match &e.kind {
+                    RunnerErrorKind::AssertFailure { expected, .. } => Some(expected.clone()),
+                    _ => None,
+                },
+                _ => None,
+            };
+            (actual, expected)
+        }
+    }
+}
+
+impl VariableJson {
+    fn from_variable(name: &str, variable: &Variable, secrets: &[&str]) -> Self {
+        let secret = variable.is_secret();
+        let value = if secret {
+            None
+        } else {
+            Some(variable.value().to_json(secrets))
+        };
+        VariableJson {
+            name: name.to_string(),
+            value,
+            secret,
         }
     }
 }
 
 /// Write the HTTP `response` body to directory `dir`.
-fn write_response(response: &Response, dir: &Path) -> Result<PathBuf, io::Error> {
+///
+/// If `deterministic` is set, the file name is derived from the response body, so identical
+/// responses share the same file.
+fn write_response(
+    response: &Response,
+    dir: &Path,
+    deterministic: bool,
+) -> Result<PathBuf, io::Error> {
     let extension = if response.is_json() {
         Some("json")
     } else if response.is_xml() {
@@ -508,7 +699,11 @@ fn write_response(response: &Response, dir: &Path) -> Result<PathBuf, io::Error>
     } else {
         None
     };
-    let id = Uuid::new_v4();
+    let id = if deterministic {
+        deterministic::stable_uuid(&response.body)
+    } else {
+        Uuid::new_v4()
+    };
     let relative_path = format!("{id}_response");
     let relative_path = Path::new(&relative_path);
     let relative_path = match extension {
@@ -87,11 +87,29 @@ impl Value {
                     Some(loc) => loc.raw(),
                     None => "None".to_string(),
                 };
+                map.insert("url".to_string(), serde_json::Value::String(v.url().raw()));
                 map.insert("location".to_string(), serde_json::Value::String(location));
                 map.insert(
                     "status".to_string(),
                     serde_json::Value::Number(serde_json::Number::from(v.status())),
                 );
+                let headers = v
+                    .headers()
+                    .iter()
+                    .map(|h| {
+                        let mut header = serde_json::Map::new();
+                        header.insert(
+                            "name".to_string(),
+                            serde_json::Value::String(h.name.clone()),
+                        );
+                        header.insert(
+                            "value".to_string(),
+                            serde_json::Value::String(h.value.clone()),
+                        );
+                        serde_json::Value::Object(header)
+                    })
+                    .collect();
+                map.insert("headers".to_string(), serde_json::Value::Array(headers));
                 serde_json::Value::Object(map)
             }
         }
@@ -21,6 +21,7 @@ mod expr;
 mod function;
 mod literal;
 mod query;
+mod remove;
 mod segment;
 mod selector;
 mod singular_query;
@@ -0,0 +1,298 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use crate::jsonpath::ast::query::AbsoluteQuery;
+use crate::jsonpath::ast::segment::{ChildSegment, DescendantSegment, Segment};
+use crate::jsonpath::ast::selector::Selector;
+
+/// An element of a node location: a member name in an object, or an index in an array.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum PathElement {
+    Index(usize),
+    Name(String),
+}
+
+/// The location of a node, from the root value.
+type Location = Vec<PathElement>;
+
+impl AbsoluteQuery {
+    /// Removes all the nodes selected by this query from a root `serde_json::Value`.
+    ///
+    /// Selected object members are removed from their object, selected array elements are removed
+    /// from their array. The root value itself can't be removed.
+    pub fn remove(&self, root_value: &mut serde_json::Value) {
+        let root = root_value.clone();
+        let mut locations = vec![vec![]];
+        for segment in self.segments() {
+            locations = locations
+                .iter()
+                .flat_map(|location| segment.locate(location, &root))
+                .collect();
+        }
+        locations.sort();
+        locations.dedup();
+        // Locations are removed in reverse order so that removing an array element doesn't shift
+        // the indices of the elements still to be removed.
+        for location in locations.iter().rev() {
+            remove_node(root_value, location);
+        }
+    }
+}
+
+impl Segment {
+    /// Returns the locations of the nodes selected by this segment, from the node at `location`.
+    fn locate(&self, location: &Location, root_value: &serde_json::Value) -> Vec<Location> {
+        match self {
+            Segment::Child(child_segment) => child_segment.locate(location, root_value),
+            Segment::Descendant(descendant_segment) => {
+                descendant_segment.locate(location, root_value)
+            }
+        }
+    }
+}
+
+impl ChildSegment {
+    fn locate(&self, location: &Location, root_value: &serde_json::Value) -> Vec<Location> {
+        self.selectors()
+            .iter()
+            .flat_map(|selector| selector.locate(location, root_value))
+            .collect()
+    }
+}
+
+impl DescendantSegment {
+    fn locate(&self, location: &Location, root_value: &serde_json::Value) -> Vec<Location> {
+        let Some(node) = node_at(root_value, location) else {
+            return vec![];
+        };
+        let mut descendants = vec![];
+        descendant_locations(node, location, &mut descendants);
+        descendants
+            .iter()
+            .flat_map(|descendant| {
+                self.selectors()
+                    .iter()
+                    .flat_map(|selector| selector.locate(descendant, root_value))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+impl Selector {
+    /// Returns the locations of the children selected by this selector, from the node at `location`.
+    fn locate(&self, location: &Location, root_value: &serde_json::Value) -> Vec<Location> {
+        let Some(node) = node_at(root_value, location) else {
+            return vec![];
+        };
+        let child = |element: PathElement| {
+            let mut child = location.clone();
+            child.push(element);
+            child
+        };
+        match (self, node) {
+            (Selector::Name(name_selector), serde_json::Value::Object(key_values)) => {
+                if key_values.contains_key(name_selector.value()) {
+                    vec![child(PathElement::Name(name_selector.value().to_string()))]
+                } else {
+                    vec![]
+                }
+            }
+            (Selector::Wildcard(_), serde_json::Value::Object(key_values)) => key_values
+                .keys()
+                .map(|key| child(PathElement::Name(key.clone())))
+                .collect(),
+            (Selector::Wildcard(_), serde_json::Value::Array(values)) => (0..values.len())
+                .map(|index| child(PathElement::Index(index)))
+                .collect(),
+            (Selector::Index(index_selector), serde_json::Value::Array(values)) => {
+                let len = values.len() as i64;
+                let index = *index_selector.value();
+                let index = if index < 0 { len + index } else { index };
+                if index >= 0 && index < len {
+                    vec![child(PathElement::Index(index as usize))]
+                } else {
+                    vec![]
+                }
+            }
+            (Selector::ArraySlice(array_slice_selector), serde_json::Value::Array(values)) => {
+                array_slice_selector
+                    .indices(values.len())
+                    .into_iter()
+                    .map(|index| child(PathElement::Index(index)))
+                    .collect()
+            }
+            (Selector::Filter(filter_selector), serde_json::Value::Object(key_values)) => {
+                key_values
+                    .iter()
+                    .filter(|(_, value)| filter_selector.expr().eval(value, root_value))
+                    .map(|(key, _)| child(PathElement::Name(key.clone())))
+                    .collect()
+            }
+            (Selector::Filter(filter_selector), serde_json::Value::Array(values)) => values
+                .iter()
+                .enumerate()
+                .filter(|(_, value)| filter_selector.expr().eval(value, root_value))
+                .map(|(index, _)| child(PathElement::Index(index)))
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
+
+/// Returns the node at `location` in a `root_value`.
+fn node_at<'value>(
+    root_value: &'value serde_json::Value,
+    location: &Location,
+) -> Option<&'value serde_json::Value> {
+    let mut node = root_value;
+    for element in location {
+        node = match (element, node) {
+            (PathElement::Name(name), serde_json::Value::Object(key_values)) => {
+                key_values.get(name)?
+            }
+            (PathElement::Index(index), serde_json::Value::Array(values)) => values.get(*index)?,
+            _ => return None,
+        };
+    }
+    Some(node)
+}
+
+/// Pushes to `locations` the location of `node` and the locations of all its descendants.
+fn descendant_locations(
+    node: &serde_json::Value,
+    location: &Location,
+    locations: &mut Vec<Location>,
+) {
+    locations.push(location.clone());
+    match node {
+        serde_json::Value::Object(key_values) => {
+            for (key, value) in key_values {
+                let mut child = location.clone();
+                child.push(PathElement::Name(key.clone()));
+                descendant_locations(value, &child, locations);
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for (index, value) in values.iter().enumerate() {
+                let mut child = location.clone();
+                child.push(PathElement::Index(index));
+                descendant_locations(value, &child, locations);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Removes the node at `location` from a `root_value`, if it exists.
+fn remove_node(root_value: &mut serde_json::Value, location: &Location) {
+    let Some((last, parent)) = location.split_last() else {
+        return;
+    };
+    let mut node = root_value;
+    for element in parent {
+        node = match (element, node) {
+            (PathElement::Name(name), serde_json::Value::Object(key_values)) => {
+                match key_values.get_mut(name) {
+                    Some(value) => value,
+                    None => return,
+                }
+            }
+            (PathElement::Index(index), serde_json::Value::Array(values)) => {
+                match values.get_mut(*index) {
+                    Some(value) => value,
+                    None => return,
+                }
+            }
+            _ => return,
+        };
+    }
+    match (last, node) {
+        (PathElement::Name(name), serde_json::Value::Object(key_values)) => {
+            key_values.remove(name);
+        }
+        (PathElement::Index(index), serde_json::Value::Array(values)) if *index < values.len() => {
+            values.remove(*index);
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::jsonpath;
+
+    fn remove(expr: &str, value: serde_json::Value) -> serde_json::Value {
+        let mut value = value;
+        jsonpath::parse(expr).unwrap().remove(&mut value);
+        value
+    }
+
+    #[test]
+    fn test_remove_members() {
+        let value =
+            json!({"id": 1, "name": "Bob", "meta": {"createdAt": "2024-01-01", "tags": []}});
+        assert_eq!(
+            remove("$.id", value.clone()),
+            json!({"name": "Bob", "meta": {"createdAt": "2024-01-01", "tags": []}})
+        );
+        assert_eq!(
+            remove("$.meta.createdAt", value.clone()),
+            json!({"id": 1, "name": "Bob", "meta": {"tags": []}})
+        );
+        assert_eq!(remove("$.unknown", value.clone()), value);
+        assert_eq!(remove("$", value.clone()), value);
+    }
+
+    #[test]
+    fn test_remove_array_elements() {
+        let value = json!([{"id": 1}, {"id": 2}, {"id": 3}, {"id": 4}]);
+        assert_eq!(remove("$[*].id", value.clone()), json!([{}, {}, {}, {}]));
+        assert_eq!(
+            remove("$[1]", value.clone()),
+            json!([{"id": 1}, {"id": 3}, {"id": 4}])
+        );
+        assert_eq!(
+            remove("$[-1]", value.clone()),
+            json!([{"id": 1}, {"id": 2}, {"id": 3}])
+        );
+        assert_eq!(
+            remove("$[0:4:2]", value.clone()),
+            json!([{"id": 2}, {"id": 4}])
+        );
+        assert_eq!(
+            remove("$[?@.id > 2]", value.clone()),
+            json!([{"id": 1}, {"id": 2}])
+        );
+    }
+
+    #[test]
+    fn test_remove_descendants() {
+        let value = json!({
+            "id": 1,
+            "items": [{"id": 10, "name": "a"}, {"id": 11, "name": "b"}],
+            "owner": {"id": 2}
+        });
+        assert_eq!(
+            remove("$..id", value),
+            json!({"items": [{"name": "a"}, {"name": "b"}], "owner": {}})
+        );
+    }
+}
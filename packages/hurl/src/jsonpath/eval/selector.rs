@@ -91,28 +91,36 @@ impl IndexSelector {
 impl ArraySliceSelector {
     pub fn eval(&self, current_value: &serde_json::Value) -> NodeList {
         if let serde_json::Value::Array(values) = current_value {
-            if self.step() == 0 {
-                return vec![];
+            return self
+                .indices(values.len())
+                .into_iter()
+                .map(|i| values[i].clone())
+                .collect();
+        }
+        vec![]
+    }
+
+    /// Returns the indices selected by this slice in an array of size `len`, in selection order.
+    pub fn indices(&self, len: usize) -> Vec<usize> {
+        if self.step() == 0 {
+            return vec![];
+        }
+        let (lower, upper) = self.get_bounds(len as i64);
+        let mut indices = vec![];
+        if self.step() > 0 {
+            let mut i = lower;
+            while i < upper {
+                indices.push(i as usize);
+                i += self.step();
             }
-            let len = values.len() as i64;
-            let (lower, upper) = self.get_bounds(len);
-            let mut results = vec![];
-            if self.step() > 0 {
-                let mut i = lower;
-                while i < upper {
-                    results.push(values.get(i as usize).unwrap().clone());
-                    i += self.step();
-                }
-            } else {
-                let mut i = upper;
-                while lower < i {
-                    results.push(values.get(i as usize).unwrap().clone());
-                    i += self.step();
-                }
+        } else {
+            let mut i = upper;
+            while lower < i {
+                indices.push(i as usize);
+                i += self.step();
             }
-            return results;
         }
-        vec![]
+        indices
     }
 
     pub fn get_start(&self, len: i64) -> i64 {
@@ -39,10 +39,14 @@
 //!
 //! This crate works on Windows, macOS and Linux.
 
+pub mod generate;
 mod html;
 pub mod http;
+mod jq;
 mod json;
 mod jsonpath;
+#[doc(hidden)]
+pub mod load;
 pub mod output;
 #[doc(hidden)]
 pub mod parallel;
@@ -0,0 +1,25 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Run a Hurl file as a load test (experimental).
+//!
+//! In load test mode, a Hurl file is replayed continuously by a set of virtual users for a given
+//! duration, optionally at a target throughput. The latency of each request and the errors of
+//! each run are collected in [`stats::LoadStats`].
+mod pacer;
+pub mod runner;
+pub mod stats;
@@ -0,0 +1,102 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::runner::{Event, EventListener};
+
+/// A pacer spaces the requests of all the virtual users of a load test, to match a target
+/// throughput.
+///
+/// The pacer is shared by the virtual users, and is notified before each request is run: each
+/// request is given the next free time slot, and the virtual user sleeps until this slot.
+pub struct Pacer {
+    /// Time between two consecutive requests, `None` if the throughput is not limited.
+    interval: Option<Duration>,
+    /// The next free time slot to run a request.
+    next: Mutex<Instant>,
+}
+
+impl Pacer {
+    /// Creates a new pacer for a target `throughput` in requests per second. If `throughput` is
+    /// `None`, requests are run as fast as possible.
+    pub fn new(throughput: Option<u32>) -> Self {
+        let interval = throughput.map(|rps| Duration::from_secs(1) / rps);
+        Pacer {
+            interval,
+            next: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Waits for the next time slot to run a request.
+    pub fn wait(&self) {
+        let Some(interval) = self.interval else {
+            return;
+        };
+        let slot = {
+            let mut next = self.next.lock().unwrap();
+            // If we're late, we don't try to catch up the delay with a burst of requests.
+            let slot = (*next).max(Instant::now());
+            *next = slot + interval;
+            slot
+        };
+        let now = Instant::now();
+        if slot > now {
+            thread::sleep(slot - now);
+        }
+    }
+}
+
+/// A pacer is shared by all the virtual users of a load test, each run subscribing a reference to
+/// the pacer.
+impl EventListener for &Pacer {
+    fn on_event(&mut self, event: &Event) {
+        if let Event::EntryStarted { .. } = event {
+            self.wait();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::Pacer;
+
+    #[test]
+    fn pacer_spaces_requests() {
+        let pacer = Pacer::new(Some(100));
+        let start = Instant::now();
+        for _ in 0..5 {
+            pacer.wait();
+        }
+        // First request is run immediately, then 4 slots of 10 ms.
+        assert!(start.elapsed() >= Duration::from_millis(40));
+    }
+
+    #[test]
+    fn pacer_without_throughput_does_not_wait() {
+        let pacer = Pacer::new(None);
+        let start = Instant::now();
+        for _ in 0..100 {
+            pacer.wait();
+        }
+        assert!(start.elapsed() < Duration::from_millis(100));
+    }
+}
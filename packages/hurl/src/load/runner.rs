@@ -0,0 +1,157 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::thread;
+use std::time::{Duration, Instant};
+
+use hurl_core::error::{DisplaySourceError, OutputFormat};
+use hurl_core::input::Input;
+use hurl_core::parser;
+
+use super::pacer::Pacer;
+use super::stats::LoadStats;
+use crate::runner;
+use crate::runner::{RunnerOptions, VariableSet};
+use crate::util::logger::{Logger, LoggerOptions};
+use crate::util::term::{Stderr, Stdout, WriteMode};
+
+/// A load runner replays a Hurl file with a pool of virtual users, for a given duration.
+///
+/// Each virtual user runs the Hurl file in its own thread, in a loop, until the load test
+/// duration is elapsed. When a target throughput is set, the requests of all the virtual users are
+/// spaced to match this throughput. Standard output and standard error of each run are
+/// discarded: the results are only used to compute the [`LoadStats`] of the load test.
+pub struct LoadRunner {
+    /// Number of virtual users.
+    concurrency: usize,
+    /// Target throughput, in requests per second, `None` for no limit.
+    throughput: Option<u32>,
+    /// Duration of the load test.
+    duration: Duration,
+}
+
+impl LoadRunner {
+    /// Creates a new load runner with `concurrency` virtual users, running for `duration`, with
+    /// an optional target `throughput` (in requests per second).
+    pub fn new(concurrency: usize, throughput: Option<u32>, duration: Duration) -> Self {
+        LoadRunner {
+            concurrency,
+            throughput,
+            duration,
+        }
+    }
+
+    /// Runs the Hurl `content` of file `filename` as a load test and returns its statistics.
+    ///
+    /// If `content` is not a syntactically correct Hurl file, the parsing error is displayed on
+    /// standard error, and an error description is returned.
+    pub fn run(
+        &self,
+        content: &str,
+        filename: &Input,
+        runner_options: &RunnerOptions,
+        variables: &VariableSet,
+        logger_options: &LoggerOptions,
+    ) -> Result<LoadStats, String> {
+        let secrets = variables.secrets();
+        let limits = runner_options.parser_limits;
+        let hurl_file = match parser::parse_hurl_file_with_limits(content, limits) {
+            Ok(h) => h,
+            Err(error) => {
+                let stderr = Stderr::new(WriteMode::Immediate);
+                let mut logger = Logger::new(logger_options, stderr, &secrets);
+                let message = error.render(
+                    &filename.to_string(),
+                    content,
+                    None,
+                    OutputFormat::Terminal(logger.color),
+                );
+                logger.error_rich(&message);
+                return Err(error.description());
+            }
+        };
+        let context_dir = &runner_options.context_dir;
+        let entries = match runner::resolve_entries(
+            &hurl_file,
+            Some(filename),
+            context_dir,
+            runner_options.parser_limits,
+            variables,
+        ) {
+            Ok(entries) => entries,
+            Err(error) => {
+                let stderr = Stderr::new(WriteMode::Immediate);
+                let mut logger = Logger::new(logger_options, stderr, &secrets);
+                let message = error.render(
+                    &filename.to_string(),
+                    content,
+                    None,
+                    OutputFormat::Terminal(logger.color),
+                );
+                logger.error_rich(&message);
+                return Err(error.description());
+            }
+        };
+
+        let pacer = Pacer::new(self.throughput);
+        let start = Instant::now();
+        let deadline = start + self.duration;
+
+        let mut stats = thread::scope(|s| {
+            let users = (0..self.concurrency)
+                .map(|_| {
+                    // Each virtual user has its own copy of the run inputs.
+                    let entries = entries.clone();
+                    let runner_options = runner_options.clone();
+                    let variables = variables.clone();
+                    let logger_options = logger_options.clone();
+                    let secrets = secrets.clone();
+                    let mut pacer = &pacer;
+                    s.spawn(move || {
+                        let mut stats = LoadStats::new();
+                        while Instant::now() < deadline {
+                            // Outputs of the run are buffered and discarded.
+                            let mut stdout = Stdout::new(WriteMode::Buffered);
+                            let stderr = Stderr::new(WriteMode::Buffered);
+                            let mut logger = Logger::new(&logger_options, stderr, &secrets);
+                            let result = runner::run_entries(
+                                &entries,
+                                content,
+                                Some(filename),
+                                &runner_options,
+                                &variables,
+                                &mut stdout,
+                                Some(&mut pacer),
+                                &mut logger,
+                            );
+                            stats.add(&result);
+                        }
+                        stats
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            let mut stats = LoadStats::new();
+            for user in users {
+                stats.merge(user.join().unwrap());
+            }
+            stats
+        });
+        stats.duration = start.elapsed();
+        Ok(stats)
+    }
+}
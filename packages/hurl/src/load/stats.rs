@@ -0,0 +1,234 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::time::Duration;
+
+use crate::runner::HurlResult;
+
+/// Upper bounds of the latency histogram buckets, in milliseconds. Latencies above the last bound
+/// are counted in an overflow bucket.
+const BUCKETS_MS: [u64; 14] = [
+    1, 2, 5, 10, 20, 50, 100, 200, 500, 1000, 2000, 5000, 10000, 30000,
+];
+
+/// Statistics collected during a load test.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LoadStats {
+    /// Number of runs of the Hurl file.
+    pub runs: usize,
+    /// Number of runs with assert errors only.
+    pub assert_failed_runs: usize,
+    /// Number of runs with runtime errors (HTTP connection errors, timeouts etc...).
+    pub runtime_failed_runs: usize,
+    /// Number of requests with errors.
+    pub failed_requests: usize,
+    /// Latencies of all the requests, in order of completion. When redirections are followed, the
+    /// latency of a request includes all its redirections.
+    pub latencies: Vec<Duration>,
+    /// Effective duration of the load test.
+    pub duration: Duration,
+}
+
+/// A bucket of a latency histogram: number of requests whose latency is above `lower_bound` and
+/// below or equal to `upper_bound`. The last bucket has no upper bound.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bucket {
+    pub lower_bound: Duration,
+    pub upper_bound: Option<Duration>,
+    pub count: usize,
+}
+
+impl LoadStats {
+    /// Creates empty load statistics.
+    pub fn new() -> Self {
+        LoadStats::default()
+    }
+
+    /// Adds the result of a run of the Hurl file to these statistics.
+    pub fn add(&mut self, result: &HurlResult) {
+        self.runs += 1;
+        let errors = result.errors();
+        if errors.iter().any(|(error, _)| !error.assert) {
+            self.runtime_failed_runs += 1;
+        } else if !errors.is_empty() {
+            self.assert_failed_runs += 1;
+        }
+        for entry in result.entries.iter() {
+            self.latencies.push(entry.transfer_duration);
+            if !entry.errors.is_empty() {
+                self.failed_requests += 1;
+            }
+        }
+    }
+
+    /// Merges the statistics of another virtual user `other` into these statistics.
+    pub fn merge(&mut self, other: LoadStats) {
+        self.runs += other.runs;
+        self.assert_failed_runs += other.assert_failed_runs;
+        self.runtime_failed_runs += other.runtime_failed_runs;
+        self.failed_requests += other.failed_requests;
+        self.latencies.extend(other.latencies);
+    }
+
+    /// Returns the number of failed runs.
+    pub fn failed_runs(&self) -> usize {
+        self.assert_failed_runs + self.runtime_failed_runs
+    }
+
+    /// Returns the number of requests.
+    pub fn requests(&self) -> usize {
+        self.latencies.len()
+    }
+
+    /// Returns the minimum latency, or `None` if no request has been run.
+    pub fn min(&self) -> Option<Duration> {
+        self.latencies.iter().min().copied()
+    }
+
+    /// Returns the maximum latency, or `None` if no request has been run.
+    pub fn max(&self) -> Option<Duration> {
+        self.latencies.iter().max().copied()
+    }
+
+    /// Returns the mean latency, or `None` if no request has been run.
+    pub fn mean(&self) -> Option<Duration> {
+        if self.latencies.is_empty() {
+            return None;
+        }
+        let total = self.latencies.iter().sum::<Duration>();
+        Some(total / self.latencies.len() as u32)
+    }
+
+    /// Returns the `percentile` latency (between 0 and 100), using the nearest-rank method, or
+    /// `None` if no request has been run.
+    pub fn percentile(&self, percentile: u64) -> Option<Duration> {
+        if self.latencies.is_empty() {
+            return None;
+        }
+        let mut latencies = self.latencies.clone();
+        latencies.sort();
+        let count = latencies.len() as u64;
+        let rank = (percentile.min(100) * count).div_ceil(100).max(1);
+        Some(latencies[(rank - 1) as usize])
+    }
+
+    /// Returns the latency histogram, from the first to the last non-empty bucket.
+    pub fn histogram(&self) -> Vec<Bucket> {
+        let mut buckets = vec![];
+        let mut lower_bound = Duration::ZERO;
+        for ms in BUCKETS_MS {
+            let upper_bound = Duration::from_millis(ms);
+            buckets.push(Bucket {
+                lower_bound,
+                upper_bound: Some(upper_bound),
+                count: 0,
+            });
+            lower_bound = upper_bound;
+        }
+        buckets.push(Bucket {
+            lower_bound,
+            upper_bound: None,
+            count: 0,
+        });
+        for latency in self.latencies.iter() {
+            let index = BUCKETS_MS
+                .iter()
+                .position(|ms| *latency <= Duration::from_millis(*ms))
+                .unwrap_or(BUCKETS_MS.len());
+            buckets[index].count += 1;
+        }
+        let Some(first) = buckets.iter().position(|b| b.count > 0) else {
+            return vec![];
+        };
+        let last = buckets.iter().rposition(|b| b.count > 0).unwrap();
+        buckets[first..=last].to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn new_stats(latencies_ms: &[u64]) -> LoadStats {
+        LoadStats {
+            latencies: latencies_ms
+                .iter()
+                .map(|ms| Duration::from_millis(*ms))
+                .collect(),
+            ..LoadStats::default()
+        }
+    }
+
+    #[test]
+    fn test_latencies() {
+        let stats = new_stats(&[30, 10, 20, 50, 40]);
+        assert_eq!(stats.requests(), 5);
+        assert_eq!(stats.min(), Some(Duration::from_millis(10)));
+        assert_eq!(stats.max(), Some(Duration::from_millis(50)));
+        assert_eq!(stats.mean(), Some(Duration::from_millis(30)));
+        assert_eq!(stats.percentile(50), Some(Duration::from_millis(30)));
+        assert_eq!(stats.percentile(90), Some(Duration::from_millis(50)));
+        assert_eq!(stats.percentile(0), Some(Duration::from_millis(10)));
+
+        let stats = LoadStats::new();
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.percentile(99), None);
+    }
+
+    #[test]
+    fn test_histogram() {
+        let stats = new_stats(&[3, 4, 8, 45, 45, 45]);
+        assert_eq!(
+            stats.histogram(),
+            vec![
+                Bucket {
+                    lower_bound: Duration::from_millis(2),
+                    upper_bound: Some(Duration::from_millis(5)),
+                    count: 2
+                },
+                Bucket {
+                    lower_bound: Duration::from_millis(5),
+                    upper_bound: Some(Duration::from_millis(10)),
+                    count: 1
+                },
+                Bucket {
+                    lower_bound: Duration::from_millis(10),
+                    upper_bound: Some(Duration::from_millis(20)),
+                    count: 0
+                },
+                Bucket {
+                    lower_bound: Duration::from_millis(20),
+                    upper_bound: Some(Duration::from_millis(50)),
+                    count: 3
+                },
+            ]
+        );
+
+        let stats = new_stats(&[60_000]);
+        assert_eq!(
+            stats.histogram(),
+            vec![Bucket {
+                lower_bound: Duration::from_millis(30_000),
+                upper_bound: None,
+                count: 1
+            }]
+        );
+        assert!(LoadStats::new().histogram().is_empty());
+    }
+}
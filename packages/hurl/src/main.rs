@@ -18,7 +18,7 @@
 mod cli;
 mod run;
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::io::IsTerminal;
 use std::io::prelude::*;
 use std::path::Path;
@@ -26,14 +26,17 @@ use std::process::ExitCode;
 use std::time::Instant;
 use std::{env, io, thread};
 
-use hurl::report::{curl, html, json, junit, tap};
+use hurl::report::{
+    AllureRenderer, HtmlRenderer, JsonRenderer, JunitRenderer, PrettyRenderer, ReportRenderers,
+    ReportRun, RunMetadata, TapRenderer, curl,
+};
 use hurl::runner;
 use hurl::runner::HurlResult;
 use hurl::util::redacted::Redact;
-use hurl_core::input::Input;
+use hurl_core::input::{Input, InputKind};
 use hurl_core::text;
 
-use crate::cli::options::{CliOptions, CliOptionsError, RunContext, Verbosity};
+use crate::cli::options::{CliOptions, CliOptionsError, InputOptions, RunContext, Verbosity};
 use crate::cli::{BaseLogger, CliError};
 
 const EXIT_OK: u8 = 0;
@@ -70,7 +73,9 @@ fn main() -> ExitCode {
     let opts = match cli::options::parse(&ctx) {
         Ok(v) => v,
         Err(e) => match e {
-            CliOptionsError::DisplayHelp(e) | CliOptionsError::DisplayVersion(e) => {
+            CliOptionsError::DisplayHelp(e)
+            | CliOptionsError::DisplayVersion(e)
+            | CliOptionsError::DisplayCompletion(e) => {
                 print!("{e}");
                 return ExitCode::from(EXIT_OK);
             }
@@ -94,8 +99,32 @@ fn main() -> ExitCode {
         }
     };
     let current_dir = current_dir.as_path();
+    // Input files can have their own options, given by project files in their directories.
+    let mut input_options = InputOptions::new(&ctx, current_dir, &opts);
+
+    // Before running any file, we can wait for a service to be ready.
+    if let Some(url) = &opts.wait_for
+        && let Err(msg) = cli::wait_for(url, opts.wait_timeout, opts.insecure, &base_logger)
+    {
+        base_logger.error(&msg);
+        return ExitCode::from(EXIT_ERROR_RUNTIME);
+    }
+
     let start = Instant::now();
 
+    // In load test mode, a single file is replayed continuously and only a summary of the run is
+    // displayed.
+    if opts.is_load_test() {
+        return run_load_test(&opts, current_dir, &mut input_options, &base_logger);
+    }
+
+    // Incremental reports (like JUnit or HTML) are written as soon as each run is completed: the
+    // renderers listen to the completed runs. If every report is incremental, the bodies of the
+    // HTTP calls can be released once the run has been reported, so long runs don't hold every
+    // response in memory.
+    let mut renderers = report_renderers(&opts);
+    let release_bodies = !opts.generate_asserts && renderers.iter().all(|r| r.is_incremental());
+
     let runs = if opts.parallel {
         let available = match thread::available_parallelism() {
             Ok(a) => a,
@@ -107,9 +136,24 @@ fn main() -> ExitCode {
         let workers_count = opts.jobs.unwrap_or(available.get());
         base_logger.debug(&format!("Parallel run using {workers_count} workers"));
 
-        run::run_par(&opts.input_files, current_dir, &opts, workers_count)
+        run::run_par(
+            &opts.input_files,
+            current_dir,
+            &opts,
+            &mut input_options,
+            workers_count,
+            &mut renderers,
+            release_bodies,
+        )
     } else {
-        run::run_seq(&opts.input_files, current_dir, &opts)
+        run::run_seq(
+            &opts.input_files,
+            current_dir,
+            &opts,
+            &mut input_options,
+            &mut renderers,
+            release_bodies,
+        )
     };
     let runs = match runs {
         // Even in the presence of false assertions, `run::run_par` or `run::run_seq` return an `Ok`
@@ -136,13 +180,26 @@ fn main() -> ExitCode {
             return ExitCode::from(EXIT_ERROR_RUNTIME);
         }
     };
+    // Errors raised while adding the completed runs to the incremental reports.
+    if let Some(error) = renderers.take_error() {
+        base_logger.error(&error.to_string());
+        return ExitCode::from(EXIT_ERROR_RUNTIME);
+    }
 
     // Compute duration of the test here to not take reports writings into account.
     let duration = start.elapsed();
 
+    // Rewrite input files with asserts generated from the actual responses.
+    if opts.generate_asserts
+        && let Err(err) = write_generated_asserts(&runs, &base_logger)
+    {
+        base_logger.error(&err.to_string());
+        return ExitCode::from(EXIT_ERROR_UNDEFINED);
+    }
+
     // Write HTML, JUnit, TAP reports on disk.
     if has_report(&opts) {
-        let ret = export_results(&runs, &opts, &base_logger);
+        let ret = export_results(&runs, &opts, &mut renderers, &base_logger);
         if let Err(err) = ret {
             base_logger.error(&err.to_string());
             return ExitCode::from(EXIT_ERROR_UNDEFINED);
@@ -158,20 +215,64 @@ fn main() -> ExitCode {
     ExitCode::from(exit_code)
 }
 
+/// Runs the input file as a load test and displays the load test summary.
+fn run_load_test(
+    opts: &CliOptions,
+    current_dir: &Path,
+    input_options: &mut InputOptions,
+    base_logger: &BaseLogger,
+) -> ExitCode {
+    let stats = match run::run_load(&opts.input_files, current_dir, opts, input_options) {
+        Ok(s) => s,
+        Err(CliError::InvalidOption(msg)) => {
+            base_logger.error(&msg);
+            return ExitCode::from(EXIT_ERROR_COMMANDLINE);
+        }
+        Err(CliError::InputRead(msg)) | Err(CliError::GenericIO(msg)) => {
+            base_logger.error(&msg);
+            return ExitCode::from(EXIT_ERROR_PARSING);
+        }
+        Err(CliError::Parsing) => {
+            return ExitCode::from(EXIT_ERROR_PARSING);
+        }
+        Err(CliError::OutputWrite(msg)) => {
+            base_logger.error(&msg);
+            return ExitCode::from(EXIT_ERROR_RUNTIME);
+        }
+    };
+    let summary = cli::load_summary(&stats, opts.concurrency.unwrap_or(1), opts.throughput);
+    base_logger.info(summary.as_str());
+
+    let exit_code = if stats.runtime_failed_runs > 0 {
+        EXIT_ERROR_RUNTIME
+    } else if stats.assert_failed_runs > 0 {
+        EXIT_ERROR_ASSERT
+    } else {
+        EXIT_OK
+    };
+    ExitCode::from(exit_code)
+}
+
 /// Returns `true` if any kind of report should be created, `false` otherwise.
 fn has_report(opts: &CliOptions) -> bool {
     opts.curl_file.is_some()
         || opts.junit_file.is_some()
         || opts.tap_file.is_some()
+        || opts.pretty_file.is_some()
+        || opts.allure_dir.is_some()
         || opts.html_dir.is_some()
         || opts.json_report_dir.is_some()
         || opts.cookie_output_file.is_some()
+        || opts.export_variables_file.is_some()
 }
 
-/// Writes `runs` results on file, in HTML, TAP, JUnit or Cookie file format.
+/// Writes `runs` results on file, in HTML, TAP, JUnit, Allure, Cookie or variables file format.
+///
+/// Incremental `renderers` have already been given each run, their reports are completed here.
 fn export_results(
     runs: &[HurlRun],
     opts: &CliOptions,
+    renderers: &mut ReportRenderers,
     logger: &BaseLogger,
 ) -> Result<(), CliError> {
     // Compute secrets from the result. As secrets can be redacted during execution, we can't
@@ -182,32 +283,42 @@ fn export_results(
         .iter()
         .flat_map(|r| r.hurl_result.variables.secrets())
         .collect::<HashSet<_>>();
-    let secrets = secrets.iter().map(|s| s.as_ref()).collect::<Vec<_>>();
+    let mut secrets = secrets.iter().map(|s| s.as_ref()).collect::<Vec<_>>();
+    // Secrets are sorted so that reports are redacted the same way from one run to another.
+    secrets.sort_unstable();
 
     if let Some(file) = &opts.curl_file {
         create_curl_export(runs, file, &secrets)?;
     }
-    if let Some(file) = &opts.junit_file {
-        logger.debug(&format!("Writing JUnit report to {}", file.display()));
-        create_junit_report(runs, file, &secrets)?;
-    }
-    if let Some(file) = &opts.tap_file {
-        // TAP files doesn't need to be redacted, they don't expose any logs apart from files names.
-        logger.debug(&format!("Writing TAP report to {}", file.display()));
-        create_tap_report(runs, file)?;
-    }
-    if let Some(dir) = &opts.html_dir {
-        logger.debug(&format!("Writing HTML report to {}", dir.display()));
-        create_html_report(runs, dir, &secrets)?;
-    }
-    if let Some(dir) = &opts.json_report_dir {
-        logger.debug(&format!("Writing JSON report to {}", dir.display()));
-        create_json_report(runs, dir, &secrets)?;
+    if !renderers.is_empty() {
+        let args = env::args().collect::<Vec<_>>();
+        let env_vars = env::vars().collect::<HashMap<_, _>>();
+        let metadata = RunMetadata::collect(&args, &env_vars);
+        let report_runs = runs
+            .iter()
+            .map(|r| ReportRun::new(&r.content, &r.filename, &r.hurl_result))
+            .collect::<Vec<_>>();
+        for renderer in renderers.iter_mut() {
+            logger.debug(&format!(
+                "Writing {} report to {}",
+                renderer.name(),
+                renderer.path().display()
+            ));
+            if renderer.is_incremental() {
+                renderer.finish(&metadata, &secrets)?;
+            } else {
+                renderer.render(&report_runs, &metadata, &secrets)?;
+            }
+        }
     }
     if let Some(file) = &opts.cookie_output_file {
         logger.debug(&format!("Writing cookies to {}", file.display()));
         create_cookies_file(runs, file, &secrets)?;
     }
+    if let Some(file) = &opts.export_variables_file {
+        logger.debug(&format!("Writing variables to {}", file.display()));
+        create_variables_file(runs, file)?;
+    }
     Ok(())
 }
 
@@ -218,61 +329,61 @@ fn create_curl_export(runs: &[HurlRun], filename: &Path, secrets: &[&str]) -> Re
     Ok(())
 }
 
-/// Creates a JUnit report for this run.
-fn create_junit_report(
-    runs: &[HurlRun],
-    filename: &Path,
-    secrets: &[&str],
-) -> Result<(), CliError> {
-    let testcases = runs
-        .iter()
-        .map(|r| junit::Testcase::from(&r.hurl_result, &r.content, &r.filename))
-        .collect::<Vec<_>>();
-    junit::write_report(filename, &testcases, secrets)?;
-    Ok(())
-}
-
-/// Creates a TAP report for this run.
-fn create_tap_report(runs: &[HurlRun], filename: &Path) -> Result<(), CliError> {
-    let testcases = runs
-        .iter()
-        .map(|r| tap::Testcase::from(&r.hurl_result, &r.filename))
-        .collect::<Vec<_>>();
-    tap::write_report(filename, &testcases)?;
-    Ok(())
-}
-
-/// Creates an HTML report for this run.
-fn create_html_report(runs: &[HurlRun], dir_path: &Path, secrets: &[&str]) -> Result<(), CliError> {
-    // We ensure that the containing folder exists.
-    let store_path = dir_path.join("store");
-    std::fs::create_dir_all(&store_path)?;
-
-    let mut testcases = vec![];
+/// Rewrites the input files of `runs` with asserts generated from their actual responses.
+///
+/// Files read from standard input are written to standard output.
+fn write_generated_asserts(runs: &[HurlRun], logger: &BaseLogger) -> Result<(), CliError> {
     for run in runs.iter() {
-        let result = &run.hurl_result;
-        let testcase = html::Testcase::from(result, &run.filename);
-        testcase.write_html(&run.content, &result.entries, &store_path, secrets)?;
-        testcases.push(testcase);
+        let content =
+            hurl::generate::generate_asserts(&run.content, &run.hurl_result).map_err(|e| {
+                CliError::GenericIO(format!(
+                    "Issue generating asserts for {}: {e}",
+                    run.filename
+                ))
+            })?;
+        match run.filename.kind() {
+            InputKind::File(path) => {
+                logger.debug(&format!("Writing generated asserts to {}", path.display()));
+                if let Err(why) = std::fs::write(path, content) {
+                    return Err(CliError::GenericIO(format!(
+                        "Issue writing to {}: {why:?}",
+                        path.display()
+                    )));
+                }
+            }
+            InputKind::Stdin(_) => print!("{content}"),
+        }
     }
-    html::write_report(dir_path, &testcases)?;
     Ok(())
 }
 
-/// Creates an JSON report for this run.
-fn create_json_report(runs: &[HurlRun], dir_path: &Path, secrets: &[&str]) -> Result<(), CliError> {
-    // We ensure that the containing folder exists.
-    let store_path = dir_path.join("store");
-    std::fs::create_dir_all(&store_path)?;
-
-    let testcases = runs
-        .iter()
-        .map(|r| json::Testcase::new(&r.hurl_result, &r.content, &r.filename))
-        .collect::<Vec<_>>();
-
-    let index_path = dir_path.join("report.json");
-    json::write_report(&index_path, &testcases, &store_path, secrets)?;
-    Ok(())
+/// Returns the list of report renderers requested on the command line.
+fn report_renderers(opts: &CliOptions) -> ReportRenderers {
+    let deterministic = opts.report_deterministic;
+    let mut renderers = ReportRenderers::new();
+    if let Some(file) = &opts.junit_file {
+        let renderer = JunitRenderer::new(file).deterministic(deterministic);
+        renderers.register(Box::new(renderer));
+    }
+    if let Some(file) = &opts.tap_file {
+        renderers.register(Box::new(TapRenderer::new(file)));
+    }
+    if let Some(file) = &opts.pretty_file {
+        renderers.register(Box::new(PrettyRenderer::new(file)));
+    }
+    if let Some(dir) = &opts.html_dir {
+        let renderer = HtmlRenderer::new(dir).deterministic(deterministic);
+        renderers.register(Box::new(renderer));
+    }
+    if let Some(dir) = &opts.json_report_dir {
+        let renderer = JsonRenderer::new(dir).deterministic(deterministic);
+        renderers.register(Box::new(renderer));
+    }
+    if let Some(dir) = &opts.allure_dir {
+        let renderer = AllureRenderer::new(dir).deterministic(deterministic);
+        renderers.register(Box::new(renderer));
+    }
+    renderers
 }
 
 /// Returns an exit code for a list of HurlResult.
@@ -345,3 +456,38 @@ fn create_cookies_file(
     }
     Ok(())
 }
+
+/// Export the variables of `runs` to `filename` file, as a JSON object.
+///
+/// Variables of later runs override those of earlier runs with the same name. Secret variables are
+/// not exported, so the file can be read back with `--load-state` without leaking them.
+fn create_variables_file(runs: &[HurlRun], filename: &Path) -> Result<(), CliError> {
+    if let Err(err) = hurl::util::path::create_dir_all(filename) {
+        return Err(CliError::GenericIO(format!(
+            "Issue creating parent directories for {}: {err:?}",
+            filename.display()
+        )));
+    }
+
+    let mut variables = serde_json::Map::new();
+    for run in runs.iter() {
+        for (name, variable) in run.hurl_result.variables.iter() {
+            if variable.is_secret() {
+                continue;
+            }
+            variables.insert(name.clone(), variable.value().to_json(&[]));
+        }
+    }
+    let json = serde_json::Value::Object(variables);
+    let mut s = serde_json::to_string_pretty(&json)
+        .map_err(|e| CliError::GenericIO(format!("Issue serializing variables: {e}")))?;
+    s.push('\n');
+
+    if let Err(why) = std::fs::write(filename, s) {
+        return Err(CliError::GenericIO(format!(
+            "Issue writing to {}: {why:?}",
+            filename.display()
+        )));
+    }
+    Ok(())
+}
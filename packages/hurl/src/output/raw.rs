@@ -77,6 +77,10 @@ mod tests {
             url: Url::from_str("http://localhost").unwrap(),
             certificate: None,
             ip_addr: Default::default(),
+            proxy_status: None,
+            hsts_upgraded: false,
+            interim_responses: vec![],
+            timings: Default::default(),
         }
     }
 
@@ -139,6 +143,10 @@ mod tests {
                             url: Url::from_str("https://baz.com").unwrap(),
                             certificate: None,
                             ip_addr: Default::default(),
+                            proxy_status: None,
+                            hsts_upgraded: false,
+                            interim_responses: vec![],
+                            timings: Default::default(),
                         },
                         timings: Default::default(),
                     }],
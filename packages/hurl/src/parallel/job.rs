@@ -15,6 +15,9 @@
  * limitations under the License.
  *
  */
+use std::sync::Arc;
+
+use hurl_core::ast::HurlFile;
 use hurl_core::input::Input;
 use hurl_core::types::Count;
 
@@ -35,6 +38,20 @@ pub struct Job {
     pub logger_options: LoggerOptions,
     /// The job 0-based index in the jobs list
     pub seq: usize,
+    /// The names of the captures exported by this job to the jobs run after it.
+    pub exports: Vec<String>,
+    /// The Hurl file of this job, if it has already been read and parsed before the run (to list
+    /// its exported captures). Otherwise, the file is read and parsed by the worker running the job.
+    pub source: Option<Arc<ParsedFile>>,
+}
+
+/// A Hurl file read and parsed ahead of its run.
+#[derive(Debug, Eq, PartialEq)]
+pub struct ParsedFile {
+    /// The Hurl source content.
+    pub content: String,
+    /// The Hurl file parsed from `content`.
+    pub hurl_file: HurlFile,
 }
 
 impl Job {
@@ -45,6 +62,7 @@ impl Job {
         runner_options: &RunnerOptions,
         variables: &VariableSet,
         logger_options: &LoggerOptions,
+        exports: &[String],
     ) -> Self {
         Job {
             filename: filename.clone(),
@@ -52,6 +70,8 @@ impl Job {
             variables: variables.clone(),
             logger_options: logger_options.clone(),
             seq,
+            exports: exports.to_vec(),
+            source: None,
         }
     }
 }
@@ -167,6 +187,7 @@ mod tests {
             &runner_options,
             &variables,
             &logger_options,
+            &[],
         )
     }
 
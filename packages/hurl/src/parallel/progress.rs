@@ -373,6 +373,7 @@ mod tests {
                     &runner_options,
                     &variables,
                     &logger_options,
+                    &[],
                 )
             })
             .collect()
@@ -25,6 +25,7 @@ use super::progress::{Mode, ParProgress};
 use super::worker::{Worker, WorkerId};
 use crate::output;
 use crate::pretty::PrettyMode;
+use crate::runner::VariableSet;
 use crate::util::term::{Stderr, Stdout, WriteMode};
 use hurl_core::error::{DisplaySourceError, OutputFormat};
 use hurl_core::types::{Count, Index};
@@ -155,6 +156,20 @@ impl ParallelRunner {
     /// order of the `jobs` is the same as the order of the `jobs` results, independently of the
     /// worker's count.
     pub fn run(&mut self, jobs: &[Job]) -> Result<Vec<JobResult>, JobError> {
+        self.run_with(jobs, &mut |_| Ok(()))
+    }
+
+    /// Runs a list of [`Job`] in parallel and returns the results, calling `on_completed` on each
+    /// job result as soon as the job is completed.
+    ///
+    /// Jobs are completed in any order, the results returned are ordered by the sequence number
+    /// (see [`ParallelRunner::run`]). `on_completed` can modify the job result, for instance to
+    /// release data that is not needed anymore once the result has been processed.
+    pub fn run_with(
+        &mut self,
+        jobs: &[Job],
+        on_completed: &mut dyn FnMut(&mut JobResult) -> Result<(), JobError>,
+    ) -> Result<Vec<JobResult>, JobError> {
         // The parallel runner runs on the main thread. It's responsible for displaying standard
         // output and standard error. Workers are buffering their output and error in memory, and
         // delegate the display to the runners.
@@ -162,15 +177,14 @@ impl ParallelRunner {
         let mut stderr = Stderr::new(WriteMode::Immediate);
 
         // Create the jobs queue:
-        let mut queue = JobQueue::new(jobs, self.repeat);
+        let queue = JobQueue::new(jobs, self.repeat);
         let jobs_count = queue.jobs_count();
+        let mut scheduler = Scheduler::new(queue);
 
         // Initiate the runner, fill our workers:
-        self.workers.iter().for_each(|_| {
-            if let Some(job) = queue.next() {
-                _ = self.tx.as_ref().unwrap().send(job);
-            }
-        });
+        while let Some(job) = scheduler.next(self.workers.len()) {
+            _ = self.tx.as_ref().unwrap().send(job);
+        }
 
         // When dumped HTTP responses, we truncate existing output file on first save, then append
         // it on subsequent write.
@@ -223,7 +237,7 @@ impl ParallelRunner {
                 // Contrary to when we receive a running message, we clear the progress bar no
                 // matter what the frequency is, to get a "correct" and up-to-date display on any
                 // test completion.
-                WorkerMessage::Completed(msg) => {
+                WorkerMessage::Completed(mut msg) => {
                     self.progress.clear_progress_bar(&mut stderr);
 
                     // The worker is becoming idle.
@@ -249,7 +263,9 @@ impl ParallelRunner {
 
                     // Report the completion of this job and update the progress.
                     self.progress.print_completed(&msg.result, &mut stderr);
+                    on_completed(&mut msg.result)?;
 
+                    scheduler.complete(&msg.result);
                     results.push(msg.result);
 
                     self.progress.update_progress_bar(
@@ -264,20 +280,16 @@ impl ParallelRunner {
                     // running messages received leads to a progress bar refresh.
                     self.progress.force_next_update();
 
-                    // We run the next job to process:
-                    let job = queue.next();
-                    match job {
-                        Some(job) => {
-                            _ = self.tx.as_ref().unwrap().send(job);
-                        }
-                        None => {
-                            // If we have received all the job results, we can stop the run.
-                            if let Count::Finite(jobs_count) = jobs_count
-                                && results.len() == jobs_count
-                            {
-                                break;
-                            }
-                        }
+                    // We run the next jobs to process:
+                    while let Some(job) = scheduler.next(self.workers.len()) {
+                        _ = self.tx.as_ref().unwrap().send(job);
+                    }
+
+                    // If we have received all the job results, we can stop the run.
+                    if let Count::Finite(jobs_count) = jobs_count
+                        && results.len() == jobs_count
+                    {
+                        break;
                     }
                 }
             }
@@ -358,3 +370,137 @@ impl ParallelRunner {
         Ok(())
     }
 }
+
+/// Schedules the jobs of a queue on the workers.
+///
+/// Jobs are usually run as soon as a worker is available. A job exporting captures (with
+/// `export-capture` options) is run alone: it waits for the running jobs to complete, and no other
+/// job is run until it completes. Its exported captures are then injected in the variables of all
+/// the jobs run after it.
+struct Scheduler<'job> {
+    /// The queue of jobs to run.
+    queue: JobQueue<'job>,
+    /// A job taken from the queue, waiting for the running jobs to complete.
+    pending: Option<Job>,
+    /// Number of jobs currently running.
+    running: usize,
+    /// `true` if the running job is exporting captures and must be run alone.
+    exclusive: bool,
+    /// The captures exported by the completed jobs.
+    exports: VariableSet,
+}
+
+impl<'job> Scheduler<'job> {
+    /// Creates a new scheduler for this `queue`.
+    fn new(queue: JobQueue<'job>) -> Self {
+        Scheduler {
+            queue,
+            pending: None,
+            running: 0,
+            exclusive: false,
+            exports: VariableSet::new(),
+        }
+    }
+
+    /// Returns the next job to run given a number of `workers_count` workers, or `None` if no job
+    /// can be run for the moment.
+    fn next(&mut self, workers_count: usize) -> Option<Job> {
+        if self.exclusive || self.running >= workers_count {
+            return None;
+        }
+        let mut job = self.pending.take().or_else(|| self.queue.next())?;
+        if !job.exports.is_empty() {
+            if self.running > 0 {
+                self.pending = Some(job);
+                return None;
+            }
+            self.exclusive = true;
+        }
+        job.variables.extend(&self.exports);
+        self.running += 1;
+        Some(job)
+    }
+
+    /// Notifies the scheduler that a job has been completed with this `result`.
+    fn complete(&mut self, result: &JobResult) {
+        self.running = self.running.saturating_sub(1);
+        let job = &result.job;
+        if !job.exports.is_empty() {
+            self.exports
+                .copy_from(&result.hurl_result.variables, &job.exports);
+            self.exclusive = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::input::Input;
+    use hurl_core::types::Count;
+
+    use super::Scheduler;
+    use crate::parallel::job::{Job, JobQueue, JobResult};
+    use crate::runner::{HurlResult, RunnerOptionsBuilder, Value, VariableSet};
+    use crate::util::logger::LoggerOptionsBuilder;
+
+    fn new_job(file: &str, index: usize, exports: &[String]) -> Job {
+        let variables = VariableSet::new();
+        let runner_options = RunnerOptionsBuilder::default().build();
+        let logger_options = LoggerOptionsBuilder::default().build();
+        Job::new(
+            &Input::new(file),
+            index,
+            &runner_options,
+            &variables,
+            &logger_options,
+            exports,
+        )
+    }
+
+    fn new_result(job: Job, variables: VariableSet) -> JobResult {
+        let hurl_result = HurlResult {
+            variables,
+            ..Default::default()
+        };
+        JobResult::new(job, String::new(), hurl_result)
+    }
+
+    #[test]
+    fn scheduler_runs_exporting_job_alone() {
+        let jobs = [
+            new_job("a.hurl", 0, &[]),
+            new_job("login.hurl", 1, &["token".to_string()]),
+            new_job("b.hurl", 2, &[]),
+            new_job("c.hurl", 3, &[]),
+        ];
+        let queue = JobQueue::new(&jobs, Count::Finite(1));
+        let mut scheduler = Scheduler::new(queue);
+
+        // The exporting job waits for the running job to complete.
+        let a = scheduler.next(4).unwrap();
+        assert_eq!(a.filename.to_string(), "a.hurl");
+        assert!(scheduler.next(4).is_none());
+        scheduler.complete(&new_result(a, VariableSet::new()));
+
+        // The exporting job is run alone.
+        let login = scheduler.next(4).unwrap();
+        assert_eq!(login.filename.to_string(), "login.hurl");
+        assert!(scheduler.next(4).is_none());
+        let mut variables = VariableSet::new();
+        variables.insert("token".to_string(), Value::String("abc".to_string()));
+        variables.insert("user".to_string(), Value::String("bob".to_string()));
+        scheduler.complete(&new_result(login, variables));
+
+        // The next jobs are run in parallel, with the exported captures.
+        let b = scheduler.next(4).unwrap();
+        let c = scheduler.next(4).unwrap();
+        assert!(scheduler.next(4).is_none());
+        for job in [b, c] {
+            assert_eq!(
+                job.variables.get("token").unwrap().value(),
+                &Value::String("abc".to_string())
+            );
+            assert!(job.variables.get("user").is_none());
+        }
+    }
+}
@@ -22,12 +22,11 @@ use std::{fmt, thread};
 use super::job::{Job, JobResult};
 use super::message::{CompletedMsg, InputReadErrorMsg, ParsingErrorMsg, RunningMsg, WorkerMessage};
 use crate::runner;
-use crate::runner::EventListener;
+use crate::runner::{Event, EventListener};
 use crate::util::logger::Logger;
 use crate::util::term::{Stderr, Stdout, WriteMode};
 use hurl_core::error::{DisplaySourceError, OutputFormat};
 use hurl_core::parser;
-use hurl_core::types::Index;
 
 /// A worker runs job in its own thread.
 pub struct Worker {
@@ -89,9 +88,13 @@ impl Worker {
                 let mut logger = Logger::new(&job.logger_options, stderr, &secrets);
 
                 // Create a worker progress listener.
-                let progress = WorkerProgress::new(worker_id, &job, &tx);
+                let mut progress = WorkerProgress::new(worker_id, &job, &tx);
 
-                let content = job.filename.read_to_string();
+                // Files exporting captures have already been read and parsed before the run.
+                let content = match &job.source {
+                    Some(source) => Ok(source.content.clone()),
+                    None => job.filename.read_to_string(),
+                };
                 let content = match content {
                     Ok(c) => c,
                     Err(e) => {
@@ -102,9 +105,43 @@ impl Worker {
                 };
 
                 // Try to parse the content
-                let hurl_file = parser::parse_hurl_file(&content);
-                let hurl_file = match hurl_file {
-                    Ok(h) => h,
+                let parsed_file;
+                let hurl_file = match &job.source {
+                    Some(source) => &source.hurl_file,
+                    None => match parser::parse_hurl_file_with_limits(
+                        &content,
+                        job.runner_options.parser_limits,
+                    ) {
+                        Ok(h) => {
+                            parsed_file = h;
+                            &parsed_file
+                        }
+                        Err(error) => {
+                            let filename = job.filename.to_string();
+                            let message = error.render(
+                                &filename,
+                                &content,
+                                None,
+                                OutputFormat::Terminal(logger.color),
+                            );
+                            logger.error_rich(&message);
+                            let msg = ParsingErrorMsg::new(worker_id, &job, &logger.stderr);
+                            _ = tx.send(WorkerMessage::ParsingError(msg));
+                            return;
+                        }
+                    },
+                };
+
+                // Included Hurl files are resolved before running any entry.
+                let entries = runner::resolve_entries(
+                    hurl_file,
+                    Some(&job.filename),
+                    &job.runner_options.context_dir,
+                    job.runner_options.parser_limits,
+                    &job.variables,
+                );
+                let entries = match entries {
+                    Ok(entries) => entries,
                     Err(error) => {
                         let filename = job.filename.to_string();
                         let message = error.render(
@@ -122,13 +159,13 @@ impl Worker {
 
                 // Now, we have a syntactically correct HurlFile instance, we can run it.
                 let result = runner::run_entries(
-                    &hurl_file.entries,
+                    &entries,
                     &content,
                     Some(&job.filename),
                     &job.runner_options,
                     &job.variables,
                     &mut stdout,
-                    Some(&progress),
+                    Some(&mut progress),
                     &mut logger,
                 );
 
@@ -173,8 +210,15 @@ impl WorkerProgress {
 }
 
 impl EventListener for WorkerProgress {
-    fn on_entry_running(&self, current: Index, last: Index, retry_count: usize) {
-        let msg = RunningMsg::new(self.worker_id, &self.job, current, last, retry_count);
-        _ = self.tx.send(WorkerMessage::Running(msg));
+    fn on_event(&mut self, event: &Event) {
+        if let Event::EntryStarted {
+            current,
+            last,
+            retry_count,
+        } = event
+        {
+            let msg = RunningMsg::new(self.worker_id, &self.job, *current, *last, *retry_count);
+            _ = self.tx.send(WorkerMessage::Running(msg));
+        }
     }
 }
@@ -0,0 +1,98 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Allure report.
+//!
+//! The Allure report is a directory of Allure results, that can be rendered with the Allure
+//! tools (see <https://allurereport.org>). Each Hurl file is exported as a test result, whose
+//! steps are the entries of the file. Requests and responses are attached to each step:
+//!
+//! ```text
+//! allure-results
+//! ├── 1fe9d647-5689-4130-b4ea-dc120c2536ba-result.json
+//! ├── 35f49c69-15f9-43df-a672-a1ff5f68c935-attachment.txt
+//! ...
+//! ├── ce7f1326-2e2a-46e9-befd-ee0d85084814-attachment.txt
+//! └── environment.properties
+//! ```
+//!
+//! The run metadata (Hurl version, git commit etc...) are written in `environment.properties`.
+mod testcase;
+
+use std::fs;
+use std::path::Path;
+
+pub use testcase::Testcase;
+
+use crate::report::{ReportError, RunMetadata};
+use crate::util::redacted::Redact;
+
+/// Writes the Allure results of a list of `testcases` to the directory `dir`.
+///
+/// Existing results in `dir` are kept, so results of multiple runs can be aggregated. `secrets`
+/// strings are redacted from the results.
+pub fn write_report(
+    dir: &Path,
+    testcases: &[Testcase],
+    metadata: &RunMetadata,
+    secrets: &[&str],
+) -> Result<(), ReportError> {
+    fs::create_dir_all(dir)
+        .map_err(|e| ReportError::from_io_error(&e, dir, "Issue writing Allure report"))?;
+
+    for testcase in testcases {
+        let path = dir.join(format!("{}-result.json", testcase.uuid));
+        let serialized = serde_json::to_string(testcase)?;
+        write_file(&path, &serialized.redact(secrets))?;
+        for attachment in testcase.attachments.iter() {
+            let path = dir.join(&attachment.source);
+            write_file(&path, &attachment.content.redact(secrets))?;
+        }
+    }
+
+    let properties = metadata
+        .properties()
+        .iter()
+        .map(|(name, value)| format!("{name}={}\n", escape_property(value)))
+        .collect::<String>();
+    let path = dir.join("environment.properties");
+    write_file(&path, &properties.redact(secrets))
+}
+
+fn write_file(path: &Path, content: &str) -> Result<(), ReportError> {
+    fs::write(path, content)
+        .map_err(|e| ReportError::from_io_error(&e, path, "Issue writing Allure report"))
+}
+
+/// Escapes a `value` of a Java properties file.
+fn escape_property(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_property() {
+        assert_eq!(escape_property("hurl --test a.hurl"), "hurl --test a.hurl");
+        assert_eq!(escape_property("C:\\tests\na"), "C:\\\\tests\\na");
+    }
+}
@@ -0,0 +1,487 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::error::{DisplaySourceError, OutputFormat};
+use hurl_core::input::Input;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::http::Call;
+use crate::report::{RunMetadata, deterministic};
+use crate::runner::{AssertResult, EntryResult, HurlResult, RunnerError};
+
+/// An Allure test result: one Hurl file, whose steps are the file entries.
+///
+/// This structure is serialized to a `{uuid}-result.json` file, following the Allure results
+/// format (see <https://allurereport.org/docs/how-it-works-test-result-file/>).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Testcase {
+    pub(crate) uuid: String,
+    history_id: String,
+    name: String,
+    full_name: String,
+    status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status_details: Option<StatusDetails>,
+    stage: Stage,
+    start: i64,
+    stop: i64,
+    labels: Vec<Label>,
+    steps: Vec<Step>,
+    /// Content of the attachments referenced by the steps, written alongside the result file.
+    #[serde(skip)]
+    pub(crate) attachments: Vec<AttachmentFile>,
+}
+
+/// Status of an Allure test or step: `failed` is used for asserts errors, `broken` for runtime
+/// errors.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Status {
+    Passed,
+    Failed,
+    Broken,
+}
+
+/// Stage of an Allure test or step: reports are written at the end of a run, so every test is
+/// finished.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Stage {
+    Finished,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+struct StatusDetails {
+    message: String,
+    trace: String,
+}
+
+/// A label of an Allure test, used to group and filter tests in dashboards.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+struct Label {
+    name: String,
+    value: String,
+}
+
+/// A step of an Allure test: an entry of a Hurl file, or an assert of an entry.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Step {
+    name: String,
+    status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status_details: Option<StatusDetails>,
+    stage: Stage,
+    start: i64,
+    stop: i64,
+    steps: Vec<Step>,
+    attachments: Vec<Attachment>,
+}
+
+/// A reference from a step to an attachment file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+struct Attachment {
+    name: String,
+    source: String,
+    #[serde(rename = "type")]
+    mime_type: String,
+}
+
+/// The content of an attachment, saved as `source` in the results directory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttachmentFile {
+    pub(crate) source: String,
+    pub(crate) content: String,
+}
+
+impl Testcase {
+    /// Creates an Allure testcase from an [`HurlResult`], with the run `metadata`.
+    ///
+    /// The identifiers of the attachments of this testcase are derived from its `uuid`.
+    pub fn from(
+        hurl_result: &HurlResult,
+        content: &str,
+        filename: &Input,
+        metadata: &RunMetadata,
+        uuid: Uuid,
+    ) -> Testcase {
+        let name = filename.to_string();
+        let start = hurl_result.timestamp * 1000;
+        let stop = start + hurl_result.duration.as_millis() as i64;
+
+        let errors = hurl_result
+            .errors()
+            .into_iter()
+            .map(|(error, entry)| {
+                let (filename, content) = entry.source(&name, content);
+                let source_info = Some(entry.source_info);
+                let trace = error.render(&filename, content, source_info, OutputFormat::Plain);
+                (error, trace)
+            })
+            .collect::<Vec<_>>();
+        let status = status(errors.iter().map(|(error, _)| *error));
+        let status_details = status_details(&errors);
+
+        let mut labels = vec![Label::new("framework", "hurl"), Label::new("suite", &name)];
+        if let Some(environment) = &metadata.environment {
+            labels.push(Label::new("tag", environment));
+        }
+
+        // As in [`HurlResult::errors`], retried entries are ignored: we only keep the last
+        // execution of each entry.
+        let mut steps = vec![];
+        let mut attachments = vec![];
+        let mut next_entries = hurl_result.entries.iter().skip(1);
+        for entry in hurl_result.entries.iter() {
+            let effective = match next_entries.next() {
+                None => true,
+                Some(next) => next.entry_index != entry.entry_index,
+            };
+            if effective {
+                let step = Step::from_entry(entry, &name, content, start, uuid, &mut attachments);
+                steps.push(step);
+            }
+        }
+
+        Testcase {
+            uuid: uuid.to_string(),
+            history_id: name.clone(),
+            name: name.clone(),
+            full_name: name,
+            status,
+            status_details,
+            stage: Stage::Finished,
+            start,
+            stop,
+            labels,
+            steps,
+            attachments,
+        }
+    }
+}
+
+impl Label {
+    fn new(name: &str, value: &str) -> Self {
+        Label {
+            name: name.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
+
+impl Step {
+    /// Creates a step from an `entry`, given the source `content` of the Hurl file (entries of
+    /// included files use the source of their own file).
+    ///
+    /// The requests and responses of the entry are added as text attachments to `attachments`,
+    /// with identifiers derived from the testcase `uuid`.
+    fn from_entry(
+        entry: &EntryResult,
+        filename: &str,
+        content: &str,
+        default_start: i64,
+        uuid: Uuid,
+        attachments: &mut Vec<AttachmentFile>,
+    ) -> Step {
+        let (filename, content) = entry.source(filename, content);
+        let lines = content.lines().collect::<Vec<_>>();
+        let line = entry.source_info.start.line;
+        let name = match entry.calls.first() {
+            Some(call) => format!("{} {}", call.request.method, call.request.url),
+            None => source_line(&lines, line),
+        };
+        let (start, stop) = match (entry.calls.first(), entry.calls.last()) {
+            (Some(first), Some(last)) => (
+                first.timings.begin_call.timestamp_millis(),
+                last.timings.end_call.timestamp_millis(),
+            ),
+            _ => (default_start, default_start),
+        };
+
+        let errors = entry
+            .errors
+            .iter()
+            .map(|error| {
+                let trace = error.render(
+                    &filename,
+                    content,
+                    Some(entry.source_info),
+                    OutputFormat::Plain,
+                );
+                (error, trace)
+            })
+            .collect::<Vec<_>>();
+
+        let steps = entry
+            .asserts
+            .iter()
+            .filter_map(|assert| Step::from_assert(assert, &lines, start, stop))
+            .collect::<Vec<_>>();
+
+        let mut step_attachments = vec![];
+        let redirected = entry.calls.len() > 1;
+        for (index, call) in entry.calls.iter().enumerate() {
+            let suffix = if redirected {
+                format!(" {}", index + 1)
+            } else {
+                String::new()
+            };
+            let request = AttachmentFile::new(request_text(call), uuid, attachments.len());
+            step_attachments.push(request.attachment(&format!("Request{suffix}")));
+            attachments.push(request);
+            let response = AttachmentFile::new(response_text(call), uuid, attachments.len());
+            step_attachments.push(response.attachment(&format!("Response{suffix}")));
+            attachments.push(response);
+        }
+
+        Step {
+            name,
+            status: status(errors.iter().map(|(error, _)| *error)),
+            status_details: status_details(&errors),
+            stage: Stage::Finished,
+            start,
+            stop,
+            steps,
+            attachments: step_attachments,
+        }
+    }
+
+    /// Creates a step from an `assert`, given the source `lines` of the Hurl file.
+    ///
+    /// Returns `None` for asserts that are always successful (like a wildcard `HTTP` version).
+    fn from_assert(assert: &AssertResult, lines: &[&str], start: i64, stop: i64) -> Option<Step> {
+        let name = match assert {
+            AssertResult::ImplicitVersion { expected, .. } => {
+                if expected == "HTTP" {
+                    return None;
+                }
+                format!("HTTP version is {expected}")
+            }
+            AssertResult::ImplicitStatus { expected, .. } => format!("status is {expected}"),
+            AssertResult::ImplicitHeader { source_info, .. }
+            | AssertResult::ImplicitBody { source_info, .. }
+            | AssertResult::Explicit { source_info, .. } => {
+                source_line(lines, source_info.start.line)
+            }
+        };
+        let (status, status_details) = match assert.to_runner_error() {
+            None => (Status::Passed, None),
+            Some(error) => (
+                Status::Failed,
+                Some(StatusDetails {
+                    message: error.description(),
+                    trace: String::new(),
+                }),
+            ),
+        };
+        Some(Step {
+            name,
+            status,
+            status_details,
+            stage: Stage::Finished,
+            start,
+            stop,
+            steps: vec![],
+            attachments: vec![],
+        })
+    }
+}
+
+impl AttachmentFile {
+    /// Creates a new text attachment, the `index`-th one of the testcase `uuid`.
+    fn new(content: String, uuid: Uuid, index: usize) -> Self {
+        let id = deterministic::stable_uuid(format!("{uuid}:{index}").as_bytes());
+        AttachmentFile {
+            source: format!("{id}-attachment.txt"),
+            content,
+        }
+    }
+
+    /// Returns a reference to this file, displayed as `name` in the report.
+    fn attachment(&self, name: &str) -> Attachment {
+        Attachment {
+            name: name.to_string(),
+            source: self.source.clone(),
+            mime_type: "text/plain".to_string(),
+        }
+    }
+}
+
+/// Returns the status of a test or a step, given its `errors`.
+fn status<'a>(errors: impl Iterator<Item = &'a RunnerError>) -> Status {
+    let mut status = Status::Passed;
+    for error in errors {
+        if !error.assert {
+            return Status::Broken;
+        }
+        status = Status::Failed;
+    }
+    status
+}
+
+/// Returns the status details of a test or a step, given its `errors` and their rendered
+/// messages.
+fn status_details(errors: &[(&RunnerError, String)]) -> Option<StatusDetails> {
+    let (first, _) = errors.first()?;
+    let trace = errors
+        .iter()
+        .map(|(_, trace)| trace.as_str())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    Some(StatusDetails {
+        message: first.description(),
+        trace,
+    })
+}
+
+/// Returns a text representation of the request of a `call`, with its headers and body.
+fn request_text(call: &Call) -> String {
+    let request = &call.request;
+    let mut text = format!("{} {}\n", request.method, request.url);
+    for header in request.headers.iter() {
+        text.push_str(&format!("{header}\n"));
+    }
+    if !request.body.is_empty() {
+        text.push('\n');
+        text.push_str(&String::from_utf8_lossy(&request.body));
+    }
+    text
+}
+
+/// Returns a text representation of the response of a `call`, with its headers and body.
+fn response_text(call: &Call) -> String {
+    let response = &call.response;
+    let mut text = format!("{} {}\n", response.version, response.status);
+    for header in response.headers.iter() {
+        text.push_str(&format!("{header}\n"));
+    }
+    if let Ok(body) = response.text()
+        && !body.is_empty()
+    {
+        text.push('\n');
+        text.push_str(&body);
+    }
+    text
+}
+
+/// Returns the trimmed source line at `line` (1-based), or an empty string if there is no such
+/// line.
+fn source_line(lines: &[&str], line: usize) -> String {
+    lines
+        .get(line.saturating_sub(1))
+        .map(|l| l.trim().to_string())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::SourceInfo;
+    use hurl_core::reader::Pos;
+    use hurl_core::types::Index;
+
+    use super::*;
+    use crate::runner::{RunnerErrorKind, Value};
+
+    #[test]
+    fn test_testcase_from_result() {
+        let content = r#"GET http://localhost:8000/hello
+HTTP 404
+[Asserts]
+header "Content-Type" == "text/plain"
+"#;
+        let filename = Input::new("test.hurl");
+        let metadata = RunMetadata {
+            environment: Some("staging".to_string()),
+            ..Default::default()
+        };
+        let res = HurlResult {
+            entries: vec![EntryResult {
+                entry_index: Index::new(1),
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 32)),
+                asserts: vec![
+                    AssertResult::ImplicitVersion {
+                        actual: "HTTP/1.1".to_string(),
+                        expected: "HTTP".to_string(),
+                        source_info: SourceInfo::new(Pos::new(2, 1), Pos::new(2, 5)),
+                    },
+                    AssertResult::ImplicitStatus {
+                        actual: 200,
+                        expected: 404,
+                        source_info: SourceInfo::new(Pos::new(2, 6), Pos::new(2, 9)),
+                    },
+                    AssertResult::Explicit {
+                        actual: Ok(Some(Value::String("text/plain".to_string()))),
+                        source_info: SourceInfo::new(Pos::new(4, 1), Pos::new(4, 7)),
+                        predicate_result: Some(Ok(())),
+                    },
+                ],
+                ..Default::default()
+            }],
+            success: false,
+            timestamp: 1,
+            ..Default::default()
+        };
+
+        let uuid = Uuid::new_v4();
+        let testcase = Testcase::from(&res, content, &filename, &metadata, uuid);
+        assert_eq!(testcase.uuid, uuid.to_string());
+        assert_eq!(testcase.name, "test.hurl");
+        assert_eq!(testcase.start, 1000);
+        assert_eq!(
+            testcase.labels,
+            vec![
+                Label::new("framework", "hurl"),
+                Label::new("suite", "test.hurl"),
+                Label::new("tag", "staging"),
+            ]
+        );
+        assert_eq!(testcase.steps.len(), 1);
+        let step = &testcase.steps[0];
+        assert_eq!(step.name, "GET http://localhost:8000/hello");
+        assert!(step.attachments.is_empty());
+        let steps = step
+            .steps
+            .iter()
+            .map(|s| (s.name.as_str(), s.status))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            steps,
+            vec![
+                ("status is 404", Status::Failed),
+                ("header \"Content-Type\" == \"text/plain\"", Status::Passed),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_status() {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+        let assert_error = RunnerError::new(
+            source_info,
+            RunnerErrorKind::AssertStatus {
+                actual: "200".to_string(),
+            },
+            true,
+        );
+        let runtime_error = RunnerError::new(source_info, RunnerErrorKind::NoQueryResult, false);
+        assert_eq!(status(std::iter::empty()), Status::Passed);
+        assert_eq!(status([assert_error.clone()].iter()), Status::Failed);
+        assert_eq!(status([assert_error, runtime_error].iter()), Status::Broken);
+    }
+}
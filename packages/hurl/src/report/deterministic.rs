@@ -0,0 +1,128 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Deterministic reports.
+//!
+//! A deterministic report is identical from one run to another, given the same HTTP exchanges,
+//! so it can be compared to golden files: timestamps and durations are zeroed, and identifiers
+//! are derived from the reported content instead of being randomly generated.
+use std::time::Duration;
+
+use sha2::{Digest, Sha256};
+use uuid::{Builder, Uuid};
+
+use crate::http::Timings;
+use crate::runner::HurlResult;
+
+/// Returns a copy of `hurl_result` with all timestamps and durations zeroed.
+pub fn normalize(hurl_result: &HurlResult) -> HurlResult {
+    let mut hurl_result = hurl_result.clone();
+    hurl_result.duration = Duration::ZERO;
+    hurl_result.timestamp = 0;
+    for entry in hurl_result.entries.iter_mut() {
+        entry.transfer_duration = Duration::ZERO;
+        for call in entry.calls.iter_mut() {
+            call.timings = Timings::default();
+            call.response.duration = Duration::ZERO;
+            call.response.timings = Timings::default();
+        }
+    }
+    hurl_result
+}
+
+/// Returns a UUID derived from `seed`: the same seed always gives the same UUID.
+pub fn stable_uuid(seed: &[u8]) -> Uuid {
+    let mut hasher = Sha256::new();
+    hasher.update(seed);
+    let hash = hasher.finalize();
+    let mut bytes = [0_u8; 16];
+    bytes.copy_from_slice(&hash[..16]);
+    Builder::from_random_bytes(bytes).into_uuid()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+
+    use super::*;
+    use crate::http::{Call, HeaderVec, HttpVersion, Request, Response, Url};
+    use crate::runner::EntryResult;
+
+    #[test]
+    fn test_normalize() {
+        let begin_call = DateTime::parse_from_rfc3339("2026-06-20T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let timings = Timings {
+            begin_call,
+            end_call: begin_call,
+            name_lookup: Duration::from_millis(1),
+            connect: Duration::from_millis(2),
+            app_connect: Duration::from_millis(3),
+            pre_transfer: Duration::from_millis(4),
+            start_transfer: Duration::from_millis(5),
+            total: Duration::from_millis(6),
+        };
+        let url = "http://localhost".parse::<Url>().unwrap();
+        let call = Call {
+            request: Request::new("GET", url.clone(), HeaderVec::new(), vec![]),
+            response: Response::new(
+                HttpVersion::Http11,
+                200,
+                HeaderVec::new(),
+                vec![],
+                Duration::from_millis(6),
+                url,
+                None,
+                Default::default(),
+                None,
+                false,
+                vec![],
+                timings.clone(),
+            ),
+            timings,
+        };
+        let hurl_result = HurlResult {
+            entries: vec![EntryResult {
+                calls: vec![call],
+                transfer_duration: Duration::from_millis(6),
+                ..Default::default()
+            }],
+            duration: Duration::from_millis(230),
+            success: true,
+            timestamp: 1781949600,
+            ..Default::default()
+        };
+
+        let normalized = normalize(&hurl_result);
+        assert_eq!(normalized.duration, Duration::ZERO);
+        assert_eq!(normalized.timestamp, 0);
+        assert_eq!(normalized.entries[0].transfer_duration, Duration::ZERO);
+        let call = &normalized.entries[0].calls[0];
+        assert_eq!(call.timings, Timings::default());
+        assert_eq!(call.response.duration, Duration::ZERO);
+        assert_eq!(call.response.timings, Timings::default());
+        assert!(normalized.success);
+    }
+
+    #[test]
+    fn test_stable_uuid() {
+        assert_eq!(stable_uuid(b"1:test.hurl"), stable_uuid(b"1:test.hurl"));
+        assert_ne!(stable_uuid(b"1:test.hurl"), stable_uuid(b"2:test.hurl"));
+        assert_eq!(stable_uuid(b"1:test.hurl").get_version_num(), 4);
+    }
+}
@@ -60,15 +60,27 @@ impl Testcase {
     fn get_errors_html(&self, content: &str, secrets: &[&str]) -> String {
         self.errors
             .iter()
-            .map(|(error, entry_src_info)| {
-                let error = error_to_html(
-                    error,
-                    *entry_src_info,
-                    content,
-                    &self.filename,
-                    &self.source_filename(),
-                    secrets,
-                );
+            .map(|(error, entry_src_info, source_file)| {
+                // Errors of included entries are rendered with their own file, which is not
+                // part of the report: they have no link to the source view.
+                let error = match source_file {
+                    Some(file) => error_to_html(
+                        error,
+                        *entry_src_info,
+                        &file.content,
+                        &file.input.to_string(),
+                        None,
+                        secrets,
+                    ),
+                    None => error_to_html(
+                        error,
+                        *entry_src_info,
+                        content,
+                        &self.filename,
+                        Some(&self.source_filename()),
+                        secrets,
+                    ),
+                };
                 format!("<div class=\"error\"><div class=\"error-desc\">{error}</div></div>")
             })
             .collect::<Vec<_>>()
@@ -82,13 +94,14 @@ fn get_status_html(success: bool, id: &str) -> String {
     format!("<span class=\"{class}\"><a href=\"{id}-timeline.html\">{label}</a></span>")
 }
 
-/// Returns an HTML `<pre>` tag representing this `error`.
+/// Returns an HTML `<pre>` tag representing this `error`, with a link to the error line in the
+/// source view `source_filename` if any.
 fn error_to_html(
     error: &RunnerError,
     entry_src_info: SourceInfo,
     content: &str,
     filename: &str,
-    source_filename: &str,
+    source_filename: Option<&str>,
     secrets: &[&str],
 ) -> String {
     let line = error.source_info.start.line;
@@ -103,10 +116,14 @@ fn error_to_html(
     let message = html_escape(&message);
     // We override the first part of the error string to add an anchor to
     // the error context.
-    let old = format!("{filename}:{line}:{column}");
-    let href = source_filename;
-    let new = format!("<a href=\"{href}#l{line}\">{filename}:{line}:{column}</a>");
-    let message = message.replace(&old, &new);
+    let message = match source_filename {
+        Some(href) => {
+            let old = format!("{filename}:{line}:{column}");
+            let new = format!("<a href=\"{href}#l{line}\">{filename}:{line}:{column}</a>");
+            message.replace(&old, &new)
+        }
+        None => message,
+    };
     format!("<pre><code>{message}</code></pre>")
 }
 
@@ -132,6 +149,7 @@ mod tests {
                 actual: "<script>alert('Hi')</script>".to_string(),
                 expected: "Hello world".to_string(),
                 type_mismatch: false,
+                json_diff: None,
             },
             true,
         );
@@ -147,7 +165,7 @@ mod tests {
             entry_src_info,
             content,
             filename,
-            source_filename,
+            Some(source_filename),
             &[],
         );
         assert_eq!(
@@ -25,16 +25,26 @@ use std::sync::LazyLock;
 
 /// Creates and HTML report for this list of [`Testcase`] at `dir_path`/index.html.
 ///
-/// If the report already exists, results are merged.
-pub fn write_report(dir_path: &Path, testcases: &[Testcase]) -> Result<(), ReportError> {
+/// If the report already exists, results are merged. If `deterministic` is set, the report date
+/// is the Unix epoch and the results are sorted by file name.
+pub fn write_report(
+    dir_path: &Path,
+    testcases: &[Testcase],
+    deterministic: bool,
+) -> Result<(), ReportError> {
     let index_path = dir_path.join("index.html");
     let mut results = parse_html(&index_path)?;
     for testcase in testcases.iter() {
         let html_result = HTMLResult::from(testcase);
         results.push(html_result);
     }
-    let now = Local::now();
-    let s = create_html_index(&now.to_rfc2822(), &results);
+    let now = if deterministic {
+        results.sort_by(|a, b| a.filename.cmp(&b.filename));
+        DateTime::UNIX_EPOCH.to_rfc2822()
+    } else {
+        Local::now().to_rfc2822()
+    };
+    let s = create_html_index(&now, &results);
 
     let file_path = index_path;
     let mut file = std::fs::File::create(&file_path)
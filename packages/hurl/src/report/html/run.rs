@@ -15,9 +15,12 @@
  * limitations under the License.
  *
  */
-use hurl_core::ast::HurlFile;
+use std::time::Duration;
 
-use crate::http::Call;
+use crate::html::html_escape;
+use crate::http::{Call, Timings, mimetype};
+use crate::pretty;
+use crate::pretty::json::Color;
 use crate::report::html::Testcase;
 use crate::report::html::nav::Tab;
 use crate::runner::EntryResult;
@@ -25,22 +28,15 @@ use crate::util::redacted::Redact;
 
 impl Testcase {
     /// Creates an HTML view of a run (HTTP status code, response header etc...)
-    pub fn get_run_html(
-        &self,
-        hurl_file: &HurlFile,
-        content: &str,
-        entries: &[EntryResult],
-        secrets: &[&str],
-    ) -> String {
+    pub fn get_run_html(&self, content: &str, entries: &[EntryResult], secrets: &[&str]) -> String {
         let nav = self.get_nav_html(content, Tab::Run, secrets);
         let nav_css = include_str!("resources/nav.css");
+        let hurl_css = hurl_core::format::hurl_css();
         let run_css = include_str!("resources/run.css");
 
         let mut run = String::new();
         for (entry_index, e) in entries.iter().enumerate() {
-            let entry_src_index = e.entry_index.to_zero_based();
-            let entry_src = hurl_file.entries.get(entry_src_index).unwrap();
-            let line = entry_src.source_info().start.line;
+            let line = e.source_info.start.line;
             let source = self.source_filename();
 
             run.push_str("<details open>");
@@ -66,6 +62,7 @@ impl Testcase {
         format!(
             include_str!("resources/run.html"),
             filename = self.filename,
+            hurl_css = hurl_css,
             nav = nav,
             nav_css = nav_css,
             run = run,
@@ -97,7 +94,11 @@ fn get_entry_html(entry: &EntryResult, entry_index: usize, secrets: &[&str]) ->
     text
 }
 
-/// Returns an HTML view of a `call` (source file, request and response headers, certificate etc...)
+/// Maximum number of bytes displayed in a request or response body preview.
+const BODY_PREVIEW_MAX_SIZE: usize = 16 * 1024;
+
+/// Returns an HTML view of a `call` (source file, request and response headers and bodies,
+/// certificate, timings etc...)
 fn get_call_html(
     call: &Call,
     entry_index: usize,
@@ -167,6 +168,9 @@ fn get_call_html(
         text.push_str(&table);
     }
 
+    let table = get_timings_html(&call.timings);
+    text.push_str(&table);
+
     let mut values = call
         .request
         .headers
@@ -175,7 +179,14 @@ fn get_call_html(
         .collect::<Vec<(&str, String)>>();
     values.sort_by_key(|a| a.0.to_lowercase());
     let table = new_table("Request Headers", &values);
-    text.push_str(&table);
+    text.push_str(&new_details("Request Headers", &table, true));
+
+    if !call.request.body.is_empty() {
+        let body = String::from_utf8(call.request.body.clone()).ok();
+        let content_type = call.request.headers.content_type();
+        let preview = get_body_html(body, call.request.body.len(), content_type, secrets);
+        text.push_str(&new_details("Request Body", &preview, false));
+    }
 
     let mut values = call
         .response
@@ -185,11 +196,110 @@ fn get_call_html(
         .collect::<Vec<(&str, String)>>();
     values.sort_by_key(|a| a.0.to_lowercase());
     let table = new_table("Response Headers", &values);
-    text.push_str(&table);
+    text.push_str(&new_details("Response Headers", &table, true));
+
+    if !call.response.body.is_empty() {
+        let body = call.response.text().ok();
+        let content_type = call.response.headers.content_type();
+        let preview = get_body_html(body, call.response.body.len(), content_type, secrets);
+        text.push_str(&new_details("Response Body", &preview, false));
+    }
+
+    text
+}
+
+/// Returns an HTML preview of a request or response body.
+///
+/// `body` is the decoded text of the body, or `None` if the body can't be decoded as text (in
+/// this case, only the body size `len` in bytes is displayed). JSON bodies are prettified. The
+/// preview is not syntax highlighted: like the bodies of the Hurl source view, it's styled as a
+/// whole by the CSS class of its content type. Previews are truncated to [`BODY_PREVIEW_MAX_SIZE`]
+/// bytes and values are redacted using `secrets`.
+fn get_body_html(
+    body: Option<String>,
+    len: usize,
+    content_type: Option<&str>,
+    secrets: &[&str],
+) -> String {
+    let Some(body) = body else {
+        return format!("<p class=\"body-info\">Binary content, {len} bytes</p>");
+    };
+    let (class, body) = match content_type {
+        Some(content_type) if mimetype::is_json(content_type) => {
+            let mut out = Vec::new();
+            let body = match pretty::format(body.as_bytes(), Color::NoColor, &mut out) {
+                Ok(()) => String::from_utf8(out).unwrap_or(body),
+                Err(_) => body,
+            };
+            ("json", body)
+        }
+        Some(content_type) if mimetype::is_xml(content_type) => ("xml", body),
+        _ => ("multiline", body),
+    };
+    let body = body.redact(secrets);
 
+    let mut end = body.len().min(BODY_PREVIEW_MAX_SIZE);
+    while !body.is_char_boundary(end) {
+        end -= 1;
+    }
+    let preview = html_escape(&body[..end]);
+
+    let mut text = String::new();
+    text.push_str(&format!(
+        "<pre class=\"body\"><code class=\"language-hurl\"><span class=\"{class}\">{preview}</span></code></pre>"
+    ));
+    if end < body.len() {
+        let truncated = body.len() - end;
+        text.push_str(&format!(
+            "<p class=\"body-info\">Preview truncated, {truncated} more bytes</p>"
+        ));
+    }
     text
 }
 
+/// Returns an HTML waterfall chart of the phases of an HTTP exchange (DNS lookup, TCP connection,
+/// TLS handshake, waiting for the first byte and content transfer) from libcurl `timings`.
+fn get_timings_html(timings: &Timings) -> String {
+    // libcurl timings are measured from the start of the transfer, see
+    // <https://curl.se/libcurl/c/curl_easy_getinfo.html#TIMES>.
+    // When there is no TLS handshake, `app_connect` is zero.
+    let app_connect = timings.app_connect.max(timings.connect);
+    let phases = [
+        ("DNS lookup", Duration::ZERO, timings.name_lookup),
+        ("TCP connect", timings.name_lookup, timings.connect),
+        ("TLS handshake", timings.connect, app_connect),
+        ("Waiting", timings.pre_transfer, timings.start_transfer),
+        ("Content transfer", timings.start_transfer, timings.total),
+    ];
+    let total = timings.total.as_micros().max(1) as f64;
+
+    let mut text = String::new();
+    text.push_str(
+        "<table class=\"timings\"><thead><tr><th colspan=\"3\">Timings</th></tr></thead><tbody>",
+    );
+    for (name, start, end) in phases {
+        let duration = end.saturating_sub(start);
+        let offset = 100.0 * start.as_micros() as f64 / total;
+        let width = 100.0 * duration.as_micros() as f64 / total;
+        let ms = duration.as_micros() as f64 / 1000.0;
+        text.push_str(&format!(
+            "<tr>\
+                <td class=\"name\">{name}</td>\
+                <td class=\"waterfall\"><div class=\"bar\" style=\"margin-left: {offset:.2}%; width: {width:.2}%\"></div></td>\
+                <td class=\"duration\">{ms:.3} ms</td>\
+            </tr>"
+        ));
+    }
+    text.push_str("</tbody></table>");
+    text
+}
+
+/// Returns a collapsible HTML section with a `title` and an HTML `content`.
+fn new_details(title: &str, content: &str, open: bool) -> String {
+    let open = if open { " open" } else { "" };
+    format!("<details class=\"section\"{open}><summary>{title}</summary>{content}</details>")
+}
+
 /// Returns an HTML table with a `title` and a list of key/values. Values are redacted using `secrets`.
 fn new_table<T: AsRef<str>, U: AsRef<str> + std::fmt::Display>(
     title: &str,
@@ -209,3 +319,81 @@ fn new_table<T: AsRef<str>, U: AsRef<str> + std::fmt::Display>(
     text.push_str("</tbody></table>");
     text
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::http::Timings;
+    use crate::report::html::run::{BODY_PREVIEW_MAX_SIZE, get_body_html, get_timings_html};
+
+    #[test]
+    fn test_body_html() {
+        let body = r#"{"id":1,"token":"s3cr3t"}"#.to_string();
+        let html = get_body_html(Some(body), 25, Some("application/json"), &["s3cr3t"]);
+        assert_eq!(
+            html,
+            r#"<pre class="body"><code class="language-hurl"><span class="json">{
+  &quot;id&quot;: 1,
+  &quot;token&quot;: &quot;***&quot;
+}
+</span></code></pre>"#
+        );
+
+        let html = get_body_html(Some("<a>b</a>".to_string()), 8, Some("text/xml"), &[]);
+        assert_eq!(
+            html,
+            r#"<pre class="body"><code class="language-hurl"><span class="xml">&lt;a&gt;b&lt;/a&gt;</span></code></pre>"#
+        );
+
+        let html = get_body_html(None, 1024, Some("image/png"), &[]);
+        assert_eq!(
+            html,
+            r#"<p class="body-info">Binary content, 1024 bytes</p>"#
+        );
+    }
+
+    #[test]
+    fn test_body_html_truncated() {
+        let body = "é".repeat(BODY_PREVIEW_MAX_SIZE);
+        let len = body.len();
+        let html = get_body_html(Some(body), len, Some("text/plain"), &[]);
+        let preview = "é".repeat(BODY_PREVIEW_MAX_SIZE / 2);
+        assert_eq!(
+            html,
+            format!(
+                "<pre class=\"body\"><code class=\"language-hurl\"><span class=\"multiline\">{preview}</span></code></pre>\
+                 <p class=\"body-info\">Preview truncated, {BODY_PREVIEW_MAX_SIZE} more bytes</p>"
+            )
+        );
+    }
+
+    #[test]
+    fn test_timings_html() {
+        let timings = Timings {
+            name_lookup: Duration::from_millis(10),
+            connect: Duration::from_millis(20),
+            app_connect: Duration::ZERO,
+            pre_transfer: Duration::from_millis(20),
+            start_transfer: Duration::from_millis(80),
+            total: Duration::from_millis(100),
+            ..Default::default()
+        };
+        let html = get_timings_html(&timings);
+        assert!(html.contains(
+            "<td class=\"name\">DNS lookup</td>\
+             <td class=\"waterfall\"><div class=\"bar\" style=\"margin-left: 0.00%; width: 10.00%\"></div></td>\
+             <td class=\"duration\">10.000 ms</td>"
+        ));
+        assert!(html.contains(
+            "<td class=\"name\">TLS handshake</td>\
+             <td class=\"waterfall\"><div class=\"bar\" style=\"margin-left: 20.00%; width: 0.00%\"></div></td>\
+             <td class=\"duration\">0.000 ms</td>"
+        ));
+        assert!(html.contains(
+            "<td class=\"name\">Content transfer</td>\
+             <td class=\"waterfall\"><div class=\"bar\" style=\"margin-left: 80.00%; width: 20.00%\"></div></td>\
+             <td class=\"duration\">20.000 ms</td>"
+        ));
+    }
+}
@@ -15,18 +15,26 @@
  * limitations under the License.
  *
  */
+use std::sync::Arc;
+
 use hurl_core::ast::{HurlFile, SourceInfo};
+use hurl_core::format::HtmlOptions;
 
 use crate::report::html::Testcase;
 use crate::report::html::nav::Tab;
-use crate::runner::RunnerError;
+use crate::runner::{RunnerError, SourceFile};
 
 impl Testcase {
     /// Returns the HTML string of the Hurl source file (syntax colored and errors).
     pub fn get_source_html(&self, hurl_file: &HurlFile, content: &str, secrets: &[&str]) -> String {
         let nav = self.get_nav_html(content, Tab::Source, secrets);
         let nav_css = include_str!("resources/nav.css");
-        let source_div = hurl_core::format::format_html(hurl_file, false);
+        // Entries are anchored, so the report can link to a specific request of the source file.
+        let options = HtmlOptions {
+            entry_anchors: true,
+            ..HtmlOptions::default()
+        };
+        let source_div = hurl_core::format::format_html(hurl_file, &options);
         let lines_div = get_numbered_lines(content, &self.errors);
         let source_css = include_str!("resources/source.css");
         let hurl_css = hurl_core::format::hurl_css();
@@ -44,10 +52,15 @@ impl Testcase {
 }
 
 /// Returns a list of lines number in HTML.
-fn get_numbered_lines(content: &str, errors: &[(RunnerError, SourceInfo)]) -> String {
+fn get_numbered_lines(
+    content: &str,
+    errors: &[(RunnerError, SourceInfo, Option<Arc<SourceFile>>)],
+) -> String {
+    // Errors of included entries refer to lines of their own file.
     let errors = errors
         .iter()
-        .map(|(error, _)| error.source_info.start.line)
+        .filter(|(_, _, source_file)| source_file.is_none())
+        .map(|(error, _, _)| error.source_info.start.line)
         .collect::<Vec<_>>();
     let mut lines =
         content
@@ -17,9 +17,10 @@
  */
 use std::fs;
 use std::path::Path;
+use std::sync::Arc;
 
 use crate::report::ReportError;
-use crate::runner::{EntryResult, HurlResult, RunnerError};
+use crate::runner::{EntryResult, HurlResult, RunnerError, SourceFile};
 use hurl_core::ast::SourceInfo;
 use hurl_core::input::Input;
 use hurl_core::parser;
@@ -33,19 +34,19 @@ pub struct Testcase {
     pub filename: String,
     pub success: bool,
     pub time_in_ms: u128,
-    /// The runtime errors, and the source information of the entry throwing this error.
-    pub errors: Vec<(RunnerError, SourceInfo)>,
+    /// The runtime errors, with the source information and the included file (if any) of the entry
+    /// throwing this error.
+    pub errors: Vec<(RunnerError, SourceInfo, Option<Arc<SourceFile>>)>,
     pub timestamp: i64,
 }
 
 impl Testcase {
-    /// Creates an HTML testcase.
-    pub fn from(hurl_result: &HurlResult, filename: &Input) -> Testcase {
-        let id = Uuid::new_v4();
+    /// Creates an HTML testcase, identified by `id`.
+    pub fn from(hurl_result: &HurlResult, filename: &Input, id: Uuid) -> Testcase {
         let errors = hurl_result
             .errors()
             .into_iter()
-            .map(|(error, entry_src_info)| (error.clone(), entry_src_info))
+            .map(|(error, entry)| (error.clone(), entry.source_info, entry.source_file.clone()))
             .collect();
         Testcase {
             id: id.to_string(),
@@ -79,14 +80,14 @@ impl Testcase {
 
         // We create the timeline view.
         let output_file = dir.join(self.timeline_filename());
-        let html = self.get_timeline_html(&hurl_file, content, entries, secrets);
+        let html = self.get_timeline_html(content, entries, secrets);
         fs::write(&output_file, html.as_bytes()).map_err(|e| {
             ReportError::from_io_error(&e, &output_file, "Issue writing HTML report")
         })?;
 
         // Then create the run view.
         let output_file = dir.join(self.run_filename());
-        let html = self.get_run_html(&hurl_file, content, entries, secrets);
+        let html = self.get_run_html(content, entries, secrets);
         fs::write(&output_file, html.as_bytes()).map_err(|e| {
             ReportError::from_io_error(&e, &output_file, "Issue writing HTML report")
         })?;
@@ -250,7 +250,13 @@ impl Scale {
     }
 
     /// Returns a pixel value of `time`.
+    ///
+    /// An empty time interval (like the zeroed timings of a deterministic report) is mapped to
+    /// the start of the pixel interval.
     pub fn to_pixel(self, time: Microsecond) -> Pixel {
+        if self.times.end.0 == self.times.start.0 {
+            return self.pixels.start;
+        }
         let pixel = (time.0 - self.times.start.0) * (self.pixels.end.0 - self.pixels.start.0)
             / (self.times.end.0 - self.times.start.0);
         Pixel(pixel)
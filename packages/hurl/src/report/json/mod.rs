@@ -19,7 +19,8 @@
 //!
 //! The JSON report is organised as follows:
 //!
-//! - `report.json`: list of each file of a run exported to JSON
+//! - `report.json`: list of each file of a run exported to JSON (version 2 of the schema, see
+//!   [`HurlResult::to_json_v2`])
 //! - `store/foo_response.{json,xml,html}`: an HTTP response referenced in `report.json`
 //!
 //! ```text
@@ -46,11 +47,13 @@ use crate::runner::HurlResult;
 /// Exports a list of [`Testcase`] to a JSON file `filename`.
 ///
 /// Response file are saved under the `response_dir` directory and referenced by path in JSON report
-/// file. `secrets` strings are redacted from the JSON report fields.
+/// file, with names derived from their content if `deterministic` is set. `secrets` strings are
+/// redacted from the JSON report fields.
 pub fn write_report(
     filename: &Path,
     testcases: &[Testcase],
     response_dir: &Path,
+    deterministic: bool,
     secrets: &[&str],
 ) -> Result<(), ReportError> {
     // We parse any potential existing report.
@@ -59,7 +62,7 @@ pub fn write_report(
     // Serialize the new report, extended any exiting one.
     let json = testcases
         .iter()
-        .map(|t| t.to_json(response_dir, secrets))
+        .map(|t| t.to_json(response_dir, deterministic, secrets))
         .collect::<Result<Vec<_>, _>>()
         .map_err(|e| ReportError::from_io_error(&e, filename, "Issue creating JSON report"))?;
     report.extend(json);
@@ -92,15 +95,21 @@ impl<'a> Testcase<'a> {
         }
     }
 
-    /// Serializes this testcase to JSON.
+    /// Serializes this testcase to JSON, using the version 2 of the JSON schema.
     ///
     /// `secrets` strings are redacted from the JSON fields.
     fn to_json(
         &self,
         response_dir: &Path,
+        deterministic: bool,
         secrets: &[&str],
     ) -> Result<serde_json::Value, io::Error> {
-        self.result
-            .to_json(self.content, self.filename, Some(response_dir), secrets)
+        self.result.to_json_v2(
+            self.content,
+            self.filename,
+            response_dir,
+            deterministic,
+            secrets,
+        )
     }
 }
@@ -24,7 +24,9 @@
 //! One Hurl file will result into one JUnit `<testcase>`.
 //!
 //! The `<testcase>` can include `<error>` (for runtime error) or `<failure>` (for assert error)
-//! Each Hurl execution will generate its own `<testsuite>` within the root `<testsuites>`.
+//! Each Hurl execution will generate its own `<testsuite>` within the root `<testsuites>`. The
+//! metadata of the run (Hurl version, command line, environment, git SHA) and the transfer time of
+//! each entry are added as `<properties>` of the `<testsuite>`.
 //!
 //! # Example:
 //!
@@ -33,6 +35,12 @@
 //! <?xml version="1.0"?>
 //! <testsuites>
 //!   <testsuite>
+//!     <properties>
+//!       <property name="hurl.version" value="7.0.0"/>
+//!       <property name="hurl.command" value="hurl --test --report-junit test.xml tests"/>
+//!       <property name="tests/hello.hurl:entry.1.time" value="0.027"/>
+//!       <property name="tests/error_assert_status.hurl:entry.1.time" value="0.006"/>
+//!     </properties>
 //!     <testcase id="tests/hello.hurl" name="tests/hello.hurl" time="0.029"/>
 //!     <testcase id="tests/error_assert_status.hurl" name="tests/error_assert_status.hurl" time="0.008">
 //!       <failure>Assert Status
@@ -61,16 +69,18 @@ use std::path::Path;
 
 pub use testcase::Testcase;
 
-use crate::report::ReportError;
 use crate::report::junit::xml::{Element, XmlDocument};
+use crate::report::{ReportError, RunMetadata};
 use crate::util::path::create_dir_all;
+use crate::util::redacted::Redact;
 
-/// Creates a JUnit from a list of `testcases`.
+/// Creates a JUnit from a list of `testcases`, with the run `metadata` as testsuite properties.
 ///
 ///  `secrets` strings are redacted from messages (mainly assert error messages).
 pub fn write_report(
     filename: &Path,
     testcases: &[Testcase],
+    metadata: &RunMetadata,
     secrets: &[&str],
 ) -> Result<(), ReportError> {
     create_dir_all(filename)
@@ -86,7 +96,7 @@ pub fn write_report(
         Element::new("testsuites")
     };
 
-    let testsuite = create_testsuite(testcases, secrets);
+    let testsuite = create_testsuite(testcases, metadata, secrets);
     root = root.add_child(testsuite);
 
     let doc = XmlDocument::new(root);
@@ -102,10 +112,10 @@ pub fn write_report(
     Ok(())
 }
 
-/// Returns a testsuite as a XML object, from a list of `testcases`.
+/// Returns a testsuite as a XML object, from a list of `testcases` and the run `metadata`.
 ///
 /// `secrets` strings are redacted from the produced HTML.
-fn create_testsuite(testcases: &[Testcase], secrets: &[&str]) -> Element {
+fn create_testsuite(testcases: &[Testcase], metadata: &RunMetadata, secrets: &[&str]) -> Element {
     let mut tests = 0;
     let mut errors = 0;
     let mut failures = 0;
@@ -121,6 +131,25 @@ fn create_testsuite(testcases: &[Testcase], secrets: &[&str]) -> Element {
         .attr("errors", &errors.to_string())
         .attr("failures", &failures.to_string());
 
+    let mut properties = metadata
+        .properties()
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect::<Vec<_>>();
+    for testcase in testcases.iter() {
+        properties.extend(testcase.properties());
+    }
+    if !properties.is_empty() {
+        let mut properties_element = Element::new("properties");
+        for (name, value) in properties {
+            let property = Element::new("property")
+                .attr("name", &name)
+                .attr("value", &value.redact(secrets));
+            properties_element = properties_element.add_child(property);
+        }
+        element = element.add_child(properties_element);
+    }
+
     for testcase in testcases.iter() {
         let child = testcase.to_xml(secrets);
         element = element.add_child(child);
@@ -133,6 +162,7 @@ mod tests {
     use std::time::Duration;
 
     use crate::http::HttpError;
+    use crate::report::RunMetadata;
     use crate::report::junit::xml::XmlDocument;
     use crate::report::junit::{Testcase, create_testsuite};
     use crate::runner::{EntryResult, HurlResult, RunnerError, RunnerErrorKind};
@@ -197,15 +227,20 @@ mod tests {
         let tc = Testcase::from(&res, content, &filename);
         testcases.push(tc);
 
-        let suite = create_testsuite(&testcases, &secrets);
+        let suite = create_testsuite(&testcases, &RunMetadata::default(), &secrets);
         let doc = XmlDocument::new(suite);
         assert_eq!(
             doc.dump(),
             "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
             <testsuite tests=\"3\" errors=\"1\" failures=\"1\">\
+                <properties>\
+                    <property name=\"test.hurl:entry.1.time\" value=\"0.000\" />\
+                    <property name=\"test.hurl:entry.1.time\" value=\"0.000\" />\
+                </properties>\
                 <testcase id=\"test.hurl\" name=\"test.hurl\" time=\"0.124\" />\
                 <testcase id=\"test.hurl\" name=\"test.hurl\" time=\"0.200\">\
-                    <failure>Assert status code\n  \
+                    <failure message=\"Assert status code\" line=\"2\" column=\"10\" query=\"HTTP/1.0 200\" actual=\"404\">\
+                    Assert status code\n  \
                     --&gt; test.hurl:2:10\n   \
                       |\n   \
                       | GET http://localhost:8000/not_found\n \
@@ -215,10 +250,46 @@ mod tests {
                     </failure>\
                 </testcase>\
                 <testcase id=\"test.hurl\" name=\"test.hurl\" time=\"0.230\">\
-                    <error>HTTP connection\n  --&gt; test.hurl:1:5\n   |\n 1 | GET http://localhost:8000/not_found\n   |     ^^^^^^^^^^^^^^ (6) Could not resolve host: unknown\n   |\
+                    <error message=\"HTTP connection\" line=\"1\" column=\"5\">HTTP connection\n  --&gt; test.hurl:1:5\n   |\n 1 | GET http://localhost:8000/not_found\n   |     ^^^^^^^^^^^^^^ (6) Could not resolve host: unknown\n   |\
                     </error>\
                 </testcase>\
             </testsuite>"
         );
     }
+
+    #[test]
+    fn create_junit_report_with_metadata() {
+        let content = "GET http://localhost:8000/hello\n\
+                       HTTP 200";
+        let filename = Input::new("test.hurl");
+        let secrets = ["foo"];
+        let res = HurlResult {
+            duration: Duration::from_millis(124),
+            success: true,
+            ..Default::default()
+        };
+        let testcases = vec![Testcase::from(&res, content, &filename)];
+        let metadata = RunMetadata {
+            hurl_version: "7.0.0".to_string(),
+            command_line: "hurl --secret token=foo test.hurl".to_string(),
+            environment: Some("staging".to_string()),
+            git_sha: Some("c0ffee".to_string()),
+        };
+
+        let suite = create_testsuite(&testcases, &metadata, &secrets);
+        let doc = XmlDocument::new(suite);
+        assert_eq!(
+            doc.dump(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+            <testsuite tests=\"1\" errors=\"0\" failures=\"0\">\
+                <properties>\
+                    <property name=\"hurl.version\" value=\"7.0.0\" />\
+                    <property name=\"hurl.command\" value=\"hurl --secret token=*** test.hurl\" />\
+                    <property name=\"hurl.environment\" value=\"staging\" />\
+                    <property name=\"git.sha\" value=\"c0ffee\" />\
+                </properties>\
+                <testcase id=\"test.hurl\" name=\"test.hurl\" time=\"0.124\" />\
+            </testsuite>"
+        );
+    }
 }
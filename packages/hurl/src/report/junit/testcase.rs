@@ -19,7 +19,7 @@ use hurl_core::error::{DisplaySourceError, OutputFormat};
 use hurl_core::input::Input;
 
 use crate::report::junit::xml::Element;
-use crate::runner::HurlResult;
+use crate::runner::{HurlResult, RunnerError, RunnerErrorKind};
 use crate::util::redacted::Redact;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -27,8 +27,31 @@ pub struct Testcase {
     id: String,
     name: String,
     time_in_ms: u128,
-    failures: Vec<String>,
-    errors: Vec<String>,
+    /// Transfer time of each entry, as a list of (entry index, time in ms).
+    entries_time_in_ms: Vec<(usize, u128)>,
+    failures: Vec<Failure>,
+    errors: Vec<Failure>,
+}
+
+/// A JUnit &lt;failure&gt; (for asserts errors) or &lt;error&gt; (for runtime errors) of a testcase.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Failure {
+    /// One-line description of the error, for instance "Assert status code".
+    description: String,
+    /// File of the error, if the error comes from an included file.
+    file: Option<String>,
+    /// Line of the error in the Hurl file.
+    line: usize,
+    /// Column of the error in the Hurl file.
+    column: usize,
+    /// Source of the failed assert (query and predicate), for asserts errors only.
+    query: Option<String>,
+    /// Actual value of the failed assert, if any.
+    actual: Option<String>,
+    /// Expected value of the failed assert, if any.
+    expected: Option<String>,
+    /// Full error message, with the error source context.
+    message: String,
 }
 
 impl Testcase {
@@ -37,26 +60,41 @@ impl Testcase {
         let id = filename.to_string();
         let name = filename.to_string();
         let time_in_ms = hurl_result.duration.as_millis();
+
+        // Retried entries are accumulated in the transfer time of their entry.
+        let mut entries_time_in_ms: Vec<(usize, u128)> = vec![];
+        for entry in hurl_result.entries.iter() {
+            let index = entry.entry_index.get();
+            let time = entry.transfer_duration.as_millis();
+            match entries_time_in_ms.last_mut() {
+                Some((last_index, last_time)) if *last_index == index => *last_time += time,
+                _ => entries_time_in_ms.push((index, time)),
+            }
+        }
+
         let mut failures = vec![];
         let mut errors = vec![];
-
-        for (error, entry_src_info) in hurl_result.errors() {
+        for (error, entry) in hurl_result.errors() {
+            let (filename, content) = entry.source(&name, content);
             let message = error.render(
-                &name,
+                &filename,
                 content,
-                Some(entry_src_info),
+                Some(entry.source_info),
                 OutputFormat::Terminal(false),
             );
+            let file = entry.source_file.as_ref().map(|_| filename);
+            let failure = Failure::new(error, file, content, message);
             if error.assert {
-                failures.push(message);
+                failures.push(failure);
             } else {
-                errors.push(message);
+                errors.push(failure);
             };
         }
         Testcase {
             id,
             name,
             time_in_ms,
+            entries_time_in_ms,
             failures,
             errors,
         }
@@ -72,17 +110,31 @@ impl Testcase {
             .attr("time", &time_in_seconds);
 
         for failure in self.failures.iter() {
-            let failure = failure.redact(secrets);
-            element = element.add_child(Element::new("failure").text(&failure));
+            element = element.add_child(failure.to_xml("failure", secrets));
         }
 
         for error in self.errors.iter() {
-            let error = error.redact(secrets);
-            element = element.add_child(Element::new("error").text(&error));
+            element = element.add_child(error.to_xml("error", secrets));
         }
         element
     }
 
+    /// Returns the transfer time of each entry of this testcase, as a list of (name, value)
+    /// properties of the testsuite.
+    ///
+    /// A testcase can't have properties, so the property names are prefixed by the testcase
+    /// name, for instance `tests/login.hurl:entry.1.time`.
+    pub fn properties(&self) -> Vec<(String, String)> {
+        self.entries_time_in_ms
+            .iter()
+            .map(|(index, time_in_ms)| {
+                let name = format!("{}:entry.{index}.time", self.name);
+                let value = format!("{:.3}", *time_in_ms as f64 / 1000.0);
+                (name, value)
+            })
+            .collect()
+    }
+
     pub fn get_error_count(&self) -> usize {
         self.errors.len()
     }
@@ -92,14 +144,79 @@ impl Testcase {
     }
 }
 
+impl Failure {
+    /// Creates a new failure from a runner `error`, given the Hurl `file` and `content` where
+    /// the error occurs and the rendered error `message`.
+    ///
+    /// `file` is only set if the error comes from an included file.
+    fn new(error: &RunnerError, file: Option<String>, content: &str, message: String) -> Failure {
+        let line = error.source_info.start.line;
+        let column = error.source_info.start.column;
+        let query = if error.assert {
+            content
+                .lines()
+                .nth(line.saturating_sub(1))
+                .map(|l| l.trim().to_string())
+        } else {
+            None
+        };
+        let (actual, expected) = match &error.kind {
+            RunnerErrorKind::AssertFailure {
+                actual, expected, ..
+            }
+            | RunnerErrorKind::AssertBodyValueError {
+                actual, expected, ..
+            } => (Some(actual.clone()), Some(expected.clone())),
+            RunnerErrorKind::AssertHeaderValueError { actual }
+            | RunnerErrorKind::AssertStatus { actual }
+            | RunnerErrorKind::AssertVersion { actual } => (Some(actual.clone()), None),
+            _ => (None, None),
+        };
+        Failure {
+            description: error.description(),
+            file,
+            line,
+            column,
+            query,
+            actual,
+            expected,
+            message,
+        }
+    }
+
+    /// Serializes this failure to an XML element named `name`.
+    ///
+    /// `secrets` strings are redacted from the attributes and message.
+    fn to_xml(&self, name: &str, secrets: &[&str]) -> Element {
+        let mut element = Element::new(name).attr("message", &self.description);
+        if let Some(file) = &self.file {
+            element = element.attr("file", file);
+        }
+        element = element
+            .attr("line", &self.line.to_string())
+            .attr("column", &self.column.to_string());
+        if let Some(query) = &self.query {
+            element = element.attr("query", &query.redact(secrets));
+        }
+        if let Some(actual) = &self.actual {
+            element = element.attr("actual", &actual.redact(secrets));
+        }
+        if let Some(expected) = &self.expected {
+            element = element.attr("expected", &expected.redact(secrets));
+        }
+        element.text(&self.message.redact(secrets))
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use std::sync::Arc;
     use std::time::Duration;
 
     use crate::http::HttpError;
     use crate::report::junit::testcase::Testcase;
     use crate::report::junit::xml::XmlDocument;
-    use crate::runner::{EntryResult, HurlResult, RunnerError, RunnerErrorKind};
+    use crate::runner::{EntryResult, HurlResult, RunnerError, RunnerErrorKind, SourceFile};
     use hurl_core::ast::SourceInfo;
     use hurl_core::input::Input;
     use hurl_core::reader::Pos;
@@ -142,6 +259,7 @@ HTTP/1.0 200
                     },
                     true,
                 )],
+                transfer_duration: Duration::from_millis(120),
                 ..Default::default()
             }],
             duration: Duration::from_millis(230),
@@ -153,7 +271,7 @@ HTTP/1.0 200
         let doc = XmlDocument::new(element);
         assert_eq!(
             doc.dump(),
-            r#"<?xml version="1.0" encoding="UTF-8"?><testcase id="test.hurl" name="test.hurl" time="0.230"><failure>Assert status code
+            r#"<?xml version="1.0" encoding="UTF-8"?><testcase id="test.hurl" name="test.hurl" time="0.230"><failure message="Assert status code" line="2" column="10" query="HTTP/1.0 200" actual="404">Assert status code
   --&gt; test.hurl:2:10
    |
    | GET http://localhost:8000/not_found
@@ -163,6 +281,47 @@ HTTP/1.0 200
         );
     }
 
+    #[test]
+    fn test_create_testcase_failure_in_included_file() {
+        let content = "include login.hurl\nGET http://localhost:8000/profile\n";
+        let filename = Input::new("test.hurl");
+        let secrets = [];
+        let hurl_result = HurlResult {
+            entries: vec![EntryResult {
+                entry_index: Index::new(1),
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 33)),
+                source_file: Some(Arc::new(SourceFile {
+                    input: Input::new("login.hurl"),
+                    content: "POST http://localhost:8000/login\nHTTP 302\n".to_string(),
+                })),
+                errors: vec![RunnerError::new(
+                    SourceInfo::new(Pos::new(2, 6), Pos::new(2, 9)),
+                    RunnerErrorKind::AssertStatus {
+                        actual: "401".to_string(),
+                    },
+                    true,
+                )],
+                ..Default::default()
+            }],
+            duration: Duration::from_millis(230),
+            success: false,
+            ..Default::default()
+        };
+
+        let element = Testcase::from(&hurl_result, content, &filename).to_xml(&secrets);
+        let doc = XmlDocument::new(element);
+        assert_eq!(
+            doc.dump(),
+            r#"<?xml version="1.0" encoding="UTF-8"?><testcase id="test.hurl" name="test.hurl" time="0.230"><failure message="Assert status code" file="login.hurl" line="2" column="6" query="HTTP 302" actual="401">Assert status code
+  --&gt; login.hurl:2:6
+   |
+   | POST http://localhost:8000/login
+ 2 | HTTP 302
+   |      ^^^ actual value is &lt;401&gt;
+   |</failure></testcase>"#
+        );
+    }
+
     #[test]
     fn test_create_testcase_error() {
         let content = "GET http://unknown";
@@ -190,7 +349,7 @@ HTTP/1.0 200
         let doc = XmlDocument::new(element);
         assert_eq!(
             doc.dump(),
-            r#"<?xml version="1.0" encoding="UTF-8"?><testcase id="test.hurl" name="test.hurl" time="0.230"><error>HTTP connection
+            r#"<?xml version="1.0" encoding="UTF-8"?><testcase id="test.hurl" name="test.hurl" time="0.230"><error message="HTTP connection" line="1" column="5">HTTP connection
   --&gt; test.hurl:1:5
    |
  1 | GET http://***
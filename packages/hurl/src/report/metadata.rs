@@ -0,0 +1,123 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::collections::HashMap;
+
+/// Environment variables holding the git commit SHA of the run, in order of precedence.
+const GIT_SHA_VARS: [&str; 3] = ["GIT_COMMIT", "GITHUB_SHA", "CI_COMMIT_SHA"];
+
+/// Environment variable holding the name of the environment of the run (ex: "staging").
+const ENVIRONMENT_VAR: &str = "HURL_ENV";
+
+/// Metadata of a Hurl run (Hurl version, command line etc...), shared by all the reports.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RunMetadata {
+    /// Version of Hurl
+    pub hurl_version: String,
+    /// Command line of the run, secrets are redacted when the report is written.
+    pub command_line: String,
+    /// Name of the environment of the run
+    pub environment: Option<String>,
+    /// Git commit SHA of the run
+    pub git_sha: Option<String>,
+}
+
+impl RunMetadata {
+    /// Collects the metadata of a run, given its command line `args` and environment variables
+    /// `env_vars`.
+    pub fn collect(args: &[String], env_vars: &HashMap<String, String>) -> Self {
+        let get_var = |name: &str| {
+            env_vars
+                .get(name)
+                .filter(|value| !value.is_empty())
+                .cloned()
+        };
+        RunMetadata {
+            hurl_version: env!("CARGO_PKG_VERSION").to_string(),
+            command_line: args.join(" "),
+            environment: get_var(ENVIRONMENT_VAR),
+            git_sha: GIT_SHA_VARS.iter().find_map(|name| get_var(name)),
+        }
+    }
+
+    /// Returns the list of non-empty metadata as (name, value) pairs.
+    pub fn properties(&self) -> Vec<(&str, &str)> {
+        let mut properties = vec![];
+        if !self.hurl_version.is_empty() {
+            properties.push(("hurl.version", self.hurl_version.as_str()));
+        }
+        if !self.command_line.is_empty() {
+            properties.push(("hurl.command", self.command_line.as_str()));
+        }
+        if let Some(environment) = &self.environment {
+            properties.push(("hurl.environment", environment.as_str()));
+        }
+        if let Some(git_sha) = &self.git_sha {
+            properties.push(("git.sha", git_sha.as_str()));
+        }
+        properties
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect_metadata() {
+        let args = ["hurl", "--test", "--secret", "token=foo", "test.hurl"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>();
+        let env_vars = HashMap::from([
+            ("HURL_ENV".to_string(), "staging".to_string()),
+            ("GITHUB_SHA".to_string(), "c0ffee".to_string()),
+            ("GIT_COMMIT".to_string(), String::new()),
+        ]);
+        let metadata = RunMetadata::collect(&args, &env_vars);
+        assert_eq!(
+            metadata.command_line,
+            "hurl --test --secret token=foo test.hurl"
+        );
+        assert_eq!(metadata.environment, Some("staging".to_string()));
+        assert_eq!(metadata.git_sha, Some("c0ffee".to_string()));
+
+        let metadata = RunMetadata::collect(&args, &HashMap::new());
+        assert_eq!(metadata.environment, None);
+        assert_eq!(metadata.git_sha, None);
+    }
+
+    #[test]
+    fn test_properties() {
+        assert!(RunMetadata::default().properties().is_empty());
+
+        let metadata = RunMetadata {
+            hurl_version: "7.0.0".to_string(),
+            command_line: "hurl --test test.hurl".to_string(),
+            environment: None,
+            git_sha: Some("c0ffee".to_string()),
+        };
+        assert_eq!(
+            metadata.properties(),
+            vec![
+                ("hurl.version", "7.0.0"),
+                ("hurl.command", "hurl --test test.hurl"),
+                ("git.sha", "c0ffee"),
+            ]
+        );
+    }
+}
@@ -16,14 +16,24 @@
  *
  */
 
-//! Various reports for Hurl runs (JUnit, HTML etc...) A report aggregates multiple runs into
+//! Various reports for Hurl runs (JUnit, HTML, Allure etc...) A report aggregates multiple runs into
 //! a single unit.
 
+pub mod allure;
 pub mod curl;
+pub(crate) mod deterministic;
 mod error;
 pub mod html;
 pub mod json;
 pub mod junit;
+mod metadata;
+pub mod pretty;
+mod renderer;
 pub mod tap;
 
 pub use error::ReportError;
+pub use metadata::RunMetadata;
+pub use renderer::{
+    AllureRenderer, HtmlRenderer, JsonRenderer, JunitRenderer, PrettyRenderer, ReportRenderer,
+    ReportRenderers, ReportRun, TapRenderer,
+};
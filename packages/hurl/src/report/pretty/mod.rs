@@ -0,0 +1,209 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Human-readable report, in a Cucumber/Gherkin style.
+//!
+//! Each Hurl file is narrated as a feature, whose scenarios are the entries of the file. The
+//! steps of a scenario are the request, followed by the asserts results. This report is aimed at
+//! sharing results with non-engineers.
+//!
+//! # Example:
+//!
+//! ```text
+//! Feature: tests/hello.hurl
+//!
+//!   Scenario: entry 1 (line 1)
+//!     Given a request GET http://localhost:8000/hello
+//!     Then ✓ the status is 200
+//!     And ✓ header "Content-Type" == "text/html; charset=utf-8"
+//!
+//!   Scenario: entry 2 (line 6)
+//!     Given a request GET http://localhost:8000/user
+//!     Then ✓ the status is 200
+//!     And ✗ jsonpath "$.name" == "Bob"
+//!       actual value is <"Alice">
+//!
+//!   Result: ✗ failed (1 of 2 scenarios failed)
+//! ```
+mod testcase;
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+
+pub use testcase::Testcase;
+
+use crate::report::ReportError;
+use crate::util::path::create_dir_all;
+use crate::util::redacted::Redact;
+
+/// Indentation of a scenario, relative to its feature.
+const SCENARIO_INDENT: &str = "  ";
+
+/// Indentation of a step, relative to its feature.
+const STEP_INDENT: &str = "    ";
+
+/// Indentation of the message of a failed step, relative to its feature.
+const MESSAGE_INDENT: &str = "      ";
+
+/// Appends a pretty report from a list of `testcases` to `filename`.
+///
+/// `secrets` strings are redacted from the report.
+pub fn write_report(
+    filename: &Path,
+    testcases: &[Testcase],
+    secrets: &[&str],
+) -> Result<(), ReportError> {
+    create_dir_all(filename)
+        .map_err(|e| ReportError::from_io_error(&e, filename, "Issue writing pretty report"))?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(filename)
+        .map_err(|e| ReportError::from_io_error(&e, filename, "Issue writing pretty report"))?;
+
+    let s = pretty_report(testcases).redact(secrets);
+    file.write_all(s.as_bytes())
+        .map_err(|e| ReportError::from_io_error(&e, filename, "Issue writing pretty report"))?;
+    Ok(())
+}
+
+/// Returns the narrative of a list of `testcases`.
+fn pretty_report(testcases: &[Testcase]) -> String {
+    let mut s = String::new();
+    for testcase in testcases.iter() {
+        s.push_str(&format!("Feature: {}\n", testcase.filename));
+        for scenario in testcase.scenarios.iter() {
+            s.push('\n');
+            s.push_str(&format!(
+                "{SCENARIO_INDENT}Scenario: {}\n",
+                scenario.description
+            ));
+            s.push_str(&format!(
+                "{STEP_INDENT}Given a request {}\n",
+                scenario.request
+            ));
+            if scenario.steps.is_empty() {
+                s.push_str(&format!("{STEP_INDENT}Then ✓ a response is received\n"));
+            }
+            for (i, step) in scenario.steps.iter().enumerate() {
+                let keyword = if i == 0 { "Then" } else { "And" };
+                let mark = if step.success { "✓" } else { "✗" };
+                s.push_str(&format!(
+                    "{STEP_INDENT}{keyword} {mark} {}\n",
+                    step.description
+                ));
+                if let Some(message) = &step.message {
+                    for line in message.lines() {
+                        s.push_str(&format!("{MESSAGE_INDENT}{line}\n"));
+                    }
+                }
+            }
+        }
+        let count = testcase.scenarios.len();
+        let failed = testcase.scenarios.iter().filter(|s| !s.success()).count();
+        let plural = if count > 1 { "s" } else { "" };
+        let result = if testcase.success {
+            format!("✓ passed ({count} scenario{plural})")
+        } else {
+            format!("✗ failed ({failed} of {count} scenario{plural} failed)")
+        };
+        s.push('\n');
+        s.push_str(&format!("{SCENARIO_INDENT}Result: {result}\n"));
+        s.push('\n');
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::testcase::{Scenario, Step};
+    use super::*;
+
+    #[test]
+    fn test_pretty_report() {
+        let testcases = vec![
+            Testcase {
+                filename: "tests/hello.hurl".to_string(),
+                success: true,
+                scenarios: vec![Scenario {
+                    description: "entry 1 (line 1)".to_string(),
+                    request: "GET http://localhost:8000/hello".to_string(),
+                    steps: vec![],
+                }],
+            },
+            Testcase {
+                filename: "tests/user.hurl".to_string(),
+                success: false,
+                scenarios: vec![
+                    Scenario {
+                        description: "entry 1 (line 1)".to_string(),
+                        request: "GET http://localhost:8000/user".to_string(),
+                        steps: vec![
+                            Step {
+                                description: "the status is 200".to_string(),
+                                success: true,
+                                message: None,
+                            },
+                            Step {
+                                description: "jsonpath \"$.name\" == \"Bob\"".to_string(),
+                                success: false,
+                                message: Some("actual value is <\"Alice\">".to_string()),
+                            },
+                        ],
+                    },
+                    Scenario {
+                        description: "entry 2 (line 6)".to_string(),
+                        request: "GET http://localhost:8000/hello".to_string(),
+                        steps: vec![Step {
+                            description: "the status is 200".to_string(),
+                            success: true,
+                            message: None,
+                        }],
+                    },
+                ],
+            },
+        ];
+        assert_eq!(
+            pretty_report(&testcases),
+            r#"Feature: tests/hello.hurl
+
+  Scenario: entry 1 (line 1)
+    Given a request GET http://localhost:8000/hello
+    Then ✓ a response is received
+
+  Result: ✓ passed (1 scenario)
+
+Feature: tests/user.hurl
+
+  Scenario: entry 1 (line 1)
+    Given a request GET http://localhost:8000/user
+    Then ✓ the status is 200
+    And ✗ jsonpath "$.name" == "Bob"
+      actual value is <"Alice">
+
+  Scenario: entry 2 (line 6)
+    Given a request GET http://localhost:8000/hello
+    Then ✓ the status is 200
+
+  Result: ✗ failed (1 of 2 scenarios failed)
+
+"#
+        );
+    }
+}
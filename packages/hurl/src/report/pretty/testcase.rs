@@ -0,0 +1,266 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::error::DisplaySourceError;
+use hurl_core::input::Input;
+use hurl_core::text::Format;
+
+use crate::runner::{AssertResult, EntryResult, HurlResult, RunnerError};
+
+/// A pretty report testcase: one Hurl file, narrated as a feature whose scenarios are the file
+/// entries.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Testcase {
+    pub(crate) filename: String,
+    pub(crate) success: bool,
+    pub(crate) scenarios: Vec<Scenario>,
+}
+
+/// A scenario of a pretty report: one entry of a Hurl file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Scenario {
+    pub(crate) description: String,
+    /// The request of this entry (method and URL).
+    pub(crate) request: String,
+    pub(crate) steps: Vec<Step>,
+}
+
+/// A step of a scenario: an assert, or a runtime error, of an entry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub(crate) description: String,
+    pub(crate) success: bool,
+    /// Explanation of a failed step (ex: "actual value is <404>").
+    pub(crate) message: Option<String>,
+}
+
+impl Testcase {
+    /// Creates a pretty report testcase from an [`HurlResult`].
+    pub fn from(hurl_result: &HurlResult, content: &str, filename: &Input) -> Testcase {
+        let filename = filename.to_string();
+        let success = hurl_result.errors().is_empty();
+        let lines = content.lines().collect::<Vec<_>>();
+
+        // As in [`HurlResult::errors`], retried entries are ignored: we only keep the last
+        // execution of each entry.
+        let mut scenarios = vec![];
+        let mut next_entries = hurl_result.entries.iter().skip(1);
+        for entry in hurl_result.entries.iter() {
+            let effective = match next_entries.next() {
+                None => true,
+                Some(next) => next.entry_index != entry.entry_index,
+            };
+            if effective {
+                scenarios.push(Scenario::from(entry, &lines));
+            }
+        }
+        Testcase {
+            filename,
+            success,
+            scenarios,
+        }
+    }
+}
+
+impl Scenario {
+    /// Creates a scenario from an [`EntryResult`], given the source `lines` of the Hurl file.
+    fn from(entry: &EntryResult, lines: &[&str]) -> Scenario {
+        let line = entry.source_info.start.line;
+        let description = format!("entry {} (line {line})", entry.entry_index);
+        let request = match entry.calls.first() {
+            Some(call) => format!("{} {}", call.request.method, call.request.url),
+            None => source_line(lines, line),
+        };
+
+        let mut steps = vec![];
+        for assert in entry.asserts.iter() {
+            if let Some(step) = Step::from_assert(assert, lines) {
+                steps.push(step);
+            }
+        }
+        for error in entry.errors.iter().filter(|e| !e.assert) {
+            steps.push(Step::from_error(error, lines));
+        }
+        Scenario {
+            description,
+            request,
+            steps,
+        }
+    }
+
+    /// Returns `true` if all the steps of this scenario have succeeded.
+    pub(crate) fn success(&self) -> bool {
+        self.steps.iter().all(|s| s.success)
+    }
+}
+
+impl Step {
+    /// Creates a step from an `assert`, given the source `lines` of the Hurl file.
+    ///
+    /// Returns `None` for asserts that are always successful (like a wildcard `HTTP` version).
+    fn from_assert(assert: &AssertResult, lines: &[&str]) -> Option<Step> {
+        let description = match assert {
+            AssertResult::ImplicitVersion { expected, .. } => {
+                if expected == "HTTP" {
+                    return None;
+                }
+                format!("the HTTP version is {expected}")
+            }
+            AssertResult::ImplicitStatus { expected, .. } => format!("the status is {expected}"),
+            AssertResult::ImplicitHeader { source_info, .. } => {
+                format!("the header {}", source_line(lines, source_info.start.line))
+            }
+            AssertResult::ImplicitBody { source_info, .. } => {
+                format!("the body is {}", source_line(lines, source_info.start.line))
+            }
+            AssertResult::Explicit { source_info, .. } => {
+                source_line(lines, source_info.start.line)
+            }
+        };
+        let error = assert.to_runner_error();
+        let success = error.is_none();
+        let message = error.map(|e| strip_carets(&e.fixme(lines).to_string(Format::Plain)));
+        Some(Step {
+            description,
+            success,
+            message,
+        })
+    }
+
+    /// Creates a failed step from a runtime `error`, given the source `lines` of the Hurl file.
+    fn from_error(error: &RunnerError, lines: &[&str]) -> Step {
+        Step {
+            description: error.description(),
+            success: false,
+            message: Some(strip_carets(&error.fixme(lines).to_string(Format::Plain))),
+        }
+    }
+}
+
+/// Returns the trimmed source line at `line` (1-based), or an empty string if there is no such
+/// line.
+fn source_line(lines: &[&str], line: usize) -> String {
+    lines
+        .get(line.saturating_sub(1))
+        .map(|l| l.trim().to_string())
+        .unwrap_or_default()
+}
+
+/// Removes the carets pointing to the error source, added at the start of an error `message`.
+///
+/// Subsequent lines of the message are indented with the width of the carets: this
+/// indentation is also removed.
+fn strip_carets(message: &str) -> String {
+    let Some(first) = message.lines().next() else {
+        return String::new();
+    };
+    let width = first.len() - first.trim_start_matches([' ', '^']).len();
+    message
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            if i == 0 {
+                &line[width..]
+            } else {
+                let indent = line.len() - line.trim_start_matches(' ').len();
+                &line[indent.min(width)..]
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::SourceInfo;
+    use hurl_core::reader::Pos;
+    use hurl_core::types::Index;
+
+    use super::*;
+    use crate::runner::Value;
+
+    #[test]
+    fn test_testcase_from_result() {
+        let content = r#"GET http://localhost:8000/hello
+HTTP 404
+[Asserts]
+header "Content-Type" == "text/plain"
+"#;
+        let filename = Input::new("test.hurl");
+        let res = HurlResult {
+            entries: vec![EntryResult {
+                entry_index: Index::new(1),
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 32)),
+                asserts: vec![
+                    AssertResult::ImplicitVersion {
+                        actual: "HTTP/1.1".to_string(),
+                        expected: "HTTP".to_string(),
+                        source_info: SourceInfo::new(Pos::new(2, 1), Pos::new(2, 5)),
+                    },
+                    AssertResult::ImplicitStatus {
+                        actual: 200,
+                        expected: 404,
+                        source_info: SourceInfo::new(Pos::new(2, 6), Pos::new(2, 9)),
+                    },
+                    AssertResult::Explicit {
+                        actual: Ok(Some(Value::String("text/plain".to_string()))),
+                        source_info: SourceInfo::new(Pos::new(4, 1), Pos::new(4, 7)),
+                        predicate_result: Some(Ok(())),
+                    },
+                ],
+                ..Default::default()
+            }],
+            success: false,
+            ..Default::default()
+        };
+
+        let testcase = Testcase::from(&res, content, &filename);
+        assert_eq!(
+            testcase.scenarios,
+            vec![Scenario {
+                description: "entry 1 (line 1)".to_string(),
+                request: "GET http://localhost:8000/hello".to_string(),
+                steps: vec![
+                    Step {
+                        description: "the status is 404".to_string(),
+                        success: false,
+                        message: Some("actual value is <200>".to_string()),
+                    },
+                    Step {
+                        description: "header \"Content-Type\" == \"text/plain\"".to_string(),
+                        success: true,
+                        message: None,
+                    },
+                ],
+            }]
+        );
+        assert!(!testcase.scenarios[0].success());
+    }
+
+    #[test]
+    fn test_strip_carets() {
+        assert_eq!(
+            strip_carets("     ^^^ actual value is <200>"),
+            "actual value is <200>"
+        );
+        assert_eq!(
+            strip_carets("^ assert failed\n  expected: 1\n  actual: 2"),
+            "assert failed\nexpected: 1\nactual: 2"
+        );
+        assert_eq!(strip_carets(""), "");
+    }
+}
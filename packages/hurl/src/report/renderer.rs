@@ -0,0 +1,685 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Pluggable report renderers.
+//!
+//! Each report format (Allure, HTML, JSON, JUnit, pretty, TAP) is exposed as an implementation of
+//! [`ReportRenderer`]. Embedders can provide their own report formats by implementing this trait
+//! and registering their renderer in a [`ReportRenderers`] list, alongside the built-in ones.
+//!
+//! Renderers can write their report incrementally: each run is added to the report as soon as it's
+//! completed, and the report is completed at the end of the whole run. This way, the HTTP calls of
+//! every run don't have to be held in memory until the end of a long run. The JUnit and HTML
+//! renderers are incremental. Completed runs are added to the incremental renderers of a
+//! [`ReportRenderers`] list by subscribing the list to the runner events.
+//!
+//! The built-in renderers whose output varies from one run to another (timestamps, durations,
+//! generated identifiers) can be made deterministic, to compare their reports with golden files.
+use std::borrow::Cow;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use hurl_core::input::Input;
+use uuid::Uuid;
+
+use crate::report::{
+    ReportError, RunMetadata, allure, deterministic, html, json, junit, pretty, tap,
+};
+use crate::runner::{Event, EventListener, HurlResult};
+
+/// The run of a Hurl file, as consumed by a [`ReportRenderer`].
+#[derive(Clone, Copy, Debug)]
+pub struct ReportRun<'a> {
+    /// Source string of the Hurl file
+    pub content: &'a str,
+    /// Source file of the Hurl content
+    pub filename: &'a Input,
+    /// Result of the execution of this Hurl file
+    pub hurl_result: &'a HurlResult,
+}
+
+impl<'a> ReportRun<'a> {
+    /// Creates a new run to be reported.
+    pub fn new(content: &'a str, filename: &'a Input, hurl_result: &'a HurlResult) -> Self {
+        ReportRun {
+            content,
+            filename,
+            hurl_result,
+        }
+    }
+}
+
+/// A report format, aggregating multiple runs into a single report.
+pub trait ReportRenderer {
+    /// Returns the name of this report format, used in logs (ex: "JUnit").
+    fn name(&self) -> &str;
+
+    /// Returns the path of the file or directory where this report is written.
+    fn path(&self) -> &Path;
+
+    /// Renders the `runs` to this report, with the run `metadata`, redacting any `secrets`.
+    fn render(
+        &self,
+        runs: &[ReportRun],
+        metadata: &RunMetadata,
+        secrets: &[&str],
+    ) -> Result<(), ReportError>;
+
+    /// Returns `true` if this renderer writes its report incrementally: each run is added with
+    /// [`ReportRenderer::add_run`] as soon as it's completed, and the report is completed with
+    /// [`ReportRenderer::finish`] at the end of the whole run, instead of being rendered at once
+    /// with [`ReportRenderer::render`].
+    fn is_incremental(&self) -> bool {
+        false
+    }
+
+    /// Adds a completed `run` to this report, `index` being the position of the run in the whole
+    /// run, redacting any `secrets`.
+    ///
+    /// Renderers should only keep what's needed to complete the report, and not the HTTP calls of
+    /// the run.
+    fn add_run(
+        &mut self,
+        _index: usize,
+        _run: &ReportRun,
+        _secrets: &[&str],
+    ) -> Result<(), ReportError> {
+        Ok(())
+    }
+
+    /// Completes the report of the added runs, with the run `metadata`, redacting any `secrets`.
+    fn finish(&mut self, _metadata: &RunMetadata, _secrets: &[&str]) -> Result<(), ReportError> {
+        Ok(())
+    }
+}
+
+/// An ordered list of report renderers.
+#[derive(Default)]
+pub struct ReportRenderers {
+    renderers: Vec<Box<dyn ReportRenderer>>,
+    /// First error raised while adding a completed run to the incremental renderers.
+    error: Option<ReportError>,
+}
+
+impl ReportRenderers {
+    /// Creates an empty list of renderers.
+    pub fn new() -> Self {
+        ReportRenderers::default()
+    }
+
+    /// Registers a new `renderer`. Renderers are run in their registration order.
+    pub fn register(&mut self, renderer: Box<dyn ReportRenderer>) {
+        self.renderers.push(renderer);
+    }
+
+    /// Returns `true` if no renderer has been registered, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.renderers.is_empty()
+    }
+
+    /// Returns an iterator over the registered renderers.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn ReportRenderer> {
+        self.renderers.iter().map(|r| r.as_ref())
+    }
+
+    /// Returns an iterator over the registered renderers, allowing to add runs to them.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Box<dyn ReportRenderer>> {
+        self.renderers.iter_mut()
+    }
+
+    /// Takes the first error raised while adding completed runs to the incremental renderers, if
+    /// any. Once an error has been raised, no more runs are added.
+    pub fn take_error(&mut self) -> Option<ReportError> {
+        self.error.take()
+    }
+}
+
+/// Completed runs are added to the incremental renderers as soon as they're published.
+impl EventListener for ReportRenderers {
+    fn on_event(&mut self, event: &Event) {
+        let Event::RunCompleted {
+            index,
+            content,
+            filename,
+            result,
+        } = event
+        else {
+            return;
+        };
+        if self.error.is_some() {
+            return;
+        }
+        let secrets = result.variables.secrets();
+        let secrets = secrets.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+        let run = ReportRun::new(content, filename, result);
+        for renderer in self.renderers.iter_mut().filter(|r| r.is_incremental()) {
+            if let Err(error) = renderer.add_run(*index, &run, &secrets) {
+                self.error = Some(error);
+                return;
+            }
+        }
+    }
+}
+
+/// Renders a JUnit XML report to a file.
+pub struct JunitRenderer {
+    file: PathBuf,
+    deterministic: bool,
+    /// Testcases of the runs added incrementally, with their position in the whole run.
+    testcases: Vec<(usize, junit::Testcase)>,
+}
+
+impl JunitRenderer {
+    /// Creates a JUnit renderer writing to `file`.
+    pub fn new(file: &Path) -> Self {
+        JunitRenderer {
+            file: file.to_path_buf(),
+            deterministic: false,
+            testcases: vec![],
+        }
+    }
+
+    /// Zeroes the durations of the report if `deterministic` is set.
+    pub fn deterministic(mut self, deterministic: bool) -> Self {
+        self.deterministic = deterministic;
+        self
+    }
+}
+
+impl ReportRenderer for JunitRenderer {
+    fn name(&self) -> &str {
+        "JUnit"
+    }
+
+    fn path(&self) -> &Path {
+        &self.file
+    }
+
+    fn render(
+        &self,
+        runs: &[ReportRun],
+        metadata: &RunMetadata,
+        secrets: &[&str],
+    ) -> Result<(), ReportError> {
+        let testcases = runs
+            .iter()
+            .map(|r| junit_testcase(r, self.deterministic))
+            .collect::<Vec<_>>();
+        junit::write_report(&self.file, &testcases, metadata, secrets)
+    }
+
+    fn is_incremental(&self) -> bool {
+        true
+    }
+
+    fn add_run(
+        &mut self,
+        index: usize,
+        run: &ReportRun,
+        _secrets: &[&str],
+    ) -> Result<(), ReportError> {
+        let testcase = junit_testcase(run, self.deterministic);
+        self.testcases.push((index, testcase));
+        Ok(())
+    }
+
+    fn finish(&mut self, metadata: &RunMetadata, secrets: &[&str]) -> Result<(), ReportError> {
+        // Runs can be completed in any order with parallel runs.
+        self.testcases.sort_by_key(|(index, _)| *index);
+        let testcases = self
+            .testcases
+            .drain(..)
+            .map(|(_, testcase)| testcase)
+            .collect::<Vec<_>>();
+        junit::write_report(&self.file, &testcases, metadata, secrets)
+    }
+}
+
+/// Returns the JUnit testcase of a `run`, with zeroed durations if `deterministic` is set.
+fn junit_testcase(run: &ReportRun, deterministic: bool) -> junit::Testcase {
+    let result = result(run, deterministic);
+    junit::Testcase::from(&result, run.content, run.filename)
+}
+
+/// Renders a TAP report to a file.
+pub struct TapRenderer {
+    file: PathBuf,
+}
+
+impl TapRenderer {
+    /// Creates a TAP renderer writing to `file`.
+    pub fn new(file: &Path) -> Self {
+        TapRenderer {
+            file: file.to_path_buf(),
+        }
+    }
+}
+
+impl ReportRenderer for TapRenderer {
+    fn name(&self) -> &str {
+        "TAP"
+    }
+
+    fn path(&self) -> &Path {
+        &self.file
+    }
+
+    fn render(
+        &self,
+        runs: &[ReportRun],
+        _metadata: &RunMetadata,
+        secrets: &[&str],
+    ) -> Result<(), ReportError> {
+        let testcases = runs
+            .iter()
+            .map(|r| tap::Testcase::from(r.hurl_result, r.content, r.filename))
+            .collect::<Vec<_>>();
+        tap::write_report(&self.file, &testcases, secrets)
+    }
+}
+
+/// Renders a human-readable report to a file.
+pub struct PrettyRenderer {
+    file: PathBuf,
+}
+
+impl PrettyRenderer {
+    /// Creates a pretty renderer writing to `file`.
+    pub fn new(file: &Path) -> Self {
+        PrettyRenderer {
+            file: file.to_path_buf(),
+        }
+    }
+}
+
+impl ReportRenderer for PrettyRenderer {
+    fn name(&self) -> &str {
+        "pretty"
+    }
+
+    fn path(&self) -> &Path {
+        &self.file
+    }
+
+    fn render(
+        &self,
+        runs: &[ReportRun],
+        _metadata: &RunMetadata,
+        secrets: &[&str],
+    ) -> Result<(), ReportError> {
+        let testcases = runs
+            .iter()
+            .map(|r| pretty::Testcase::from(r.hurl_result, r.content, r.filename))
+            .collect::<Vec<_>>();
+        pretty::write_report(&self.file, &testcases, secrets)
+    }
+}
+
+/// Renders an HTML report to a directory.
+pub struct HtmlRenderer {
+    dir: PathBuf,
+    deterministic: bool,
+    /// Testcases of the runs added incrementally, with their position in the whole run.
+    testcases: Vec<(usize, html::Testcase)>,
+}
+
+impl HtmlRenderer {
+    /// Creates an HTML renderer writing to `dir`.
+    pub fn new(dir: &Path) -> Self {
+        HtmlRenderer {
+            dir: dir.to_path_buf(),
+            deterministic: false,
+            testcases: vec![],
+        }
+    }
+
+    /// Zeroes the timestamps and durations of the report, derives the pages names from the runs
+    /// and sorts the report rows by file name, if `deterministic` is set.
+    pub fn deterministic(mut self, deterministic: bool) -> Self {
+        self.deterministic = deterministic;
+        self
+    }
+}
+
+impl ReportRenderer for HtmlRenderer {
+    fn name(&self) -> &str {
+        "HTML"
+    }
+
+    fn path(&self) -> &Path {
+        &self.dir
+    }
+
+    fn render(
+        &self,
+        runs: &[ReportRun],
+        _metadata: &RunMetadata,
+        secrets: &[&str],
+    ) -> Result<(), ReportError> {
+        // We ensure that the containing folder exists.
+        let store_path = create_store_dir(&self.dir, "Issue writing HTML report")?;
+
+        let mut testcases = vec![];
+        for (index, run) in runs.iter().enumerate() {
+            let testcase = self.write_run(index, run, &store_path, secrets)?;
+            testcases.push(testcase);
+        }
+        html::write_report(&self.dir, &testcases, self.deterministic)
+    }
+
+    fn is_incremental(&self) -> bool {
+        true
+    }
+
+    fn add_run(
+        &mut self,
+        index: usize,
+        run: &ReportRun,
+        secrets: &[&str],
+    ) -> Result<(), ReportError> {
+        let store_path = create_store_dir(&self.dir, "Issue writing HTML report")?;
+        let testcase = self.write_run(index, run, &store_path, secrets)?;
+        self.testcases.push((index, testcase));
+        Ok(())
+    }
+
+    fn finish(&mut self, _metadata: &RunMetadata, _secrets: &[&str]) -> Result<(), ReportError> {
+        // Runs can be completed in any order with parallel runs.
+        self.testcases.sort_by_key(|(index, _)| *index);
+        let testcases = self
+            .testcases
+            .drain(..)
+            .map(|(_, testcase)| testcase)
+            .collect::<Vec<_>>();
+        html::write_report(&self.dir, &testcases, self.deterministic)
+    }
+}
+
+impl HtmlRenderer {
+    /// Writes the HTML pages of a `run` at `index` to the `store_path` directory, and returns its
+    /// testcase.
+    fn write_run(
+        &self,
+        index: usize,
+        run: &ReportRun,
+        store_path: &Path,
+        secrets: &[&str],
+    ) -> Result<html::Testcase, ReportError> {
+        let result = result(run, self.deterministic);
+        let id = run_id(run, index, self.deterministic);
+        let testcase = html::Testcase::from(&result, run.filename, id);
+        testcase.write_html(run.content, &result.entries, store_path, secrets)?;
+        Ok(testcase)
+    }
+}
+
+/// Renders a JSON report to a directory.
+pub struct JsonRenderer {
+    dir: PathBuf,
+    deterministic: bool,
+}
+
+impl JsonRenderer {
+    /// Creates a JSON renderer writing to `dir`.
+    pub fn new(dir: &Path) -> Self {
+        JsonRenderer {
+            dir: dir.to_path_buf(),
+            deterministic: false,
+        }
+    }
+
+    /// Zeroes the timestamps and durations of the report and derives the stored responses names
+    /// from their content, if `deterministic` is set.
+    pub fn deterministic(mut self, deterministic: bool) -> Self {
+        self.deterministic = deterministic;
+        self
+    }
+}
+
+impl ReportRenderer for JsonRenderer {
+    fn name(&self) -> &str {
+        "JSON"
+    }
+
+    fn path(&self) -> &Path {
+        &self.dir
+    }
+
+    fn render(
+        &self,
+        runs: &[ReportRun],
+        _metadata: &RunMetadata,
+        secrets: &[&str],
+    ) -> Result<(), ReportError> {
+        // We ensure that the containing folder exists.
+        let store_path = create_store_dir(&self.dir, "Issue writing JSON report")?;
+
+        let results = results(runs, self.deterministic);
+        let testcases = runs
+            .iter()
+            .zip(results.iter())
+            .map(|(r, result)| json::Testcase::new(result, r.content, r.filename))
+            .collect::<Vec<_>>();
+
+        let index_path = self.dir.join("report.json");
+        json::write_report(
+            &index_path,
+            &testcases,
+            &store_path,
+            self.deterministic,
+            secrets,
+        )
+    }
+}
+
+/// Renders Allure results to a directory.
+pub struct AllureRenderer {
+    dir: PathBuf,
+    deterministic: bool,
+}
+
+impl AllureRenderer {
+    /// Creates an Allure renderer writing to `dir`.
+    pub fn new(dir: &Path) -> Self {
+        AllureRenderer {
+            dir: dir.to_path_buf(),
+            deterministic: false,
+        }
+    }
+
+    /// Zeroes the timestamps of the results and derives the results identifiers from the runs,
+    /// if `deterministic` is set.
+    pub fn deterministic(mut self, deterministic: bool) -> Self {
+        self.deterministic = deterministic;
+        self
+    }
+}
+
+impl ReportRenderer for AllureRenderer {
+    fn name(&self) -> &str {
+        "Allure"
+    }
+
+    fn path(&self) -> &Path {
+        &self.dir
+    }
+
+    fn render(
+        &self,
+        runs: &[ReportRun],
+        metadata: &RunMetadata,
+        secrets: &[&str],
+    ) -> Result<(), ReportError> {
+        let results = results(runs, self.deterministic);
+        let testcases = runs
+            .iter()
+            .zip(results.iter())
+            .enumerate()
+            .map(|(index, (r, result))| {
+                let uuid = run_id(r, index, self.deterministic);
+                allure::Testcase::from(result, r.content, r.filename, metadata, uuid)
+            })
+            .collect::<Vec<_>>();
+        allure::write_report(&self.dir, &testcases, metadata, secrets)
+    }
+}
+
+/// Returns the results of the `runs`, with zeroed timestamps and durations if `deterministic` is
+/// set.
+fn results<'a>(runs: &[ReportRun<'a>], deterministic: bool) -> Vec<Cow<'a, HurlResult>> {
+    runs.iter().map(|r| result(r, deterministic)).collect()
+}
+
+/// Returns the result of a `run`, with zeroed timestamps and durations if `deterministic` is set.
+fn result<'a>(run: &ReportRun<'a>, deterministic: bool) -> Cow<'a, HurlResult> {
+    if deterministic {
+        Cow::Owned(deterministic::normalize(run.hurl_result))
+    } else {
+        Cow::Borrowed(run.hurl_result)
+    }
+}
+
+/// Returns an identifier for the `run` at `index` in a report: randomly generated, or derived
+/// from the run position and file name if `deterministic` is set.
+fn run_id(run: &ReportRun, index: usize, deterministic: bool) -> Uuid {
+    if deterministic {
+        let seed = format!("{index}:{}", run.filename);
+        deterministic::stable_uuid(seed.as_bytes())
+    } else {
+        Uuid::new_v4()
+    }
+}
+
+/// Creates the `store` directory of a report directory `dir`, and returns its path.
+fn create_store_dir(dir: &Path, message: &str) -> Result<PathBuf, ReportError> {
+    let store_path = dir.join("store");
+    fs::create_dir_all(&store_path)
+        .map_err(|e| ReportError::from_io_error(&e, &store_path, message))?;
+    Ok(store_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::*;
+
+    /// A custom report format, as an embedder would provide.
+    struct CsvRenderer {
+        file: PathBuf,
+    }
+
+    impl ReportRenderer for CsvRenderer {
+        fn name(&self) -> &str {
+            "CSV"
+        }
+
+        fn path(&self) -> &Path {
+            &self.file
+        }
+
+        fn render(
+            &self,
+            _runs: &[ReportRun],
+            _metadata: &RunMetadata,
+            _secrets: &[&str],
+        ) -> Result<(), ReportError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_register_renderers() {
+        let mut renderers = ReportRenderers::new();
+        assert!(renderers.is_empty());
+
+        renderers.register(Box::new(JunitRenderer::new(Path::new("report.xml"))));
+        renderers.register(Box::new(CsvRenderer {
+            file: PathBuf::from("report.csv"),
+        }));
+        renderers.register(Box::new(HtmlRenderer::new(Path::new("report"))));
+        assert!(!renderers.is_empty());
+
+        let names = renderers.iter().map(|r| r.name()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["JUnit", "CSV", "HTML"]);
+        let paths = renderers.iter().map(|r| r.path()).collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                Path::new("report.xml"),
+                Path::new("report.csv"),
+                Path::new("report")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_junit_renderer_is_incremental() {
+        let file = std::env::temp_dir().join(format!("hurl-junit-{}.xml", std::process::id()));
+        let mut renderer = JunitRenderer::new(&file);
+        assert!(renderer.is_incremental());
+
+        // Runs are added in completion order, and reported in their index order.
+        let hurl_result = HurlResult::default();
+        let (a, b) = (Input::new("a.hurl"), Input::new("b.hurl"));
+        renderer
+            .add_run(1, &ReportRun::new("", &b, &hurl_result), &[])
+            .unwrap();
+        renderer
+            .add_run(0, &ReportRun::new("", &a, &hurl_result), &[])
+            .unwrap();
+        renderer.finish(&RunMetadata::default(), &[]).unwrap();
+
+        let xml = std::fs::read_to_string(&file).unwrap();
+        assert!(xml.find("a.hurl").unwrap() < xml.find("b.hurl").unwrap());
+        std::fs::remove_file(&file).unwrap();
+    }
+
+    #[test]
+    fn test_renderers_add_completed_runs() {
+        let file = std::env::temp_dir().join(format!("hurl-events-{}.xml", std::process::id()));
+        let mut renderers = ReportRenderers::new();
+        renderers.register(Box::new(JunitRenderer::new(&file)));
+
+        let hurl_result = HurlResult::default();
+        let filename = Input::new("a.hurl");
+        renderers.on_event(&Event::RunCompleted {
+            index: 0,
+            content: "",
+            filename: &filename,
+            result: &hurl_result,
+        });
+        assert!(renderers.take_error().is_none());
+        for renderer in renderers.iter_mut() {
+            renderer.finish(&RunMetadata::default(), &[]).unwrap();
+        }
+
+        let xml = std::fs::read_to_string(&file).unwrap();
+        assert!(xml.contains("a.hurl"));
+        std::fs::remove_file(&file).unwrap();
+    }
+
+    #[test]
+    fn test_run_id() {
+        let filename = Input::new("test.hurl");
+        let hurl_result = HurlResult::default();
+        let run = ReportRun::new("", &filename, &hurl_result);
+        assert_eq!(run_id(&run, 0, true), run_id(&run, 0, true));
+        assert_ne!(run_id(&run, 0, true), run_id(&run, 1, true));
+        assert_ne!(run_id(&run, 0, false), run_id(&run, 0, false));
+    }
+}
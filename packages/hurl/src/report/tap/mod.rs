@@ -16,6 +16,48 @@
  *
  */
 //! [Test Anything Protocol (TAP)](https://testanything.org) report.
+//!
+//! The report follows TAP version 14: each Hurl file is a subtest, whose test points are the
+//! entries of the file. Each entry is itself a subtest, whose test points are the asserts of the
+//! entry. Failed asserts, and entries with errors other than asserts errors, have a YAML diagnostic
+//! block describing their errors.
+//!
+//! # Example:
+//!
+//! ```text
+//! TAP version 14
+//! 1..2
+//! # Subtest: tests/hello.hurl
+//!     1..1
+//!     # Subtest: entry 1 (line 1)
+//!         1..1
+//!         ok 1 - status is 200 (line 2)
+//!     ok 1 - entry 1 (line 1)
+//! ok 1 - tests/hello.hurl
+//! # Subtest: tests/error_assert_status.hurl
+//!     1..1
+//!     # Subtest: entry 1 (line 1)
+//!         1..1
+//!         not ok 1 - status is 200 (line 2)
+//!           ---
+//!           errors:
+//!             - severity: fail
+//!               at:
+//!                 file: tests/error_assert_status.hurl
+//!                 line: 2
+//!                 column: 10
+//!               message: |
+//!                 Assert status code
+//!                   --> tests/error_assert_status.hurl:2:10
+//!                    |
+//!                    | GET http://localhost:8000/not_found
+//!                  2 | HTTP/1.0 200
+//!                    |          ^^^ actual value is <404>
+//!                    |
+//!           ...
+//!     not ok 1 - entry 1 (line 1)
+//! not ok 2 - tests/error_assert_status.hurl
+//! ```
 mod report;
 mod testcase;
 
@@ -23,14 +23,31 @@ use std::path::Path;
 use regex::Regex;
 
 use super::Testcase;
+use super::testcase::TestPoint;
 use crate::report::ReportError;
 use crate::util::path::create_dir_all;
+use crate::util::redacted::Redact;
 
-/// See <https://testanything.org/tap-version-13-specification.html>
-const TAP_REPORT_VERSION_MARKER: &str = "TAP version 13";
+/// See <https://testanything.org/tap-version-14-specification.html>
+const TAP_REPORT_VERSION_MARKER: &str = "TAP version 14";
+
+/// Previous TAP version marker, still accepted when appending to an existing report.
+const TAP_REPORT_VERSION_13_MARKER: &str = "TAP version 13";
+
+/// Indentation of a subtest, relative to its parent.
+const SUBTEST_INDENT: &str = "    ";
+
+/// Indentation of a YAML diagnostic block, relative to its test point.
+const YAML_INDENT: &str = "  ";
 
 /// Creates/Append a Tap report from a list of `testcases`
-pub fn write_report(filename: &Path, testcases: &[Testcase]) -> Result<(), ReportError> {
+///
+/// `secrets` strings are redacted from the YAML diagnostic blocks.
+pub fn write_report(
+    filename: &Path,
+    testcases: &[Testcase],
+    secrets: &[&str],
+) -> Result<(), ReportError> {
     let mut all_testcases = vec![];
 
     let existing_testcases = parse_tap_file(filename)?;
@@ -40,17 +57,29 @@ pub fn write_report(filename: &Path, testcases: &[Testcase]) -> Result<(), Repor
     for testcase in testcases {
         all_testcases.push(testcase);
     }
-    write_tap_file(filename, &all_testcases)
+    write_tap_file(filename, &all_testcases, secrets)
 }
 
 /// Creates a Tap from a list of `testcases`.
-fn write_tap_file(filename: &Path, testcases: &[&Testcase]) -> Result<(), ReportError> {
+fn write_tap_file(
+    filename: &Path,
+    testcases: &[&Testcase],
+    secrets: &[&str],
+) -> Result<(), ReportError> {
     create_dir_all(filename)
         .map_err(|e| ReportError::from_io_error(&e, filename, "Issue writing TAP report"))?;
 
     let mut file = File::create(filename)
         .map_err(|e| ReportError::from_io_error(&e, filename, "Issue writing TAP report"))?;
 
+    let s = tap_report(testcases, secrets);
+    file.write_all(s.as_bytes())
+        .map_err(|e| ReportError::from_io_error(&e, filename, "Issue writing TAP report"))?;
+    Ok(())
+}
+
+/// Serializes `testcases` to a TAP 14 report, each testcase being a subtest.
+fn tap_report(testcases: &[&Testcase], secrets: &[&str]) -> String {
     let start = 1;
     let end = testcases.len();
 
@@ -61,11 +90,59 @@ fn write_tap_file(filename: &Path, testcases: &[&Testcase]) -> Result<(), Report
         let state = if testcase.success { "ok" } else { "not ok" };
         let number = i + 1;
         let description = &testcase.description;
+        if !testcase.points.is_empty() {
+            s.push_str(format!("# Subtest: {description}\n").as_str());
+            s.push_str(&subtest(&testcase.points, SUBTEST_INDENT, secrets));
+        }
         s.push_str(format!("{state} {number} - {description}\n").as_str());
     }
-    file.write_all(s.as_bytes())
-        .map_err(|e| ReportError::from_io_error(&e, filename, "Issue writing TAP report"))?;
-    Ok(())
+    s
+}
+
+/// Serializes the test `points` of a subtest, indented by `indent`, with their YAML diagnostic
+/// blocks and their own subtests.
+fn subtest(points: &[TestPoint], indent: &str, secrets: &[&str]) -> String {
+    let mut s = format!("{indent}1..{}\n", points.len());
+    for (i, point) in points.iter().enumerate() {
+        let state = if point.success { "ok" } else { "not ok" };
+        let number = i + 1;
+        let description = escape_description(&point.description.redact(secrets));
+        if !point.points.is_empty() {
+            s.push_str(format!("{indent}# Subtest: {description}\n").as_str());
+            let indent = format!("{indent}{SUBTEST_INDENT}");
+            s.push_str(&subtest(&point.points, &indent, secrets));
+        }
+        s.push_str(format!("{indent}{state} {number} - {description}\n").as_str());
+        if point.diagnostic.is_empty() {
+            continue;
+        }
+        s.push_str(format!("{indent}{YAML_INDENT}---\n").as_str());
+        for line in point.diagnostic.iter() {
+            let line = line.redact(secrets);
+            s.push_str(format!("{indent}{YAML_INDENT}{line}\n").as_str());
+        }
+        s.push_str(format!("{indent}{YAML_INDENT}...\n").as_str());
+    }
+    s
+}
+
+/// Escapes the `#` and `\\` characters of a test point `description`, so a `#` in a Hurl
+/// source line is not read as a TAP directive.
+fn escape_description(description: &str) -> String {
+    description.replace('\\', "\\\\").replace('#', "\\#")
+}
+
+/// Unescapes a test point `description` escaped with [`escape_description`].
+fn unescape_description(description: &str) -> String {
+    let mut s = String::with_capacity(description.len());
+    let mut chars = description.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => s.push(chars.next().unwrap_or('\\')),
+            _ => s.push(c),
+        }
+    }
+    s
 }
 
 /// Parse Tap report file
@@ -79,13 +156,17 @@ fn parse_tap_file(filename: &Path) -> Result<Vec<Testcase>, ReportError> {
 }
 
 /// Parse Tap report
+///
+/// Subtests lines (indented by 4 spaces) are attached to the following top-level test line.
 fn parse_tap_report(s: &str) -> Result<Vec<Testcase>, ReportError> {
     let mut testcases = vec![];
     let mut lines: Vec<&str> = s.lines().collect::<Vec<&str>>();
     if !lines.is_empty() {
         let mut header = lines.remove(0);
         // A tap report may have a protocol version header as per TAP
-        if header.eq_ignore_ascii_case(TAP_REPORT_VERSION_MARKER) {
+        if header.eq_ignore_ascii_case(TAP_REPORT_VERSION_MARKER)
+            || header.eq_ignore_ascii_case(TAP_REPORT_VERSION_13_MARKER)
+        {
             header = lines.remove(0);
         }
         let re = Regex::new(r"^1\.\.\d+.*$").unwrap();
@@ -94,17 +175,74 @@ fn parse_tap_report(s: &str) -> Result<Vec<Testcase>, ReportError> {
                 "Invalid TAP Header <{header}>"
             )));
         }
+        let mut subtest_lines = vec![];
         for line in lines {
+            if let Some(subtest_line) = line.strip_prefix(SUBTEST_INDENT) {
+                subtest_lines.push(subtest_line);
+                continue;
+            }
             let line = line.trim();
-            if !line.is_empty() {
-                let testcase = Testcase::parse(line)?;
-                testcases.push(testcase);
+            if line.is_empty() || line.starts_with('#') {
+                continue;
             }
+            let mut testcase = Testcase::parse(line)?;
+            testcase.points = parse_subtest(&subtest_lines)?;
+            subtest_lines.clear();
+            testcases.push(testcase);
         }
     }
     Ok(testcases)
 }
 
+/// Parses the `lines` of a subtest (without their indentation), returning its test points.
+///
+/// Lines of a nested subtest (indented by 4 spaces) are attached to the following test point.
+fn parse_subtest(lines: &[&str]) -> Result<Vec<TestPoint>, ReportError> {
+    let re = Regex::new(r"^1\.\.\d+.*$").unwrap();
+    let mut points: Vec<TestPoint> = vec![];
+    let mut subtest_lines = vec![];
+    let mut in_yaml = false;
+    for line in lines {
+        if in_yaml {
+            let yaml_line = line.strip_prefix(YAML_INDENT).unwrap_or(line);
+            if yaml_line.trim_end() == "..." {
+                in_yaml = false;
+            } else if let Some(point) = points.last_mut() {
+                point.diagnostic.push(yaml_line.to_string());
+            }
+            continue;
+        }
+        if let Some(subtest_line) = line.strip_prefix(SUBTEST_INDENT) {
+            subtest_lines.push(subtest_line);
+            continue;
+        }
+        if let Some(yaml_line) = line.strip_prefix(YAML_INDENT) {
+            if yaml_line.trim_end() != "---" {
+                return Err(ReportError::from_string(&format!(
+                    "Invalid TAP line <{line}> - YAML block must start with ---"
+                )));
+            }
+            if points.is_empty() {
+                return Err(ReportError::from_string(&format!(
+                    "Invalid TAP line <{line}> - YAML block without test point"
+                )));
+            }
+            in_yaml = true;
+            continue;
+        }
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || re.is_match(line) {
+            continue;
+        }
+        let mut point = TestPoint::parse(line)?;
+        point.description = unescape_description(&point.description);
+        point.points = parse_subtest(&subtest_lines)?;
+        subtest_lines.clear();
+        points.push(point);
+    }
+    Ok(points)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -122,15 +260,18 @@ not ok 3 - tests_ok/test.3.hurl
             vec![
                 Testcase {
                     description: "tests_ok/test.1.hurl".to_string(),
-                    success: true
+                    success: true,
+                    points: vec![],
                 },
                 Testcase {
                     description: "tests_ok/test.2.hurl".to_string(),
-                    success: true
+                    success: true,
+                    points: vec![],
                 },
                 Testcase {
                     description: "tests_ok/test.3.hurl".to_string(),
-                    success: false
+                    success: false,
+                    points: vec![],
                 }
             ]
         );
@@ -150,15 +291,18 @@ not ok 3 - tests_ok/test.3.hurl
             vec![
                 Testcase {
                     description: "tests_ok/test.1.hurl".to_string(),
-                    success: true
+                    success: true,
+                    points: vec![],
                 },
                 Testcase {
                     description: "tests_ok/test.2.hurl".to_string(),
-                    success: true
+                    success: true,
+                    points: vec![],
                 },
                 Testcase {
                     description: "tests_ok/test.3.hurl".to_string(),
-                    success: false
+                    success: false,
+                    points: vec![],
                 }
             ]
         );
@@ -176,26 +320,118 @@ ok 5 - test.5.hurl
             vec![
                 Testcase {
                     description: "test.1.hurl".to_string(),
-                    success: true
+                    success: true,
+                    points: vec![],
                 },
                 Testcase {
                     description: "test.2.hurl".to_string(),
-                    success: true
+                    success: true,
+                    points: vec![],
                 },
                 Testcase {
                     description: "test.3.hurl".to_string(),
-                    success: false
+                    success: false,
+                    points: vec![],
                 },
                 Testcase {
                     description: "test.4.hurl".to_string(),
-                    success: false
+                    success: false,
+                    points: vec![],
                 },
                 Testcase {
                     description: "test.5.hurl".to_string(),
-                    success: true
+                    success: true,
+                    points: vec![],
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_tap_report_with_subtests() {
+        let s = r#"TAP version 14
+1..2
+# Subtest: test.1.hurl
+    1..1
+    ok 1 - entry 1 (line 1)
+ok 1 - test.1.hurl
+# Subtest: test.2.hurl
+    1..2
+    # Subtest: entry 1 (line 1)
+        1..1
+        ok 1 - status is 200 (line 2)
+    ok 1 - entry 1 (line 1)
+    # Subtest: entry 2 (line 4)
+        1..2
+        ok 1 - status is 200 (line 5)
+        not ok 2 - jsonpath "$.id" == 1 \# user id (line 7)
+          ---
+          errors:
+            - severity: fail
+          ...
+    not ok 2 - entry 2 (line 4)
+not ok 2 - test.2.hurl
+"#;
+        let testcases = parse_tap_report(s).unwrap();
+        assert_eq!(
+            testcases,
+            vec![
+                Testcase {
+                    description: "test.1.hurl".to_string(),
+                    success: true,
+                    points: vec![TestPoint {
+                        description: "entry 1 (line 1)".to_string(),
+                        success: true,
+                        diagnostic: vec![],
+                        points: vec![],
+                    }],
+                },
+                Testcase {
+                    description: "test.2.hurl".to_string(),
+                    success: false,
+                    points: vec![
+                        TestPoint {
+                            description: "entry 1 (line 1)".to_string(),
+                            success: true,
+                            diagnostic: vec![],
+                            points: vec![TestPoint {
+                                description: "status is 200 (line 2)".to_string(),
+                                success: true,
+                                diagnostic: vec![],
+                                points: vec![],
+                            }],
+                        },
+                        TestPoint {
+                            description: "entry 2 (line 4)".to_string(),
+                            success: false,
+                            diagnostic: vec![],
+                            points: vec![
+                                TestPoint {
+                                    description: "status is 200 (line 5)".to_string(),
+                                    success: true,
+                                    diagnostic: vec![],
+                                    points: vec![],
+                                },
+                                TestPoint {
+                                    description: "jsonpath \"$.id\" == 1 # user id (line 7)"
+                                        .to_string(),
+                                    success: false,
+                                    diagnostic: vec![
+                                        "errors:".to_string(),
+                                        "  - severity: fail".to_string(),
+                                    ],
+                                    points: vec![],
+                                },
+                            ],
+                        },
+                    ],
                 }
             ]
         );
+
+        // Parsing and serializing a report is idempotent.
+        let testcases = testcases.iter().collect::<Vec<_>>();
+        assert_eq!(tap_report(&testcases, &[]), s);
     }
 
     #[test]
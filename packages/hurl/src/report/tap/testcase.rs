@@ -15,25 +15,56 @@
  * limitations under the License.
  *
  */
+use hurl_core::error::{DisplaySourceError, OutputFormat};
 use hurl_core::input::Input;
 
+use hurl_core::ast::SourceInfo;
+
 use crate::report::ReportError;
-use crate::runner::HurlResult;
+use crate::runner::{AssertResult, EntryResult, HurlResult, RunnerError};
 
+/// A TAP testcase: one Hurl file, reported as a subtest whose test points are the file entries.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Testcase {
     pub(crate) description: String,
     pub(crate) success: bool,
+    pub(crate) points: Vec<TestPoint>,
+}
+
+/// A TAP test point of a subtest: one entry of a Hurl file, or one assert of an entry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TestPoint {
+    pub(crate) description: String,
+    pub(crate) success: bool,
+    /// Lines of the YAML diagnostic block, without the `---` and `...` markers.
+    pub(crate) diagnostic: Vec<String>,
+    /// Test points of the subtest of this test point: the asserts of an entry.
+    pub(crate) points: Vec<TestPoint>,
 }
 
 impl Testcase {
     /// Creates an Tap &lt;testcase&gt; from an [`HurlResult`].
-    pub fn from(hurl_result: &HurlResult, filename: &Input) -> Testcase {
+    pub fn from(hurl_result: &HurlResult, content: &str, filename: &Input) -> Testcase {
         let description = filename.to_string();
         let success = hurl_result.errors().is_empty();
+
+        // As in [`HurlResult::errors`], retried entries are ignored: we only keep the last
+        // execution of each entry.
+        let mut points = vec![];
+        let mut next_entries = hurl_result.entries.iter().skip(1);
+        for entry in hurl_result.entries.iter() {
+            let effective = match next_entries.next() {
+                None => true,
+                Some(next) => next.entry_index != entry.entry_index,
+            };
+            if effective {
+                points.push(TestPoint::from(entry, content, &description));
+            }
+        }
         Testcase {
             description,
             success,
+            points,
         }
     }
 
@@ -41,44 +72,192 @@ impl Testcase {
     /// ok 1 - this is the first test
     /// nok 2 - this is the second test
     pub fn parse(line: &str) -> Result<Testcase, ReportError> {
-        let mut line = line;
-        let success = if line.starts_with("ok") {
-            line = &line[2..];
-            true
-        } else if line.starts_with("not ok") {
-            line = &line[6..];
-            false
-        } else {
-            return Err(ReportError::from_string(&format!(
-                "Invalid TAP line <{line}> - must start with ok or nok"
-            )));
-        };
+        let (success, description) = parse_test_line(line)?;
+        Ok(Testcase {
+            description,
+            success,
+            points: vec![],
+        })
+    }
+}
 
-        let description = match line.find('-') {
-            None => {
-                return Err(ReportError::from_string(&format!(
-                    "Invalid TAP line <{line}> - missing '-' separator"
-                )));
-            }
-            Some(index) => {
-                if line.split_at(index).0.trim().parse::<usize>().is_err() {
-                    return Err(ReportError::from_string(&format!(
-                        "Invalid TAP line <{line}> - missing test number"
-                    )));
+impl TestPoint {
+    /// Creates a TAP test point from an [`EntryResult`].
+    ///
+    /// The asserts of the entry are the test points of its subtest. Errors of the entry which are
+    /// not asserts errors (asserts errors being described by their own test point) are described
+    /// in a YAML diagnostic block.
+    fn from(entry: &EntryResult, content: &str, filename: &str) -> TestPoint {
+        let (filename, content) = entry.source(filename, content);
+        let line = entry.source_info.start.line;
+        let description = format!("entry {} (line {line})", entry.entry_index);
+        let success = entry.errors.is_empty();
+
+        let errors = entry
+            .errors
+            .iter()
+            .filter(|e| !e.assert)
+            .collect::<Vec<_>>();
+        let mut diagnostic = vec![];
+        if !errors.is_empty() {
+            diagnostic.push("errors:".to_string());
+        }
+        for error in errors {
+            diagnostic.extend(error_diagnostic(
+                error,
+                "error",
+                &filename,
+                content,
+                entry.source_info,
+            ));
+        }
+
+        // Implicit asserts are evaluated before explicit asserts: test points are sorted by
+        // line to follow the Hurl source.
+        let mut asserts = entry.asserts.iter().collect::<Vec<_>>();
+        asserts.sort_by_key(|a| a.line());
+        let points = asserts
+            .into_iter()
+            .filter_map(|assert| TestPoint::from_assert(assert, entry, &filename, content))
+            .collect();
+        TestPoint {
+            description,
+            success,
+            diagnostic,
+            points,
+        }
+    }
+
+    /// Creates a TAP test point from an `assert` of an `entry`.
+    ///
+    /// Returns `None` for asserts that are always successful (like a wildcard `HTTP` version).
+    fn from_assert(
+        assert: &AssertResult,
+        entry: &EntryResult,
+        filename: &str,
+        content: &str,
+    ) -> Option<TestPoint> {
+        let line = assert.line();
+        let name = match assert {
+            AssertResult::ImplicitVersion { expected, .. } => {
+                if expected == "HTTP" {
+                    return None;
                 }
-                line.split_at(index).1[1..].trim().to_string()
+                format!("HTTP version is {expected}")
             }
+            AssertResult::ImplicitStatus { expected, .. } => format!("status is {expected}"),
+            AssertResult::ImplicitHeader { .. }
+            | AssertResult::ImplicitBody { .. }
+            | AssertResult::Explicit { .. } => content
+                .lines()
+                .nth(line.saturating_sub(1))
+                .map(|l| l.trim().to_string())
+                .unwrap_or_default(),
         };
-        Ok(Testcase {
+        let description = format!("{name} (line {line})");
+
+        let mut diagnostic = vec![];
+        let error = assert.to_runner_error();
+        let success = error.is_none();
+        if let Some(error) = error {
+            diagnostic.push("errors:".to_string());
+            diagnostic.extend(error_diagnostic(
+                &error,
+                "fail",
+                filename,
+                content,
+                entry.source_info,
+            ));
+        }
+        Some(TestPoint {
+            description,
+            success,
+            diagnostic,
+            points: vec![],
+        })
+    }
+
+    /// Creates a TAP test point from a TAP line.
+    pub fn parse(line: &str) -> Result<TestPoint, ReportError> {
+        let (success, description) = parse_test_line(line)?;
+        Ok(TestPoint {
             description,
             success,
+            diagnostic: vec![],
+            points: vec![],
         })
     }
 }
 
+/// Returns the lines of the YAML diagnostic block describing an `error`, with a given `severity`.
+fn error_diagnostic(
+    error: &RunnerError,
+    severity: &str,
+    filename: &str,
+    content: &str,
+    entry_src_info: SourceInfo,
+) -> Vec<String> {
+    let message = error.render(
+        filename,
+        content,
+        Some(entry_src_info),
+        OutputFormat::Terminal(false),
+    );
+    let mut diagnostic = vec![
+        format!("  - severity: {severity}"),
+        "    at:".to_string(),
+        format!("      file: {filename}"),
+        format!("      line: {}", error.source_info.start.line),
+        format!("      column: {}", error.source_info.start.column),
+        "    message: |".to_string(),
+    ];
+    for message_line in message.lines() {
+        diagnostic.push(format!("      {message_line}"));
+    }
+    diagnostic
+}
+
+/// Parses a TAP test line, returning the test status and its description.
+fn parse_test_line(line: &str) -> Result<(bool, String), ReportError> {
+    let mut line = line;
+    let success = if line.starts_with("ok") {
+        line = &line[2..];
+        true
+    } else if line.starts_with("not ok") {
+        line = &line[6..];
+        false
+    } else {
+        return Err(ReportError::from_string(&format!(
+            "Invalid TAP line <{line}> - must start with ok or nok"
+        )));
+    };
+
+    let description = match line.find('-') {
+        None => {
+            return Err(ReportError::from_string(&format!(
+                "Invalid TAP line <{line}> - missing '-' separator"
+            )));
+        }
+        Some(index) => {
+            if line.split_at(index).0.trim().parse::<usize>().is_err() {
+                return Err(ReportError::from_string(&format!(
+                    "Invalid TAP line <{line}> - missing test number"
+                )));
+            }
+            line.split_at(index).1[1..].trim().to_string()
+        }
+    };
+    Ok((success, description))
+}
+
 #[cfg(test)]
 mod tests {
+    use hurl_core::ast::SourceInfo;
+    use hurl_core::reader::Pos;
+    use hurl_core::types::Index;
+
     use super::*;
+    use crate::runner::{RunnerErrorKind, Value};
 
     #[test]
     fn parse_tap_test_line() {
@@ -91,7 +270,120 @@ mod tests {
             Testcase::parse("ok 1 - tests_ok/test.1.hurl").unwrap(),
             Testcase {
                 description: "tests_ok/test.1.hurl".to_string(),
-                success: true
+                success: true,
+                points: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn create_testcase_with_failed_entry() {
+        let content = r#"GET http://localhost:8000/not_found
+HTTP/1.0 200
+[Asserts]
+header "Server" contains "Flask" # server
+"#;
+        let filename = Input::new("test.hurl");
+        let hurl_result = HurlResult {
+            entries: vec![EntryResult {
+                entry_index: Index::new(1),
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 35)),
+                asserts: vec![
+                    AssertResult::ImplicitVersion {
+                        actual: "HTTP/1.0".to_string(),
+                        expected: "HTTP/1.0".to_string(),
+                        source_info: SourceInfo::new(Pos::new(2, 1), Pos::new(2, 9)),
+                    },
+                    AssertResult::ImplicitStatus {
+                        actual: 404,
+                        expected: 200,
+                        source_info: SourceInfo::new(Pos::new(2, 10), Pos::new(2, 13)),
+                    },
+                    AssertResult::Explicit {
+                        actual: Ok(Some(Value::String("Flask".to_string()))),
+                        source_info: SourceInfo::new(Pos::new(4, 17), Pos::new(4, 25)),
+                        predicate_result: Some(Ok(())),
+                    },
+                ],
+                errors: vec![
+                    RunnerError::new(
+                        SourceInfo::new(Pos::new(2, 10), Pos::new(2, 13)),
+                        RunnerErrorKind::AssertStatus {
+                            actual: "404".to_string(),
+                        },
+                        true,
+                    ),
+                    RunnerError::new(
+                        SourceInfo::new(Pos::new(1, 1), Pos::new(1, 4)),
+                        RunnerErrorKind::NoQueryResult,
+                        false,
+                    ),
+                ],
+                ..Default::default()
+            }],
+            success: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            Testcase::from(&hurl_result, content, &filename),
+            Testcase {
+                description: "test.hurl".to_string(),
+                success: false,
+                points: vec![TestPoint {
+                    description: "entry 1 (line 1)".to_string(),
+                    success: false,
+                    diagnostic: vec![
+                        "errors:".to_string(),
+                        "  - severity: error".to_string(),
+                        "    at:".to_string(),
+                        "      file: test.hurl".to_string(),
+                        "      line: 1".to_string(),
+                        "      column: 1".to_string(),
+                        "    message: |".to_string(),
+                        "      No query result".to_string(),
+                        "        --> test.hurl:1:1".to_string(),
+                        "         |".to_string(),
+                        "       1 | GET http://localhost:8000/not_found".to_string(),
+                        "         | ^^^ query didn't return any result".to_string(),
+                        "         |".to_string(),
+                    ],
+                    points: vec![
+                        TestPoint {
+                            description: "HTTP version is HTTP/1.0 (line 2)".to_string(),
+                            success: true,
+                            diagnostic: vec![],
+                            points: vec![],
+                        },
+                        TestPoint {
+                            description: "status is 200 (line 2)".to_string(),
+                            success: false,
+                            diagnostic: vec![
+                                "errors:".to_string(),
+                                "  - severity: fail".to_string(),
+                                "    at:".to_string(),
+                                "      file: test.hurl".to_string(),
+                                "      line: 2".to_string(),
+                                "      column: 10".to_string(),
+                                "    message: |".to_string(),
+                                "      Assert status code".to_string(),
+                                "        --> test.hurl:2:10".to_string(),
+                                "         |".to_string(),
+                                "         | GET http://localhost:8000/not_found".to_string(),
+                                "       2 | HTTP/1.0 200".to_string(),
+                                "         |          ^^^ actual value is <404>".to_string(),
+                                "         |".to_string(),
+                            ],
+                            points: vec![],
+                        },
+                        TestPoint {
+                            description: "header \"Server\" contains \"Flask\" # server (line 4)"
+                                .to_string(),
+                            success: true,
+                            diagnostic: vec![],
+                            points: vec![],
+                        },
+                    ],
+                }],
             }
         );
     }
@@ -17,28 +17,39 @@
  */
 use std::cmp::min;
 use std::path::Path;
+use std::sync::Arc;
+use std::thread;
 
-use hurl::parallel::job::{Job, JobResult};
+use hurl::load::runner::LoadRunner;
+use hurl::load::stats::LoadStats;
+use hurl::parallel::job::{Job, JobResult, ParsedFile};
 use hurl::parallel::runner::ParallelRunner;
 use hurl::pretty::PrettyMode;
-use hurl::runner::{HurlResult, Output, VariableSet};
-use hurl::util::term::{Stdout, WriteMode};
+use hurl::runner::{Event, EventListener, HurlResult, Output, VariableSet};
+use hurl::util::logger::Logger;
+use hurl::util::term::{Stderr, Stdout, WriteMode};
 use hurl::{output, parallel, runner};
 use hurl_core::error::{DisplaySourceError, OutputFormat};
 use hurl_core::input::Input;
+use hurl_core::parser::{self, ParserLimits};
 use hurl_core::types::Count;
 
 use crate::cli::CliError;
-use crate::cli::options::CliOptions;
+use crate::cli::options::{CliOptions, InputOptions};
 use crate::{HurlRun, cli};
 
 /// Runs Hurl `files` sequentially, given a current directory and command-line options (see
-/// [`crate::cli::options::CliOptions`]). This function returns a list of [`HurlRun`] results or
-/// an error.
+/// [`crate::cli::options::CliOptions`]). Each file is run with its own options from
+/// `input_options`, and a [`Event::RunCompleted`] event is published to `listener` as soon as the
+/// file run is completed. If `release_bodies` is `true`, the bodies of the HTTP calls are then
+/// released. This function returns a list of [`HurlRun`] results or an error.
 pub fn run_seq(
     files: &[Input],
     current_dir: &Path,
     options: &CliOptions,
+    input_options: &mut InputOptions,
+    listener: &mut dyn EventListener,
+    release_bodies: bool,
 ) -> Result<Vec<HurlRun>, CliError> {
     let mut runs = vec![];
 
@@ -49,6 +60,10 @@ pub fn run_seq(
     // it on subsequent write.
     let mut append = false;
 
+    // Captures exported by a file (with `export-capture` options) are injected in the variables of
+    // the files run after it.
+    let mut exports = VariableSet::new();
+
     for filename in queue {
         let content = filename.read_to_string();
         let content = match content {
@@ -58,17 +73,38 @@ pub fn run_seq(
                 return Err(error);
             }
         };
-        let mut variables = VariableSet::from(&options.variables);
-        options.secrets.iter().for_each(|(name, value)| {
+        let file_options = input_options.get(&filename)?;
+        let mut variables = VariableSet::from(&file_options.variables);
+        file_options.secrets.iter().for_each(|(name, value)| {
             variables.insert_secret(name.clone(), value.clone());
         });
-        let runner_options = options.to_runner_options(&filename, current_dir)?;
-        let logger_options = options.to_logger_options();
+        variables.extend(&exports);
+        let runner_options = file_options.to_runner_options(&filename, current_dir)?;
+        let logger_options = file_options.to_logger_options();
+
+        // The file is parsed once, to be run and to get its exported captures.
+        let limits = file_options.parser_limits();
+        let hurl_file = match parser::parse_hurl_file_with_limits(&content, limits) {
+            Ok(hurl_file) => hurl_file,
+            Err(error) => {
+                let stderr = Stderr::new(WriteMode::Immediate);
+                let secrets = variables.secrets();
+                let mut logger = Logger::new(&logger_options, stderr, &secrets);
+                let message = error.render(
+                    &filename.to_string(),
+                    &content,
+                    None,
+                    OutputFormat::Terminal(file_options.color_stderr),
+                );
+                logger.error_rich(&message);
+                return Err(CliError::Parsing);
+            }
+        };
 
-        // Run our Hurl file now, we can only fail if there is a parsing error.
-        // The parsing error is displayed in the `execute` call, that's why we gobble the error
-        // string.
-        let Ok(hurl_result) = runner::run(
+        // Run our Hurl file now, we can only fail if included files can't be resolved.
+        // The error is displayed in the `execute` call, that's why we gobble the error string.
+        let Ok(mut hurl_result) = runner::run_hurl_file(
+            &hurl_file,
             &content,
             Some(&filename),
             &runner_options,
@@ -94,6 +130,19 @@ pub fn run_seq(
         )?;
         append = true;
 
+        let names = runner::exported_captures(&hurl_file);
+        exports.copy_from(&hurl_result.variables, &names);
+
+        listener.on_event(&Event::RunCompleted {
+            index: runs.len(),
+            content: &content,
+            filename: &filename,
+            result: &hurl_result,
+        });
+        if release_bodies {
+            hurl_result.release_bodies();
+        }
+
         let run = HurlRun {
             content,
             filename: filename.clone(),
@@ -165,13 +214,18 @@ fn print_output(
 }
 
 /// Runs Hurl `files` in parallel, given a current directory and command-line options (see
-/// [`crate::cli::options::CliOptions`]). This function returns a list of [`HurlRun`] results or
-/// an error.
+/// [`crate::cli::options::CliOptions`]). Each file is run with its own options from
+/// `input_options`, and a [`Event::RunCompleted`] event is published to `listener` as soon as the
+/// file run is completed. If `release_bodies` is `true`, the bodies of the HTTP calls are then
+/// released. This function returns a list of [`HurlRun`] results or an error.
 pub fn run_par(
     files: &[Input],
     current_dir: &Path,
     options: &CliOptions,
+    input_options: &mut InputOptions,
     workers_count: usize,
+    listener: &mut dyn EventListener,
+    release_bodies: bool,
 ) -> Result<Vec<HurlRun>, CliError> {
     // We're going to use the right numbers of workers. We don't need to use more workers than there
     // are input files (repeat option act as if we're dealing with a multiplied number of files)
@@ -180,30 +234,37 @@ pub fn run_par(
         Some(Count::Infinite) => workers_count,
         None => min(files.len(), workers_count),
     };
-    let mut variables = VariableSet::from(&options.variables);
-    options.secrets.iter().for_each(|(name, value)| {
-        variables.insert_secret(name.clone(), value.clone());
-    });
     let output_type =
         options
             .output_type
             .to_output_type(options.include, options.color_stdout, options.pretty);
     let max_width = terminal_size::terminal_size().map(|(w, _)| w.0 as usize);
-    let jobs = files
-        .iter()
-        .enumerate()
-        .map(|(seq, input)| {
-            let runner_options = options.to_runner_options(input, current_dir)?;
-            let logger_options = options.to_logger_options();
-            Ok(Job::new(
-                input,
-                seq,
-                &runner_options,
-                &variables,
-                &logger_options,
-            ))
-        })
-        .collect::<Result<Vec<Job>, CliError>>()?;
+    let mut exports =
+        exported_captures_par(files, options.parser_limits(), workers_count).into_iter();
+    let mut jobs = vec![];
+    for (seq, input) in files.iter().enumerate() {
+        let file_options = input_options.get(input)?;
+        let mut variables = VariableSet::from(&file_options.variables);
+        file_options.secrets.iter().for_each(|(name, value)| {
+            variables.insert_secret(name.clone(), value.clone());
+        });
+        let runner_options = file_options.to_runner_options(input, current_dir)?;
+        let logger_options = file_options.to_logger_options();
+        let (exports, source) = exports.next().unwrap_or_default();
+        let mut job = Job::new(
+            input,
+            seq,
+            &runner_options,
+            &variables,
+            &logger_options,
+            &exports,
+        );
+        job.source = source;
+        jobs.push(job);
+    }
+    // Jobs exporting captures are run first, so their captures can be used by the other jobs
+    // (results are sorted by sequence number, so the order of the results is unchanged).
+    jobs.sort_by_key(|job| job.exports.is_empty());
 
     let mut runner = ParallelRunner::new(
         workers_count,
@@ -214,11 +275,128 @@ pub fn run_par(
         options.color_stderr,
         max_width,
     );
-    let results = runner.run(&jobs)?;
+    let results = runner.run_with(&jobs, &mut |result| {
+        let job = &result.job;
+        listener.on_event(&Event::RunCompleted {
+            index: job.seq,
+            content: &result.content,
+            filename: &job.filename,
+            result: &result.hurl_result,
+        });
+        if release_bodies {
+            result.hurl_result.release_bodies();
+        }
+        Ok(())
+    })?;
     let results = results.into_iter().map(HurlRun::from).collect();
     Ok(results)
 }
 
+/// Returns the captures exported by each of the input `files`, with the parsed file for the files
+/// exporting captures.
+///
+/// With a large number of files, reading files on a single thread can noticeably delay the first
+/// request: files are processed by `workers_count` threads. Files exporting captures are parsed
+/// here with the parser `limits`, and their parsed file is passed to their job so they're not parsed
+/// again by the workers.
+fn exported_captures_par(
+    files: &[Input],
+    limits: ParserLimits,
+    workers_count: usize,
+) -> Vec<ExportedCaptures> {
+    let chunk_size = files.len().div_ceil(workers_count.max(1)).max(1);
+    thread::scope(|s| {
+        let handles = files
+            .chunks(chunk_size)
+            .map(|chunk| {
+                s.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|input| exported_captures(input, limits))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("exported captures thread to succeed"))
+            .collect()
+    })
+}
+
+/// The names of the captures exported by a file, and its parsed file if it exports captures.
+type ExportedCaptures = (Vec<String>, Option<Arc<ParsedFile>>);
+
+/// Returns the captures exported by an `input` file, parsed with the parser `limits`.
+///
+/// Reading and parsing errors are ignored here: they're reported by the workers, when the job is
+/// run.
+fn exported_captures(input: &Input, limits: ParserLimits) -> ExportedCaptures {
+    let Ok(content) = input.read_to_string() else {
+        return (vec![], None);
+    };
+    // Most files don't export any capture: we skip the parsing of these files.
+    if !content.contains("export-capture") {
+        return (vec![], None);
+    }
+    let Ok(hurl_file) = parser::parse_hurl_file_with_limits(&content, limits) else {
+        return (vec![], None);
+    };
+    let names = runner::exported_captures(&hurl_file);
+    if names.is_empty() {
+        return (names, None);
+    }
+    let source = ParsedFile { content, hurl_file };
+    (names, Some(Arc::new(source)))
+}
+
+/// Runs a Hurl file as a load test, given a current directory and command-line options (see
+/// [`crate::cli::options::CliOptions`]). The file is replayed continuously by `--concurrency`
+/// virtual users during `--duration`. This function returns the statistics of the load test or an
+/// error.
+pub fn run_load(
+    files: &[Input],
+    current_dir: &Path,
+    options: &CliOptions,
+    input_options: &mut InputOptions,
+) -> Result<LoadStats, CliError> {
+    let [filename] = files else {
+        let message = "load test mode requires exactly one input file".to_string();
+        return Err(CliError::InvalidOption(message));
+    };
+    let content = match filename.read_to_string() {
+        Ok(c) => c,
+        Err(error) => {
+            let error = CliError::InputRead(format!("Issue reading from {filename}: {error}"));
+            return Err(error);
+        }
+    };
+    let file_options = input_options.get(filename)?;
+    let mut variables = VariableSet::from(&file_options.variables);
+    file_options.secrets.iter().for_each(|(name, value)| {
+        variables.insert_secret(name.clone(), value.clone());
+    });
+    let runner_options = file_options.to_runner_options(filename, current_dir)?;
+    let logger_options = file_options.to_logger_options();
+
+    let runner = LoadRunner::new(
+        options.concurrency.unwrap_or(1),
+        options.throughput,
+        options.duration,
+    );
+    // The parsing error is displayed in the `run` call, that's why we gobble the error string.
+    let Ok(stats) = runner.run(
+        &content,
+        filename,
+        &runner_options,
+        &variables,
+        &logger_options,
+    ) else {
+        return Err(CliError::Parsing);
+    };
+    Ok(stats)
+}
+
 impl From<JobResult> for HurlRun {
     fn from(job_result: JobResult) -> Self {
         HurlRun {
@@ -15,6 +15,8 @@
  * limitations under the License.
  *
  */
+use std::time::Duration;
+
 use hurl_core::ast::{Assert, SourceInfo};
 use hurl_core::reader::Pos;
 
@@ -25,8 +27,9 @@ use super::cache::BodyCache;
 use super::diff::diff;
 use super::error::{RunnerError, RunnerErrorKind};
 use super::filter::{FilterOptions, eval_filters};
-use super::predicate::eval_predicate;
-use super::query::{QueryOptions, eval_query};
+use super::predicate::{PredicateOptions, eval_predicate};
+use super::pretty::{BodyMismatch, DEFAULT_BODY_PRINT_LIMIT, body_mismatch};
+use super::query::{QueryOptions, QueryResult, eval_aggregate_query, eval_query};
 use super::result::AssertResult;
 use super::value::Value;
 use super::variable::VariableSet;
@@ -34,6 +37,16 @@ use super::variable::VariableSet;
 impl AssertResult {
     /// Evaluates an assert and returns `None` if assert is succeeded or an `Error` if failed.
     pub fn to_runner_error(&self) -> Option<RunnerError> {
+        self.to_runner_error_with_limit(DEFAULT_BODY_PRINT_LIMIT)
+    }
+
+    /// Evaluates an assert and returns `None` if assert is succeeded or an `Error` if failed.
+    ///
+    /// Mismatched structured bodies (JSON, XML) are pretty-printed up to `body_print_limit` chars.
+    pub(crate) fn to_runner_error_with_limit(
+        &self,
+        body_print_limit: usize,
+    ) -> Option<RunnerError> {
         match self {
             AssertResult::ImplicitVersion {
                 actual,
@@ -89,6 +102,17 @@ impl AssertResult {
                     Ok(actual) => {
                         if actual == expected {
                             None
+                        } else if let Some(pretty) =
+                            pretty_body_mismatch(expected, actual, body_print_limit)
+                        {
+                            let actual = actual.to_string();
+                            let expected = expected.to_string();
+                            let kind = RunnerErrorKind::AssertBodyValueError {
+                                actual,
+                                expected,
+                                pretty: Some(Box::new(pretty)),
+                            };
+                            Some(RunnerError::new(*source_info, kind, false))
                         } else if use_diff(expected, actual) {
                             let actual = actual.to_string();
                             let expected = expected.to_string();
@@ -110,7 +134,11 @@ impl AssertResult {
                         } else {
                             let actual = actual.to_string();
                             let expected = expected.to_string();
-                            let kind = RunnerErrorKind::AssertBodyValueError { actual, expected };
+                            let kind = RunnerErrorKind::AssertBodyValueError {
+                                actual,
+                                expected,
+                                pretty: None,
+                            };
                             Some(RunnerError::new(*source_info, kind, false))
                         }
                     }
@@ -135,6 +163,25 @@ impl AssertResult {
     }
 }
 
+/// Returns a pretty-printed mismatch if `expected` and `actual` are both structured (JSON, XML) string bodies,
+/// and one of them is on a single line (like a minified JSON).
+///
+/// When both bodies are multiline, a line diff is more relevant and can be mapped to the Hurl source file.
+fn pretty_body_mismatch(
+    expected: &Value,
+    actual: &Value,
+    body_print_limit: usize,
+) -> Option<BodyMismatch> {
+    if let (Value::String(expected), Value::String(actual)) = (expected, actual) {
+        if expected.trim_end().contains('\n') && actual.trim_end().contains('\n') {
+            return None;
+        }
+        body_mismatch(actual, expected, body_print_limit)
+    } else {
+        None
+    }
+}
+
 fn use_diff(expected: &Value, actual: &Value) -> bool {
     if let (Value::String(expected), Value::String(actual)) = (actual, expected) {
         expected.contains('\n') || actual.contains('\n')
@@ -157,7 +204,31 @@ pub fn eval_explicit_assert(
     options: &QueryOptions,
 ) -> AssertResult {
     let query_result = eval_query(&assert.query, variables, http_responses, cache, options);
+    eval_query_result(assert, query_result, variables, context_dir, options)
+}
+
+/// Evaluates an aggregate explicit `assert` (on `durationMean`, `durationPercentile` queries etc...),
+/// given a set of `variables`, the `durations` of all the repetitions of an entry and a context
+/// directory `context_dir`.
+pub fn eval_aggregate_assert(
+    assert: &Assert,
+    variables: &VariableSet,
+    durations: &[Duration],
+    context_dir: &ContextDir,
+    options: &QueryOptions,
+) -> AssertResult {
+    let query_result = eval_aggregate_query(&assert.query, durations);
+    eval_query_result(assert, query_result, variables, context_dir, options)
+}
 
+/// Applies the filters and the predicate of an explicit `assert` to its evaluated `query_result`.
+fn eval_query_result(
+    assert: &Assert,
+    query_result: QueryResult,
+    variables: &VariableSet,
+    context_dir: &ContextDir,
+    options: &QueryOptions,
+) -> AssertResult {
     let actual = if assert.filters.is_empty() {
         query_result
     } else if let Ok(optional_value) = query_result {
@@ -188,6 +259,10 @@ pub fn eval_explicit_assert(
     };
 
     let source_info = assert.predicate.predicate_func.source_info;
+    let options = PredicateOptions {
+        clock_skew: options.clock_skew,
+        json_diff_limit: options.json_diff_limit,
+    };
     let predicate_result = match &actual {
         Err(_) => None,
         Ok(actual) => Some(eval_predicate(
@@ -195,6 +270,7 @@ pub fn eval_explicit_assert(
             variables,
             actual,
             context_dir,
+            &options,
         )),
     };
 
@@ -238,6 +314,7 @@ pub mod tests {
                         3,
                         "3".to_source(),
                     ))),
+                    ignoring: None,
                 },
             },
         };
@@ -279,9 +356,7 @@ pub mod tests {
                 &[&xml_three_users_http_response()],
                 &mut cache,
                 &context_dir,
-                &QueryOptions {
-                    use_jsonpath_coercion: true,
-                },
+                &QueryOptions::default(),
             ),
             AssertResult::Explicit {
                 actual: Ok(Some(Value::Number(Number::Integer(3)))),
@@ -303,4 +378,37 @@ pub mod tests {
             &Value::String("b".to_string())
         ));
     }
+
+    #[test]
+    pub fn test_pretty_body_mismatch() {
+        // Minified actual JSON
+        let mismatch = pretty_body_mismatch(
+            &Value::String("{\n  \"id\": 1\n}".to_string()),
+            &Value::String(r#"{"id":2}"#.to_string()),
+            100,
+        )
+        .unwrap();
+        assert_eq!(mismatch.line, 2);
+        assert_eq!(mismatch.column, 9);
+
+        // Both multiline: a line diff is used
+        assert!(
+            pretty_body_mismatch(
+                &Value::String("{\n  \"id\": 1\n}".to_string()),
+                &Value::String("{\n  \"id\": 2\n}".to_string()),
+                100,
+            )
+            .is_none()
+        );
+
+        // Not structured
+        assert!(
+            pretty_body_mismatch(
+                &Value::String("Hello".to_string()),
+                &Value::String("World".to_string()),
+                100,
+            )
+            .is_none()
+        );
+    }
 }
@@ -0,0 +1,241 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Background execution of Hurl content, for embedders that can't block the calling thread.
+//!
+//! The runner is synchronous: HTTP transfers are blocking libcurl transfers. A background run is
+//! a synchronous run on a dedicated OS thread, whose result can be awaited. It's not a non-blocking
+//! run: each run uses its own thread, so it doesn't scale beyond a thread per run.
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+
+use hurl_core::input::Input;
+
+use super::hurl_file::run;
+use super::result::HurlResult;
+use super::runner_options::RunnerOptions;
+use super::variable::VariableSet;
+use crate::util::cancel::CancellationToken;
+use crate::util::logger::LoggerOptions;
+
+/// Runs a Hurl `content` on a new thread with the synchronous [`crate::runner::run`], and returns
+/// a handle to the run. The handle is a future resolving to the [`HurlResult`] of the run, that
+/// can be awaited in any executor (tokio, async-std etc...) without blocking its threads.
+///
+/// Dropping the handle before the completion of the run cancels the run, through the cancellation
+/// token of `runner_options` (a token is created if there is none). If the run panics, the handle
+/// resolves to an error.
+///
+/// # Example
+///
+/// ```ignore
+/// let result = runner::run_in_background(
+///     content.to_string(),
+///     Some(Input::new("sample.hurl")),
+///     runner_opts,
+///     variables,
+///     logger_opts,
+/// )
+/// .await;
+/// assert!(result.unwrap().success);
+/// ```
+pub fn run_in_background(
+    content: String,
+    filename: Option<Input>,
+    mut runner_options: RunnerOptions,
+    variables: VariableSet,
+    logger_options: LoggerOptions,
+) -> RunHandle {
+    let token = runner_options
+        .cancellation_token
+        .get_or_insert_with(CancellationToken::new)
+        .clone();
+    spawn_run(token, move || {
+        run(
+            &content,
+            filename.as_ref(),
+            &runner_options,
+            &variables,
+            &logger_options,
+        )
+    })
+}
+
+/// Runs `f` on a new thread and returns a handle resolving to its result.
+///
+/// `token` is cancelled when the handle is dropped before completion.
+fn spawn_run<F>(token: CancellationToken, f: F) -> RunHandle
+where
+    F: FnOnce() -> Result<HurlResult, String> + Send + 'static,
+{
+    let state = Arc::new(Mutex::new(RunState::default()));
+    let thread_state = Arc::clone(&state);
+    thread::spawn(move || {
+        // A panic of the run must still complete the handle, or it would be pending forever.
+        let result = panic::catch_unwind(AssertUnwindSafe(f))
+            .unwrap_or_else(|_| Err("The run has panicked".to_string()));
+        let mut state = thread_state.lock().unwrap_or_else(|e| e.into_inner());
+        state.result = Some(result);
+        state.done = true;
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    });
+    RunHandle { state, token }
+}
+
+/// A handle to a Hurl run on a background thread, see [`run_in_background`].
+///
+/// The handle is a future resolving to the result of the run.
+pub struct RunHandle {
+    state: Arc<Mutex<RunState>>,
+    /// Token of the run, cancelled if this handle is dropped before completion.
+    token: CancellationToken,
+}
+
+/// The state shared between a [`RunHandle`] and the thread running the Hurl content.
+#[derive(Default)]
+struct RunState {
+    /// Result of the run, `None` while the run is not completed or once it has been taken.
+    result: Option<Result<HurlResult, String>>,
+    /// `true` once the run is completed.
+    done: bool,
+    /// Waker of the task awaiting the run, notified on completion.
+    waker: Option<Waker>,
+}
+
+impl Future for RunHandle {
+    type Output = Result<HurlResult, String>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl Drop for RunHandle {
+    fn drop(&mut self) {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if !state.done {
+            self.token.cancel();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::task::Wake;
+    use std::thread::Thread;
+
+    use super::*;
+    use crate::runner::RunnerOptionsBuilder;
+    use crate::util::logger::LoggerOptionsBuilder;
+
+    /// A waker unparking the thread awaiting a future.
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    /// Runs a `future` to completion on the current thread.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = std::pin::pin!(future);
+        let waker = Arc::new(ThreadWaker(thread::current())).into();
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_run_in_background() {
+        let result = block_on(run_in_background(
+            "# No entry\n".to_string(),
+            None,
+            RunnerOptionsBuilder::new().build(),
+            VariableSet::new(),
+            LoggerOptionsBuilder::new().build(),
+        ));
+        let result = result.unwrap();
+        assert!(result.success);
+        assert!(result.entries.is_empty());
+
+        let result = block_on(run_in_background(
+            "GET http://localhost:8000\nxxx".to_string(),
+            None,
+            RunnerOptionsBuilder::new().build(),
+            VariableSet::new(),
+            LoggerOptionsBuilder::new().build(),
+        ));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_run_in_background_panic() {
+        let handle = spawn_run(CancellationToken::new(), || panic!("boom"));
+        assert_eq!(block_on(handle).unwrap_err(), "The run has panicked");
+    }
+
+    #[test]
+    fn test_run_in_background_drop_cancels_run() {
+        let token = CancellationToken::new();
+        let options = RunnerOptionsBuilder::new()
+            .cancellation_token(Some(token.clone()))
+            .build();
+        let handle = run_in_background(
+            "GET http://localhost:8000\n[Options]\ndelay: 60s\n".to_string(),
+            None,
+            options,
+            VariableSet::new(),
+            LoggerOptionsBuilder::new().build(),
+        );
+        assert!(!token.is_cancelled());
+        drop(handle);
+        assert!(token.is_cancelled());
+
+        // A completed run is not cancelled.
+        let token = CancellationToken::new();
+        let options = RunnerOptionsBuilder::new()
+            .cancellation_token(Some(token.clone()))
+            .build();
+        let handle = run_in_background(
+            "# No entry\n".to_string(),
+            None,
+            options,
+            VariableSet::new(),
+            LoggerOptionsBuilder::new().build(),
+        );
+        assert!(block_on(handle).unwrap().success);
+        assert!(!token.is_cancelled());
+    }
+}
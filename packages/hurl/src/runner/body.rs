@@ -23,11 +23,15 @@ use crate::http;
 use crate::util::path::ContextDir;
 
 use super::error::{RunnerError, RunnerErrorKind};
+use super::fixture;
 use super::json::eval_json_value;
 use super::multiline::eval_multiline;
 use super::template::eval_template;
 use super::variable::VariableSet;
 
+/// Filename of a file body read from the standard input.
+const STDIN_FILENAME: &str = "-";
+
 pub fn eval_body(
     body: &Body,
     variables: &VariableSet,
@@ -58,8 +62,19 @@ pub fn eval_bytes(
         Bytes::Base64(Base64 { value, .. }) => Ok(http::Body::Binary(value.clone())),
         Bytes::Hex(Hex { value, .. }) => Ok(http::Body::Binary(value.clone())),
         Bytes::File(File { filename, .. }) => {
+            // `file,-;` streams the body from the standard input.
+            if eval_template(filename, variables)? == STDIN_FILENAME {
+                return Ok(http::Body::Stdin);
+            }
             let value = eval_file(filename, variables, context_dir)?;
-            let filename = eval_template(filename, variables)?;
+            let mut filename = eval_template(filename, variables)?;
+            // Fixtures are exposed with their path in the fixtures directory (for instance in
+            // the curl command line), instead of their digest.
+            if let Some(hash) = fixture::parse_reference(&filename)
+                && let Some(fixtures_dir) = context_dir.fixtures_dir()
+            {
+                filename = fixtures_dir.join(hash).to_string_lossy().to_string();
+            }
             Ok(http::Body::File(value, filename))
         }
     }
@@ -71,14 +86,21 @@ pub fn eval_file(
     context_dir: &ContextDir,
 ) -> Result<Vec<u8>, RunnerError> {
     let file = eval_template(filename, variables)?;
+    // Files referenced by their digest (`file,sha256:<hash>;`) are resolved from the fixture store.
+    if let Some(hash) = fixture::parse_reference(&file) {
+        // Missing fixtures are not downloaded in sandbox mode, as it writes to the fixtures store.
+        let download = !context_dir.is_sandboxed();
+        return match fixture::resolve(hash, context_dir.fixtures_dir(), download) {
+            Ok((_, value)) => Ok(value),
+            Err(kind) => Err(RunnerError::new(filename.source_info, kind, false)),
+        };
+    }
     // In order not to leak any private date, we check that the user provided file
     // is a child of the context directory.
     let path = PathBuf::from(file);
-    if !context_dir.is_access_allowed(&path) {
-        let kind = RunnerErrorKind::UnauthorizedFileAccess { path };
-        return Err(RunnerError::new(filename.source_info, kind, false));
-    }
-    let resolved_file = context_dir.resolved_path(&path);
+    let resolved_file = context_dir
+        .read_path(&path)
+        .map_err(|e| RunnerError::new(filename.source_info, e.into(), false))?;
     match std::fs::read(resolved_file) {
         Ok(value) => Ok(value),
         Err(_) => {
@@ -129,6 +151,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_body_stdin() {
+        // file,-;
+        let whitespace = Whitespace {
+            value: String::new(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+
+        let bytes = Bytes::File(File {
+            space0: whitespace.clone(),
+            filename: Template::new(
+                None,
+                vec![TemplateElement::String {
+                    value: "-".to_string(),
+                    source: "-".to_source(),
+                }],
+                SourceInfo::new(Pos::new(1, 6), Pos::new(1, 7)),
+            ),
+            space1: whitespace,
+        });
+
+        let variables = VariableSet::new();
+        let current_dir = Path::new("/home");
+        let file_root = Path::new("");
+        let context_dir = ContextDir::new(current_dir, file_root);
+        assert_eq!(
+            eval_bytes(&bytes, &variables, &context_dir).unwrap(),
+            http::Body::Stdin
+        );
+    }
+
     #[test]
     fn test_body_file_error() {
         // file, data.bin;
@@ -167,4 +220,42 @@ mod tests {
             SourceInfo::new(Pos::new(1, 7), Pos::new(1, 15))
         );
     }
+
+    #[test]
+    fn test_body_fixture_error() {
+        // file,sha256:7f83b1657ff1fc53b92dc18148a1d65dfc2d4b1fa3d677284addd200126d9069;
+        let whitespace = Whitespace {
+            value: String::new(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        let filename = "sha256:7f83b1657ff1fc53b92dc18148a1d65dfc2d4b1fa3d677284addd200126d9069";
+        let bytes = Bytes::File(File {
+            space0: whitespace.clone(),
+            filename: Template::new(
+                None,
+                vec![TemplateElement::String {
+                    value: filename.to_string(),
+                    source: filename.to_source(),
+                }],
+                SourceInfo::new(Pos::new(1, 6), Pos::new(1, 77)),
+            ),
+            space1: whitespace,
+        });
+
+        let variables = VariableSet::new();
+        let context_dir = ContextDir::new(Path::new("/home"), Path::new(""));
+        let error = eval_bytes(&bytes, &variables, &context_dir).err().unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FixtureResolution {
+                hash: "7f83b1657ff1fc53b92dc18148a1d65dfc2d4b1fa3d677284addd200126d9069"
+                    .to_string(),
+                message: "no fixtures directory, check --fixtures-dir option".to_string(),
+            }
+        );
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 6), Pos::new(1, 77))
+        );
+    }
 }
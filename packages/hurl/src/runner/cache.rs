@@ -17,7 +17,7 @@
  */
 use super::xpath;
 
-/// This is a cache to hold parsed structured data (XML/JSON/text), computed from an HTTP response
+/// This is a cache to hold parsed structured data (XML/JSON/CSV/text), computed from an HTTP response
 /// body bytes. This cache lives for a given request, and allows reusing parsed response for
 /// multiple queries of the same type (for instance, two XPath queries will share their XML document
 /// through this cache).
@@ -27,6 +27,8 @@ pub struct BodyCache {
     xml: Option<xpath::Document>,
     /// The parsed JSON body
     json: Option<serde_json::Value>,
+    /// The parsed CSV body, as JSON
+    csv: Option<serde_json::Value>,
 }
 
 impl BodyCache {
@@ -54,6 +56,16 @@ impl BodyCache {
     pub fn set_json(&mut self, json: serde_json::Value) {
         self.json = Some(json);
     }
+
+    /// Returns a reference to a cached CSV response, converted to JSON.
+    pub fn csv(&self) -> Option<&serde_json::Value> {
+        self.csv.as_ref()
+    }
+
+    /// Caches a parsed CSV, converted to JSON.
+    pub fn set_csv(&mut self, csv: serde_json::Value) {
+        self.csv = Some(csv);
+    }
 }
 
 #[cfg(test)]
@@ -15,21 +15,32 @@
  * limitations under the License.
  *
  */
-use hurl_core::ast::{Capture, SourceInfo};
+use hurl_core::ast::{Capture, CaptureFallback, CaptureType, CaptureTypeAnnotation, SourceInfo};
 
 use crate::http;
+use crate::util::path::ContextDir;
 
 use super::cache::BodyCache;
 use super::error::{RunnerError, RunnerErrorKind};
 use super::filter::{FilterOptions, eval_filters};
+use super::number::Number;
+use super::predicate_value::eval_predicate_value;
 use super::query::{QueryOptions, eval_query};
 use super::result::CaptureResult;
 use super::template::eval_template;
+use super::value::Value;
 use super::variable::VariableSet;
 
 /// Evaluates a `capture` with `variables` map and a list of `http_responses`, returns a
 /// [`CaptureResult`] on success or an [`RunnerError`].
 ///
+/// If the capture has a type annotation, the captured value is coerced to this type, or an error
+/// is raised if the coercion is not possible.
+///
+/// If the query or the filters return no value, the capture `fallback` is used: an `optional`
+/// capture returns `None` (the variable is not set) and a `default` capture returns its default
+/// value. Files referenced by a default value are resolved against `context_dir`.
+///
 /// The `cache` is used to store XML / JSON structured response data and avoid redundant parsing
 /// operation on the response.
 pub fn eval_capture(
@@ -37,11 +48,49 @@ pub fn eval_capture(
     variables: &VariableSet,
     http_responses: &[&http::Response],
     cache: &mut BodyCache,
+    context_dir: &ContextDir,
     options: &QueryOptions,
-) -> Result<CaptureResult, RunnerError> {
+) -> Result<Option<CaptureResult>, RunnerError> {
     let name = eval_template(&capture.name, variables)?;
+    let value = match eval_value(capture, variables, http_responses, cache, options) {
+        Ok(value) => value,
+        Err(error)
+            if matches!(
+                error.kind,
+                RunnerErrorKind::NoQueryResult | RunnerErrorKind::NoFilterResult
+            ) =>
+        {
+            match &capture.fallback {
+                None => return Err(error),
+                Some((_, CaptureFallback::Optional)) => return Ok(None),
+                Some((_, CaptureFallback::Default { value, .. })) => {
+                    eval_predicate_value(value, variables, context_dir)?
+                }
+            }
+        }
+        Err(error) => return Err(error),
+    };
+    let value = match &capture.type_annotation {
+        Some((_, annotation)) => eval_type_annotation(value, annotation)?,
+        None => value,
+    };
+
+    Ok(Some(CaptureResult {
+        name: name.clone(),
+        value,
+    }))
+}
+
+/// Evaluates the query and the filters of a `capture`.
+fn eval_value(
+    capture: &Capture,
+    variables: &VariableSet,
+    http_responses: &[&http::Response],
+    cache: &mut BodyCache,
+    options: &QueryOptions,
+) -> Result<Value, RunnerError> {
     let value = eval_query(&capture.query, variables, http_responses, cache, options)?;
-    let value = match value {
+    match value {
         Some(value) => {
             let filters = capture.filters.iter().map(|(_, f)| f).collect::<Vec<_>>();
             match eval_filters(
@@ -60,34 +109,82 @@ pub fn eval_capture(
                     let start = filters.first().unwrap().source_info.start;
                     let end = filters.last().unwrap().source_info.end;
                     let pos = SourceInfo::new(start, end);
-                    return Err(RunnerError::new(
+                    Err(RunnerError::new(
                         pos,
                         RunnerErrorKind::NoFilterResult,
                         false,
-                    ));
+                    ))
                 }
-                Some(v) => v,
+                Some(v) => Ok(v),
             }
         }
-        None => {
-            return Err(RunnerError::new(
-                capture.query.source_info,
-                RunnerErrorKind::NoQueryResult,
-                false,
-            ));
+        None => Err(RunnerError::new(
+            capture.query.source_info,
+            RunnerErrorKind::NoQueryResult,
+            false,
+        )),
+    }
+}
+
+/// Coerces a captured `value` to the type declared by the capture type `annotation`.
+///
+/// Coercions are lossless: a float is coerced to an integer only if it has no fractional part, a
+/// string is coerced to a number or a boolean only if it can be parsed as such.
+fn eval_type_annotation(
+    value: Value,
+    annotation: &CaptureTypeAnnotation,
+) -> Result<Value, RunnerError> {
+    let coerced = match (annotation.value, &value) {
+        (CaptureType::Boolean, Value::Bool(_)) => Some(value.clone()),
+        (CaptureType::Boolean, Value::String(s)) => match s.as_str() {
+            "true" => Some(Value::Bool(true)),
+            "false" => Some(Value::Bool(false)),
+            _ => None,
+        },
+        (CaptureType::Float, Value::Number(Number::Float(_))) => Some(value.clone()),
+        (CaptureType::Float, Value::Number(Number::Integer(i))) => {
+            Some(Value::Number(Number::Float(*i as f64)))
         }
+        (CaptureType::Float, Value::String(s)) => s
+            .parse::<f64>()
+            .ok()
+            .filter(|f| f.is_finite())
+            .map(|f| Value::Number(Number::Float(f))),
+        (CaptureType::Integer, Value::Number(Number::Integer(_) | Number::BigInteger(_))) => {
+            Some(value.clone())
+        }
+        (CaptureType::Integer, Value::Number(Number::Float(f)))
+            if f.fract() == 0.0 && *f >= i64::MIN as f64 && *f < i64::MAX as f64 =>
+        {
+            Some(Value::Number(Number::Integer(*f as i64)))
+        }
+        (CaptureType::Integer, Value::String(s)) => s
+            .parse::<i64>()
+            .ok()
+            .map(|i| Value::Number(Number::Integer(i))),
+        (CaptureType::String, Value::String(_)) => Some(value.clone()),
+        (CaptureType::String, Value::Bool(_) | Value::Date(_) | Value::Number(_)) => {
+            value.render().map(Value::String)
+        }
+        _ => None,
     };
-
-    Ok(CaptureResult {
-        name: name.clone(),
-        value,
-    })
+    match coerced {
+        Some(coerced) => Ok(coerced),
+        None => {
+            let kind = RunnerErrorKind::CaptureTypeCoercion {
+                value: value.repr(),
+                expected: annotation.value.identifier().to_string(),
+            };
+            Err(RunnerError::new(annotation.source_info, kind, false))
+        }
+    }
 }
 
 #[cfg(test)]
 pub mod tests {
     use hurl_core::ast::{
-        LineTerminator, Query, QueryValue, SourceInfo, Template, TemplateElement, Whitespace,
+        LineTerminator, PredicateValue, Query, QueryValue, SourceInfo, Template, TemplateElement,
+        Whitespace,
     };
     use hurl_core::reader::Pos;
     use hurl_core::types::ToSource;
@@ -119,6 +216,8 @@ pub mod tests {
             // xpath count(//user)
             query: query::tests::xpath_count_user_query(),
             filters: vec![],
+            type_annotation: None,
+            fallback: None,
             space3: whitespace.clone(),
             redacted: false,
             line_terminator0: LineTerminator {
@@ -152,6 +251,8 @@ pub mod tests {
             // xpath count(//user)
             query: query::tests::jsonpath_duration(),
             filters: vec![],
+            type_annotation: None,
+            fallback: None,
             space3: whitespace.clone(),
             redacted: false,
             line_terminator0: LineTerminator {
@@ -186,6 +287,8 @@ pub mod tests {
             space2: whitespace.clone(),
 
             query: query::tests::xpath_invalid_query(),
+            type_annotation: None,
+            fallback: None,
             space3: whitespace.clone(),
             redacted: false,
             line_terminator0: LineTerminator {
@@ -200,6 +303,7 @@ pub mod tests {
             &variables,
             &[&http::xml_three_users_http_response()],
             &mut cache,
+            &ContextDir::default(),
             &QueryOptions::default(),
         )
         .err()
@@ -245,6 +349,8 @@ pub mod tests {
                 },
             },
             filters: vec![],
+            type_annotation: None,
+            fallback: None,
             space3: whitespace.clone(),
             redacted: false,
             line_terminator0: LineTerminator {
@@ -266,13 +372,14 @@ pub mod tests {
                 &variables,
                 &[&http::xml_three_users_http_response()],
                 &mut cache,
+                &ContextDir::default(),
                 &QueryOptions::default()
             )
             .unwrap(),
-            CaptureResult {
+            Some(CaptureResult {
                 name: "UserCount".to_string(),
                 value: Value::Number(Number::from(3.0)),
-            }
+            })
         );
 
         assert_eq!(
@@ -281,12 +388,193 @@ pub mod tests {
                 &variables,
                 &[&http::json_http_response()],
                 &mut cache,
+                &ContextDir::default(),
                 &QueryOptions::default()
             )
             .unwrap(),
-            CaptureResult {
+            Some(CaptureResult {
                 name: "duration".to_string(),
                 value: Value::Number(Number::from(1.5)),
+            })
+        );
+    }
+
+    fn missing_header_capture(fallback: Option<CaptureFallback>) -> Capture {
+        let whitespace = Whitespace {
+            value: String::new(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        Capture {
+            line_terminators: vec![],
+            space0: whitespace.clone(),
+            name: Template::new(
+                None,
+                vec![TemplateElement::String {
+                    value: "token".to_string(),
+                    source: "token".to_source(),
+                }],
+                SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            ),
+            space1: whitespace.clone(),
+            space2: whitespace.clone(),
+
+            // header "X-Token"
+            query: Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 17)),
+                value: QueryValue::Header {
+                    space0: whitespace.clone(),
+                    name: Template::new(
+                        Some('"'),
+                        vec![TemplateElement::String {
+                            value: "X-Token".to_string(),
+                            source: "X-Token".to_source(),
+                        }],
+                        SourceInfo::new(Pos::new(1, 8), Pos::new(1, 17)),
+                    ),
+                },
+            },
+            filters: vec![],
+            type_annotation: None,
+            fallback: fallback.map(|f| (whitespace.clone(), f)),
+            space3: whitespace.clone(),
+            redacted: false,
+            line_terminator0: LineTerminator {
+                space0: whitespace.clone(),
+                comment: None,
+                newline: whitespace,
+            },
+        }
+    }
+
+    #[test]
+    fn test_capture_fallback() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let whitespace = Whitespace {
+            value: String::new(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+
+        let error = eval_capture(
+            &missing_header_capture(None),
+            &variables,
+            &[&http::json_http_response()],
+            &mut cache,
+            &ContextDir::default(),
+            &QueryOptions::default(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error.kind, RunnerErrorKind::NoQueryResult);
+
+        assert_eq!(
+            eval_capture(
+                &missing_header_capture(Some(CaptureFallback::Optional)),
+                &variables,
+                &[&http::json_http_response()],
+                &mut cache,
+                &ContextDir::default(),
+                &QueryOptions::default(),
+            )
+            .unwrap(),
+            None
+        );
+
+        let fallback = CaptureFallback::Default {
+            space0: whitespace,
+            value: PredicateValue::Bool(false),
+        };
+        assert_eq!(
+            eval_capture(
+                &missing_header_capture(Some(fallback)),
+                &variables,
+                &[&http::json_http_response()],
+                &mut cache,
+                &ContextDir::default(),
+                &QueryOptions::default(),
+            )
+            .unwrap(),
+            Some(CaptureResult {
+                name: "token".to_string(),
+                value: Value::Bool(false),
+            })
+        );
+    }
+
+    #[test]
+    fn test_type_annotation() {
+        let annotation = |value| CaptureTypeAnnotation {
+            space0: Whitespace {
+                value: " ".to_string(),
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            },
+            value,
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+        };
+
+        assert_eq!(
+            eval_type_annotation(
+                Value::String("42".to_string()),
+                &annotation(CaptureType::Integer)
+            )
+            .unwrap(),
+            Value::Number(Number::Integer(42))
+        );
+        assert_eq!(
+            eval_type_annotation(
+                Value::Number(Number::Float(42.0)),
+                &annotation(CaptureType::Integer)
+            )
+            .unwrap(),
+            Value::Number(Number::Integer(42))
+        );
+        assert_eq!(
+            eval_type_annotation(
+                Value::Number(Number::Integer(1)),
+                &annotation(CaptureType::Float)
+            )
+            .unwrap(),
+            Value::Number(Number::Float(1.0))
+        );
+        assert_eq!(
+            eval_type_annotation(
+                Value::String("true".to_string()),
+                &annotation(CaptureType::Boolean)
+            )
+            .unwrap(),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            eval_type_annotation(
+                Value::Number(Number::Integer(7)),
+                &annotation(CaptureType::String)
+            )
+            .unwrap(),
+            Value::String("7".to_string())
+        );
+
+        let error = eval_type_annotation(
+            Value::Number(Number::Float(1.5)),
+            &annotation(CaptureType::Integer),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::CaptureTypeCoercion {
+                value: "float <1.5>".to_string(),
+                expected: "integer".to_string(),
+            }
+        );
+        let error = eval_type_annotation(
+            Value::String("abc".to_string()),
+            &annotation(CaptureType::Integer),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::CaptureTypeCoercion {
+                value: "string <abc>".to_string(),
+                expected: "integer".to_string(),
             }
         );
     }
@@ -0,0 +1,163 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use serde_json::{Map, Value};
+
+/// Parses a CSV `text` to a list of records, each record being a list of fields.
+///
+/// Parsing follows [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180): fields are separated by
+/// commas, records by CRLF or LF, and fields can be enclosed in double quotes (a double quote inside
+/// a quoted field is escaped by another double quote). A trailing line break is ignored.
+pub fn parse(text: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut records = vec![];
+    let mut record = vec![];
+    let mut field = String::new();
+    let mut chars = text.chars().peekable();
+    // `true` when the current record has at least one character, so that an empty line at the end
+    // of the text doesn't produce an empty record.
+    let mut in_record = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if field.is_empty() => {
+                in_record = true;
+                loop {
+                    match chars.next() {
+                        Some('"') if chars.peek() == Some(&'"') => {
+                            chars.next();
+                            field.push('"');
+                        }
+                        Some('"') => break,
+                        Some(c) => field.push(c),
+                        None => return Err("unterminated quoted field".to_string()),
+                    }
+                }
+                match chars.peek() {
+                    None | Some(',') | Some('\r') | Some('\n') => {}
+                    Some(c) => {
+                        return Err(format!("unexpected character <{c}> after quoted field"));
+                    }
+                }
+            }
+            ',' => {
+                in_record = true;
+                record.push(std::mem::take(&mut field));
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                if in_record {
+                    record.push(std::mem::take(&mut field));
+                    records.push(std::mem::take(&mut record));
+                }
+                in_record = false;
+            }
+            c => {
+                in_record = true;
+                field.push(c);
+            }
+        }
+    }
+    if in_record {
+        record.push(field);
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// Converts CSV `records` to JSON.
+///
+/// If `header` is `true`, the first record is used as field names and each following record is
+/// converted to an object, otherwise each record is converted to an array of strings.
+pub fn to_json(records: &[Vec<String>], header: bool) -> Value {
+    let to_array = |record: &Vec<String>| {
+        Value::Array(record.iter().map(|f| Value::String(f.clone())).collect())
+    };
+    if !header {
+        return Value::Array(records.iter().map(to_array).collect());
+    }
+    let Some((names, records)) = records.split_first() else {
+        return Value::Array(vec![]);
+    };
+    let objects = records
+        .iter()
+        .map(|record| {
+            let mut object = Map::new();
+            for (name, field) in names.iter().zip(record.iter()) {
+                object.insert(name.clone(), Value::String(field.clone()));
+            }
+            Value::Object(object)
+        })
+        .collect();
+    Value::Array(objects)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_csv() {
+        assert_eq!(parse("").unwrap(), Vec::<Vec<String>>::new());
+        assert_eq!(
+            parse("name,price\nbanana,10.5\r\napple,2\n").unwrap(),
+            vec![
+                vec!["name".to_string(), "price".to_string()],
+                vec!["banana".to_string(), "10.5".to_string()],
+                vec!["apple".to_string(), "2".to_string()],
+            ]
+        );
+        assert_eq!(
+            parse("a,,\"b,\"\"c\"\"\"\nd\n\"multi\nline\",e").unwrap(),
+            vec![
+                vec!["a".to_string(), String::new(), "b,\"c\"".to_string()],
+                vec!["d".to_string()],
+                vec!["multi\nline".to_string(), "e".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_invalid_csv() {
+        assert_eq!(
+            parse("a,\"b").unwrap_err(),
+            "unterminated quoted field".to_string()
+        );
+        assert_eq!(
+            parse("a,\"b\"c").unwrap_err(),
+            "unexpected character <c> after quoted field".to_string()
+        );
+    }
+
+    #[test]
+    fn csv_to_json() {
+        let records = parse("name,price\nbanana,10.5\napple,2").unwrap();
+        assert_eq!(
+            to_json(&records, true),
+            json!([
+                {"name": "banana", "price": "10.5"},
+                {"name": "apple", "price": "2"},
+            ])
+        );
+        assert_eq!(
+            to_json(&records, false),
+            json!([["name", "price"], ["banana", "10.5"], ["apple", "2"]])
+        );
+        assert_eq!(to_json(&[], true), json!([]));
+    }
+}
@@ -0,0 +1,140 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! File-level defaults, declared in a `[Defaults]` section at the top of a Hurl file.
+use hurl_core::ast::{Entry, HurlFile, SectionValue};
+
+/// Returns the entries of `hurl_file`, with the file-level defaults applied to each entry.
+///
+/// Default headers are added to each request, unless the request already defines a header with
+/// the same name. Default options are evaluated before the request options, so an option
+/// explicitly set on a request overrides its default value.
+pub fn entries_with_defaults(hurl_file: &HurlFile) -> Vec<Entry> {
+    let Some(defaults) = &hurl_file.defaults else {
+        return hurl_file.entries.clone();
+    };
+    let mut entries = hurl_file.entries.clone();
+    for entry in entries.iter_mut() {
+        let request = &mut entry.request;
+
+        let mut headers = defaults
+            .headers
+            .iter()
+            .filter(|d| {
+                let name = d.key.to_string();
+                !request
+                    .headers
+                    .iter()
+                    .any(|h| h.key.to_string().eq_ignore_ascii_case(&name))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        headers.append(&mut request.headers);
+        request.headers = headers;
+
+        for section in defaults.sections.iter() {
+            let SectionValue::Options(default_options) = &section.value else {
+                continue;
+            };
+            let options = request
+                .sections
+                .iter_mut()
+                .find_map(|s| match &mut s.value {
+                    SectionValue::Options(options) => Some(options),
+                    _ => None,
+                });
+            match options {
+                Some(options) => {
+                    let mut merged = default_options.clone();
+                    merged.append(options);
+                    *options = merged;
+                }
+                None => request.sections.insert(0, section.clone()),
+            }
+        }
+    }
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::parser::parse_hurl_file;
+
+    use super::*;
+
+    #[test]
+    fn test_entries_without_defaults() {
+        let hurl_file = parse_hurl_file("GET http://localhost:8000/a\n").unwrap();
+        assert_eq!(entries_with_defaults(&hurl_file), hurl_file.entries);
+    }
+
+    #[test]
+    fn test_entries_with_defaults() {
+        let content = r#"[Defaults]
+Accept: application/json
+User-Agent: hurl-test
+[Options]
+location: true
+retry: 2
+
+GET http://localhost:8000/a
+
+GET http://localhost:8000/b
+user-agent: custom
+[Options]
+retry: 5
+"#;
+        let hurl_file = parse_hurl_file(content).unwrap();
+        let entries = entries_with_defaults(&hurl_file);
+        assert_eq!(entries.len(), 2);
+
+        let headers = entries[0]
+            .request
+            .headers
+            .iter()
+            .map(|h| (h.key.to_string(), h.value.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            headers,
+            vec![
+                ("Accept".to_string(), "application/json".to_string()),
+                ("User-Agent".to_string(), "hurl-test".to_string()),
+            ]
+        );
+        let options = entries[0].request.options();
+        assert_eq!(options.len(), 2);
+
+        let headers = entries[1]
+            .request
+            .headers
+            .iter()
+            .map(|h| (h.key.to_string(), h.value.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            headers,
+            vec![
+                ("Accept".to_string(), "application/json".to_string()),
+                ("user-agent".to_string(), "custom".to_string()),
+            ]
+        );
+        // Request options come after the default options, so they override them.
+        let options = entries[1].request.options();
+        assert_eq!(options.len(), 3);
+        assert_eq!(options[1].kind.to_string(), "retry: 2");
+        assert_eq!(options[2].kind.to_string(), "retry: 5");
+    }
+}
@@ -15,23 +15,33 @@
  * limitations under the License.
  *
  */
+use std::time::Duration;
+
+use base64::Engine;
+use base64::engine::general_purpose;
 use hurl_core::ast::{
-    Assert, Capture, Entry, FilterValue, PredicateFuncValue, Response, SourceInfo,
+    Assert, Capture, Entry, FilterValue, OptionKind, PredicateFuncValue, Response, SourceInfo,
 };
 use hurl_core::types::Index;
 
 use crate::http;
-use crate::http::{ClientOptions, CurlCmd};
+use crate::http::{AUTHORIZATION, ClientOptions, CurlCmd, Header};
 use crate::util::logger::{Logger, Verbosity};
+use crate::util::path::ContextDir;
 use crate::util::term::WriteMode;
 
 use super::cache::BodyCache;
 use super::error::{RunnerError, RunnerErrorKind};
+use super::event::{self, EventListener};
+use super::form;
+use super::oauth2::{self, ClientCredentials, TokenCache};
+use super::predicate_value::eval_predicate_value;
 use super::query::QueryOptions;
 use super::request;
 use super::response;
 use super::result::{AssertResult, CaptureResult, EntryResult};
 use super::runner_options::RunnerOptions;
+use super::value::Value;
 use super::variable::VariableSet;
 
 /// Runs an `entry` with `http_client` and returns one [`EntryResult`].
@@ -40,12 +50,18 @@ use super::variable::VariableSet;
 /// been executed. If `http_client` has been configured to follow redirection, the `calls` list contains
 /// every step of the redirection for the first to the last.
 /// `variables` are used to render values at runtime, and can be updated by captures.
+/// `tokens` holds the OAuth 2.0 access tokens already obtained during this run.
+/// Completed HTTP calls and evaluated asserts are published to `listener` as soon as they're
+/// available, before the entry run is completed.
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     entry: &Entry,
     entry_index: Index,
     http_client: &mut http::Client,
     variables: &mut VariableSet,
+    tokens: &mut TokenCache,
     runner_options: &RunnerOptions,
+    mut listener: Option<&mut (dyn EventListener + '_)>,
     logger: &mut Logger,
 ) -> EntryResult {
     let compressed = runner_options.compressed;
@@ -54,10 +70,12 @@ pub fn run(
 
     // We don't allow creating secrets if the logger is immediate and verbose because, in this case,
     // network logs have already been written and may have leaked secrets before captures evaluation.
-    // Note: in `--test` mode, the logger is buffered so there is no restriction on logger level.
+    // Note: in `--test` mode, the logger is buffered so there is no restriction on logger level. The
+    // same applies to verbose transcripts, that are redacted before being written.
     if let Some(response_spec) = &entry.response {
-        let immediate_logs =
-            matches!(logger.stderr.mode(), WriteMode::Immediate) && logger.verbosity.is_some();
+        let immediate_logs = matches!(logger.stderr.mode(), WriteMode::Immediate)
+            && logger.verbosity.is_some()
+            && !logger.has_transcript();
         if immediate_logs {
             let redacted = response_spec.captures().iter().find(|c| c.redacted);
             if let Some(redacted) = redacted {
@@ -76,7 +94,7 @@ pub fn run(
     }
 
     // Evaluates our source requests given our set of variables
-    let http_request = match request::eval_request(&entry.request, variables, context_dir) {
+    let mut http_request = match request::eval_request(&entry.request, variables, context_dir) {
         Ok(r) => r,
         Err(error) => {
             return EntryResult {
@@ -89,8 +107,66 @@ pub fn run(
         }
     };
 
+    if runner_options.nested_form_params {
+        http_request.form = form::encode_form_names(&http_request.form);
+    }
+
+    // With an explicit boundary, the multipart form data is encoded by Hurl and not by libcurl
+    // (which always generates a random boundary).
+    if let Some(boundary) = &runner_options.multipart_boundary
+        && !http_request.multipart.is_empty()
+    {
+        let params = std::mem::take(&mut http_request.multipart);
+        let data = http::encode_multipart(&params, boundary);
+        http_request.body = http::Body::Binary(data);
+        http_request.implicit_content_type =
+            Some(format!("multipart/form-data; boundary={boundary}"));
+    }
+
     let client_options = ClientOptions::from(runner_options, logger.verbosity);
 
+    // Credentials are sent Base64 encoded to the server and to the proxy: if they contain a secret,
+    // the encoded credentials must be redacted from the logs and the reports.
+    add_credentials_secrets(&client_options, variables);
+    add_constant_time_equal_secrets(entry, variables, context_dir);
+    logger.set_secrets(variables.secrets());
+
+    // With OAuth 2.0 client credentials, we add a bearer token to the request (unless the request
+    // has already an explicit `Authorization` header).
+    let credentials = match ClientCredentials::from(runner_options) {
+        Ok(credentials) => credentials,
+        Err(kind) => {
+            let error = RunnerError::new(get_oauth2_source_info(entry), kind, false);
+            return EntryResult {
+                entry_index,
+                source_info,
+                errors: vec![error],
+                compressed,
+                ..Default::default()
+            };
+        }
+    };
+    if let Some(credentials) = &credentials
+        && !http_request.headers.contains_key(AUTHORIZATION)
+    {
+        match oauth2::get_access_token(credentials, tokens, http_client, &client_options, logger) {
+            Ok(token) => {
+                let header = Header::new(AUTHORIZATION, &format!("Bearer {token}"));
+                http_request.headers.push(header);
+            }
+            Err(kind) => {
+                let error = RunnerError::new(get_oauth2_source_info(entry), kind, false);
+                return EntryResult {
+                    entry_index,
+                    source_info,
+                    errors: vec![error],
+                    compressed,
+                    ..Default::default()
+                };
+            }
+        }
+    }
+
     // Experimental features with cookie storage
     if let Some(s) = request::get_cmd_cookie_storage_set(&entry.request) {
         if let Ok(cookie) = http::Cookie::from_netscape(&s) {
@@ -133,10 +209,22 @@ pub fn run(
         }
     };
 
+    event::publish_calls(listener.as_deref_mut(), entry_index, &calls);
+
     // Now, we can compute capture and asserts on the last HTTP request/response chains.
     let responses = calls.iter().map(|c| &c.response).collect::<Vec<_>>();
     let http_response = responses.last().unwrap();
 
+    // If the access token has been rejected, we remove it from the cache so a new token is
+    // requested when the entry is retried.
+    if let Some(credentials) = &credentials
+        && runner_options.retry.is_some()
+        && http_response.status == 401
+    {
+        logger.debug("OAuth 2.0 access token has been rejected, a new one will be requested");
+        tokens.invalidate(&credentials.token_url);
+    }
+
     // `transfer_duration` represent the network time of calls, not including assert processing.
     let transfer_duration = calls.iter().map(|call| call.timings.total).sum();
 
@@ -144,10 +232,15 @@ pub fn run(
     // 1. first, check implicit assert on status and version. If KO, test is failed
     // 2. then, we compute captures, we might need them in asserts
     // 3. finally, run the remaining asserts
+    // With soft asserts, a failure at steps 1 or 2 doesn't stop the evaluation: all the asserts
+    // are run, and their errors are reported together.
     let mut cache = BodyCache::new();
     let mut asserts = vec![];
+    let mut capture_errors = vec![];
     let query_options = QueryOptions {
         use_jsonpath_coercion: runner_options.use_jsonpath_coercion,
+        clock_skew: runner_options.clock_skew,
+        json_diff_limit: runner_options.json_diff_limit,
     };
 
     if !runner_options.no_assert
@@ -155,9 +248,10 @@ pub fn run(
     {
         let mut status_asserts =
             response::eval_version_status_asserts(response_spec, http_response);
-        let errors = asserts_to_errors(&status_asserts);
+        event::publish_asserts(listener.as_deref_mut(), entry_index, &status_asserts);
+        let errors = asserts_to_errors(&status_asserts, runner_options.body_print_limit);
         asserts.append(&mut status_asserts);
-        if !errors.is_empty() {
+        if !errors.is_empty() && !runner_options.soft_asserts {
             logger.debug("");
             return EntryResult {
                 entry_index,
@@ -169,6 +263,7 @@ pub fn run(
                 transfer_duration,
                 compressed,
                 curl_cmd,
+                source_file: None,
             };
         }
     };
@@ -181,9 +276,15 @@ pub fn run(
                 &responses,
                 &mut cache,
                 variables,
+                context_dir,
                 &query_options,
+                runner_options.retry_on_capture_failure,
             ) {
                 Ok(captures) => captures,
+                Err(e) if runner_options.soft_asserts => {
+                    capture_errors.push(e);
+                    vec![]
+                }
                 Err(e) => {
                     return EntryResult {
                         entry_index,
@@ -195,6 +296,7 @@ pub fn run(
                         transfer_duration,
                         compressed,
                         curl_cmd,
+                        source_file: None,
                     };
                 }
             }
@@ -203,6 +305,7 @@ pub fn run(
 
     // After captures evaluation, we update the logger with secrets from the variable set. The variable
     // set can have been updated with new secrets to redact.
+    add_constant_time_equal_secrets(entry, variables, context_dir);
     logger.set_secrets(variables.secrets());
 
     log_captures(&captures, logger);
@@ -221,10 +324,16 @@ pub fn run(
             context_dir,
             &query_options,
         );
+        event::publish_asserts(listener, entry_index, &other_asserts);
         asserts.append(&mut other_asserts);
     };
 
-    let errors = asserts_to_errors(&asserts);
+    let mut errors = asserts_to_errors(&asserts, runner_options.body_print_limit);
+    if !capture_errors.is_empty() {
+        // Errors are reported in the order of the file: captures come before explicit asserts.
+        errors.append(&mut capture_errors);
+        errors.sort_by_key(|e| (e.source_info.start.line, e.source_info.start.column));
+    }
 
     EntryResult {
         entry_index,
@@ -236,14 +345,109 @@ pub fn run(
         transfer_duration,
         compressed,
         curl_cmd,
+        source_file: None,
+    }
+}
+
+/// Evaluates the aggregate asserts of an `entry` (asserts on `durationMean`, `durationPercentile`
+/// queries etc...) on the `durations` of the responses of all its repetitions.
+///
+/// Assert results and errors are appended to `result`, the entry result of the last repetition, and
+/// the new errors are returned.
+pub fn run_aggregate_asserts(
+    entry: &Entry,
+    durations: &[Duration],
+    variables: &VariableSet,
+    runner_options: &RunnerOptions,
+    result: &mut EntryResult,
+) -> Vec<RunnerError> {
+    let Some(response_spec) = &entry.response else {
+        return vec![];
+    };
+    if runner_options.no_assert {
+        return vec![];
     }
+    let query_options = QueryOptions {
+        use_jsonpath_coercion: runner_options.use_jsonpath_coercion,
+        clock_skew: runner_options.clock_skew,
+        json_diff_limit: runner_options.json_diff_limit,
+    };
+    let mut asserts = response::eval_aggregate_asserts(
+        response_spec,
+        variables,
+        durations,
+        &runner_options.context_dir,
+        &query_options,
+    );
+    let errors = asserts_to_errors(&asserts, runner_options.body_print_limit);
+    result.asserts.append(&mut asserts);
+    result.errors.extend(errors.iter().cloned());
+    errors
+}
+
+/// Adds to the `variables` secrets the Base64 encoded credentials of the `user` and `proxy-user`
+/// options, if these credentials contain a secret.
+fn add_credentials_secrets(options: &ClientOptions, variables: &mut VariableSet) {
+    let secrets = variables.secrets();
+    for user in [&options.user, &options.proxy_user].into_iter().flatten() {
+        if secrets
+            .iter()
+            .any(|s| !s.is_empty() && user.contains(s.as_str()))
+        {
+            let encoded = general_purpose::STANDARD.encode(user.as_bytes());
+            variables.add_secret_value(encoded);
+        }
+    }
+}
+
+/// Adds to the `variables` secrets the expected values of the `constantTimeEquals` predicates of
+/// this `entry`, as these values are compared to secrets.
+///
+/// Expected values that can't be evaluated yet (using a capture of this entry for instance) are
+/// ignored, this function being called again once the captures are evaluated.
+fn add_constant_time_equal_secrets(
+    entry: &Entry,
+    variables: &mut VariableSet,
+    context_dir: &ContextDir,
+) {
+    let Some(response) = &entry.response else {
+        return;
+    };
+    for assert in response.asserts() {
+        let PredicateFuncValue::ConstantTimeEqual { value, .. } =
+            &assert.predicate.predicate_func.value
+        else {
+            continue;
+        };
+        if let Ok(Value::String(secret)) = eval_predicate_value(value, variables, context_dir)
+            && !secret.is_empty()
+        {
+            variables.add_secret_value(secret);
+        }
+    }
+}
+
+/// Returns the source info of the `oauth2-token-url` option of this `entry`, or the request URL
+/// source info if the option has been set globally.
+fn get_oauth2_source_info(entry: &Entry) -> SourceInfo {
+    entry
+        .request
+        .options()
+        .iter()
+        .find_map(|option| match &option.kind {
+            OptionKind::OAuth2TokenUrl(url) => Some(url.source_info),
+            _ => None,
+        })
+        .unwrap_or(entry.request.url.source_info)
 }
 
 /// Converts a list of [`AssertResult`] to a list of [`RunnerError`].
-fn asserts_to_errors(asserts: &[AssertResult]) -> Vec<RunnerError> {
+///
+/// Mismatched structured bodies are pretty-printed up to `body_print_limit` chars.
+fn asserts_to_errors(asserts: &[AssertResult], body_print_limit: usize) -> Vec<RunnerError> {
     asserts
         .iter()
-        .filter_map(|assert| assert.to_runner_error())
+        .filter_map(|assert| assert.to_runner_error_with_limit(body_print_limit))
         .map(
             |RunnerError {
                  source_info,
@@ -260,16 +464,25 @@ impl ClientOptions {
             allow_reuse: runner_options.allow_reuse,
             aws_sigv4: runner_options.aws_sigv4.clone(),
             cacert_file: runner_options.cacert_file.clone(),
+            cancellation_token: runner_options.cancellation_token.clone(),
+            chunked: runner_options.chunked,
             client_cert_file: runner_options.client_cert_file.clone(),
+            client_cert_password: runner_options.client_cert_password.clone(),
+            client_cert_type: runner_options.client_cert_type,
             client_key_file: runner_options.client_key_file.clone(),
             compressed: runner_options.compressed,
             connect_timeout: runner_options.connect_timeout,
             connects_to: runner_options.connects_to.clone(),
             cookie_input_file: runner_options.cookie_input_file.clone(),
             digest: runner_options.digest,
+            dns_servers: runner_options.dns_servers.clone(),
+            expect_continue: runner_options.expect_continue,
             follow_location: runner_options.follow_location,
             headers: runner_options.headers.clone(),
+            hmac_signature: runner_options.hmac_signature.clone(),
+            hsts_file: runner_options.hsts_file.clone(),
             http_version: runner_options.http_version,
+            http3_fallback: runner_options.http3_fallback,
             ip_resolve: runner_options.ip_resolve,
             max_filesize: runner_options.max_filesize,
             max_recv_speed: runner_options.max_recv_speed,
@@ -285,11 +498,13 @@ impl ClientOptions {
             pinned_pub_key: runner_options.pinned_pub_key.clone(),
             proxy: runner_options.proxy.clone(),
             proxy_headers: runner_options.proxy_headers.clone(),
+            proxy_user: runner_options.proxy_user.clone(),
             no_proxy: runner_options.no_proxy.clone(),
             insecure: runner_options.insecure,
             resolves: runner_options.resolves.clone(),
             ssl_no_revoke: runner_options.ssl_no_revoke,
             timeout: runner_options.timeout,
+            trailers: runner_options.trailers.clone(),
             unix_socket: runner_options.unix_socket.clone(),
             use_cookie_store: runner_options.use_cookie_store,
             user: runner_options.user.clone(),
@@ -320,34 +535,7 @@ fn log_request(
 
     logger.debug("");
     logger.debug_important("Request:");
-    logger.debug(&format!("{} {}", request.method, request.url.raw()));
-    for header in &request.headers {
-        logger.debug(&header.to_string());
-    }
-    if !request.querystring.is_empty() {
-        logger.debug("[Query]");
-        for param in &request.querystring {
-            logger.debug(&param.to_string());
-        }
-    }
-    if !request.form.is_empty() {
-        logger.debug("[Form]");
-        for param in &request.form {
-            logger.debug(&param.to_string());
-        }
-    }
-    if !request.multipart.is_empty() {
-        logger.debug("[Multipart]");
-        for param in &request.multipart {
-            logger.debug(&param.to_string());
-        }
-    }
-    if !request.cookies.is_empty() {
-        logger.debug("[Cookies]");
-        for cookie in &request.cookies {
-            logger.debug(&cookie.to_string());
-        }
-    }
+    logger.debug_hurl(&request.hurl_lines());
     logger.debug("");
     logger.debug("Request can be run with the following curl command:");
     logger.debug(&curl_cmd.to_string());
@@ -24,8 +24,11 @@ use hurl_core::error::DisplaySourceError;
 use hurl_core::text::{Style, StyledString};
 
 use crate::http::HttpError;
+use crate::util::path::AccessError;
 
 use super::diff::DiffHunk;
+use super::json_diff::JsonDiff;
+use super::pretty::BodyMismatch;
 
 /// Represents a single instance of a runtime error, usually triggered by running a
 /// [`hurl_core::ast::Entry`]. Running a Hurl content (see [`crate::runner::run`]) returns a list of
@@ -57,11 +60,15 @@ pub enum RunnerErrorKind {
     AssertBodyValueError {
         actual: String,
         expected: String,
+        /// Pretty-printed actual and expected bodies, for structured (JSON, XML) bodies.
+        pretty: Option<Box<BodyMismatch>>,
     },
     AssertFailure {
         actual: String,
         expected: String,
         type_mismatch: bool,
+        /// Differences between actual and expected values, for a failed JSON equality.
+        json_diff: Option<Box<JsonDiff>>,
     },
     AssertHeaderValueError {
         actual: String,
@@ -74,6 +81,17 @@ pub enum RunnerErrorKind {
     },
     /// The user tries to output binaries data to standard output.
     BinaryOutput,
+    /// The capture `name` has a `null` value, see `--retry-on-capture-failure` option.
+    CaptureNullValue {
+        name: String,
+    },
+    /// A captured `value` can not be coerced to the `expected` type of the capture type annotation.
+    CaptureTypeCoercion {
+        value: String,
+        expected: String,
+    },
+    /// An arithmetic operation of an expression can not be computed, with a given reason.
+    ExpressionArithmetic(String),
     ExpressionInvalidType {
         value: String,
         expecting: String,
@@ -87,6 +105,11 @@ pub enum RunnerErrorKind {
         path: PathBuf,
         error: String,
     },
+    /// The fixture of SHA-256 digest `hash` can not be resolved from the fixtures directory.
+    FixtureResolution {
+        hash: String,
+        message: String,
+    },
     FilterDecode(String),
     FilterDateParsingError {
         date: String,
@@ -101,8 +124,27 @@ pub enum RunnerErrorKind {
         expected: String,
     },
     FilterInvalidFormatSpecifier(String),
+    FilterInvalidJqExpression {
+        value: String,
+    },
+    /// A jq expression evaluation raised an error, with a given message.
+    FilterJqEval(String),
     FilterMissingInput,
+    /// The call to the function `name` failed, with a given message.
+    FunctionCall {
+        name: String,
+        message: String,
+    },
+    /// The function `name` is neither a builtin nor a custom function.
+    FunctionNotDefined {
+        name: String,
+    },
     Http(HttpError),
+    /// The Hurl file `path` can not be included, with a given message.
+    Include {
+        path: PathBuf,
+        message: String,
+    },
     InvalidJson {
         value: String,
     },
@@ -122,13 +164,31 @@ pub enum RunnerErrorKind {
     NoFilterResult,
     /// A query on response doesn't return value.
     NoQueryResult,
+    /// An OAuth 2.0 access token can not be obtained from the token endpoint `url`.
+    OAuth2Token {
+        url: String,
+        message: String,
+    },
     PossibleLoggedSecret,
     QueryHeaderNotFound,
+    QueryInvalidCsv {
+        message: String,
+    },
     QueryInvalidJsonpathExpression {
         value: String,
     },
     QueryInvalidXml,
     QueryInvalidJson,
+    /// An operation denied in sandbox mode, see `--sandbox` option.
+    SandboxViolation {
+        message: String,
+    },
+    /// The response body doesn't match the snapshot `path`, with a textual `diff`, see
+    /// `--snapshot-dir` option.
+    SnapshotMismatch {
+        path: PathBuf,
+        diff: String,
+    },
     TemplateVariableNotDefined {
         name: String,
     },
@@ -143,6 +203,23 @@ pub enum RunnerErrorKind {
     },
 }
 
+impl From<AccessError> for RunnerErrorKind {
+    fn from(error: AccessError) -> Self {
+        match error {
+            AccessError::OutsideFileRoot(path) => RunnerErrorKind::UnauthorizedFileAccess { path },
+            AccessError::SandboxRead(path) => RunnerErrorKind::SandboxViolation {
+                message: format!("reading file {}", path.display()),
+            },
+            AccessError::SandboxWrite(path) => RunnerErrorKind::SandboxViolation {
+                message: format!("writing file {}", path.display()),
+            },
+            AccessError::SandboxScheme(url) => RunnerErrorKind::SandboxViolation {
+                message: format!("connecting to {url}"),
+            },
+        }
+    }
+}
+
 /// Textual Output for runner errors
 impl DisplaySourceError for RunnerError {
     fn source_info(&self) -> SourceInfo {
@@ -158,17 +235,30 @@ impl DisplaySourceError for RunnerError {
             RunnerErrorKind::AssertStatus { .. } => "Assert status code".to_string(),
             RunnerErrorKind::AssertVersion { .. } => "Assert HTTP version".to_string(),
             RunnerErrorKind::BinaryOutput => "Binary output".to_string(),
+            RunnerErrorKind::CaptureNullValue { .. } => "Null capture".to_string(),
+            RunnerErrorKind::CaptureTypeCoercion { .. } => "Invalid capture type".to_string(),
+            RunnerErrorKind::ExpressionArithmetic { .. } => {
+                "Invalid arithmetic operation".to_string()
+            }
             RunnerErrorKind::ExpressionInvalidType { .. } => "Invalid expression type".to_string(),
             RunnerErrorKind::FileReadAccess { .. } => "File read access".to_string(),
             RunnerErrorKind::FileWriteAccess { .. } => "File write access".to_string(),
+            RunnerErrorKind::FixtureResolution { .. } => "Fixture resolution".to_string(),
             RunnerErrorKind::FilterDateParsingError { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterDecode { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterInvalidEncoding { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterInvalidInputValue { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterInvalidInputType { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterInvalidFormatSpecifier { .. } => "Filter error".to_string(),
+            RunnerErrorKind::FilterInvalidJqExpression { .. } => {
+                "Invalid jq expression".to_string()
+            }
+            RunnerErrorKind::FilterJqEval(_) => "Filter error".to_string(),
             RunnerErrorKind::FilterMissingInput => "Filter error".to_string(),
+            RunnerErrorKind::FunctionCall { .. } => "Function error".to_string(),
+            RunnerErrorKind::FunctionNotDefined { .. } => "Undefined function".to_string(),
             RunnerErrorKind::Http(http_error) => http_error.description(),
+            RunnerErrorKind::Include { .. } => "Include".to_string(),
             RunnerErrorKind::InvalidJson { .. } => "Invalid JSON".to_string(),
             RunnerErrorKind::InvalidOptionValue { .. } => "Invalid option value".to_string(),
             RunnerErrorKind::InvalidRegex => "Invalid regex".to_string(),
@@ -176,13 +266,17 @@ impl DisplaySourceError for RunnerError {
             RunnerErrorKind::InvalidXPathEval => "Invalid XPath expression".to_string(),
             RunnerErrorKind::NoFilterResult => "Filter error".to_string(),
             RunnerErrorKind::NoQueryResult => "No query result".to_string(),
+            RunnerErrorKind::OAuth2Token { .. } => "OAuth 2.0 token".to_string(),
             RunnerErrorKind::PossibleLoggedSecret => "Invalid redacted secret".to_string(),
             RunnerErrorKind::QueryHeaderNotFound => "Header not found".to_string(),
+            RunnerErrorKind::QueryInvalidCsv { .. } => "Invalid CSV".to_string(),
             RunnerErrorKind::QueryInvalidJson => "Invalid JSON".to_string(),
             RunnerErrorKind::QueryInvalidJsonpathExpression { .. } => {
                 "Invalid JSONPath".to_string()
             }
             RunnerErrorKind::QueryInvalidXml => "Invalid XML".to_string(),
+            RunnerErrorKind::SandboxViolation { .. } => "Sandbox violation".to_string(),
+            RunnerErrorKind::SnapshotMismatch { .. } => "Snapshot mismatch".to_string(),
             RunnerErrorKind::TemplateVariableNotDefined { .. } => "Undefined variable".to_string(),
             RunnerErrorKind::UnauthorizedFileAccess { .. } => {
                 "Unauthorized file access".to_string()
@@ -203,11 +297,34 @@ impl DisplaySourceError for RunnerError {
                 }
                 message
             }
+            RunnerErrorKind::AssertBodyValueError {
+                pretty: Some(pretty),
+                ..
+            } => {
+                let message = format!(
+                    "   first difference at line {}, column {} of pretty-printed bodies\n   actual:\n{}\n   expected:\n{}",
+                    pretty.line,
+                    pretty.column,
+                    indent(&pretty.actual),
+                    indent(&pretty.expected),
+                );
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::AssertBodyValueError { actual, .. } => {
                 let message = &format!("actual value is <{actual}>");
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::AssertFailure {
+                json_diff: Some(json_diff),
+                ..
+            } => {
+                let message = format!(
+                    "   actual and expected JSON differ:\n{}",
+                    indent(&json_diff.to_string())
+                );
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::AssertFailure {
                 actual,
                 expected,
@@ -237,11 +354,25 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::CaptureNullValue { name } => {
+                let message = &format!("captured value of {name} is null");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::CaptureTypeCoercion { value, expected } => {
+                let message = &format!("{value} can not be coerced to {expected}");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::AssertVersion { actual, .. } => {
                 let message = &format!("actual value is <{actual}>");
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::ExpressionArithmetic(reason) => {
+                let message = error::add_carets(reason, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::ExpressionInvalidType {
                 value, expecting, ..
             } => {
@@ -259,6 +390,11 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::FixtureResolution { hash, message } => {
+                let message = &format!("fixture sha256:{hash} can not be resolved: {message}");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::FilterDateParsingError { date, format } => {
                 let message = &format!("value <{date}> could not be parsed with <{format}> format");
                 let message = error::add_carets(message, self.source_info, content);
@@ -291,16 +427,42 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::FilterInvalidJqExpression { value } => {
+                let message = &format!("jq expression '{value}' is not valid");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::FilterJqEval(reason) => {
+                let message = &format!("jq error: {reason}");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::FilterMissingInput => {
                 let message = "missing value to apply filter";
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::FunctionCall { name, message } => {
+                let message = &format!("function {name}: {message}");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::FunctionNotDefined { name } => {
+                let message = &format!("function {name} is not defined");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::Http(http_error) => {
                 let message = http_error.message();
                 let message = error::add_carets(&message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::Include { path, message } => {
+                let path = path.to_string_lossy();
+                let message = &format!("file {path} can not be included: {message}");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::InvalidJson { value } => {
                 let message = &format!("actual value is <{value}>");
                 let message = error::add_carets(message, self.source_info, content);
@@ -341,6 +503,11 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::OAuth2Token { url, message } => {
+                let message = &format!("unable to get an access token from <{url}>: {message}");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::PossibleLoggedSecret => {
                 let message = "redacted secret not authorized in verbose";
                 let message = error::add_carets(message, self.source_info, content);
@@ -351,6 +518,11 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::QueryInvalidCsv { message } => {
+                let message = &format!("HTTP response is not a valid CSV: {message}");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::QueryInvalidJson => {
                 let message = "HTTP response is not a valid JSON";
                 let message = error::add_carets(message, self.source_info, content);
@@ -366,6 +538,19 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::SandboxViolation { message } => {
+                let message = &format!("{message} is not allowed with --sandbox option");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::SnapshotMismatch { path, diff } => {
+                let message = format!(
+                    "   response body doesn't match snapshot {}\n{}",
+                    path.to_string_lossy(),
+                    indent(diff)
+                );
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::TemplateVariableNotDefined { name } => {
                 let message = &format!("you must set the variable {name}");
                 let message = error::add_carets(message, self.source_info, content);
@@ -423,6 +608,14 @@ impl DisplaySourceError for RunnerError {
     }
 }
 
+/// Indents each line of `s` so it's aligned with the actual / expected labels.
+fn indent(s: &str) -> String {
+    s.lines()
+        .map(|line| format!("      {line}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 /// Color each line separately
 fn color_red_multiline_string(s: &str) -> StyledString {
     let lines = s.split('\n');
@@ -474,6 +667,8 @@ fn hunk_string(
 
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
+
     use hurl_core::ast::SourceInfo;
     use hurl_core::error::{DisplaySourceError, OutputFormat};
     use hurl_core::reader::Pos;
@@ -481,6 +676,7 @@ mod tests {
 
     use crate::http::HttpError;
     use crate::runner::diff::diff;
+    use crate::runner::json_diff::json_diff;
     use crate::runner::{RunnerError, RunnerErrorKind};
 
     #[test]
@@ -610,6 +806,7 @@ jsonpath "$.count" >= 5
                 actual: "integer <2>".to_string(),
                 expected: "greater than integer <5>".to_string(),
                 type_mismatch: false,
+                json_diff: None,
             },
             assert: true,
         };
@@ -642,6 +839,70 @@ jsonpath "$.count" >= 5
         );
     }
 
+    #[test]
+    fn test_assert_error_json_diff() {
+        let content = r#"GET http://api
+HTTP 200
+[Asserts]
+body == file,expected.json;
+"#;
+        let lines = content.lines().collect::<Vec<_>>();
+        let error_source_info = SourceInfo::new(Pos::new(4, 0), Pos::new(4, 0));
+        let json_diff = json_diff(
+            &serde_json::json!({"items": [{"price": 12}], "name": "Bob"}),
+            &serde_json::json!({"items": [{"price": 10}], "name": "Alice", "id": 1}),
+            2,
+        );
+        let error = RunnerError {
+            source_info: error_source_info,
+            kind: RunnerErrorKind::AssertFailure {
+                actual: "string <...>".to_string(),
+                expected: "bytes <...>".to_string(),
+                type_mismatch: false,
+                json_diff: json_diff.map(Box::new),
+            },
+            assert: true,
+        };
+
+        assert_eq!(
+            error.message(&lines).to_string(Format::Plain),
+            r#"
+ 4 | body == file,expected.json;
+   |   actual and expected JSON differ:
+   |      $.id: expected 1 actual none
+   |      $.items[0].price: expected 10 actual 12
+   |      ... 1 more difference
+   |"#
+        );
+    }
+
+    #[test]
+    fn test_snapshot_mismatch() {
+        let content = r#"GET http://api/users
+HTTP 200
+"#;
+        let lines = content.lines().collect::<Vec<_>>();
+        let error_source_info = SourceInfo::new(Pos::new(1, 5), Pos::new(1, 21));
+        let error = RunnerError::new(
+            error_source_info,
+            RunnerErrorKind::SnapshotMismatch {
+                path: PathBuf::from("snapshots/test.hurl.entry-1.snap"),
+                diff: "-  \"name\": \"Bob\"\n+  \"name\": \"Alice\"".to_string(),
+            },
+            true,
+        );
+
+        assert_eq!(
+            error.message(&lines).to_string(Format::Plain),
+            r#"
+ 1 | GET http://api/users
+   |   response body doesn't match snapshot snapshots/test.hurl.entry-1.snap
+   |      -  "name": "Bob"
+   |      +  "name": "Alice"
+   |"#
+        );
+    }
+
     #[test]
     fn test_assert_error_newline() {
         let content = r#"GET http://localhost
@@ -15,13 +15,185 @@
  * limitations under the License.
  *
  */
+//! Events published by the runner, during the execution of one Hurl file.
+//!
+//! The runner doesn't know what is done with its events: the logger, reports, progress bars, load
+//! test pacing or custom sinks subscribe to the events by implementing [`EventListener`], and can
+//! be combined with an [`EventBus`].
+use hurl_core::input::Input;
 use hurl_core::types::Index;
 
-/// This trait is implemented by run event observers, during the execution of one Hurl file.
-pub trait EventListener {
-    /// Call when running an entry.
+use super::result::{AssertResult, EntryResult, HurlResult};
+use crate::http::Call;
+
+/// A typed event published by the runner.
+#[derive(Clone, Debug)]
+pub enum Event<'a> {
+    /// An entry starts running.
     /// `current` is the entry index in the Hurl file,
     /// `last` is the last entry index (may be less that the total number of entries).
     /// `retry_count` is the current number of retries (i.e. 0 for a first run)
-    fn on_entry_running(&self, current: Index, last: Index, retry_count: usize);
+    EntryStarted {
+        current: Index,
+        last: Index,
+        retry_count: usize,
+    },
+    /// An HTTP call of the entry `entry_index` has been completed.
+    CallCompleted { entry_index: Index, call: &'a Call },
+    /// An assert of the entry `entry_index` has been evaluated.
+    AssertEvaluated {
+        entry_index: Index,
+        assert: &'a AssertResult,
+    },
+    /// An entry run has been completed, with a `result`. An entry can be run several times, when
+    /// it's retried or repeated.
+    /// `content` and `filename` are the source of the run, used to render the errors of `result`.
+    /// `retry` is `true` if the entry is going to be retried.
+    EntryCompleted {
+        result: &'a EntryResult,
+        content: &'a str,
+        filename: Option<&'a Input>,
+        retry: bool,
+    },
+    /// The run of a Hurl file has been completed, with a `result`.
+    /// `index` is the position of the run in the whole run (runs can be completed in any order with
+    /// parallel runs), `content` and `filename` are the source of the run.
+    RunCompleted {
+        index: usize,
+        content: &'a str,
+        filename: &'a Input,
+        result: &'a HurlResult,
+    },
+}
+
+/// This trait is implemented by run event observers, during the execution of one Hurl file.
+pub trait EventListener {
+    /// Called when the runner publishes an `event`.
+    fn on_event(&mut self, event: &Event);
+}
+
+/// Dispatches the events published by the runner to a list of subscribed listeners.
+#[derive(Default)]
+pub struct EventBus<'a> {
+    listeners: Vec<&'a mut dyn EventListener>,
+}
+
+impl<'a> EventBus<'a> {
+    /// Creates a new event bus, without any subscriber.
+    pub fn new() -> Self {
+        EventBus { listeners: vec![] }
+    }
+
+    /// Subscribes a `listener` to all the events published on this bus.
+    pub fn subscribe(&mut self, listener: &'a mut dyn EventListener) {
+        self.listeners.push(listener);
+    }
+}
+
+impl EventListener for EventBus<'_> {
+    fn on_event(&mut self, event: &Event) {
+        self.listeners.iter_mut().for_each(|l| l.on_event(event));
+    }
+}
+
+/// Publishes the completed HTTP `calls` of the entry `entry_index` to an optional `listener`.
+pub fn publish_calls(
+    listener: Option<&mut (dyn EventListener + '_)>,
+    entry_index: Index,
+    calls: &[Call],
+) {
+    let Some(listener) = listener else {
+        return;
+    };
+    for call in calls.iter() {
+        listener.on_event(&Event::CallCompleted { entry_index, call });
+    }
+}
+
+/// Publishes the evaluated `asserts` of the entry `entry_index` to an optional `listener`.
+pub fn publish_asserts(
+    listener: Option<&mut (dyn EventListener + '_)>,
+    entry_index: Index,
+    asserts: &[AssertResult],
+) {
+    let Some(listener) = listener else {
+        return;
+    };
+    for assert in asserts.iter() {
+        listener.on_event(&Event::AssertEvaluated {
+            entry_index,
+            assert,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder {
+        events: Vec<String>,
+    }
+
+    impl EventListener for Recorder {
+        fn on_event(&mut self, event: &Event) {
+            let name = match event {
+                Event::EntryStarted { current, .. } => format!("started {current}"),
+                Event::CallCompleted { entry_index, .. } => format!("call {entry_index}"),
+                Event::AssertEvaluated { entry_index, .. } => format!("assert {entry_index}"),
+                Event::EntryCompleted { result, .. } => format!("completed {}", result.entry_index),
+                Event::RunCompleted { index, .. } => format!("run {index}"),
+            };
+            self.events.push(name);
+        }
+    }
+
+    #[test]
+    fn test_event_bus() {
+        let mut recorder1 = Recorder::default();
+        let mut recorder2 = Recorder::default();
+        let mut bus = EventBus::new();
+        bus.subscribe(&mut recorder1);
+        bus.subscribe(&mut recorder2);
+
+        bus.on_event(&Event::EntryStarted {
+            current: Index::new(1),
+            last: Index::new(2),
+            retry_count: 0,
+        });
+        let result = EntryResult {
+            entry_index: Index::new(1),
+            ..Default::default()
+        };
+        let assert = AssertResult::ImplicitVersion {
+            actual: "HTTP/1.1".to_string(),
+            expected: "HTTP".to_string(),
+            source_info: result.source_info,
+        };
+        publish_asserts(Some(&mut bus), result.entry_index, &[assert]);
+        bus.on_event(&Event::EntryCompleted {
+            result: &result,
+            content: "",
+            filename: None,
+            retry: false,
+        });
+        let hurl_result = HurlResult::default();
+        let filename = Input::new("test.hurl");
+        bus.on_event(&Event::RunCompleted {
+            index: 0,
+            content: "",
+            filename: &filename,
+            result: &hurl_result,
+        });
+
+        let expected = vec![
+            "started 1".to_string(),
+            "assert 1".to_string(),
+            "completed 1".to_string(),
+            "run 0".to_string(),
+        ];
+        assert_eq!(recorder1.events, expected);
+        assert_eq!(recorder2.events, expected);
+    }
 }
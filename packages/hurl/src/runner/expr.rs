@@ -15,10 +15,15 @@
  * limitations under the License.
  *
  */
-use hurl_core::ast::{Expr, ExprKind};
+use std::cmp::Ordering;
+
+use hurl_core::ast::{BinaryOperator, Expr, ExprKind, Number as AstNumber, SourceInfo};
 
 use super::error::{RunnerError, RunnerErrorKind};
+use super::filter::{FilterOptions, eval_filters};
 use super::function;
+use super::number::Number;
+use super::template::eval_template;
 use super::value::Value;
 use super::variable::VariableSet;
 
@@ -36,6 +41,56 @@ pub fn eval(expr: &Expr, variables: &VariableSet) -> Result<Value, RunnerError>
             }
         }
         ExprKind::Function(fct) => function::eval(fct),
+        ExprKind::Call(call) => {
+            let arguments = call
+                .arguments
+                .iter()
+                .map(|argument| eval(&argument.expr, variables))
+                .collect::<Result<Vec<_>, _>>()?;
+            function::eval_call(call, &arguments, variables, expr.source_info)
+        }
+        ExprKind::Bool(value) => Ok(Value::Bool(*value)),
+        ExprKind::Null => Ok(Value::Null),
+        ExprKind::Number(number) => Ok(Value::Number(eval_number(number))),
+        ExprKind::String(template) => {
+            let s = eval_template(template, variables)?;
+            Ok(Value::String(s))
+        }
+        ExprKind::Filtered(filtered) => {
+            let value = eval(&filtered.expr, variables)?;
+            let filters = filtered.filters.iter().map(|(_, f)| f).collect::<Vec<_>>();
+            match eval_filters(
+                &filters,
+                &value,
+                variables,
+                false,
+                &FilterOptions::default(),
+            )? {
+                Some(value) => Ok(value),
+                None => {
+                    let start = filters.first().unwrap().source_info.start;
+                    let end = filters.last().unwrap().source_info.end;
+                    let kind = RunnerErrorKind::NoFilterResult;
+                    Err(RunnerError::new(SourceInfo::new(start, end), kind, false))
+                }
+            }
+        }
+        ExprKind::Parenthesized(parenthesized) => eval(&parenthesized.expr, variables),
+        ExprKind::Binary(binary) => {
+            let left_value = eval(&binary.left, variables)?;
+            let right_value = eval(&binary.right, variables)?;
+            eval_binary(
+                expr,
+                (&binary.left, left_value),
+                binary.operator,
+                (&binary.right, right_value),
+            )
+        }
+        ExprKind::If(if_expr) => match eval(&if_expr.condition, variables)? {
+            Value::Bool(true) => eval(&if_expr.then_expr, variables),
+            Value::Bool(false) => eval(&if_expr.else_expr, variables),
+            v => Err(invalid_type(&if_expr.condition, &v, "boolean")),
+        },
     }
 }
 
@@ -53,10 +108,155 @@ pub fn render(expr: &Expr, variables: &VariableSet) -> Result<String, RunnerErro
     }
 }
 
+/// Evaluates a number.
+fn eval_number(number: &AstNumber) -> Number {
+    match number {
+        AstNumber::Float(value) => Number::Float(value.as_f64()),
+        AstNumber::Integer(value) => Number::Integer(value.as_i64()),
+        AstNumber::BigInteger(value) => Number::BigInteger(value.clone()),
+    }
+}
+
+/// Evaluates the binary operation `expr`, given its evaluated `left` and `right` operands.
+fn eval_binary(
+    expr: &Expr,
+    left: (&Expr, Value),
+    operator: BinaryOperator,
+    right: (&Expr, Value),
+) -> Result<Value, RunnerError> {
+    let (left_expr, left) = left;
+    let (right_expr, right) = right;
+    match (operator, &left, &right) {
+        (BinaryOperator::Equal, _, _) => Ok(Value::Bool(is_equal(&left, &right))),
+        (BinaryOperator::NotEqual, _, _) => Ok(Value::Bool(!is_equal(&left, &right))),
+        (
+            BinaryOperator::Less
+            | BinaryOperator::LessOrEqual
+            | BinaryOperator::Greater
+            | BinaryOperator::GreaterOrEqual,
+            _,
+            _,
+        ) => {
+            let ordering = match (&left, &right) {
+                (Value::Number(n1), Value::Number(n2)) => n1.cmp_value(n2),
+                (Value::String(s1), Value::String(s2)) => s1.cmp(s2),
+                (Value::Date(d1), Value::Date(d2)) => d1.cmp(d2),
+                (Value::Number(_), _) => return Err(invalid_type(right_expr, &right, "number")),
+                (Value::String(_), _) => return Err(invalid_type(right_expr, &right, "string")),
+                (Value::Date(_), _) => return Err(invalid_type(right_expr, &right, "date")),
+                _ => return Err(invalid_type(left_expr, &left, "number, string or date")),
+            };
+            let value = match operator {
+                BinaryOperator::Less => ordering == Ordering::Less,
+                BinaryOperator::LessOrEqual => ordering != Ordering::Greater,
+                BinaryOperator::Greater => ordering == Ordering::Greater,
+                _ => ordering != Ordering::Less,
+            };
+            Ok(Value::Bool(value))
+        }
+        (_, Value::Number(n1), Value::Number(n2)) => match eval_arithmetic(operator, n1, n2) {
+            Ok(number) => Ok(Value::Number(number)),
+            Err(message) => {
+                let kind = RunnerErrorKind::ExpressionArithmetic(message);
+                Err(RunnerError::new(expr.source_info, kind, false))
+            }
+        },
+        // `+` concatenates a string with any renderable value.
+        (BinaryOperator::Add, Value::String(_), _) | (BinaryOperator::Add, _, Value::String(_)) => {
+            let Some(s1) = left.render() else {
+                return Err(invalid_type(left_expr, &left, "string"));
+            };
+            let Some(s2) = right.render() else {
+                return Err(invalid_type(right_expr, &right, "string"));
+            };
+            Ok(Value::String(format!("{s1}{s2}")))
+        }
+        (_, Value::Number(_), _) => Err(invalid_type(right_expr, &right, "number")),
+        (BinaryOperator::Add, _, _) => Err(invalid_type(left_expr, &left, "number or string")),
+        _ => Err(invalid_type(left_expr, &left, "number")),
+    }
+}
+
+/// Returns `true` if `left` and `right` are equal, integers and floats being compared by value.
+fn is_equal(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Number(n1), Value::Number(n2)) => n1.cmp_value(n2) == Ordering::Equal,
+        _ => left == right,
+    }
+}
+
+/// Applies the arithmetic `operator` to `left` and `right`.
+///
+/// Operations on integers give integers, except for a division with a remainder, which gives a
+/// float. Returns the reason of the failure if the operation can't be computed.
+fn eval_arithmetic(
+    operator: BinaryOperator,
+    left: &Number,
+    right: &Number,
+) -> Result<Number, String> {
+    match (left, right) {
+        (Number::BigInteger(_), _) | (_, Number::BigInteger(_)) => {
+            Err("integer overflow".to_string())
+        }
+        (Number::Integer(i1), Number::Integer(i2)) => {
+            let (i1, i2) = (*i1, *i2);
+            let value = match operator {
+                BinaryOperator::Add => i1.checked_add(i2),
+                BinaryOperator::Subtract => i1.checked_sub(i2),
+                BinaryOperator::Multiply => i1.checked_mul(i2),
+                BinaryOperator::Divide | BinaryOperator::Modulo if i2 == 0 => {
+                    return Err("division by zero".to_string());
+                }
+                BinaryOperator::Divide if i1.checked_rem(i2).is_some_and(|r| r != 0) => {
+                    return Ok(Number::Float(i1 as f64 / i2 as f64));
+                }
+                BinaryOperator::Divide => i1.checked_div(i2),
+                _ => i1.checked_rem(i2),
+            };
+            match value {
+                Some(value) => Ok(Number::Integer(value)),
+                None => Err("integer overflow".to_string()),
+            }
+        }
+        _ => {
+            let f1 = as_f64(left);
+            let f2 = as_f64(right);
+            let value = match operator {
+                BinaryOperator::Add => f1 + f2,
+                BinaryOperator::Subtract => f1 - f2,
+                BinaryOperator::Multiply => f1 * f2,
+                BinaryOperator::Divide | BinaryOperator::Modulo if f2 == 0.0 => {
+                    return Err("division by zero".to_string());
+                }
+                BinaryOperator::Divide => f1 / f2,
+                _ => f1 % f2,
+            };
+            Ok(Number::Float(value))
+        }
+    }
+}
+
+fn as_f64(number: &Number) -> f64 {
+    match number {
+        Number::Float(f) => *f,
+        Number::Integer(i) => *i as f64,
+        Number::BigInteger(_) => unreachable!("big integers are rejected before"),
+    }
+}
+
+fn invalid_type(expr: &Expr, value: &Value, expecting: &str) -> RunnerError {
+    let kind = RunnerErrorKind::ExpressionInvalidType {
+        value: value.repr(),
+        expecting: expecting.to_string(),
+    };
+    RunnerError::new(expr.source_info, kind, false)
+}
+
 #[cfg(test)]
 mod tests {
-    use hurl_core::ast::{ExprKind, SourceInfo, Variable};
+    use hurl_core::ast::{BinaryExpr, ExprKind, I64, IfExpr, Variable, Whitespace};
     use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
 
     use super::*;
 
@@ -91,4 +291,148 @@ mod tests {
             "2023-01-10T08:29:52.000000Z"
         );
     }
+
+    fn whitespace() -> Whitespace {
+        Whitespace {
+            value: " ".to_string(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        }
+    }
+
+    fn expression(kind: ExprKind) -> Expr {
+        Expr {
+            kind,
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        }
+    }
+
+    fn variable(name: &str) -> Expr {
+        expression(ExprKind::Variable(Variable {
+            name: name.to_string(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        }))
+    }
+
+    fn integer(value: i64) -> Expr {
+        let number = AstNumber::Integer(I64::new(value, value.to_string().to_source()));
+        expression(ExprKind::Number(number))
+    }
+
+    fn binary(left: Expr, operator: BinaryOperator, right: Expr) -> Expr {
+        expression(ExprKind::Binary(Box::new(BinaryExpr {
+            left,
+            space0: whitespace(),
+            operator,
+            space1: whitespace(),
+            right,
+        })))
+    }
+
+    #[test]
+    fn test_eval_arithmetic() {
+        let mut variables = VariableSet::new();
+        variables.insert("count".to_string(), Value::Number(Number::Integer(41)));
+
+        let expr = binary(variable("count"), BinaryOperator::Add, integer(1));
+        assert_eq!(
+            eval(&expr, &variables).unwrap(),
+            Value::Number(Number::Integer(42))
+        );
+        let expr = binary(variable("count"), BinaryOperator::Divide, integer(2));
+        assert_eq!(
+            eval(&expr, &variables).unwrap(),
+            Value::Number(Number::Float(20.5))
+        );
+        let expr = binary(variable("count"), BinaryOperator::Modulo, integer(2));
+        assert_eq!(
+            eval(&expr, &variables).unwrap(),
+            Value::Number(Number::Integer(1))
+        );
+
+        let expr = binary(variable("count"), BinaryOperator::Divide, integer(0));
+        assert_eq!(
+            eval(&expr, &variables).unwrap_err().kind,
+            RunnerErrorKind::ExpressionArithmetic("division by zero".to_string())
+        );
+        let expr = binary(integer(i64::MAX), BinaryOperator::Add, integer(1));
+        assert_eq!(
+            eval(&expr, &variables).unwrap_err().kind,
+            RunnerErrorKind::ExpressionArithmetic("integer overflow".to_string())
+        );
+    }
+
+    #[test]
+    fn test_eval_concatenation() {
+        let mut variables = VariableSet::new();
+        variables.insert("name".to_string(), Value::String("user".to_string()));
+        variables.insert("id".to_string(), Value::Number(Number::Integer(7)));
+        variables.insert("items".to_string(), Value::List(vec![]));
+
+        let expr = binary(variable("name"), BinaryOperator::Add, variable("id"));
+        assert_eq!(
+            eval(&expr, &variables).unwrap(),
+            Value::String("user7".to_string())
+        );
+
+        let expr = binary(variable("name"), BinaryOperator::Add, variable("items"));
+        assert_eq!(
+            eval(&expr, &variables).unwrap_err().kind,
+            RunnerErrorKind::ExpressionInvalidType {
+                value: "list <[]>".to_string(),
+                expecting: "string".to_string(),
+            }
+        );
+        let expr = binary(variable("name"), BinaryOperator::Subtract, integer(1));
+        assert_eq!(
+            eval(&expr, &variables).unwrap_err().kind,
+            RunnerErrorKind::ExpressionInvalidType {
+                value: "string <user>".to_string(),
+                expecting: "number".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_eval_comparison_and_if() {
+        let mut variables = VariableSet::new();
+        variables.insert("count".to_string(), Value::Number(Number::Float(2.0)));
+
+        let condition = binary(variable("count"), BinaryOperator::Equal, integer(2));
+        assert_eq!(eval(&condition, &variables).unwrap(), Value::Bool(true));
+        let condition = binary(variable("count"), BinaryOperator::Greater, integer(1));
+        assert_eq!(eval(&condition, &variables).unwrap(), Value::Bool(true));
+
+        let expr = expression(ExprKind::If(Box::new(IfExpr {
+            space0: whitespace(),
+            condition,
+            space1: whitespace(),
+            space2: whitespace(),
+            then_expr: integer(10),
+            space3: whitespace(),
+            space4: whitespace(),
+            else_expr: integer(20),
+        })));
+        assert_eq!(
+            eval(&expr, &variables).unwrap(),
+            Value::Number(Number::Integer(10))
+        );
+
+        let expr = expression(ExprKind::If(Box::new(IfExpr {
+            space0: whitespace(),
+            condition: variable("count"),
+            space1: whitespace(),
+            space2: whitespace(),
+            then_expr: integer(10),
+            space3: whitespace(),
+            space4: whitespace(),
+            else_expr: integer(20),
+        })));
+        assert_eq!(
+            eval(&expr, &variables).unwrap_err().kind,
+            RunnerErrorKind::ExpressionInvalidType {
+                value: "float <2.0>".to_string(),
+                expecting: "boolean".to_string(),
+            }
+        );
+    }
 }
@@ -0,0 +1,155 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use chrono::TimeDelta;
+use hurl_core::ast::{IntegerValue, SourceInfo};
+
+use crate::runner::filter::nth::eval_integer_value;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Returns the date `value` shifted by a number of `days`, which can be negative.
+pub fn eval_add_days(
+    value: &Value,
+    days: &IntegerValue,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let days = eval_integer_value(days, variables)?;
+    let delta = TimeDelta::try_days(days);
+    add_delta(value, delta, source_info, assert)
+}
+
+/// Returns the date `value` shifted by `delta`.
+///
+/// `delta` is `None` if the shift can't be represented, in which case an error is returned, as if
+/// the resulting date was out of range.
+pub fn add_delta(
+    value: &Value,
+    delta: Option<TimeDelta>,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::Date(date) => match delta.and_then(|delta| date.checked_add_signed(delta)) {
+            Some(date) => Ok(Some(Value::Date(date))),
+            None => {
+                let kind =
+                    RunnerErrorKind::FilterInvalidInputValue("date out of range".to_string());
+                Err(RunnerError::new(source_info, kind, assert))
+            }
+        },
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInputType {
+                actual: v.kind().to_string(),
+                expected: "date".to_string(),
+            };
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+    use hurl_core::ast::{Filter, FilterValue, I64, SourceInfo, Whitespace};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use super::*;
+    use crate::runner::Number;
+    use crate::runner::filter::eval::{FilterOptions, eval_filter};
+
+    fn date(s: &str) -> Value {
+        Value::Date(DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc))
+    }
+
+    fn add_days_filter(days: i64) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::AddDays {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                days: IntegerValue::Literal(I64::new(days, days.to_string().to_source())),
+            },
+        }
+    }
+
+    #[test]
+    fn eval_filter_add_days() {
+        let variables = VariableSet::new();
+        assert_eq!(
+            eval_filter(
+                &add_days_filter(7),
+                &date("2026-02-25T10:00:00Z"),
+                &variables,
+                false,
+                &FilterOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            date("2026-03-04T10:00:00Z")
+        );
+        assert_eq!(
+            eval_filter(
+                &add_days_filter(-1),
+                &date("2026-01-01T00:00:00Z"),
+                &variables,
+                false,
+                &FilterOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            date("2025-12-31T00:00:00Z")
+        );
+    }
+
+    #[test]
+    fn eval_filter_add_days_error() {
+        let variables = VariableSet::new();
+        let error = eval_filter(
+            &add_days_filter(1),
+            &Value::Number(Number::Integer(1)),
+            &variables,
+            false,
+            &FilterOptions::default(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInputType {
+                actual: "integer".to_string(),
+                expected: "date".to_string(),
+            }
+        );
+
+        let error = eval_filter(
+            &add_days_filter(i64::MAX),
+            &date("2026-01-01T00:00:00Z"),
+            &variables,
+            false,
+            &FilterOptions::default(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInputValue("date out of range".to_string())
+        );
+    }
+}
@@ -0,0 +1,99 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use chrono::TimeDelta;
+use hurl_core::ast::{IntegerValue, SourceInfo};
+
+use crate::runner::filter::add_days::add_delta;
+use crate::runner::filter::nth::eval_integer_value;
+use crate::runner::{RunnerError, Value, VariableSet};
+
+/// Returns the date `value` shifted by a number of `seconds`, which can be negative.
+pub fn eval_add_seconds(
+    value: &Value,
+    seconds: &IntegerValue,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let seconds = eval_integer_value(seconds, variables)?;
+    let delta = TimeDelta::try_seconds(seconds);
+    add_delta(value, delta, source_info, assert)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+    use hurl_core::ast::{
+        Expr, ExprKind, Filter, FilterValue, Placeholder, SourceInfo, Variable, Whitespace,
+    };
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::Number;
+    use crate::runner::filter::eval::{FilterOptions, eval_filter};
+
+    fn whitespace() -> Whitespace {
+        Whitespace {
+            value: String::new(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        }
+    }
+
+    #[test]
+    fn eval_filter_add_seconds() {
+        // addSeconds {{ttl}}
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 19)),
+            value: FilterValue::AddSeconds {
+                space0: whitespace(),
+                seconds: IntegerValue::Placeholder(Placeholder {
+                    space0: whitespace(),
+                    expr: Expr {
+                        kind: ExprKind::Variable(Variable {
+                            name: "ttl".to_string(),
+                            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                        }),
+                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    },
+                    space1: whitespace(),
+                }),
+            },
+        };
+        let mut variables = VariableSet::new();
+        variables.insert("ttl".to_string(), Value::Number(Number::Integer(-90)));
+
+        let now = DateTime::parse_from_rfc3339("2026-06-20T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let expected = DateTime::parse_from_rfc3339("2026-06-20T09:58:30Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Date(now),
+                &variables,
+                false,
+                &FilterOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Date(expected)
+        );
+    }
+}
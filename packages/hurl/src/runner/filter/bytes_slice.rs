@@ -0,0 +1,193 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{IntegerValue, SourceInfo};
+
+use crate::runner::filter::nth::eval_integer_value;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Returns the bytes of `value` from the zero-based byte offset `start` (inclusive) to `end`
+/// (exclusive). Strings are sliced on their UTF-8 encoded bytes. Negative offsets are counted from
+/// the end of the bytes.
+pub fn eval_bytes_slice(
+    value: &Value,
+    start: &IntegerValue,
+    end: &IntegerValue,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let start = eval_integer_value(start, variables)?;
+    let end = eval_integer_value(end, variables)?;
+
+    let bytes = match value {
+        Value::Bytes(bytes) => bytes.as_slice(),
+        Value::String(s) => s.as_bytes(),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInputType {
+                actual: v.kind().to_string(),
+                expected: "bytes or string".to_string(),
+            };
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    };
+    match try_slice(bytes, start, end) {
+        Ok(slice) => Ok(Some(Value::Bytes(slice.to_vec()))),
+        Err(err) => {
+            let kind = RunnerErrorKind::FilterInvalidInputValue(err);
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Returns the sub-slice of `items` between `start` (inclusive) and `end` (exclusive).
+/// Negative offsets are counted from the end of the collection.
+fn try_slice<U>(items: &[U], start: i64, end: i64) -> Result<&[U], String> {
+    let len = items.len() as i64;
+    let offset = |index: i64| if index < 0 { len + index } else { index };
+    let (start, end) = (offset(start), offset(end));
+    if start < 0 || end > len || start > end {
+        let error = format!("invalid range {start}..{end} - size is {len}");
+        return Err(error);
+    }
+    Ok(&items[start as usize..end as usize])
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, I64, IntegerValue, SourceInfo, Whitespace};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use super::*;
+    use crate::runner::Number;
+    use crate::runner::filter::eval::{FilterOptions, eval_filter};
+
+    fn new_bytes_slice_filter(start: i64, end: i64) -> Filter {
+        let space = Whitespace {
+            value: String::new(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::BytesSlice {
+                space0: space.clone(),
+                start: IntegerValue::Literal(I64::new(start, start.to_string().to_source())),
+                space1: space,
+                end: IntegerValue::Literal(I64::new(end, end.to_string().to_source())),
+            },
+        }
+    }
+
+    #[test]
+    fn eval_filter_bytes_slice() {
+        let variables = VariableSet::new();
+        let bytes = Value::Bytes(vec![0xde, 0xad, 0xbe, 0xef, 0x00, 0x01]);
+
+        assert_eq!(
+            eval_filter(
+                &new_bytes_slice_filter(1, 3),
+                &bytes,
+                &variables,
+                false,
+                &FilterOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bytes(vec![0xad, 0xbe])
+        );
+        assert_eq!(
+            eval_filter(
+                &new_bytes_slice_filter(-2, 6),
+                &bytes,
+                &variables,
+                false,
+                &FilterOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bytes(vec![0x00, 0x01])
+        );
+        assert_eq!(
+            eval_filter(
+                &new_bytes_slice_filter(2, 2),
+                &bytes,
+                &variables,
+                false,
+                &FilterOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bytes(vec![])
+        );
+
+        // Strings are sliced by UTF-8 byte offsets, not by characters.
+        assert_eq!(
+            eval_filter(
+                &new_bytes_slice_filter(0, 4),
+                &Value::String("café au lait".to_string()),
+                &variables,
+                false,
+                &FilterOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bytes(b"caf\xc3".to_vec())
+        );
+    }
+
+    #[test]
+    fn eval_filter_bytes_slice_error() {
+        let variables = VariableSet::new();
+        let bytes = Value::Bytes(vec![0xde, 0xad, 0xbe, 0xef]);
+
+        assert_eq!(
+            eval_filter(
+                &new_bytes_slice_filter(2, 8),
+                &bytes,
+                &variables,
+                false,
+                &FilterOptions::default()
+            )
+            .err()
+            .unwrap(),
+            RunnerError::new(
+                SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                RunnerErrorKind::FilterInvalidInputValue(
+                    "invalid range 2..8 - size is 4".to_string()
+                ),
+                false
+            )
+        );
+        assert_eq!(
+            eval_filter(
+                &new_bytes_slice_filter(0, 2),
+                &Value::Number(Number::Integer(42)),
+                &variables,
+                false,
+                &FilterOptions::default()
+            )
+            .err()
+            .unwrap()
+            .kind,
+            RunnerErrorKind::FilterInvalidInputType {
+                actual: "integer".to_string(),
+                expected: "bytes or string".to_string()
+            }
+        );
+    }
+}
@@ -0,0 +1,127 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::xpath;
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Converts a XML string `value` to its canonical form (Canonical XML 1.0 without comments).
+///
+/// Two XML documents differing only by attributes order, quotes, empty elements syntax or
+/// whitespace between elements have the same canonical form.
+pub fn eval_canonicalize(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(value) => match xpath::canonicalize(value) {
+            Ok(canonical) => Ok(Some(Value::String(canonical))),
+            Err(_) => {
+                let kind = RunnerErrorKind::FilterInvalidInputValue(
+                    "value is not a valid XML".to_string(),
+                );
+                Err(RunnerError::new(source_info, kind, assert))
+            }
+        },
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInputType {
+                actual: v.kind().to_string(),
+                expected: "string".to_string(),
+            };
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::{FilterOptions, eval_filter};
+    use crate::runner::{RunnerErrorKind, Value, VariableSet};
+
+    fn new_canonicalize_filter() -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 13)),
+            value: FilterValue::Canonicalize,
+        }
+    }
+
+    #[test]
+    fn eval_filter_canonicalize() {
+        let variables = VariableSet::new();
+        let filter = new_canonicalize_filter();
+        let xml = r#"<?xml version="1.0"?>
+<user   role='admin' id="1">
+    <name>Bob</name>
+    <tags/>
+</user>"#;
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String(xml.to_string()),
+                &variables,
+                false,
+                &FilterOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String(
+                r#"<user id="1" role="admin"><name>Bob</name><tags></tags></user>"#.to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn eval_filter_canonicalize_error() {
+        let variables = VariableSet::new();
+        let filter = new_canonicalize_filter();
+        let error = eval_filter(
+            &filter,
+            &Value::String("<user><name>Bob</user>".to_string()),
+            &variables,
+            false,
+            &FilterOptions::default(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInputValue("value is not a valid XML".to_string())
+        );
+
+        let error = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            false,
+            &FilterOptions::default(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInputType {
+                actual: "boolean".to_string(),
+                expected: "string".to_string()
+            }
+        );
+    }
+}
@@ -17,22 +17,32 @@
  */
 use hurl_core::ast::{Filter, FilterValue};
 
+use crate::runner::filter::add_days::eval_add_days;
+use crate::runner::filter::add_seconds::eval_add_seconds;
 use crate::runner::filter::base64_decode::eval_base64_decode;
 use crate::runner::filter::base64_encode::eval_base64_encode;
 use crate::runner::filter::base64_url_safe_decode::eval_base64_url_safe_decode;
 use crate::runner::filter::base64_url_safe_encode::eval_base64_url_safe_encode;
+use crate::runner::filter::brotli_decompress::eval_brotli_decompress;
+use crate::runner::filter::bytes_slice::eval_bytes_slice;
+use crate::runner::filter::canonicalize::eval_canonicalize;
 use crate::runner::filter::charset_decode::eval_charset_decode;
 use crate::runner::filter::count::eval_count;
 use crate::runner::filter::days_after_now::eval_days_after_now;
 use crate::runner::filter::days_before_now::eval_days_before_now;
 use crate::runner::filter::first::eval_first;
 use crate::runner::filter::format::eval_date_format;
+use crate::runner::filter::from_hex::eval_from_hex;
+use crate::runner::filter::gunzip::eval_gunzip;
 use crate::runner::filter::html_escape::eval_html_escape;
 use crate::runner::filter::html_unescape::eval_html_unescape;
+use crate::runner::filter::jq::eval_jq;
 use crate::runner::filter::jsonpath::eval_jsonpath;
+use crate::runner::filter::jwt::{eval_jwt_header, eval_jwt_payload};
 use crate::runner::filter::last::eval_last;
 use crate::runner::filter::location::eval_location;
 use crate::runner::filter::nth::eval_nth;
+use crate::runner::filter::parse_json::eval_parse_json;
 use crate::runner::filter::regex::eval_regex;
 use crate::runner::filter::replace::eval_replace;
 use crate::runner::filter::replace_regex::eval_replace_regex;
@@ -41,13 +51,18 @@ use crate::runner::filter::to_date::eval_to_date;
 use crate::runner::filter::to_float::eval_to_float;
 use crate::runner::filter::to_hex::eval_to_hex;
 use crate::runner::filter::to_int::eval_to_int;
+use crate::runner::filter::to_json::eval_to_json;
 use crate::runner::filter::to_string::eval_to_string;
+use crate::runner::filter::to_timestamp::eval_to_timestamp;
 use crate::runner::filter::url_decode::eval_url_decode;
 use crate::runner::filter::url_encode::eval_url_encode;
 use crate::runner::filter::url_query_param::eval_url_query_param;
 use crate::runner::filter::utf8_decode::eval_utf8_decode;
 use crate::runner::filter::utf8_encode::eval_utf8_encode;
+use crate::runner::filter::xml_escape::eval_xml_escape;
+use crate::runner::filter::xml_unescape::eval_xml_unescape;
 use crate::runner::filter::xpath::eval_xpath;
+use crate::runner::filter::zstd_decompress::eval_zstd_decompress;
 use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
 
 /// Options controlling filter evaluation behavior.
@@ -97,6 +112,12 @@ pub fn eval_filter(
 ) -> Result<Option<Value>, RunnerError> {
     let source_info = filter.source_info;
     match &filter.value {
+        FilterValue::AddDays { days, .. } => {
+            eval_add_days(value, days, variables, source_info, in_assert)
+        }
+        FilterValue::AddSeconds { seconds, .. } => {
+            eval_add_seconds(value, seconds, variables, source_info, in_assert)
+        }
         FilterValue::Base64Decode => eval_base64_decode(value, source_info, in_assert),
         FilterValue::Base64Encode => eval_base64_encode(value, source_info, in_assert),
         FilterValue::Base64UrlSafeDecode => {
@@ -105,6 +126,11 @@ pub fn eval_filter(
         FilterValue::Base64UrlSafeEncode => {
             eval_base64_url_safe_encode(value, source_info, in_assert)
         }
+        FilterValue::BrotliDecompress => eval_brotli_decompress(value, source_info, in_assert),
+        FilterValue::BytesSlice { start, end, .. } => {
+            eval_bytes_slice(value, start, end, variables, source_info, in_assert)
+        }
+        FilterValue::Canonicalize => eval_canonicalize(value, source_info, in_assert),
         FilterValue::Count => eval_count(value, source_info, in_assert),
         FilterValue::CharsetDecode { encoding, .. } => {
             eval_charset_decode(value, encoding, variables, source_info, in_assert)
@@ -121,8 +147,11 @@ pub fn eval_filter(
         FilterValue::DateFormat { fmt, .. } => {
             eval_date_format(value, fmt, variables, source_info, in_assert)
         }
+        FilterValue::FromHex => eval_from_hex(value, source_info, in_assert),
+        FilterValue::Gunzip => eval_gunzip(value, source_info, in_assert),
         FilterValue::HtmlEscape => eval_html_escape(value, source_info, in_assert),
         FilterValue::HtmlUnescape => eval_html_unescape(value, source_info, in_assert),
+        FilterValue::Jq { expr, .. } => eval_jq(value, expr, variables, source_info, in_assert),
         FilterValue::JsonPath { expr, .. } => eval_jsonpath(
             value,
             expr,
@@ -131,12 +160,15 @@ pub fn eval_filter(
             in_assert,
             options.use_jsonpath_coercion,
         ),
+        FilterValue::JwtHeader => eval_jwt_header(value, source_info, in_assert),
+        FilterValue::JwtPayload => eval_jwt_payload(value, source_info, in_assert),
         FilterValue::Last => eval_last(value, source_info, in_assert),
         FilterValue::Location => eval_location(value, source_info, in_assert),
         FilterValue::Regex {
             value: regex_value, ..
         } => eval_regex(value, regex_value, variables, source_info, in_assert),
         FilterValue::Nth { n, .. } => eval_nth(value, n, variables, source_info, in_assert),
+        FilterValue::ParseJson => eval_parse_json(value, source_info, in_assert),
         FilterValue::Replace {
             old_value,
             new_value,
@@ -157,9 +189,18 @@ pub fn eval_filter(
             eval_to_date(value, fmt, variables, source_info, in_assert)
         }
         FilterValue::ToFloat => eval_to_float(value, source_info, in_assert),
-        FilterValue::ToHex => eval_to_hex(value, source_info, in_assert),
+        FilterValue::ToHex { case, separator } => eval_to_hex(
+            value,
+            variables,
+            source_info,
+            in_assert,
+            case.as_ref().map(|(_, case)| *case),
+            separator.as_ref().map(|(_, separator)| separator),
+        ),
         FilterValue::ToInt => eval_to_int(value, source_info, in_assert),
+        FilterValue::ToJson => eval_to_json(value, source_info, in_assert),
         FilterValue::ToString => eval_to_string(value, source_info, in_assert),
+        FilterValue::ToTimestamp => eval_to_timestamp(value, source_info, in_assert),
         FilterValue::UrlDecode => eval_url_decode(value, source_info, in_assert),
         FilterValue::UrlEncode => eval_url_encode(value, source_info, in_assert),
         FilterValue::UrlQueryParam { param, .. } => {
@@ -167,9 +208,12 @@ pub fn eval_filter(
         }
         FilterValue::Utf8Decode => eval_utf8_decode(value, source_info, in_assert),
         FilterValue::Utf8Encode => eval_utf8_encode(value, source_info, in_assert),
+        FilterValue::XmlEscape => eval_xml_escape(value, source_info, in_assert),
+        FilterValue::XmlUnescape => eval_xml_unescape(value, source_info, in_assert),
         FilterValue::XPath { expr, .. } => {
             eval_xpath(value, expr, variables, source_info, in_assert)
         }
+        FilterValue::ZstdDecompress => eval_zstd_decompress(value, source_info, in_assert),
     }
 }
 
@@ -0,0 +1,125 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value, hex};
+
+/// Converts an hexadecimal string `value` to bytes.
+///
+/// Digits can be lowercase or uppercase, and bytes can be separated by spaces, `:` or `-` (for
+/// instance `"DE:AD:BE:EF"`), so strings produced by `toHex` can be converted back to bytes.
+pub fn eval_from_hex(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(value) => {
+            let digits = value
+                .chars()
+                .filter(|c| !c.is_ascii_whitespace() && *c != ':' && *c != '-')
+                .collect::<String>();
+            match hex::decode(&digits) {
+                Some(bytes) => Ok(Some(Value::Bytes(bytes))),
+                None => {
+                    let kind = RunnerErrorKind::FilterDecode("hex".to_string());
+                    Err(RunnerError::new(source_info, kind, assert))
+                }
+            }
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInputType {
+                actual: v.kind().to_string(),
+                expected: "string".to_string(),
+            };
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::VariableSet;
+    use crate::runner::filter::eval::{FilterOptions, eval_filter};
+
+    fn filter() -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::FromHex,
+        }
+    }
+
+    #[test]
+    fn eval_filter_from_hex_ok() {
+        let variables = VariableSet::new();
+        let expected = Value::Bytes(vec![0xde, 0xad, 0xbe, 0xef]);
+
+        for s in [
+            "deadbeef",
+            "DEADBEEF",
+            "de:ad:be:ef",
+            "DE AD BE EF",
+            "de-ad-be-ef",
+        ] {
+            let ret = eval_filter(
+                &filter(),
+                &Value::String(s.to_string()),
+                &variables,
+                false,
+                &FilterOptions::default(),
+            );
+            assert_eq!(ret.unwrap().unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn eval_filter_from_hex_ko() {
+        let variables = VariableSet::new();
+
+        let ret = eval_filter(
+            &filter(),
+            &Value::String("dea".to_string()),
+            &variables,
+            false,
+            &FilterOptions::default(),
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterDecode("hex".to_string())
+        );
+
+        let ret = eval_filter(
+            &filter(),
+            &Value::Bytes(vec![0xde, 0xad]),
+            &variables,
+            false,
+            &FilterOptions::default(),
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInputType {
+                actual: "bytes".to_string(),
+                expected: "string".to_string()
+            }
+        );
+    }
+}
@@ -0,0 +1,97 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::io::Read;
+
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Decompresses GZip compressed bytes `value`.
+pub fn eval_gunzip(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::Bytes(value) => match decompress(value) {
+            Some(decompressed) => Ok(Some(Value::Bytes(decompressed))),
+            None => {
+                let kind = RunnerErrorKind::FilterDecode("gzip".to_string());
+                Err(RunnerError::new(source_info, kind, assert))
+            }
+        },
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInputType {
+                actual: v.kind().to_string(),
+                expected: "bytes".to_string(),
+            };
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Decompresses GZip `data`, returns `None` if `data` is not valid GZip data.
+fn decompress(data: &[u8]) -> Option<Vec<u8>> {
+    let mut decoder = libflate::gzip::Decoder::new(data).ok()?;
+    let mut buf = Vec::new();
+    decoder.read_to_end(&mut buf).ok()?;
+    Some(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::Number;
+
+    #[test]
+    fn eval_filter_gunzip_ok() {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10));
+        // `echo -n "Hello World!" | gzip -n`
+        let bytes = vec![
+            0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0xf3, 0x48, 0xcd, 0xc9,
+            0xc9, 0x57, 0x08, 0xcf, 0x2f, 0xca, 0x49, 0x51, 0x04, 0x00, 0xa3, 0x1c, 0x29, 0x1c,
+            0x0c, 0x00, 0x00, 0x00,
+        ];
+        let ret = eval_gunzip(&Value::Bytes(bytes), source_info, false);
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::Bytes(b"Hello World!".to_vec())
+        );
+    }
+
+    #[test]
+    fn eval_filter_gunzip_ko() {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10));
+        let ret = eval_gunzip(&Value::Bytes(b"Hello World!".to_vec()), source_info, false);
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterDecode("gzip".to_string())
+        );
+
+        let ret = eval_gunzip(&Value::Number(Number::Integer(42)), source_info, false);
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInputType {
+                actual: "integer".to_string(),
+                expected: "bytes".to_string(),
+            }
+        );
+    }
+}
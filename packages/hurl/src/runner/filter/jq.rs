@@ -0,0 +1,188 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::jq;
+use crate::runner::template::eval_template;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Evaluates a jq expression `expr` against a `value`.
+///
+/// A jq expression produces a stream of values: an empty stream returns no value, a single value
+/// is returned as is and multiple values are returned as a list.
+pub fn eval_jq(
+    value: &Value,
+    expr: &Template,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let json = match value {
+        Value::String(text) => match serde_json::from_str::<serde_json::Value>(text) {
+            Ok(v) => v,
+            Err(_) => {
+                let kind = RunnerErrorKind::FilterInvalidInputValue(
+                    "value is not a valid JSON".to_string(),
+                );
+                return Err(RunnerError::new(source_info, kind, false));
+            }
+        },
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInputType {
+                actual: v.kind().to_string(),
+                expected: "string".to_string(),
+            };
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    };
+
+    let expr_str = eval_template(expr, variables)?;
+    let program = match jq::parse(&expr_str) {
+        Ok(p) => p,
+        Err(_) => {
+            let kind = RunnerErrorKind::FilterInvalidJqExpression { value: expr_str };
+            return Err(RunnerError::new(expr.source_info, kind, false));
+        }
+    };
+    let mut results = match program.eval(&json) {
+        Ok(results) => results,
+        Err(e) => {
+            let kind = RunnerErrorKind::FilterJqEval(e.to_string());
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    };
+    match results.len() {
+        0 => Ok(None),
+        1 => Ok(results.pop().map(|v| Value::from_json(&v))),
+        _ => Ok(Some(Value::List(
+            results.iter().map(Value::from_json).collect(),
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::FilterOptions;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn jq_filter(expr: &str) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Jq {
+                expr: Template::new(
+                    Some('"'),
+                    vec![TemplateElement::String {
+                        value: expr.to_string(),
+                        source: expr.to_source(),
+                    }],
+                    SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                ),
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn eval_filter_jq() {
+        let variables = VariableSet::new();
+        let input = Value::String(r#"{"items":[{"price":10},{"price":32}]}"#.to_string());
+
+        assert_eq!(
+            eval_filter(
+                &jq_filter("[.items[].price] | add"),
+                &input,
+                &variables,
+                false,
+                &FilterOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Integer(42))
+        );
+        assert_eq!(
+            eval_filter(
+                &jq_filter(".items[].price"),
+                &input,
+                &variables,
+                false,
+                &FilterOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::List(vec![
+                Value::Number(Number::Integer(10)),
+                Value::Number(Number::Integer(32))
+            ])
+        );
+        assert_eq!(
+            eval_filter(
+                &jq_filter(".items[] | select(.price > 100)"),
+                &input,
+                &variables,
+                false,
+                &FilterOptions::default()
+            )
+            .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn eval_filter_jq_error() {
+        let variables = VariableSet::new();
+        let input = Value::String(r#"{"items":[{"price":10},{"price":32}]}"#.to_string());
+
+        let error = eval_filter(
+            &jq_filter(".items |"),
+            &input,
+            &variables,
+            false,
+            &FilterOptions::default(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidJqExpression {
+                value: ".items |".to_string()
+            }
+        );
+
+        let error = eval_filter(
+            &jq_filter(".items + 1"),
+            &input,
+            &variables,
+            false,
+            &FilterOptions::default(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterJqEval(
+                r#"cannot calculate [{"price":10},{"price":32}] + 1"#.to_string()
+            )
+        );
+    }
+}
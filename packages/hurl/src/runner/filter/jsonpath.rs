@@ -15,11 +15,13 @@
  * limitations under the License.
  *
  */
+use std::str::FromStr;
+
 use hurl_core::ast::{SourceInfo, Template};
 
 use crate::jsonpath;
 use crate::runner::template::eval_template;
-use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value, VariableSet};
 
 /// Evaluates a JSONPath expression `expr` against a `value`.
 pub fn eval_jsonpath(
@@ -43,17 +45,18 @@ pub fn eval_jsonpath(
             }
             Ok(v) => v,
         },
-
-        // FIXME: for the moment `jsonpath` filter accepts only string. We need to decide if the filter
-        // accept any value or if we need a `toJson` filter that takes any value and render to string.
-        // See <https://github.com/Orange-OpenSource/hurl/issues/4249>
-        // v => match v.try_to_json() {
-        //     Some(v) => v,
-        //     None => {
-        //         let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
-        //         return Err(RunnerError::new(source_info, kind, assert));
-        //     }
-        // },
+        // Structured values (produced for instance by `parseJson` filter or `redirects` query)
+        // are also accepted.
+        Value::Object(_) | Value::List(_) | Value::HttpResponse(_) => match value.try_to_json() {
+            Some(v) => v,
+            None => {
+                let kind = RunnerErrorKind::FilterInvalidInputValue(format!(
+                    "{} can not be converted to JSON",
+                    value.repr()
+                ));
+                return Err(RunnerError::new(source_info, kind, assert));
+            }
+        },
         v => {
             let kind = RunnerErrorKind::FilterInvalidInputType {
                 actual: v.kind().to_string(),
@@ -66,44 +69,72 @@ pub fn eval_jsonpath(
 }
 
 impl Value {
-    // /// Deserializes this [`Value`] to a JSON [`serde_json::Value`].
-    // fn try_to_json(&self) -> Option<serde_json::Value> {
-    //     match self {
-    //         Value::Bool(value) => Some(serde_json::Value::Bool(*value)),
-    //         Value::List(values) => values
-    //             .iter()
-    //             .map(|v| v.try_to_json())
-    //             .collect::<Option<_>>(),
-    //         Value::Null => Some(serde_json::Value::Null),
-    //         Value::Number(Number::Float(value)) => {
-    //             let number = serde_json::Number::from_f64(*value)?;
-    //             Some(serde_json::Value::Number(number))
-    //         }
-    //         Value::Number(Number::Integer(value)) => {
-    //             let number = serde_json::Number::from_i128(*value as i128)?;
-    //             Some(serde_json::Value::Number(number))
-    //         }
-    //         Value::Number(Number::BigInteger(value)) => match serde_json::Number::from_str(value) {
-    //             Ok(n) => Some(serde_json::Value::Number(n)),
-    //             Err(_) => None,
-    //         },
-    //         Value::Object(values) => {
-    //             let mut obj = serde_json::Map::new();
-    //             for (key, value) in values {
-    //                 let value = value.try_to_json()?;
-    //                 obj.insert(key.clone(), value);
-    //             }
-    //             Some(serde_json::Value::Object(obj))
-    //         }
-    //         Value::String(value) => Some(serde_json::Value::String(value.clone())),
-    //         Value::Regex(_)
-    //         | Value::Nodeset(_)
-    //         | Value::HttpResponse(_)
-    //         | Value::Date(_)
-    //         | Value::Bytes(_)
-    //         | Value::Unit => None,
-    //     }
-    // }
+    /// Deserializes this [`Value`] to a JSON [`serde_json::Value`].
+    pub(crate) fn try_to_json(&self) -> Option<serde_json::Value> {
+        match self {
+            Value::Bool(value) => Some(serde_json::Value::Bool(*value)),
+            Value::List(values) => values
+                .iter()
+                .map(|v| v.try_to_json())
+                .collect::<Option<_>>(),
+            Value::Null => Some(serde_json::Value::Null),
+            Value::Number(Number::Float(value)) => {
+                let number = serde_json::Number::from_f64(*value)?;
+                Some(serde_json::Value::Number(number))
+            }
+            Value::Number(Number::Integer(value)) => {
+                let number = serde_json::Number::from(*value);
+                Some(serde_json::Value::Number(number))
+            }
+            Value::Number(Number::BigInteger(value)) => match serde_json::Number::from_str(value) {
+                Ok(n) => Some(serde_json::Value::Number(n)),
+                Err(_) => None,
+            },
+            Value::Object(values) => {
+                let mut obj = serde_json::Map::new();
+                for (key, value) in values {
+                    let value = value.try_to_json()?;
+                    obj.insert(key.clone(), value);
+                }
+                Some(serde_json::Value::Object(obj))
+            }
+            Value::String(value) => Some(serde_json::Value::String(value.clone())),
+            Value::HttpResponse(response) => {
+                // Headers with the same name are merged in a single comma separated value.
+                let mut headers = serde_json::Map::new();
+                for header in response.headers() {
+                    let value = match headers.get(&header.name) {
+                        Some(serde_json::Value::String(prev)) => {
+                            format!("{prev}, {}", header.value)
+                        }
+                        _ => header.value.clone(),
+                    };
+                    headers.insert(header.name.clone(), serde_json::Value::String(value));
+                }
+                let location = match response.location() {
+                    Some(location) => serde_json::Value::String(location.raw()),
+                    None => serde_json::Value::Null,
+                };
+                let mut obj = serde_json::Map::new();
+                obj.insert(
+                    "url".to_string(),
+                    serde_json::Value::String(response.url().raw()),
+                );
+                obj.insert(
+                    "status".to_string(),
+                    serde_json::Value::Number(serde_json::Number::from(response.status())),
+                );
+                obj.insert("location".to_string(), location);
+                obj.insert("headers".to_string(), serde_json::Value::Object(headers));
+                Some(serde_json::Value::Object(obj))
+            }
+            Value::Regex(_)
+            | Value::Nodeset(_)
+            | Value::Date(_)
+            | Value::Bytes(_)
+            | Value::Unit => None,
+        }
+    }
 }
 
 pub fn eval_jsonpath_json(
@@ -134,12 +165,16 @@ pub fn eval_jsonpath_json(
 
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
+
+    use crate::http::{Header, HeaderVec, Url};
     use crate::runner::filter::FilterOptions;
     use crate::runner::filter::eval::eval_filter;
-    use crate::runner::{Value, VariableSet};
+    use crate::runner::{HttpResponse, Number, Value, VariableSet};
     use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
     use hurl_core::reader::Pos;
     use hurl_core::types::ToSource;
+    use serde_json::json;
 
     #[test]
     fn eval_filter_jsonpath() {
@@ -191,60 +226,124 @@ mod tests {
         );
     }
 
-    // #[test]
-    // fn test_try_to_json_bool() {
-    //     let input = Value::Bool(true);
-    //     let expected = json!(true);
-    //     let actual = input.try_to_json().unwrap();
-    //     assert_eq!(actual, expected);
-    //
-    //     let input = Value::Bool(false);
-    //     let expected = serde_json::Value::Bool(false);
-    //     let actual = input.try_to_json().unwrap();
-    //     assert_eq!(actual, expected);
-    // }
-    //
-    // #[test]
-    // fn test_try_to_json_number() {
-    //     let input = Value::Number(Number::Integer(42));
-    //     let expected = json!(42);
-    //     let actual = input.try_to_json().unwrap();
-    //     assert_eq!(actual, expected);
-    //
-    //     let input = Value::Number(Number::Float(3.33));
-    //     let expected = json!(3.33);
-    //     let actual = input.try_to_json().unwrap();
-    //     assert_eq!(actual, expected);
-    //
-    //     let input = Value::Number(Number::BigInteger("10000000000000000365".to_string()));
-    //     let expected: serde_json::Value = serde_json::from_str("10000000000000000365").unwrap();
-    //     let actual = input.try_to_json().unwrap();
-    //     assert_eq!(actual, expected);
-    // }
-    //
-    // #[test]
-    // fn test_try_to_json_list() {
-    //     let input = Value::List(vec![
-    //         Value::String("foo".to_string()),
-    //         Value::String("bar".to_string()),
-    //         Value::String("baz".to_string()),
-    //     ]);
-    //     let expected = json!(["foo", "bar", "baz"]);
-    //     let actual = input.try_to_json().unwrap();
-    //     assert_eq!(actual, expected);
-    // }
-    //
-    // #[test]
-    // fn test_try_to_json_object() {
-    //     let input = Value::Object(vec![
-    //         ("name".to_string(), Value::String("bob".to_string())),
-    //         ("age".to_string(), Value::Number(Number::Integer(33))),
-    //     ]);
-    //     let expected = json!({
-    //         "name": "bob",
-    //         "age": 33
-    //     });
-    //     let actual = input.try_to_json().unwrap();
-    //     assert_eq!(actual, expected);
-    // }
+    #[test]
+    fn eval_filter_jsonpath_structured_value() {
+        let variables = VariableSet::new();
+
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::JsonPath {
+                expr: Template::new(
+                    Some('"'),
+                    vec![TemplateElement::String {
+                        value: "$.user.id".to_string(),
+                        source: "$.user.id".to_source(),
+                    }],
+                    SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                ),
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+            },
+        };
+        let value = Value::Object(vec![(
+            "user".to_string(),
+            Value::Object(vec![("id".to_string(), Value::Number(Number::Integer(7)))]),
+        )]);
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &value,
+                &variables,
+                false,
+                &FilterOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Integer(7))
+        );
+    }
+
+    #[test]
+    fn test_try_to_json_bool() {
+        let input = Value::Bool(true);
+        let expected = json!(true);
+        let actual = input.try_to_json().unwrap();
+        assert_eq!(actual, expected);
+
+        let input = Value::Bool(false);
+        let expected = serde_json::Value::Bool(false);
+        let actual = input.try_to_json().unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_try_to_json_number() {
+        let input = Value::Number(Number::Integer(42));
+        let expected = json!(42);
+        let actual = input.try_to_json().unwrap();
+        assert_eq!(actual, expected);
+
+        let input = Value::Number(Number::Float(3.33));
+        let expected = json!(3.33);
+        let actual = input.try_to_json().unwrap();
+        assert_eq!(actual, expected);
+
+        let input = Value::Number(Number::BigInteger("10000000000000000365".to_string()));
+        let expected: serde_json::Value = serde_json::from_str("10000000000000000365").unwrap();
+        let actual = input.try_to_json().unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_try_to_json_list() {
+        let input = Value::List(vec![
+            Value::String("foo".to_string()),
+            Value::String("bar".to_string()),
+            Value::String("baz".to_string()),
+        ]);
+        let expected = json!(["foo", "bar", "baz"]);
+        let actual = input.try_to_json().unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_try_to_json_object() {
+        let input = Value::Object(vec![
+            ("name".to_string(), Value::String("bob".to_string())),
+            ("age".to_string(), Value::Number(Number::Integer(33))),
+        ]);
+        let expected = json!({
+            "name": "bob",
+            "age": 33
+        });
+        let actual = input.try_to_json().unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_try_to_json_http_response() {
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Location", "/bar"));
+        headers.push(Header::new("Set-Cookie", "a=1"));
+        headers.push(Header::new("Set-Cookie", "b=2"));
+        let input = Value::HttpResponse(Box::new(HttpResponse::new(
+            Url::from_str("http://localhost/foo").unwrap(),
+            301,
+            headers,
+            Some(Url::from_str("http://localhost/bar").unwrap()),
+        )));
+        let expected = json!({
+            "url": "http://localhost/foo",
+            "status": 301,
+            "location": "http://localhost/bar",
+            "headers": {
+                "Location": "/bar",
+                "Set-Cookie": "a=1, b=2"
+            }
+        });
+        let actual = input.try_to_json().unwrap();
+        assert_eq!(actual, expected);
+    }
 }
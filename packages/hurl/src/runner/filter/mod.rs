@@ -20,10 +20,15 @@ pub use eval::{FilterOptions, eval_filters};
 pub use jsonpath::eval_jsonpath_json;
 pub use xpath::eval_xpath_doc;
 
+mod add_days;
+mod add_seconds;
 mod base64_decode;
 mod base64_encode;
 mod base64_url_safe_decode;
 mod base64_url_safe_encode;
+mod brotli_decompress;
+mod bytes_slice;
+mod canonicalize;
 mod charset_decode;
 mod count;
 mod days_after_now;
@@ -31,12 +36,17 @@ mod days_before_now;
 mod eval;
 mod first;
 mod format;
+mod from_hex;
+mod gunzip;
 mod html_escape;
 mod html_unescape;
+mod jq;
 mod jsonpath;
+mod jwt;
 mod last;
 mod location;
 mod nth;
+mod parse_json;
 mod regex;
 mod replace;
 mod replace_regex;
@@ -45,10 +55,15 @@ mod to_date;
 mod to_float;
 mod to_hex;
 mod to_int;
+mod to_json;
 mod to_string;
+mod to_timestamp;
 mod url_decode;
 mod url_encode;
 mod url_query_param;
 mod utf8_decode;
 mod utf8_encode;
+mod xml_escape;
+mod xml_unescape;
 mod xpath;
+mod zstd_decompress;
@@ -0,0 +1,132 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Parses a JSON string `value` to a structured value (object, list, string, number etc...).
+pub fn eval_parse_json(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(text) => match serde_json::from_str::<serde_json::Value>(text) {
+            Ok(json) => Ok(Some(Value::from_json(&json))),
+            Err(_) => {
+                let kind = RunnerErrorKind::FilterInvalidInputValue(
+                    "value is not a valid JSON".to_string(),
+                );
+                Err(RunnerError::new(source_info, kind, assert))
+            }
+        },
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInputType {
+                actual: v.kind().to_string(),
+                expected: "string".to_string(),
+            };
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::filter::eval::{FilterOptions, eval_filter};
+    use crate::runner::{Number, VariableSet};
+
+    #[test]
+    fn eval_filter_parse_json_ok() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::ParseJson,
+        };
+
+        let ret = eval_filter(
+            &filter,
+            &Value::String(r#"{"name":"Bob","tags":["a","b"],"age":42}"#.to_string()),
+            &variables,
+            false,
+            &FilterOptions::default(),
+        );
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::Object(vec![
+                ("age".to_string(), Value::Number(Number::Integer(42))),
+                ("name".to_string(), Value::String("Bob".to_string())),
+                (
+                    "tags".to_string(),
+                    Value::List(vec![
+                        Value::String("a".to_string()),
+                        Value::String("b".to_string())
+                    ])
+                ),
+            ])
+        );
+
+        let ret = eval_filter(
+            &filter,
+            &Value::String("true".to_string()),
+            &variables,
+            false,
+            &FilterOptions::default(),
+        );
+        assert_eq!(ret.unwrap().unwrap(), Value::Bool(true));
+    }
+
+    #[test]
+    fn eval_filter_parse_json_ko() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::ParseJson,
+        };
+
+        let ret = eval_filter(
+            &filter,
+            &Value::String("{\"name\":".to_string()),
+            &variables,
+            false,
+            &FilterOptions::default(),
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInputValue("value is not a valid JSON".to_string())
+        );
+
+        let ret = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            false,
+            &FilterOptions::default(),
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInputType {
+                actual: "boolean".to_string(),
+                expected: "string".to_string()
+            }
+        );
+    }
+}
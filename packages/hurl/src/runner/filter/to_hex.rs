@@ -15,18 +15,40 @@
  * limitations under the License.
  *
  */
-use hurl_core::ast::SourceInfo;
+use hurl_core::ast::{HexCase, SourceInfo, Template};
 
-use crate::runner::{RunnerError, RunnerErrorKind, Value, hex};
+use crate::runner::template::eval_template;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet, hex};
 
 /// Converts bytes `value` to hexadecimal string.
+///
+/// Digits are lowercase unless `case` is [`HexCase::Upper`], and bytes are joined with an optional
+/// `separator` (for instance `"de:ad:be:ef"`).
 pub fn eval_to_hex(
     value: &Value,
+    variables: &VariableSet,
     source_info: SourceInfo,
     assert: bool,
+    case: Option<HexCase>,
+    separator: Option<&Template>,
 ) -> Result<Option<Value>, RunnerError> {
     match value {
-        Value::Bytes(value) => Ok(Some(Value::String(hex::encode(value)))),
+        Value::Bytes(value) => {
+            let mut s = hex::encode(value);
+            if case == Some(HexCase::Upper) {
+                s.make_ascii_uppercase();
+            }
+            if let Some(separator) = separator {
+                let separator = eval_template(separator, variables)?;
+                s = s
+                    .as_bytes()
+                    .chunks(2)
+                    .map(|pair| String::from_utf8_lossy(pair))
+                    .collect::<Vec<_>>()
+                    .join(&separator);
+            }
+            Ok(Some(Value::String(s)))
+        }
         v => {
             let kind = RunnerErrorKind::FilterInvalidInputType {
                 actual: v.kind().to_string(),
@@ -39,8 +61,9 @@ pub fn eval_to_hex(
 
 #[cfg(test)]
 mod tests {
-    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, TemplateElement, Whitespace};
     use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
 
     use super::*;
     use crate::runner::VariableSet;
@@ -51,7 +74,10 @@ mod tests {
         let variables = VariableSet::new();
         let filter = Filter {
             source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
-            value: FilterValue::ToHex,
+            value: FilterValue::ToHex {
+                case: None,
+                separator: None,
+            },
         };
         let bytes = vec![0x3c, 0x3c, 0x3f, 0x3f, 0x3f, 0x3e, 0x3e];
 
@@ -68,12 +94,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn eval_filter_to_hex_with_case_and_separator() {
+        let variables = VariableSet::new();
+        let whitespace = Whitespace {
+            value: " ".to_string(),
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+        };
+        let separator = Template::new(
+            Some('"'),
+            vec![TemplateElement::String {
+                value: ":".to_string(),
+                source: ":".to_source(),
+            }],
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+        );
+        let bytes = Value::Bytes(vec![0xde, 0xad, 0xbe, 0xef]);
+
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::ToHex {
+                case: Some((whitespace.clone(), HexCase::Upper)),
+                separator: None,
+            },
+        };
+        let ret = eval_filter(
+            &filter,
+            &bytes,
+            &variables,
+            false,
+            &FilterOptions::default(),
+        );
+        assert_eq!(ret.unwrap().unwrap(), Value::String("DEADBEEF".to_string()));
+
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::ToHex {
+                case: Some((whitespace.clone(), HexCase::Lower)),
+                separator: Some((whitespace, separator)),
+            },
+        };
+        let ret = eval_filter(
+            &filter,
+            &bytes,
+            &variables,
+            false,
+            &FilterOptions::default(),
+        );
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::String("de:ad:be:ef".to_string())
+        );
+    }
+
     #[test]
     fn eval_filter_to_hex_ko_invalid_input() {
         let variables = VariableSet::new();
         let filter = Filter {
             source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
-            value: FilterValue::ToHex,
+            value: FilterValue::ToHex {
+                case: None,
+                separator: None,
+            },
         };
 
         let ret = eval_filter(
@@ -0,0 +1,112 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Serializes `value` to a JSON string.
+pub fn eval_to_json(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value.try_to_json() {
+        Some(json) => Ok(Some(Value::String(json.to_string()))),
+        None => {
+            let kind = RunnerErrorKind::FilterInvalidInputValue(format!(
+                "{} can not be converted to JSON",
+                value.repr()
+            ));
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::filter::eval::{FilterOptions, eval_filter};
+    use crate::runner::{Number, VariableSet};
+
+    #[test]
+    fn eval_filter_to_json_ok() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::ToJson,
+        };
+
+        let ret = eval_filter(
+            &filter,
+            &Value::Object(vec![
+                ("name".to_string(), Value::String("Bob".to_string())),
+                (
+                    "ids".to_string(),
+                    Value::List(vec![
+                        Value::Number(Number::Integer(1)),
+                        Value::Number(Number::Float(2.5)),
+                        Value::Null,
+                    ]),
+                ),
+            ]),
+            &variables,
+            false,
+            &FilterOptions::default(),
+        );
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::String(r#"{"ids":[1,2.5,null],"name":"Bob"}"#.to_string())
+        );
+
+        let ret = eval_filter(
+            &filter,
+            &Value::String("Hello \"World\"".to_string()),
+            &variables,
+            false,
+            &FilterOptions::default(),
+        );
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::String(r#""Hello \"World\"""#.to_string())
+        );
+    }
+
+    #[test]
+    fn eval_filter_to_json_ko() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::ToJson,
+        };
+
+        let ret = eval_filter(
+            &filter,
+            &Value::Bytes(vec![1, 2, 3]),
+            &variables,
+            false,
+            &FilterOptions::default(),
+        );
+        assert!(matches!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInputValue(_)
+        ));
+    }
+}
@@ -0,0 +1,89 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value};
+
+/// Returns the number of seconds since the Unix epoch of a date `value`.
+pub fn eval_to_timestamp(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::Date(date) => Ok(Some(Value::Number(Number::Integer(date.timestamp())))),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInputType {
+                actual: v.kind().to_string(),
+                expected: "date".to_string(),
+            };
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::VariableSet;
+    use crate::runner::filter::eval::{FilterOptions, eval_filter};
+
+    #[test]
+    fn eval_filter_to_timestamp() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 12)),
+            value: FilterValue::ToTimestamp,
+        };
+        let date = DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Date(date),
+                &variables,
+                false,
+                &FilterOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Integer(1767225600))
+        );
+
+        let error = eval_filter(
+            &filter,
+            &Value::String("2026-01-01".to_string()),
+            &variables,
+            false,
+            &FilterOptions::default(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInputType {
+                actual: "string".to_string(),
+                expected: "date".to_string(),
+            }
+        );
+    }
+}
@@ -0,0 +1,98 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Converts the characters `&`, `<`, `>`, `"` and `'` in `value` to their XML predefined entities.
+pub fn eval_xml_escape(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(value) => {
+            let encoded = xml_escape(value);
+            Ok(Some(Value::String(encoded)))
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInputType {
+                actual: v.kind().to_string(),
+                expected: "string".to_string(),
+            };
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+fn xml_escape(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&apos;"),
+            _ => output.push(c),
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::{FilterOptions, eval_filter};
+    use crate::runner::{Value, VariableSet};
+
+    #[test]
+    fn eval_filter_xml_escape() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::XmlEscape,
+        };
+
+        let tests = [
+            ("foo", "foo"),
+            ("<tag>", "&lt;tag&gt;"),
+            ("foo & bar", "foo &amp; bar"),
+            (
+                r#"<a title="It's">café</a>"#,
+                "&lt;a title=&quot;It&apos;s&quot;&gt;café&lt;/a&gt;",
+            ),
+        ];
+        for (input, output) in tests.iter() {
+            assert_eq!(
+                eval_filter(
+                    &filter,
+                    &Value::String(input.to_string()),
+                    &variables,
+                    false,
+                    &FilterOptions::default()
+                )
+                .unwrap()
+                .unwrap(),
+                Value::String(output.to_string())
+            );
+        }
+    }
+}
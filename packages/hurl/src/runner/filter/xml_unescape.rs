@@ -0,0 +1,131 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Converts the XML predefined entities (e.g. &lt;, &apos;) and numeric character references
+/// (e.g. &#62;, &#x3e;) in `value` to the corresponding Unicode characters.
+///
+/// Unknown entities are left unchanged.
+pub fn eval_xml_unescape(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(value) => {
+            let decoded = xml_unescape(value);
+            Ok(Some(Value::String(decoded)))
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInputType {
+                actual: v.kind().to_string(),
+                expected: "string".to_string(),
+            };
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+fn xml_unescape(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest
+            .find(';')
+            .and_then(|end| decode_reference(&rest[1..end]).map(|c| (c, end)));
+        match decoded {
+            Some((c, end)) => {
+                output.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                output.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Decodes the entity or character reference `name` (the text between `&` and `;`).
+fn decode_reference(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        _ => {
+            let code = if let Some(hex) = name.strip_prefix("#x") {
+                u32::from_str_radix(hex, 16).ok()?
+            } else if let Some(dec) = name.strip_prefix('#') {
+                dec.parse::<u32>().ok()?
+            } else {
+                return None;
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::{FilterOptions, eval_filter};
+    use crate::runner::{Value, VariableSet};
+
+    #[test]
+    fn eval_filter_xml_unescape() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::XmlUnescape,
+        };
+
+        let tests = [
+            ("foo", "foo"),
+            ("&lt;tag&gt;", "<tag>"),
+            ("foo &amp; bar", "foo & bar"),
+            ("&quot;It&apos;s&quot;", "\"It's\""),
+            ("&#62;&#x3e;&#x1F600;", ">>😀"),
+            ("&nbsp; &foo &#xZZ; &", "&nbsp; &foo &#xZZ; &"),
+            ("&amp;lt;", "&lt;"),
+        ];
+        for (input, output) in tests.iter() {
+            assert_eq!(
+                eval_filter(
+                    &filter,
+                    &Value::String(input.to_string()),
+                    &variables,
+                    false,
+                    &FilterOptions::default()
+                )
+                .unwrap()
+                .unwrap(),
+                Value::String(output.to_string())
+            );
+        }
+    }
+}
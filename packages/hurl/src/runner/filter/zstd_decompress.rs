@@ -0,0 +1,91 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Decompresses Zstandard compressed bytes `value`.
+pub fn eval_zstd_decompress(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::Bytes(value) => match decompress(value) {
+            Some(decompressed) => Ok(Some(Value::Bytes(decompressed))),
+            None => {
+                let kind = RunnerErrorKind::FilterDecode("zstd".to_string());
+                Err(RunnerError::new(source_info, kind, assert))
+            }
+        },
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInputType {
+                actual: v.kind().to_string(),
+                expected: "bytes".to_string(),
+            };
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Decompresses Zstandard `data`, returns `None` if `data` is not valid Zstandard data.
+fn decompress(data: &[u8]) -> Option<Vec<u8>> {
+    zstd::stream::decode_all(data).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::Number;
+
+    #[test]
+    fn eval_filter_zstd_decompress_ok() {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10));
+        // `echo -n "Hello World!" | zstd`
+        let bytes = vec![
+            0x28, 0xb5, 0x2f, 0xfd, 0x04, 0x58, 0x61, 0x00, 0x00, 0x48, 0x65, 0x6c, 0x6c, 0x6f,
+            0x20, 0x57, 0x6f, 0x72, 0x6c, 0x64, 0x21, 0x91, 0x4d, 0x7f, 0x3e,
+        ];
+        let ret = eval_zstd_decompress(&Value::Bytes(bytes), source_info, false);
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::Bytes(b"Hello World!".to_vec())
+        );
+    }
+
+    #[test]
+    fn eval_filter_zstd_decompress_ko() {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10));
+        let ret = eval_zstd_decompress(&Value::Bytes(b"Hello World!".to_vec()), source_info, false);
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterDecode("zstd".to_string())
+        );
+
+        let ret = eval_zstd_decompress(&Value::Number(Number::Integer(42)), source_info, false);
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInputType {
+                actual: "integer".to_string(),
+                expected: "bytes".to_string(),
+            }
+        );
+    }
+}
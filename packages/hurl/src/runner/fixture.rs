@@ -0,0 +1,213 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Content-addressable fixture store.
+//!
+//! Large binary fixtures can be referenced in file nodes by their SHA-256 digest instead of a
+//! path: `file,sha256:<hash>;`. These fixtures are resolved from a fixtures directory, where each
+//! fixture is stored in a file named after its digest. If a fixture is missing, it can be
+//! downloaded from the URL listed for its digest in the `manifest.json` file of the fixtures
+//! directory:
+//!
+//! ```json
+//! {
+//!   "4e1243bd22c66e76c2ba9eddc1f91394e57f9f83...": "https://example.org/fixtures/video.mp4"
+//! }
+//! ```
+//!
+//! The digest of a fixture is always verified, so a run is reproducible even if fixtures are not
+//! versioned alongside the Hurl files.
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use curl::easy::Easy;
+use sha2::{Digest, Sha256};
+
+use super::error::RunnerErrorKind;
+use super::hex;
+
+/// Name of the manifest file listing fixtures download URLs, in the fixtures directory.
+pub const MANIFEST_FILE_NAME: &str = "manifest.json";
+
+/// Returns the SHA-256 digest referenced by a file name like `sha256:<hash>`, or `None` if
+/// `filename` is a regular file name.
+pub fn parse_reference(filename: &str) -> Option<&str> {
+    let hash = filename.strip_prefix("sha256:")?;
+    let is_digest = hash.len() == 64
+        && hash
+            .chars()
+            .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c));
+    is_digest.then_some(hash)
+}
+
+/// Resolves the fixture of SHA-256 digest `hash` from the `fixtures_dir` directory, and returns
+/// its path and content.
+///
+/// If the fixture is not in the fixtures directory, and `allow_download` is true, it is downloaded from
+/// the URL listed in the fixtures manifest and saved in the fixtures directory.
+pub fn resolve(
+    hash: &str,
+    fixtures_dir: Option<&Path>,
+    allow_download: bool,
+) -> Result<(PathBuf, Vec<u8>), RunnerErrorKind> {
+    let error = |message: String| RunnerErrorKind::FixtureResolution {
+        hash: hash.to_string(),
+        message,
+    };
+    let Some(fixtures_dir) = fixtures_dir else {
+        return Err(error(
+            "no fixtures directory, check --fixtures-dir option".to_string(),
+        ));
+    };
+
+    let path = fixtures_dir.join(hash);
+    if path.is_file() {
+        let data = fs::read(&path)
+            .map_err(|e| error(format!("{} can not be read ({e})", path.display())))?;
+        check_digest(hash, &data).map_err(error)?;
+        return Ok((path, data));
+    }
+
+    if !allow_download {
+        return Err(error(format!(
+            "fixture not found in {}, fixtures can not be downloaded with --sandbox option",
+            fixtures_dir.display()
+        )));
+    }
+
+    // The fixture is downloaded and verified before being stored, so the store never holds
+    // corrupted fixtures.
+    let url = manifest_url(hash, fixtures_dir).map_err(error)?;
+    let data = download(&url).map_err(|e| error(format!("download from <{url}> failed: {e}")))?;
+    check_digest(hash, &data).map_err(error)?;
+    fs::write(&path, &data)
+        .map_err(|e| error(format!("{} can not be written ({e})", path.display())))?;
+    Ok((path, data))
+}
+
+/// Returns the download URL of the fixture `hash`, listed in the manifest of `fixtures_dir`.
+fn manifest_url(hash: &str, fixtures_dir: &Path) -> Result<String, String> {
+    let manifest = fixtures_dir.join(MANIFEST_FILE_NAME);
+    if !manifest.is_file() {
+        return Err(format!("fixture not found in {}", fixtures_dir.display()));
+    }
+    let content =
+        fs::read(&manifest).map_err(|e| format!("{} can not be read ({e})", manifest.display()))?;
+    let urls = serde_json::from_slice::<HashMap<String, String>>(&content)
+        .map_err(|e| format!("{} is not a valid manifest ({e})", manifest.display()))?;
+    urls.get(hash).cloned().ok_or_else(|| {
+        format!(
+            "fixture not found in {} and not listed in {}",
+            fixtures_dir.display(),
+            manifest.display()
+        )
+    })
+}
+
+/// Downloads the content of `url`.
+fn download(url: &str) -> Result<Vec<u8>, String> {
+    let mut data = vec![];
+    let mut easy = Easy::new();
+    easy.url(url).map_err(|e| e.to_string())?;
+    easy.follow_location(true).map_err(|e| e.to_string())?;
+    {
+        let mut transfer = easy.transfer();
+        transfer
+            .write_function(|chunk| {
+                data.extend_from_slice(chunk);
+                Ok(chunk.len())
+            })
+            .map_err(|e| e.to_string())?;
+        transfer.perform().map_err(|e| e.to_string())?;
+    }
+    let status = easy.response_code().map_err(|e| e.to_string())?;
+    if !(200..300).contains(&status) {
+        return Err(format!("unexpected HTTP status {status}"));
+    }
+    Ok(data)
+}
+
+/// Checks that the SHA-256 digest of `data` is `hash`.
+fn check_digest(hash: &str, data: &[u8]) -> Result<(), String> {
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    let actual = hex::encode(&hasher.finalize()[..]);
+    if actual == hash {
+        Ok(())
+    } else {
+        Err(format!("content digest is sha256:{actual}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // SHA-256 digest of "Hello World!".
+    const HELLO_HASH: &str = "7f83b1657ff1fc53b92dc18148a1d65dfc2d4b1fa3d677284addd200126d9069";
+
+    #[test]
+    fn test_parse_reference() {
+        assert_eq!(
+            parse_reference(&format!("sha256:{HELLO_HASH}")),
+            Some(HELLO_HASH)
+        );
+        assert_eq!(parse_reference("data.bin"), None);
+        assert_eq!(parse_reference("sha256:7f83b1"), None);
+        assert_eq!(
+            parse_reference(&format!("sha256:{}", HELLO_HASH.to_uppercase())),
+            None
+        );
+    }
+
+    #[test]
+    fn test_resolve() {
+        let dir = std::env::temp_dir().join("hurl-test-fixtures");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(HELLO_HASH), b"Hello World!").unwrap();
+        let (path, data) = resolve(HELLO_HASH, Some(&dir), true).unwrap();
+        assert_eq!(path, dir.join(HELLO_HASH));
+        assert_eq!(data, b"Hello World!");
+
+        // A fixture whose content doesn't match its digest is rejected.
+        let hash = "0".repeat(64);
+        fs::write(dir.join(&hash), b"Hello World!").unwrap();
+        assert_eq!(
+            resolve(&hash, Some(&dir), true).unwrap_err(),
+            RunnerErrorKind::FixtureResolution {
+                hash: hash.clone(),
+                message: format!("content digest is sha256:{HELLO_HASH}"),
+            }
+        );
+
+        assert!(resolve(HELLO_HASH, None, true).is_err());
+
+        // A missing fixture is not downloaded if downloads are disabled.
+        let hash = "1".repeat(64);
+        assert_eq!(
+            resolve(&hash, Some(&dir), false).unwrap_err(),
+            RunnerErrorKind::FixtureResolution {
+                hash: hash.clone(),
+                message: format!(
+                    "fixture not found in {}, fixtures can not be downloaded with --sandbox option",
+                    dir.display()
+                ),
+            }
+        );
+    }
+}
@@ -0,0 +1,168 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Encoding of the form params names, with arrays and nested keys.
+//!
+//! ```hurl
+//! POST https://example.org/users
+//! [Form]
+//! user[name]: Bob
+//! user[address][city]: Paris
+//! tag: a
+//! tag: b
+//! ```
+//!
+//! is sent as `user[name]=Bob&user[address][city]=Paris&tag[]=a&tag[]=b`.
+use std::collections::HashMap;
+
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+
+use crate::http::Param;
+
+/// Characters that are percent-encoded in a form param name: everything except the unreserved
+/// characters of RFC 3986, as libcurl does for the form param values.
+const NAME_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Returns the form `params` with percent-encoded names, keeping the brackets of nested keys.
+///
+/// Names repeated in `params` are sent as arrays: a `[]` suffix is added to each of them.
+pub fn encode_form_names(params: &[Param]) -> Vec<Param> {
+    let mut counts = HashMap::new();
+    for param in params {
+        *counts.entry(param.name.as_str()).or_insert(0) += 1;
+    }
+    params
+        .iter()
+        .map(|param| {
+            let mut name = encode_name(&param.name);
+            if counts[param.name.as_str()] > 1 && !param.name.ends_with("[]") {
+                name.push_str("[]");
+            }
+            Param {
+                name,
+                value: param.value.clone(),
+            }
+        })
+        .collect()
+}
+
+/// Percent-encodes a form param `name`.
+///
+/// A nested key like `user[address][city]` is encoded segment by segment, so brackets are kept.
+/// A name that is not a valid nested key is encoded as a whole.
+fn encode_name(name: &str) -> String {
+    match split_nested_key(name) {
+        Some((base, keys)) => {
+            let mut encoded = encode(base);
+            for key in keys {
+                encoded.push('[');
+                encoded.push_str(&encode(key));
+                encoded.push(']');
+            }
+            encoded
+        }
+        None => encode(name),
+    }
+}
+
+/// Splits a nested key `name` (like `user[address][city]`) into its base (`user`) and its keys
+/// (`address`, `city`).
+///
+/// Returns `None` if `name` is not a nested key.
+fn split_nested_key(name: &str) -> Option<(&str, Vec<&str>)> {
+    let start = name.find('[')?;
+    let base = &name[..start];
+    if base.is_empty() || base.contains(']') {
+        return None;
+    }
+    let mut keys = vec![];
+    let mut rest = &name[start..];
+    while !rest.is_empty() {
+        let inner = rest.strip_prefix('[')?;
+        let end = inner.find(']')?;
+        let key = &inner[..end];
+        if key.contains('[') {
+            return None;
+        }
+        keys.push(key);
+        rest = &inner[end + 1..];
+    }
+    Some((base, keys))
+}
+
+fn encode(s: &str) -> String {
+    utf8_percent_encode(s, NAME_ENCODE_SET).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_form_names() {
+        let params = vec![
+            Param::new("user[name]", "Bob"),
+            Param::new("user[address][city]", "Paris"),
+            Param::new("tag", "a"),
+            Param::new("tag", "b"),
+            Param::new("ids[]", "1"),
+            Param::new("ids[]", "2"),
+            Param::new("first name", "Bob"),
+        ];
+        assert_eq!(
+            encode_form_names(&params),
+            vec![
+                Param::new("user[name]", "Bob"),
+                Param::new("user[address][city]", "Paris"),
+                Param::new("tag[]", "a"),
+                Param::new("tag[]", "b"),
+                Param::new("ids[]", "1"),
+                Param::new("ids[]", "2"),
+                Param::new("first%20name", "Bob"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_encode_name() {
+        assert_eq!(encode_name("param1"), "param1");
+        assert_eq!(encode_name("a=b&c"), "a%3Db%26c");
+        assert_eq!(encode_name("values[0]"), "values[0]");
+        assert_eq!(encode_name("user[first name]"), "user[first%20name]");
+        assert_eq!(encode_name("café[ville]"), "caf%C3%A9[ville]");
+        // Not nested keys
+        assert_eq!(encode_name("[a]"), "%5Ba%5D");
+        assert_eq!(encode_name("a[b"), "a%5Bb");
+        assert_eq!(encode_name("a[b]c"), "a%5Bb%5Dc");
+    }
+
+    #[test]
+    fn test_split_nested_key() {
+        assert_eq!(split_nested_key("user"), None);
+        assert_eq!(split_nested_key("user[name]"), Some(("user", vec!["name"])));
+        assert_eq!(
+            split_nested_key("user[address][city]"),
+            Some(("user", vec!["address", "city"]))
+        );
+        assert_eq!(split_nested_key("tag[]"), Some(("tag", vec![""])));
+        assert_eq!(split_nested_key("a[[b]]"), None);
+    }
+}
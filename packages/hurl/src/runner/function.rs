@@ -15,12 +15,99 @@
  * limitations under the License.
  *
  */
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Write;
+use std::sync::Arc;
+
 use chrono::Utc;
-use hurl_core::ast::Function;
+use hurl_core::ast::{Function, FunctionCall, FunctionName, SourceInfo};
 use uuid::Uuid;
 
-use super::error::RunnerError;
+use super::error::{RunnerError, RunnerErrorKind};
+use super::number::Number;
 use super::value::Value;
+use super::variable::VariableSet;
+
+/// A function that can be called from a Hurl expression, like `{{slugify(title)}}`.
+///
+/// Custom functions are registered in a [`VariableSet`] with [`VariableSet::insert_function`],
+/// and can be used to generate test data per request. Any closure taking a slice of [`Value`]
+/// arguments and returning a `Result<Value, String>` is a custom function.
+///
+/// # Example
+///
+/// ```
+/// use hurl::runner::{Value, VariableSet};
+///
+/// let mut variables = VariableSet::new();
+/// variables.insert_function("upper", |args: &[Value]| match args {
+///     [Value::String(s)] => Ok(Value::String(s.to_uppercase())),
+///     _ => Err("expecting a string argument".to_string()),
+/// });
+/// ```
+pub trait CustomFunction: Send + Sync {
+    /// Calls this function with the evaluated `arguments`, returns a [`Value`] on success or an
+    /// error message.
+    fn call(&self, arguments: &[Value]) -> Result<Value, String>;
+}
+
+impl<F> CustomFunction for F
+where
+    F: Fn(&[Value]) -> Result<Value, String> + Send + Sync,
+{
+    fn call(&self, arguments: &[Value]) -> Result<Value, String> {
+        self(arguments)
+    }
+}
+
+/// A set of custom functions, indexed by name.
+#[derive(Clone, Default)]
+pub struct FunctionSet {
+    functions: HashMap<String, Arc<dyn CustomFunction>>,
+}
+
+impl FunctionSet {
+    /// Inserts a custom `function` named `name`, replacing any function with the same name.
+    pub fn insert(&mut self, name: &str, function: Arc<dyn CustomFunction>) {
+        self.functions.insert(name.to_string(), function);
+    }
+
+    /// Returns the function named `name`.
+    pub fn get(&self, name: &str) -> Option<&dyn CustomFunction> {
+        self.functions.get(name).map(|f| f.as_ref())
+    }
+
+    /// Inserts all the functions of `other` into this set.
+    pub fn extend(&mut self, other: &FunctionSet) {
+        for (name, function) in other.functions.iter() {
+            self.functions.insert(name.clone(), function.clone());
+        }
+    }
+}
+
+impl fmt::Debug for FunctionSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut names = self.functions.keys().collect::<Vec<_>>();
+        names.sort();
+        f.debug_set().entries(names).finish()
+    }
+}
+
+/// Two sets are equal if they share the same functions under the same names.
+impl PartialEq for FunctionSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.functions.len() == other.functions.len()
+            && self.functions.iter().all(|(name, function)| {
+                other
+                    .functions
+                    .get(name)
+                    .is_some_and(|other| Arc::ptr_eq(function, other))
+            })
+    }
+}
+
+impl Eq for FunctionSet {}
 
 /// Evaluates the function `function`, returns a [`Value`] on success or an [`RunnerError`] .
 pub fn eval(function: &Function) -> Result<Value, RunnerError> {
@@ -35,3 +122,255 @@ pub fn eval(function: &Function) -> Result<Value, RunnerError> {
         }
     }
 }
+
+/// Evaluates the function call `call` with its evaluated `arguments`, returns a [`Value`] on
+/// success or an [`RunnerError`].
+///
+/// Builtin functions take precedence over the custom functions of `variables`.
+pub fn eval_call(
+    call: &FunctionCall,
+    arguments: &[Value],
+    variables: &VariableSet,
+    source_info: SourceInfo,
+) -> Result<Value, RunnerError> {
+    let result = match &call.name {
+        FunctionName::Now => now(arguments),
+        FunctionName::RandomInt => random_int(arguments),
+        FunctionName::Uuidv7 => uuidv7(arguments),
+        FunctionName::Custom(name) => match variables.function(name) {
+            Some(function) => function.call(arguments),
+            None => {
+                let kind = RunnerErrorKind::FunctionNotDefined { name: name.clone() };
+                return Err(RunnerError::new(source_info, kind, false));
+            }
+        },
+    };
+    result.map_err(|message| {
+        let kind = RunnerErrorKind::FunctionCall {
+            name: call.name.to_string(),
+            message,
+        };
+        RunnerError::new(source_info, kind, false)
+    })
+}
+
+/// Returns the current date, formatted with the optional `strftime` format argument.
+fn now(arguments: &[Value]) -> Result<Value, String> {
+    let now = Utc::now();
+    match arguments {
+        [] => Ok(Value::Date(now)),
+        [Value::String(format)] => {
+            let mut formatted = String::new();
+            match write!(formatted, "{}", now.format(format)) {
+                Ok(_) => Ok(Value::String(formatted)),
+                Err(_) => Err(format!("<{format}> format is not valid")),
+            }
+        }
+        [value] => Err(invalid_argument(1, value, "string")),
+        _ => Err(invalid_arguments_count(arguments, "0 or 1")),
+    }
+}
+
+/// Returns a random integer between the `min` and `max` arguments, inclusive.
+fn random_int(arguments: &[Value]) -> Result<Value, String> {
+    let (min, max) = match arguments {
+        [
+            Value::Number(Number::Integer(min)),
+            Value::Number(Number::Integer(max)),
+        ] => (*min, *max),
+        [Value::Number(Number::Integer(_)), value] => {
+            return Err(invalid_argument(2, value, "integer"));
+        }
+        [value, _] => return Err(invalid_argument(1, value, "integer")),
+        _ => return Err(invalid_arguments_count(arguments, "2")),
+    };
+    if min > max {
+        return Err(format!("min <{min}> is greater than max <{max}>"));
+    }
+    // A UUID v4 is made of 122 random bits: the modulo bias on a 64-bit range is negligible.
+    let random = Uuid::new_v4().as_u128();
+    let range = (i128::from(max) - i128::from(min) + 1) as u128;
+    let value = i128::from(min) + (random % range) as i128;
+    Ok(Value::Number(Number::Integer(value as i64)))
+}
+
+/// Returns a new UUID v7 string.
+fn uuidv7(arguments: &[Value]) -> Result<Value, String> {
+    if !arguments.is_empty() {
+        return Err(invalid_arguments_count(arguments, "0"));
+    }
+    Ok(Value::String(Uuid::now_v7().to_string()))
+}
+
+fn invalid_argument(index: usize, value: &Value, expected: &str) -> String {
+    format!(
+        "argument {index} must be {expected}, actual value is {}",
+        value.repr()
+    )
+}
+
+fn invalid_arguments_count(arguments: &[Value], expected: &str) -> String {
+    format!(
+        "expecting {expected} argument(s), actual number is {}",
+        arguments.len()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::reader::Pos;
+
+    use super::*;
+
+    fn call(name: &str) -> FunctionCall {
+        FunctionCall {
+            name: FunctionName::new(name),
+            arguments: vec![],
+        }
+    }
+
+    fn source_info() -> SourceInfo {
+        SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1))
+    }
+
+    fn integer(value: i64) -> Value {
+        Value::Number(Number::Integer(value))
+    }
+
+    #[test]
+    fn test_builtin_functions() {
+        let variables = VariableSet::new();
+
+        let value = eval_call(&call("uuidv7"), &[], &variables, source_info()).unwrap();
+        let Value::String(uuid) = value else {
+            panic!("expecting a string");
+        };
+        assert_eq!(Uuid::parse_str(&uuid).unwrap().get_version_num(), 7);
+
+        for _ in 0..100 {
+            let value = eval_call(
+                &call("randomInt"),
+                &[integer(-2), integer(2)],
+                &variables,
+                source_info(),
+            )
+            .unwrap();
+            let Value::Number(Number::Integer(value)) = value else {
+                panic!("expecting an integer");
+            };
+            assert!((-2..=2).contains(&value));
+        }
+        assert_eq!(
+            eval_call(
+                &call("randomInt"),
+                &[integer(i64::MIN), integer(i64::MIN)],
+                &variables,
+                source_info()
+            )
+            .unwrap(),
+            integer(i64::MIN)
+        );
+
+        assert!(matches!(
+            eval_call(&call("now"), &[], &variables, source_info()).unwrap(),
+            Value::Date(_)
+        ));
+        let value = eval_call(
+            &call("now"),
+            &[Value::String("%Y".to_string())],
+            &variables,
+            source_info(),
+        )
+        .unwrap();
+        assert_eq!(value, Value::String(Utc::now().format("%Y").to_string()));
+    }
+
+    #[test]
+    fn test_builtin_functions_error() {
+        let variables = VariableSet::new();
+
+        let error =
+            eval_call(&call("randomInt"), &[integer(1)], &variables, source_info()).unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FunctionCall {
+                name: "randomInt".to_string(),
+                message: "expecting 2 argument(s), actual number is 1".to_string(),
+            }
+        );
+
+        let error = eval_call(
+            &call("randomInt"),
+            &[integer(1), Value::String("10".to_string())],
+            &variables,
+            source_info(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FunctionCall {
+                name: "randomInt".to_string(),
+                message: "argument 2 must be integer, actual value is string <10>".to_string(),
+            }
+        );
+
+        let error = eval_call(
+            &call("randomInt"),
+            &[integer(10), integer(1)],
+            &variables,
+            source_info(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FunctionCall {
+                name: "randomInt".to_string(),
+                message: "min <10> is greater than max <1>".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_custom_functions() {
+        let mut variables = VariableSet::new();
+        variables.insert_function("upper", |args: &[Value]| match args {
+            [Value::String(s)] => Ok(Value::String(s.to_uppercase())),
+            _ => Err("expecting a string argument".to_string()),
+        });
+
+        assert_eq!(
+            eval_call(
+                &call("upper"),
+                &[Value::String("bob".to_string())],
+                &variables,
+                source_info()
+            )
+            .unwrap(),
+            Value::String("BOB".to_string())
+        );
+
+        let error = eval_call(&call("upper"), &[], &variables, source_info()).unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FunctionCall {
+                name: "upper".to_string(),
+                message: "expecting a string argument".to_string(),
+            }
+        );
+
+        let error = eval_call(&call("lower"), &[], &variables, source_info()).unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FunctionNotDefined {
+                name: "lower".to_string()
+            }
+        );
+
+        // Builtin functions can not be overridden.
+        variables.insert_function("uuidv7", |_: &[Value]| Ok(Value::Null));
+        assert_ne!(
+            eval_call(&call("uuidv7"), &[], &variables, source_info()).unwrap(),
+            Value::Null
+        );
+    }
+}
@@ -23,6 +23,24 @@ pub fn encode(bytes: &[u8]) -> String {
     BytesToHexChars::new(bytes).collect()
 }
 
+/// Decodes an hexadecimal string `s` to bytes, ignoring the case of digits.
+///
+/// Returns `None` if `s` has an odd length or contains a non hexadecimal digit.
+pub fn decode(s: &str) -> Option<Vec<u8>> {
+    let digits = s.as_bytes();
+    if !digits.len().is_multiple_of(2) {
+        return None;
+    }
+    digits
+        .chunks(2)
+        .map(|pair| {
+            let high = (pair[0] as char).to_digit(16)?;
+            let low = (pair[1] as char).to_digit(16)?;
+            Some((high * 16 + low) as u8)
+        })
+        .collect()
+}
+
 struct BytesToHexChars<'a> {
     inner: ::core::slice::Iter<'a, u8>,
     next: Option<char>,
@@ -77,4 +95,15 @@ mod tests {
         let expected = "c4e3bac3cac0bde7";
         assert_eq!(encode(&bytes), expected);
     }
+
+    #[test]
+    fn test_decode() {
+        let expected = vec![0xc4, 0xe3, 0xba, 0xc3, 0xca, 0xc0, 0xbd, 0xe7];
+        assert_eq!(decode("c4e3bac3cac0bde7").unwrap(), expected);
+        assert_eq!(decode("C4E3BAC3CAC0BDE7").unwrap(), expected);
+        assert_eq!(decode("").unwrap(), Vec::<u8>::new());
+        assert!(decode("c4e").is_none());
+        assert!(decode("zz").is_none());
+        assert!(decode("é1").is_none());
+    }
 }
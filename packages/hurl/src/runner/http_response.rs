@@ -17,21 +17,38 @@
  */
 use std::fmt::Display;
 
-use crate::http::Url;
+use crate::http::{HeaderVec, Url};
 
-/// Represents an HTTP request for `Value::HttpResponse`
+/// Represents an HTTP response for `Value::HttpResponse`
+///
+/// This is notably used to describe each hop of a redirection chain (see `redirects` query).
 #[derive(Clone, Debug)]
 pub struct HttpResponse {
+    /// Absolute URL of this HTTP response
+    url: Url,
     /// Absolute URL of redirection
     location: Option<Url>,
     /// Status code of the HTTP response
     status: u32,
+    /// Headers of the HTTP response
+    headers: HeaderVec,
 }
 
 impl HttpResponse {
-    /// Returns a new HTTP response, given an optional `location` of redirection and a `status` code.
-    pub fn new(location: Option<Url>, status: u32) -> Self {
-        HttpResponse { location, status }
+    /// Returns a new HTTP response, given its `url`, a `status` code, its `headers` and an
+    /// optional `location` of redirection.
+    pub fn new(url: Url, status: u32, headers: HeaderVec, location: Option<Url>) -> Self {
+        HttpResponse {
+            url,
+            location,
+            status,
+            headers,
+        }
+    }
+
+    /// Returns the URL of this HTTP response.
+    pub fn url(&self) -> &Url {
+        &self.url
     }
 
     /// Returns the (optional) redirection URL suggested by this HTTP response.
@@ -43,6 +60,11 @@ impl HttpResponse {
     pub fn status(&self) -> u32 {
         self.status
     }
+
+    /// Returns the headers of this HTTP response.
+    pub fn headers(&self) -> &HeaderVec {
+        &self.headers
+    }
 }
 
 impl Display for HttpResponse {
@@ -15,26 +15,36 @@
  * limitations under the License.
  *
  */
+use std::collections::HashSet;
+use std::fs;
+use std::sync::Arc;
 use std::thread;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use chrono::Utc;
-use hurl_core::ast::{Entry, OptionKind, SourceInfo};
+use hurl_core::ast::{
+    Entry, HurlFile, OptionKind, SectionValue, SourceInfo, Template, TemplateElement,
+};
 use hurl_core::error::{DisplaySourceError, OutputFormat};
-use hurl_core::input::Input;
+use hurl_core::input::{Input, InputKind};
 use hurl_core::parser;
-use hurl_core::types::{Count, Index};
+use hurl_core::types::{Count, Index, ToSource};
 
-use crate::http::{Call, Client, CredentialForwarding, FollowLocation};
-use crate::util::logger::{ErrorFormat, Logger, LoggerOptions};
+use crate::http::{Client, Cookie, CookieStore, CredentialForwarding, FollowLocation};
+use crate::util::logger::{Logger, LoggerOptions, Verbosity};
 use crate::util::term::{Stderr, Stdout, WriteMode};
 
-use super::event::EventListener;
+use super::defaults::entries_with_defaults;
+use super::error::{RunnerError, RunnerErrorKind};
+use super::event::{Event, EventListener};
+use super::include::{ResolvedEntry, resolve_entries};
+use super::oauth2::TokenCache;
 use super::options;
-use super::result::{EntryResult, HurlResult};
+use super::result::{EntryResult, HurlResult, SourceFile};
 use super::runner_options::RunnerOptions;
+use super::snapshot::{self, SnapshotStatus};
 use super::variable::VariableSet;
-use super::{Output, entry};
+use super::{Number, Output, Value, entry, event, replay};
 
 /// Runs a Hurl `content` and returns a [`HurlResult`] upon completion.
 ///
@@ -47,6 +57,10 @@ use super::{Output, entry};
 ///
 /// `filename` indicates an optional file source, used when displaying errors.
 ///
+/// A run can be aborted from another thread with a [`crate::util::cancel::CancellationToken`]
+/// set in `runner_options`: the in-flight HTTP transfer is aborted and a non successful
+/// [`HurlResult`] with the entries run so far is returned.
+///
 /// # Example
 ///
 /// ```
@@ -93,6 +107,49 @@ pub fn run(
     runner_options: &RunnerOptions,
     variables: &VariableSet,
     logger_options: &LoggerOptions,
+) -> Result<HurlResult, String> {
+    // Try to parse the content
+    let hurl_file = parser::parse_hurl_file_with_limits(content, runner_options.parser_limits);
+    let hurl_file = match hurl_file {
+        Ok(h) => h,
+        Err(error) => {
+            let stderr = Stderr::new(WriteMode::Immediate);
+            let secrets = variables.secrets();
+            let mut logger = Logger::new(logger_options, stderr, &secrets);
+            let filename = filename.map_or(String::new(), |f| f.to_string());
+            let message = error.render(
+                &filename,
+                content,
+                None,
+                OutputFormat::Terminal(logger.color),
+            );
+            logger.error_rich(&message);
+            return Err(error.description());
+        }
+    };
+
+    run_hurl_file(
+        &hurl_file,
+        content,
+        filename,
+        runner_options,
+        variables,
+        logger_options,
+    )
+}
+
+/// Runs a `hurl_file` parsed from `content` and returns a [`HurlResult`] upon completion.
+///
+/// This is the same as [`run`], for callers that need the parsed file before or after its run
+/// (to get its exported captures for instance) and don't want to parse it twice. An error is
+/// returned if the included files of `hurl_file` can't be resolved.
+pub fn run_hurl_file(
+    hurl_file: &HurlFile,
+    content: &str,
+    filename: Option<&Input>,
+    runner_options: &RunnerOptions,
+    variables: &VariableSet,
+    logger_options: &LoggerOptions,
 ) -> Result<HurlResult, String> {
     // In this method, we run Hurl content sequentially. Standard output and standard error messages
     // are written immediately (in parallel mode, we'll use buffered standard output and error).
@@ -104,10 +161,16 @@ pub fn run(
     let secrets = variables.secrets();
     let mut logger = Logger::new(logger_options, stderr, &secrets);
 
-    // Try to parse the content
-    let hurl_file = parser::parse_hurl_file(content);
-    let hurl_file = match hurl_file {
-        Ok(h) => h,
+    // Included Hurl files are resolved before running any entry.
+    let context_dir = &runner_options.context_dir;
+    let entries = match resolve_entries(
+        hurl_file,
+        filename,
+        context_dir,
+        runner_options.parser_limits,
+        variables,
+    ) {
+        Ok(entries) => entries,
         Err(error) => {
             let filename = filename.map_or(String::new(), |f| f.to_string());
             let message = error.render(
@@ -123,7 +186,7 @@ pub fn run(
 
     // Now, we have a syntactically correct HurlFile instance, we can run it.
     let result = run_entries(
-        &hurl_file.entries,
+        &entries,
         content,
         filename,
         runner_options,
@@ -141,21 +204,38 @@ pub fn run(
     Ok(result)
 }
 
+/// Returns the names of the captures exported by a `hurl_file`, with `export-capture` options.
+///
+/// Exported captures are published at the end of the file run, to the files run after it.
+pub fn exported_captures(hurl_file: &HurlFile) -> Vec<String> {
+    let mut names = vec![];
+    for entry in entries_with_defaults(hurl_file).iter() {
+        for option in entry.request.options() {
+            if let OptionKind::ExportCapture(name) = &option.kind
+                && !names.contains(name)
+            {
+                names.push(name.clone());
+            }
+        }
+    }
+    names
+}
+
 #[allow(clippy::too_many_arguments)]
 /// Runs a list of `entries` and returns a [`HurlResult`] upon completion.
 ///
 /// `content` is the original source content, used to construct `entries`. It is used to construct
-/// rich error messages with annotated source code.
-/// New entry run events are reported to `progress` and are usually used to display a progress bar
-/// in test mode.
+/// rich error messages with annotated source code (entries of included files use their own source).
+/// Run events are published to `logger` (which renders the entry preambles and errors) and to an
+/// optional `listener`, usually used to display a progress bar in test mode.
 pub fn run_entries(
-    entries: &[Entry],
+    entries: &[ResolvedEntry],
     content: &str,
     filename: Option<&Input>,
     runner_options: &RunnerOptions,
     variables: &VariableSet,
     stdout: &mut Stdout,
-    listener: Option<&dyn EventListener>,
+    mut listener: Option<&mut (dyn EventListener + '_)>,
     logger: &mut Logger,
 ) -> HurlResult {
     // Early returns for empty entries.
@@ -168,44 +248,81 @@ pub fn run_entries(
     }
 
     let mut http_client = Client::new();
+    let mut tokens = TokenCache::new();
     let mut entries_result = vec![];
     let mut variables = variables.clone();
     let mut current = Index::new(runner_options.from_entry.unwrap_or(1));
     let mut repeat_count = 0;
+    // Response durations of each repetition of the current entry, used by aggregate asserts.
+    let mut durations = vec![];
+    // With `paginate`, URL of the next page to run for the current entry, and count of pages run.
+    let mut next_page: Option<String> = None;
+    let mut page_count = 0;
     let last = Index::new(runner_options.to_entry.unwrap_or(entries.len()));
+    // Response bodies are only kept for the entries whose body is referenced by the run.
+    let referenced_bodies = referenced_entry_bodies(content, entries);
     let default_verbosity = logger.verbosity;
+    // With a verbose directory, each entry is logged verbosely to a transcript that is only saved
+    // if the entry fails.
+    let entry_default_verbosity = match (&runner_options.verbose_dir, default_verbosity) {
+        (Some(_), None | Some(Verbosity::LowVerbose)) => Some(Verbosity::Verbose),
+        _ => default_verbosity,
+    };
     let start = Instant::now();
     let timestamp = Utc::now().timestamp();
 
     log_run_info(entries, runner_options, &variables, logger);
 
+    // Implicit variables expose the run context (current file, entry index and attempt) to
+    // templates, captures and asserts.
+    if let Some(filename) = filename {
+        variables.insert_implicit("hurl_file", Value::String(filename.to_string()));
+    }
+
     // Main loop processing each entry.
     // The `entry_index` is not always incremented of each loop tick: an entry can be retried upon
     // errors for instance. Each entry is executed with options that are computed from the global
     // runner options and the "overridden" request options.
     // See <docs/spec/runner/run_cycle.md>
     loop {
-        if current > last {
+        if current > last || is_cancelled(runner_options) {
             break;
         }
-        let entry = &entries[current.to_zero_based()];
+        let ResolvedEntry { entry, source_file } = &entries[current.to_zero_based()];
+        let paged_entry;
+        let entry = match &next_page {
+            Some(url) => {
+                paged_entry = entry_with_url(entry, url);
+                &paged_entry
+            }
+            None => entry,
+        };
+        let entry_index = Value::Number(Number::Integer(current.get() as i64));
+        variables.insert_implicit("entry_index", entry_index);
+        variables.insert_implicit("attempt", Value::Number(Number::Integer(1)));
 
         // We compute the new logger verbosity for this entry, before entering into the `run`
         // function because entry options can modify the logger verbosity and we want the preamble
         // "Executing entry..." to be displayed based on the entry level verbosity.
-        logger.verbosity = default_verbosity;
-        let entry_verbosity = options::get_entry_verbosity(entry, default_verbosity, &variables);
+        logger.verbosity = entry_default_verbosity;
+        let entry_verbosity =
+            options::get_entry_verbosity(entry, entry_default_verbosity, &variables);
         if let Ok(entry_verbosity) = entry_verbosity {
             logger.verbosity = entry_verbosity;
         }
 
-        log_run_entry(current, logger);
+        if runner_options.verbose_dir.is_some() {
+            logger.start_transcript();
+        }
 
         // We can report the progression of the run for --test mode. Fo this call, the retry count is
         // zero, it will be potentially incremented in `run_request`.
-        if let Some(listener) = listener {
-            listener.on_entry_running(current, last, 0);
-        }
+        let event = Event::EntryStarted {
+            current,
+            last,
+            retry_count: 0,
+        };
+        publish(&event, logger, listener.as_deref_mut());
 
         // The real execution of the entry happens here, first: we compute the overridden request
         // options.
@@ -217,9 +334,17 @@ pub fn run_entries(
                 entry_index: current,
                 source_info: entry.source_info(),
                 errors: vec![error.clone()],
+                source_file: source_file.clone(),
                 ..Default::default()
             };
-            log_errors(&entry_result, content, filename, false, logger);
+            let event = Event::EntryCompleted {
+                result: &entry_result,
+                content,
+                filename,
+                retry: false,
+            };
+            publish(&event, logger, listener.as_deref_mut());
+            save_transcript(runner_options, filename, current, true, logger);
             entries_result.push(entry_result);
             if runner_options.continue_on_error {
                 current += 1;
@@ -235,27 +360,57 @@ pub fn run_entries(
         if options.skip {
             logger.debug("");
             logger.debug_important(&format!("Entry {current} has been skipped"));
+            save_transcript(runner_options, filename, current, false, logger);
             current += 1;
             continue;
         }
 
+        // With `foreach`, the entry is repeated once per item of the list.
+        let repeat = match &options.foreach {
+            Some(items) => Some(Count::Finite(items.len())),
+            None => options.repeat,
+        };
+
         // Repeat 0 is equivalent to skip.
-        if options.repeat == Some(Count::Finite(0)) {
+        if repeat == Some(Count::Finite(0)) {
             logger.debug("");
             logger.debug_important(&format!("Entry {current} is skipped (repeat 0 times)"));
+            save_transcript(runner_options, filename, current, false, logger);
             current += 1;
             continue;
         }
 
+        // Each repetition of a `foreach` entry binds the current item and its index.
+        if let Some(item) = options
+            .foreach
+            .as_ref()
+            .and_then(|items| items.get(repeat_count))
+        {
+            variables.insert("item".to_string(), item.clone());
+            let index = Value::Number(Number::Integer(repeat_count as i64));
+            variables.insert("index".to_string(), index);
+        }
+
         // Should we delay?
         let delay = options.delay;
         let delay_ms = delay.as_millis();
         if delay_ms > 0 {
             logger.debug("");
             logger.debug_important(&format!("Delay entry {current} (pause {delay_ms} ms)"));
-            thread::sleep(delay);
+            sleep(delay, runner_options);
+            if is_cancelled(runner_options) {
+                break;
+            }
         };
 
+        // Cookies read with a `cookie-file` option are only sent by this entry: the cookie storage
+        // is saved, to be restored once the entry has been run.
+        let entry_cookie_file = options
+            .cookie_input_file
+            .as_ref()
+            .filter(|&file| runner_options.cookie_input_file.as_ref() != Some(file));
+        let saved_cookies = entry_cookie_file.map(|_| http_client.cookie_store(logger));
+
         // Loop for executing HTTP run requests, with optional retry. Only "HTTP" errors in options
         // are taken into account for retry (errors while computing entry options and output error
         // are not retried).
@@ -265,25 +420,119 @@ pub fn run_entries(
             last,
             content,
             filename,
+            source_file.as_ref(),
             &mut http_client,
+            &mut tokens,
             &options,
             &mut variables,
             stdout,
-            listener,
+            listener.as_deref_mut(),
             logger,
         );
 
+        if let (Some(file), Some(saved)) = (entry_cookie_file, saved_cookies) {
+            restore_cookies(&mut http_client, saved, file, logger);
+        }
+
         let has_error = results.last().is_some_and(|r| !r.errors.is_empty());
+        save_transcript(runner_options, filename, current, has_error, logger);
+        if has_error && let Some(result) = results.last() {
+            save_replay(&options, filename, result, &variables, logger);
+        }
+
+        // The last response body of this entry is exposed to the next entries through the implicit
+        // variable `entry_<index>_body`, so responses of different entries can be compared.
+        if referenced_bodies.contains(&current.get())
+            && let Some(body) = results.last().and_then(last_response_body)
+        {
+            variables.insert_implicit(&format!("entry_{current}_body"), body);
+        }
+
+        if let Some(call) = results.last().and_then(|r| r.calls.last()) {
+            durations.push(call.response.duration);
+        }
+
+        // With soft asserts, an entry that has received a response doesn't stop the run: its
+        // failed asserts are reported and the next entries are run.
+        let soft_failure =
+            options.soft_asserts && results.last().is_some_and(|r| !r.calls.is_empty());
 
         entries_result.extend(results);
 
-        if !runner_options.continue_on_error && has_error {
+        if !runner_options.continue_on_error && has_error && !soft_failure {
             break;
         }
 
-        // We pass to the next entry if the repeat count is reached.
+        // With `paginate`, the entry is run again while its capture holds the URL of a next page.
+        // All the pages are considered as a single repetition of the entry.
+        if let Some(name) = &options.paginate {
+            page_count += 1;
+            let url = entries_result
+                .last()
+                .filter(|_| !has_error)
+                .and_then(|result| next_page_url(result, name));
+            match (url, options.paginate_max) {
+                (Some(_), Count::Finite(n)) if page_count >= n => {
+                    logger.warning(&format!(
+                        "Pagination of entry {current} stopped after {n} pages (see paginate-max)"
+                    ));
+                }
+                (Some(url), _) => {
+                    next_page = Some(url);
+                    logger.debug_important(&format!(
+                        "Paginate entry {current} (page {})",
+                        page_count + 1
+                    ));
+                    continue;
+                }
+                (None, _) => {}
+            }
+            next_page = None;
+            page_count = 0;
+        }
+
+        // Once all the repetitions of the entry have been run, we evaluate the aggregate asserts
+        // (`durationMean`, `durationPercentile` etc...) on the whole set of responses.
         repeat_count += 1;
-        match options.repeat {
+        let last_repeat = match repeat {
+            None => true,
+            Some(Count::Finite(n)) => repeat_count >= n,
+            Some(Count::Infinite) => false,
+        };
+        if last_repeat && let Some(result) = entries_result.last_mut() {
+            let count = result.asserts.len();
+            let errors =
+                entry::run_aggregate_asserts(entry, &durations, &variables, &options, result);
+            event::publish_asserts(
+                listener.as_deref_mut(),
+                result.entry_index,
+                &result.asserts[count..],
+            );
+            durations.clear();
+            if !errors.is_empty() {
+                let aggregate_result = EntryResult {
+                    entry_index: result.entry_index,
+                    source_info: result.source_info,
+                    errors,
+                    source_file: source_file.clone(),
+                    ..Default::default()
+                };
+                // Errors of the aggregate asserts are published as a completion of the entry.
+                let event = Event::EntryCompleted {
+                    result: &aggregate_result,
+                    content,
+                    filename,
+                    retry: false,
+                };
+                publish(&event, logger, listener.as_deref_mut());
+                if !runner_options.continue_on_error && !options.soft_asserts {
+                    break;
+                }
+            }
+        }
+
+        // We pass to the next entry if the repeat count is reached.
+        match repeat {
             None => {
                 repeat_count = 0;
                 current += 1;
@@ -303,9 +552,15 @@ pub fn run_entries(
         }
     }
 
+    logger.verbosity = default_verbosity;
+    // A cancelled run is never successful, even if all the entries run so far have succeeded.
+    let cancelled = is_cancelled(runner_options);
+    if cancelled {
+        logger.warning("The run has been cancelled");
+    }
     let duration = start.elapsed();
     let cookie_store = http_client.cookie_store(logger);
-    let success = is_success(&entries_result);
+    let success = is_success(&entries_result) && !cancelled;
     HurlResult {
         entries: entries_result,
         duration,
@@ -316,11 +571,143 @@ pub fn run_entries(
     }
 }
 
+/// Returns `true` if the run has been cancelled through the `options` cancellation token.
+fn is_cancelled(options: &RunnerOptions) -> bool {
+    options
+        .cancellation_token
+        .as_ref()
+        .is_some_and(|token| token.is_cancelled())
+}
+
+/// Blocks the current thread for `duration`, waking up early if the run is cancelled.
+fn sleep(duration: Duration, options: &RunnerOptions) {
+    match &options.cancellation_token {
+        Some(token) => {
+            token.sleep(duration);
+        }
+        None => thread::sleep(duration),
+    }
+}
+
+/// Restores the cookie storage of `http_client` to its `saved` state, after an entry has read the
+/// cookies of `cookie_file`.
+///
+/// Cookies read from the file are removed, or set back to their saved value, while cookies received
+/// by the entry are kept.
+fn restore_cookies(
+    http_client: &mut Client,
+    saved: CookieStore,
+    cookie_file: &str,
+    logger: &mut Logger,
+) {
+    let file_cookies = fs::read_to_string(cookie_file)
+        .map(|content| {
+            content
+                .lines()
+                .filter_map(|line| Cookie::from_netscape(line).ok())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    // Domains of cookies including subdomains are stored with a leading dot by libcurl.
+    let same_cookie = |a: &Cookie, b: &Cookie| {
+        a.domain().trim_start_matches('.') == b.domain().trim_start_matches('.')
+            && a.path() == b.path()
+            && a.name() == b.name()
+    };
+
+    let mut cookies = vec![];
+    for cookie in http_client.cookie_store(logger).into_vec() {
+        let from_file = file_cookies
+            .iter()
+            .any(|c| same_cookie(c, &cookie) && c.value() == cookie.value());
+        if !from_file || saved.cookies().any(|c| c == &cookie) {
+            cookies.push(cookie);
+        } else if let Some(saved) = saved.cookies().find(|c| same_cookie(c, &cookie)) {
+            cookies.push(saved.clone());
+        }
+    }
+    http_client.set_cookie_storage(&cookies);
+}
+
+/// Returns the indices of the entries whose body is referenced with an `entry_<index>_body`
+/// variable, in the source `content` or in the included files of `entries`.
+///
+/// Keeping only these bodies bounds the memory used by a run with many entries.
+fn referenced_entry_bodies(content: &str, entries: &[ResolvedEntry]) -> HashSet<usize> {
+    let mut included: Vec<&Arc<SourceFile>> = vec![];
+    for file in entries.iter().filter_map(|e| e.source_file.as_ref()) {
+        if !included.iter().any(|f| Arc::ptr_eq(f, file)) {
+            included.push(file);
+        }
+    }
+    let sources = std::iter::once(content).chain(included.iter().map(|f| f.content.as_str()));
+
+    let mut indices = HashSet::new();
+    for source in sources {
+        for (start, _) in source.match_indices("entry_") {
+            let rest = &source[start + "entry_".len()..];
+            let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            if rest[digits..].starts_with("_body")
+                && let Ok(index) = rest[..digits].parse::<usize>()
+            {
+                indices.insert(index);
+            }
+        }
+    }
+    indices
+}
+
+/// Returns the body of the last response of an entry `result`, as a string if the body can be
+/// decoded, as bytes otherwise.
+fn last_response_body(result: &EntryResult) -> Option<Value> {
+    let response = &result.calls.last()?.response;
+    match response.text() {
+        Ok(text) => Some(Value::String(text)),
+        Err(_) => response.uncompress_body().ok().map(Value::Bytes),
+    }
+}
+
+/// Returns the URL of the next page of a paginated entry `result`, from the capture `name`.
+///
+/// A relative URL is resolved against the URL of the last request. There is no next page if the
+/// capture is missing or `null`.
+fn next_page_url(result: &EntryResult, name: &str) -> Option<String> {
+    let capture = result.captures.iter().rev().find(|c| c.name == name)?;
+    let Value::String(url) = &capture.value else {
+        return None;
+    };
+    let next = result
+        .calls
+        .last()
+        .and_then(|call| call.request.url.join(url).ok())
+        .map(|next| next.to_string());
+    Some(next.unwrap_or_else(|| url.clone()))
+}
+
+/// Returns a copy of `entry` requesting the literal `url`.
+///
+/// The `[Query]` sections are removed, as the query params are expected to be part of `url`.
+fn entry_with_url(entry: &Entry, url: &str) -> Entry {
+    let mut entry = entry.clone();
+    let source_info = entry.request.url.source_info;
+    let element = TemplateElement::String {
+        value: url.to_string(),
+        source: url.to_source(),
+    };
+    entry.request.url = Template::new(None, vec![element], source_info);
+    entry
+        .request
+        .sections
+        .retain(|section| !matches!(section.value, SectionValue::QueryParams(..)));
+    entry
+}
+
 /// Runs an HTTP request and optionally retry it until there are no HTTP errors or the maximum retry
 /// count is reached. Returns a list of [`EntryResult`]. This list contains the results for each retry;
 /// so if there are no retry, this list contains a single entry.
 ///
-/// `current` is the current index of the entry run, `last` is the index of the last entry to be run
+/// `current` is the current index of the entry run, `last` is the index of the last entry to be run.
+/// `source_file` is the Hurl file of this entry if it has been included from another file.
 #[allow(clippy::too_many_arguments)]
 fn run_request(
     entry: &Entry,
@@ -328,18 +715,36 @@ fn run_request(
     last: Index,
     content: &str,
     filename: Option<&Input>,
+    source_file: Option<&Arc<SourceFile>>,
     http_client: &mut Client,
+    tokens: &mut TokenCache,
     options: &RunnerOptions,
     variables: &mut VariableSet,
     stdout: &mut Stdout,
-    listener: Option<&dyn EventListener>,
+    mut listener: Option<&mut (dyn EventListener + '_)>,
     logger: &mut Logger,
 ) -> Vec<EntryResult> {
     let mut results = vec![];
     let mut retry_count = 0;
 
     loop {
-        let mut result = entry::run(entry, current, http_client, variables, options, logger);
+        let attempt = Value::Number(Number::Integer(retry_count as i64 + 1));
+        variables.insert_implicit("attempt", attempt);
+        let mut result = entry::run(
+            entry,
+            current,
+            http_client,
+            variables,
+            tokens,
+            options,
+            listener.as_deref_mut(),
+            logger,
+        );
+        result.source_file = source_file.cloned();
+
+        // The response body is compared to its snapshot before the retry decision, so a snapshot
+        // mismatch can be retried like any assert failure.
+        check_snapshot(entry, filename, options, &mut result, logger);
 
         // Check if we need to retry.
         let has_error = !result.errors.is_empty();
@@ -359,8 +764,10 @@ fn run_request(
             logger.debug("");
         }
 
-        // The retry does not take into account a possible output Error
-        let retry = options.retry.is_some() && !retry_max_reached && has_error;
+        // The retry does not take into account a possible output Error, and a cancelled run is
+        // never retried.
+        let retry =
+            options.retry.is_some() && !retry_max_reached && has_error && !is_cancelled(options);
 
         // Response body can be outputted when:
         // 1. --output is overridden on a request level and there is no error,
@@ -377,21 +784,27 @@ fn run_request(
             write_entry_response(entry, &mut result, output, options, stdout);
         }
 
-        // We log eventual errors. If we're retrying, errors logs are just informative debug logs;
-        // if we're not retrying, errors are logs as failed errors.
+        // Eventual errors are logged by the logger on entry completion. If we're retrying, errors
+        // logs are just informative debug logs; if we're not retrying, errors are logs as failed
+        // errors.
         // We recompute `has_error` because response output in `write_entry_response` can fail and
         // add errors (these errors are not taken into account for retry).
         let has_error = !result.errors.is_empty();
-        if has_error {
-            // We ensure that errors messages start on newlines if output is write on stdout.
-            if matches!(should_output, Some(Output::Stdout))
-                && !result.has_response_trailing_newline()
-            {
-                stdout.write_all(b"\n").unwrap();
-            }
-            log_errors(&result, content, filename, retry, logger);
+        // We ensure that errors messages start on newlines if output is write on stdout.
+        if has_error
+            && matches!(should_output, Some(Output::Stdout))
+            && !result.has_response_trailing_newline()
+        {
+            stdout.write_all(b"\n").unwrap();
         }
 
+        let event = Event::EntryCompleted {
+            result: &result,
+            content,
+            filename,
+            retry,
+        };
+        publish(&event, logger, listener.as_deref_mut());
         results.push(result);
 
         // No retry, we leave the HTTP run requests loop.
@@ -412,15 +825,17 @@ fn run_request(
         // If we're retrying, we report the running event just before sleeping in case the retry
         // interval is too high: this way the visual retry hint will take the interval into account.
         // (user will see "retry x" before going into sleep).
-        if let Some(listener) = listener {
-            listener.on_entry_running(current, last, retry_count);
-        }
-
-        thread::sleep(options.retry_interval);
+        let event = Event::EntryStarted {
+            current,
+            last,
+            retry_count,
+        };
+        publish(&event, logger, listener.as_deref_mut());
 
-        // TODO: We keep this log because we don't want to change stderr with the changes
-        // introduced by <https://github.com/Orange-OpenSource/hurl/issues/1973>
-        log_run_entry(current, logger);
+        sleep(options.retry_interval, options);
+        if is_cancelled(options) {
+            break;
+        }
     }
 
     results
@@ -471,6 +886,141 @@ fn write_entry_response(
     }
 }
 
+/// Stops the verbose transcript of the entry `entry_index` and, if the entry has `failed`, writes
+/// it to a file under the verbose directory of `options`.
+fn save_transcript(
+    options: &RunnerOptions,
+    filename: Option<&Input>,
+    entry_index: Index,
+    failed: bool,
+    logger: &mut Logger,
+) {
+    let Some(transcript) = logger.stop_transcript() else {
+        return;
+    };
+    let Some(verbose_dir) = &options.verbose_dir else {
+        return;
+    };
+    if !failed {
+        return;
+    }
+    let path = verbose_dir.join(entry_file_name(filename, entry_index, "log"));
+    if let Err(err) = fs::create_dir_all(verbose_dir).and_then(|_| fs::write(&path, transcript)) {
+        logger.warning(&format!(
+            "Issue writing verbose transcript to {}: {err}",
+            path.display()
+        ));
+    }
+}
+
+/// Writes a Hurl file reproducing the request of the failed entry `result` under the replay
+/// directory of `options`.
+///
+/// Nothing is written if the entry has failed before its request has been sent.
+fn save_replay(
+    options: &RunnerOptions,
+    filename: Option<&Input>,
+    result: &EntryResult,
+    variables: &VariableSet,
+    logger: &mut Logger,
+) {
+    let Some(replay_dir) = &options.replay_dir else {
+        return;
+    };
+    let Some(call) = result.calls.first() else {
+        return;
+    };
+    let source = filename.map_or("stdin".to_string(), |f| f.to_string());
+    let content = replay::replay_file(
+        &call.request,
+        &source,
+        result.entry_index,
+        options,
+        variables,
+    );
+    let path = replay_dir.join(entry_file_name(filename, result.entry_index, "hurl"));
+    if let Err(err) = fs::create_dir_all(replay_dir).and_then(|_| fs::write(&path, content)) {
+        logger.warning(&format!(
+            "Issue writing replay file to {}: {err}",
+            path.display()
+        ));
+    }
+}
+
+/// Compares the last response body of the entry `result` to its snapshot under the snapshot
+/// directory of `options`. If there is no snapshot yet (or with `--snapshot-update`), the response
+/// body is recorded as the new snapshot.
+///
+/// Response bodies of failed entries are never recorded, and snapshots are ignored with
+/// `--no-assert`. A snapshot mismatch is added to the `result` errors.
+fn check_snapshot(
+    entry: &Entry,
+    filename: Option<&Input>,
+    options: &RunnerOptions,
+    result: &mut EntryResult,
+    logger: &mut Logger,
+) {
+    let Some(snapshot_dir) = &options.snapshot_dir else {
+        return;
+    };
+    if options.no_assert {
+        return;
+    }
+    let Some(call) = result.calls.last() else {
+        return;
+    };
+    let path = snapshot_dir.join(entry_file_name(filename, result.entry_index, "snap"));
+    let failed = !result.errors.is_empty();
+    if failed && (options.snapshot_update || !path.exists()) {
+        return;
+    }
+    let source_info = entry.request.url.source_info;
+    let content = match snapshot::snapshot_content(&call.response, &options.snapshot_masks) {
+        Ok(content) => content,
+        Err(error) => {
+            let error = RunnerError::new(source_info, RunnerErrorKind::Http(error), false);
+            result.errors.push(error);
+            return;
+        }
+    };
+    let error = match snapshot::check(&path, &content, options.snapshot_update) {
+        Ok(SnapshotStatus::Matched) => return,
+        Ok(SnapshotStatus::Recorded) => {
+            logger.debug_important(&format!("Snapshot recorded to {}", path.display()));
+            return;
+        }
+        Ok(SnapshotStatus::Mismatch(diff)) => {
+            let kind = RunnerErrorKind::SnapshotMismatch { path, diff };
+            RunnerError::new(source_info, kind, true)
+        }
+        Err(kind) => RunnerError::new(source_info, kind, false),
+    };
+    result.errors.push(error);
+}
+
+/// Returns the name of a file with this `extension` for the entry `entry_index` of `filename`.
+///
+/// For instance, the verbose transcript of the second entry of `tests/api/login.hurl` is named
+/// `tests_api_login.hurl.entry-2.log`. This name only depends on the path of the file and the index
+/// of the entry, so snapshots can be found from one run to another.
+fn entry_file_name(filename: Option<&Input>, entry_index: Index, extension: &str) -> String {
+    let name = match filename.map(|f| f.kind()) {
+        Some(InputKind::File(path)) => path
+            .to_string_lossy()
+            .chars()
+            .map(|c| {
+                if c.is_alphanumeric() || c == '.' || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect(),
+        _ => "stdin".to_string(),
+    };
+    format!("{name}.entry-{entry_index}.{extension}")
+}
+
 /// Use source_info from output option if this option has been defined
 fn get_output_source_info(entry: &Entry) -> SourceInfo {
     let mut source_info = entry.source_info();
@@ -516,6 +1066,13 @@ fn get_non_default_options(options: &RunnerOptions) -> Vec<(&'static str, String
 
     let mut non_default_options = vec![];
 
+    if options.clock_skew != default_options.clock_skew {
+        non_default_options.push((
+            "clock skew",
+            format!("{}ms", options.clock_skew.as_millis() as u64),
+        ));
+    }
+
     if options.continue_on_error != default_options.continue_on_error {
         non_default_options.push(("continue_on_error", options.continue_on_error.to_string()));
     }
@@ -528,6 +1085,12 @@ fn get_non_default_options(options: &RunnerOptions) -> Vec<(&'static str, String
         non_default_options.push(("delay", format!("{}ms", options.delay.as_millis() as u64)));
     }
 
+    if options.dns_servers != default_options.dns_servers
+        && let Some(dns_servers) = &options.dns_servers
+    {
+        non_default_options.push(("dns servers", dns_servers.to_string()));
+    }
+
     if options.follow_location != default_options.follow_location {
         match options.follow_location {
             FollowLocation::No => {}
@@ -548,6 +1111,12 @@ fn get_non_default_options(options: &RunnerOptions) -> Vec<(&'static str, String
         non_default_options.push(("max redirect", options.max_redirect.to_string()));
     }
 
+    if options.multipart_boundary != default_options.multipart_boundary
+        && let Some(multipart_boundary) = &options.multipart_boundary
+    {
+        non_default_options.push(("multipart boundary", multipart_boundary.to_string()));
+    }
+
     if options.proxy != default_options.proxy
         && let Some(proxy) = &options.proxy
     {
@@ -562,6 +1131,21 @@ fn get_non_default_options(options: &RunnerOptions) -> Vec<(&'static str, String
         non_default_options.push(("retry", value));
     }
 
+    if options.retry_on_capture_failure != default_options.retry_on_capture_failure {
+        non_default_options.push((
+            "retry on capture failure",
+            options.retry_on_capture_failure.to_string(),
+        ));
+    }
+
+    if options.soft_asserts != default_options.soft_asserts {
+        non_default_options.push(("soft asserts", options.soft_asserts.to_string()));
+    }
+
+    if options.nested_form_params != default_options.nested_form_params {
+        non_default_options.push(("nested form params", options.nested_form_params.to_string()));
+    }
+
     if options.unix_socket != default_options.unix_socket
         && let Some(unix_socket) = &options.unix_socket
     {
@@ -573,7 +1157,7 @@ fn get_non_default_options(options: &RunnerOptions) -> Vec<(&'static str, String
 
 /// Logs various debug information at the start of `hurl_file` run.
 fn log_run_info(
-    entries: &[Entry],
+    entries: &[ResolvedEntry],
     runner_options: &RunnerOptions,
     variables: &VariableSet,
     logger: &mut Logger,
@@ -603,55 +1187,21 @@ fn log_run_info(
     }
 }
 
-/// Logs runner `errors`.
-/// If we're going to `retry` the entry, we log errors only in verbose. Otherwise, we log error on stderr.
-fn log_errors(
-    entry_result: &EntryResult,
-    content: &str,
-    filename: Option<&Input>,
-    retry: bool,
-    logger: &mut Logger,
-) {
-    if retry {
-        entry_result.errors.iter().for_each(|error| {
-            logger.debug_error(content, filename, error, entry_result.source_info);
-        });
-        return;
-    }
-
-    if logger.error_format == ErrorFormat::Long
-        && let Some(Call { response, .. }) = entry_result.calls.last()
-    {
-        logger.info_curl_cmd(&entry_result.curl_cmd.to_string());
-        logger.info("");
-
-        response.log_info_all(logger);
+/// Publishes a run `event` to the `logger`, then to an optional `listener`.
+fn publish(event: &Event, logger: &mut Logger, listener: Option<&mut (dyn EventListener + '_)>) {
+    logger.on_event(event);
+    if let Some(listener) = listener {
+        listener.on_event(event);
     }
-
-    entry_result.errors.iter().for_each(|error| {
-        let filename = filename.map_or(String::new(), |f| f.to_string());
-        let message = error.render(
-            &filename,
-            content,
-            Some(entry_result.source_info),
-            OutputFormat::Terminal(logger.color),
-        );
-        logger.error_rich(&message);
-    });
-}
-
-/// Logs the header indicating the begin of the entry run.
-fn log_run_entry(entry_index: Index, logger: &mut Logger) {
-    logger.debug_important(
-        "------------------------------------------------------------------------------",
-    );
-    logger.debug_important(&format!("Executing entry {entry_index}"));
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::runner::CaptureResult;
     use crate::runner::RunnerOptionsBuilder;
+    use crate::util::cancel::CancellationToken;
+    use crate::util::logger::LoggerOptionsBuilder;
 
     #[test]
     fn get_non_default_options_returns_empty_when_default() {
@@ -673,4 +1223,129 @@ mod test {
         assert_eq!(first_non_default.0, "delay");
         assert_eq!(first_non_default.1, "500ms");
     }
+
+    #[test]
+    fn entry_file_name_is_sanitized() {
+        let filename = Input::new("tests/api/login v2.hurl");
+        assert_eq!(
+            entry_file_name(Some(&filename), Index::new(2), "log"),
+            "tests_api_login_v2.hurl.entry-2.log"
+        );
+        assert_eq!(
+            entry_file_name(Some(&filename), Index::new(2), "hurl"),
+            "tests_api_login_v2.hurl.entry-2.hurl"
+        );
+        assert_eq!(
+            entry_file_name(None, Index::new(1), "log"),
+            "stdin.entry-1.log"
+        );
+    }
+
+    #[test]
+    fn cancelled_run_is_not_successful() {
+        let token = CancellationToken::new();
+        token.cancel();
+        let options = RunnerOptionsBuilder::new()
+            .cancellation_token(Some(token))
+            .build();
+        let logger_options = LoggerOptionsBuilder::new().build();
+
+        let result = run(
+            "GET http://localhost:8000/hello\nHTTP 200\n",
+            None,
+            &options,
+            &VariableSet::new(),
+            &logger_options,
+        )
+        .unwrap();
+        assert!(!result.success);
+        assert!(result.entries.is_empty());
+    }
+
+    #[test]
+    fn next_page_url_is_read_from_capture() {
+        let capture = |value: Value| CaptureResult {
+            name: "next".to_string(),
+            value,
+        };
+        let mut result = EntryResult {
+            captures: vec![capture(Value::String("/items?page=2".to_string()))],
+            ..Default::default()
+        };
+        assert_eq!(
+            next_page_url(&result, "next"),
+            Some("/items?page=2".to_string())
+        );
+        assert_eq!(next_page_url(&result, "other"), None);
+
+        result.captures = vec![capture(Value::Null)];
+        assert_eq!(next_page_url(&result, "next"), None);
+    }
+
+    #[test]
+    fn entry_with_url_removes_query_params() {
+        let content = "GET http://localhost:8000/items\n[Query]\nlimit: 10\nHTTP 200\n";
+        let file = parser::parse_hurl_file(content).unwrap();
+        let entry = entry_with_url(&file.entries[0], "http://localhost:8000/items?page=2");
+        assert_eq!(
+            entry.request.url.to_string(),
+            "http://localhost:8000/items?page=2"
+        );
+        assert!(entry.request.sections.is_empty());
+        assert_eq!(entry.source_info(), file.entries[0].source_info());
+    }
+
+    #[test]
+    fn exported_captures_are_listed() {
+        let content = r#"
+POST http://localhost:8000/login
+[Options]
+export-capture: token
+export-capture: user_id
+HTTP 200
+[Captures]
+token: jsonpath "$.token"
+user_id: jsonpath "$.id"
+
+GET http://localhost:8000/me
+[Options]
+export-capture: token
+HTTP 200
+"#;
+        let hurl_file = parser::parse_hurl_file(content).unwrap();
+        assert_eq!(
+            exported_captures(&hurl_file),
+            vec!["token".to_string(), "user_id".to_string()]
+        );
+        let hurl_file = parser::parse_hurl_file("GET http://localhost:8000/hello").unwrap();
+        assert!(exported_captures(&hurl_file).is_empty());
+    }
+
+    #[test]
+    fn referenced_entry_bodies_are_read_from_sources() {
+        let content = r#"
+GET http://localhost:8000/a
+HTTP 200
+
+GET http://localhost:8000/b
+HTTP 200
+[Asserts]
+body == "{{entry_1_body}}"
+variable "entry_12_body" exists
+header "X-Entry" == "entry_3"
+"#;
+        let hurl_file = parser::parse_hurl_file(content).unwrap();
+        let entries = entries_with_defaults(&hurl_file)
+            .into_iter()
+            .map(|entry| ResolvedEntry {
+                entry,
+                source_file: None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            referenced_entry_bodies(content, &entries),
+            HashSet::from([1, 12])
+        );
+        assert!(referenced_entry_bodies("GET http://localhost:8000/a", &entries).is_empty());
+    }
 }
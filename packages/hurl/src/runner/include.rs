@@ -0,0 +1,273 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Composition of Hurl files with `include` directives.
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use hurl_core::ast::{Entry, HurlFile, Include};
+use hurl_core::error::DisplaySourceError;
+use hurl_core::input::{Input, InputKind};
+use hurl_core::parser::{self, ParserLimits};
+
+use super::defaults::entries_with_defaults;
+use super::error::{RunnerError, RunnerErrorKind};
+use super::result::SourceFile;
+use super::template::eval_template;
+use super::variable::VariableSet;
+use crate::util::path::ContextDir;
+
+/// An entry to be run, with the Hurl file it comes from if it has been included.
+#[derive(Clone, Debug)]
+pub struct ResolvedEntry {
+    /// The entry to run.
+    pub entry: Entry,
+    /// The included Hurl file of this entry, `None` for the entries of the run file.
+    pub source_file: Option<Arc<SourceFile>>,
+}
+
+/// Returns the entries of `hurl_file` to be run, with included Hurl files resolved and file-level
+/// defaults applied.
+///
+/// The entries of an included file are inserted in place of its `include` directive: they're run
+/// with the same variables and cookie storage, so captures of included entries are available to
+/// the next entries. Included files are resolved relatively to the file root of `context_dir`, and
+/// their defaults only apply to their own entries. Included entries keep their included file, so
+/// that their errors are rendered against its source. Included files are parsed with the parser
+/// `limits` of the run.
+pub fn resolve_entries(
+    hurl_file: &HurlFile,
+    filename: Option<&Input>,
+    context_dir: &ContextDir,
+    limits: ParserLimits,
+    variables: &VariableSet,
+) -> Result<Vec<ResolvedEntry>, RunnerError> {
+    let mut stack = vec![];
+    if let Some(InputKind::File(path)) = filename.map(|f| f.kind())
+        && let Ok(path) = path.canonicalize()
+    {
+        stack.push(path);
+    }
+    resolve(hurl_file, context_dir, limits, variables, &mut stack)
+}
+
+/// Resolves the entries of `hurl_file`, `stack` being the files currently included, used to
+/// detect include cycles.
+fn resolve(
+    hurl_file: &HurlFile,
+    context_dir: &ContextDir,
+    limits: ParserLimits,
+    variables: &VariableSet,
+    stack: &mut Vec<PathBuf>,
+) -> Result<Vec<ResolvedEntry>, RunnerError> {
+    let mut entries = vec![];
+    for entry in entries_with_defaults(hurl_file) {
+        for include in entry.includes.iter() {
+            let included = included_entries(include, context_dir, limits, variables, stack)?;
+            entries.extend(included);
+        }
+        entries.push(ResolvedEntry {
+            entry,
+            source_file: None,
+        });
+    }
+    for include in hurl_file.includes.iter() {
+        let included = included_entries(include, context_dir, limits, variables, stack)?;
+        entries.extend(included);
+    }
+    Ok(entries)
+}
+
+/// Returns the entries of the Hurl file included by `include`.
+///
+/// Errors raised in the included file are reported on the `include` directive, as the source
+/// information of these errors refers to another file.
+fn included_entries(
+    include: &Include,
+    context_dir: &ContextDir,
+    limits: ParserLimits,
+    variables: &VariableSet,
+    stack: &mut Vec<PathBuf>,
+) -> Result<Vec<ResolvedEntry>, RunnerError> {
+    let filename = eval_template(&include.filename, variables)?;
+    let path = PathBuf::from(&filename);
+    let resolved_path = context_dir
+        .read_path(&path)
+        .map_err(|e| RunnerError::new(include.source_info, e.into(), false))?;
+    let error = |message: String| {
+        let kind = RunnerErrorKind::Include {
+            path: path.clone(),
+            message,
+        };
+        RunnerError::new(include.source_info, kind, false)
+    };
+
+    let canonical_path = resolved_path
+        .canonicalize()
+        .map_err(|e| error(e.to_string()))?;
+    if stack.contains(&canonical_path) {
+        return Err(error("include cycle detected".to_string()));
+    }
+    let input = Input::new(&resolved_path.to_string_lossy());
+    let content = input.read_to_string().map_err(|e| error(e.to_string()))?;
+    let hurl_file = parser::parse_hurl_file_with_limits(&content, limits).map_err(|e| {
+        let message = format!("{} at line {}", e.description(), e.pos.line);
+        error(message.to_lowercase())
+    })?;
+
+    stack.push(canonical_path);
+    let entries =
+        resolve(&hurl_file, context_dir, limits, variables, stack).map_err(|e| match e.kind {
+            RunnerErrorKind::Include { path, message } => {
+                error(format!("{}: {message}", path.display()))
+            }
+            _ => error(e.description().to_lowercase()),
+        })?;
+    stack.pop();
+
+    // Entries of this file are attached to it, while entries of nested included files already
+    // have their own file.
+    let source_file = Arc::new(SourceFile { input, content });
+    let entries = entries
+        .into_iter()
+        .map(|e| ResolvedEntry {
+            source_file: e.source_file.or_else(|| Some(source_file.clone())),
+            entry: e.entry,
+        })
+        .collect();
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::util::path::FileRootMode;
+
+    #[test]
+    fn test_resolve_entries() {
+        let dir = std::env::temp_dir().join("hurl_test_resolve_entries");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("login.hurl"), "POST http://localhost:8000/login\n").unwrap();
+        let context_dir = ContextDir::new(&dir, &dir);
+
+        let content = r#"include "login.hurl"
+GET http://localhost:8000/a
+include "login.hurl"
+"#;
+        let hurl_file = parser::parse_hurl_file(content).unwrap();
+        let entries = resolve_entries(
+            &hurl_file,
+            None,
+            &context_dir,
+            ParserLimits::default(),
+            &VariableSet::new(),
+        )
+        .unwrap();
+        let methods = entries
+            .iter()
+            .map(|e| e.entry.request.method.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(methods, vec!["POST", "GET", "POST"]);
+        let included = entries
+            .iter()
+            .map(|e| e.source_file.as_ref().map(|f| f.content.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            included,
+            vec![
+                Some("POST http://localhost:8000/login\n"),
+                None,
+                Some("POST http://localhost:8000/login\n")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_entries_cycle() {
+        let dir = std::env::temp_dir().join("hurl_test_resolve_entries_cycle");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("a.hurl"), "include \"b.hurl\"\n").unwrap();
+        fs::write(dir.join("b.hurl"), "include \"a.hurl\"\n").unwrap();
+        let context_dir = ContextDir::new(&dir, &dir);
+
+        let hurl_file = parser::parse_hurl_file("include \"a.hurl\"\n").unwrap();
+        let error = resolve_entries(
+            &hurl_file,
+            None,
+            &context_dir,
+            ParserLimits::default(),
+            &VariableSet::new(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::Include {
+                path: PathBuf::from("a.hurl"),
+                message: "b.hurl: a.hurl: include cycle detected".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_resolve_entries_outside_file_root() {
+        let dir = std::env::temp_dir().join("hurl_test_resolve_entries_outside_file_root");
+        let root = dir.join("root");
+        fs::create_dir_all(&root).unwrap();
+        fs::write(
+            dir.join("secret.hurl"),
+            "GET http://localhost:8000/secret\n",
+        )
+        .unwrap();
+        let hurl_file = parser::parse_hurl_file("include \"../secret.hurl\"\n").unwrap();
+
+        let context_dir = ContextDir::new(&dir, &root);
+        let error = resolve_entries(
+            &hurl_file,
+            None,
+            &context_dir,
+            ParserLimits::default(),
+            &VariableSet::new(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::UnauthorizedFileAccess {
+                path: PathBuf::from("../secret.hurl")
+            }
+        );
+
+        let context_dir = context_dir
+            .with_file_root_mode(FileRootMode::Permissive)
+            .with_sandbox(true);
+        let error = resolve_entries(
+            &hurl_file,
+            None,
+            &context_dir,
+            ParserLimits::default(),
+            &VariableSet::new(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::SandboxViolation {
+                message: "reading file ../secret.hurl".to_string()
+            }
+        );
+    }
+}
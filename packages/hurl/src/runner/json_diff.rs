@@ -0,0 +1,222 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Structural diff of JSON values.
+//!
+//! When a JSON equality assert fails, the differing nodes are reported with their JSONPath
+//! (for instance `$.items[3].price: expected 10 actual 12`) instead of the whole documents.
+use std::fmt;
+
+use serde_json::Value;
+
+use super::json_include::json_equals;
+
+/// Default maximum number of differences displayed for a failed JSON assert.
+pub const DEFAULT_JSON_DIFF_LIMIT: usize = 10;
+
+/// A list of differences between an actual and an expected JSON value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsonDiff {
+    /// The first differences, in document order.
+    pub differences: Vec<JsonDifference>,
+    /// The total number of differences, including the ones not kept in `differences`.
+    pub total: usize,
+}
+
+/// A difference between an actual and an expected JSON value, at a given `path`.
+///
+/// A `None` value means that the node doesn't exist on this side.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsonDifference {
+    pub path: String,
+    pub actual: Option<Value>,
+    pub expected: Option<Value>,
+}
+
+/// Returns the differences between the JSON values `actual` and `expected`, keeping at most
+/// `limit` of them. Returns `None` if the values are equal or `limit` is 0.
+///
+/// Objects are compared member by member and arrays element by element, numbers being compared
+/// by value (i.e. `1` is equal to `1.0`).
+pub fn json_diff(actual: &Value, expected: &Value, limit: usize) -> Option<JsonDiff> {
+    if limit == 0 {
+        return None;
+    }
+    let mut differences = vec![];
+    diff_values("$", actual, expected, &mut differences);
+    if differences.is_empty() {
+        return None;
+    }
+    let total = differences.len();
+    differences.truncate(limit);
+    Some(JsonDiff { differences, total })
+}
+
+fn diff_values(path: &str, actual: &Value, expected: &Value, diffs: &mut Vec<JsonDifference>) {
+    match (actual, expected) {
+        (Value::Object(actual), Value::Object(expected)) => {
+            for (name, expected) in expected {
+                let path = member_path(path, name);
+                match actual.get(name) {
+                    Some(actual) => diff_values(&path, actual, expected, diffs),
+                    None => diffs.push(JsonDifference {
+                        path,
+                        actual: None,
+                        expected: Some(expected.clone()),
+                    }),
+                }
+            }
+            for (name, actual) in actual {
+                if !expected.contains_key(name) {
+                    diffs.push(JsonDifference {
+                        path: member_path(path, name),
+                        actual: Some(actual.clone()),
+                        expected: None,
+                    });
+                }
+            }
+        }
+        (Value::Array(actual), Value::Array(expected)) => {
+            for index in 0..actual.len().max(expected.len()) {
+                let path = format!("{path}[{index}]");
+                match (actual.get(index), expected.get(index)) {
+                    (Some(actual), Some(expected)) => diff_values(&path, actual, expected, diffs),
+                    (actual, expected) => diffs.push(JsonDifference {
+                        path,
+                        actual: actual.cloned(),
+                        expected: expected.cloned(),
+                    }),
+                }
+            }
+        }
+        (actual, expected) => {
+            if !json_equals(actual, expected) {
+                diffs.push(JsonDifference {
+                    path: path.to_string(),
+                    actual: Some(actual.clone()),
+                    expected: Some(expected.clone()),
+                });
+            }
+        }
+    }
+}
+
+/// Returns the JSONPath of the member `name` of the object at `path`, using the dot notation
+/// when `name` is a simple identifier, and the bracket notation otherwise.
+fn member_path(path: &str, name: &str) -> String {
+    let mut chars = name.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if is_identifier {
+        format!("{path}.{name}")
+    } else {
+        let name = name.replace('\\', "\\\\").replace('\'', "\\'");
+        format!("{path}['{name}']")
+    }
+}
+
+impl fmt::Display for JsonDifference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let display = |value: &Option<Value>| match value {
+            Some(value) => value.to_string(),
+            None => "none".to_string(),
+        };
+        write!(
+            f,
+            "{}: expected {} actual {}",
+            self.path,
+            display(&self.expected),
+            display(&self.actual)
+        )
+    }
+}
+
+impl fmt::Display for JsonDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines = self
+            .differences
+            .iter()
+            .map(|d| d.to_string())
+            .collect::<Vec<_>>();
+        write!(f, "{}", lines.join("\n"))?;
+        let omitted = self.total - self.differences.len();
+        if omitted > 0 {
+            let plural = if omitted > 1 { "s" } else { "" };
+            write!(f, "\n... {omitted} more difference{plural}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_json_diff_equal() {
+        let value = json!({"name": "Bob", "ids": [1, 2]});
+        assert_eq!(json_diff(&value, &value, 10), None);
+        assert_eq!(json_diff(&json!([1.0]), &json!([1]), 10), None);
+    }
+
+    #[test]
+    fn test_json_diff() {
+        let actual = json!({
+            "items": [{"price": 10}, {"price": 12}],
+            "owner": "Bob",
+            "tags": ["a"],
+        });
+        let expected = json!({
+            "count": 2,
+            "items": [{"price": 10}, {"price": 10}],
+            "tags": ["a", "b"],
+        });
+        let diff = json_diff(&actual, &expected, 10).unwrap();
+        assert_eq!(diff.total, 4);
+        assert_eq!(
+            diff.to_string(),
+            "$.count: expected 2 actual none\n\
+             $.items[1].price: expected 10 actual 12\n\
+             $.tags[1]: expected \"b\" actual none\n\
+             $.owner: expected none actual \"Bob\""
+        );
+    }
+
+    #[test]
+    fn test_json_diff_limit() {
+        let diff = json_diff(&json!([1, 2, 3]), &json!([4, 5, 6]), 1).unwrap();
+        assert_eq!(diff.total, 3);
+        assert_eq!(
+            diff.to_string(),
+            "$[0]: expected 4 actual 1\n... 2 more differences"
+        );
+        assert_eq!(json_diff(&json!([1]), &json!([2]), 0), None);
+    }
+
+    #[test]
+    fn test_member_path() {
+        assert_eq!(member_path("$", "price"), "$.price");
+        assert_eq!(member_path("$.a", "_id2"), "$.a._id2");
+        assert_eq!(member_path("$", "first-name"), "$['first-name']");
+        assert_eq!(member_path("$", "it's"), "$['it\\'s']");
+        assert_eq!(member_path("$", "2fa"), "$['2fa']");
+    }
+}
@@ -0,0 +1,153 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use serde_json::{Number, Value};
+
+/// Returns `true` if the JSON value `actual` deep-includes the JSON value `expected`.
+///
+/// - an object includes another object if each member of the expected object is included in the
+///   actual member with the same name (extra members are ignored),
+/// - an array includes another array if each element of the expected array is included in at least
+///   one element of the actual array (order and extra elements are ignored),
+/// - other values (strings, numbers, booleans and null) must be equal, numbers being compared by
+///   value (i.e. `1` is equal to `1.0`).
+pub fn json_includes(actual: &Value, expected: &Value) -> bool {
+    match (actual, expected) {
+        (Value::Object(actual), Value::Object(expected)) => {
+            expected.iter().all(|(name, expected)| {
+                actual
+                    .get(name)
+                    .is_some_and(|actual| json_includes(actual, expected))
+            })
+        }
+        (Value::Array(actual), Value::Array(expected)) => expected
+            .iter()
+            .all(|expected| actual.iter().any(|actual| json_includes(actual, expected))),
+        (Value::Number(actual), Value::Number(expected)) => numbers_equal(actual, expected),
+        (actual, expected) => actual == expected,
+    }
+}
+
+/// Returns `true` if the JSON values `a` and `b` are equal.
+///
+/// Unlike [`json_includes`], objects must have the same members and arrays the same elements in
+/// the same order. Numbers are compared by value (i.e. `1` is equal to `1.0`).
+pub fn json_equals(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Object(a), Value::Object(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(name, a)| b.get(name).is_some_and(|b| json_equals(a, b)))
+        }
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| json_equals(a, b))
+        }
+        (Value::Number(a), Value::Number(b)) => numbers_equal(a, b),
+        (a, b) => a == b,
+    }
+}
+
+/// Returns `true` if two JSON numbers have the same value.
+fn numbers_equal(a: &Number, b: &Number) -> bool {
+    if let (Some(a), Some(b)) = (a.as_i64(), b.as_i64()) {
+        return a == b;
+    }
+    if let (Some(a), Some(b)) = (a.as_u64(), b.as_u64()) {
+        return a == b;
+    }
+    match (a.as_f64(), b.as_f64()) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_includes_scalars() {
+        assert!(json_includes(&json!(1), &json!(1)));
+        assert!(json_includes(&json!(1), &json!(1.0)));
+        assert!(json_includes(&json!("foo"), &json!("foo")));
+        assert!(json_includes(&json!(null), &json!(null)));
+        assert!(!json_includes(&json!(1), &json!("1")));
+        assert!(!json_includes(&json!(true), &json!(false)));
+        assert!(!json_includes(&json!(null), &json!({})));
+    }
+
+    #[test]
+    fn test_includes_objects() {
+        let actual = json!({
+            "id": 1,
+            "name": "Bob",
+            "address": {"city": "Paris", "zip": "75001"},
+            "tags": ["admin", "user"]
+        });
+        assert!(json_includes(&actual, &json!({})));
+        assert!(json_includes(&actual, &json!({"name": "Bob"})));
+        assert!(json_includes(
+            &actual,
+            &json!({"address": {"city": "Paris"}, "tags": ["user"]})
+        ));
+        assert!(!json_includes(&actual, &json!({"name": "Alice"})));
+        assert!(!json_includes(
+            &actual,
+            &json!({"email": "bob@example.org"})
+        ));
+        assert!(!json_includes(
+            &actual,
+            &json!({"address": {"country": "FR"}})
+        ));
+        assert!(!json_includes(&actual, &json!({"tags": ["guest"]})));
+        assert!(!json_includes(&actual, &json!([{"name": "Bob"}])));
+    }
+
+    #[test]
+    fn test_includes_arrays() {
+        let actual = json!([
+            {"id": 1, "name": "Bob"},
+            {"id": 2, "name": "Alice"}
+        ]);
+        assert!(json_includes(&actual, &json!([])));
+        assert!(json_includes(&actual, &json!([{"name": "Alice"}])));
+        assert!(json_includes(&actual, &json!([{"id": 2}, {"id": 1}])));
+        assert!(!json_includes(&actual, &json!([{"id": 3}])));
+        assert!(!json_includes(&actual, &json!({"id": 1})));
+    }
+
+    #[test]
+    fn test_equals() {
+        assert!(json_equals(&json!(1), &json!(1.0)));
+        assert!(json_equals(
+            &json!({"id": 1, "tags": ["a", "b"]}),
+            &json!({"tags": ["a", "b"], "id": 1})
+        ));
+        assert!(!json_equals(
+            &json!({"id": 1}),
+            &json!({"id": 1, "name": "Bob"})
+        ));
+        assert!(!json_equals(
+            &json!({"id": 1, "name": "Bob"}),
+            &json!({"id": 1})
+        ));
+        assert!(!json_equals(&json!(["a", "b"]), &json!(["b", "a"])));
+        assert!(!json_equals(&json!(["a", "b"]), &json!(["a"])));
+    }
+}
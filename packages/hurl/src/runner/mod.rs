@@ -18,48 +18,64 @@
 
 //! A runner for Hurl files. If you want to execute an Hurl file, this is the right place.
 
+pub use self::background::{RunHandle, run_in_background};
 pub use self::error::{RunnerError, RunnerErrorKind};
 #[doc(hidden)]
-pub use self::event::EventListener;
+pub use self::event::{Event, EventBus, EventListener};
+pub use self::function::CustomFunction;
 pub use self::http_response::HttpResponse;
-pub use self::hurl_file::run;
 #[doc(hidden)]
 pub use self::hurl_file::run_entries;
+pub use self::hurl_file::{exported_captures, run, run_hurl_file};
+#[doc(hidden)]
+pub use self::include::{ResolvedEntry, resolve_entries};
 pub use self::number::Number;
 pub use self::output::Output;
-pub use self::result::{AssertResult, CaptureResult, EntryResult, HurlResult};
+pub use self::result::{AssertResult, CaptureResult, EntryResult, HurlResult, SourceFile};
 pub use self::runner_options::{RunnerOptions, RunnerOptionsBuilder};
 pub use self::value::{EvalError, Value};
 pub use self::variable::{Variable, VariableSet, Visibility};
 
 mod assert;
+mod background;
 mod body;
 mod cache;
 mod capture;
+mod csv;
+mod defaults;
 mod diff;
 mod entry;
 mod error;
 mod event;
 mod expr;
 mod filter;
+mod fixture;
+mod form;
 mod function;
 pub mod hex;
 mod http_response;
 mod hurl_file;
+mod include;
 mod json;
+mod json_diff;
+mod json_include;
 mod multiline;
 mod multipart;
 mod number;
+mod oauth2;
 mod options;
 mod output;
 mod predicate;
 mod predicate_value;
+mod pretty;
 mod query;
 mod regex;
+mod replay;
 mod request;
 mod response;
 mod result;
 mod runner_options;
+mod snapshot;
 mod template;
 mod value;
 mod value_impl;
@@ -18,7 +18,7 @@
 use std::ffi::OsStr;
 use std::path::Path;
 
-use hurl_core::ast::{FilenameParam, FilenameValue, KeyValue, MultipartParam};
+use hurl_core::ast::{FilenameParam, FilenameValue, KeyValue, MultipartParam, NestedParam};
 
 use crate::http;
 use crate::util::path::ContextDir;
@@ -37,6 +37,11 @@ use super::variable::VariableSet;
 /// field1: value1
 /// field2: file,example.txt;
 /// field3: file,example.zip; application/zip
+/// field4: file,example.xml; text/xml; Content-Transfer-Encoding: binary
+/// field5: file,example.txt; charset=iso-8859-1; filename=report.txt
+/// field6: multipart,mixed; Content-ID: <attachments>
+///   file1: file,a.txt;
+///   file2: file,b.txt;
 /// ```
 pub fn eval_multipart_param(
     multipart_param: &MultipartParam,
@@ -53,9 +58,40 @@ pub fn eval_multipart_param(
             let file_param = eval_file_param(param, context_dir, variables)?;
             Ok(http::MultipartParam::FileParam(file_param))
         }
+        MultipartParam::NestedParam(param) => {
+            let nested_param = eval_nested_param(param, variables, context_dir)?;
+            Ok(http::MultipartParam::NestedParam(nested_param))
+        }
     }
 }
 
+fn eval_nested_param(
+    nested_param: &NestedParam,
+    variables: &VariableSet,
+    context_dir: &ContextDir,
+) -> Result<http::NestedParam, RunnerError> {
+    let name = eval_template(&nested_param.key, variables)?;
+    let subtype = eval_template(&nested_param.value.subtype, variables)?;
+    let headers = match &nested_param.value.headers {
+        Some(headers) => {
+            let value = eval_template(headers, variables)?;
+            split_part_attributes(&value).headers
+        }
+        None => vec![],
+    };
+    let params = nested_param
+        .params
+        .iter()
+        .map(|param| eval_multipart_param(param, variables, context_dir))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(http::NestedParam {
+        name,
+        content_type: format!("multipart/{subtype}"),
+        headers,
+        params,
+    })
+}
+
 fn eval_file_param(
     filename_param: &FilenameParam,
     context_dir: &ContextDir,
@@ -64,49 +100,141 @@ fn eval_file_param(
     let name = eval_template(&filename_param.key, variables)?;
     let filename = eval_template(&filename_param.value.filename, variables)?;
     let data = eval_file(&filename_param.value.filename, variables, context_dir)?;
-    let content_type = eval_content_type(&filename_param.value, variables)?;
+    let PartAttributes {
+        content_type,
+        filename: part_filename,
+        headers,
+    } = eval_part_attributes(&filename_param.value, variables)?;
     Ok(http::FileParam {
         name,
         filename,
+        part_filename,
         data,
         content_type,
+        headers,
     })
 }
 
-/// Returns the evaluated content type for this file param value, given a set of `variables`.
-fn eval_content_type(
+/// Attributes of a file part, following the file name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct PartAttributes {
+    /// Content type of the part, with its parameters (like `charset=utf-8`).
+    content_type: String,
+    /// Filename sent in the part `Content-Disposition` header, instead of the file name.
+    filename: Option<String>,
+    /// Custom headers of the part (like `Content-Transfer-Encoding`).
+    headers: Vec<http::Header>,
+}
+
+/// Returns the evaluated attributes (content type, part filename and custom part headers) for
+/// this file param value, given a set of `variables`.
+///
+/// Attributes follow the file name, separated by `;`:
+///
+/// ```hurl
+/// file: file,data.xml; text/xml; Content-Transfer-Encoding: binary
+/// file: file,data.txt; charset=iso-8859-1; filename=report.txt
+/// ```
+///
+/// If no content type is given (only parameters like `charset`), the content type is inferred
+/// from the file extension.
+fn eval_part_attributes(
     file_value: &FilenameValue,
     variables: &VariableSet,
-) -> Result<String, RunnerError> {
-    let value = match &file_value.content_type {
-        Some(content_type) => eval_template(content_type, variables)?,
-        None => {
-            let value = eval_template(&file_value.filename, variables)?;
-            match Path::new(value.as_str())
-                .extension()
-                .and_then(OsStr::to_str)
-            {
-                Some("gif") => "image/gif".to_string(),
-                Some("jpg") => "image/jpeg".to_string(),
-                Some("jpeg") => "image/jpeg".to_string(),
-                Some("png") => "image/png".to_string(),
-                Some("svg") => "image/svg+xml".to_string(),
-                Some("txt") => "text/plain".to_string(),
-                Some("htm") => "text/html".to_string(),
-                Some("html") => "text/html".to_string(),
-                Some("pdf") => "application/pdf".to_string(),
-                Some("xml") => "application/xml".to_string(),
-                _ => "application/octet-stream".to_string(),
-            }
+) -> Result<PartAttributes, RunnerError> {
+    let mut attributes = match &file_value.content_type {
+        Some(content_type) => {
+            let value = eval_template(content_type, variables)?;
+            split_part_attributes(&value)
         }
+        None => PartAttributes {
+            content_type: String::new(),
+            filename: None,
+            headers: vec![],
+        },
     };
-    Ok(value)
+    let has_media_type = attributes
+        .content_type
+        .split(';')
+        .next()
+        .is_some_and(|media_type| !media_type.is_empty() && !media_type.contains('='));
+    if has_media_type {
+        return Ok(attributes);
+    }
+    let value = eval_template(&file_value.filename, variables)?;
+    let media_type = match Path::new(value.as_str())
+        .extension()
+        .and_then(OsStr::to_str)
+    {
+        Some("gif") => "image/gif",
+        Some("jpg") => "image/jpeg",
+        Some("jpeg") => "image/jpeg",
+        Some("png") => "image/png",
+        Some("svg") => "image/svg+xml",
+        Some("txt") => "text/plain",
+        Some("htm") => "text/html",
+        Some("html") => "text/html",
+        Some("pdf") => "application/pdf",
+        Some("xml") => "application/xml",
+        _ => "application/octet-stream",
+    };
+    attributes.content_type = if attributes.content_type.is_empty() {
+        media_type.to_string()
+    } else {
+        format!("{media_type}; {}", attributes.content_type)
+    };
+    Ok(attributes)
+}
+
+/// Splits a part attributes `value` into a content type, a part filename and a list of custom
+/// headers.
+///
+/// Each `;` separated segment of the form `Name: value` is a header, a `filename=value` segment
+/// is the part filename (optionally double-quoted) and the other segments are the content type
+/// and its parameters (like `charset=utf-8`).
+fn split_part_attributes(value: &str) -> PartAttributes {
+    let mut content_type = vec![];
+    let mut filename = None;
+    let mut headers = vec![];
+    for segment in value.split(';') {
+        let segment = segment.trim();
+        let header = segment.split_once(':').filter(|(name, _)| {
+            !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+        if let Some((name, value)) = header {
+            headers.push(http::Header::new(name, value.trim()));
+            continue;
+        }
+        let part_filename = segment
+            .split_once('=')
+            .filter(|(name, _)| name.trim().eq_ignore_ascii_case("filename"));
+        if let Some((_, value)) = part_filename {
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .unwrap_or(value);
+            filename = Some(value.to_string());
+            continue;
+        }
+        if !segment.is_empty() {
+            content_type.push(segment);
+        }
+    }
+    PartAttributes {
+        content_type: content_type.join("; "),
+        filename,
+        headers,
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
+
     use super::*;
-    use crate::runner::Value;
+    use crate::runner::{RunnerErrorKind, Value};
+    use crate::util::path::FileRootMode;
     use hurl_core::ast::{
         Expr, ExprKind, LineTerminator, Placeholder, SourceInfo, Template, TemplateElement,
         Variable, Whitespace,
@@ -172,8 +300,65 @@ mod tests {
             http::FileParam {
                 name: "upload1".to_string(),
                 filename: "hello.txt".to_string(),
+                part_filename: None,
                 data: b"Hello World!".to_vec(),
                 content_type: "text/plain".to_string(),
+                headers: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn test_eval_nested_param() {
+        let content = "POST https://example.org\n\
+                       [Multipart]\n\
+                       parts: multipart,{{subtype}}; Content-ID: <parts>\n  \
+                       field1: value1\n";
+        let hurl_file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        let param = &hurl_file.entries[0].request.multipart_form_data()[0];
+        let context_dir = ContextDir::default();
+        let mut variables = VariableSet::default();
+        variables.insert("subtype".to_string(), Value::String("related".to_string()));
+        assert_eq!(
+            eval_multipart_param(param, &variables, &context_dir).unwrap(),
+            http::MultipartParam::NestedParam(http::NestedParam {
+                name: "parts".to_string(),
+                content_type: "multipart/related".to_string(),
+                headers: vec![http::Header::new("Content-ID", "<parts>")],
+                params: vec![http::MultipartParam::Param(http::Param {
+                    name: "field1".to_string(),
+                    value: "value1".to_string(),
+                })],
+            })
+        );
+    }
+
+    #[test]
+    fn test_eval_file_param_outside_file_root() {
+        let content = "POST https://example.org\n\
+                       [Multipart]\n\
+                       upload: file,../secret.txt;\n";
+        let hurl_file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        let param = &hurl_file.entries[0].request.multipart_form_data()[0];
+        let variables = VariableSet::default();
+
+        let context_dir = ContextDir::new(Path::new("/tmp"), Path::new("root"));
+        let error = eval_multipart_param(param, &variables, &context_dir).unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::UnauthorizedFileAccess {
+                path: PathBuf::from("../secret.txt")
+            }
+        );
+
+        let context_dir = context_dir
+            .with_file_root_mode(FileRootMode::Permissive)
+            .with_sandbox(true);
+        let error = eval_multipart_param(param, &variables, &context_dir).unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::SandboxViolation {
+                message: "reading file ../secret.txt".to_string()
             }
         );
     }
@@ -190,7 +375,7 @@ mod tests {
         // foo: hello.txt;
         // ```
         assert_eq!(
-            eval_content_type(
+            eval_part_attributes(
                 &FilenameValue {
                     space0: whitespace(),
                     filename: Template::new(
@@ -207,7 +392,8 @@ mod tests {
                 },
                 &variables
             )
-            .unwrap(),
+            .unwrap()
+            .content_type,
             "text/plain".to_string()
         );
 
@@ -215,7 +401,7 @@ mod tests {
         // bar: hello.html;
         // ```
         assert_eq!(
-            eval_content_type(
+            eval_part_attributes(
                 &FilenameValue {
                     space0: whitespace(),
                     filename: Template::new(
@@ -232,7 +418,8 @@ mod tests {
                 },
                 &variables
             )
-            .unwrap(),
+            .unwrap()
+            .content_type,
             "text/html".to_string()
         );
 
@@ -240,7 +427,7 @@ mod tests {
         // bar: hello.txt; text/html
         // ```
         assert_eq!(
-            eval_content_type(
+            eval_part_attributes(
                 &FilenameValue {
                     space0: whitespace(),
                     filename: Template::new(
@@ -264,7 +451,8 @@ mod tests {
                 },
                 &variables
             )
-            .unwrap(),
+            .unwrap()
+            .content_type,
             "text/html".to_string()
         );
 
@@ -272,7 +460,7 @@ mod tests {
         // baz: hello;
         // ```
         assert_eq!(
-            eval_content_type(
+            eval_part_attributes(
                 &FilenameValue {
                     space0: whitespace(),
                     filename: Template::new(
@@ -289,7 +477,8 @@ mod tests {
                 },
                 &variables
             )
-            .unwrap(),
+            .unwrap()
+            .content_type,
             "application/octet-stream".to_string()
         );
 
@@ -297,7 +486,7 @@ mod tests {
         // quz: hello.txt; {{ct}}
         // ```
         assert_eq!(
-            eval_content_type(
+            eval_part_attributes(
                 &FilenameValue {
                     space0: whitespace(),
                     filename: Template::new(
@@ -334,8 +523,106 @@ mod tests {
                 },
                 &variables
             )
-            .unwrap(),
+            .unwrap()
+            .content_type,
             "application/json".to_string()
         );
     }
+
+    #[test]
+    fn test_file_value_charset_and_filename() {
+        let variables = VariableSet::default();
+        let file_value = |content_type: &str| FilenameValue {
+            space0: whitespace(),
+            filename: Template::new(
+                None,
+                vec![TemplateElement::String {
+                    value: "hello.txt".to_string(),
+                    source: "hello.txt".to_source(),
+                }],
+                SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            ),
+            space1: whitespace(),
+            space2: whitespace(),
+            content_type: Some(Template::new(
+                None,
+                vec![TemplateElement::String {
+                    value: content_type.to_string(),
+                    source: content_type.to_source(),
+                }],
+                SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            )),
+        };
+
+        // ```hurl
+        // foo: hello.txt; charset=iso-8859-1; filename=report.txt
+        // ```
+        assert_eq!(
+            eval_part_attributes(
+                &file_value("charset=iso-8859-1; filename=report.txt"),
+                &variables
+            )
+            .unwrap(),
+            PartAttributes {
+                content_type: "text/plain; charset=iso-8859-1".to_string(),
+                filename: Some("report.txt".to_string()),
+                headers: vec![],
+            }
+        );
+
+        // ```hurl
+        // foo: hello.txt; text/csv; charset=utf-8; filename="my report.csv"
+        // ```
+        assert_eq!(
+            eval_part_attributes(
+                &file_value("text/csv; charset=utf-8; filename=\"my report.csv\""),
+                &variables
+            )
+            .unwrap(),
+            PartAttributes {
+                content_type: "text/csv; charset=utf-8".to_string(),
+                filename: Some("my report.csv".to_string()),
+                headers: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn test_split_part_attributes() {
+        assert_eq!(
+            split_part_attributes("text/plain; charset=us-ascii"),
+            PartAttributes {
+                content_type: "text/plain; charset=us-ascii".to_string(),
+                filename: None,
+                headers: vec![],
+            }
+        );
+        assert_eq!(
+            split_part_attributes("text/xml; Content-Transfer-Encoding: binary; X-Id: 1"),
+            PartAttributes {
+                content_type: "text/xml".to_string(),
+                filename: None,
+                headers: vec![
+                    http::Header::new("Content-Transfer-Encoding", "binary"),
+                    http::Header::new("X-Id", "1"),
+                ],
+            }
+        );
+        assert_eq!(
+            split_part_attributes("Content-Transfer-Encoding: base64"),
+            PartAttributes {
+                content_type: String::new(),
+                filename: None,
+                headers: vec![http::Header::new("Content-Transfer-Encoding", "base64")],
+            }
+        );
+        assert_eq!(
+            split_part_attributes("application/pdf; Filename = \"invoice.pdf\""),
+            PartAttributes {
+                content_type: "application/pdf".to_string(),
+                filename: Some("invoice.pdf".to_string()),
+                headers: vec![],
+            }
+        );
+    }
 }
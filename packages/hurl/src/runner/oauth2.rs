@@ -0,0 +1,264 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! OAuth 2.0 access token acquisition, using the client credentials grant
+//! (see <https://datatracker.ietf.org/doc/html/rfc6749#section-4.4>).
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::http;
+use crate::http::{ClientOptions, HeaderVec, Method, Param, RequestSpec, Url};
+use crate::util::logger::Logger;
+
+use super::error::RunnerErrorKind;
+use super::runner_options::RunnerOptions;
+
+/// Client credentials used to request an access token to an authorization server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientCredentials {
+    /// URL of the token endpoint.
+    pub token_url: String,
+    pub client_id: String,
+    pub client_secret: String,
+}
+
+impl ClientCredentials {
+    /// Returns the client credentials configured in `runner_options`, or `None` if no token URL
+    /// has been set.
+    pub fn from(runner_options: &RunnerOptions) -> Result<Option<Self>, RunnerErrorKind> {
+        let Some(token_url) = &runner_options.oauth2_token_url else {
+            return Ok(None);
+        };
+        let missing = |name: &str| RunnerErrorKind::OAuth2Token {
+            url: token_url.clone(),
+            message: format!("missing {name} option"),
+        };
+        let client_id = runner_options
+            .oauth2_client_id
+            .clone()
+            .ok_or_else(|| missing("oauth2-client-id"))?;
+        let client_secret = runner_options
+            .oauth2_client_secret
+            .clone()
+            .ok_or_else(|| missing("oauth2-client-secret"))?;
+        Ok(Some(ClientCredentials {
+            token_url: token_url.clone(),
+            client_id,
+            client_secret,
+        }))
+    }
+}
+
+/// An access token, with an optional expiration.
+#[derive(Clone, Debug)]
+struct AccessToken {
+    value: String,
+    expires_at: Option<Instant>,
+}
+
+/// This is a cache to hold the access tokens, per token URL. This cache lives for a given Hurl
+/// file run, so that entries sharing the same authorization server only request one token.
+#[derive(Clone, Debug, Default)]
+pub struct TokenCache {
+    tokens: HashMap<String, AccessToken>,
+}
+
+impl TokenCache {
+    /// Creates a new empty cache.
+    pub fn new() -> Self {
+        TokenCache::default()
+    }
+
+    /// Returns the non expired access token for this `token_url`.
+    fn get(&self, token_url: &str) -> Option<&str> {
+        let token = self.tokens.get(token_url)?;
+        match token.expires_at {
+            Some(expires_at) if expires_at <= Instant::now() => None,
+            _ => Some(&token.value),
+        }
+    }
+
+    /// Removes the cached access token for this `token_url` so it will be requested again.
+    pub fn invalidate(&mut self, token_url: &str) {
+        self.tokens.remove(token_url);
+    }
+}
+
+/// Returns an access token for these client `credentials`, either from the `cache` or requested
+/// to the token endpoint with `http_client`.
+///
+/// The token request uses the connection settings of `options` (proxy, TLS etc...) but not the
+/// authentication and custom headers of the entry.
+pub fn get_access_token(
+    credentials: &ClientCredentials,
+    cache: &mut TokenCache,
+    http_client: &mut http::Client,
+    options: &ClientOptions,
+    logger: &mut Logger,
+) -> Result<String, RunnerErrorKind> {
+    if let Some(token) = cache.get(&credentials.token_url) {
+        logger.debug(&format!(
+            "Using cached OAuth 2.0 access token from {}",
+            credentials.token_url
+        ));
+        return Ok(token.to_string());
+    }
+
+    let error = |message: String| RunnerErrorKind::OAuth2Token {
+        url: credentials.token_url.clone(),
+        message,
+    };
+    logger.debug("");
+    logger.debug_important(&format!(
+        "Requesting OAuth 2.0 access token from {}",
+        credentials.token_url
+    ));
+
+    let url = Url::from_str(&credentials.token_url).map_err(|e| error(e.reason))?;
+    let request = RequestSpec {
+        method: Method("POST".to_string()),
+        url,
+        form: vec![Param {
+            name: "grant_type".to_string(),
+            value: "client_credentials".to_string(),
+        }],
+        implicit_content_type: Some("application/x-www-form-urlencoded".to_string()),
+        ..Default::default()
+    };
+    // The client authenticates with the authorization server using HTTP Basic authentication.
+    let options = ClientOptions {
+        aws_sigv4: None,
+        chunked: false,
+        digest: false,
+        expect_continue: None,
+        headers: HeaderVec::new(),
+        hmac_signature: None,
+        negotiate: false,
+        netrc: false,
+        netrc_file: None,
+        netrc_optional: false,
+        no_headers: vec![],
+        ntlm: false,
+        trailers: HeaderVec::new(),
+        user: Some(format!(
+            "{}:{}",
+            credentials.client_id, credentials.client_secret
+        )),
+        ..options.clone()
+    };
+    let calls = http_client
+        .execute_with_redirect(&request, &options, logger)
+        .map_err(|e| error(e.message()))?;
+    let Some(call) = calls.last() else {
+        return Err(error("no response".to_string()));
+    };
+    let response = &call.response;
+    if !(200..300).contains(&response.status) {
+        return Err(error(format!("unexpected HTTP status {}", response.status)));
+    }
+
+    let token = parse_token_response(&response.body).map_err(error)?;
+    let value = token.value.clone();
+    cache.tokens.insert(credentials.token_url.clone(), token);
+    Ok(value)
+}
+
+/// Parses the JSON body of a successful token response.
+fn parse_token_response(body: &[u8]) -> Result<AccessToken, String> {
+    let json = serde_json::from_slice::<serde_json::Value>(body)
+        .map_err(|_| "token response is not a valid JSON".to_string())?;
+    let Some(value) = json.get("access_token").and_then(|v| v.as_str()) else {
+        return Err("token response has no access_token".to_string());
+    };
+    let expires_at = json
+        .get("expires_in")
+        .and_then(|v| v.as_u64())
+        .map(|secs| Instant::now() + Duration::from_secs(secs));
+    Ok(AccessToken {
+        value: value.to_string(),
+        expires_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_token_response() {
+        let token =
+            parse_token_response(br#"{"access_token":"abc","token_type":"Bearer"}"#).unwrap();
+        assert_eq!(token.value, "abc");
+        assert!(token.expires_at.is_none());
+
+        let token = parse_token_response(br#"{"access_token":"abc","expires_in":3600}"#).unwrap();
+        assert!(token.expires_at.is_some());
+
+        assert_eq!(
+            parse_token_response(br#"{"error":"invalid_client"}"#).unwrap_err(),
+            "token response has no access_token"
+        );
+        assert_eq!(
+            parse_token_response(b"xxx").unwrap_err(),
+            "token response is not a valid JSON"
+        );
+    }
+
+    #[test]
+    fn test_token_cache() {
+        let mut cache = TokenCache::new();
+        cache.tokens.insert(
+            "https://auth.com/token".to_string(),
+            AccessToken {
+                value: "abc".to_string(),
+                expires_at: None,
+            },
+        );
+        cache.tokens.insert(
+            "https://auth.org/token".to_string(),
+            AccessToken {
+                value: "def".to_string(),
+                expires_at: Some(Instant::now()),
+            },
+        );
+        assert_eq!(cache.get("https://auth.com/token"), Some("abc"));
+        assert_eq!(cache.get("https://auth.org/token"), None);
+
+        cache.invalidate("https://auth.com/token");
+        assert_eq!(cache.get("https://auth.com/token"), None);
+    }
+
+    #[test]
+    fn test_client_credentials() {
+        let options = RunnerOptions::default();
+        assert_eq!(ClientCredentials::from(&options), Ok(None));
+
+        let options = RunnerOptions {
+            oauth2_token_url: Some("https://auth.com/token".to_string()),
+            oauth2_client_id: Some("bob".to_string()),
+            ..RunnerOptions::default()
+        };
+        assert_eq!(
+            ClientCredentials::from(&options),
+            Err(RunnerErrorKind::OAuth2Token {
+                url: "https://auth.com/token".to_string(),
+                message: "missing oauth2-client-secret option".to_string()
+            })
+        );
+    }
+}
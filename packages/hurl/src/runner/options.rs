@@ -15,15 +15,21 @@
  * limitations under the License.
  *
  */
+use std::path::Path;
+
 use hurl_core::ast::{
-    BooleanOption, CountOption, DurationOption, Entry, NaturalOption, Number as AstNumber,
-    OptionKind, Placeholder, Template, VariableDefinition, VariableValue, VerbosityOption,
+    AuthTypeOption, BooleanOption, ClientCertTypeOption, CountOption, DurationOption, Entry,
+    EntryOption, NaturalOption, Number as AstNumber, OptionKind, Placeholder, SourceInfo, Template,
+    VariableDefinition, VariableValue, VerbosityOption,
 };
 use hurl_core::types::{BytesPerSec, Count, DurationUnit};
 
-use crate::http::{CredentialForwarding, FollowLocation, Header, IpResolve, RequestedHttpVersion};
+use crate::http::{
+    ClientCertType, CredentialForwarding, FollowLocation, Header, IpResolve, RequestedHttpVersion,
+};
 use crate::pretty::PrettyMode;
 use crate::util::logger::{Logger, Verbosity};
+use crate::util::path::ContextDir;
 
 use super::error::{RunnerError, RunnerErrorKind};
 use super::expr;
@@ -43,13 +49,12 @@ pub fn get_entry_options(
     variables: &mut VariableSet,
     logger: &mut Logger,
 ) -> Result<RunnerOptions, RunnerError> {
-    let runner_options = runner_options.clone();
     // When used globally (on the command line), `--output` writes the last successful request
     // to `output` file. We don't want to output every entry's response, so we initialize
     // output to `None`.
     let mut entry_options = RunnerOptions {
         output: None,
-        ..runner_options
+        ..runner_options.clone()
     };
     if entry.request.options().is_empty() {
         return Ok(entry_options);
@@ -58,24 +63,52 @@ pub fn get_entry_options(
     logger.debug("");
     logger.debug_important("Entry options:");
 
+    let context_dir = &runner_options.context_dir;
     for option in entry.request.options() {
+        check_sandbox(option, context_dir, variables)?;
         match &option.kind {
+            // The authentication type selects one of the digest, ntlm and negotiate schemes,
+            // the credentials being given by the user option.
+            OptionKind::AuthType(value) => {
+                entry_options.digest = *value == AuthTypeOption::Digest;
+                entry_options.ntlm = *value == AuthTypeOption::Ntlm;
+                entry_options.negotiate = *value == AuthTypeOption::Negotiate;
+            }
             OptionKind::AwsSigV4(value) => {
                 let value = eval_template(value, variables)?;
                 entry_options.aws_sigv4 = Some(value);
             }
             OptionKind::CaCertificate(filename) => {
-                let value = eval_template(filename, variables)?;
+                let value = eval_file_option(filename, variables, context_dir)?;
                 entry_options.cacert_file = Some(value);
             }
+            OptionKind::Chunked(value) => {
+                let value = eval_boolean_option(value, variables)?;
+                entry_options.chunked = value;
+            }
             OptionKind::ClientCert(filename) => {
-                let value = eval_template(filename, variables)?;
+                let value = eval_file_option(filename, variables, context_dir)?;
                 entry_options.client_cert_file = Some(value);
             }
+            OptionKind::ClientCertPassword(value) => {
+                let value = eval_template(value, variables)?;
+                entry_options.client_cert_password = Some(value);
+            }
+            OptionKind::ClientCertType(value) => {
+                entry_options.client_cert_type = match value {
+                    ClientCertTypeOption::Pem => ClientCertType::Pem,
+                    ClientCertTypeOption::Der => ClientCertType::Der,
+                    ClientCertTypeOption::P12 => ClientCertType::P12,
+                };
+            }
             OptionKind::ClientKey(filename) => {
-                let value = eval_template(filename, variables)?;
+                let value = eval_file_option(filename, variables, context_dir)?;
                 entry_options.client_key_file = Some(value);
             }
+            OptionKind::ClockSkew(value) => {
+                let value = eval_duration_option(value, variables, DurationUnit::MilliSecond)?;
+                entry_options.clock_skew = value;
+            }
             OptionKind::Compressed(value) => {
                 let value = eval_boolean_option(value, variables)?;
                 entry_options.compressed = value;
@@ -88,6 +121,10 @@ pub fn get_entry_options(
                 let value = eval_duration_option(value, variables, DurationUnit::MilliSecond)?;
                 entry_options.connect_timeout = value;
             }
+            OptionKind::CookieFile(filename) => {
+                let value = eval_file_option(filename, variables, context_dir)?;
+                entry_options.cookie_input_file = Some(value);
+            }
             OptionKind::Delay(value) => {
                 let value = eval_duration_option(value, variables, DurationUnit::MilliSecond)?;
                 entry_options.delay = value;
@@ -96,10 +133,25 @@ pub fn get_entry_options(
                 let value = eval_boolean_option(value, variables)?;
                 entry_options.digest = value;
             }
+            OptionKind::DnsServers(value) => {
+                let value = eval_template(value, variables)?;
+                entry_options.dns_servers = Some(value);
+            }
+            OptionKind::ExpectContinue(value) => {
+                let value = eval_boolean_option(value, variables)?;
+                entry_options.expect_continue = Some(value);
+            }
+            // Exported captures are collected by the scheduler from the Hurl file content, before
+            // and after the file run.
+            OptionKind::ExportCapture(_) => {}
             OptionKind::Header(header) => {
-                let header = eval_header_option(header, variables)?;
+                let header = eval_header_option("header", header, variables)?;
                 entry_options.headers.push(header);
             }
+            OptionKind::HmacSignature(value) => {
+                let value = eval_template(value, variables)?;
+                entry_options.hmac_signature = Some(value);
+            }
             // HTTP version options (such as http1.0, http1.1, http2 etc...) are activated
             // through a flag. In an `[Options]` section, the signification of such a flag is:
             //
@@ -166,6 +218,10 @@ pub fn get_entry_options(
                 }
                 entry_options.allow_reuse = false;
             }
+            OptionKind::Http3Fallback(value) => {
+                let value = eval_boolean_option(value, variables)?;
+                entry_options.http3_fallback = value;
+            }
             OptionKind::FailWithBody(value) => {
                 let value = eval_boolean_option(value, variables)?;
                 entry_options.fail_with_body = value;
@@ -186,6 +242,10 @@ pub fn get_entry_options(
                         FollowLocation::Follow(CredentialForwarding::AllHosts);
                 }
             }
+            OptionKind::Foreach(value) => {
+                let value = eval_list_option(value, variables)?;
+                entry_options.foreach = Some(value);
+            }
             OptionKind::Insecure(value) => {
                 let value = eval_boolean_option(value, variables)?;
                 entry_options.insecure = value;
@@ -219,6 +279,10 @@ pub fn get_entry_options(
                 let value = eval_duration_option(value, variables, DurationUnit::MilliSecond)?;
                 entry_options.timeout = value;
             }
+            OptionKind::MultipartBoundary(value) => {
+                let value = eval_template(value, variables)?;
+                entry_options.multipart_boundary = Some(value);
+            }
             OptionKind::Negotiate(value) => {
                 let value = eval_boolean_option(value, variables)?;
                 entry_options.negotiate = value;
@@ -228,7 +292,7 @@ pub fn get_entry_options(
                 entry_options.netrc = value;
             }
             OptionKind::NetRcFile(value) => {
-                let filename = eval_template(value, variables)?;
+                let filename = eval_file_option(value, variables, context_dir)?;
                 entry_options.netrc_file = Some(filename);
             }
             OptionKind::NetRcOptional(value) => {
@@ -243,6 +307,27 @@ pub fn get_entry_options(
                 let value = eval_boolean_option(value, variables)?;
                 entry_options.ntlm = value;
             }
+            OptionKind::OAuth2ClientId(value) => {
+                let value = eval_template(value, variables)?;
+                entry_options.oauth2_client_id = Some(value);
+            }
+            OptionKind::OAuth2ClientSecret(value) => {
+                let value = eval_template(value, variables)?;
+                entry_options.oauth2_client_secret = Some(value);
+            }
+            OptionKind::OAuth2TokenUrl(value) => {
+                let value = eval_template(value, variables)?;
+                entry_options.oauth2_token_url = Some(value);
+            }
+            OptionKind::OnlyIf(value) => {
+                if !eval_boolean_option(value, variables)? {
+                    entry_options.skip = true;
+                }
+            }
+            OptionKind::SoftAsserts(value) => {
+                let value = eval_boolean_option(value, variables)?;
+                entry_options.soft_asserts = value;
+            }
             OptionKind::Output(output) => {
                 let filename = eval_template(output, variables)?;
                 let output = Output::new(&filename);
@@ -252,6 +337,13 @@ pub fn get_entry_options(
                 }
                 entry_options.output = Some(output);
             }
+            OptionKind::Paginate(name) => {
+                entry_options.paginate = Some(name.clone());
+            }
+            OptionKind::PaginateMax(value) => {
+                let value = eval_count_option(value, variables)?;
+                entry_options.paginate_max = value;
+            }
             OptionKind::PathAsIs(value) => {
                 let value = eval_boolean_option(value, variables)?;
                 entry_options.path_as_is = value;
@@ -264,6 +356,14 @@ pub fn get_entry_options(
                 let value = eval_template(value, variables)?;
                 entry_options.proxy = Some(value);
             }
+            OptionKind::ProxyHeader(header) => {
+                let header = eval_header_option("proxy-header", header, variables)?;
+                entry_options.proxy_headers.push(header);
+            }
+            OptionKind::ProxyUser(value) => {
+                let value = eval_template(value, variables)?;
+                entry_options.proxy_user = Some(value);
+            }
             OptionKind::Repeat(value) => {
                 let value = eval_count_option(value, variables)?;
                 entry_options.repeat = Some(value);
@@ -280,13 +380,32 @@ pub fn get_entry_options(
                 let value = eval_duration_option(value, variables, DurationUnit::MilliSecond)?;
                 entry_options.retry_interval = value;
             }
+            OptionKind::RetryOnCaptureFailure(value) => {
+                let value = eval_boolean_option(value, variables)?;
+                entry_options.retry_on_capture_failure = value;
+            }
             OptionKind::Skip(value) => {
                 let value = eval_boolean_option(value, variables)?;
                 entry_options.skip = value;
             }
+            OptionKind::SkipIf(value) => {
+                if eval_boolean_option(value, variables)? {
+                    entry_options.skip = true;
+                }
+            }
+            OptionKind::NestedFormParams(value) => {
+                let value = eval_boolean_option(value, variables)?;
+                entry_options.nested_form_params = value;
+            }
+            OptionKind::Trailer(trailer) => {
+                let trailer = eval_header_option("trailer", trailer, variables)?;
+                entry_options.trailers.push(trailer);
+            }
             OptionKind::UnixSocket(value) => {
                 let value = eval_template(value, variables)?;
-                entry_options.unix_socket = Some(value);
+                // An empty socket disables the Unix socket (including one set from the command
+                // line), so the same file can be run against the network.
+                entry_options.unix_socket = if value.is_empty() { None } else { Some(value) };
             }
             OptionKind::User(value) => {
                 let value = eval_template(value, variables)?;
@@ -355,14 +474,73 @@ pub fn get_entry_verbosity(
     Ok(verbosity)
 }
 
-/// Evaluate a template into a [`Header`], given a set of variables.
-fn eval_header_option(header: &Template, variables: &VariableSet) -> Result<Header, RunnerError> {
+/// Checks that an entry `option` can be used in sandbox mode, given a `context_dir`.
+///
+/// In sandbox mode, an entry can't use the `.netrc` file of the user, connect to a Unix socket, or
+/// use a proxy or an OAuth 2.0 token endpoint with a scheme other than HTTP or HTTPS.
+fn check_sandbox(
+    option: &EntryOption,
+    context_dir: &ContextDir,
+    variables: &VariableSet,
+) -> Result<(), RunnerError> {
+    if !context_dir.is_sandboxed() {
+        return Ok(());
+    }
+    let name = option.kind.identifier();
+    let source_info = SourceInfo::new(
+        option.space0.source_info.end,
+        option.space1.source_info.start,
+    );
+    let allowed = match &option.kind {
+        OptionKind::NetRc(value) | OptionKind::NetRcOptional(value) => {
+            !eval_boolean_option(value, variables)?
+        }
+        OptionKind::UnixSocket(value) => eval_template(value, variables)?.is_empty(),
+        OptionKind::Proxy(value) | OptionKind::OAuth2TokenUrl(value) => {
+            let url = eval_template(value, variables)?;
+            context_dir.check_url(&url).is_ok()
+        }
+        _ => true,
+    };
+    if allowed {
+        return Ok(());
+    }
+    let kind = RunnerErrorKind::SandboxViolation {
+        message: format!("option {name}"),
+    };
+    Err(RunnerError::new(source_info, kind, false))
+}
+
+/// Evaluates the file name of an option, like `cacert` or `netrc-file`, given a set of `variables`.
+///
+/// In sandbox mode, the file is resolved from the `context_dir` and must be under the file root.
+fn eval_file_option(
+    filename: &Template,
+    variables: &VariableSet,
+    context_dir: &ContextDir,
+) -> Result<String, RunnerError> {
+    let value = eval_template(filename, variables)?;
+    if !context_dir.is_sandboxed() {
+        return Ok(value);
+    }
+    let path = context_dir
+        .read_path(Path::new(&value))
+        .map_err(|e| RunnerError::new(filename.source_info, e.into(), false))?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// Evaluate a template into a [`Header`] for the option `name`, given a set of variables.
+fn eval_header_option(
+    name: &str,
+    header: &Template,
+    variables: &VariableSet,
+) -> Result<Header, RunnerError> {
     let source_info = header.source_info;
     let header = eval_template(header, variables)?;
     Header::parse(&header).ok_or({
         let message = "missing `:`".to_string();
         let kind = RunnerErrorKind::InvalidOptionValue {
-            name: "header".to_string(),
+            name: name.to_string(),
             value: header,
             message,
         };
@@ -392,6 +570,24 @@ fn eval_boolean_option(
     }
 }
 
+/// Evaluates a list option, using a set of `variables`.
+fn eval_list_option(
+    placeholder: &Placeholder,
+    variables: &VariableSet,
+) -> Result<Vec<Value>, RunnerError> {
+    let Placeholder { expr, .. } = placeholder;
+    match expr::eval(expr, variables)? {
+        Value::List(values) => Ok(values),
+        v => {
+            let kind = RunnerErrorKind::ExpressionInvalidType {
+                value: v.repr(),
+                expecting: "list".to_string(),
+            };
+            Err(RunnerError::new(expr.source_info, kind, false))
+        }
+    }
+}
+
 /// Evaluates a natural option, using a set of `variables`.
 fn eval_natural_option(
     natural_value: &NaturalOption,
@@ -532,7 +728,8 @@ fn eval_number(number: &AstNumber) -> Value {
 #[cfg(test)]
 mod tests {
     use hurl_core::ast::{
-        Duration, Expr, ExprKind, Placeholder, SourceInfo, U64, Variable, Whitespace,
+        Duration, Expr, ExprKind, Placeholder, SourceInfo, TemplateElement, U64, Variable,
+        Whitespace,
     };
     use hurl_core::reader::Pos;
     use hurl_core::types::{DurationUnit, ToSource};
@@ -618,6 +815,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_eval_list_option() {
+        let placeholder = Placeholder {
+            space0: Whitespace {
+                value: String::new(),
+                source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            },
+            expr: Expr {
+                kind: ExprKind::Variable(Variable {
+                    name: "users".to_string(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                }),
+                source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            },
+            space1: Whitespace {
+                value: String::new(),
+                source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            },
+        };
+        let mut variables = VariableSet::default();
+        let users = vec![
+            Value::String("alice".to_string()),
+            Value::String("bob".to_string()),
+        ];
+        variables.insert("users".to_string(), Value::List(users.clone()));
+        assert_eq!(eval_list_option(&placeholder, &variables).unwrap(), users);
+
+        variables.insert("users".to_string(), Value::String("alice".to_string()));
+        let error = eval_list_option(&placeholder, &variables).err().unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::ExpressionInvalidType {
+                value: "string <alice>".to_string(),
+                expecting: "list".to_string()
+            }
+        );
+    }
+
     #[test]
     fn test_eval_natural_option() {
         let mut variables = VariableSet::default();
@@ -645,4 +880,43 @@ mod tests {
             std::time::Duration::from_millis(10)
         );
     }
+
+    #[test]
+    fn test_eval_file_option() {
+        let filename = |value: &str| {
+            Template::new(
+                None,
+                vec![TemplateElement::String {
+                    value: value.to_string(),
+                    source: value.to_source(),
+                }],
+                SourceInfo::new(Pos::new(1, 9), Pos::new(1, 9 + value.len())),
+            )
+        };
+        let variables = VariableSet::new();
+        let context_dir = ContextDir::new(Path::new("/tmp"), Path::new("tests"));
+        assert_eq!(
+            eval_file_option(&filename("/etc/ca.pem"), &variables, &context_dir).unwrap(),
+            "/etc/ca.pem"
+        );
+
+        // In sandbox mode, files are resolved from the file root, and can't be outside it.
+        let context_dir = context_dir.with_sandbox(true);
+        assert_eq!(
+            eval_file_option(&filename("ca.pem"), &variables, &context_dir).unwrap(),
+            "tests/ca.pem"
+        );
+        let error =
+            eval_file_option(&filename("/etc/ca.pem"), &variables, &context_dir).unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::SandboxViolation {
+                message: "reading file /etc/ca.pem".to_string()
+            }
+        );
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 9), Pos::new(1, 20))
+        );
+    }
 }
@@ -25,7 +25,7 @@ use hurl_core::ast::SourceInfo;
 use crate::util::path::ContextDir;
 use crate::util::term::Stdout;
 
-use super::error::{RunnerError, RunnerErrorKind};
+use super::error::RunnerError;
 
 /// Represents the output of write operation: can be either a file or standard output.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -58,8 +58,8 @@ impl Output {
 
     /// Consume an output and returns a new output given a `context_dir`.
     ///
-    /// If the output is a file, and the file is not allowed to write given this `context_dir`, this
-    /// method returns an error.
+    /// If the output is a file, and the file is not allowed to write given this `context_dir` (or
+    /// if `context_dir` is sandboxed), this method returns an error.
     pub fn try_with(
         self,
         context_dir: &ContextDir,
@@ -68,12 +68,9 @@ impl Output {
         let output = match self {
             Output::Stdout => Output::Stdout,
             Output::File(filename) => {
-                if !context_dir.is_access_allowed(&filename) {
-                    let kind = RunnerErrorKind::UnauthorizedFileAccess { path: filename };
-                    let error = RunnerError::new(source_info, kind, false);
-                    return Err(error);
-                }
-                let path = context_dir.resolved_path(&filename);
+                let path = context_dir
+                    .write_path(&filename)
+                    .map_err(|e| RunnerError::new(source_info, e.into(), false))?;
                 Output::File(path)
             }
         };
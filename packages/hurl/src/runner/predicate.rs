@@ -16,18 +16,44 @@
  *
  */
 use std::cmp::Ordering;
+use std::hint;
+use std::time::Duration;
 
-use hurl_core::ast::{Predicate, PredicateFunc, PredicateFuncValue, PredicateValue, SourceInfo};
+use hurl_core::ast::{
+    Ignoring, Predicate, PredicateFunc, PredicateFuncValue, PredicateValue, SourceInfo,
+};
 use hurl_core::reader::Pos;
 
+use crate::jsonpath;
 use crate::util::path::ContextDir;
 
 use super::error::{RunnerError, RunnerErrorKind};
+use super::hex;
+use super::json_diff::{DEFAULT_JSON_DIFF_LIMIT, JsonDiff, json_diff};
+use super::json_include::{json_equals, json_includes};
 use super::number::Number;
 use super::predicate_value::{eval_predicate_value, eval_predicate_value_template};
+use super::template::eval_template;
 use super::value::{EvalError, Value};
 use super::variable::VariableSet;
 
+/// Options controlling predicate evaluation behavior.
+pub struct PredicateOptions {
+    /// Tolerance of date comparisons: dates at most `clock_skew` apart are considered as equal.
+    pub clock_skew: Duration,
+    /// Maximum number of differences reported when a JSON equality fails, 0 to disable.
+    pub json_diff_limit: usize,
+}
+
+impl Default for PredicateOptions {
+    fn default() -> Self {
+        PredicateOptions {
+            clock_skew: Duration::ZERO,
+            json_diff_limit: DEFAULT_JSON_DIFF_LIMIT,
+        }
+    }
+}
+
 /// Evaluates a `predicate` against an actual `value`.
 ///
 /// The predicate is a test with an expected value. The expected value (contained in the `predicate`
@@ -51,12 +77,14 @@ pub fn eval_predicate(
     variables: &VariableSet,
     value: &Option<Value>,
     context_dir: &ContextDir,
+    options: &PredicateOptions,
 ) -> Result<(), RunnerError> {
     let result = eval_predicate_func(
         &predicate.predicate_func,
         variables,
         value.as_ref(),
         context_dir,
+        options,
     )?;
 
     // Column error is set to 0 to disable the error display of "^^^"
@@ -79,6 +107,7 @@ pub fn eval_predicate(
             actual: result.actual,
             expected,
             type_mismatch: true,
+            json_diff: None,
         };
         Err(RunnerError::new(source_info, kind, true))
     } else if predicate.not == result.success {
@@ -88,6 +117,7 @@ pub fn eval_predicate(
             actual: result.actual,
             expected,
             type_mismatch: false,
+            json_diff: result.json_diff.map(Box::new),
         };
         Err(RunnerError::new(source_info, kind, true))
     } else {
@@ -101,6 +131,8 @@ struct PredicateResult {
     pub type_mismatch: bool,
     pub actual: String,
     pub expected: String,
+    /// Differences between actual and expected JSON values, for a failed JSON equality.
+    pub json_diff: Option<JsonDiff>,
 }
 
 impl Value {
@@ -126,6 +158,13 @@ impl Value {
     }
 }
 
+impl Value {
+    /// Returns a representation of this value with its type only, its content being redacted.
+    fn redacted_repr(&self) -> String {
+        format!("{} <***>", self.kind())
+    }
+}
+
 impl Number {
     fn expected(&self) -> String {
         match self {
@@ -156,6 +195,10 @@ fn expected_no_value(
             let value = eval_predicate_value(value, variables, context_dir)?;
             Ok(value.format())
         }
+        PredicateFuncValue::ConstantTimeEqual { value, .. } => {
+            let value = eval_predicate_value(value, variables, context_dir)?;
+            Ok(format!("constant time equals {}", value.redacted_repr()))
+        }
         PredicateFuncValue::GreaterThan { value, .. } => {
             let value = eval_predicate_value(value, variables, context_dir)?;
             Ok(format!("greater than <{}>", value.format()))
@@ -188,6 +231,10 @@ fn expected_no_value(
             let value = eval_predicate_value(value, variables, context_dir)?;
             Ok(format!("include {}", value.format()))
         }
+        PredicateFuncValue::IncludeJson { value, .. } => {
+            let value = eval_predicate_value(value, variables, context_dir)?;
+            Ok(format!("includes JSON {}", value.format()))
+        }
         PredicateFuncValue::Match {
             value: expected, ..
         } => {
@@ -220,6 +267,7 @@ fn eval_predicate_func(
     variables: &VariableSet,
     value: Option<&Value>,
     context_dir: &ContextDir,
+    options: &PredicateOptions,
 ) -> Result<PredicateResult, RunnerError> {
     let value = match value {
         Some(value) => value,
@@ -230,29 +278,55 @@ fn eval_predicate_func(
                 actual: "none".to_string(),
                 expected,
                 type_mismatch: false,
+                json_diff: None,
             });
         }
     };
 
     match &predicate_func.value {
+        PredicateFuncValue::Equal {
+            value: expected,
+            ignoring: Some(ignoring),
+            ..
+        } => eval_equal_ignoring(
+            expected,
+            ignoring,
+            predicate_func.source_info,
+            variables,
+            value,
+            context_dir,
+            options.json_diff_limit,
+        ),
         PredicateFuncValue::Equal {
             value: expected, ..
-        } => eval_equal(expected, variables, value, context_dir),
+        } => eval_equal(
+            expected,
+            variables,
+            value,
+            context_dir,
+            options.clock_skew,
+            options.json_diff_limit,
+        ),
         PredicateFuncValue::NotEqual {
             value: expected, ..
-        } => eval_not_equal(expected, variables, value, context_dir),
+        } => eval_not_equal(expected, variables, value, context_dir, options.clock_skew),
+        PredicateFuncValue::ConstantTimeEqual {
+            value: expected, ..
+        } => eval_constant_time_equal(expected, variables, value, context_dir),
         PredicateFuncValue::GreaterThan {
             value: expected, ..
-        } => eval_greater_than(expected, variables, value, context_dir),
+        } => eval_greater_than(expected, variables, value, context_dir, options.clock_skew),
         PredicateFuncValue::GreaterThanOrEqual {
             value: expected, ..
-        } => eval_greater_than_or_equal(expected, variables, value, context_dir),
+        } => {
+            eval_greater_than_or_equal(expected, variables, value, context_dir, options.clock_skew)
+        }
         PredicateFuncValue::LessThan {
             value: expected, ..
-        } => eval_less_than(expected, variables, value, context_dir),
+        } => eval_less_than(expected, variables, value, context_dir, options.clock_skew),
         PredicateFuncValue::LessThanOrEqual {
             value: expected, ..
-        } => eval_less_than_or_equal(expected, variables, value, context_dir),
+        } => eval_less_than_or_equal(expected, variables, value, context_dir, options.clock_skew),
         PredicateFuncValue::StartWith {
             value: expected, ..
         } => eval_start_with(expected, variables, value, context_dir),
@@ -265,6 +339,15 @@ fn eval_predicate_func(
         PredicateFuncValue::Include {
             value: expected, ..
         } => eval_include(expected, variables, value, context_dir),
+        PredicateFuncValue::IncludeJson {
+            value: expected, ..
+        } => eval_include_json(
+            expected,
+            predicate_func.source_info,
+            variables,
+            value,
+            context_dir,
+        ),
         PredicateFuncValue::Match {
             value: expected, ..
         } => eval_match(
@@ -298,9 +381,74 @@ fn eval_equal(
     variables: &VariableSet,
     actual: &Value,
     context_dir: &ContextDir,
+    clock_skew: Duration,
+    json_diff_limit: usize,
+) -> Result<PredicateResult, RunnerError> {
+    let expected = eval_predicate_value(expected, variables, context_dir)?;
+    let mut result = assert_values_equal(actual, &expected, clock_skew);
+    if !result.success {
+        result.json_diff = values_json_diff(actual, &expected, json_diff_limit);
+    }
+    Ok(result)
+}
+
+/// Evaluates if an `expected` JSON value (using a `variables` set) is equal to an `actual` value,
+/// once the nodes matched by the `ignoring` JSONPath expressions have been removed from both values.
+fn eval_equal_ignoring(
+    expected: &PredicateValue,
+    ignoring: &Ignoring,
+    source_info: SourceInfo,
+    variables: &VariableSet,
+    actual: &Value,
+    context_dir: &ContextDir,
+    json_diff_limit: usize,
 ) -> Result<PredicateResult, RunnerError> {
+    let mut paths = vec![];
+    let mut queries = vec![];
+    for (_, template) in &ignoring.paths {
+        let path = eval_template(template, variables)?;
+        let Ok(query) = jsonpath::parse(&path) else {
+            let kind = RunnerErrorKind::QueryInvalidJsonpathExpression { value: path };
+            return Err(RunnerError::new(template.source_info, kind, false));
+        };
+        paths.push(format!("\"{path}\""));
+        queries.push(query);
+    }
+    let ignoring_display = format!("ignoring {}", paths.join(", "));
+
     let expected = eval_predicate_value(expected, variables, context_dir)?;
-    Ok(assert_values_equal(actual, &expected))
+    let Some(mut expected_json) = to_json(&expected) else {
+        let kind = RunnerErrorKind::InvalidJson {
+            value: expected.repr(),
+        };
+        return Err(RunnerError::new(source_info, kind, true));
+    };
+    let Some(mut actual_json) = to_json(actual) else {
+        return Ok(PredicateResult {
+            success: false,
+            actual: actual.repr(),
+            expected: format!("JSON <{expected_json}> {ignoring_display}"),
+            type_mismatch: true,
+            json_diff: None,
+        });
+    };
+    for query in &queries {
+        query.remove(&mut expected_json);
+        query.remove(&mut actual_json);
+    }
+    let success = json_equals(&actual_json, &expected_json);
+    let json_diff = if success {
+        None
+    } else {
+        json_diff(&actual_json, &expected_json, json_diff_limit)
+    };
+    Ok(PredicateResult {
+        success,
+        actual: format!("JSON <{actual_json}>"),
+        expected: format!("JSON <{expected_json}> {ignoring_display}"),
+        type_mismatch: false,
+        json_diff,
+    })
 }
 
 /// Evaluates if an `expected` value (using a `variables` set) is not equal to an `actual` value.
@@ -309,9 +457,55 @@ fn eval_not_equal(
     variables: &VariableSet,
     actual: &Value,
     context_dir: &ContextDir,
+    clock_skew: Duration,
 ) -> Result<PredicateResult, RunnerError> {
     let expected = eval_predicate_value(expected, variables, context_dir)?;
-    Ok(assert_values_not_equal(actual, &expected))
+    Ok(assert_values_not_equal(actual, &expected, clock_skew))
+}
+
+/// Evaluates if an `expected` value (using a `variables` set) is equal to an `actual` value, in a
+/// time that doesn't depend on the compared values content.
+/// This predicate works with string and bytes. The compared values are redacted from the results,
+/// as they are usually secrets (tokens, signatures etc...).
+fn eval_constant_time_equal(
+    expected: &PredicateValue,
+    variables: &VariableSet,
+    actual: &Value,
+    context_dir: &ContextDir,
+) -> Result<PredicateResult, RunnerError> {
+    let expected = eval_predicate_value(expected, variables, context_dir)?;
+    let (success, type_mismatch) = match (actual, &expected) {
+        (Value::String(actual), Value::String(expected)) => (
+            constant_time_eq(actual.as_bytes(), expected.as_bytes()),
+            false,
+        ),
+        (Value::Bytes(actual), Value::Bytes(expected)) => {
+            (constant_time_eq(actual, expected), false)
+        }
+        _ => (false, true),
+    };
+    Ok(PredicateResult {
+        success,
+        actual: actual.redacted_repr(),
+        expected: format!("constant time equals {}", expected.redacted_repr()),
+        type_mismatch,
+        json_diff: None,
+    })
+}
+
+/// Returns `true` if the bytes `a` and `b` are equal.
+///
+/// All the bytes are compared, even after a first difference, so the comparison time only depends
+/// on the lengths of `a` and `b`.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    let mut diff = u8::from(a.len() != b.len());
+    for i in 0..a.len().max(b.len()) {
+        let x = a.get(i).copied().unwrap_or_default();
+        let y = b.get(i).copied().unwrap_or_default();
+        diff |= x ^ y;
+    }
+    // Prevents the compiler from short-circuiting the loop.
+    hint::black_box(diff) == 0
 }
 
 /// Evaluates if an `expected` value (using a `variables` set) is greater than an `actual` value.
@@ -320,9 +514,10 @@ fn eval_greater_than(
     variables: &VariableSet,
     actual: &Value,
     context_dir: &ContextDir,
+    clock_skew: Duration,
 ) -> Result<PredicateResult, RunnerError> {
     let expected = eval_predicate_value(expected, variables, context_dir)?;
-    Ok(assert_values_greater(actual, &expected))
+    Ok(assert_values_greater(actual, &expected, clock_skew))
 }
 
 /// Evaluates if an `expected` value (using a `variables` set) is greater than or equal to an `actual` value.
@@ -331,9 +526,12 @@ fn eval_greater_than_or_equal(
     variables: &VariableSet,
     actual: &Value,
     context_dir: &ContextDir,
+    clock_skew: Duration,
 ) -> Result<PredicateResult, RunnerError> {
     let expected = eval_predicate_value(expected, variables, context_dir)?;
-    Ok(assert_values_greater_or_equal(actual, &expected))
+    Ok(assert_values_greater_or_equal(
+        actual, &expected, clock_skew,
+    ))
 }
 
 /// Evaluates if an `expected` value (using a `variables` set) is less than an `actual` value.
@@ -342,9 +540,10 @@ fn eval_less_than(
     variables: &VariableSet,
     actual: &Value,
     context_dir: &ContextDir,
+    clock_skew: Duration,
 ) -> Result<PredicateResult, RunnerError> {
     let expected = eval_predicate_value(expected, variables, context_dir)?;
-    Ok(assert_values_less(actual, &expected))
+    Ok(assert_values_less(actual, &expected, clock_skew))
 }
 
 /// Evaluates if an `expected` value (using a `variables` set) is less than an `actual` value.
@@ -353,9 +552,10 @@ fn eval_less_than_or_equal(
     variables: &VariableSet,
     actual: &Value,
     context_dir: &ContextDir,
+    clock_skew: Duration,
 ) -> Result<PredicateResult, RunnerError> {
     let expected = eval_predicate_value(expected, variables, context_dir)?;
-    Ok(assert_values_less_or_equal(actual, &expected))
+    Ok(assert_values_less_or_equal(actual, &expected, clock_skew))
 }
 
 /// Evaluates if an `expected` value (using a `variables` set) starts with an `actual` value.
@@ -375,12 +575,14 @@ fn eval_start_with(
             actual: actual_display,
             expected: expected_display,
             type_mismatch: false,
+            json_diff: None,
         }),
         Err(_) => Ok(PredicateResult {
             success: false,
             actual: actual_display,
             expected: expected_display,
             type_mismatch: true,
+            json_diff: None,
         }),
     }
 }
@@ -402,18 +604,20 @@ fn eval_end_with(
             actual: actual_display,
             expected: expected_display,
             type_mismatch: false,
+            json_diff: None,
         }),
         Err(_) => Ok(PredicateResult {
             success: false,
             actual: actual_display,
             expected: expected_display,
             type_mismatch: true,
+            json_diff: None,
         }),
     }
 }
 
 /// Evaluates if an `expected` value (using a `variables` set) contains an `actual` value.
-/// This predicate works with string and bytes.
+/// This predicate works with string, bytes, lists, objects and HTTP responses.
 fn eval_contain(
     expected: &PredicateValue,
     variables: &VariableSet,
@@ -429,12 +633,14 @@ fn eval_contain(
             actual: actual_display,
             expected: expected_display,
             type_mismatch: false,
+            json_diff: None,
         }),
         _ => Ok(PredicateResult {
             success: false,
             actual: actual_display,
             expected: expected_display,
             type_mismatch: true,
+            json_diff: None,
         }),
     }
 }
@@ -451,6 +657,66 @@ fn eval_include(
     Ok(assert_include(actual, &expected))
 }
 
+/// Evaluates if an `actual` value deep-includes an `expected` JSON fragment (using a `variables` set).
+///
+/// Both values can be JSON strings or structured values (objects, lists etc...). Extra object
+/// members and list elements of the actual value are ignored (see [`json_includes`]).
+fn eval_include_json(
+    expected: &PredicateValue,
+    source_info: SourceInfo,
+    variables: &VariableSet,
+    actual: &Value,
+    context_dir: &ContextDir,
+) -> Result<PredicateResult, RunnerError> {
+    let expected = eval_predicate_value(expected, variables, context_dir)?;
+    let Some(expected_json) = to_json(&expected) else {
+        let kind = RunnerErrorKind::InvalidJson {
+            value: expected.repr(),
+        };
+        return Err(RunnerError::new(source_info, kind, true));
+    };
+    let expected_display = format!("includes JSON <{expected_json}>");
+    let actual_display = actual.repr();
+    match to_json(actual) {
+        Some(actual_json) => Ok(PredicateResult {
+            success: json_includes(&actual_json, &expected_json),
+            actual: actual_display,
+            expected: expected_display,
+            type_mismatch: false,
+            json_diff: None,
+        }),
+        None => Ok(PredicateResult {
+            success: false,
+            actual: actual_display,
+            expected: expected_display,
+            type_mismatch: true,
+            json_diff: None,
+        }),
+    }
+}
+
+/// Returns the differences between `actual` and `expected` values if both are JSON objects or
+/// arrays (or strings and bytes of JSON objects or arrays).
+fn values_json_diff(actual: &Value, expected: &Value, limit: usize) -> Option<JsonDiff> {
+    let actual = to_json(actual)?;
+    let expected = to_json(expected)?;
+    let is_structured = |value: &serde_json::Value| value.is_object() || value.is_array();
+    if !is_structured(&actual) || !is_structured(&expected) {
+        return None;
+    }
+    json_diff(&actual, &expected, limit)
+}
+
+/// Converts a `value` to a JSON value: strings and bytes are parsed as JSON text, other values
+/// are converted.
+fn to_json(value: &Value) -> Option<serde_json::Value> {
+    match value {
+        Value::String(s) => serde_json::from_str(s).ok(),
+        Value::Bytes(b) => serde_json::from_slice(b).ok(),
+        _ => value.try_to_json(),
+    }
+}
+
 /// Evaluates if an `expected` regex (using a `variables` set) matches an `actual` value.
 fn eval_match(
     expected: &PredicateValue,
@@ -468,12 +734,14 @@ fn eval_match(
             actual: actual_display,
             expected: expected_display,
             type_mismatch: false,
+            json_diff: None,
         }),
         Err(EvalError::Type) => Ok(PredicateResult {
             success: false,
             actual: actual_display,
             expected: expected_display,
             type_mismatch: true,
+            json_diff: None,
         }),
         Err(EvalError::InvalidRegex) => Err(RunnerError::new(
             source_info,
@@ -490,6 +758,7 @@ fn eval_is_integer(actual: &Value) -> Result<PredicateResult, RunnerError> {
         actual: actual.repr(),
         expected: "integer".to_string(),
         type_mismatch: false,
+        json_diff: None,
     })
 }
 
@@ -500,6 +769,7 @@ fn eval_is_float(actual: &Value) -> Result<PredicateResult, RunnerError> {
         actual: actual.repr(),
         expected: "float".to_string(),
         type_mismatch: false,
+        json_diff: None,
     })
 }
 
@@ -510,6 +780,7 @@ fn eval_is_boolean(actual: &Value) -> Result<PredicateResult, RunnerError> {
         actual: actual.repr(),
         expected: "boolean".to_string(),
         type_mismatch: false,
+        json_diff: None,
     })
 }
 
@@ -520,6 +791,7 @@ fn eval_is_string(actual: &Value) -> Result<PredicateResult, RunnerError> {
         actual: actual.repr(),
         expected: "string".to_string(),
         type_mismatch: false,
+        json_diff: None,
     })
 }
 
@@ -530,6 +802,7 @@ fn eval_is_collection(actual: &Value) -> Result<PredicateResult, RunnerError> {
         actual: actual.repr(),
         expected: "collection".to_string(),
         type_mismatch: false,
+        json_diff: None,
     })
 }
 
@@ -540,6 +813,7 @@ fn eval_is_list(actual: &Value) -> Result<PredicateResult, RunnerError> {
         actual: actual.repr(),
         expected: "list".to_string(),
         type_mismatch: false,
+        json_diff: None,
     })
 }
 
@@ -550,6 +824,7 @@ fn eval_is_object(actual: &Value) -> Result<PredicateResult, RunnerError> {
         actual: actual.repr(),
         expected: "object".to_string(),
         type_mismatch: false,
+        json_diff: None,
     })
 }
 
@@ -560,6 +835,7 @@ fn eval_is_date(actual: &Value) -> Result<PredicateResult, RunnerError> {
         actual: actual.repr(),
         expected: "date".to_string(),
         type_mismatch: false,
+        json_diff: None,
     })
 }
 
@@ -574,12 +850,14 @@ fn eval_is_iso_date(actual: &Value) -> Result<PredicateResult, RunnerError> {
             actual: actual.to_string(),
             expected: "string with format YYYY-MM-DDTHH:mm:ss.sssZ".to_string(),
             type_mismatch: false,
+            json_diff: None,
         }),
         _ => Ok(PredicateResult {
             success: false,
             actual: actual.repr(),
             expected: "string".to_string(),
             type_mismatch: true,
+            json_diff: None,
         }),
     }
 }
@@ -594,12 +872,14 @@ fn eval_exist(actual: &Value) -> Result<PredicateResult, RunnerError> {
             actual: actual_display,
             expected: expected_display,
             type_mismatch: false,
+            json_diff: None,
         }),
         _ => Ok(PredicateResult {
             success: true,
             actual: actual_display,
             expected: expected_display,
             type_mismatch: false,
+            json_diff: None,
         }),
     }
 }
@@ -615,6 +895,7 @@ fn eval_is_empty(actual: &Value) -> Result<PredicateResult, RunnerError> {
                 actual: actual_display,
                 expected: expected_display,
                 type_mismatch: false,
+                json_diff: None,
             })
         }
         _ => Ok(PredicateResult {
@@ -622,6 +903,7 @@ fn eval_is_empty(actual: &Value) -> Result<PredicateResult, RunnerError> {
             actual: actual.repr(),
             expected: expected_display,
             type_mismatch: true,
+            json_diff: None,
         }),
     }
 }
@@ -633,6 +915,7 @@ fn eval_is_number(actual: &Value) -> Result<PredicateResult, RunnerError> {
         actual: actual.repr(),
         expected: "number".to_string(),
         type_mismatch: false,
+        json_diff: None,
     })
 }
 
@@ -644,12 +927,14 @@ fn eval_is_ipv4(actual: &Value) -> Result<PredicateResult, RunnerError> {
             actual: actual.to_string(),
             expected: "string in IPv4 format".to_string(),
             type_mismatch: false,
+            json_diff: None,
         }),
         _ => Ok(PredicateResult {
             success: false,
             actual: actual.repr(),
             expected: "string".to_string(),
             type_mismatch: true,
+            json_diff: None,
         }),
     }
 }
@@ -662,12 +947,14 @@ fn eval_is_ipv6(actual: &Value) -> Result<PredicateResult, RunnerError> {
             actual: actual.to_string(),
             expected: "string in IPv6 format".to_string(),
             type_mismatch: false,
+            json_diff: None,
         }),
         _ => Ok(PredicateResult {
             success: false,
             actual: actual.repr(),
             expected: "string".to_string(),
             type_mismatch: true,
+            json_diff: None,
         }),
     }
 }
@@ -680,31 +967,71 @@ fn eval_is_uuid(actual: &Value) -> Result<PredicateResult, RunnerError> {
             actual: actual.to_string(),
             expected: "string in UUID format".to_string(),
             type_mismatch: false,
+            json_diff: None,
         }),
         _ => Ok(PredicateResult {
             success: false,
             actual: actual.repr(),
             expected: "string".to_string(),
             type_mismatch: true,
+            json_diff: None,
         }),
     }
 }
 
-fn assert_values_equal(actual: &Value, expected: &Value) -> PredicateResult {
-    let success = actual == expected;
-    let actual = actual.repr();
-    let expected = expected.repr();
+fn assert_values_equal(actual: &Value, expected: &Value, clock_skew: Duration) -> PredicateResult {
+    let success = actual == expected || dates_within_skew(actual, expected, clock_skew);
+    let (actual, expected) = match (actual, expected) {
+        (Value::Bytes(actual), Value::Bytes(expected))
+            if !success && actual.len().max(expected.len()) > BYTES_DIFF_WINDOW * 2 =>
+        {
+            bytes_diff(actual, expected)
+        }
+        _ => (actual.repr(), expected.repr()),
+    };
     let type_mismatch = false;
     PredicateResult {
         success,
         actual,
         expected,
         type_mismatch,
+        json_diff: None,
     }
 }
 
-fn assert_values_not_equal(actual: &Value, expected: &Value) -> PredicateResult {
-    let success = actual != expected;
+/// Number of bytes displayed around the first difference of two bytes values.
+const BYTES_DIFF_WINDOW: usize = 8;
+
+/// Returns a hex representation of two different bytes values `actual` and `expected`, displaying
+/// only the bytes surrounding their first difference with its offset.
+fn bytes_diff(actual: &[u8], expected: &[u8]) -> (String, String) {
+    let offset = actual
+        .iter()
+        .zip(expected.iter())
+        .position(|(a, e)| a != e)
+        .unwrap_or(actual.len().min(expected.len()));
+    let start = offset.saturating_sub(BYTES_DIFF_WINDOW);
+    let window = |bytes: &[u8]| {
+        let end = bytes.len().min(offset + BYTES_DIFF_WINDOW);
+        let start = start.min(end);
+        let prefix = if start > 0 { "..." } else { "" };
+        let suffix = if end < bytes.len() { "..." } else { "" };
+        let hex = hex::encode(&bytes[start..end]);
+        let len = bytes.len();
+        format!(
+            "bytes <{prefix}{hex}{suffix}> (offsets {start}..{end} of {len}, \
+             first difference at offset {offset})"
+        )
+    };
+    (window(actual), window(expected))
+}
+
+fn assert_values_not_equal(
+    actual: &Value,
+    expected: &Value,
+    clock_skew: Duration,
+) -> PredicateResult {
+    let success = actual != expected && !dates_within_skew(actual, expected, clock_skew);
     let actual = actual.repr();
     let expected = expected.repr();
     let type_mismatch = false;
@@ -713,86 +1040,135 @@ fn assert_values_not_equal(actual: &Value, expected: &Value) -> PredicateResult
         actual,
         expected,
         type_mismatch,
+        json_diff: None,
     }
 }
 
-fn assert_values_greater(actual_value: &Value, expected_value: &Value) -> PredicateResult {
+fn assert_values_greater(
+    actual_value: &Value,
+    expected_value: &Value,
+    clock_skew: Duration,
+) -> PredicateResult {
     let actual = actual_value.repr();
     let expected = format!("greater than {}", expected_value.repr());
 
-    match actual_value.compare(expected_value) {
+    match compare_values(actual_value, expected_value, clock_skew) {
         Ok(ordering) => PredicateResult {
             success: ordering == Ordering::Greater,
             actual,
             expected,
             type_mismatch: false,
+            json_diff: None,
         },
         _ => PredicateResult {
             success: false,
             actual,
             expected,
             type_mismatch: true,
+            json_diff: None,
         },
     }
 }
 
-fn assert_values_greater_or_equal(actual_value: &Value, expected_value: &Value) -> PredicateResult {
+fn assert_values_greater_or_equal(
+    actual_value: &Value,
+    expected_value: &Value,
+    clock_skew: Duration,
+) -> PredicateResult {
     let actual = actual_value.repr();
     let expected = format!("greater or equal than {}", expected_value.repr());
-    match actual_value.compare(expected_value) {
+    match compare_values(actual_value, expected_value, clock_skew) {
         Ok(ordering) => PredicateResult {
             success: ordering == Ordering::Greater || ordering == Ordering::Equal,
             actual,
             expected,
             type_mismatch: false,
+            json_diff: None,
         },
         _ => PredicateResult {
             success: false,
             actual,
             expected,
             type_mismatch: true,
+            json_diff: None,
         },
     }
 }
 
-fn assert_values_less(actual_value: &Value, expected_value: &Value) -> PredicateResult {
+fn assert_values_less(
+    actual_value: &Value,
+    expected_value: &Value,
+    clock_skew: Duration,
+) -> PredicateResult {
     let actual = actual_value.repr();
     let expected = format!("less than {}", expected_value.repr());
-    match actual_value.compare(expected_value) {
+    match compare_values(actual_value, expected_value, clock_skew) {
         Ok(ordering) => PredicateResult {
             success: ordering == Ordering::Less,
             actual,
             expected,
             type_mismatch: false,
+            json_diff: None,
         },
         _ => PredicateResult {
             success: false,
             actual,
             expected,
             type_mismatch: true,
+            json_diff: None,
         },
     }
 }
 
-fn assert_values_less_or_equal(actual_value: &Value, expected_value: &Value) -> PredicateResult {
+fn assert_values_less_or_equal(
+    actual_value: &Value,
+    expected_value: &Value,
+    clock_skew: Duration,
+) -> PredicateResult {
     let actual = actual_value.repr();
     let expected = format!("less or equal than {}", expected_value.repr());
-    match actual_value.compare(expected_value) {
+    match compare_values(actual_value, expected_value, clock_skew) {
         Ok(ordering) => PredicateResult {
             success: ordering == Ordering::Less || ordering == Ordering::Equal,
             actual,
             expected,
             type_mismatch: false,
+            json_diff: None,
         },
         _ => PredicateResult {
             success: false,
             actual,
             expected,
             type_mismatch: true,
+            json_diff: None,
         },
     }
 }
 
+/// Returns `true` if `actual` and `expected` are dates at most `clock_skew` apart.
+fn dates_within_skew(actual: &Value, expected: &Value, clock_skew: Duration) -> bool {
+    match (actual, expected) {
+        (Value::Date(actual), Value::Date(expected)) => {
+            let diff = actual.signed_duration_since(*expected).abs();
+            diff.to_std().is_ok_and(|diff| diff <= clock_skew)
+        }
+        _ => false,
+    }
+}
+
+/// Compares an `actual` value to an `expected` value, dates at most `clock_skew` apart being
+/// considered as equal.
+fn compare_values(
+    actual: &Value,
+    expected: &Value,
+    clock_skew: Duration,
+) -> Result<Ordering, EvalError> {
+    if dates_within_skew(actual, expected, clock_skew) {
+        return Ok(Ordering::Equal);
+    }
+    actual.compare(expected)
+}
+
 fn assert_include(value: &Value, element: &Value) -> PredicateResult {
     let actual = value.repr();
     let expected = format!("includes {}", element.repr());
@@ -802,12 +1178,14 @@ fn assert_include(value: &Value, element: &Value) -> PredicateResult {
             actual,
             expected,
             type_mismatch: false,
+            json_diff: None,
         },
         Err(_) => PredicateResult {
             success: false,
             actual,
             expected,
             type_mismatch: true,
+            json_diff: None,
         },
     }
 }
@@ -817,8 +1195,8 @@ mod tests {
     use std::path::Path;
 
     use hurl_core::ast::{
-        Expr, ExprKind, Float, I64, Placeholder, Regex, Template, TemplateElement, Variable,
-        Whitespace,
+        Base64, Expr, ExprKind, Float, I64, Placeholder, Regex, Template, TemplateElement,
+        Variable, Whitespace,
     };
     use hurl_core::types::ToSource;
 
@@ -856,6 +1234,7 @@ mod tests {
                         10,
                         "10".to_source(),
                     ))),
+                    ignoring: None,
                 },
                 source_info: SourceInfo::new(Pos::new(1, 11), Pos::new(1, 12)),
             },
@@ -866,7 +1245,8 @@ mod tests {
                 &predicate,
                 &variables,
                 &Some(Value::Bool(true)),
-                &context_dir
+                &context_dir,
+                &PredicateOptions::default()
             )
             .is_ok()
         );
@@ -876,6 +1256,7 @@ mod tests {
             &variables,
             &Some(Value::Number(Number::Integer(10))),
             &context_dir,
+            &PredicateOptions::default(),
         )
         .unwrap_err();
         assert_eq!(
@@ -884,6 +1265,7 @@ mod tests {
                 actual: "integer <10>".to_string(),
                 expected: "not integer <10>".to_string(),
                 type_mismatch: false,
+                json_diff: None,
             }
         );
         assert_eq!(
@@ -896,7 +1278,8 @@ mod tests {
                 &predicate,
                 &variables,
                 &Some(Value::Number(Number::Integer(1))),
-                &context_dir
+                &context_dir,
+                &PredicateOptions::default()
             )
             .is_ok()
         );
@@ -932,6 +1315,7 @@ mod tests {
             &variables,
             &Some(Value::Number(Number::Integer(1))),
             &context_dir,
+            &PredicateOptions::default(),
         )
         .unwrap_err();
         assert_eq!(
@@ -940,17 +1324,26 @@ mod tests {
                 actual: "integer <1>".to_string(),
                 expected: "not integer <1>".to_string(),
                 type_mismatch: false,
+                json_diff: None,
             }
         );
 
         // no value => fails, the negated value is still reported
-        let error = eval_predicate(&predicate, &variables, &None, &context_dir).unwrap_err();
+        let error = eval_predicate(
+            &predicate,
+            &variables,
+            &None,
+            &context_dir,
+            &PredicateOptions::default(),
+        )
+        .unwrap_err();
         assert_eq!(
             error.kind,
             RunnerErrorKind::AssertFailure {
                 actual: "none".to_string(),
                 expected: "not integer <1>".to_string(),
                 type_mismatch: false,
+                json_diff: None,
             }
         );
 
@@ -976,6 +1369,7 @@ mod tests {
             &variables,
             &Some(Value::Number(Number::Integer(2))),
             &context_dir,
+            &PredicateOptions::default(),
         )
         .unwrap_err();
         assert_eq!(
@@ -984,6 +1378,7 @@ mod tests {
                 actual: "integer <2>".to_string(),
                 expected: "integer <1>".to_string(),
                 type_mismatch: false,
+                json_diff: None,
             }
         );
     }
@@ -1002,7 +1397,15 @@ mod tests {
             "10".to_source(),
         )));
         let value = Value::Bool(true);
-        let result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let result = eval_equal(
+            &expected,
+            &variables,
+            &value,
+            &context_dir,
+            Duration::ZERO,
+            DEFAULT_JSON_DIFF_LIMIT,
+        )
+        .unwrap();
         assert!(!result.success);
         // FIXME: should be type_mismatch = true here
         // assert!(assert_result.type_mismatch);
@@ -1025,7 +1428,15 @@ mod tests {
             "10".to_source(),
         )));
         let value = Value::Unit;
-        let result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let result = eval_equal(
+            &expected,
+            &variables,
+            &value,
+            &context_dir,
+            Duration::ZERO,
+            DEFAULT_JSON_DIFF_LIMIT,
+        )
+        .unwrap();
         assert!(!result.success);
         assert!(!result.type_mismatch);
         assert_eq!(result.actual, "unit");
@@ -1046,7 +1457,15 @@ mod tests {
             "10".to_source(),
         )));
         let value = Value::Number(Number::Integer(1));
-        let result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let result = eval_equal(
+            &expected,
+            &variables,
+            &value,
+            &context_dir,
+            Duration::ZERO,
+            DEFAULT_JSON_DIFF_LIMIT,
+        )
+        .unwrap();
         assert!(!result.success);
         assert!(!result.type_mismatch);
         assert_eq!(result.actual, "integer <1>");
@@ -1056,7 +1475,15 @@ mod tests {
         // value: false
         let expected = PredicateValue::Bool(true);
         let value = Value::Bool(false);
-        let result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let result = eval_equal(
+            &expected,
+            &variables,
+            &value,
+            &context_dir,
+            Duration::ZERO,
+            DEFAULT_JSON_DIFF_LIMIT,
+        )
+        .unwrap();
         assert!(!result.success);
         assert!(!result.type_mismatch);
         assert_eq!(result.actual, "boolean <false>");
@@ -1069,7 +1496,15 @@ mod tests {
             "1.2".to_source(),
         )));
         let value = Value::Number(Number::Float(1.1));
-        let result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let result = eval_equal(
+            &expected,
+            &variables,
+            &value,
+            &context_dir,
+            Duration::ZERO,
+            DEFAULT_JSON_DIFF_LIMIT,
+        )
+        .unwrap();
         assert!(!result.success);
         assert!(!result.type_mismatch);
         assert_eq!(result.actual, "float <1.1>");
@@ -1091,13 +1526,27 @@ mod tests {
         };
 
         let value = Some(&Value::Unit);
-        let result = eval_predicate_func(&pred_func, &variables, value, &context_dir).unwrap();
+        let result = eval_predicate_func(
+            &pred_func,
+            &variables,
+            value,
+            &context_dir,
+            &PredicateOptions::default(),
+        )
+        .unwrap();
         assert!(result.success);
         assert_eq!(result.actual.as_str(), "unit");
         assert_eq!(result.expected.as_str(), "something");
 
         let value = None;
-        let result = eval_predicate_func(&pred_func, &variables, value, &context_dir).unwrap();
+        let result = eval_predicate_func(
+            &pred_func,
+            &variables,
+            value,
+            &context_dir,
+            &PredicateOptions::default(),
+        )
+        .unwrap();
         assert!(!result.success);
         assert!(!result.type_mismatch);
         assert_eq!(result.actual, "none");
@@ -1118,7 +1567,15 @@ mod tests {
             "1".to_source(),
         )));
         let value = Value::Number(Number::Integer(1));
-        let result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let result = eval_equal(
+            &expected,
+            &variables,
+            &value,
+            &context_dir,
+            Duration::ZERO,
+            DEFAULT_JSON_DIFF_LIMIT,
+        )
+        .unwrap();
         assert!(result.success);
         assert!(!result.type_mismatch);
         assert_eq!(result.actual, "integer <1>");
@@ -1136,7 +1593,15 @@ mod tests {
         // value: false
         let expected = PredicateValue::Bool(false);
         let value = Value::Bool(false);
-        let result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let result = eval_equal(
+            &expected,
+            &variables,
+            &value,
+            &context_dir,
+            Duration::ZERO,
+            DEFAULT_JSON_DIFF_LIMIT,
+        )
+        .unwrap();
         assert!(result.success);
         assert!(!result.type_mismatch);
         assert_eq!(result.actual, "boolean <false>");
@@ -1146,7 +1611,15 @@ mod tests {
         // value: false
         let expected = PredicateValue::Bool(true);
         let value = Value::Bool(false);
-        let result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let result = eval_equal(
+            &expected,
+            &variables,
+            &value,
+            &context_dir,
+            Duration::ZERO,
+            DEFAULT_JSON_DIFF_LIMIT,
+        )
+        .unwrap();
         assert!(!result.success);
         assert!(!result.type_mismatch);
         assert_eq!(result.actual, "boolean <false>");
@@ -1156,7 +1629,15 @@ mod tests {
         // value: true
         let expected = PredicateValue::Bool(true);
         let value = Value::Bool(true);
-        let result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let result = eval_equal(
+            &expected,
+            &variables,
+            &value,
+            &context_dir,
+            Duration::ZERO,
+            DEFAULT_JSON_DIFF_LIMIT,
+        )
+        .unwrap();
         assert!(result.success);
         assert!(!result.type_mismatch);
         assert_eq!(result.actual, "boolean <true>");
@@ -1177,7 +1658,15 @@ mod tests {
             "1.1".to_source(),
         )));
         let value = Value::Number(Number::Float(1.1));
-        let result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let result = eval_equal(
+            &expected,
+            &variables,
+            &value,
+            &context_dir,
+            Duration::ZERO,
+            DEFAULT_JSON_DIFF_LIMIT,
+        )
+        .unwrap();
         assert!(result.success);
         assert!(!result.type_mismatch);
         assert_eq!(result.actual, "float <1.1>");
@@ -1198,7 +1687,15 @@ mod tests {
             "1".to_source(),
         )));
         let value = Value::Number(Number::Float(1.0));
-        let result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let result = eval_equal(
+            &expected,
+            &variables,
+            &value,
+            &context_dir,
+            Duration::ZERO,
+            DEFAULT_JSON_DIFF_LIMIT,
+        )
+        .unwrap();
         assert!(result.success);
         assert!(!result.type_mismatch);
         assert_eq!(result.actual, "float <1.0>");
@@ -1219,13 +1716,71 @@ mod tests {
             "1".to_source(),
         )));
         let value = Value::Number(Number::Integer(2));
-        let result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let result = eval_equal(
+            &expected,
+            &variables,
+            &value,
+            &context_dir,
+            Duration::ZERO,
+            DEFAULT_JSON_DIFF_LIMIT,
+        )
+        .unwrap();
         assert!(!result.success);
         assert!(!result.type_mismatch);
         assert_eq!(result.actual, "integer <2>");
         assert_eq!(result.expected, "integer <1>");
     }
 
+    #[test]
+    fn test_predicate_value_equals_bytes() {
+        // Small bytes values are fully displayed.
+        let result = assert_values_equal(
+            &Value::Bytes(vec![0xff]),
+            &Value::Bytes(vec![0x00]),
+            Duration::ZERO,
+        );
+        assert!(!result.success);
+        assert_eq!(result.actual, "bytes <ff>");
+        assert_eq!(result.expected, "bytes <00>");
+
+        // Large bytes values are displayed around their first difference.
+        let actual = (0..32).collect::<Vec<u8>>();
+        let mut expected = actual.clone();
+        expected[20] = 0xff;
+        let result = assert_values_equal(
+            &Value::Bytes(actual.clone()),
+            &Value::Bytes(expected),
+            Duration::ZERO,
+        );
+        assert!(!result.success);
+        assert_eq!(
+            result.actual,
+            "bytes <...0c0d0e0f101112131415161718191a1b...> (offsets 12..28 of 32, first difference at offset 20)"
+        );
+        assert_eq!(
+            result.expected,
+            "bytes <...0c0d0e0f10111213ff15161718191a1b...> (offsets 12..28 of 32, first difference at offset 20)"
+        );
+
+        // Expected value is a prefix of the actual value.
+        let actual = (0..20).collect::<Vec<u8>>();
+        let expected = actual[..18].to_vec();
+        let result = assert_values_equal(
+            &Value::Bytes(actual),
+            &Value::Bytes(expected),
+            Duration::ZERO,
+        );
+        assert!(!result.success);
+        assert_eq!(
+            result.actual,
+            "bytes <...0a0b0c0d0e0f10111213> (offsets 10..20 of 20, first difference at offset 18)"
+        );
+        assert_eq!(
+            result.expected,
+            "bytes <...0a0b0c0d0e0f1011> (offsets 10..18 of 18, first difference at offset 18)"
+        );
+    }
+
     #[test]
     fn test_predicate_value_equals_string() {
         let variables = VariableSet::new();
@@ -1261,7 +1816,15 @@ mod tests {
         // base_url is not defined
         let expected = PredicateValue::String(template.clone());
         let value = Value::String(String::from("http://localhost:8000"));
-        let error = eval_equal(&expected, &variables, &value, &context_dir).unwrap_err();
+        let error = eval_equal(
+            &expected,
+            &variables,
+            &value,
+            &context_dir,
+            Duration::ZERO,
+            DEFAULT_JSON_DIFF_LIMIT,
+        )
+        .unwrap_err();
         assert_eq!(
             error.kind,
             RunnerErrorKind::TemplateVariableNotDefined {
@@ -1281,7 +1844,15 @@ mod tests {
             String::from("base_url"),
             Value::String(String::from("http://localhost:8000")),
         );
-        let result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let result = eval_equal(
+            &expected,
+            &variables,
+            &value,
+            &context_dir,
+            Duration::ZERO,
+            DEFAULT_JSON_DIFF_LIMIT,
+        )
+        .unwrap();
         assert!(result.success);
         assert!(!result.type_mismatch);
         assert_eq!(result.actual, "string <http://localhost:8000>");
@@ -1293,53 +1864,78 @@ mod tests {
         assert_eq!(
             assert_values_greater(
                 &Value::Number(Number::Integer(2)),
-                &Value::Number(Number::Integer(1))
+                &Value::Number(Number::Integer(1)),
+                Duration::ZERO
             ),
             PredicateResult {
                 success: true,
                 type_mismatch: false,
                 actual: "integer <2>".to_string(),
                 expected: "greater than integer <1>".to_string(),
+                json_diff: None,
             }
         );
         assert_eq!(
             assert_values_greater(
                 &Value::Number(Number::Integer(1)),
-                &Value::Number(Number::Integer(1))
+                &Value::Number(Number::Integer(1)),
+                Duration::ZERO
             ),
             PredicateResult {
                 success: false,
                 type_mismatch: false,
                 actual: "integer <1>".to_string(),
                 expected: "greater than integer <1>".to_string(),
+                json_diff: None,
             }
         );
         assert_eq!(
             assert_values_greater(
                 &Value::Number(Number::Float(1.1)),
-                &Value::Number(Number::Integer(1))
+                &Value::Number(Number::Integer(1)),
+                Duration::ZERO
             ),
             PredicateResult {
                 success: true,
                 type_mismatch: false,
                 actual: "float <1.1>".to_string(),
                 expected: "greater than integer <1>".to_string(),
+                json_diff: None,
             }
         );
         assert_eq!(
             assert_values_greater(
                 &Value::Number(Number::Float(1.1)),
-                &Value::Number(Number::Integer(2))
+                &Value::Number(Number::Integer(2)),
+                Duration::ZERO
             ),
             PredicateResult {
                 success: false,
                 type_mismatch: false,
                 actual: "float <1.1>".to_string(),
                 expected: "greater than integer <2>".to_string(),
+                json_diff: None,
             }
         );
     }
 
+    #[test]
+    fn test_assert_values_dates_with_clock_skew() {
+        let now = chrono::TimeZone::with_ymd_and_hms(&chrono::Utc, 2025, 1, 1, 10, 0, 0).unwrap();
+        let actual = Value::Date(now + chrono::TimeDelta::seconds(3));
+        let expected = Value::Date(now);
+
+        assert!(!assert_values_equal(&actual, &expected, Duration::ZERO).success);
+        assert!(assert_values_equal(&actual, &expected, Duration::from_secs(5)).success);
+        assert!(!assert_values_equal(&actual, &expected, Duration::from_secs(2)).success);
+        assert!(!assert_values_not_equal(&actual, &expected, Duration::from_secs(5)).success);
+
+        assert!(assert_values_greater(&actual, &expected, Duration::ZERO).success);
+        assert!(!assert_values_greater(&actual, &expected, Duration::from_secs(5)).success);
+        assert!(assert_values_less_or_equal(&actual, &expected, Duration::from_secs(5)).success);
+        assert!(!assert_values_less(&expected, &actual, Duration::from_secs(5)).success);
+    }
+
     #[test]
     fn test_predicate_is_empty_are_false() {
         // predicate: `isEmpty`
@@ -1420,6 +2016,7 @@ mod tests {
                 value: PredicateFuncValue::Equal {
                     space0: whitespace(),
                     value: PredicateValue::Null,
+                    ignoring: None,
                 },
             },
         };
@@ -1430,7 +2027,8 @@ mod tests {
                 &predicate,
                 &variables,
                 &Some(Value::Number(Number::Integer(1))),
-                &context_dir
+                &context_dir,
+                &PredicateOptions::default()
             )
             .is_ok()
         );
@@ -1461,6 +2059,7 @@ mod tests {
             &variables,
             &Some(Value::Number(Number::Integer(1))),
             &context_dir,
+            &PredicateOptions::default(),
         )
         .unwrap_err();
         assert_eq!(
@@ -1469,6 +2068,7 @@ mod tests {
                 actual: "integer <1>".to_string(),
                 expected: "not starts with string <toto>".to_string(),
                 type_mismatch: true,
+                json_diff: None,
             }
         );
     }
@@ -1512,19 +2112,27 @@ mod tests {
                 value: PredicateFuncValue::Equal {
                     space0: whitespace(),
                     value: PredicateValue::Null,
+                    ignoring: None,
                 },
             },
         };
 
-        let error = eval_predicate(&predicate, &variables, &None, &context_dir)
-            .err()
-            .unwrap();
+        let error = eval_predicate(
+            &predicate,
+            &variables,
+            &None,
+            &context_dir,
+            &PredicateOptions::default(),
+        )
+        .err()
+        .unwrap();
         assert_eq!(
             error.kind,
             RunnerErrorKind::AssertFailure {
                 actual: "none".to_string(),
                 expected: "null".to_string(),
                 type_mismatch: false,
+                json_diff: None,
             }
         );
 
@@ -1537,12 +2145,22 @@ mod tests {
                 value: PredicateFuncValue::Equal {
                     space0: whitespace(),
                     value: PredicateValue::Null,
+                    ignoring: None,
                 },
             },
         };
 
         let variables = VariableSet::new();
-        assert!(eval_predicate(&predicate, &variables, &None, &context_dir).is_ok());
+        assert!(
+            eval_predicate(
+                &predicate,
+                &variables,
+                &None,
+                &context_dir,
+                &PredicateOptions::default()
+            )
+            .is_ok()
+        );
     }
 
     #[test]
@@ -1568,6 +2186,214 @@ mod tests {
         assert_eq!(result.expected, "matches regex </a{3}/>");
     }
 
+    #[test]
+    fn test_predicate_include_json() {
+        let variables = VariableSet::new();
+        let current_dir = Path::new("/home");
+        let file_root = Path::new("file_root");
+        let context_dir = ContextDir::new(current_dir, file_root);
+        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+        let json_value = |s: &str| {
+            PredicateValue::String(Template {
+                delimiter: Some('"'),
+                elements: vec![TemplateElement::String {
+                    value: s.to_string(),
+                    source: s.to_source(),
+                }],
+                source_info,
+            })
+        };
+
+        // predicate: `includesJson "{\"user\": {\"name\": \"Bob\"}}"`
+        // value: {"id": 1, "user": {"name": "Bob", "age": 42}}
+        let expected = json_value(r#"{"user": {"name": "Bob"}}"#);
+        let value = Value::String(r#"{"id": 1, "user": {"name": "Bob", "age": 42}}"#.to_string());
+        let result =
+            eval_include_json(&expected, source_info, &variables, &value, &context_dir).unwrap();
+        assert!(result.success);
+        assert!(!result.type_mismatch);
+        assert_eq!(
+            result.expected,
+            r#"includes JSON <{"user":{"name":"Bob"}}>"#
+        );
+
+        // value: {"name": "Alice"} (as an object)
+        let value = Value::Object(vec![(
+            "name".to_string(),
+            Value::String("Alice".to_string()),
+        )]);
+        let expected = json_value(r#"{"name": "Bob"}"#);
+        let result =
+            eval_include_json(&expected, source_info, &variables, &value, &context_dir).unwrap();
+        assert!(!result.success);
+        assert!(!result.type_mismatch);
+
+        // value: not a JSON
+        let value = Value::String("<html></html>".to_string());
+        let result =
+            eval_include_json(&expected, source_info, &variables, &value, &context_dir).unwrap();
+        assert!(!result.success);
+        assert!(result.type_mismatch);
+
+        // expected: not a JSON
+        let expected = json_value("{name");
+        let error = eval_include_json(&expected, source_info, &variables, &value, &context_dir)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::InvalidJson {
+                value: "string <{name>".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_predicate_equal_json_diff() {
+        let variables = VariableSet::new();
+        let current_dir = Path::new("/home");
+        let file_root = Path::new("file_root");
+        let context_dir = ContextDir::new(current_dir, file_root);
+
+        // predicate: `== base64,eyJpdGVtcyI6IFt7InByaWNlIjogMTB9LCB7InByaWNlIjogMTB9XX0=;`
+        // value: {"items":[{"price":10},{"price":12}]}
+        let expected_json = r#"{"items": [{"price": 10}, {"price": 10}]}"#;
+        let expected = PredicateValue::Base64(Base64 {
+            space0: whitespace(),
+            value: expected_json.as_bytes().to_vec(),
+            source: "eyJpdGVtcyI6IFt7InByaWNlIjogMTB9LCB7InByaWNlIjogMTB9XX0=".to_source(),
+            space1: whitespace(),
+        });
+        let value = Value::Bytes(br#"{"items":[{"price":10},{"price":12}]}"#.to_vec());
+        let result = eval_equal(
+            &expected,
+            &variables,
+            &value,
+            &context_dir,
+            Duration::ZERO,
+            DEFAULT_JSON_DIFF_LIMIT,
+        )
+        .unwrap();
+        assert!(!result.success);
+        assert_eq!(
+            result.json_diff.unwrap().to_string(),
+            "$.items[1].price: expected 10 actual 12"
+        );
+
+        // No diff for non structured values.
+        let expected = PredicateValue::Number(hurl_core::ast::Number::Integer(I64::new(
+            1,
+            "1".to_source(),
+        )));
+        let value = Value::Number(Number::Integer(2));
+        let result = eval_equal(
+            &expected,
+            &variables,
+            &value,
+            &context_dir,
+            Duration::ZERO,
+            DEFAULT_JSON_DIFF_LIMIT,
+        )
+        .unwrap();
+        assert!(!result.success);
+        assert!(result.json_diff.is_none());
+    }
+
+    #[test]
+    fn test_predicate_equal_ignoring() {
+        let variables = VariableSet::new();
+        let current_dir = Path::new("/home");
+        let file_root = Path::new("file_root");
+        let context_dir = ContextDir::new(current_dir, file_root);
+        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+        let template = |s: &str| Template {
+            delimiter: Some('"'),
+            elements: vec![TemplateElement::String {
+                value: s.to_string(),
+                source: s.to_source(),
+            }],
+            source_info,
+        };
+        let ignoring = |paths: &[&str]| Ignoring {
+            space0: whitespace(),
+            paths: paths.iter().map(|p| (whitespace(), template(p))).collect(),
+        };
+
+        // predicate: `== "{\"id\": 2, \"name\": \"Bob\"}" ignoring "$.id"`
+        // value: {"id": 1, "name": "Bob"}
+        let expected = PredicateValue::String(template(r#"{"id": 2, "name": "Bob"}"#));
+        let value = Value::String(r#"{"id": 1, "name": "Bob"}"#.to_string());
+        let result = eval_equal_ignoring(
+            &expected,
+            &ignoring(&["$.id"]),
+            source_info,
+            &variables,
+            &value,
+            &context_dir,
+            DEFAULT_JSON_DIFF_LIMIT,
+        )
+        .unwrap();
+        assert!(result.success);
+        assert!(!result.type_mismatch);
+        assert_eq!(result.actual, r#"JSON <{"name":"Bob"}>"#);
+        assert_eq!(result.expected, r#"JSON <{"name":"Bob"}> ignoring "$.id""#);
+
+        // value: {"id": 1, "name": "Alice", "users": [{"id": 1}]}
+        let value = Value::String(
+            r#"{"id": 1, "name": "Alice", "users": [{"id": 3, "role": "admin"}]}"#.to_string(),
+        );
+        let expected = PredicateValue::String(template(
+            r#"{"id": 2, "name": "Bob", "users": [{"id": 4, "role": "admin"}]}"#,
+        ));
+        let result = eval_equal_ignoring(
+            &expected,
+            &ignoring(&["$..id", "$.name"]),
+            source_info,
+            &variables,
+            &value,
+            &context_dir,
+            DEFAULT_JSON_DIFF_LIMIT,
+        )
+        .unwrap();
+        assert!(result.success);
+
+        let result = eval_equal_ignoring(
+            &expected,
+            &ignoring(&["$..id"]),
+            source_info,
+            &variables,
+            &value,
+            &context_dir,
+            DEFAULT_JSON_DIFF_LIMIT,
+        )
+        .unwrap();
+        assert!(!result.success);
+        assert!(!result.type_mismatch);
+        assert_eq!(
+            result.json_diff.unwrap().to_string(),
+            r#"$.name: expected "Bob" actual "Alice""#
+        );
+
+        // invalid JSONPath expression
+        let error = eval_equal_ignoring(
+            &expected,
+            &ignoring(&["id"]),
+            source_info,
+            &variables,
+            &value,
+            &context_dir,
+            DEFAULT_JSON_DIFF_LIMIT,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::QueryInvalidJsonpathExpression {
+                value: "id".to_string()
+            }
+        );
+    }
+
     #[test]
     fn test_predicate_is_iso_date() {
         let value = Value::String("2020-03-09T22:18:26.625Z".to_string());
@@ -1597,4 +2423,51 @@ mod tests {
         assert_eq!(result.actual, "float <1.0>");
         assert_eq!(result.expected, "number");
     }
+
+    #[test]
+    fn test_predicate_constant_time_equal() {
+        let variables = VariableSet::new();
+        let current_dir = Path::new("/home");
+        let file_root = Path::new("file_root");
+        let context_dir = ContextDir::new(current_dir, file_root);
+        let expected = PredicateValue::String(Template::new(
+            Some('"'),
+            vec![TemplateElement::String {
+                value: "s3cr3t".to_string(),
+                source: "s3cr3t".to_source(),
+            }],
+            SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        ));
+
+        // predicate: `constantTimeEquals "s3cr3t"`
+        // value: "s3cr3t"
+        let value = Value::String("s3cr3t".to_string());
+        let result = eval_constant_time_equal(&expected, &variables, &value, &context_dir).unwrap();
+        assert!(result.success);
+        assert!(!result.type_mismatch);
+
+        // value: "s3cr3T"
+        let value = Value::String("s3cr3T".to_string());
+        let result = eval_constant_time_equal(&expected, &variables, &value, &context_dir).unwrap();
+        assert!(!result.success);
+        assert!(!result.type_mismatch);
+        assert_eq!(result.actual, "string <***>");
+        assert_eq!(result.expected, "constant time equals string <***>");
+
+        // value: 1
+        let value = Value::Number(Number::Integer(1));
+        let result = eval_constant_time_equal(&expected, &variables, &value, &context_dir).unwrap();
+        assert!(!result.success);
+        assert!(result.type_mismatch);
+        assert_eq!(result.actual, "integer <***>");
+    }
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"", b""));
+        assert!(constant_time_eq(b"abc", b"abc"));
+        assert!(!constant_time_eq(b"abc", b"abd"));
+        assert!(!constant_time_eq(b"abc", b"ab"));
+        assert!(!constant_time_eq(b"ab", b"ab\0"));
+    }
 }
@@ -0,0 +1,253 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Pretty-printing of mismatched response bodies.
+//!
+//! When an implicit body assert fails on a structured (JSON or XML) body, actual and expected
+//! bodies are pretty-printed so that a minified body can be compared line by line. Each printed body
+//! is truncated to a maximum size, and a marker is inserted under the first difference.
+use std::cmp::min;
+
+/// Default maximum number of chars printed for each body of a mismatch.
+pub const DEFAULT_BODY_PRINT_LIMIT: usize = 2048;
+
+/// A mismatch between an actual and an expected structured body, pretty-printed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BodyMismatch {
+    /// Excerpt of the pretty-printed actual body, including a marker line under the first difference.
+    pub actual: String,
+    /// Excerpt of the pretty-printed expected body, including a marker line under the first difference.
+    pub expected: String,
+    /// Line (1-based) of the first difference, in the pretty-printed bodies.
+    pub line: usize,
+    /// Column (1-based) of the first difference, in the pretty-printed bodies.
+    pub column: usize,
+}
+
+/// Returns a [`BodyMismatch`] between `actual` and `expected` if both bodies are JSON (or both XML)
+/// and differ once pretty-printed, `None` otherwise.
+///
+/// Each pretty-printed body is truncated to a window of at most `limit` chars, including the first
+/// difference.
+pub fn body_mismatch(actual: &str, expected: &str, limit: usize) -> Option<BodyMismatch> {
+    let (actual, expected) = match (pretty_json(actual), pretty_json(expected)) {
+        (Some(actual), Some(expected)) => (actual, expected),
+        _ => match (pretty_xml(actual), pretty_xml(expected)) {
+            (Some(actual), Some(expected)) => (actual, expected),
+            _ => return None,
+        },
+    };
+    let offset = first_difference(&actual, &expected)?;
+    let (line, column) = line_column(&actual, offset);
+    Some(BodyMismatch {
+        actual: excerpt(&actual, offset, limit),
+        expected: excerpt(&expected, offset, limit),
+        line,
+        column,
+    })
+}
+
+/// Pretty-prints a JSON `text`, returns `None` if `text` is not valid JSON.
+///
+/// Object keys are sorted, so two JSON documents with the same members in different orders
+/// are printed the same.
+fn pretty_json(text: &str) -> Option<String> {
+    let value = serde_json::from_str::<serde_json::Value>(text).ok()?;
+    serde_json::to_string_pretty(&value).ok()
+}
+
+/// Pretty-prints an XML `text`, indenting each element on its own line. Returns `None` if `text`
+/// doesn't look like XML.
+///
+/// This is a lexical indentation: the document is not validated.
+fn pretty_xml(text: &str) -> Option<String> {
+    let text = text.trim();
+    if !text.starts_with('<') || !text.ends_with('>') {
+        return None;
+    }
+    let mut lines = vec![];
+    let mut depth = 0_usize;
+    let mut rest = text;
+    while !rest.is_empty() {
+        let (token, next) = if rest.starts_with('<') {
+            let end = rest.find('>')?;
+            rest.split_at(end + 1)
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            rest.split_at(end)
+        };
+        rest = next;
+        let token = token.trim();
+        if token.is_empty() {
+            continue;
+        }
+        let is_tag = token.starts_with('<');
+        let is_closing = token.starts_with("</");
+        let is_opening = is_tag
+            && !is_closing
+            && !token.starts_with("<?")
+            && !token.starts_with("<!")
+            && !token.ends_with("/>");
+        if is_closing {
+            depth = depth.saturating_sub(1);
+        }
+        lines.push(format!("{}{token}", "  ".repeat(depth)));
+        if is_opening {
+            depth += 1;
+        }
+    }
+    Some(lines.join("\n"))
+}
+
+/// Returns the char offset of the first difference between `left` and `right`, `None` if they're equal.
+fn first_difference(left: &str, right: &str) -> Option<usize> {
+    let mut left_chars = left.chars();
+    let mut right_chars = right.chars();
+    let mut offset = 0;
+    loop {
+        match (left_chars.next(), right_chars.next()) {
+            (None, None) => return None,
+            (Some(l), Some(r)) if l == r => offset += 1,
+            _ => return Some(offset),
+        }
+    }
+}
+
+/// Returns the 1-based line and column of the char `offset` in `text`.
+fn line_column(text: &str, offset: usize) -> (usize, usize) {
+    let mut line = 1;
+    let mut column = 1;
+    for c in text.chars().take(offset) {
+        if c == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+    (line, column)
+}
+
+/// Returns an excerpt of `text` of at most `limit` chars around the char `offset`, with a `^`
+/// marker line under `offset`. Truncated parts are replaced with `...`.
+fn excerpt(text: &str, offset: usize, limit: usize) -> String {
+    let chars = text.chars().collect::<Vec<_>>();
+    let offset = min(offset, chars.len());
+
+    // We start the window on a line boundary, so the difference is displayed with some context.
+    let mut start = offset.saturating_sub(limit / 2);
+    while start > 0 && chars[start - 1] != '\n' {
+        start -= 1;
+    }
+    let end = min(chars.len(), start + limit.max(offset - start + 1));
+
+    // The marker is inserted after the line of the difference.
+    let line_start = chars[..offset]
+        .iter()
+        .rposition(|c| *c == '\n')
+        .map_or(0, |i| i + 1);
+    let line_end = chars[offset..]
+        .iter()
+        .position(|c| *c == '\n')
+        .map_or(chars.len(), |i| offset + i);
+    let marker = format!("{}^", " ".repeat(offset - line_start));
+
+    let mut s = String::new();
+    if start > 0 {
+        s.push_str("...\n");
+    }
+    if end <= line_end {
+        s.extend(&chars[start..end]);
+        s.push('\n');
+        s.push_str(&marker);
+        if end < chars.len() {
+            s.push_str("\n...");
+        }
+    } else {
+        s.extend(&chars[start..line_end]);
+        s.push('\n');
+        s.push_str(&marker);
+        let tail = chars[line_end..end].iter().collect::<String>();
+        s.push_str(tail.trim_end_matches('\n'));
+        if end < chars.len() {
+            s.push_str("\n...");
+        }
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pretty_json() {
+        assert_eq!(
+            pretty_json(r#"{"name":"Bob","ids":[1,2]}"#).unwrap(),
+            "{\n  \"ids\": [\n    1,\n    2\n  ],\n  \"name\": \"Bob\"\n}"
+        );
+        assert!(pretty_json("Hello").is_none());
+    }
+
+    #[test]
+    fn test_pretty_xml() {
+        assert_eq!(
+            pretty_xml(r#"<?xml version="1.0"?><a><b id="1">Hi</b><c/></a>"#).unwrap(),
+            "<?xml version=\"1.0\"?>\n<a>\n  <b id=\"1\">\n    Hi\n  </b>\n  <c/>\n</a>"
+        );
+        assert!(pretty_xml("Hello").is_none());
+    }
+
+    #[test]
+    fn test_first_difference() {
+        assert_eq!(first_difference("abc", "abc"), None);
+        assert_eq!(first_difference("abc", "abd"), Some(2));
+        assert_eq!(first_difference("ab", "abc"), Some(2));
+    }
+
+    #[test]
+    fn test_body_mismatch() {
+        let mismatch = body_mismatch(
+            r#"{"name":"Bob","age":31}"#,
+            "{\n  \"name\": \"Bob\",\n  \"age\": 30\n}",
+            DEFAULT_BODY_PRINT_LIMIT,
+        )
+        .unwrap();
+        assert_eq!(mismatch.line, 2);
+        assert_eq!(mismatch.column, 11);
+        assert_eq!(
+            mismatch.actual,
+            "{\n  \"age\": 31,\n          ^\n  \"name\": \"Bob\"\n}"
+        );
+        assert_eq!(
+            mismatch.expected,
+            "{\n  \"age\": 30,\n          ^\n  \"name\": \"Bob\"\n}"
+        );
+
+        // Same JSON, differently formatted
+        assert!(body_mismatch(r#"{"a":1}"#, "{\n  \"a\": 1\n}", 100).is_none());
+        // Not structured
+        assert!(body_mismatch("Hello", "World", 100).is_none());
+    }
+
+    #[test]
+    fn test_excerpt_truncated() {
+        let text = "line1\nline2\nline3\nline4\nline5";
+        assert_eq!(excerpt(text, 14, 12), "...\nline2\nline3\n  ^\n...");
+        assert_eq!(excerpt(text, 0, 3), "lin\n^\n...");
+    }
+}
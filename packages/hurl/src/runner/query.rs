@@ -15,10 +15,13 @@
  * limitations under the License.
  *
  */
+use std::time::Duration;
+
 use chrono::Utc;
+use encoding_rs::Encoding;
 use hurl_core::ast::{
     CertificateAttributeName, CookieAttribute, CookieAttributeName, CookiePath, Query, QueryValue,
-    RegexValue, SourceInfo, Template,
+    RegexValue, SourceInfo, Template, TimingsAttributeName,
 };
 use regex::Regex;
 use sha2::Digest;
@@ -26,9 +29,11 @@ use sha2::Digest;
 use crate::http::{HttpError, Response, ResponseCookie};
 
 use super::cache::BodyCache;
+use super::csv;
 use super::error::{RunnerError, RunnerErrorKind};
 use super::filter;
 use super::http_response::HttpResponse;
+use super::json_diff::DEFAULT_JSON_DIFF_LIMIT;
 use super::number::Number;
 use super::template::eval_template;
 use super::value::Value;
@@ -40,12 +45,18 @@ pub type QueryResult = Result<Option<Value>, RunnerError>;
 /// Options controlling query evaluation behavior.
 pub struct QueryOptions {
     pub use_jsonpath_coercion: bool,
+    /// Tolerance applied to date comparisons of asserts.
+    pub clock_skew: Duration,
+    /// Maximum number of differences reported when a JSON equality assert fails.
+    pub json_diff_limit: usize,
 }
 
 impl Default for QueryOptions {
     fn default() -> Self {
         QueryOptions {
             use_jsonpath_coercion: true,
+            clock_skew: Duration::ZERO,
+            json_diff_limit: DEFAULT_JSON_DIFF_LIMIT,
         }
     }
 }
@@ -62,13 +73,17 @@ pub fn eval_query(
     match &query.value {
         QueryValue::Status => eval_query_status(last_response),
         QueryValue::Version => eval_query_version(last_response),
+        QueryValue::VersionNegotiated => eval_query_version_negotiated(last_response),
         QueryValue::Url => eval_query_url(last_response),
         QueryValue::Header { name, .. } => eval_query_header(last_response, name, variables),
         QueryValue::Cookie {
             expr: CookiePath { name, attribute },
             ..
         } => eval_query_cookie(last_response, name, attribute, variables),
-        QueryValue::Body => eval_query_body(last_response, query.source_info),
+        QueryValue::Body { charset } => {
+            let charset = charset.as_ref().map(|(_, charset)| charset);
+            eval_query_body(last_response, charset, variables, query.source_info)
+        }
         QueryValue::Xpath { expr, .. } => {
             eval_query_xpath(last_response, cache, expr, variables, query.source_info)
         }
@@ -80,21 +95,44 @@ pub fn eval_query(
             query.source_info,
             options.use_jsonpath_coercion,
         ),
+        QueryValue::Csv { expr, .. } => eval_query_csv(
+            last_response,
+            cache,
+            expr,
+            variables,
+            query.source_info,
+            options.use_jsonpath_coercion,
+        ),
         QueryValue::Regex { value, .. } => {
             eval_query_regex(last_response, value, variables, query.source_info)
         }
         QueryValue::Variable { name, .. } => eval_query_variable(name, variables),
         QueryValue::Duration => eval_query_duration(last_response),
+        QueryValue::DurationMean | QueryValue::DurationPercentile { .. } => {
+            // Outside a repeated entry, an aggregate query is evaluated on the only sample we have.
+            eval_aggregate_query(query, &[last_response.duration])
+        }
         QueryValue::Bytes => eval_query_bytes(last_response, query.source_info),
         QueryValue::RawBytes => eval_query_rawbytes(last_response),
+        QueryValue::Sha1 => eval_query_sha1(last_response, query.source_info),
         QueryValue::Sha256 => eval_query_sha256(last_response, query.source_info),
+        QueryValue::Sha512 => eval_query_sha512(last_response, query.source_info),
         QueryValue::Md5 => eval_query_md5(last_response, query.source_info),
+        QueryValue::Crc32 => eval_query_crc32(last_response, query.source_info),
         QueryValue::Certificate {
             attribute_name: field,
             ..
         } => eval_query_certificate(last_response, *field),
         QueryValue::Ip => eval_ip(last_response),
+        QueryValue::ProxyStatus => eval_proxy_status(last_response),
+        QueryValue::HstsUpgraded => eval_hsts_upgraded(last_response),
+        QueryValue::ContinueReceived => eval_continue_received(last_response),
+        QueryValue::EarlyHints => eval_early_hints(last_response),
         QueryValue::Redirects => eval_redirects(responses),
+        QueryValue::Timings {
+            attribute_name: field,
+            ..
+        } => eval_query_timings(last_response, *field),
     }
 }
 
@@ -117,6 +155,11 @@ fn eval_query_version(response: &Response) -> QueryResult {
     )))
 }
 
+/// Evaluates the HTTP protocol actually negotiated for the HTTP `response` (ex: "HTTP/2").
+fn eval_query_version_negotiated(response: &Response) -> QueryResult {
+    Ok(Some(Value::String(response.version.to_string())))
+}
+
 /// Evaluates the final URL of the HTTP `response`.
 fn eval_query_url(response: &Response) -> QueryResult {
     Ok(Some(Value::String(response.url.to_string())))
@@ -165,9 +208,27 @@ fn eval_query_cookie(
 /// Evaluates the HTTP `response` body as text.
 ///
 /// `query_source_info` is the source position of the query, used if an error is returned.
-fn eval_query_body(response: &Response, query_source_info: SourceInfo) -> QueryResult {
-    // Can return a string if encoding is known and utf8.
-    match response.text() {
+/// Evaluates the text body of the HTTP `response`.
+///
+/// The body is decoded with `charset` if any, instead of the charset of the response `Content-Type`
+/// header. `query_source_info` is the source position of the query, used if an error is returned.
+fn eval_query_body(
+    response: &Response,
+    charset: Option<&Template>,
+    variables: &VariableSet,
+    query_source_info: SourceInfo,
+) -> QueryResult {
+    let text = match charset {
+        Some(charset) => {
+            let charset = eval_template(charset, variables)?;
+            match Encoding::for_label(charset.as_bytes()) {
+                Some(encoding) => response.text_with_encoding(encoding),
+                None => Err(HttpError::InvalidCharset { charset }),
+            }
+        }
+        None => response.text(),
+    };
+    match text {
         Ok(s) => Ok(Some(Value::String(s))),
         Err(inner) => Err(RunnerError::new(
             query_source_info,
@@ -283,6 +344,63 @@ fn parse_cache_json<'cache>(
     Ok(cache.json().unwrap())
 }
 
+/// Evaluates a JSONPath expression on the HTTP `response` CSV body, given a set of `variables`.
+///
+/// The CSV body is converted to a list of objects (or a list of lists if the response has no CSV
+/// header) before evaluating the JSONPath expression.
+///
+/// `query_source_info` is the source position of the query, used if an error is returned.
+fn eval_query_csv(
+    response: &Response,
+    cache: &mut BodyCache,
+    expr: &Template,
+    variables: &VariableSet,
+    query_source_info: SourceInfo,
+    use_coercion: bool,
+) -> QueryResult {
+    let json = match cache.csv() {
+        Some(j) => j,
+        None => parse_cache_csv(response, cache, query_source_info)?,
+    };
+    filter::eval_jsonpath_json(json, expr, variables, use_coercion)
+}
+
+/// Parse this HTTP `response` body as CSV, and store the document (converted to JSON) to the
+/// response `cache`.
+///
+/// `query_source_info` is used for error reporting.
+fn parse_cache_csv<'cache>(
+    response: &Response,
+    cache: &'cache mut BodyCache,
+    query_source_info: SourceInfo,
+) -> Result<&'cache serde_json::Value, RunnerError> {
+    // Get the response as text if possible
+    let text = match response.text() {
+        Ok(t) => t,
+        Err(e) => {
+            return Err(RunnerError::new(
+                query_source_info,
+                RunnerErrorKind::Http(e),
+                false,
+            ));
+        }
+    };
+    let records = match csv::parse(&text) {
+        Ok(r) => r,
+        Err(message) => {
+            return Err(RunnerError::new(
+                query_source_info,
+                RunnerErrorKind::QueryInvalidCsv { message },
+                false,
+            ));
+        }
+    };
+    let json = csv::to_json(&records, response.has_csv_header());
+    // Everything is ok, we can put the response in the cache
+    cache.set_csv(json);
+    Ok(cache.csv().unwrap())
+}
+
 /// Evaluates a regex query on the HTTP `response` body, given a set of `variables`.
 ///
 /// `query_source_info` is the source position of the query, used if an error is returned.
@@ -345,6 +463,67 @@ fn eval_query_duration(response: &Response) -> QueryResult {
     ))))
 }
 
+/// Evaluates the duration of a transfer phase of the HTTP `response`, in milliseconds.
+///
+/// Like libcurl, each duration is measured from the start of the transfer.
+fn eval_query_timings(response: &Response, timings_attribute: TimingsAttributeName) -> QueryResult {
+    let timings = &response.timings;
+    let duration = match timings_attribute {
+        TimingsAttributeName::NameLookup => timings.name_lookup,
+        TimingsAttributeName::Connect => timings.connect,
+        TimingsAttributeName::AppConnect => timings.app_connect,
+        TimingsAttributeName::PreTransfer => timings.pre_transfer,
+        TimingsAttributeName::StartTransfer => timings.start_transfer,
+        TimingsAttributeName::Total => timings.total,
+    };
+    Ok(Some(Value::Number(Number::Integer(
+        duration.as_millis() as i64
+    ))))
+}
+
+/// Returns `true` if this `query` is an aggregate query, evaluated on the responses of all the
+/// repetitions of an entry (like `durationMean` or `durationPercentile`).
+pub fn is_aggregate(query: &Query) -> bool {
+    matches!(
+        query.value,
+        QueryValue::DurationMean | QueryValue::DurationPercentile { .. }
+    )
+}
+
+/// Evaluates an aggregate `query` on the `durations` of the responses of all the repetitions of
+/// an entry. Non aggregate queries have no value.
+pub fn eval_aggregate_query(query: &Query, durations: &[Duration]) -> QueryResult {
+    match &query.value {
+        QueryValue::DurationMean => Ok(eval_duration_mean(durations)),
+        QueryValue::DurationPercentile { percentile, .. } => {
+            Ok(eval_duration_percentile(durations, percentile.as_u64()))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Computes the mean of `durations` in milliseconds.
+fn eval_duration_mean(durations: &[Duration]) -> Option<Value> {
+    if durations.is_empty() {
+        return None;
+    }
+    let total = durations.iter().map(|d| d.as_millis()).sum::<u128>();
+    let mean = total / durations.len() as u128;
+    Some(Value::Number(Number::Integer(mean as i64)))
+}
+
+/// Computes the `percentile` of `durations` in milliseconds, using the nearest-rank method.
+fn eval_duration_percentile(durations: &[Duration], percentile: u64) -> Option<Value> {
+    if durations.is_empty() {
+        return None;
+    }
+    let mut samples = durations.iter().map(|d| d.as_millis()).collect::<Vec<_>>();
+    samples.sort_unstable();
+    let rank = (percentile as usize * samples.len()).div_ceil(100);
+    let index = rank.max(1) - 1;
+    Some(Value::Number(Number::Integer(samples[index] as i64)))
+}
+
 /// Evaluates the HTTP `response` body as bytes.
 ///
 /// `query_source_info` is the source position of the query, used if an error is returned.
@@ -364,20 +543,20 @@ fn eval_query_rawbytes(response: &Response) -> QueryResult {
     Ok(Some(Value::Bytes(response.body.clone())))
 }
 
+/// Evaluates the SHA-1 hash of the HTTP `response` body bytes.
+///
+/// `query_source_info` is the source position of the query, used if an error is returned.
+fn eval_query_sha1(response: &Response, query_source_info: SourceInfo) -> QueryResult {
+    let bytes = uncompress_body(response, query_source_info)?;
+    let result = sha1::Sha1::digest(bytes);
+    Ok(Some(Value::Bytes(result[..].to_vec())))
+}
+
 /// Evaluates the SHA-256 hash of the HTTP `response` body bytes.
 ///
 /// `query_source_info` is the source position of the query, used if an error is returned.
 fn eval_query_sha256(response: &Response, query_source_info: SourceInfo) -> QueryResult {
-    let bytes = match response.uncompress_body() {
-        Ok(s) => s,
-        Err(inner) => {
-            return Err(RunnerError::new(
-                query_source_info,
-                RunnerErrorKind::Http(inner),
-                false,
-            ));
-        }
-    };
+    let bytes = uncompress_body(response, query_source_info)?;
     let mut hasher = sha2::Sha256::new();
     hasher.update(bytes);
     let result = hasher.finalize();
@@ -385,24 +564,45 @@ fn eval_query_sha256(response: &Response, query_source_info: SourceInfo) -> Quer
     Ok(Some(bytes))
 }
 
+/// Evaluates the SHA-512 hash of the HTTP `response` body bytes.
+///
+/// `query_source_info` is the source position of the query, used if an error is returned.
+fn eval_query_sha512(response: &Response, query_source_info: SourceInfo) -> QueryResult {
+    let bytes = uncompress_body(response, query_source_info)?;
+    let result = sha2::Sha512::digest(bytes);
+    Ok(Some(Value::Bytes(result[..].to_vec())))
+}
+
 /// Evaluates the MD-5 hash of the HTTP `response` body bytes.
 ///
 /// `query_source_info` is the source position of the query, used if an error is returned.
 fn eval_query_md5(response: &Response, query_source_info: SourceInfo) -> QueryResult {
-    let bytes = match response.uncompress_body() {
-        Ok(s) => s,
-        Err(inner) => {
-            return Err(RunnerError::new(
-                query_source_info,
-                RunnerErrorKind::Http(inner),
-                false,
-            ));
-        }
-    };
+    let bytes = uncompress_body(response, query_source_info)?;
     let bytes = md5::compute(bytes).to_vec();
     Ok(Some(Value::Bytes(bytes)))
 }
 
+/// Evaluates the CRC-32 checksum of the HTTP `response` body bytes, as 4 big-endian bytes.
+///
+/// `query_source_info` is the source position of the query, used if an error is returned.
+fn eval_query_crc32(response: &Response, query_source_info: SourceInfo) -> QueryResult {
+    let bytes = uncompress_body(response, query_source_info)?;
+    let checksum = crc32fast::hash(&bytes);
+    Ok(Some(Value::Bytes(checksum.to_be_bytes().to_vec())))
+}
+
+/// Returns the HTTP `response` body bytes, after content encoding decompression.
+///
+/// `query_source_info` is the source position of the query, used if an error is returned.
+fn uncompress_body(
+    response: &Response,
+    query_source_info: SourceInfo,
+) -> Result<Vec<u8>, RunnerError> {
+    response
+        .uncompress_body()
+        .map_err(|inner| RunnerError::new(query_source_info, RunnerErrorKind::Http(inner), false))
+}
+
 /// Evaluates the SSL certificate attribute, of the HTTP `response`.
 fn eval_query_certificate(
     response: &Response,
@@ -439,6 +639,35 @@ fn eval_ip(response: &Response) -> QueryResult {
     Ok(Some(Value::String(response.ip_addr.to_string())))
 }
 
+/// Evaluates the status code of the proxy CONNECT response, if any, for this HTTP `response`.
+fn eval_proxy_status(response: &Response) -> QueryResult {
+    Ok(response
+        .proxy_status
+        .map(|status| Value::Number(Number::Integer(i64::from(status)))))
+}
+
+/// Evaluates whether the request of this HTTP `response` has been upgraded to HTTPS by HSTS.
+fn eval_hsts_upgraded(response: &Response) -> QueryResult {
+    Ok(Some(Value::Bool(response.hsts_upgraded)))
+}
+
+/// Evaluates whether an interim `100 Continue` response has been received before this HTTP
+/// `response`.
+fn eval_continue_received(response: &Response) -> QueryResult {
+    Ok(Some(Value::Bool(response.continue_received())))
+}
+
+/// Evaluates the `Link` headers of the interim `103 Early Hints` responses received before this
+/// HTTP `response`.
+fn eval_early_hints(response: &Response) -> QueryResult {
+    let links = response
+        .early_hints()
+        .iter()
+        .map(|link| Value::String(link.to_string()))
+        .collect();
+    Ok(Some(Value::List(links)))
+}
+
 /// Evaluates the redirects within a list of HTTP `responses`
 fn eval_redirects(responses: &[&Response]) -> QueryResult {
     let mut it = responses.iter().peekable();
@@ -450,7 +679,12 @@ fn eval_redirects(responses: &[&Response]) -> QueryResult {
         let location = it.peek().map(|r| r.url.clone());
         // We're only interested to redirection:
         if location.is_some() {
-            let response = Value::HttpResponse(HttpResponse::new(location, r.status));
+            let response = Value::HttpResponse(Box::new(HttpResponse::new(
+                r.url.clone(),
+                r.status,
+                r.headers.clone(),
+                location,
+            )));
             values.push(response);
         }
     }
@@ -544,13 +778,13 @@ impl Value {
 pub mod tests {
     use std::num::ParseIntError;
 
-    use hurl_core::ast::{SourceInfo, TemplateElement, Whitespace};
+    use hurl_core::ast::{SourceInfo, TemplateElement, U64, Whitespace};
     use hurl_core::reader::Pos;
     use hurl_core::types::ToSource;
 
     use super::*;
     use crate::http;
-    use crate::http::{HeaderVec, HttpError, HttpVersion};
+    use crate::http::{Header, HeaderVec, HttpError, HttpVersion, InterimResponse};
 
     fn default_response() -> Response {
         Response {
@@ -562,6 +796,10 @@ pub mod tests {
             url: "http://localhost".parse().unwrap(),
             certificate: None,
             ip_addr: Default::default(),
+            proxy_status: None,
+            hsts_upgraded: false,
+            interim_responses: vec![],
+            timings: Default::default(),
         }
     }
 
@@ -784,6 +1022,200 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_query_version_negotiated() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        assert_eq!(
+            eval_query(
+                &Query {
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    value: QueryValue::VersionNegotiated,
+                },
+                &variables,
+                &[&http::hello_http_response()],
+                &mut cache,
+                &QueryOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("HTTP/1.0".to_string())
+        );
+    }
+
+    #[test]
+    fn test_query_proxy_status() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let query = Query {
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            value: QueryValue::ProxyStatus,
+        };
+        assert_eq!(
+            eval_query(
+                &query,
+                &variables,
+                &[&http::hello_http_response()],
+                &mut cache,
+                &QueryOptions::default()
+            )
+            .unwrap(),
+            None
+        );
+
+        let response = Response {
+            proxy_status: Some(407),
+            ..http::hello_http_response()
+        };
+        assert_eq!(
+            eval_query(
+                &query,
+                &variables,
+                &[&response],
+                &mut cache,
+                &QueryOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Integer(407))
+        );
+    }
+
+    #[test]
+    fn test_query_hsts_upgraded() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let query = Query {
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            value: QueryValue::HstsUpgraded,
+        };
+        assert_eq!(
+            eval_query(
+                &query,
+                &variables,
+                &[&http::hello_http_response()],
+                &mut cache,
+                &QueryOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bool(false)
+        );
+
+        let response = Response {
+            hsts_upgraded: true,
+            ..http::hello_http_response()
+        };
+        assert_eq!(
+            eval_query(
+                &query,
+                &variables,
+                &[&response],
+                &mut cache,
+                &QueryOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bool(true)
+        );
+    }
+
+    #[test]
+    fn test_query_continue_received() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let query = Query {
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            value: QueryValue::ContinueReceived,
+        };
+        assert_eq!(
+            eval_query(
+                &query,
+                &variables,
+                &[&http::hello_http_response()],
+                &mut cache,
+                &QueryOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bool(false)
+        );
+
+        let response = Response {
+            interim_responses: vec![InterimResponse {
+                status: 100,
+                headers: HeaderVec::new(),
+            }],
+            ..http::hello_http_response()
+        };
+        assert_eq!(
+            eval_query(
+                &query,
+                &variables,
+                &[&response],
+                &mut cache,
+                &QueryOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bool(true)
+        );
+    }
+
+    #[test]
+    fn test_query_early_hints() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let query = Query {
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            value: QueryValue::EarlyHints,
+        };
+        assert_eq!(
+            eval_query(
+                &query,
+                &variables,
+                &[&http::hello_http_response()],
+                &mut cache,
+                &QueryOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::List(vec![])
+        );
+
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Link", "</style.css>; rel=preload; as=style"));
+        headers.push(Header::new("Link", "</script.js>; rel=preload; as=script"));
+        let response = Response {
+            interim_responses: vec![
+                InterimResponse {
+                    status: 100,
+                    headers: HeaderVec::new(),
+                },
+                InterimResponse {
+                    status: 103,
+                    headers,
+                },
+            ],
+            ..http::hello_http_response()
+        };
+        assert_eq!(
+            eval_query(
+                &query,
+                &variables,
+                &[&response],
+                &mut cache,
+                &QueryOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::List(vec![
+                Value::String("</style.css>; rel=preload; as=style".to_string()),
+                Value::String("</script.js>; rel=preload; as=script".to_string()),
+            ])
+        );
+    }
+
     #[test]
     fn test_header_not_found() {
         let variables = VariableSet::new();
@@ -1160,7 +1592,7 @@ pub mod tests {
             eval_query(
                 &Query {
                     source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
-                    value: QueryValue::Body,
+                    value: QueryValue::Body { charset: None },
                 },
                 &variables,
                 &[&http::hello_http_response()],
@@ -1174,7 +1606,7 @@ pub mod tests {
         let error = eval_query(
             &Query {
                 source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 2)),
-                value: QueryValue::Body,
+                value: QueryValue::Body { charset: None },
             },
             &variables,
             &[&http::bytes_http_response()],
@@ -1195,6 +1627,71 @@ pub mod tests {
         );
     }
 
+    /// Returns a `body` query with a `charset`.
+    fn body_charset(charset: &str) -> Query {
+        // body "{charset}"
+        let end = Pos::new(1, 8 + charset.len());
+        let charset = Template::new(
+            Some('"'),
+            vec![TemplateElement::String {
+                value: charset.to_string(),
+                source: charset.to_source(),
+            }],
+            SourceInfo::new(Pos::new(1, 6), end),
+        );
+        Query {
+            source_info: SourceInfo::new(Pos::new(1, 1), end),
+            value: QueryValue::Body {
+                charset: Some((
+                    Whitespace {
+                        value: String::from(" "),
+                        source_info: SourceInfo::new(Pos::new(1, 5), Pos::new(1, 6)),
+                    },
+                    charset,
+                )),
+            },
+        }
+    }
+
+    #[test]
+    fn test_body_charset() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+
+        // "café" in ISO-8859-1, served without charset (UTF-8 by default).
+        let http_response = Response {
+            body: vec![0x63, 0x61, 0x66, 0xe9],
+            ..default_response()
+        };
+        assert_eq!(
+            eval_query(
+                &body_charset("latin1"),
+                &variables,
+                &[&http_response],
+                &mut cache,
+                &QueryOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("café".to_string())
+        );
+
+        let error = eval_query(
+            &body_charset("foo"),
+            &variables,
+            &[&http_response],
+            &mut cache,
+            &QueryOptions::default(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::Http(HttpError::InvalidCharset {
+                charset: "foo".to_string()
+            })
+        );
+    }
+
     #[test]
     fn test_query_invalid_utf8() {
         let variables = VariableSet::new();
@@ -1460,6 +1957,114 @@ pub mod tests {
         );
     }
 
+    pub fn csv_query(expr: &str) -> Query {
+        // csv <expr>
+        Query {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 7 + expr.len())),
+            value: QueryValue::Csv {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 4), Pos::new(1, 5)),
+                },
+                expr: Template::new(
+                    Some('"'),
+                    vec![TemplateElement::String {
+                        value: expr.to_string(),
+                        source: expr.to_source(),
+                    }],
+                    SourceInfo::new(Pos::new(1, 5), Pos::new(1, 7 + expr.len())),
+                ),
+            },
+        }
+    }
+
+    #[test]
+    fn test_query_csv() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let mut headers = HeaderVec::new();
+        headers.push(http::Header::new("Content-Type", "text/csv"));
+        let http_response = Response {
+            headers,
+            body: String::into_bytes(String::from("name,price\nbanana,10.5\napple,2\n")),
+            ..default_response()
+        };
+        assert_eq!(
+            eval_query(
+                &csv_query("$[0].price"),
+                &variables,
+                &[&http_response],
+                &mut cache,
+                &QueryOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("10.5".to_string())
+        );
+        assert_eq!(
+            eval_query(
+                &csv_query("$[*].name"),
+                &variables,
+                &[&http_response],
+                &mut cache,
+                &QueryOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::List(vec![
+                Value::String("banana".to_string()),
+                Value::String("apple".to_string())
+            ])
+        );
+
+        let mut cache = BodyCache::new();
+        let mut headers = HeaderVec::new();
+        headers.push(http::Header::new("Content-Type", "text/csv; header=absent"));
+        let http_response = Response {
+            headers,
+            body: String::into_bytes(String::from("banana,10.5\r\napple,2\r\n")),
+            ..default_response()
+        };
+        assert_eq!(
+            eval_query(
+                &csv_query("$[1][0]"),
+                &variables,
+                &[&http_response],
+                &mut cache,
+                &QueryOptions::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("apple".to_string())
+        );
+    }
+
+    #[test]
+    fn test_query_invalid_csv() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let http_response = Response {
+            body: String::into_bytes(String::from("name\n\"banana")),
+            ..default_response()
+        };
+        let error = eval_query(
+            &csv_query("$[0].name"),
+            &variables,
+            &[&http_response],
+            &mut cache,
+            &QueryOptions::default(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error.source_info.start, Pos { line: 1, column: 1 });
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::QueryInvalidCsv {
+                message: "unterminated quoted field".to_string()
+            }
+        );
+    }
+
     #[test]
     fn test_query_regex() {
         let variables = VariableSet::new();
@@ -1573,6 +2178,154 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_query_timings() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let response = Response {
+            timings: http::Timings {
+                name_lookup: Duration::from_micros(1_500),
+                connect: Duration::from_millis(12),
+                app_connect: Duration::from_millis(30),
+                pre_transfer: Duration::from_millis(31),
+                start_transfer: Duration::from_millis(80),
+                total: Duration::from_millis(95),
+                ..Default::default()
+            },
+            ..default_response()
+        };
+        let eval = |attribute_name: TimingsAttributeName, cache: &mut BodyCache| {
+            let value = QueryValue::Timings {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                attribute_name,
+            };
+            eval_query(
+                &Query {
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    value,
+                },
+                &variables,
+                &[&response],
+                cache,
+                &QueryOptions::default(),
+            )
+            .unwrap()
+            .unwrap()
+        };
+
+        assert_eq!(
+            eval(TimingsAttributeName::NameLookup, &mut cache),
+            Value::Number(Number::Integer(1))
+        );
+        assert_eq!(
+            eval(TimingsAttributeName::Connect, &mut cache),
+            Value::Number(Number::Integer(12))
+        );
+        assert_eq!(
+            eval(TimingsAttributeName::AppConnect, &mut cache),
+            Value::Number(Number::Integer(30))
+        );
+        assert_eq!(
+            eval(TimingsAttributeName::StartTransfer, &mut cache),
+            Value::Number(Number::Integer(80))
+        );
+        assert_eq!(
+            eval(TimingsAttributeName::Total, &mut cache),
+            Value::Number(Number::Integer(95))
+        );
+    }
+
+    #[test]
+    fn test_query_digests() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let response = Response {
+            body: b"123456789".to_vec(),
+            ..default_response()
+        };
+        let eval = |value: QueryValue, cache: &mut BodyCache| {
+            eval_query(
+                &Query {
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    value,
+                },
+                &variables,
+                &[&response],
+                cache,
+                &QueryOptions::default(),
+            )
+            .unwrap()
+            .unwrap()
+        };
+
+        assert_eq!(
+            eval(QueryValue::Sha1, &mut cache),
+            Value::Bytes(decode_hex("f7c3bc1d808e04732adf679965ccc34ca7ae3441").unwrap())
+        );
+        assert_eq!(
+            eval(QueryValue::Sha512, &mut cache),
+            Value::Bytes(
+                decode_hex(
+                    "d9e6762dd1c8eaf6d61b3c6192fc408d4d6d5f1176d0c29169bc24e71c3f274a\
+                     d27fcd5811b313d681f7e55ec02d73d499c95455b6b5bb503acf574fba8ffe85"
+                )
+                .unwrap()
+            )
+        );
+        assert_eq!(
+            eval(QueryValue::Crc32, &mut cache),
+            Value::Bytes(decode_hex("cbf43926").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_aggregate_query() {
+        let query = |value: QueryValue| Query {
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            value,
+        };
+        let percentile = |n: u64| {
+            query(QueryValue::DurationPercentile {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                percentile: U64::new(n, n.to_string().to_source()),
+            })
+        };
+        let durations = [40, 10, 30, 20, 50, 60, 70, 80, 90, 100]
+            .iter()
+            .map(|ms| Duration::from_millis(*ms))
+            .collect::<Vec<_>>();
+
+        assert!(is_aggregate(&query(QueryValue::DurationMean)));
+        assert!(is_aggregate(&percentile(95)));
+        assert!(!is_aggregate(&query(QueryValue::Duration)));
+
+        assert_eq!(
+            eval_aggregate_query(&query(QueryValue::DurationMean), &durations)
+                .unwrap()
+                .unwrap(),
+            Value::Number(Number::Integer(55))
+        );
+        for (n, expected) in [(0, 10), (50, 50), (90, 90), (95, 100), (100, 100)] {
+            assert_eq!(
+                eval_aggregate_query(&percentile(n), &durations)
+                    .unwrap()
+                    .unwrap(),
+                Value::Number(Number::Integer(expected))
+            );
+        }
+        assert!(
+            eval_aggregate_query(&query(QueryValue::DurationMean), &[])
+                .unwrap()
+                .is_none()
+        );
+    }
+
     #[test]
     fn test_query_certificate() {
         assert!(
@@ -0,0 +1,308 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Standalone Hurl files reproducing the requests of failed entries.
+use hurl_core::types::Index;
+
+use crate::http::{CredentialForwarding, FollowLocation, IpResolve, Request, RequestedHttpVersion};
+
+use super::runner_options::RunnerOptions;
+use super::value::Value;
+use super::variable::VariableSet;
+
+/// Headers that are not written in a replay file, as they're computed by the HTTP client.
+const COMPUTED_HEADERS: [&str; 2] = ["Host", "Content-Length"];
+
+/// Returns a standalone Hurl file reproducing the HTTP `request` sent by the entry `entry_index`
+/// of the Hurl file `filename`.
+///
+/// The request is written as it has been sent: variables are resolved, and headers implicitly
+/// added by the HTTP client (`User-Agent`, `Accept`, cookies etc...) are written explicitly.
+/// Secrets values of `variables` are replaced by a placeholder with the secret name (or by `***`
+/// if the secret is not a variable anymore) so the file can be replayed with `--secret` options.
+pub(crate) fn replay_file(
+    request: &Request,
+    filename: &str,
+    entry_index: Index,
+    options: &RunnerOptions,
+    variables: &VariableSet,
+) -> String {
+    let secrets = secrets(variables);
+    let mut s = format!("# Replay of entry {entry_index} of {filename}\n");
+    let names = secrets
+        .iter()
+        .filter_map(|(_, name)| name.as_ref())
+        .collect::<Vec<_>>();
+    if !names.is_empty() {
+        let args = names
+            .iter()
+            .map(|name| format!("--secret {name}=..."))
+            .collect::<Vec<_>>()
+            .join(" ");
+        s.push_str(&format!(
+            "# Secrets have been redacted, replay with: hurl {args}\n"
+        ));
+    }
+
+    let url = encode_value(request.url.raw().as_str(), &secrets);
+    s.push_str(&format!("{} {url}\n", request.method));
+    for header in request.headers.iter() {
+        if COMPUTED_HEADERS
+            .iter()
+            .any(|name| header.name.eq_ignore_ascii_case(name))
+        {
+            continue;
+        }
+        let value = encode_value(&header.value, &secrets);
+        s.push_str(&format!("{}: {value}\n", header.name));
+    }
+
+    let replay_options = replay_options(options);
+    if !replay_options.is_empty() {
+        s.push_str("[Options]\n");
+        for (name, value) in replay_options {
+            s.push_str(&format!("{name}: {}\n", encode_value(&value, &secrets)));
+        }
+    }
+
+    if !request.body.is_empty() {
+        let body = match std::str::from_utf8(&request.body) {
+            Ok(text) => encode_oneline_string(text, &secrets),
+            Err(_) => format!("hex,{};", super::hex::encode(&request.body)),
+        };
+        s.push_str(&body);
+        s.push('\n');
+    }
+    s
+}
+
+/// Returns the secrets values of `variables`, with the name of their variable if any.
+///
+/// Longer secrets come first so a secret containing another one is redacted as a whole.
+fn secrets(variables: &VariableSet) -> Vec<(String, Option<String>)> {
+    let mut secrets = variables
+        .secrets()
+        .into_iter()
+        .filter(|value| !value.is_empty())
+        .map(|value| {
+            let name = variables
+                .iter()
+                .filter(|(_, var)| var.is_secret())
+                .find(|(_, var)| matches!(var.value(), Value::String(v) if *v == value))
+                .map(|(name, _)| name.clone());
+            (value, name)
+        })
+        .collect::<Vec<_>>();
+    secrets.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()).then(a.cmp(b)));
+    secrets
+}
+
+/// Returns the options of the runner that have an impact on the way a request is sent.
+fn replay_options(options: &RunnerOptions) -> Vec<(&'static str, String)> {
+    let mut replay_options = vec![];
+    match options.follow_location {
+        FollowLocation::No => {}
+        FollowLocation::Follow(CredentialForwarding::OnlyInitialHost) => {
+            replay_options.push(("location", "true".to_string()));
+        }
+        FollowLocation::Follow(CredentialForwarding::AllHosts) => {
+            replay_options.push(("location-trusted", "true".to_string()));
+        }
+    }
+    match options.http_version {
+        RequestedHttpVersion::Default => {}
+        RequestedHttpVersion::Http10 => replay_options.push(("http1.0", "true".to_string())),
+        RequestedHttpVersion::Http11 => replay_options.push(("http1.1", "true".to_string())),
+        RequestedHttpVersion::Http2 => replay_options.push(("http2", "true".to_string())),
+        RequestedHttpVersion::Http3 => replay_options.push(("http3", "true".to_string())),
+    }
+    match options.ip_resolve {
+        IpResolve::Default => {}
+        IpResolve::IpV4 => replay_options.push(("ipv4", "true".to_string())),
+        IpResolve::IpV6 => replay_options.push(("ipv6", "true".to_string())),
+    }
+    if options.insecure {
+        replay_options.push(("insecure", "true".to_string()));
+    }
+    for connect_to in options.connects_to.iter() {
+        replay_options.push(("connect-to", connect_to.clone()));
+    }
+    for resolve in options.resolves.iter() {
+        replay_options.push(("resolve", resolve.clone()));
+    }
+    if let Some(proxy) = &options.proxy {
+        replay_options.push(("proxy", proxy.clone()));
+    }
+    if let Some(unix_socket) = &options.unix_socket {
+        replay_options.push(("unix-socket", unix_socket.clone()));
+    }
+    if let Some(dns_servers) = &options.dns_servers {
+        replay_options.push(("dns-servers", dns_servers.clone()));
+    }
+    replay_options
+}
+
+/// Encodes `s` as an unquoted Hurl value (URL, header value, option value), redacting `secrets`.
+fn encode_value(s: &str, secrets: &[(String, Option<String>)]) -> String {
+    encode(s, secrets, |c| match c {
+        '\\' => Some("\\\\"),
+        '#' => Some("\\#"),
+        '\n' => Some("\\n"),
+        '\r' => Some("\\r"),
+        '\t' => Some("\\t"),
+        _ => None,
+    })
+}
+
+/// Encodes `s` as a Hurl oneline string (between backticks), redacting `secrets`.
+fn encode_oneline_string(s: &str, secrets: &[(String, Option<String>)]) -> String {
+    let s = encode(s, secrets, |c| match c {
+        '\\' => Some("\\\\"),
+        '`' => Some("\\`"),
+        '\n' => Some("\\n"),
+        '\r' => Some("\\r"),
+        '\t' => Some("\\t"),
+        _ => None,
+    });
+    format!("`{s}`")
+}
+
+/// Encodes `s` with an `escape` function, replacing `secrets` by placeholders.
+///
+/// Opening braces are escaped when they could start a placeholder (followed by another brace or
+/// by a redacted secret).
+fn encode(
+    s: &str,
+    secrets: &[(String, Option<String>)],
+    escape: impl Fn(char) -> Option<&'static str>,
+) -> String {
+    let mut encoded = String::new();
+    let mut rest = s;
+    while !rest.is_empty() {
+        let secret = secrets
+            .iter()
+            .find(|(value, _)| rest.starts_with(value.as_str()));
+        if let Some((value, name)) = secret {
+            match name {
+                Some(name) => encoded.push_str(&format!("{{{{{name}}}}}")),
+                None => encoded.push_str("***"),
+            }
+            rest = &rest[value.len()..];
+            continue;
+        }
+        let mut chars = rest.chars();
+        let c = chars.next().unwrap();
+        rest = chars.as_str();
+        let placeholder_next = rest.starts_with('{')
+            || secrets
+                .iter()
+                .any(|(value, name)| name.is_some() && rest.starts_with(value.as_str()));
+        if c == '{' && placeholder_next {
+            encoded.push_str("\\u{7b}");
+        } else if let Some(escaped) = escape(c) {
+            encoded.push_str(escaped);
+        } else {
+            encoded.push(c);
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::http::{Header, HeaderVec, Url};
+    use crate::runner::RunnerOptionsBuilder;
+
+    fn request() -> Request {
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Host", "localhost:8000"));
+        headers.push(Header::new("Accept", "*/*"));
+        headers.push(Header::new("Authorization", "Bearer s3cr3t"));
+        headers.push(Header::new("Content-Length", "28"));
+        headers.push(Header::new("X-Tag", "#1"));
+        Request {
+            url: Url::from_str("http://localhost:8000/login?user=bob").unwrap(),
+            method: "POST".to_string(),
+            headers,
+            body: b"{\"token\":\"s3cr3t\",\"n\":`1`}".to_vec(),
+        }
+    }
+
+    #[test]
+    fn replay_file_with_secrets() {
+        let mut variables = VariableSet::new();
+        variables.insert_secret("token".to_string(), "s3cr3t".to_string());
+        let options = RunnerOptionsBuilder::new().insecure(true).build();
+
+        assert_eq!(
+            replay_file(
+                &request(),
+                "tests/login.hurl",
+                Index::new(2),
+                &options,
+                &variables
+            ),
+            r#"# Replay of entry 2 of tests/login.hurl
+# Secrets have been redacted, replay with: hurl --secret token=...
+POST http://localhost:8000/login?user=bob
+Accept: */*
+Authorization: Bearer {{token}}
+X-Tag: \#1
+[Options]
+insecure: true
+`{"token":"{{token}}","n":\`1\`}`
+"#
+        );
+    }
+
+    #[test]
+    fn replay_file_with_binary_body() {
+        let request = Request {
+            url: Url::from_str("http://localhost:8000/upload").unwrap(),
+            method: "PUT".to_string(),
+            headers: HeaderVec::new(),
+            body: vec![0xff, 0x00, 0x10],
+        };
+        let options = RunnerOptionsBuilder::new().build();
+
+        assert_eq!(
+            replay_file(
+                &request,
+                "upload.hurl",
+                Index::new(1),
+                &options,
+                &VariableSet::new()
+            ),
+            "# Replay of entry 1 of upload.hurl\nPUT http://localhost:8000/upload\nhex,ff0010;\n"
+        );
+    }
+
+    #[test]
+    fn encode_placeholder_like_strings() {
+        assert_eq!(encode_value("{{a}}", &[]), r"\u{7b}{a}}");
+        assert_eq!(encode_value("{a}", &[]), "{a}");
+        let secrets = [("bar".to_string(), Some("foo".to_string()))];
+        assert_eq!(encode_value("{bar}", &secrets), r"\u{7b}{{foo}}}");
+        assert_eq!(
+            encode_oneline_string("line1\nline2", &[]),
+            r"`line1\nline2`"
+        );
+    }
+}
@@ -43,6 +43,9 @@ pub fn eval_request(
 ) -> Result<RequestSpec, RunnerError> {
     let method = eval_method(&request.method);
     let url = eval_url(&request.url, variables)?;
+    context_dir
+        .check_url(&url.raw())
+        .map_err(|e| RunnerError::new(request.url.source_info, e.into(), false))?;
 
     // Headers
     let mut headers = HeaderVec::new();
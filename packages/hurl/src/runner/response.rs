@@ -15,6 +15,8 @@
  * limitations under the License.
  *
  */
+use std::time::Duration;
+
 use hurl_core::ast::{Base64, Body, Bytes, Hex, Response, SourceInfo, StatusValue};
 
 use crate::http;
@@ -27,7 +29,7 @@ use super::capture;
 use super::error::{RunnerError, RunnerErrorKind};
 use super::json;
 use super::multiline;
-use super::query::QueryOptions;
+use super::query::{self, QueryOptions};
 use super::result::{AssertResult, CaptureResult};
 use super::template;
 use super::value::Value;
@@ -156,8 +158,12 @@ pub fn eval_asserts(
         asserts.push(assert);
     }
 
-    // Then, checks all the explicit asserts.
+    // Then, checks all the explicit asserts. Aggregate asserts are evaluated once all the
+    // repetitions of the entry have been run, with `eval_aggregate_asserts`.
     for assert in response.asserts() {
+        if query::is_aggregate(&assert.query) {
+            continue;
+        }
         let assert_result = assert::eval_explicit_assert(
             assert,
             variables,
@@ -171,6 +177,26 @@ pub fn eval_asserts(
     asserts
 }
 
+/// Returns a list of assert results for the aggregate asserts of a spec `response` (asserts on
+/// `durationMean`, `durationPercentile` queries etc...), given a set of `variables` and the
+/// `durations` of the responses of all the repetitions of an entry.
+pub fn eval_aggregate_asserts(
+    response: &Response,
+    variables: &VariableSet,
+    durations: &[Duration],
+    context_dir: &ContextDir,
+    options: &QueryOptions,
+) -> Vec<AssertResult> {
+    response
+        .asserts()
+        .iter()
+        .filter(|assert| query::is_aggregate(&assert.query))
+        .map(|assert| {
+            assert::eval_aggregate_assert(assert, variables, durations, context_dir, options)
+        })
+        .collect()
+}
+
 /// Check the body of an actual HTTP response against a spec body, given a set of variables.
 fn eval_implicit_body_asserts(
     spec_body: &Body,
@@ -365,17 +391,47 @@ fn eval_implicit_body_asserts(
 }
 
 /// Evaluates captures from a list of HTTP `http_responses`, given a set of `variables`.
+///
+/// If `strict` is `true`, a capture with a `null` value, or an optional capture without value,
+/// is considered as failed (see `--retry-on-capture-failure`).
 pub fn eval_captures(
     response: &Response,
     http_responses: &[&http::Response],
     cache: &mut BodyCache,
     variables: &mut VariableSet,
+    context_dir: &ContextDir,
     options: &QueryOptions,
+    strict: bool,
 ) -> Result<Vec<CaptureResult>, RunnerError> {
     let mut captures = vec![];
     for capture in response.captures() {
-        let capture_result =
-            capture::eval_capture(capture, variables, http_responses, cache, options)?;
+        let capture_result = capture::eval_capture(
+            capture,
+            variables,
+            http_responses,
+            cache,
+            context_dir,
+            options,
+        )?;
+        // An optional capture without value is skipped.
+        let Some(capture_result) = capture_result else {
+            if strict {
+                let source_info = capture.query.source_info;
+                return Err(RunnerError::new(
+                    source_info,
+                    RunnerErrorKind::NoQueryResult,
+                    false,
+                ));
+            }
+            continue;
+        };
+        if strict && matches!(capture_result.value, Value::Null) {
+            let source_info = capture.query.source_info;
+            let kind = RunnerErrorKind::CaptureNullValue {
+                name: capture_result.name,
+            };
+            return Err(RunnerError::new(source_info, kind, false));
+        }
         // Update variables now so the captures set is ready in case
         // the next captures reference this new variable.
         let name = capture_result.name.clone();
@@ -482,6 +538,7 @@ mod tests {
                         actual: "integer <2>".to_string(),
                         expected: "integer <3>".to_string(),
                         type_mismatch: false,
+                        json_diff: None,
                     },
                     true
                 ))),
@@ -519,7 +576,9 @@ mod tests {
                 &[&http::xml_two_users_http_response()],
                 &mut cache,
                 &mut variables,
+                &ContextDir::default(),
                 &QueryOptions::default(),
+                false,
             )
             .unwrap(),
             vec![CaptureResult {
@@ -528,4 +587,81 @@ mod tests {
             }]
         );
     }
+
+    #[test]
+    pub fn test_eval_captures_strict() {
+        let content = r#"GET http://localhost
+HTTP 200
+[Captures]
+status: jsonpath "$.status"
+report: jsonpath "$.report" optional
+"#;
+        let hurl_file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        let response_spec = hurl_file.entries[0].response.as_ref().unwrap();
+        let http_response = http::Response {
+            body: br#"{"status": "RUNNING", "report": null}"#.to_vec(),
+            ..http::json_http_response()
+        };
+        let mut cache = BodyCache::new();
+        let mut variables = VariableSet::new();
+        let captures = eval_captures(
+            response_spec,
+            &[&http_response],
+            &mut cache,
+            &mut variables,
+            &ContextDir::default(),
+            &QueryOptions::default(),
+            false,
+        )
+        .unwrap();
+        assert_eq!(captures.len(), 2);
+        assert_eq!(captures[1].value, Value::Null);
+
+        let error = eval_captures(
+            response_spec,
+            &[&http_response],
+            &mut cache,
+            &mut variables,
+            &ContextDir::default(),
+            &QueryOptions::default(),
+            true,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::CaptureNullValue {
+                name: "report".to_string()
+            }
+        );
+        assert_eq!(error.source_info.start, Pos::new(5, 9));
+
+        // An optional capture without value is skipped, unless in strict mode.
+        let http_response = http::Response {
+            body: br#"{"status": "RUNNING"}"#.to_vec(),
+            ..http::json_http_response()
+        };
+        let mut cache = BodyCache::new();
+        let captures = eval_captures(
+            response_spec,
+            &[&http_response],
+            &mut cache,
+            &mut variables,
+            &ContextDir::default(),
+            &QueryOptions::default(),
+            false,
+        )
+        .unwrap();
+        assert_eq!(captures.len(), 1);
+        let error = eval_captures(
+            response_spec,
+            &[&http_response],
+            &mut cache,
+            &mut variables,
+            &ContextDir::default(),
+            &QueryOptions::default(),
+            true,
+        )
+        .unwrap_err();
+        assert_eq!(error.kind, RunnerErrorKind::NoQueryResult);
+    }
 }
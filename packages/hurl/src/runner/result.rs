@@ -17,9 +17,11 @@
  */
 use std::cmp::min;
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::Duration;
 
 use hurl_core::ast::SourceInfo;
+use hurl_core::input::Input;
 use hurl_core::reader::Pos;
 use hurl_core::types::Index;
 
@@ -52,24 +54,23 @@ pub struct HurlResult {
 }
 
 impl HurlResult {
-    /// Returns all the effective errors of this `HurlResult`, with the source information
-    /// of the entry where the error happens.
+    /// Returns all the effective errors of this `HurlResult`, with the result of the entry where
+    /// the error happens.
     ///
     /// The errors are only the "effective" ones: those that are due to retry are
     /// ignored.
-    pub fn errors(&self) -> Vec<(&RunnerError, SourceInfo)> {
+    pub fn errors(&self) -> Vec<(&RunnerError, &EntryResult)> {
         let mut errors = vec![];
         let mut next_entries = self.entries.iter().skip(1);
         for entry in self.entries.iter() {
             match next_entries.next() {
                 None => {
-                    let new_errors = entry.errors.iter().map(|error| (error, entry.source_info));
+                    let new_errors = entry.errors.iter().map(|error| (error, entry));
                     errors.extend(new_errors);
                 }
                 Some(next) => {
                     if next.entry_index != entry.entry_index {
-                        let new_errors =
-                            entry.errors.iter().map(|error| (error, entry.source_info));
+                        let new_errors = entry.errors.iter().map(|error| (error, entry));
                         errors.extend(new_errors);
                     }
                 }
@@ -77,6 +78,17 @@ impl HurlResult {
         }
         errors
     }
+
+    /// Releases the bodies of the HTTP requests and responses of this result.
+    ///
+    /// Once a run has been reported, its bodies are not needed anymore: releasing them keeps the
+    /// memory usage of long runs (with a lot of files or repeats) bounded.
+    pub fn release_bodies(&mut self) {
+        for call in self.entries.iter_mut().flat_map(|e| e.calls.iter_mut()) {
+            call.request.body = Vec::new();
+            call.response.body = Vec::new();
+        }
+    }
 }
 
 /// Represents the execution result of an entry.
@@ -104,6 +116,18 @@ pub struct EntryResult {
     pub compressed: bool,
     /// The debug curl command line from this entry result.
     pub curl_cmd: CurlCmd,
+    /// The Hurl file of this entry if it has been included from another file, `None` for the
+    /// entries of the run file. Source information of this entry and its errors refer to this file.
+    pub source_file: Option<Arc<SourceFile>>,
+}
+
+/// A Hurl source file, used to render errors of the entries it contains.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceFile {
+    /// The input of this file.
+    pub input: Input,
+    /// The content of this file.
+    pub content: String,
 }
 
 impl Default for EntryResult {
@@ -118,6 +142,7 @@ impl Default for EntryResult {
             transfer_duration: Duration::from_millis(0),
             compressed: false,
             curl_cmd: CurlCmd::default(),
+            source_file: None,
         }
     }
 }
@@ -189,6 +214,15 @@ pub struct CaptureResult {
 }
 
 impl EntryResult {
+    /// Returns the filename and the content of the Hurl source of this entry, used to render its
+    /// errors: its included file if any, or the run `filename` and `content` otherwise.
+    pub fn source<'a>(&'a self, filename: &str, content: &'a str) -> (String, &'a str) {
+        match &self.source_file {
+            Some(file) => (file.input.to_string(), &file.content),
+            None => (filename.to_string(), content),
+        }
+    }
+
     /// Writes the last HTTP response of this entry result to this `output`.
     /// The HTTP response can be decompressed if the entry's `compressed` option has been set.
     /// This method doesn't check if the response has write access to this output using a context
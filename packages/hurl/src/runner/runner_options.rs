@@ -15,23 +15,35 @@
  * limitations under the License.
  *
  */
+use std::path::PathBuf;
 use std::time::Duration;
 
+use hurl_core::parser::ParserLimits;
 use hurl_core::types::{BytesPerSec, Count};
 
-use crate::http::{FollowLocation, HeaderVec, IpResolve, RequestedHttpVersion};
+use crate::http::{ClientCertType, FollowLocation, HeaderVec, IpResolve, RequestedHttpVersion};
 use crate::pretty::PrettyMode;
+use crate::util::cancel::CancellationToken;
 use crate::util::path::ContextDir;
 
+use super::json_diff::DEFAULT_JSON_DIFF_LIMIT;
 use super::output::Output;
+use super::pretty::DEFAULT_BODY_PRINT_LIMIT;
+use super::value::Value;
 
 /// Build a [`RunnerOptions`] instance.
 pub struct RunnerOptionsBuilder {
     allow_reuse: bool,
     aws_sigv4: Option<String>,
+    body_print_limit: usize,
     cacert_file: Option<String>,
+    cancellation_token: Option<CancellationToken>,
+    chunked: bool,
     client_cert_file: Option<String>,
+    client_cert_password: Option<String>,
+    client_cert_type: ClientCertType,
     client_key_file: Option<String>,
+    clock_skew: Duration,
     color_stdout: bool,
     compressed: bool,
     connect_timeout: Duration,
@@ -41,17 +53,24 @@ pub struct RunnerOptionsBuilder {
     cookie_input_file: Option<String>,
     delay: Duration,
     digest: bool,
+    dns_servers: Option<String>,
+    expect_continue: Option<bool>,
     fail_with_body: bool,
     follow_location: FollowLocation,
     from_entry: Option<usize>,
     headers: HeaderVec,
+    hmac_signature: Option<String>,
+    hsts_file: Option<String>,
     http_version: RequestedHttpVersion,
+    http3_fallback: bool,
     insecure: bool,
     ip_resolve: IpResolve,
+    json_diff_limit: usize,
     max_filesize: Option<u64>,
     max_recv_speed: Option<BytesPerSec>,
     max_redirect: Count,
     max_send_speed: Option<BytesPerSec>,
+    multipart_boundary: Option<String>,
     negotiate: bool,
     netrc: bool,
     netrc_file: Option<String>,
@@ -60,25 +79,39 @@ pub struct RunnerOptionsBuilder {
     no_headers: Vec<String>,
     no_proxy: Option<String>,
     ntlm: bool,
+    oauth2_client_id: Option<String>,
+    oauth2_client_secret: Option<String>,
+    oauth2_token_url: Option<String>,
     output: Option<Output>,
+    parser_limits: ParserLimits,
     path_as_is: bool,
     pretty_mode: PrettyMode,
     pinned_pub_key: Option<String>,
     proxy: Option<String>,
     proxy_headers: HeaderVec,
+    proxy_user: Option<String>,
     repeat: Option<Count>,
+    replay_dir: Option<PathBuf>,
     resolves: Vec<String>,
     retry: Option<Count>,
     retry_interval: Duration,
+    retry_on_capture_failure: bool,
     skip: bool,
+    snapshot_dir: Option<PathBuf>,
+    snapshot_masks: Vec<String>,
+    snapshot_update: bool,
+    soft_asserts: bool,
     ssl_no_revoke: bool,
+    nested_form_params: bool,
     timeout: Duration,
     to_entry: Option<usize>,
+    trailers: HeaderVec,
     unix_socket: Option<String>,
     use_cookie_store: bool,
     use_jsonpath_coercion: bool,
     user: Option<String>,
     user_agent: Option<String>,
+    verbose_dir: Option<PathBuf>,
 }
 
 impl Default for RunnerOptionsBuilder {
@@ -86,9 +119,15 @@ impl Default for RunnerOptionsBuilder {
         RunnerOptionsBuilder {
             allow_reuse: true,
             aws_sigv4: None,
+            body_print_limit: DEFAULT_BODY_PRINT_LIMIT,
             cacert_file: None,
+            cancellation_token: None,
+            chunked: false,
             client_cert_file: None,
+            client_cert_password: None,
+            client_cert_type: ClientCertType::default(),
             client_key_file: None,
+            clock_skew: Duration::ZERO,
             color_stdout: true,
             compressed: false,
             connect_timeout: Duration::from_secs(300),
@@ -98,17 +137,24 @@ impl Default for RunnerOptionsBuilder {
             cookie_input_file: None,
             delay: Duration::from_millis(0),
             digest: false,
+            dns_servers: None,
+            expect_continue: None,
             fail_with_body: false,
             follow_location: FollowLocation::default(),
             from_entry: None,
             headers: HeaderVec::new(),
+            hmac_signature: None,
+            hsts_file: None,
             http_version: RequestedHttpVersion::default(),
+            http3_fallback: false,
             insecure: false,
             ip_resolve: IpResolve::default(),
+            json_diff_limit: DEFAULT_JSON_DIFF_LIMIT,
             max_filesize: None,
             max_recv_speed: None,
             max_redirect: Count::Finite(50),
             max_send_speed: None,
+            multipart_boundary: None,
             negotiate: false,
             netrc: false,
             netrc_file: None,
@@ -117,25 +163,39 @@ impl Default for RunnerOptionsBuilder {
             no_proxy: None,
             no_headers: Vec::new(),
             ntlm: false,
+            oauth2_client_id: None,
+            oauth2_client_secret: None,
+            oauth2_token_url: None,
             output: None,
+            parser_limits: ParserLimits::default(),
             path_as_is: false,
             pinned_pub_key: None,
             pretty_mode: PrettyMode::Automatic,
             proxy: None,
             proxy_headers: HeaderVec::new(),
+            proxy_user: None,
             repeat: None,
+            replay_dir: None,
             resolves: vec![],
             retry: None,
             retry_interval: Duration::from_millis(1000),
+            retry_on_capture_failure: false,
             skip: false,
+            snapshot_dir: None,
+            snapshot_masks: vec![],
+            snapshot_update: false,
+            soft_asserts: false,
             ssl_no_revoke: false,
+            nested_form_params: false,
             timeout: Duration::from_secs(300),
             to_entry: None,
+            trailers: HeaderVec::new(),
             unix_socket: None,
             use_cookie_store: true,
             use_jsonpath_coercion: true,
             user: None,
             user_agent: None,
+            verbose_dir: None,
         }
     }
 }
@@ -163,6 +223,14 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the maximum number of chars printed for each body when a JSON or XML body assert fails.
+    ///
+    /// Default is 2048.
+    pub fn body_print_limit(&mut self, body_print_limit: usize) -> &mut Self {
+        self.body_print_limit = body_print_limit;
+        self
+    }
+
     /// Specifies the certificate file for peer verification.
     /// The file may contain multiple CA certificates and must be in PEM format.
     pub fn cacert_file(&mut self, cacert_file: Option<String>) -> &mut Self {
@@ -170,18 +238,54 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets a token to cancel the run from another thread.
+    ///
+    /// When the token is cancelled, the current HTTP transfer is aborted, no more entries are run
+    /// and the results of the entries already run are returned.
+    pub fn cancellation_token(&mut self, token: Option<CancellationToken>) -> &mut Self {
+        self.cancellation_token = token;
+        self
+    }
+
+    /// Sends the request body with a chunked transfer encoding.
+    pub fn chunked(&mut self, chunked: bool) -> &mut Self {
+        self.chunked = chunked;
+        self
+    }
+
     /// Sets Client certificate file and password.
     pub fn client_cert_file(&mut self, client_cert_file: Option<String>) -> &mut Self {
         self.client_cert_file = client_cert_file;
         self
     }
 
+    /// Sets the password of the client certificate (or of its private key).
+    pub fn client_cert_password(&mut self, client_cert_password: Option<String>) -> &mut Self {
+        self.client_cert_password = client_cert_password;
+        self
+    }
+
+    /// Sets the format of the client certificate file (PEM by default).
+    pub fn client_cert_type(&mut self, client_cert_type: ClientCertType) -> &mut Self {
+        self.client_cert_type = client_cert_type;
+        self
+    }
+
     /// Sets private key file name.
     pub fn client_key_file(&mut self, client_key_file: Option<String>) -> &mut Self {
         self.client_key_file = client_key_file;
         self
     }
 
+    /// Sets the tolerance applied to date comparisons in asserts: two dates at most `clock_skew`
+    /// apart are considered as equal.
+    ///
+    /// Default is zero (no tolerance).
+    pub fn clock_skew(&mut self, clock_skew: Duration) -> &mut Self {
+        self.clock_skew = clock_skew;
+        self
+    }
+
     /// Whether we use color in stdout, or not. This property is used when response is outputted
     /// to a file or to standard output through `[Options]` section.
     pub fn color_stdout(&mut self, color_stdout: bool) -> &mut Self {
@@ -281,12 +385,32 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the HMAC signature configuration `ALGORITHM:HEADER:SECRET:TEMPLATE`. When set, each
+    /// request is signed just before being sent.
+    pub fn hmac_signature(&mut self, hmac_signature: Option<String>) -> &mut Self {
+        self.hmac_signature = hmac_signature;
+        self
+    }
+
+    /// Sets the HSTS cache file. The file is read before the first request and updated with the
+    /// `Strict-Transport-Security` headers received over HTTPS.
+    pub fn hsts_file(&mut self, hsts_file: Option<String>) -> &mut Self {
+        self.hsts_file = hsts_file;
+        self
+    }
+
     /// Set requested HTTP version (can be different of the effective HTTP version).
     pub fn http_version(&mut self, version: RequestedHttpVersion) -> &mut Self {
         self.http_version = version;
         self
     }
 
+    /// Retries requests over HTTP/2 when HTTP/3 can not be negotiated.
+    pub fn http3_fallback(&mut self, http3_fallback: bool) -> &mut Self {
+        self.http3_fallback = http3_fallback;
+        self
+    }
+
     /// Ignores all asserts defined in the Hurl file.
     pub fn no_assert(&mut self, no_assert: bool) -> &mut Self {
         self.no_assert = no_assert;
@@ -305,6 +429,15 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the maximum number of differences displayed when a JSON equality assert fails, 0 to
+    /// disable the structured diff.
+    ///
+    /// Default is 10.
+    pub fn json_diff_limit(&mut self, json_diff_limit: usize) -> &mut Self {
+        self.json_diff_limit = json_diff_limit;
+        self
+    }
+
     /// Set the file size limit
     pub fn max_filesize(&mut self, max_filesize: Option<u64>) -> &mut Self {
         self.max_filesize = max_filesize;
@@ -343,6 +476,30 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the list of DNS servers to be used instead of the system default.
+    ///
+    /// The servers are given as a comma separated list of IP addresses (with an optional port),
+    /// for instance `1.1.1.1,8.8.8.8:53`.
+    pub fn dns_servers(&mut self, dns_servers: Option<String>) -> &mut Self {
+        self.dns_servers = dns_servers;
+        self
+    }
+
+    /// Forces (`Some(true)`) or suppresses (`Some(false)`) the `Expect: 100-continue` request
+    /// header.
+    pub fn expect_continue(&mut self, expect_continue: Option<bool>) -> &mut Self {
+        self.expect_continue = expect_continue;
+        self
+    }
+
+    /// Sets the boundary used to delimit the parts of a multipart form data request.
+    ///
+    /// If not set, the boundary is randomly generated by libcurl.
+    pub fn multipart_boundary(&mut self, multipart_boundary: Option<String>) -> &mut Self {
+        self.multipart_boundary = multipart_boundary;
+        self
+    }
+
     /// Sets the HTTP Negotiate (SPNEGO) authentication flag.
     pub fn negotiate(&mut self, negotiate: bool) -> &mut Self {
         self.negotiate = negotiate;
@@ -385,12 +542,38 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the client identifier used to get an OAuth 2.0 access token.
+    pub fn oauth2_client_id(&mut self, oauth2_client_id: Option<String>) -> &mut Self {
+        self.oauth2_client_id = oauth2_client_id;
+        self
+    }
+
+    /// Sets the client secret used to get an OAuth 2.0 access token.
+    pub fn oauth2_client_secret(&mut self, oauth2_client_secret: Option<String>) -> &mut Self {
+        self.oauth2_client_secret = oauth2_client_secret;
+        self
+    }
+
+    /// Sets the URL of the OAuth 2.0 token endpoint. When set, an access token is requested
+    /// with the client credentials grant and sent as a bearer token.
+    pub fn oauth2_token_url(&mut self, oauth2_token_url: Option<String>) -> &mut Self {
+        self.oauth2_token_url = oauth2_token_url;
+        self
+    }
+
     /// Specifies the file to output the HTTP response instead of stdout.
     pub fn output(&mut self, output: Option<Output>) -> &mut Self {
         self.output = output;
         self
     }
 
+    /// Sets the limits of the parser of Hurl files, included files being parsed with the same
+    /// limits.
+    pub fn parser_limits(&mut self, parser_limits: ParserLimits) -> &mut Self {
+        self.parser_limits = parser_limits;
+        self
+    }
+
     /// Sets the pinned public key.
     pub fn pinned_pub_key(&mut self, pinned_pub_key: Option<String>) -> &mut Self {
         self.pinned_pub_key = pinned_pub_key;
@@ -415,12 +598,27 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the user name and password to use for proxy authentication.
+    pub fn proxy_user(&mut self, proxy_user: Option<String>) -> &mut Self {
+        self.proxy_user = proxy_user;
+        self
+    }
+
     /// Set the number of repetition for a given entry.
     pub fn repeat(&mut self, repeat: Option<Count>) -> &mut Self {
         self.repeat = repeat;
         self
     }
 
+    /// Sets the directory where the replay files of failed entries are written.
+    ///
+    /// When set, a standalone Hurl file reproducing the request of each failed entry is saved
+    /// under `replay_dir`.
+    pub fn replay_dir(&mut self, replay_dir: Option<PathBuf>) -> &mut Self {
+        self.replay_dir = replay_dir;
+        self
+    }
+
     /// Provides a custom address for a specific host and port pair.
     pub fn resolves(&mut self, resolves: &[String]) -> &mut Self {
         self.resolves = resolves.to_vec();
@@ -443,18 +641,71 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Considers a capture with a `null` value, or an optional capture without value, as failed.
+    ///
+    /// Combined with [`RunnerOptionsBuilder::retry`], the entry is retried until its captures
+    /// succeed, so a field can be polled until it appears.
+    pub fn retry_on_capture_failure(&mut self, retry_on_capture_failure: bool) -> &mut Self {
+        self.retry_on_capture_failure = retry_on_capture_failure;
+        self
+    }
+
     /// Skip the run without executing any request.
     pub fn skip(&mut self, skip: bool) -> &mut Self {
         self.skip = skip;
         self
     }
 
+    /// Sets the directory where the snapshots of response bodies are recorded.
+    ///
+    /// When set, the last response body of each entry is recorded to a snapshot file under
+    /// `snapshot_dir` if there is no snapshot yet, and compared to this snapshot otherwise.
+    pub fn snapshot_dir(&mut self, snapshot_dir: Option<PathBuf>) -> &mut Self {
+        self.snapshot_dir = snapshot_dir;
+        self
+    }
+
+    /// Sets the regexes of the values replaced by a placeholder in snapshots.
+    ///
+    /// Masks are used to ignore volatile values (like identifiers or dates) when response bodies
+    /// are recorded and compared.
+    pub fn snapshot_masks(&mut self, snapshot_masks: &[String]) -> &mut Self {
+        self.snapshot_masks = snapshot_masks.to_vec();
+        self
+    }
+
+    /// Records the response bodies to their snapshots, overwriting existing snapshots, instead of
+    /// comparing them.
+    pub fn snapshot_update(&mut self, snapshot_update: bool) -> &mut Self {
+        self.snapshot_update = snapshot_update;
+        self
+    }
+
+    /// Evaluates all the asserts of an entry, even if the status code or a capture fails.
+    ///
+    /// Once a response has been received, failed asserts don't stop the run: they are reported
+    /// together, and the next entries are run. The run is still considered as failed.
+    pub fn soft_asserts(&mut self, soft_asserts: bool) -> &mut Self {
+        self.soft_asserts = soft_asserts;
+        self
+    }
+
     /// Disables certificate revocation checks for SSL backends where such behavior is present.
     pub fn ssl_no_revoke(&mut self, ssl_no_revoke: bool) -> &mut Self {
         self.ssl_no_revoke = ssl_no_revoke;
         self
     }
 
+    /// Encodes form params names as arrays and nested keys.
+    ///
+    /// By default, the names of the form params are sent as is. When set, the names are
+    /// percent-encoded (keeping nested keys brackets like `user[address][city]`) and repeated names
+    /// are sent as arrays (`tag[]`).
+    pub fn nested_form_params(&mut self, nested_form_params: bool) -> &mut Self {
+        self.nested_form_params = nested_form_params;
+        self
+    }
+
     /// Sets maximum time allowed for the transfer.
     ///
     /// Default 300 seconds.
@@ -469,6 +720,13 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the trailers sent after a chunked request body. Setting trailers implies a chunked
+    /// transfer encoding.
+    pub fn trailers(&mut self, trailers: HeaderVec) -> &mut Self {
+        self.trailers = trailers;
+        self
+    }
+
     /// Sets the specified unix domain socket to connect through, instead of using the network.
     pub fn unix_socket(&mut self, unix_socket: Option<String>) -> &mut Self {
         self.unix_socket = unix_socket;
@@ -498,14 +756,29 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the directory where the verbose transcripts of failed entries are written.
+    ///
+    /// When set, verbose logs of each entry are not written to the standard error, but saved in
+    /// a file under `verbose_dir` if the entry fails.
+    pub fn verbose_dir(&mut self, verbose_dir: Option<PathBuf>) -> &mut Self {
+        self.verbose_dir = verbose_dir;
+        self
+    }
+
     /// Create an instance of [`RunnerOptions`].
     pub fn build(&self) -> RunnerOptions {
         RunnerOptions {
             allow_reuse: self.allow_reuse,
             aws_sigv4: self.aws_sigv4.clone(),
+            body_print_limit: self.body_print_limit,
             cacert_file: self.cacert_file.clone(),
+            cancellation_token: self.cancellation_token.clone(),
+            chunked: self.chunked,
             client_cert_file: self.client_cert_file.clone(),
+            client_cert_password: self.client_cert_password.clone(),
+            client_cert_type: self.client_cert_type,
             client_key_file: self.client_key_file.clone(),
+            clock_skew: self.clock_skew,
             color_stdout: self.color_stdout,
             compressed: self.compressed,
             connect_timeout: self.connect_timeout,
@@ -515,18 +788,26 @@ impl RunnerOptionsBuilder {
             continue_on_error: self.continue_on_error,
             cookie_input_file: self.cookie_input_file.clone(),
             digest: self.digest,
+            dns_servers: self.dns_servers.clone(),
+            expect_continue: self.expect_continue,
             fail_with_body: self.fail_with_body,
             follow_location: self.follow_location,
+            foreach: None,
             from_entry: self.from_entry,
             headers: self.headers.clone(),
+            hmac_signature: self.hmac_signature.clone(),
+            hsts_file: self.hsts_file.clone(),
             http_version: self.http_version,
+            http3_fallback: self.http3_fallback,
             no_assert: self.no_assert,
             insecure: self.insecure,
             ip_resolve: self.ip_resolve,
+            json_diff_limit: self.json_diff_limit,
             max_filesize: self.max_filesize,
             max_recv_speed: self.max_recv_speed,
             max_redirect: self.max_redirect,
             max_send_speed: self.max_send_speed,
+            multipart_boundary: self.multipart_boundary.clone(),
             negotiate: self.negotiate,
             netrc: self.netrc,
             netrc_file: self.netrc_file.clone(),
@@ -534,25 +815,41 @@ impl RunnerOptionsBuilder {
             no_headers: self.no_headers.clone(),
             no_proxy: self.no_proxy.clone(),
             ntlm: self.ntlm,
+            oauth2_client_id: self.oauth2_client_id.clone(),
+            oauth2_client_secret: self.oauth2_client_secret.clone(),
+            oauth2_token_url: self.oauth2_token_url.clone(),
             output: self.output.clone(),
+            paginate: None,
+            paginate_max: Count::Finite(100),
+            parser_limits: self.parser_limits,
             path_as_is: self.path_as_is,
             pinned_pub_key: self.pinned_pub_key.clone(),
             pretty: self.pretty_mode,
             proxy: self.proxy.clone(),
             proxy_headers: self.proxy_headers.clone(),
+            proxy_user: self.proxy_user.clone(),
             repeat: self.repeat,
+            replay_dir: self.replay_dir.clone(),
             resolves: self.resolves.clone(),
             retry: self.retry,
             retry_interval: self.retry_interval,
+            retry_on_capture_failure: self.retry_on_capture_failure,
             skip: self.skip,
+            snapshot_dir: self.snapshot_dir.clone(),
+            snapshot_masks: self.snapshot_masks.clone(),
+            snapshot_update: self.snapshot_update,
+            soft_asserts: self.soft_asserts,
             ssl_no_revoke: self.ssl_no_revoke,
+            nested_form_params: self.nested_form_params,
             timeout: self.timeout,
             to_entry: self.to_entry,
+            trailers: self.trailers.clone(),
             unix_socket: self.unix_socket.clone(),
             use_cookie_store: self.use_cookie_store,
             use_jsonpath_coercion: self.use_jsonpath_coercion,
             user: self.user.clone(),
             user_agent: self.user_agent.clone(),
+            verbose_dir: self.verbose_dir.clone(),
         }
     }
 }
@@ -567,12 +864,24 @@ pub struct RunnerOptions {
     pub(crate) allow_reuse: bool,
     /// Specifies the AWS SigV4 option.
     pub(crate) aws_sigv4: Option<String>,
+    /// Maximum number of chars printed for each body when a JSON or XML body assert fails.
+    pub(crate) body_print_limit: usize,
     /// Specifies the certificate file for peer verification.
     pub(crate) cacert_file: Option<String>,
+    /// Token to cancel the run.
+    pub(crate) cancellation_token: Option<CancellationToken>,
+    /// Sends the request body with a chunked transfer encoding.
+    pub(crate) chunked: bool,
     /// Sets Client certificate file and password.
     pub(crate) client_cert_file: Option<String>,
+    /// Sets Client certificate password, when not given with the certificate file.
+    pub(crate) client_cert_password: Option<String>,
+    /// Sets Client certificate file format.
+    pub(crate) client_cert_type: ClientCertType,
     /// Sets private key file name.
     pub(crate) client_key_file: Option<String>,
+    /// Sets the tolerance applied to date comparisons in asserts.
+    pub(crate) clock_skew: Duration,
     /// Whether we use color in stdout, or not. This property is used when response is outputted
     /// to a file or to standard output through `[Options]` section.
     pub(crate) color_stdout: bool,
@@ -593,20 +902,34 @@ pub struct RunnerOptions {
     pub(crate) cookie_input_file: Option<String>,
     /// Enables HTTP Digest authentication.
     pub(crate) digest: bool,
+    /// List of DNS servers to be used instead of the system default.
+    pub(crate) dns_servers: Option<String>,
+    /// Forces or suppresses the `Expect: 100-continue` request header.
+    pub(crate) expect_continue: Option<bool>,
     /// Outputs response body on standard output if there are any run errors.
     pub(crate) fail_with_body: bool,
     /// Sets follow redirect.
     pub(crate) follow_location: FollowLocation,
+    /// Runs the entry once per item of this list. This option is only set on an entry level.
+    pub(crate) foreach: Option<Vec<Value>>,
     /// Executes Hurl file from from_entry (starting at 1), ignores the beginning of the file.
     pub(crate) from_entry: Option<usize>,
     /// Sets additional headers (overrides if a header already exists).
     pub(crate) headers: HeaderVec,
+    /// HMAC signature configuration `ALGORITHM:HEADER:SECRET:TEMPLATE`.
+    pub(crate) hmac_signature: Option<String>,
+    /// Sets the HSTS cache file.
+    pub(crate) hsts_file: Option<String>,
     /// Set requested HTTP version (can be different of the effective HTTP version).
     pub(crate) http_version: RequestedHttpVersion,
+    /// Retries requests over HTTP/2 when HTTP/3 can not be negotiated.
+    pub(crate) http3_fallback: bool,
     /// Set IP version.
     pub(crate) ip_resolve: IpResolve,
     /// Allows Hurl to perform “insecure” SSL connections and transfers.
     pub(crate) insecure: bool,
+    /// Sets the maximum number of differences displayed when a JSON equality assert fails.
+    pub(crate) json_diff_limit: usize,
     /// Set the file size limit.
     pub(crate) max_filesize: Option<u64>,
     /// Set the maximum download speed.
@@ -615,6 +938,8 @@ pub struct RunnerOptions {
     pub(crate) max_redirect: Count,
     /// Set the maximum upload speed.
     pub(crate) max_send_speed: Option<BytesPerSec>,
+    /// Boundary of the multipart form data requests (randomly generated if not set).
+    pub(crate) multipart_boundary: Option<String>,
     /// Enables HTTP Negotiate (SPNEGO) authentication.
     pub(crate) negotiate: bool,
     /// Sets the netrc flag.
@@ -631,8 +956,21 @@ pub struct RunnerOptions {
     pub(crate) no_proxy: Option<String>,
     /// Enables HTTP NTLM authentication.
     pub(crate) ntlm: bool,
+    /// Client identifier used to get an OAuth 2.0 access token.
+    pub(crate) oauth2_client_id: Option<String>,
+    /// Client secret used to get an OAuth 2.0 access token.
+    pub(crate) oauth2_client_secret: Option<String>,
+    /// URL of the OAuth 2.0 token endpoint.
+    pub(crate) oauth2_token_url: Option<String>,
     /// Specifies the file to output the HTTP response.
     pub(crate) output: Option<Output>,
+    /// Name of the capture holding the URL of the next page, the entry being run again while
+    /// this capture is not null.
+    pub(crate) paginate: Option<String>,
+    /// Sets the maximum number of pages run for a paginated entry.
+    pub(crate) paginate_max: Count,
+    /// Limits of the parser of Hurl files.
+    pub(crate) parser_limits: ParserLimits,
     pub(crate) path_as_is: bool,
     /// Sets the pinned public key.
     pub(crate) pinned_pub_key: Option<String>,
@@ -642,22 +980,41 @@ pub struct RunnerOptions {
     pub(crate) proxy: Option<String>,
     /// Sets headers to sent exclusively to proxy.
     pub(crate) proxy_headers: HeaderVec,
+    /// Sets the user name and password to use for proxy authentication.
+    pub(crate) proxy_user: Option<String>,
     /// Set the number of repetition for a given entry.
     pub(crate) repeat: Option<Count>,
+    /// Directory where the replay files of failed entries are written.
+    pub(crate) replay_dir: Option<PathBuf>,
     /// Provides a custom address for a specific host and port pair.
     pub(crate) resolves: Vec<String>,
     /// Sets maximum number of retries.
     pub(crate) retry: Option<Count>,
     /// Sets duration between each retry.
     pub(crate) retry_interval: Duration,
+    /// Considers a capture with a `null` value, or an optional capture without value, as failed.
+    pub(crate) retry_on_capture_failure: bool,
     /// Skip the run without executing any request.
     pub(crate) skip: bool,
+    /// Directory where the snapshots of response bodies are recorded.
+    pub(crate) snapshot_dir: Option<PathBuf>,
+    /// Regexes of the values replaced by a placeholder in snapshots.
+    pub(crate) snapshot_masks: Vec<String>,
+    /// Records the response bodies to their snapshots instead of comparing them.
+    pub(crate) snapshot_update: bool,
+    /// Evaluates all the asserts of an entry, even if the status code or a capture fails, and
+    /// runs the next entries once a response has been received.
+    pub(crate) soft_asserts: bool,
     /// Disables certificate revocation checks for SSL backends where such behavior is present.
     pub(crate) ssl_no_revoke: bool,
+    /// Encodes repeated form params names as arrays and percent-encodes nested keys.
+    pub(crate) nested_form_params: bool,
     /// Sets maximum time allowed for the transfer.
     pub(crate) timeout: Duration,
     /// Executes Hurl file to to_entry (starting at 1), ignores the remaining of the file.
     pub(crate) to_entry: Option<usize>,
+    /// Trailers sent after a chunked request body.
+    pub(crate) trailers: HeaderVec,
     /// Sets the specified unix domain socket to connect through, instead of using the network.
     pub(crate) unix_socket: Option<String>,
     /// Activates the cookie support for a single file.
@@ -668,6 +1025,8 @@ pub struct RunnerOptions {
     pub(crate) user: Option<String>,
     /// Specifies the User-Agent string to send to the HTTP server.
     pub(crate) user_agent: Option<String>,
+    /// Directory where the verbose transcripts of failed entries are written.
+    pub(crate) verbose_dir: Option<PathBuf>,
 }
 
 impl Default for RunnerOptions {
@@ -0,0 +1,228 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Snapshots of response bodies, recorded on a first run and compared on the next runs.
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use regex::Regex;
+use similar::{ChangeTag, TextDiff};
+
+use crate::http::{HttpError, Response};
+
+use super::error::RunnerErrorKind;
+
+/// Maximum number of lines displayed in the diff of a snapshot mismatch.
+const MAX_DIFF_LINES: usize = 20;
+
+/// Placeholder of the values matched by a snapshot mask.
+const MASK_PLACEHOLDER: &str = "<masked>";
+
+/// Result of the comparison of a response body with its snapshot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum SnapshotStatus {
+    /// The response body is equal to the snapshot.
+    Matched,
+    /// There was no snapshot (or snapshots are updated): the response body has been recorded.
+    Recorded,
+    /// The response body differs from the snapshot, with a textual diff.
+    Mismatch(String),
+}
+
+/// Returns the content of the snapshot of an HTTP `response`.
+///
+/// Textual bodies are normalized so snapshots are stable between runs: JSON bodies are
+/// pretty-printed with sorted object keys, and values matching one of the `masks` regexes (like
+/// identifiers or dates) are replaced by `<masked>`. Binary bodies are recorded as is.
+pub(crate) fn snapshot_content(
+    response: &Response,
+    masks: &[String],
+) -> Result<Vec<u8>, HttpError> {
+    match response.text() {
+        Ok(text) => {
+            let masks = masks
+                .iter()
+                .filter_map(|mask| Regex::new(mask).ok())
+                .collect::<Vec<_>>();
+            Ok(normalize(&text, response.is_json(), &masks).into_bytes())
+        }
+        Err(HttpError::InvalidDecoding { .. }) => response.uncompress_body(),
+        Err(error) => Err(error),
+    }
+}
+
+/// Normalizes a textual body `text`, before it's recorded or compared to a snapshot.
+fn normalize(text: &str, is_json: bool, masks: &[Regex]) -> String {
+    let mut text = if is_json && let Ok(value) = serde_json::from_str::<serde_json::Value>(text) {
+        let mut pretty = serde_json::to_string_pretty(&value).unwrap();
+        pretty.push('\n');
+        pretty
+    } else {
+        text.to_string()
+    };
+    for mask in masks {
+        text = mask.replace_all(&text, MASK_PLACEHOLDER).to_string();
+    }
+    text
+}
+
+/// Compares the snapshot `content` of a response to the snapshot file `path`.
+///
+/// If the snapshot file doesn't exist yet, or if `update` is `true`, `content` is written to `path`
+/// and becomes the new reference of the next runs.
+pub(crate) fn check(
+    path: &Path,
+    content: &[u8],
+    update: bool,
+) -> Result<SnapshotStatus, RunnerErrorKind> {
+    if !update {
+        match fs::read(path) {
+            Ok(snapshot) if snapshot == content => return Ok(SnapshotStatus::Matched),
+            Ok(snapshot) => return Ok(SnapshotStatus::Mismatch(diff(&snapshot, content))),
+            Err(error) if error.kind() == ErrorKind::NotFound => {}
+            Err(_) => {
+                return Err(RunnerErrorKind::FileReadAccess {
+                    path: path.to_path_buf(),
+                });
+            }
+        }
+    }
+    let write = match path.parent() {
+        Some(dir) => fs::create_dir_all(dir).and_then(|_| fs::write(path, content)),
+        None => fs::write(path, content),
+    };
+    match write {
+        Ok(_) => Ok(SnapshotStatus::Recorded),
+        Err(error) => Err(RunnerErrorKind::FileWriteAccess {
+            path: path.to_path_buf(),
+            error: error.to_string(),
+        }),
+    }
+}
+
+/// Returns the lines that differ between a `snapshot` and the `actual` content, prefixed by `-` for
+/// the snapshot and `+` for the actual content.
+fn diff(snapshot: &[u8], actual: &[u8]) -> String {
+    let (Ok(snapshot), Ok(actual)) = (std::str::from_utf8(snapshot), std::str::from_utf8(actual))
+    else {
+        return format!(
+            "binary content differs ({} bytes in snapshot, {} bytes in response)",
+            snapshot.len(),
+            actual.len()
+        );
+    };
+    let text_diff = TextDiff::from_lines(snapshot, actual);
+    let lines = text_diff
+        .iter_all_changes()
+        .filter_map(|change| match change.tag() {
+            ChangeTag::Delete => Some(format!("-{}", change.value().trim_end_matches('\n'))),
+            ChangeTag::Insert => Some(format!("+{}", change.value().trim_end_matches('\n'))),
+            ChangeTag::Equal => None,
+        })
+        .collect::<Vec<_>>();
+    let mut s = lines
+        .iter()
+        .take(MAX_DIFF_LINES)
+        .cloned()
+        .collect::<Vec<_>>()
+        .join("\n");
+    if lines.len() > MAX_DIFF_LINES {
+        s.push_str(&format!(
+            "\n... {} more line(s)",
+            lines.len() - MAX_DIFF_LINES
+        ));
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_json_body() {
+        let text = r#"{"name":"Bob","id":1234,"tags":["a","b"]}"#;
+        assert_eq!(
+            normalize(text, true, &[]),
+            "{\n  \"id\": 1234,\n  \"name\": \"Bob\",\n  \"tags\": [\n    \"a\",\n    \"b\"\n  ]\n}\n"
+        );
+        // Bodies that are not JSON responses are kept as is.
+        assert_eq!(normalize(text, false, &[]), text);
+        assert_eq!(normalize("{not json", true, &[]), "{not json");
+    }
+
+    #[test]
+    fn normalize_with_masks() {
+        let masks = [
+            Regex::new(r"\d{4}-\d{2}-\d{2}").unwrap(),
+            Regex::new(r#""id": \d+"#).unwrap(),
+        ];
+        assert_eq!(
+            normalize("Generated on 2026-10-16", false, &masks),
+            "Generated on <masked>"
+        );
+        assert_eq!(
+            normalize(r#"{"id":1234,"name":"Bob"}"#, true, &masks),
+            "{\n  <masked>,\n  \"name\": \"Bob\"\n}\n"
+        );
+    }
+
+    #[test]
+    fn diff_text() {
+        assert_eq!(
+            diff(b"a\nb\nc\n", b"a\nB\nc\nd\n"),
+            "-b\n+B\n+d".to_string()
+        );
+    }
+
+    #[test]
+    fn diff_is_truncated() {
+        let snapshot = (0..30).map(|i| format!("{i}\n")).collect::<String>();
+        let diff = diff(snapshot.as_bytes(), b"");
+        assert_eq!(diff.lines().count(), MAX_DIFF_LINES + 1);
+        assert!(diff.ends_with("... 10 more line(s)"));
+    }
+
+    #[test]
+    fn diff_binary() {
+        assert_eq!(
+            diff(&[0xff, 0x00], &[0xff]),
+            "binary content differs (2 bytes in snapshot, 1 bytes in response)"
+        );
+    }
+
+    #[test]
+    fn check_records_then_compares() {
+        let dir = std::env::temp_dir().join(format!("hurl-snapshot-{}", std::process::id()));
+        let path = dir.join("test.hurl.entry-1.snap");
+
+        assert_eq!(
+            check(&path, b"Hello\n", false),
+            Ok(SnapshotStatus::Recorded)
+        );
+        assert_eq!(check(&path, b"Hello\n", false), Ok(SnapshotStatus::Matched));
+        assert_eq!(
+            check(&path, b"Bye\n", false),
+            Ok(SnapshotStatus::Mismatch("-Hello\n+Bye".to_string()))
+        );
+        assert_eq!(check(&path, b"Bye\n", true), Ok(SnapshotStatus::Recorded));
+        assert_eq!(check(&path, b"Bye\n", false), Ok(SnapshotStatus::Matched));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -34,7 +34,7 @@ pub enum Value {
     /// A date.
     Date(chrono::DateTime<chrono::Utc>),
     /// A structure to represent an HTTP response.
-    HttpResponse(HttpResponse),
+    HttpResponse(Box<HttpResponse>),
     /// A list of [`Value`].
     List(Vec<Value>),
     /// A structure to represents node of object (returned from XPath queries).
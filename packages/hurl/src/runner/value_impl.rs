@@ -59,6 +59,9 @@ impl Value {
 
     /// Returns `true` if the value contains another value, otherwise `false`.
     ///
+    /// Objects and HTTP responses headers are tested against a `name: value` string: the member
+    /// (or header) `name` must exist with this `value`. Without `:`, only the name is tested.
+    ///
     /// Returns a [`EvalError::Type`] if the given value types are not supported.
     pub fn contains(&self, other: &Value) -> Result<bool, EvalError> {
         match (self, other) {
@@ -77,6 +80,23 @@ impl Value {
                 }
                 Ok(included)
             }
+            (Value::Object(members), Value::String(pair)) => {
+                let (name, value) = split_pair(pair);
+                let included = members
+                    .iter()
+                    .filter(|(n, _)| n == name)
+                    .any(|(_, v)| value.is_none_or(|value| v.to_string() == value));
+                Ok(included)
+            }
+            (Value::HttpResponse(response), Value::String(pair)) => {
+                let (name, value) = split_pair(pair);
+                let values = response.headers().values(name);
+                let included = match value {
+                    Some(value) => values.contains(&value),
+                    None => !values.is_empty(),
+                };
+                Ok(included)
+            }
             _ => Err(EvalError::Type),
         }
     }
@@ -237,6 +257,14 @@ impl Value {
     }
 }
 
+/// Splits a `name: value` string in a name and an optional value, trimming both.
+fn split_pair(s: &str) -> (&str, Option<&str>) {
+    match s.split_once(':') {
+        Some((name, value)) => (name.trim(), Some(value.trim())),
+        None => (s.trim(), None),
+    }
+}
+
 fn contains(haystack: &[u8], needle: &[u8]) -> bool {
     haystack
         .windows(needle.len())
@@ -246,7 +274,8 @@ fn contains(haystack: &[u8], needle: &[u8]) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::runner::Number;
+    use crate::http::{Header, HeaderVec, Url};
+    use crate::runner::{HttpResponse, Number};
 
     #[test]
     fn test_compare() {
@@ -325,6 +354,34 @@ mod tests {
         assert!(!values.contains(&Value::Number(Number::Integer(4))).unwrap());
     }
 
+    #[test]
+    fn test_contains_key_value() {
+        let object = Value::Object(vec![
+            ("name".to_string(), Value::String("Bob".to_string())),
+            ("age".to_string(), Value::Number(Number::Integer(42))),
+        ]);
+        let contains = |s: &str| object.contains(&Value::String(s.to_string())).unwrap();
+        assert!(contains("name: Bob"));
+        assert!(contains("age:42"));
+        assert!(contains("name"));
+        assert!(!contains("name: Alice"));
+        assert!(!contains("Name: Bob"));
+        assert!(!contains("city"));
+
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Cache-Control", "no-store"));
+        headers.push(Header::new("Set-Cookie", "a=1"));
+        headers.push(Header::new("Set-Cookie", "b=2"));
+        let url = Url::from_str("https://example.org").unwrap();
+        let response = Value::HttpResponse(Box::new(HttpResponse::new(url, 200, headers, None)));
+        let contains = |s: &str| response.contains(&Value::String(s.to_string())).unwrap();
+        assert!(contains("cache-control: no-store"));
+        assert!(contains("Set-Cookie: b=2"));
+        assert!(contains("set-cookie"));
+        assert!(!contains("cache-control: no-cache"));
+        assert!(!contains("Location"));
+    }
+
     #[test]
     fn test_include() {
         let values = Value::List(vec![
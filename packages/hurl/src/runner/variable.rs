@@ -16,7 +16,9 @@
  *
  */
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
+use super::function::{CustomFunction, FunctionSet};
 use super::value::Value;
 
 /// Represents a variable named to hold `Value`.
@@ -70,6 +72,11 @@ pub struct VariableSet {
     /// and the previous secrets. What's a secret remains a secret forever, even if a new secret
     /// variable get a new value.
     secrets: HashSet<String>,
+    /// The implicit variables, set by the runner to expose the run context (current file, entry
+    /// index etc...). An implicit variable is shadowed by a variable with the same name.
+    implicits: HashMap<String, Variable>,
+    /// The custom functions, that can be called in expressions like any builtin function.
+    functions: FunctionSet,
 }
 
 impl VariableSet {
@@ -78,6 +85,8 @@ impl VariableSet {
         VariableSet {
             variables: HashMap::new(),
             secrets: HashSet::new(),
+            implicits: HashMap::new(),
+            functions: FunctionSet::default(),
         }
     }
 
@@ -95,6 +104,8 @@ impl VariableSet {
         VariableSet {
             variables: variables.clone(),
             secrets: HashSet::new(),
+            implicits: HashMap::new(),
+            functions: FunctionSet::default(),
         }
     }
 
@@ -116,9 +127,66 @@ impl VariableSet {
         self.variables.insert(name, variable);
     }
 
+    /// Adds `value` to the secrets values, without declaring a new variable.
+    ///
+    /// This is used to redact values derived from secrets, like encoded credentials.
+    pub fn add_secret_value(&mut self, value: String) {
+        self.secrets.insert(value);
+    }
+
+    /// Inserts an implicit public variable named `name` with `value`.
+    ///
+    /// Implicit variables are not listed by [`VariableSet::iter`] and can be shadowed by any
+    /// variable named `name`.
+    pub fn insert_implicit(&mut self, name: &str, value: Value) {
+        let variable = Variable::new(name, &value, Visibility::Public);
+        self.implicits.insert(name.to_string(), variable);
+    }
+
+    /// Inserts a custom `function` named `name`, that can be called in expressions.
+    ///
+    /// A custom function can not override a builtin function, like `uuidv7` or `randomInt`.
+    pub fn insert_function(&mut self, name: &str, function: impl CustomFunction + 'static) {
+        self.functions.insert(name, Arc::new(function));
+    }
+
+    /// Copies the variables of `other` named `names` into this set, keeping their visibility.
+    ///
+    /// Names not defined in `other` are ignored.
+    pub fn copy_from(&mut self, other: &VariableSet, names: &[String]) {
+        for name in names {
+            let Some(variable) = other.variables.get(name) else {
+                continue;
+            };
+            if variable.is_secret() {
+                self.secrets.insert(variable.value.to_string());
+            }
+            self.variables.insert(name.clone(), variable.clone());
+        }
+    }
+
+    /// Inserts all the variables and custom functions of `other` into this set, keeping the
+    /// variables visibility.
+    ///
+    /// Variables already defined in this set are overridden.
+    pub fn extend(&mut self, other: &VariableSet) {
+        self.secrets.extend(other.secrets.iter().cloned());
+        self.functions.extend(&other.functions);
+        for (name, variable) in other.variables.iter() {
+            self.variables.insert(name.clone(), variable.clone());
+        }
+    }
+
     /// Returns a reference to the value corresponding to the variable named `name`.
     pub fn get(&self, name: &str) -> Option<&Variable> {
-        self.variables.get(name)
+        self.variables
+            .get(name)
+            .or_else(|| self.implicits.get(name))
+    }
+
+    /// Returns the custom function named `name`.
+    pub fn function(&self, name: &str) -> Option<&dyn CustomFunction> {
+        self.functions.get(name)
     }
 
     /// Returns an iterator over all the variables values.
@@ -242,4 +310,66 @@ mod test {
         secrets.sort();
         assert_eq!(secrets, vec!["1234", "42"]);
     }
+
+    #[test]
+    fn add_secret_value() {
+        let mut variables = VariableSet::new();
+        variables.insert_secret("password".to_string(), "secret".to_string());
+        variables.add_secret_value("Ym9iOnNlY3JldA==".to_string());
+
+        let mut secrets = variables.secrets();
+        secrets.sort();
+        assert_eq!(secrets, vec!["Ym9iOnNlY3JldA==", "secret"]);
+        assert_eq!(variables.iter().count(), 1);
+    }
+
+    #[test]
+    fn implicit_variable_is_shadowed() {
+        let mut variables = VariableSet::new();
+        variables.insert_implicit("entry_index", Value::Number(Integer(1)));
+        assert_eq!(
+            variables.get("entry_index").unwrap().value,
+            Value::Number(Integer(1))
+        );
+        assert_eq!(variables.iter().count(), 0);
+
+        variables.insert("entry_index".to_string(), Value::String("foo".to_string()));
+        variables.insert_implicit("entry_index", Value::Number(Integer(2)));
+        assert_eq!(
+            variables.get("entry_index").unwrap().value,
+            Value::String("foo".to_string())
+        );
+    }
+
+    #[test]
+    fn copy_variables_from_set() {
+        let mut other = VariableSet::new();
+        other.insert("foo".to_string(), Value::Number(Integer(1)));
+        other.insert("bar".to_string(), Value::Bool(true));
+        other.insert_secret("token".to_string(), "12345".to_string());
+
+        let mut variables = VariableSet::new();
+        variables.copy_from(
+            &other,
+            &["foo".to_string(), "token".to_string(), "baz".to_string()],
+        );
+        assert_eq!(variables.iter().count(), 2);
+        assert_eq!(
+            variables.get("foo").unwrap().value,
+            Value::Number(Integer(1))
+        );
+        assert!(variables.get("bar").is_none());
+        assert!(variables.get("token").unwrap().is_secret());
+        assert_eq!(variables.secrets(), vec!["12345"]);
+
+        let mut variables = VariableSet::new();
+        variables.insert("foo".to_string(), Value::Number(Integer(2)));
+        variables.extend(&other);
+        assert_eq!(variables.iter().count(), 3);
+        assert_eq!(
+            variables.get("foo").unwrap().value,
+            Value::Number(Integer(1))
+        );
+        assert_eq!(variables.secrets(), vec!["12345"]);
+    }
 }
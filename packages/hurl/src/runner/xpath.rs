@@ -21,6 +21,7 @@ use std::ptr;
 
 use libxml::bindings::{htmlReadMemory, xmlReadMemory};
 use libxml::parser::{ParseFormat, Parser, XmlParseError};
+use libxml::tree::{CanonicalizationMode, CanonicalizationOptions};
 
 use super::number::Number;
 use super::value::Value;
@@ -79,6 +80,44 @@ impl Document {
     }
 }
 
+/// Returns the canonical form of a XML string `data` ([Canonical XML 1.0] without comments).
+///
+/// Contrary to [`Document::parse`], `data` must be a well-formed XML document. Whitespace-only text
+/// nodes between elements are removed before canonicalization.
+///
+/// [Canonical XML 1.0]: https://www.w3.org/TR/xml-c14n
+pub fn canonicalize(data: &str) -> Result<String, String> {
+    let input_bytes = data.as_bytes();
+    let input_ptr = input_bytes.as_ptr() as *const c_char;
+    let Ok(input_len) = try_usize_to_i32(input_bytes.len()) else {
+        return Err("document too large".to_string());
+    };
+    let encoding = CString::new("utf-8").unwrap();
+    let encoding_ptr = encoding.as_ptr();
+    let url_ptr = ptr::null();
+
+    // XML_PARSE_NOERROR | XML_PARSE_NOWARNING | XML_PARSE_NOBLANKS
+    let options = 32 + 64 + 256;
+    let doc = unsafe {
+        let doc_ptr = xmlReadMemory(input_ptr, input_len, url_ptr, encoding_ptr, options);
+        if doc_ptr.is_null() {
+            return Err("invalid input data".to_string());
+        }
+        libxml::tree::Document::new_ptr(doc_ptr)
+    };
+    if doc.get_root_element().is_none() {
+        return Err("no root element".to_string());
+    }
+
+    let options = CanonicalizationOptions {
+        mode: CanonicalizationMode::Canonical1_0,
+        inclusive_ns_prefixes: vec![],
+        with_comments: false,
+    };
+    doc.canonicalize(options, None)
+        .map_err(|_| "canonicalization error".to_string())
+}
+
 /// FIXME: Here are some patched functions of libxml crate.
 /// Started from libxml 2.11.1+, we have some encoding issue.
 /// See:
@@ -507,4 +546,27 @@ mod tests {
         let expr = "//_:book/_:notes/*[local-name()='p']";
         assert_eq!(doc.eval_xpath(expr).unwrap(), Value::Nodeset(1));
     }
+
+    #[test]
+    fn test_canonicalize() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<food>
+  <!-- Fruits -->
+  <banana price="1.1"   type="fruit"/>
+  <apple type='fruit'>Golden &amp; Red</apple>
+</food>
+"#;
+        assert_eq!(
+            canonicalize(xml).unwrap(),
+            r#"<food><banana price="1.1" type="fruit"></banana><apple type="fruit">Golden &amp; Red</apple></food>"#
+        );
+
+        let a = r#"<a c="1" b="2"><d/></a>"#;
+        let b = r#"<a   b="2"
+  c="1"><d></d></a>"#;
+        assert_eq!(canonicalize(a).unwrap(), canonicalize(b).unwrap());
+
+        assert!(canonicalize("").is_err());
+        assert!(canonicalize("<a><b></a>").is_err());
+    }
 }
@@ -0,0 +1,126 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Cooperative cancellation of a run.
+use std::fmt;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+/// A handle to cancel a run from another thread.
+///
+/// A token is cheap to clone: all the clones share the same state, so cancelling one of them
+/// cancels all the others. Once cancelled, a token stays cancelled.
+///
+/// # Example
+///
+/// ```
+/// use hurl::util::cancel::CancellationToken;
+///
+/// let token = CancellationToken::new();
+/// let handle = token.clone();
+/// assert!(!token.is_cancelled());
+/// handle.cancel();
+/// assert!(token.is_cancelled());
+/// ```
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    state: Arc<(Mutex<bool>, Condvar)>,
+}
+
+impl CancellationToken {
+    /// Creates a new token, not cancelled.
+    pub fn new() -> Self {
+        CancellationToken::default()
+    }
+
+    /// Cancels this token, waking up any thread sleeping on it.
+    pub fn cancel(&self) {
+        let (cancelled, condvar) = &*self.state;
+        *cancelled.lock().unwrap() = true;
+        condvar.notify_all();
+    }
+
+    /// Returns `true` if this token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        let (cancelled, _) = &*self.state;
+        *cancelled.lock().unwrap()
+    }
+
+    /// Blocks the current thread for `duration`, or until this token is cancelled.
+    ///
+    /// Returns `true` if the token has been cancelled.
+    pub fn sleep(&self, duration: Duration) -> bool {
+        let (cancelled, condvar) = &*self.state;
+        let deadline = Instant::now() + duration;
+        let mut cancelled = cancelled.lock().unwrap();
+        while !*cancelled {
+            let now = Instant::now();
+            if now >= deadline {
+                break;
+            }
+            cancelled = condvar.wait_timeout(cancelled, deadline - now).unwrap().0;
+        }
+        *cancelled
+    }
+}
+
+impl fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+/// Two tokens are equal if they share the same state.
+impl PartialEq for CancellationToken {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn test_cancel() {
+        let token = CancellationToken::new();
+        let other = token.clone();
+        assert!(!token.is_cancelled());
+        assert_eq!(token, other);
+        assert_ne!(token, CancellationToken::new());
+
+        other.cancel();
+        assert!(token.is_cancelled());
+        assert!(other.is_cancelled());
+    }
+
+    #[test]
+    fn test_sleep() {
+        let token = CancellationToken::new();
+        assert!(!token.sleep(Duration::from_millis(10)));
+
+        let other = token.clone();
+        let handle = thread::spawn(move || other.sleep(Duration::from_secs(60)));
+        token.cancel();
+        assert!(handle.join().unwrap());
+        assert!(token.sleep(Duration::from_secs(60)));
+    }
+}
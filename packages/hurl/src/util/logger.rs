@@ -20,8 +20,10 @@ use hurl_core::ast::SourceInfo;
 use hurl_core::error::{DisplaySourceError, OutputFormat};
 use hurl_core::input::Input;
 use hurl_core::text::{Format, Style, StyledString};
+use hurl_core::types::Index;
 
-use crate::runner::Value;
+use crate::http::Call;
+use crate::runner::{EntryResult, Event, EventListener, Value};
 use crate::util::redacted::Redact;
 use crate::util::term::{Stderr, WriteMode};
 
@@ -46,6 +48,8 @@ pub struct Logger {
     pub(crate) verbosity: Option<Verbosity>,
     pub(crate) stderr: Stderr,
     secrets: Vec<String>,
+    /// When started, debug messages are written to this transcript instead of the standard error.
+    transcript: Option<String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -116,6 +120,7 @@ impl Logger {
             verbosity: options.verbosity,
             stderr: term,
             secrets: secrets.to_vec(),
+            transcript: None,
         }
     }
 
@@ -160,7 +165,7 @@ impl Logger {
             s.push(" ");
             s.push(message);
         }
-        self.eprintln(&s.to_string(fmt));
+        self.debug_eprintln(&s.to_string(fmt));
     }
 
     /// Prints a given debug message in bold to this logger [`Stderr`] instance, in verbose and very verbose mode.
@@ -177,7 +182,32 @@ impl Logger {
             s.push(" ");
             s.push_with(message, Style::new().bold());
         }
-        self.eprintln(&s.to_string(fmt));
+        self.debug_eprintln(&s.to_string(fmt));
+    }
+
+    /// Prints Hurl `lines` to this logger [`Stderr`] instance, in verbose and very verbose mode.
+    ///
+    /// If colors are enabled, lines are syntax highlighted. Displayed lines start with `*`.
+    pub fn debug_hurl(&mut self, lines: &[StyledString]) {
+        if self.verbosity.is_none() || self.verbosity == Some(Verbosity::LowVerbose) {
+            return;
+        }
+        for line in lines {
+            let plain = line.to_string(Format::Plain).redact(&self.secrets);
+            let message = if self.color {
+                // A secret split across styled tokens is not redacted in the highlighted line: in
+                // this case, we fall back to the plain redacted line.
+                let ansi = line.to_string(Format::Ansi).redact(&self.secrets);
+                if strip_ansi(&ansi) == plain {
+                    ansi
+                } else {
+                    plain
+                }
+            } else {
+                plain
+            };
+            self.debug(&message);
+        }
     }
 
     /// Prints a given debug message from libcurl to this logger [`Stderr`] instance, in verbose and very verbose mode.
@@ -194,7 +224,7 @@ impl Logger {
             s.push(" ");
             s.push(message);
         }
-        self.eprintln(&s.to_string(fmt));
+        self.debug_eprintln(&s.to_string(fmt));
     }
 
     /// Prints an error (syntax error or runtime error) to this logger [`Stderr`] instance, in verbose and very verbose mode.
@@ -232,14 +262,14 @@ impl Logger {
         s.push_with(name, Style::new().cyan().bold());
         s.push(": ");
         s.push(value);
-        self.eprintln(&s.to_string(fmt));
+        self.debug_eprintln(&s.to_string(fmt));
     }
 
     pub fn debug_header_in_end(&mut self) {
         if self.verbosity.is_none() {
             return;
         }
-        self.eprintln("<");
+        self.debug_eprintln("<");
     }
 
     #[deprecated(since = "8.1.0", note = "please use single `debug_header_in` instead")]
@@ -263,7 +293,7 @@ impl Logger {
         s.push_with(name, Style::new().cyan().bold());
         s.push(": ");
         s.push(value);
-        self.eprintln(&s.to_string(fmt));
+        self.debug_eprintln(&s.to_string(fmt));
     }
 
     #[deprecated(since = "8.1.0", note = "please use single `debug_header_in` instead")]
@@ -277,7 +307,7 @@ impl Logger {
         if self.verbosity.is_none() {
             return;
         }
-        self.eprintln(">");
+        self.debug_eprintln(">");
     }
 
     /// Prints a HTTP response status code to this logger [`Stderr`] instance, in verbose and very verbose mode.
@@ -289,7 +319,7 @@ impl Logger {
         let mut s = StyledString::new();
         s.push("< ");
         s.push_with(line, Style::new().green().bold());
-        self.eprintln(&s.to_string(fmt));
+        self.debug_eprintln(&s.to_string(fmt));
     }
 
     /// Prints a warning given message to this logger [`Stderr`] instance, no matter what is the verbosity.
@@ -324,7 +354,7 @@ impl Logger {
         let mut s = StyledString::new();
         s.push("> ");
         s.push_with(line, Style::new().purple().bold());
-        self.eprintln(&s.to_string(fmt));
+        self.debug_eprintln(&s.to_string(fmt));
     }
 
     /// Prints a capture to this logger [`Stderr`] instance, in verbose and very verbose mode.
@@ -340,7 +370,24 @@ impl Logger {
         s.push_with(name, Style::new().yellow().bold());
         s.push(": ");
         s.push(&value);
-        self.eprintln(&s.to_string(fmt));
+        self.debug_eprintln(&s.to_string(fmt));
+    }
+
+    /// Starts a new transcript: until [`Logger::stop_transcript`] is called, debug messages are
+    /// written to the transcript and not to this logger [`Stderr`] instance. Other messages (info,
+    /// warning, errors) are written to both.
+    pub fn start_transcript(&mut self) {
+        self.transcript = Some(String::new());
+    }
+
+    /// Stops the current transcript and returns its content, without any ANSI color codes.
+    pub fn stop_transcript(&mut self) -> Option<String> {
+        self.transcript.take()
+    }
+
+    /// Returns `true` if a transcript is in progress, `false` otherwise.
+    pub fn has_transcript(&self) -> bool {
+        self.transcript.is_some()
     }
 
     /// Update logger with new `secrets`.
@@ -357,9 +404,82 @@ impl Logger {
             let new_buffer = old_buffer.redact(&self.secrets);
             self.stderr.set_buffer(new_buffer);
         }
+        if let Some(transcript) = &self.transcript {
+            self.transcript = Some(transcript.redact(&self.secrets));
+        }
+    }
+
+    /// Logs the header indicating the begin of the entry `entry_index` run.
+    fn log_entry_started(&mut self, entry_index: Index) {
+        self.debug_important(
+            "------------------------------------------------------------------------------",
+        );
+        self.debug_important(&format!("Executing entry {entry_index}"));
+    }
+
+    /// Logs the errors of an `entry_result`.
+    /// If we're going to `retry` the entry, we log errors only in verbose. Otherwise, we log error on stderr.
+    /// Errors of an included entry are rendered with the source of its file instead of `content`.
+    fn log_entry_errors(
+        &mut self,
+        entry_result: &EntryResult,
+        content: &str,
+        filename: Option<&Input>,
+        retry: bool,
+    ) {
+        let (content, filename) = match &entry_result.source_file {
+            Some(file) => (file.content.as_str(), Some(&file.input)),
+            None => (content, filename),
+        };
+        if retry {
+            entry_result.errors.iter().for_each(|error| {
+                self.debug_error(content, filename, error, entry_result.source_info);
+            });
+            return;
+        }
+
+        if self.error_format == ErrorFormat::Long
+            && let Some(Call { response, .. }) = entry_result.calls.last()
+        {
+            self.info_curl_cmd(&entry_result.curl_cmd.to_string());
+            self.info("");
+
+            response.log_info_all(self);
+        }
+
+        entry_result.errors.iter().for_each(|error| {
+            let filename = filename.map_or(String::new(), |f| f.to_string());
+            let message = error.render(
+                &filename,
+                content,
+                Some(entry_result.source_info),
+                OutputFormat::Terminal(self.color),
+            );
+            self.error_rich(&message);
+        });
+    }
+
+    /// Prints a debug message to the current transcript if any, or to this logger [`Stderr`] instance.
+    fn debug_eprintln(&mut self, message: &str) {
+        if self.transcript.is_some() {
+            self.transcript_println(message);
+            return;
+        }
+        self.eprintln(message);
+    }
+
+    fn transcript_println(&mut self, message: &str) {
+        let message = strip_ansi(message).redact(&self.secrets);
+        if let Some(transcript) = &mut self.transcript {
+            transcript.push_str(&message);
+            transcript.push('\n');
+        }
     }
 
     fn eprintln(&mut self, message: &str) {
+        if self.transcript.is_some() {
+            self.transcript_println(message);
+        }
         if self.secrets.is_empty() {
             self.stderr.eprintln(message);
             return;
@@ -368,3 +488,41 @@ impl Logger {
         self.stderr.eprintln(&redacted);
     }
 }
+
+/// The logger renders the run events: the preamble of each entry run, and the errors of each
+/// completed entry.
+impl EventListener for Logger {
+    fn on_event(&mut self, event: &Event) {
+        match event {
+            Event::EntryStarted { current, .. } => self.log_entry_started(*current),
+            Event::EntryCompleted {
+                result,
+                content,
+                filename,
+                retry,
+            } if !result.errors.is_empty() => {
+                self.log_entry_errors(result, content, *filename, *retry);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Removes ANSI escape sequences (colors, styles) from `message`.
+fn strip_ansi(message: &str) -> String {
+    let mut s = String::with_capacity(message.len());
+    let mut chars = message.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // An escape sequence is terminated by an ASCII letter (`m` for styles).
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            s.push(c);
+        }
+    }
+    s
+}
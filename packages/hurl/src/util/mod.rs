@@ -16,6 +16,7 @@
  *
  */
 //! Common utilities like log, path helpers and standard output/error wrapper.
+pub mod cancel;
 pub mod logger;
 pub mod path;
 pub mod redacted;
@@ -29,6 +29,27 @@ pub struct ContextDir {
     /// As a consequence, it is always defined (and can't be replaced by a `Option<PathBuf>`).
     /// It can be relative (to the current directory) or absolute.
     file_root: PathBuf,
+    /// The content-addressable fixtures directory, used to resolve `file,sha256:<hash>;` files.
+    fixtures_dir: Option<PathBuf>,
+    /// The directory from which relative filenames are resolved, the file root if not set.
+    /// It can be relative (to the current directory) or absolute.
+    base_dir: Option<PathBuf>,
+    /// The policy of accesses to files outside the file root.
+    file_root_mode: FileRootMode,
+    /// In sandbox mode, files can't be written and can only be read under the file root, whatever
+    /// the file root mode.
+    sandbox: bool,
+}
+
+/// Policy of accesses to files outside the file root.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum FileRootMode {
+    /// Files must be under the file root: `..` components and symbolic links can't be used to
+    /// access a file outside the file root.
+    #[default]
+    Strict,
+    /// Files can be accessed anywhere, the file root only being used to resolve relative paths.
+    Permissive,
 }
 
 impl Default for ContextDir {
@@ -36,6 +57,10 @@ impl Default for ContextDir {
         ContextDir {
             current_dir: PathBuf::new(),
             file_root: PathBuf::new(),
+            fixtures_dir: None,
+            base_dir: None,
+            file_root_mode: FileRootMode::default(),
+            sandbox: false,
         }
     }
 }
@@ -46,25 +71,131 @@ impl ContextDir {
         ContextDir {
             current_dir: PathBuf::from(current_dir),
             file_root: PathBuf::from(file_root),
+            fixtures_dir: None,
+            base_dir: None,
+            file_root_mode: FileRootMode::default(),
+            sandbox: false,
         }
     }
 
+    /// Returns this context directory with relative filenames resolved from `base_dir` instead of
+    /// the file root. Accesses are still checked against the file root.
+    pub fn with_base_dir(mut self, base_dir: &Path) -> ContextDir {
+        self.base_dir = Some(PathBuf::from(base_dir));
+        self
+    }
+
+    /// Returns this context directory with a policy `file_root_mode` for accesses outside the file
+    /// root.
+    pub fn with_file_root_mode(mut self, file_root_mode: FileRootMode) -> ContextDir {
+        self.file_root_mode = file_root_mode;
+        self
+    }
+
+    /// Returns this context directory in sandbox mode if `sandbox` is true: files can't be written
+    /// and can only be read under the file root.
+    pub fn with_sandbox(mut self, sandbox: bool) -> ContextDir {
+        self.sandbox = sandbox;
+        self
+    }
+
+    /// Returns true if this context directory is in sandbox mode.
+    pub fn is_sandboxed(&self) -> bool {
+        self.sandbox
+    }
+
+    /// Returns this context directory with a fixtures directory `fixtures_dir`, relative to the
+    /// current directory.
+    pub fn with_fixtures_dir(mut self, fixtures_dir: &Path) -> ContextDir {
+        self.fixtures_dir = Some(self.current_dir.join(fixtures_dir));
+        self
+    }
+
+    /// Returns the content-addressable fixtures directory, if any.
+    pub fn fixtures_dir(&self) -> Option<&Path> {
+        self.fixtures_dir.as_deref()
+    }
+
     /// Returns a path (absolute or relative), given a filename.
     pub fn resolved_path(&self, filename: &Path) -> PathBuf {
-        self.file_root.join(filename)
+        let base_dir = self.base_dir.as_deref().unwrap_or(&self.file_root);
+        base_dir.join(filename)
+    }
+
+    /// Returns the path to read the file `filename`, or an error if this access is not allowed.
+    ///
+    /// Every file read by the runner (request bodies, multipart files, included Hurl files and
+    /// files of options) must be resolved through this method.
+    pub fn read_path(&self, filename: &Path) -> Result<PathBuf, AccessError> {
+        if !self.is_access_allowed(filename) {
+            let path = filename.to_path_buf();
+            return Err(if self.sandbox {
+                AccessError::SandboxRead(path)
+            } else {
+                AccessError::OutsideFileRoot(path)
+            });
+        }
+        Ok(self.resolved_path(filename))
+    }
+
+    /// Returns the path to write the file `filename`, or an error if this access is not allowed,
+    /// for instance when using `output` option in \[Options\] sections. No file can be written
+    /// in sandbox mode.
+    pub fn write_path(&self, filename: &Path) -> Result<PathBuf, AccessError> {
+        let path = filename.to_path_buf();
+        if self.sandbox {
+            return Err(AccessError::SandboxWrite(path));
+        }
+        if !self.is_access_allowed(filename) {
+            return Err(AccessError::OutsideFileRoot(path));
+        }
+        Ok(self.resolved_path(filename))
+    }
+
+    /// Checks that the runner can connect to `url`, a request URL or a proxy for instance.
+    /// In sandbox mode, only HTTP and HTTPS schemes are allowed. An URL without scheme is allowed,
+    /// as libcurl defaults to HTTP.
+    pub fn check_url(&self, url: &str) -> Result<(), AccessError> {
+        if !self.sandbox {
+            return Ok(());
+        }
+        match url.split_once("://") {
+            Some((scheme, _))
+                if !scheme.eq_ignore_ascii_case("http")
+                    && !scheme.eq_ignore_ascii_case("https") =>
+            {
+                Err(AccessError::SandboxScheme(url.to_string()))
+            }
+            _ => Ok(()),
+        }
     }
 
     /// Checks if a given `filename` access is authorized.
-    /// This method is used to check if a local file can be included in POST request or if a
-    /// response can be outputted to a given file when using `output` option in \[Options\] sections.
-    pub fn is_access_allowed(&self, filename: &Path) -> bool {
+    fn is_access_allowed(&self, filename: &Path) -> bool {
+        if self.file_root_mode == FileRootMode::Permissive && !self.sandbox {
+            return true;
+        }
         let file = self.resolved_path(filename);
         let absolute_file = self.current_dir.join(file);
         let absolute_file_root = self.current_dir.join(&self.file_root);
         is_descendant(absolute_file.as_path(), absolute_file_root.as_path())
+            && !is_escaping_link(absolute_file.as_path(), absolute_file_root.as_path())
     }
 }
 
+/// An access denied by a [`ContextDir`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccessError {
+    /// The file is outside the file root.
+    OutsideFileRoot(PathBuf),
+    /// The file can't be read in sandbox mode, as it is outside the file root.
+    SandboxRead(PathBuf),
+    /// The file can't be written in sandbox mode.
+    SandboxWrite(PathBuf),
+    /// The URL uses a scheme other than HTTP or HTTPS in sandbox mode.
+    SandboxScheme(String),
+}
+
 /// Return true if `path` is a descendant path of `ancestor`, false otherwise.
 fn is_descendant(path: &Path, ancestor: &Path) -> bool {
     let path = normalize_path(path);
@@ -77,6 +208,24 @@ fn is_descendant(path: &Path, ancestor: &Path) -> bool {
     false
 }
 
+/// Returns true if `path` is not a descendant of `ancestor` once symbolic links are resolved, false
+/// otherwise.
+///
+/// Only the existing part of `path` can be resolved (an output file may not exist yet for
+/// instance), and if `ancestor` doesn't exist, there is no link to check.
+fn is_escaping_link(path: &Path, ancestor: &Path) -> bool {
+    let Ok(ancestor) = ancestor.canonicalize() else {
+        return false;
+    };
+    let path = normalize_path(path);
+    for existing in path.ancestors() {
+        if let Ok(canonical) = existing.canonicalize() {
+            return !canonical.starts_with(&ancestor);
+        }
+    }
+    false
+}
+
 /// Returns the absolute form of this `path` with all intermediate components normalized.
 /// Contrary to the methods [`std::fs::canonicalize`] on [`Path`], this function doesn't require
 /// the final path to exist.
@@ -220,6 +369,105 @@ mod tests {
         assert!(!ctx.is_access_allowed(Path::new("../../file/foo.bin")));
     }
 
+    #[test]
+    fn check_filename_allowed_access_with_permissive_mode() {
+        let current_dir = Path::new("/tmp");
+        let file_root = Path::new("/file");
+        let ctx =
+            ContextDir::new(current_dir, file_root).with_file_root_mode(FileRootMode::Permissive);
+        assert!(ctx.is_access_allowed(Path::new("foo.bin")));
+        assert!(ctx.is_access_allowed(Path::new("/tmp/foo.bin")));
+        assert!(ctx.is_access_allowed(Path::new("../foo.bin")));
+    }
+
+    #[test]
+    fn check_filename_access_in_sandbox() {
+        let current_dir = Path::new("/tmp");
+        let file_root = Path::new("/file");
+        let ctx = ContextDir::new(current_dir, file_root)
+            .with_file_root_mode(FileRootMode::Permissive)
+            .with_sandbox(true);
+        assert!(ctx.is_sandboxed());
+        assert!(ctx.is_access_allowed(Path::new("foo.bin")));
+        assert!(!ctx.is_access_allowed(Path::new("/tmp/foo.bin")));
+        assert!(!ctx.is_access_allowed(Path::new("../foo.bin")));
+
+        assert_eq!(
+            ctx.read_path(Path::new("../foo.bin")),
+            Err(AccessError::SandboxRead(PathBuf::from("../foo.bin")))
+        );
+        assert_eq!(
+            ctx.write_path(Path::new("foo.bin")),
+            Err(AccessError::SandboxWrite(PathBuf::from("foo.bin")))
+        );
+        let ctx = ContextDir::new(current_dir, file_root);
+        assert_eq!(
+            ctx.write_path(Path::new("/tmp/foo.bin")),
+            Err(AccessError::OutsideFileRoot(PathBuf::from("/tmp/foo.bin")))
+        );
+        assert_eq!(
+            ctx.write_path(Path::new("foo.bin")),
+            Ok(PathBuf::from("/file/foo.bin"))
+        );
+    }
+
+    #[test]
+    fn check_url_in_sandbox() {
+        let ctx = ContextDir::default();
+        assert!(ctx.check_url("file:///etc/passwd").is_ok());
+
+        let ctx = ctx.with_sandbox(true);
+        assert!(ctx.check_url("http://localhost:8000").is_ok());
+        assert!(ctx.check_url("HTTPS://localhost:8000").is_ok());
+        assert!(ctx.check_url("localhost:3128").is_ok());
+        assert_eq!(
+            ctx.check_url("socks5://localhost:1080"),
+            Err(AccessError::SandboxScheme(
+                "socks5://localhost:1080".to_string()
+            ))
+        );
+        assert!(ctx.check_url("file:///etc/passwd").is_err());
+    }
+
+    #[test]
+    fn check_filename_resolved_from_base_dir() {
+        // ```
+        // $ cd /tmp
+        // $ hurl --file-root tests --file-base file tests/api/test.hurl
+        // ```
+        let current_dir = Path::new("/tmp");
+        let file_root = Path::new("tests");
+        let ctx = ContextDir::new(current_dir, file_root).with_base_dir(Path::new("tests/api"));
+        assert_eq!(
+            ctx.resolved_path(Path::new("foo.bin")),
+            PathBuf::from("tests/api/foo.bin")
+        );
+        assert!(ctx.is_access_allowed(Path::new("foo.bin")));
+        assert!(ctx.is_access_allowed(Path::new("../data/foo.bin")));
+
+        assert!(!ctx.is_access_allowed(Path::new("../../foo.bin")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn check_filename_denied_access_through_link() {
+        let dir = std::env::temp_dir().join("hurl_test_link_access");
+        let root = dir.join("root");
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(dir.join("secret.txt"), "secret").unwrap();
+        let link = root.join("link");
+        if !link.exists() {
+            std::os::unix::fs::symlink(&dir, &link).unwrap();
+        }
+
+        let ctx = ContextDir::new(&dir, &root);
+        assert!(ctx.is_access_allowed(Path::new("foo.bin")));
+        assert!(!ctx.is_access_allowed(Path::new("link/secret.txt")));
+
+        let ctx = ctx.with_file_root_mode(FileRootMode::Permissive);
+        assert!(ctx.is_access_allowed(Path::new("link/secret.txt")));
+    }
+
     #[test]
     fn is_descendant_true() {
         let child = Path::new("/tmp/foo/bar.txt");
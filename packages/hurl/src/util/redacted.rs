@@ -16,6 +16,16 @@
  *
  */
 
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+
+/// Characters that are percent-encoded by libcurl in URL query and form params: everything except
+/// the unreserved characters of RFC 3986.
+const UNRESERVED_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
 pub trait Redact {
     /// Redacts this given a list of secrets.
     fn redact(&self, secrets: &[impl AsRef<str>]) -> String;
@@ -27,13 +37,35 @@ where
 {
     fn redact(&self, secrets: &[impl AsRef<str>]) -> String {
         let mut value = self.to_string();
-        for s in secrets {
-            value = value.replace(s.as_ref(), "***");
+        for s in redacted_values(secrets) {
+            value = value.replace(&s, "***");
         }
         value
     }
 }
 
+/// Returns the values to redact for these `secrets`, the longest first.
+///
+/// A secret can be sent URL encoded (in a query string, a form body or an exported curl command
+/// line), so its percent-encoded forms are redacted too. Redacting the longest values first
+/// prevents a secret to be partially revealed when it contains another secret. Empty secrets
+/// are ignored.
+fn redacted_values(secrets: &[impl AsRef<str>]) -> Vec<String> {
+    let mut values = vec![];
+    for secret in secrets {
+        let secret = secret.as_ref();
+        if secret.is_empty() {
+            continue;
+        }
+        values.push(secret.to_string());
+        values.push(utf8_percent_encode(secret, UNRESERVED_ENCODE_SET).to_string());
+        values.push(utf8_percent_encode(secret, NON_ALPHANUMERIC).to_string());
+    }
+    values.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+    values.dedup();
+    values
+}
+
 #[cfg(test)]
 mod tests {
     use crate::util::redacted::Redact;
@@ -52,4 +84,25 @@ mod tests {
         assert_eq(&"bar".redact(&secrets), "***");
         assert_eq(&"Baz is not secret".redact(&secrets), "Baz is not secret");
     }
+
+    #[test]
+    fn redacted_string_hides_encoded_secret() {
+        let secrets = ["a b&c=d~"];
+        assert_eq!(
+            "https://example.org/?token=a%20b%26c%3Dd~".redact(&secrets),
+            "https://example.org/?token=***"
+        );
+        assert_eq!(
+            "curl 'https://example.org/?token=a%20b%26c%3Dd%7E'".redact(&secrets),
+            "curl 'https://example.org/?token=***'"
+        );
+        assert_eq!("token: a b&c=d~".redact(&secrets), "token: ***");
+    }
+
+    #[test]
+    fn redacted_string_hides_longest_secret_first() {
+        let secrets = ["foo", "", "foobar"];
+        assert_eq!("foobar and foo".redact(&secrets), "*** and ***");
+        assert_eq!("no secret".redact(&secrets), "no secret");
+    }
 }
@@ -28,18 +28,71 @@ use super::section::{Assert, Capture, Cookie, MultipartParam, RegexValue, Sectio
 /// Represents Hurl AST root node.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct HurlFile {
+    pub defaults: Option<Defaults>,
     pub entries: Vec<Entry>,
+    /// Hurl files included after the last entry.
+    pub includes: Vec<Include>,
     pub line_terminators: Vec<LineTerminator>,
 }
 
+/// Represents the file-level defaults, at the top of a Hurl file: headers and options applied to
+/// every entry of the file.
+///
+/// ```hurl
+/// [Defaults]
+/// Authorization: Bearer {{token}}
+/// [Options]
+/// location: true
+/// variable: host=example.org
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Defaults {
+    pub line_terminators: Vec<LineTerminator>,
+    pub space0: Whitespace,
+    pub line_terminator0: LineTerminator,
+    pub headers: Vec<KeyValue>,
+    pub sections: Vec<Section>,
+    pub source_info: SourceInfo,
+}
+
+impl Defaults {
+    /// Returns the default options of the entries.
+    pub fn options(&self) -> &[EntryOption] {
+        for section in &self.sections {
+            if let SectionValue::Options(options) = &section.value {
+                return options;
+            }
+        }
+        &[]
+    }
+}
+
 /// Represents an entry; a request AST specification to be run and an optional response AST
 /// specification to be checked.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Entry {
+    /// Hurl files included before this entry.
+    pub includes: Vec<Include>,
     pub request: Request,
     pub response: Option<Response>,
 }
 
+/// Represents the inclusion of another Hurl file, at entry position. The entries of the included
+/// file are run as if they were written in place of the include directive.
+///
+/// ```hurl
+/// include "common/login.hurl"
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Include {
+    pub line_terminators: Vec<LineTerminator>,
+    pub space0: Whitespace,
+    pub space1: Whitespace,
+    pub filename: Template,
+    pub line_terminator0: LineTerminator,
+    pub source_info: SourceInfo,
+}
+
 impl Entry {
     /// Returns the source information for this entry.
     pub fn source_info(&self) -> SourceInfo {
@@ -273,11 +326,28 @@ pub struct Filter {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[allow(clippy::large_enum_variant)]
 pub enum FilterValue {
+    AddDays {
+        space0: Whitespace,
+        days: IntegerValue,
+    },
+    AddSeconds {
+        space0: Whitespace,
+        seconds: IntegerValue,
+    },
     Base64Decode,
     Base64Encode,
     Base64UrlSafeDecode,
     Base64UrlSafeEncode,
+    BrotliDecompress,
+    BytesSlice {
+        space0: Whitespace,
+        start: IntegerValue,
+        space1: Whitespace,
+        end: IntegerValue,
+    },
+    Canonicalize,
     CharsetDecode {
         space0: Whitespace,
         encoding: Template,
@@ -298,18 +368,27 @@ pub enum FilterValue {
         space0: Whitespace,
         fmt: Template,
     },
+    FromHex,
+    Gunzip,
     HtmlEscape,
     HtmlUnescape,
+    Jq {
+        space0: Whitespace,
+        expr: Template,
+    },
     JsonPath {
         space0: Whitespace,
         expr: Template,
     },
+    JwtHeader,
+    JwtPayload,
     Last,
     Location,
     Nth {
         space0: Whitespace,
         n: IntegerValue,
     },
+    ParseJson,
     Regex {
         space0: Whitespace,
         value: RegexValue,
@@ -335,9 +414,14 @@ pub enum FilterValue {
         fmt: Template,
     },
     ToFloat,
-    ToHex,
+    ToHex {
+        case: Option<(Whitespace, HexCase)>,
+        separator: Option<(Whitespace, Template)>,
+    },
     ToInt,
+    ToJson,
     ToString,
+    ToTimestamp,
     UrlDecode,
     UrlEncode,
     UrlQueryParam {
@@ -346,20 +430,28 @@ pub enum FilterValue {
     },
     Utf8Decode,
     Utf8Encode,
+    XmlEscape,
+    XmlUnescape,
     XPath {
         space0: Whitespace,
         expr: Template,
     },
+    ZstdDecompress,
 }
 
 impl FilterValue {
     /// Returns the Hurl identifier for this filter type.
     pub fn identifier(&self) -> &'static str {
         match self {
+            FilterValue::AddDays { .. } => "addDays",
+            FilterValue::AddSeconds { .. } => "addSeconds",
             FilterValue::Base64Decode => "base64Decode",
             FilterValue::Base64Encode => "base64Encode",
             FilterValue::Base64UrlSafeDecode => "base64UrlSafeDecode",
             FilterValue::Base64UrlSafeEncode => "base64UrlSafeEncode",
+            FilterValue::BrotliDecompress => "brotliDecompress",
+            FilterValue::BytesSlice { .. } => "bytesSlice",
+            FilterValue::Canonicalize => "canonicalize",
             FilterValue::CharsetDecode { .. } => "charsetDecode",
             FilterValue::Count => "count",
             FilterValue::DaysAfterNow => "daysAfterNow",
@@ -368,27 +460,55 @@ impl FilterValue {
             FilterValue::First => "first",
             FilterValue::Format { .. } => "format",
             FilterValue::DateFormat { .. } => "dateFormat",
+            FilterValue::FromHex => "fromHex",
+            FilterValue::Gunzip => "gunzip",
             FilterValue::HtmlEscape => "htmlEscape",
             FilterValue::HtmlUnescape => "htmlUnescape",
+            FilterValue::Jq { .. } => "jq",
             FilterValue::JsonPath { .. } => "jsonpath",
+            FilterValue::JwtHeader => "jwtHeader",
+            FilterValue::JwtPayload => "jwtPayload",
             FilterValue::Last => "last",
             FilterValue::Location => "location",
             FilterValue::Nth { .. } => "nth",
+            FilterValue::ParseJson => "parseJson",
             FilterValue::Regex { .. } => "regex",
             FilterValue::Replace { .. } => "replace",
             FilterValue::ReplaceRegex { .. } => "replaceRegex",
             FilterValue::Split { .. } => "split",
             FilterValue::ToDate { .. } => "toDate",
             FilterValue::ToFloat => "toFloat",
-            FilterValue::ToHex => "toHex",
+            FilterValue::ToHex { .. } => "toHex",
             FilterValue::ToInt => "toInt",
+            FilterValue::ToJson => "toJson",
             FilterValue::ToString => "toString",
+            FilterValue::ToTimestamp => "toTimestamp",
             FilterValue::UrlDecode => "urlDecode",
             FilterValue::UrlEncode => "urlEncode",
             FilterValue::UrlQueryParam { .. } => "urlQueryParam",
             FilterValue::Utf8Decode => "utf8Decode",
             FilterValue::Utf8Encode => "utf8Encode",
+            FilterValue::XmlEscape => "xmlEscape",
+            FilterValue::XmlUnescape => "xmlUnescape",
             FilterValue::XPath { .. } => "xpath",
+            FilterValue::ZstdDecompress => "zstdDecompress",
+        }
+    }
+}
+
+/// Case of the hexadecimal digits produced by a `toHex` filter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HexCase {
+    Lower,
+    Upper,
+}
+
+impl HexCase {
+    /// Returns the Hurl string identifier of this case.
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            HexCase::Lower => "lower",
+            HexCase::Upper => "upper",
         }
     }
 }
@@ -35,44 +35,70 @@ pub struct EntryOption {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum OptionKind {
+    AuthType(AuthTypeOption),
     AwsSigV4(Template),
     CaCertificate(Template),
+    Chunked(BooleanOption),
     ClientCert(Template),
+    ClientCertPassword(Template),
+    ClientCertType(ClientCertTypeOption),
     ClientKey(Template),
+    ClockSkew(DurationOption),
     Compressed(BooleanOption),
     ConnectTo(Template),
     ConnectTimeout(DurationOption),
+    CookieFile(Template),
     Delay(DurationOption),
     Digest(BooleanOption),
+    DnsServers(Template),
+    ExpectContinue(BooleanOption),
+    ExportCapture(String),
     FailWithBody(BooleanOption),
     FollowLocation(BooleanOption),
     FollowLocationTrusted(BooleanOption),
+    Foreach(Placeholder),
     Header(Template),
+    HmacSignature(Template),
     Http10(BooleanOption),
     Http11(BooleanOption),
     Http2(BooleanOption),
     Http3(BooleanOption),
+    Http3Fallback(BooleanOption),
     Insecure(BooleanOption),
     IpV4(BooleanOption),
     IpV6(BooleanOption),
     LimitRate(NaturalOption),
     MaxRedirect(CountOption),
     MaxTime(DurationOption),
+    MultipartBoundary(Template),
     Negotiate(BooleanOption),
     NetRc(BooleanOption),
     NetRcFile(Template),
     NetRcOptional(BooleanOption),
     NoHeader(Template),
     Ntlm(BooleanOption),
+    OAuth2ClientId(Template),
+    OAuth2ClientSecret(Template),
+    OAuth2TokenUrl(Template),
+    OnlyIf(BooleanOption),
     Output(Template),
+    Paginate(String),
+    PaginateMax(CountOption),
     PathAsIs(BooleanOption),
     PinnedPublicKey(Template),
     Proxy(Template),
+    ProxyHeader(Template),
+    ProxyUser(Template),
     Repeat(CountOption),
     Resolve(Template),
     Retry(CountOption),
     RetryInterval(DurationOption),
+    RetryOnCaptureFailure(BooleanOption),
     Skip(BooleanOption),
+    SkipIf(BooleanOption),
+    SoftAsserts(BooleanOption),
+    NestedFormParams(BooleanOption),
+    Trailer(Template),
     UnixSocket(Template),
     User(Template),
     Variable(VariableDefinition),
@@ -85,44 +111,70 @@ impl OptionKind {
     /// Returns the Hurl string identifier of this option.
     pub fn identifier(&self) -> &'static str {
         match self {
+            OptionKind::AuthType(_) => "auth-type",
             OptionKind::AwsSigV4(_) => "aws-sigv4",
             OptionKind::CaCertificate(_) => "cacert",
+            OptionKind::Chunked(_) => "chunked",
             OptionKind::ClientCert(_) => "cert",
+            OptionKind::ClientCertPassword(_) => "client-cert-password",
+            OptionKind::ClientCertType(_) => "client-cert-type",
             OptionKind::ClientKey(_) => "key",
+            OptionKind::ClockSkew(_) => "clock-skew",
             OptionKind::Compressed(_) => "compressed",
             OptionKind::ConnectTo(_) => "connect-to",
             OptionKind::ConnectTimeout(_) => "connect-timeout",
+            OptionKind::CookieFile(_) => "cookie-file",
             OptionKind::Delay(_) => "delay",
             OptionKind::Digest(_) => "digest",
+            OptionKind::DnsServers(_) => "dns-servers",
+            OptionKind::ExpectContinue(_) => "expect-continue",
+            OptionKind::ExportCapture(_) => "export-capture",
             OptionKind::FailWithBody(_) => "fail-with-body",
             OptionKind::FollowLocation(_) => "location",
             OptionKind::FollowLocationTrusted(_) => "location-trusted",
+            OptionKind::Foreach(_) => "foreach",
             OptionKind::Header(_) => "header",
+            OptionKind::HmacSignature(_) => "hmac-signature",
             OptionKind::Http10(_) => "http1.0",
             OptionKind::Http11(_) => "http1.1",
             OptionKind::Http2(_) => "http2",
             OptionKind::Http3(_) => "http3",
+            OptionKind::Http3Fallback(_) => "http3-fallback",
             OptionKind::Insecure(_) => "insecure",
             OptionKind::IpV4(_) => "ipv4",
             OptionKind::IpV6(_) => "ipv6",
             OptionKind::LimitRate(_) => "limit-rate",
             OptionKind::MaxRedirect(_) => "max-redirs",
             OptionKind::MaxTime(_) => "max-time",
+            OptionKind::MultipartBoundary(_) => "multipart-boundary",
             OptionKind::Negotiate(_) => "negotiate",
             OptionKind::NetRc(_) => "netrc",
             OptionKind::NetRcFile(_) => "netrc-file",
             OptionKind::NetRcOptional(_) => "netrc-optional",
             OptionKind::NoHeader(_) => "no-header",
             OptionKind::Ntlm(_) => "ntlm",
+            OptionKind::OAuth2ClientId(_) => "oauth2-client-id",
+            OptionKind::OAuth2ClientSecret(_) => "oauth2-client-secret",
+            OptionKind::OAuth2TokenUrl(_) => "oauth2-token-url",
+            OptionKind::OnlyIf(_) => "only-if",
             OptionKind::Output(_) => "output",
+            OptionKind::Paginate(_) => "paginate",
+            OptionKind::PaginateMax(_) => "paginate-max",
             OptionKind::PathAsIs(_) => "path-as-is",
             OptionKind::PinnedPublicKey(_) => "pinnedpubkey",
             OptionKind::Proxy(_) => "proxy",
+            OptionKind::ProxyHeader(_) => "proxy-header",
+            OptionKind::ProxyUser(_) => "proxy-user",
             OptionKind::Repeat(_) => "repeat",
             OptionKind::Resolve(_) => "resolve",
             OptionKind::Retry(_) => "retry",
             OptionKind::RetryInterval(_) => "retry-interval",
+            OptionKind::RetryOnCaptureFailure(_) => "retry-on-capture-failure",
             OptionKind::Skip(_) => "skip",
+            OptionKind::SkipIf(_) => "skip-if",
+            OptionKind::SoftAsserts(_) => "soft-asserts",
+            OptionKind::NestedFormParams(_) => "nested-form-params",
+            OptionKind::Trailer(_) => "trailer",
             OptionKind::UnixSocket(_) => "unix-socket",
             OptionKind::User(_) => "user",
             OptionKind::Variable(_) => "variable",
@@ -136,44 +188,70 @@ impl OptionKind {
 impl fmt::Display for OptionKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let value = match self {
+            OptionKind::AuthType(value) => value.to_string(),
             OptionKind::AwsSigV4(value) => value.to_string(),
             OptionKind::CaCertificate(filename) => filename.to_string(),
+            OptionKind::Chunked(value) => value.to_string(),
             OptionKind::ClientCert(filename) => filename.to_string(),
+            OptionKind::ClientCertPassword(value) => value.to_string(),
+            OptionKind::ClientCertType(value) => value.to_string(),
             OptionKind::ClientKey(filename) => filename.to_string(),
+            OptionKind::ClockSkew(value) => value.to_string(),
             OptionKind::Compressed(value) => value.to_string(),
             OptionKind::ConnectTo(value) => value.to_string(),
             OptionKind::ConnectTimeout(value) => value.to_string(),
+            OptionKind::CookieFile(filename) => filename.to_string(),
             OptionKind::Delay(value) => value.to_string(),
             OptionKind::Digest(value) => value.to_string(),
+            OptionKind::DnsServers(value) => value.to_string(),
+            OptionKind::ExpectContinue(value) => value.to_string(),
+            OptionKind::ExportCapture(value) => value.to_string(),
             OptionKind::FailWithBody(value) => value.to_string(),
             OptionKind::FollowLocation(value) => value.to_string(),
             OptionKind::FollowLocationTrusted(value) => value.to_string(),
+            OptionKind::Foreach(value) => value.to_string(),
             OptionKind::Header(value) => value.to_string(),
+            OptionKind::HmacSignature(value) => value.to_string(),
             OptionKind::Http10(value) => value.to_string(),
             OptionKind::Http11(value) => value.to_string(),
             OptionKind::Http2(value) => value.to_string(),
             OptionKind::Http3(value) => value.to_string(),
+            OptionKind::Http3Fallback(value) => value.to_string(),
             OptionKind::Insecure(value) => value.to_string(),
             OptionKind::IpV4(value) => value.to_string(),
             OptionKind::IpV6(value) => value.to_string(),
             OptionKind::LimitRate(value) => value.to_string(),
             OptionKind::MaxRedirect(value) => value.to_string(),
             OptionKind::MaxTime(value) => value.to_string(),
+            OptionKind::MultipartBoundary(value) => value.to_string(),
             OptionKind::Negotiate(value) => value.to_string(),
             OptionKind::NetRc(value) => value.to_string(),
             OptionKind::NetRcFile(filename) => filename.to_string(),
             OptionKind::NetRcOptional(value) => value.to_string(),
             OptionKind::NoHeader(value) => value.to_string(),
             OptionKind::Ntlm(value) => value.to_string(),
+            OptionKind::OAuth2ClientId(value) => value.to_string(),
+            OptionKind::OAuth2ClientSecret(value) => value.to_string(),
+            OptionKind::OAuth2TokenUrl(value) => value.to_string(),
+            OptionKind::OnlyIf(value) => value.to_string(),
             OptionKind::Output(filename) => filename.to_string(),
+            OptionKind::Paginate(value) => value.to_string(),
+            OptionKind::PaginateMax(value) => value.to_string(),
             OptionKind::PathAsIs(value) => value.to_string(),
             OptionKind::PinnedPublicKey(value) => value.to_string(),
             OptionKind::Proxy(value) => value.to_string(),
+            OptionKind::ProxyHeader(value) => value.to_string(),
+            OptionKind::ProxyUser(value) => value.to_string(),
             OptionKind::Repeat(value) => value.to_string(),
             OptionKind::Resolve(value) => value.to_string(),
             OptionKind::Retry(value) => value.to_string(),
             OptionKind::RetryInterval(value) => value.to_string(),
+            OptionKind::RetryOnCaptureFailure(value) => value.to_string(),
             OptionKind::Skip(value) => value.to_string(),
+            OptionKind::SkipIf(value) => value.to_string(),
+            OptionKind::SoftAsserts(value) => value.to_string(),
+            OptionKind::NestedFormParams(value) => value.to_string(),
+            OptionKind::Trailer(value) => value.to_string(),
             OptionKind::UnixSocket(value) => value.to_string(),
             OptionKind::User(value) => value.to_string(),
             OptionKind::Variable(value) => value.to_string(),
@@ -337,3 +415,51 @@ impl VerbosityOption {
         }
     }
 }
+
+/// Format of a client certificate file, used by the `cert` option.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ClientCertTypeOption {
+    Pem,
+    Der,
+    P12,
+}
+
+impl fmt::Display for ClientCertTypeOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.identifier())
+    }
+}
+
+impl ClientCertTypeOption {
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            ClientCertTypeOption::Pem => "PEM",
+            ClientCertTypeOption::Der => "DER",
+            ClientCertTypeOption::P12 => "P12",
+        }
+    }
+}
+
+/// HTTP authentication scheme, used with the credentials of the `user` option.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AuthTypeOption {
+    Digest,
+    Ntlm,
+    Negotiate,
+}
+
+impl fmt::Display for AuthTypeOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.identifier())
+    }
+}
+
+impl AuthTypeOption {
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            AuthTypeOption::Digest => "digest",
+            AuthTypeOption::Ntlm => "ntlm",
+            AuthTypeOption::Negotiate => "negotiate",
+        }
+    }
+}
@@ -21,6 +21,7 @@ use std::fmt::Formatter;
 use crate::reader::Pos;
 use crate::types::{SourceString, ToSource};
 
+use super::core::Filter;
 use super::json::JsonValue;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -511,7 +512,7 @@ impl fmt::Display for Expr {
 
 impl ToSource for Expr {
     fn to_source(&self) -> SourceString {
-        self.kind.to_string().to_source()
+        self.kind.to_source()
     }
 }
 
@@ -519,6 +520,20 @@ impl ToSource for Expr {
 pub enum ExprKind {
     Variable(Variable),
     Function(Function),
+    /// A call to a function with arguments, like `randomInt(1, 100)`.
+    Call(FunctionCall),
+    Bool(bool),
+    Null,
+    Number(Number),
+    String(Box<Template>),
+    /// An expression followed by filters, like `user jsonpath "$.name"`.
+    Filtered(Box<FilteredExpr>),
+    /// An expression between parentheses, like `(count + 1)`.
+    Parenthesized(Box<ParenthesizedExpr>),
+    /// A binary operation, like `count + 1` or `status == "ok"`.
+    Binary(Box<BinaryExpr>),
+    /// A conditional expression, like `if count > 1 then "items" else "item"`.
+    If(Box<IfExpr>),
 }
 
 impl fmt::Display for ExprKind {
@@ -526,10 +541,165 @@ impl fmt::Display for ExprKind {
         match self {
             ExprKind::Variable(variable) => write!(f, "{variable}"),
             ExprKind::Function(function) => write!(f, "{function}"),
+            _ => write!(f, "{}", self.to_source()),
         }
     }
 }
 
+impl ToSource for ExprKind {
+    fn to_source(&self) -> SourceString {
+        let mut source = SourceString::new();
+        match self {
+            ExprKind::Variable(variable) => source.push_str(&variable.to_string()),
+            ExprKind::Function(function) => source.push_str(&function.to_string()),
+            ExprKind::Call(call) => source.push_str(call.to_source().as_str()),
+            ExprKind::Bool(value) => source.push_str(&value.to_string()),
+            ExprKind::Null => source.push_str("null"),
+            ExprKind::Number(number) => source.push_str(number.to_source().as_str()),
+            ExprKind::String(template) => source.push_str(template.to_source().as_str()),
+            ExprKind::Filtered(filtered) => source.push_str(filtered.to_source().as_str()),
+            ExprKind::Parenthesized(parenthesized) => {
+                source.push_str(parenthesized.to_source().as_str());
+            }
+            ExprKind::Binary(binary) => source.push_str(binary.to_source().as_str()),
+            ExprKind::If(if_expr) => source.push_str(if_expr.to_source().as_str()),
+        }
+        source
+    }
+}
+
+/// An expression followed by filters, like `user jsonpath "$.name"`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FilteredExpr {
+    pub expr: Expr,
+    pub filters: Vec<(Whitespace, Filter)>,
+    /// Source of the filters chain, including its leading spaces.
+    pub source: SourceString,
+}
+
+impl ToSource for FilteredExpr {
+    fn to_source(&self) -> SourceString {
+        let mut source = SourceString::new();
+        source.push_str(self.expr.to_source().as_str());
+        source.push_str(self.source.as_str());
+        source
+    }
+}
+
+/// An expression between parentheses, like `(count + 1)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParenthesizedExpr {
+    pub space0: Whitespace,
+    pub expr: Expr,
+    pub space1: Whitespace,
+}
+
+impl ToSource for ParenthesizedExpr {
+    fn to_source(&self) -> SourceString {
+        let mut source = SourceString::new();
+        source.push('(');
+        source.push_str(self.space0.as_str());
+        source.push_str(self.expr.to_source().as_str());
+        source.push_str(self.space1.as_str());
+        source.push(')');
+        source
+    }
+}
+
+/// A binary operation, like `count + 1` or `status == "ok"`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BinaryExpr {
+    pub left: Expr,
+    pub space0: Whitespace,
+    pub operator: BinaryOperator,
+    pub space1: Whitespace,
+    pub right: Expr,
+}
+
+impl ToSource for BinaryExpr {
+    fn to_source(&self) -> SourceString {
+        let mut source = SourceString::new();
+        source.push_str(self.left.to_source().as_str());
+        source.push_str(self.space0.as_str());
+        source.push_str(self.operator.symbol());
+        source.push_str(self.space1.as_str());
+        source.push_str(self.right.to_source().as_str());
+        source
+    }
+}
+
+/// A conditional expression, like `if count > 1 then "items" else "item"`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IfExpr {
+    pub space0: Whitespace,
+    pub condition: Expr,
+    pub space1: Whitespace,
+    pub space2: Whitespace,
+    pub then_expr: Expr,
+    pub space3: Whitespace,
+    pub space4: Whitespace,
+    pub else_expr: Expr,
+}
+
+impl ToSource for IfExpr {
+    fn to_source(&self) -> SourceString {
+        let mut source = SourceString::new();
+        source.push_str("if");
+        source.push_str(self.space0.as_str());
+        source.push_str(self.condition.to_source().as_str());
+        source.push_str(self.space1.as_str());
+        source.push_str("then");
+        source.push_str(self.space2.as_str());
+        source.push_str(self.then_expr.to_source().as_str());
+        source.push_str(self.space3.as_str());
+        source.push_str("else");
+        source.push_str(self.space4.as_str());
+        source.push_str(self.else_expr.to_source().as_str());
+        source
+    }
+}
+
+/// Operators of binary expressions, like `count + 1`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinaryOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo,
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl BinaryOperator {
+    /// Returns the symbol of this operator, as written in a Hurl file.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            BinaryOperator::Add => "+",
+            BinaryOperator::Subtract => "-",
+            BinaryOperator::Multiply => "*",
+            BinaryOperator::Divide => "/",
+            BinaryOperator::Modulo => "%",
+            BinaryOperator::Equal => "==",
+            BinaryOperator::NotEqual => "!=",
+            BinaryOperator::Less => "<",
+            BinaryOperator::LessOrEqual => "<=",
+            BinaryOperator::Greater => ">",
+            BinaryOperator::GreaterOrEqual => ">=",
+        }
+    }
+}
+
+impl fmt::Display for BinaryOperator {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Variable {
     pub name: String,
@@ -557,6 +727,78 @@ impl fmt::Display for Function {
     }
 }
 
+/// A call to a function with arguments, like `randomInt(1, 100)` or `uuidv7()`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionCall {
+    pub name: FunctionName,
+    pub arguments: Vec<FunctionArgument>,
+}
+
+impl ToSource for FunctionCall {
+    fn to_source(&self) -> SourceString {
+        let mut source = SourceString::new();
+        source.push_str(&self.name.to_string());
+        source.push('(');
+        for (i, argument) in self.arguments.iter().enumerate() {
+            if i > 0 {
+                source.push(',');
+            }
+            source.push_str(argument.space0.as_str());
+            source.push_str(argument.expr.to_source().as_str());
+            source.push_str(argument.space1.as_str());
+        }
+        source.push(')');
+        source
+    }
+}
+
+/// An argument of a function call, with its surrounding spaces.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionArgument {
+    pub space0: Whitespace,
+    pub expr: Expr,
+    pub space1: Whitespace,
+}
+
+/// The name of a called function.
+///
+/// Builtin functions are known by Hurl, other functions are resolved at runtime (for instance,
+/// functions registered by a program using Hurl as a library).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FunctionName {
+    /// `now()` returns the current date, `now(format)` the current date formatted as a string.
+    Now,
+    /// `randomInt(min, max)` returns a random integer between `min` and `max` inclusive.
+    RandomInt,
+    /// `uuidv7()` returns a new time-ordered UUID v7 string.
+    Uuidv7,
+    /// A function that is not a builtin.
+    Custom(String),
+}
+
+impl FunctionName {
+    /// Returns the function named `name`, builtin or not.
+    pub fn new(name: &str) -> Self {
+        match name {
+            "now" => FunctionName::Now,
+            "randomInt" => FunctionName::RandomInt,
+            "uuidv7" => FunctionName::Uuidv7,
+            _ => FunctionName::Custom(name.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for FunctionName {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            FunctionName::Now => write!(f, "now"),
+            FunctionName::RandomInt => write!(f, "randomInt"),
+            FunctionName::Uuidv7 => write!(f, "uuidv7"),
+            FunctionName::Custom(name) => write!(f, "{name}"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
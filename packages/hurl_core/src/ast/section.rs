@@ -23,7 +23,7 @@ use super::core::Filter;
 use super::option::EntryOption;
 use super::primitive::{
     Base64, File, Hex, KeyValue, LineTerminator, MultilineString, Number, Placeholder, Regex,
-    SourceInfo, Template, Whitespace,
+    SourceInfo, Template, U64, Whitespace,
 };
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -83,6 +83,7 @@ pub struct Cookie {
 pub enum MultipartParam {
     Param(KeyValue),
     FilenameParam(FilenameParam),
+    NestedParam(NestedParam),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -105,6 +106,38 @@ pub struct FilenameValue {
     pub content_type: Option<Template>,
 }
 
+/// A nested multipart part, made of the parts indented below it:
+///
+/// ```hurl
+/// [Multipart]
+/// attachments: multipart,mixed; Content-ID: <attachments>
+///   file1: file,a.txt;
+///   file2: file,b.txt;
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NestedParam {
+    pub line_terminators: Vec<LineTerminator>,
+    pub space0: Whitespace,
+    pub key: Template,
+    pub space1: Whitespace,
+    pub space2: Whitespace,
+    pub value: NestedValue,
+    pub line_terminator0: LineTerminator,
+    /// Parts of this nested part, indented below it.
+    pub params: Vec<MultipartParam>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NestedValue {
+    pub space0: Whitespace,
+    /// Multipart subtype (like `mixed` or `related`).
+    pub subtype: Template,
+    pub space1: Whitespace,
+    pub space2: Whitespace,
+    /// Custom headers of the part, separated by `;`.
+    pub headers: Option<Template>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Capture {
     pub line_terminators: Vec<LineTerminator>,
@@ -114,11 +147,54 @@ pub struct Capture {
     pub space2: Whitespace,
     pub query: Query,
     pub filters: Vec<(Whitespace, Filter)>,
+    pub type_annotation: Option<(Whitespace, CaptureTypeAnnotation)>,
+    pub fallback: Option<(Whitespace, CaptureFallback)>,
     pub space3: Whitespace,
     pub redacted: bool,
     pub line_terminator0: LineTerminator,
 }
 
+/// Type annotation of a capture (`as integer` for instance), the captured value is coerced to this type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CaptureTypeAnnotation {
+    pub space0: Whitespace,
+    pub value: CaptureType,
+    pub source_info: SourceInfo,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CaptureType {
+    Boolean,
+    Float,
+    Integer,
+    String,
+}
+
+impl CaptureType {
+    /// Returns the Hurl identifier for this capture type.
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            CaptureType::Boolean => "boolean",
+            CaptureType::Float => "float",
+            CaptureType::Integer => "integer",
+            CaptureType::String => "string",
+        }
+    }
+}
+
+/// Behaviour of a capture when its query (or one of its filters) returns no value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[allow(clippy::large_enum_variant)]
+pub enum CaptureFallback {
+    /// The capture is skipped and the variable is left unset.
+    Optional,
+    /// The variable is set to `value`.
+    Default {
+        space0: Whitespace,
+        value: PredicateValue,
+    },
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Assert {
     pub line_terminators: Vec<LineTerminator>,
@@ -141,6 +217,7 @@ pub struct Query {
 pub enum QueryValue {
     Status,
     Version,
+    VersionNegotiated,
     Url,
     Header {
         space0: Whitespace,
@@ -150,7 +227,10 @@ pub enum QueryValue {
         space0: Whitespace,
         expr: CookiePath,
     },
-    Body,
+    Body {
+        /// Charset used to decode the body, overriding the charset of the response.
+        charset: Option<(Whitespace, Template)>,
+    },
     Xpath {
         space0: Whitespace,
         expr: Template,
@@ -159,6 +239,10 @@ pub enum QueryValue {
         space0: Whitespace,
         expr: Template,
     },
+    Csv {
+        space0: Whitespace,
+        expr: Template,
+    },
     Regex {
         space0: Whitespace,
         value: RegexValue,
@@ -168,16 +252,32 @@ pub enum QueryValue {
         name: Template,
     },
     Duration,
+    DurationMean,
+    DurationPercentile {
+        space0: Whitespace,
+        percentile: U64,
+    },
     Bytes,
     RawBytes,
+    Sha1,
     Sha256,
+    Sha512,
     Md5,
+    Crc32,
     Certificate {
         space0: Whitespace,
         attribute_name: CertificateAttributeName,
     },
     Ip,
+    ProxyStatus,
+    HstsUpgraded,
+    ContinueReceived,
+    EarlyHints,
     Redirects,
+    Timings {
+        space0: Whitespace,
+        attribute_name: TimingsAttributeName,
+    },
 }
 
 impl QueryValue {
@@ -186,22 +286,34 @@ impl QueryValue {
         match self {
             QueryValue::Status => "status",
             QueryValue::Version => "version",
+            QueryValue::VersionNegotiated => "versionNegotiated",
             QueryValue::Url => "url",
             QueryValue::Header { .. } => "header",
             QueryValue::Cookie { .. } => "cookie",
-            QueryValue::Body => "body",
+            QueryValue::Body { .. } => "body",
             QueryValue::Xpath { .. } => "xpath",
             QueryValue::Jsonpath { .. } => "jsonpath",
+            QueryValue::Csv { .. } => "csv",
             QueryValue::Regex { .. } => "regex",
             QueryValue::Variable { .. } => "variable",
             QueryValue::Duration => "duration",
+            QueryValue::DurationMean => "durationMean",
+            QueryValue::DurationPercentile { .. } => "durationPercentile",
             QueryValue::Bytes => "bytes",
             QueryValue::RawBytes => "rawbytes",
+            QueryValue::Sha1 => "sha1",
             QueryValue::Sha256 => "sha256",
+            QueryValue::Sha512 => "sha512",
             QueryValue::Md5 => "md5",
+            QueryValue::Crc32 => "crc32",
             QueryValue::Certificate { .. } => "certificate",
             QueryValue::Ip => "ip",
+            QueryValue::ProxyStatus => "proxyStatus",
+            QueryValue::HstsUpgraded => "hstsUpgraded",
+            QueryValue::ContinueReceived => "continueReceived",
+            QueryValue::EarlyHints => "earlyHints",
             QueryValue::Redirects => "redirects",
+            QueryValue::Timings { .. } => "timings",
         }
     }
 }
@@ -328,6 +440,43 @@ impl ToSource for CertificateAttributeName {
     }
 }
 
+/// Phase of an HTTP transfer, queried with `timings` query.
+///
+/// Names follow libcurl timings (see <https://curl.se/libcurl/c/curl_easy_getinfo.html#TIMES>).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TimingsAttributeName {
+    NameLookup,
+    Connect,
+    AppConnect,
+    PreTransfer,
+    StartTransfer,
+    Total,
+}
+
+impl TimingsAttributeName {
+    /// Returns the Hurl string identifier of this timings attribute name.
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            TimingsAttributeName::NameLookup => "namelookup",
+            TimingsAttributeName::Connect => "connect",
+            TimingsAttributeName::AppConnect => "appconnect",
+            TimingsAttributeName::PreTransfer => "pretransfer",
+            TimingsAttributeName::StartTransfer => "starttransfer",
+            TimingsAttributeName::Total => "total",
+        }
+    }
+}
+
+impl ToSource for TimingsAttributeName {
+    fn to_source(&self) -> SourceString {
+        let mut s = SourceString::new();
+        s.push('"');
+        s.push_str(self.identifier());
+        s.push('"');
+        s
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Predicate {
     pub not: bool,
@@ -362,17 +511,30 @@ pub enum PredicateValue {
     String(Template),
 }
 
+/// JSONPath expressions of the nodes excluded from an equality predicate, for instance
+/// `ignoring "$.id" "$.createdAt"` in `body == file,user.json; ignoring "$.id" "$.createdAt"`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ignoring {
+    pub space0: Whitespace,
+    pub paths: Vec<(Whitespace, Template)>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[allow(clippy::large_enum_variant)]
 pub enum PredicateFuncValue {
     Equal {
         space0: Whitespace,
         value: PredicateValue,
+        ignoring: Option<Ignoring>,
     },
     NotEqual {
         space0: Whitespace,
         value: PredicateValue,
     },
+    ConstantTimeEqual {
+        space0: Whitespace,
+        value: PredicateValue,
+    },
     GreaterThan {
         space0: Whitespace,
         value: PredicateValue,
@@ -405,6 +567,10 @@ pub enum PredicateFuncValue {
         space0: Whitespace,
         value: PredicateValue,
     },
+    IncludeJson {
+        space0: Whitespace,
+        value: PredicateValue,
+    },
     Match {
         space0: Whitespace,
         value: PredicateValue,
@@ -432,6 +598,7 @@ impl PredicateFuncValue {
         match self {
             PredicateFuncValue::Equal { .. } => "==",
             PredicateFuncValue::NotEqual { .. } => "!=",
+            PredicateFuncValue::ConstantTimeEqual { .. } => "constantTimeEquals",
             PredicateFuncValue::GreaterThan { .. } => ">",
             PredicateFuncValue::GreaterThanOrEqual { .. } => ">=",
             PredicateFuncValue::LessThan { .. } => "<",
@@ -440,6 +607,7 @@ impl PredicateFuncValue {
             PredicateFuncValue::EndWith { .. } => "endsWith",
             PredicateFuncValue::Contain { .. } => "contains",
             PredicateFuncValue::Include { .. } => "includes",
+            PredicateFuncValue::IncludeJson { .. } => "includesJson",
             PredicateFuncValue::Match { .. } => "matches",
             PredicateFuncValue::Exist => "exists",
             PredicateFuncValue::IsBoolean => "isBoolean",
@@ -21,13 +21,14 @@
 //!
 //! Code heavily inspired from <https://github.com/rust-lang/rust/blob/master/compiler/rustc_ast/src/visit.rs>
 use crate::ast::{
-    Assert, Base64, Body, BooleanOption, Bytes, Capture, Comment, Cookie, CookiePath, CountOption,
-    Duration, DurationOption, Entry, EntryOption, File, FilenameParam, FilenameValue, Filter,
-    FilterValue, Hex, HurlFile, IntegerValue, JsonValue, KeyValue, LineTerminator, Method,
-    MultilineString, MultipartParam, NaturalOption, Number, OptionKind, Placeholder, Predicate,
-    PredicateFuncValue, PredicateValue, Query, QueryValue, Regex, RegexValue, Request, Response,
-    Section, SectionValue, StatusValue, Template, U64, VariableDefinition, VariableValue,
-    VerbosityOption, VersionValue, Whitespace,
+    Assert, AuthTypeOption, Base64, Body, BooleanOption, Bytes, Capture, CaptureFallback,
+    ClientCertTypeOption, Comment, Cookie, CookiePath, CountOption, Defaults, Duration,
+    DurationOption, Entry, EntryOption, File, FilenameParam, FilenameValue, Filter, FilterValue,
+    Hex, HurlFile, Include, IntegerValue, JsonValue, KeyValue, LineTerminator, Method,
+    MultilineString, MultipartParam, NaturalOption, NestedParam, NestedValue, Number, OptionKind,
+    Placeholder, Predicate, PredicateFuncValue, PredicateValue, Query, QueryValue, Regex,
+    RegexValue, Request, Response, Section, SectionValue, StatusValue, Template, U64,
+    VariableDefinition, VariableValue, VerbosityOption, VersionValue, Whitespace,
 };
 use crate::types::{Count, DurationUnit, SourceString, ToSource};
 
@@ -76,6 +77,10 @@ pub trait Visitor: Sized {
         walk_count_option(self, option);
     }
 
+    fn visit_defaults(&mut self, defaults: &Defaults) {
+        walk_defaults(self, defaults);
+    }
+
     fn visit_duration(&mut self, duration: &Duration) {
         walk_duration(self, duration);
     }
@@ -108,6 +113,14 @@ pub trait Visitor: Sized {
 
     fn visit_filename(&mut self, filename: &Template) {}
 
+    fn visit_nested_param(&mut self, param: &NestedParam) {
+        walk_nested_param(self, param);
+    }
+
+    fn visit_nested_value(&mut self, value: &NestedValue) {
+        walk_nested_value(self, value);
+    }
+
     fn visit_filter(&mut self, filter: &Filter) {
         walk_filter(self, filter);
     }
@@ -134,6 +147,10 @@ pub trait Visitor: Sized {
 
     fn visit_i64(&mut self, n: i64) {}
 
+    fn visit_include(&mut self, include: &Include) {
+        walk_include(self, include);
+    }
+
     fn visit_json_body(&mut self, json: &JsonValue) {}
 
     fn visit_kv(&mut self, kv: &KeyValue) {
@@ -220,6 +237,14 @@ pub trait Visitor: Sized {
         walk_variable_value(self, value);
     }
 
+    fn visit_auth_type_option(&mut self, value: &AuthTypeOption) {
+        walk_auth_type_option(self, value);
+    }
+
+    fn visit_client_cert_type_option(&mut self, value: &ClientCertTypeOption) {
+        walk_client_cert_type_option(self, value);
+    }
+
     fn visit_verbosity_option(&mut self, value: &VerbosityOption) {
         walk_verbosity_option(self, value);
     }
@@ -292,6 +317,23 @@ pub fn walk_capture<V: Visitor>(visitor: &mut V, capture: &Capture) {
         visitor.visit_whitespace(space);
         visitor.visit_filter(filter);
     }
+    if let Some((space, annotation)) = &capture.type_annotation {
+        visitor.visit_whitespace(space);
+        visitor.visit_literal("as");
+        visitor.visit_whitespace(&annotation.space0);
+        visitor.visit_literal(annotation.value.identifier());
+    }
+    if let Some((space, fallback)) = &capture.fallback {
+        visitor.visit_whitespace(space);
+        match fallback {
+            CaptureFallback::Optional => visitor.visit_literal("optional"),
+            CaptureFallback::Default { space0, value } => {
+                visitor.visit_literal("default");
+                visitor.visit_whitespace(space0);
+                visitor.visit_predicate_value(value);
+            }
+        }
+    }
     if capture.redacted {
         visitor.visit_whitespace(&capture.space3);
         // The next node should have been literal to be more correct
@@ -329,6 +371,20 @@ pub fn walk_count_option<V: Visitor>(visitor: &mut V, option: &CountOption) {
     }
 }
 
+pub fn walk_defaults<V: Visitor>(visitor: &mut V, defaults: &Defaults) {
+    defaults.line_terminators.iter().for_each(|lt| {
+        visitor.visit_lt(lt);
+    });
+    visitor.visit_whitespace(&defaults.space0);
+    visitor.visit_section_header("[Defaults]");
+    visitor.visit_lt(&defaults.line_terminator0);
+    defaults.headers.iter().for_each(|h| visitor.visit_kv(h));
+    defaults
+        .sections
+        .iter()
+        .for_each(|s| visitor.visit_section(s));
+}
+
 pub fn walk_duration<V: Visitor>(visitor: &mut V, duration: &Duration) {
     visitor.visit_u64(&duration.value);
     if let Some(unit) = duration.unit {
@@ -344,6 +400,7 @@ pub fn walk_duration_option<V: Visitor>(visitor: &mut V, option: &DurationOption
 }
 
 pub fn walk_entry<V: Visitor>(visitor: &mut V, entry: &Entry) {
+    entry.includes.iter().for_each(|i| visitor.visit_include(i));
     visitor.visit_request(&entry.request);
     if let Some(ref response) = entry.response {
         visitor.visit_response(response);
@@ -360,44 +417,70 @@ pub fn walk_entry_option<V: Visitor>(visitor: &mut V, option: &EntryOption) {
     visitor.visit_literal(":");
     visitor.visit_whitespace(&option.space2);
     match &option.kind {
+        OptionKind::AuthType(value) => visitor.visit_auth_type_option(value),
         OptionKind::AwsSigV4(value) => visitor.visit_template(value),
         OptionKind::CaCertificate(filename) => visitor.visit_filename(filename),
+        OptionKind::Chunked(value) => visitor.visit_bool_option(value),
         OptionKind::ClientCert(filename) => visitor.visit_filename(filename),
+        OptionKind::ClientCertPassword(value) => visitor.visit_template(value),
+        OptionKind::ClientCertType(value) => visitor.visit_client_cert_type_option(value),
         OptionKind::ClientKey(filename) => visitor.visit_filename(filename),
+        OptionKind::ClockSkew(value) => visitor.visit_duration_option(value),
         OptionKind::Compressed(value) => visitor.visit_bool_option(value),
         OptionKind::ConnectTo(value) => visitor.visit_template(value),
         OptionKind::ConnectTimeout(value) => visitor.visit_duration_option(value),
+        OptionKind::CookieFile(filename) => visitor.visit_filename(filename),
         OptionKind::Delay(value) => visitor.visit_duration_option(value),
         OptionKind::Digest(value) => visitor.visit_bool_option(value),
+        OptionKind::DnsServers(value) => visitor.visit_template(value),
+        OptionKind::ExpectContinue(value) => visitor.visit_bool_option(value),
+        OptionKind::ExportCapture(value) => visitor.visit_variable_name(value),
         OptionKind::FailWithBody(value) => visitor.visit_bool_option(value),
         OptionKind::FollowLocation(value) => visitor.visit_bool_option(value),
         OptionKind::FollowLocationTrusted(value) => visitor.visit_bool_option(value),
+        OptionKind::Foreach(value) => visitor.visit_placeholder(value),
         OptionKind::Header(value) => visitor.visit_template(value),
+        OptionKind::HmacSignature(value) => visitor.visit_template(value),
         OptionKind::Http10(value) => visitor.visit_bool_option(value),
         OptionKind::Http11(value) => visitor.visit_bool_option(value),
         OptionKind::Http2(value) => visitor.visit_bool_option(value),
         OptionKind::Http3(value) => visitor.visit_bool_option(value),
+        OptionKind::Http3Fallback(value) => visitor.visit_bool_option(value),
         OptionKind::Insecure(value) => visitor.visit_bool_option(value),
         OptionKind::IpV4(value) => visitor.visit_bool_option(value),
         OptionKind::IpV6(value) => visitor.visit_bool_option(value),
         OptionKind::LimitRate(value) => visitor.visit_natural_option(value),
         OptionKind::MaxRedirect(value) => visitor.visit_count_option(value),
         OptionKind::MaxTime(value) => visitor.visit_duration_option(value),
+        OptionKind::MultipartBoundary(value) => visitor.visit_template(value),
         OptionKind::Negotiate(value) => visitor.visit_bool_option(value),
         OptionKind::NetRc(value) => visitor.visit_bool_option(value),
         OptionKind::NetRcFile(filename) => visitor.visit_filename(filename),
         OptionKind::NetRcOptional(value) => visitor.visit_bool_option(value),
         OptionKind::NoHeader(value) => visitor.visit_template(value),
         OptionKind::Ntlm(value) => visitor.visit_bool_option(value),
+        OptionKind::OAuth2ClientId(value) => visitor.visit_template(value),
+        OptionKind::OAuth2ClientSecret(value) => visitor.visit_template(value),
+        OptionKind::OAuth2TokenUrl(value) => visitor.visit_template(value),
+        OptionKind::OnlyIf(value) => visitor.visit_bool_option(value),
         OptionKind::Output(filename) => visitor.visit_filename(filename),
+        OptionKind::Paginate(value) => visitor.visit_variable_name(value),
+        OptionKind::PaginateMax(value) => visitor.visit_count_option(value),
         OptionKind::PathAsIs(value) => visitor.visit_bool_option(value),
         OptionKind::PinnedPublicKey(value) => visitor.visit_template(value),
         OptionKind::Proxy(value) => visitor.visit_template(value),
+        OptionKind::ProxyHeader(value) => visitor.visit_template(value),
+        OptionKind::ProxyUser(value) => visitor.visit_template(value),
         OptionKind::Repeat(value) => visitor.visit_count_option(value),
         OptionKind::Resolve(value) => visitor.visit_template(value),
         OptionKind::Retry(value) => visitor.visit_count_option(value),
         OptionKind::RetryInterval(value) => visitor.visit_duration_option(value),
+        OptionKind::RetryOnCaptureFailure(value) => visitor.visit_bool_option(value),
         OptionKind::Skip(value) => visitor.visit_bool_option(value),
+        OptionKind::SkipIf(value) => visitor.visit_bool_option(value),
+        OptionKind::SoftAsserts(value) => visitor.visit_bool_option(value),
+        OptionKind::NestedFormParams(value) => visitor.visit_bool_option(value),
+        OptionKind::Trailer(value) => visitor.visit_template(value),
         OptionKind::UnixSocket(value) => visitor.visit_filename(value),
         OptionKind::User(value) => visitor.visit_template(value),
         OptionKind::Variable(value) => visitor.visit_variable_def(value),
@@ -419,10 +502,31 @@ pub fn walk_file<V: Visitor>(visitor: &mut V, file: &File) {
 pub fn walk_filter<V: Visitor>(visitor: &mut V, filter: &Filter) {
     visitor.visit_filter_kind(&filter.value);
     match &filter.value {
+        FilterValue::AddDays { space0, days } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_integer_value(days);
+        }
+        FilterValue::AddSeconds { space0, seconds } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_integer_value(seconds);
+        }
         FilterValue::Base64Decode => {}
         FilterValue::Base64Encode => {}
         FilterValue::Base64UrlSafeDecode => {}
         FilterValue::Base64UrlSafeEncode => {}
+        FilterValue::BrotliDecompress => {}
+        FilterValue::BytesSlice {
+            space0,
+            start,
+            space1,
+            end,
+        } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_integer_value(start);
+            visitor.visit_whitespace(space1);
+            visitor.visit_integer_value(end);
+        }
+        FilterValue::Canonicalize => {}
         FilterValue::CharsetDecode { space0, encoding } => {
             visitor.visit_whitespace(space0);
             visitor.visit_template(encoding);
@@ -435,6 +539,7 @@ pub fn walk_filter<V: Visitor>(visitor: &mut V, filter: &Filter) {
             visitor.visit_template(encoding);
         }
         FilterValue::First => {}
+        FilterValue::FromHex => {}
         FilterValue::Format { space0, fmt } => {
             visitor.visit_whitespace(space0);
             visitor.visit_template(fmt);
@@ -443,18 +548,26 @@ pub fn walk_filter<V: Visitor>(visitor: &mut V, filter: &Filter) {
             visitor.visit_whitespace(space0);
             visitor.visit_template(fmt);
         }
+        FilterValue::Gunzip => {}
         FilterValue::HtmlEscape => {}
         FilterValue::HtmlUnescape => {}
+        FilterValue::Jq { space0, expr } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(expr);
+        }
         FilterValue::JsonPath { space0, expr } => {
             visitor.visit_whitespace(space0);
             visitor.visit_template(expr);
         }
+        FilterValue::JwtHeader => {}
+        FilterValue::JwtPayload => {}
         FilterValue::Last => {}
         FilterValue::Location => {}
         FilterValue::Nth { space0, n } => {
             visitor.visit_whitespace(space0);
             visitor.visit_integer_value(n);
         }
+        FilterValue::ParseJson => {}
         FilterValue::Regex { space0, value } => {
             visitor.visit_whitespace(space0);
             match value {
@@ -496,9 +609,20 @@ pub fn walk_filter<V: Visitor>(visitor: &mut V, filter: &Filter) {
             visitor.visit_template(fmt);
         }
         FilterValue::ToFloat => {}
-        FilterValue::ToHex => {}
+        FilterValue::ToHex { case, separator } => {
+            if let Some((space, case)) = case {
+                visitor.visit_whitespace(space);
+                visitor.visit_literal(case.identifier());
+            }
+            if let Some((space, separator)) = separator {
+                visitor.visit_whitespace(space);
+                visitor.visit_template(separator);
+            }
+        }
         FilterValue::ToInt => {}
+        FilterValue::ToJson => {}
         FilterValue::ToString => {}
+        FilterValue::ToTimestamp => {}
         FilterValue::UrlDecode => {}
         FilterValue::UrlEncode => {}
         FilterValue::UrlQueryParam { space0, param } => {
@@ -507,10 +631,13 @@ pub fn walk_filter<V: Visitor>(visitor: &mut V, filter: &Filter) {
         }
         FilterValue::Utf8Decode => {}
         FilterValue::Utf8Encode => {}
+        FilterValue::XmlEscape => {}
+        FilterValue::XmlUnescape => {}
         FilterValue::XPath { space0, expr } => {
             visitor.visit_whitespace(space0);
             visitor.visit_template(expr);
         }
+        FilterValue::ZstdDecompress => {}
     }
 }
 
@@ -539,6 +666,43 @@ pub fn walk_filename_value<V: Visitor>(visitor: &mut V, value: &FilenameValue) {
     }
 }
 
+pub fn walk_nested_param<V: Visitor>(visitor: &mut V, param: &NestedParam) {
+    param.line_terminators.iter().for_each(|lt| {
+        visitor.visit_lt(lt);
+    });
+    visitor.visit_whitespace(&param.space0);
+    visitor.visit_template(&param.key);
+    visitor.visit_whitespace(&param.space1);
+    visitor.visit_literal(":");
+    visitor.visit_whitespace(&param.space2);
+    visitor.visit_nested_value(&param.value);
+    visitor.visit_lt(&param.line_terminator0);
+    param
+        .params
+        .iter()
+        .for_each(|p| walk_multipart_param(visitor, p));
+}
+
+pub fn walk_nested_value<V: Visitor>(visitor: &mut V, value: &NestedValue) {
+    visitor.visit_literal("multipart,");
+    visitor.visit_whitespace(&value.space0);
+    visitor.visit_template(&value.subtype);
+    visitor.visit_whitespace(&value.space1);
+    visitor.visit_literal(";");
+    visitor.visit_whitespace(&value.space2);
+    if let Some(headers) = &value.headers {
+        visitor.visit_template(headers);
+    }
+}
+
+pub fn walk_multipart_param<V: Visitor>(visitor: &mut V, param: &MultipartParam) {
+    match param {
+        MultipartParam::Param(param) => visitor.visit_kv(param),
+        MultipartParam::FilenameParam(param) => visitor.visit_filename_param(param),
+        MultipartParam::NestedParam(param) => visitor.visit_nested_param(param),
+    }
+}
+
 pub fn walk_header<V: Visitor>(visitor: &mut V, header: &KeyValue) {
     visitor.visit_kv(header);
 }
@@ -552,12 +716,27 @@ pub fn walk_hex<V: Visitor>(visitor: &mut V, hex: &Hex) {
 }
 
 pub fn walk_hurl_file<V: Visitor>(visitor: &mut V, file: &HurlFile) {
+    if let Some(defaults) = &file.defaults {
+        visitor.visit_defaults(defaults);
+    }
     file.entries.iter().for_each(|e| visitor.visit_entry(e));
+    file.includes.iter().for_each(|i| visitor.visit_include(i));
     file.line_terminators.iter().for_each(|lt| {
         visitor.visit_lt(lt);
     });
 }
 
+pub fn walk_include<V: Visitor>(visitor: &mut V, include: &Include) {
+    include.line_terminators.iter().for_each(|lt| {
+        visitor.visit_lt(lt);
+    });
+    visitor.visit_whitespace(&include.space0);
+    visitor.visit_literal("include");
+    visitor.visit_whitespace(&include.space1);
+    visitor.visit_filename(&include.filename);
+    visitor.visit_lt(&include.line_terminator0);
+}
+
 pub fn walk_integer_value<V: Visitor>(visitor: &mut V, n: &IntegerValue) {
     match n {
         IntegerValue::Literal(value) => visitor.visit_i64(value.as_i64()),
@@ -613,6 +792,10 @@ pub fn walk_query<V: Visitor>(visitor: &mut V, query: &Query) {
             visitor.visit_whitespace(space0);
             visitor.visit_template(expr);
         }
+        QueryValue::Csv { space0, expr } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(expr);
+        }
         QueryValue::Regex { space0, value } => {
             visitor.visit_whitespace(space0);
             match value {
@@ -631,16 +814,41 @@ pub fn walk_query<V: Visitor>(visitor: &mut V, query: &Query) {
             visitor.visit_whitespace(space0);
             visitor.visit_string(attribute_name.to_source().as_str());
         }
-        QueryValue::Body
-        | QueryValue::Status
+        QueryValue::Timings {
+            space0,
+            attribute_name,
+        } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_string(attribute_name.to_source().as_str());
+        }
+        QueryValue::DurationPercentile { space0, percentile } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_u64(percentile);
+        }
+        QueryValue::Body { charset } => {
+            if let Some((space, charset)) = charset {
+                visitor.visit_whitespace(space);
+                visitor.visit_template(charset);
+            }
+        }
+        QueryValue::Status
         | QueryValue::Url
         | QueryValue::Duration
+        | QueryValue::DurationMean
         | QueryValue::Bytes
         | QueryValue::RawBytes
+        | QueryValue::Sha1
         | QueryValue::Sha256
+        | QueryValue::Sha512
         | QueryValue::Md5
+        | QueryValue::Crc32
         | QueryValue::Version
+        | QueryValue::VersionNegotiated
         | QueryValue::Ip
+        | QueryValue::ProxyStatus
+        | QueryValue::HstsUpgraded
+        | QueryValue::ContinueReceived
+        | QueryValue::EarlyHints
         | QueryValue::Redirects => {}
     }
 }
@@ -653,14 +861,30 @@ pub fn walk_predicate<V: Visitor>(visitor: &mut V, pred: &Predicate) {
     let kind = &pred.predicate_func.value;
     visitor.visit_predicate_kind(kind);
     match kind {
-        PredicateFuncValue::Equal { space0, value } => {
+        PredicateFuncValue::Equal {
+            space0,
+            value,
+            ignoring,
+        } => {
             visitor.visit_whitespace(space0);
             visitor.visit_predicate_value(value);
+            if let Some(ignoring) = ignoring {
+                visitor.visit_whitespace(&ignoring.space0);
+                visitor.visit_literal("ignoring");
+                for (space, path) in &ignoring.paths {
+                    visitor.visit_whitespace(space);
+                    visitor.visit_template(path);
+                }
+            }
         }
         PredicateFuncValue::NotEqual { space0, value } => {
             visitor.visit_whitespace(space0);
             visitor.visit_predicate_value(value);
         }
+        PredicateFuncValue::ConstantTimeEqual { space0, value } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_predicate_value(value);
+        }
         PredicateFuncValue::GreaterThan { space0, value } => {
             visitor.visit_whitespace(space0);
             visitor.visit_predicate_value(value);
@@ -693,6 +917,10 @@ pub fn walk_predicate<V: Visitor>(visitor: &mut V, pred: &Predicate) {
             visitor.visit_whitespace(space0);
             visitor.visit_predicate_value(value);
         }
+        PredicateFuncValue::IncludeJson { space0, value } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_predicate_value(value);
+        }
         PredicateFuncValue::Match { space0, value } => {
             visitor.visit_whitespace(space0);
             visitor.visit_predicate_value(value);
@@ -787,10 +1015,9 @@ pub fn walk_section_value<V: Visitor>(visitor: &mut V, section_value: &SectionVa
         SectionValue::Captures(captures) => captures.iter().for_each(|c| visitor.visit_capture(c)),
         SectionValue::Cookies(cookies) => cookies.iter().for_each(|c| visitor.visit_cookie(c)),
         SectionValue::FormParams(params, _) => params.iter().for_each(|p| visitor.visit_kv(p)),
-        SectionValue::MultipartFormData(params, _) => params.iter().for_each(|p| match p {
-            MultipartParam::Param(param) => visitor.visit_kv(param),
-            MultipartParam::FilenameParam(param) => visitor.visit_filename_param(param),
-        }),
+        SectionValue::MultipartFormData(params, _) => {
+            params.iter().for_each(|p| walk_multipart_param(visitor, p));
+        }
         SectionValue::Options(options) => {
             options.iter().for_each(|o| visitor.visit_entry_option(o));
         }
@@ -815,6 +1042,14 @@ pub fn walk_variable_value<V: Visitor>(visitor: &mut V, value: &VariableValue) {
     }
 }
 
+pub fn walk_auth_type_option<V: Visitor>(visitor: &mut V, value: &AuthTypeOption) {
+    visitor.visit_string(value.identifier());
+}
+
+pub fn walk_client_cert_type_option<V: Visitor>(visitor: &mut V, value: &ClientCertTypeOption) {
+    visitor.visit_string(value.identifier());
+}
+
 pub fn walk_verbosity_option<V: Visitor>(visitor: &mut V, value: &VerbosityOption) {
     visitor.visit_string(value.identifier());
 }
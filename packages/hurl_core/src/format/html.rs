@@ -17,42 +17,65 @@
  */
 use crate::ast::visit::Visitor;
 use crate::ast::{
-    Comment, Entry, FilterValue, JsonValue, Method, Placeholder, Regex, Request, Response,
-    Template, U64, Whitespace, visit,
+    Comment, Entry, Filter, FilterValue, JsonValue, Method, Placeholder, Query, Regex, Request,
+    Response, SourceInfo, Template, TemplateElement, U64, Whitespace, visit,
 };
 use crate::ast::{
-    CookiePath, HurlFile, MultilineString, Number, PredicateFuncValue, QueryValue, StatusValue,
-    VersionValue,
+    CookiePath, HurlFile, MultilineString, MultilineStringKind, Number, PredicateFuncValue,
+    QueryValue, StatusValue, VersionValue,
 };
+use crate::reader::Pos;
 use crate::types::{DurationUnit, SourceString, ToSource};
 
-/// Returns an HTML string of the Hurl file `hurl_file`.
+/// Options of the HTML export of a Hurl file.
 ///
-/// If `standalone` is true, a complete HTML body with inline styling is returned.
-/// Otherwise, a `<pre>` HTML tag is returned, without styling.
-pub fn format(file: &HurlFile, standalone: bool) -> String {
+/// Default options render a `<pre>` HTML tag, without line numbers nor entry anchors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HtmlOptions {
+    /// Returns a complete HTML page with inline styling, instead of a `<pre>` HTML tag.
+    pub standalone: bool,
+    /// Prefixes each line with its number, anchored by a `line-N` id (1-based).
+    pub line_numbers: bool,
+    /// Anchors each entry by an `entry-N` id (1-based). Entries of a standalone page are always
+    /// anchored by their heading.
+    pub entry_anchors: bool,
+}
+
+/// Returns an HTML string of the Hurl file `hurl_file`, given some `options`.
+///
+/// If `options.standalone` is true, a complete HTML page with inline styling is returned. Each
+/// entry is rendered in its own `<section>`, with a heading and a collapsible (keyboard
+/// accessible) block. Otherwise, a `<pre>` HTML tag is returned, without styling.
+pub fn format(file: &HurlFile, options: &HtmlOptions) -> String {
     let mut fmt = HtmlFormatter::new();
-    let body = fmt.format(file);
-    if standalone {
+    fmt.line_numbers = options.line_numbers;
+    if options.standalone {
         let css = include_str!("hurl.css");
+        let standalone_css = include_str!("standalone.css");
+        let body = fmt.format_sections(file);
         format!(
             r#"<!DOCTYPE html>
-<html>
+<html lang="en">
     <head>
         <meta charset="utf-8">
+        <meta name="viewport" content="width=device-width, initial-scale=1">
         <title>Hurl File</title>
         <style>
 {css}
+{standalone_css}
         </style>
     </head>
     <body>
-{body}
+<main>
+<h1>Hurl File</h1>
+{body}</main>
     </body>
 </html>
 "#
         )
     } else {
-        body.to_string()
+        fmt.entry_anchors = options.entry_anchors;
+        fmt.format(file).to_string()
     }
 }
 
@@ -60,9 +83,36 @@ pub fn hurl_css() -> String {
     include_str!("hurl.css").to_string()
 }
 
+/// Escapes `text` to be inserted in an HTML document.
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
 /// A HTML formatter for Hurl content.
+///
+/// Placeholders, queries and filters spans are annotated with `data-identifier`, `data-start` and
+/// `data-end` attributes (positions are formatted as `line:column`), so they can be enriched
+/// (tooltips, cross-links etc...) without parsing the Hurl file again:
+///
+/// ```html
+/// <span class="query-type" data-identifier="jsonpath" data-start="4:1" data-end="4:9">jsonpath</span>
+/// ```
 struct HtmlFormatter {
     buffer: String,
+    /// Source info of the query or filter currently visited.
+    source_info: Option<SourceInfo>,
+    /// Prefixes each line of the `<pre>` blocks with its number.
+    line_numbers: bool,
+    /// Adds an `entry-N` id to each entry span.
+    entry_anchors: bool,
+    /// Number of entries visited so far.
+    entry_count: usize,
+    /// Number of lines numbered so far, in all the `<pre>` blocks.
+    line_count: usize,
+    /// Position in the buffer of the `<pre>` block currently formatted.
+    code_start: Option<usize>,
 }
 
 const HURL_BASE64_VALUE_CLASS: &str = "base64";
@@ -71,6 +121,7 @@ const HURL_COMMENT_CLASS: &str = "comment";
 const HURL_DURATION_UNIT: &str = "unit";
 const HURL_ENTRY_CLASS: &str = "entry";
 const HURL_HEX_CLASS: &str = "hex";
+const HURL_LINE_NUMBER_CLASS: &str = "line-number";
 const HURL_FILENAME_CLASS: &str = "filename";
 const HURL_FILTER_KIND_CLASS: &str = "filter-type";
 const HURL_JSON_CLASS: &str = "json";
@@ -97,6 +148,12 @@ impl HtmlFormatter {
     fn new() -> Self {
         HtmlFormatter {
             buffer: String::new(),
+            source_info: None,
+            line_numbers: false,
+            entry_anchors: false,
+            entry_count: 0,
+            line_count: 0,
+            code_start: None,
         }
     }
 
@@ -106,22 +163,146 @@ impl HtmlFormatter {
         &self.buffer
     }
 
+    /// Formats each entry of `file` in its own `<section>`, with a heading and a collapsible block.
+    ///
+    /// The heading of an entry is its first leading comment if any, or its method and URL.
+    fn format_sections(&mut self, file: &HurlFile) -> &str {
+        self.buffer.clear();
+        if let Some(defaults) = &file.defaults {
+            self.pre_open(HURL_LANG_CLASS);
+            self.visit_defaults(defaults);
+            self.pre_close();
+            self.push_trusted("\n");
+        }
+        for (index, entry) in file.entries.iter().enumerate() {
+            let id = format!("entry-{}", index + 1);
+            // The heading is kept out of the `<summary>`, whose content is a button label for
+            // assistive technologies.
+            self.push_trusted(&format!(
+                "<section aria-labelledby=\"{id}\">\n<h2 id=\"{id}\">"
+            ));
+            self.push_untrusted(&entry_name(entry, index + 1));
+            self.push_trusted("</h2>\n<details open>\n<summary>Source</summary>\n");
+            self.pre_open(HURL_LANG_CLASS);
+            self.visit_entry(entry);
+            self.pre_close();
+            self.push_trusted("\n</details>\n</section>\n");
+        }
+        if !file.includes.is_empty() {
+            self.pre_open(HURL_LANG_CLASS);
+            file.includes.iter().for_each(|i| self.visit_include(i));
+            self.pre_close();
+            self.push_trusted("\n");
+        }
+        // Comments at the end of the file are not part of any entry.
+        if file.line_terminators.iter().any(|lt| lt.comment.is_some()) {
+            self.pre_open(HURL_LANG_CLASS);
+            file.line_terminators
+                .iter()
+                .for_each(|lt| self.visit_lt(lt));
+            self.pre_close();
+            self.push_trusted("\n");
+        }
+        &self.buffer
+    }
+
     fn pre_open(&mut self, class: &'static str) {
         self.buffer.push_str("<pre><code class=\"");
         self.buffer.push_str(class);
         self.buffer.push_str("\">");
+        if self.line_numbers {
+            self.code_start = Some(self.buffer.len());
+        }
     }
 
     fn pre_close(&mut self) {
+        if let Some(start) = self.code_start.take() {
+            let code = self.buffer.split_off(start);
+            self.push_numbered_lines(&code);
+        }
         self.buffer.push_str("</code></pre>");
     }
 
+    /// Pushes the HTML `code` of a `<pre>` block, with a line number anchor at the start of each
+    /// line.
+    ///
+    /// The anchor is inserted after the tags closed at the start of the line, and before the tags
+    /// opened, so it's not styled by the spans of the line. The trailing tags after the last
+    /// newline are not a line.
+    fn push_numbered_lines(&mut self, code: &str) {
+        let mut at_line_start = true;
+        let mut in_tag = false;
+        let mut closing_tag = false;
+        // Tags opened at the start of the current line, before its first character.
+        let mut line_tags = String::new();
+        let mut chars = code.chars().peekable();
+        while let Some(c) = chars.next() {
+            if at_line_start {
+                if c == '<' && !in_tag {
+                    in_tag = true;
+                    closing_tag = line_tags.is_empty() && chars.peek() == Some(&'/');
+                }
+                if in_tag {
+                    in_tag = c != '>';
+                    if closing_tag {
+                        self.buffer.push(c);
+                    } else {
+                        line_tags.push(c);
+                    }
+                    continue;
+                }
+                self.line_count += 1;
+                let line = self.line_count;
+                self.buffer.push_str(&format!(
+                    "<a class=\"{HURL_LINE_NUMBER_CLASS}\" id=\"line-{line}\" href=\"#line-{line}\">{line}</a>"
+                ));
+                self.buffer.push_str(&line_tags);
+                line_tags.clear();
+                at_line_start = false;
+            }
+            match c {
+                '<' => in_tag = true,
+                '>' => in_tag = false,
+                '\n' if !in_tag => at_line_start = true,
+                _ => {}
+            }
+            self.buffer.push(c);
+        }
+        self.buffer.push_str(&line_tags);
+    }
+
     fn span_open(&mut self, class: &'static str) {
         self.buffer.push_str("<span class=\"");
         self.buffer.push_str(class);
         self.buffer.push_str("\">");
     }
 
+    /// Opens a span with a `class`, annotated with the `identifier` and the `start`/`end` positions
+    /// of the spanned node.
+    fn span_open_with_data(&mut self, class: &'static str, identifier: &str, start: Pos, end: Pos) {
+        self.buffer.push_str("<span class=\"");
+        self.buffer.push_str(class);
+        self.buffer.push_str("\" data-identifier=\"");
+        self.push_untrusted(identifier);
+        self.buffer.push_str(&format!(
+            "\" data-start=\"{}:{}\" data-end=\"{}:{}\">",
+            start.line, start.column, end.line, end.column
+        ));
+    }
+
+    /// Opens a span for a query or filter `identifier`, using the source info of the query or
+    /// filter currently visited.
+    fn span_open_kind(&mut self, class: &'static str, identifier: &str) {
+        match self.source_info.take() {
+            Some(source_info) => {
+                let start = source_info.start;
+                let end = Pos::new(start.line, start.column + identifier.chars().count());
+                self.span_open_with_data(class, identifier, start, end);
+            }
+            None => self.span_open(class),
+        }
+    }
+
     fn span_close(&mut self) {
         self.buffer.push_str("</span>");
     }
@@ -132,16 +313,89 @@ impl HtmlFormatter {
     }
 
     fn push_untrusted(&mut self, str: &str) {
-        let escaped = str
-            .replace('&', "&amp;")
-            .replace('<', "&lt;")
-            .replace('>', "&gt;");
-        self.buffer.push_str(&escaped);
+        self.buffer.push_str(&escape_html(str));
     }
 
     fn push_trusted(&mut self, str: &str) {
         self.buffer.push_str(str);
     }
+
+    /// Pushes the source of a `template`, with its placeholders wrapped in `expr` spans.
+    fn push_template(&mut self, template: &Template) {
+        if let Some(delimiter) = template.delimiter {
+            self.push_untrusted(&delimiter.to_string());
+        }
+        for element in &template.elements {
+            match element {
+                TemplateElement::String { source, .. } => self.push_source(source),
+                TemplateElement::Placeholder(placeholder) => self.visit_placeholder(placeholder),
+            }
+        }
+        if let Some(delimiter) = template.delimiter {
+            self.push_untrusted(&delimiter.to_string());
+        }
+    }
+
+    /// Pushes the source of a JSON `value`, with the placeholders of its templates wrapped in
+    /// `expr` spans.
+    fn push_json(&mut self, value: &JsonValue) {
+        match value {
+            JsonValue::Placeholder(placeholder) => self.visit_placeholder(placeholder),
+            JsonValue::String(template) => self.push_template(template),
+            JsonValue::List { space0, elements } => {
+                self.push_trusted("[");
+                self.push_untrusted(space0);
+                for (index, element) in elements.iter().enumerate() {
+                    if index > 0 {
+                        self.push_trusted(",");
+                    }
+                    self.push_untrusted(&element.space0);
+                    self.push_json(&element.value);
+                    self.push_untrusted(&element.space1);
+                }
+                self.push_trusted("]");
+            }
+            JsonValue::Object { space0, elements } => {
+                self.push_trusted("{");
+                self.push_untrusted(space0);
+                for (index, element) in elements.iter().enumerate() {
+                    if index > 0 {
+                        self.push_trusted(",");
+                    }
+                    self.push_untrusted(&element.space0);
+                    self.push_template(&element.name);
+                    self.push_untrusted(&element.space1);
+                    self.push_trusted(":");
+                    self.push_untrusted(&element.space2);
+                    self.push_json(&element.value);
+                    self.push_untrusted(&element.space3);
+                }
+                self.push_trusted("}");
+            }
+            JsonValue::Number(_) | JsonValue::Boolean(_) | JsonValue::Null => {
+                self.push_source(&value.to_source());
+            }
+        }
+    }
+}
+
+/// Returns a human readable name for the `entry` at `index` (1-based).
+fn entry_name(entry: &Entry, index: usize) -> String {
+    let comment = entry
+        .request
+        .line_terminators
+        .iter()
+        .find_map(|lt| lt.comment.as_ref())
+        .map(|c| c.value.trim())
+        .filter(|c| !c.is_empty());
+    match comment {
+        Some(comment) => format!("Entry {index}: {comment}"),
+        None => format!(
+            "Entry {index}: {} {}",
+            entry.request.method,
+            entry.request.url.to_source()
+        ),
+    }
 }
 
 impl Visitor for HtmlFormatter {
@@ -176,19 +430,31 @@ impl Visitor for HtmlFormatter {
     }
 
     fn visit_entry(&mut self, entry: &Entry) {
-        self.span_open(HURL_ENTRY_CLASS);
+        self.entry_count += 1;
+        if self.entry_anchors {
+            let id = format!("entry-{}", self.entry_count);
+            self.buffer
+                .push_str(&format!("<span class=\"{HURL_ENTRY_CLASS}\" id=\"{id}\">"));
+        } else {
+            self.span_open(HURL_ENTRY_CLASS);
+        }
         visit::walk_entry(self, entry);
         self.span_close();
     }
 
     fn visit_filename(&mut self, filename: &Template) {
         self.span_open(HURL_FILENAME_CLASS);
-        self.push_source(&filename.to_source());
+        self.push_template(filename);
         self.span_close();
     }
 
+    fn visit_filter(&mut self, filter: &Filter) {
+        self.source_info = Some(filter.source_info);
+        visit::walk_filter(self, filter);
+    }
+
     fn visit_filter_kind(&mut self, kind: &FilterValue) {
-        self.span_open(HURL_FILTER_KIND_CLASS);
+        self.span_open_kind(HURL_FILTER_KIND_CLASS, kind.identifier());
         self.push_trusted(kind.identifier());
         self.span_close();
     }
@@ -213,7 +479,7 @@ impl Visitor for HtmlFormatter {
 
     fn visit_json_body(&mut self, json: &JsonValue) {
         self.span_open(HURL_JSON_CLASS);
-        self.push_source(&json.to_source());
+        self.push_json(json);
         self.span_close();
     }
 
@@ -229,7 +495,26 @@ impl Visitor for HtmlFormatter {
 
     fn visit_multiline_string(&mut self, string: &MultilineString) {
         self.span_open(HURL_MULTILINESTRING_CLASS);
-        self.push_source(&string.to_source());
+        self.push_trusted("```");
+        self.push_trusted(string.lang());
+        self.push_untrusted(string.space.as_str());
+        self.push_untrusted(string.newline.as_str());
+        match &string.kind {
+            MultilineStringKind::Text(value)
+            | MultilineStringKind::Json(value)
+            | MultilineStringKind::Xml(value)
+            | MultilineStringKind::Raw(value) => self.push_template(value),
+            MultilineStringKind::GraphQl(graphql) => {
+                self.push_template(&graphql.value);
+                if let Some(variables) = &graphql.variables {
+                    self.push_trusted("variables");
+                    self.push_untrusted(variables.space.as_str());
+                    self.push_json(&variables.value);
+                    self.push_untrusted(variables.whitespace.as_str());
+                }
+            }
+        }
+        self.push_trusted("```");
         self.span_close();
     }
 
@@ -252,7 +537,14 @@ impl Visitor for HtmlFormatter {
     }
 
     fn visit_placeholder(&mut self, placeholder: &Placeholder) {
-        self.span_open(HURL_PLACEHOLDER_CLASS);
+        // Positions include the surrounding `{{` and `}}`. Placeholders built without a source (like
+        // the ones imported from JSON) have no room for the `{{` before them.
+        let start = placeholder.space0.source_info.start;
+        let start = Pos::new(start.line, start.column.saturating_sub(2).max(1));
+        let end = placeholder.space1.source_info.end;
+        let end = Pos::new(end.line, end.column + 2);
+        let identifier = placeholder.expr.kind.to_string();
+        self.span_open_with_data(HURL_PLACEHOLDER_CLASS, &identifier, start, end);
         self.push_source(&placeholder.to_source());
         self.span_close();
     }
@@ -263,8 +555,13 @@ impl Visitor for HtmlFormatter {
         self.span_close();
     }
 
+    fn visit_query(&mut self, query: &Query) {
+        self.source_info = Some(query.source_info);
+        visit::walk_query(self, query);
+    }
+
     fn visit_query_kind(&mut self, kind: &QueryValue) {
-        self.span_open(HURL_QUERY_TYPE_CLASS);
+        self.span_open_kind(HURL_QUERY_TYPE_CLASS, kind.identifier());
         self.push_trusted(kind.identifier());
         self.span_close();
     }
@@ -306,13 +603,13 @@ impl Visitor for HtmlFormatter {
 
     fn visit_template(&mut self, template: &Template) {
         self.span_open(HURL_STRING_CLASS);
-        self.push_source(&template.to_source());
+        self.push_template(template);
         self.span_close();
     }
 
     fn visit_url(&mut self, url: &Template) {
         self.span_open(HURL_URL_CLASS);
-        self.push_source(&url.to_source());
+        self.push_template(url);
         self.span_close();
     }
 
@@ -353,10 +650,12 @@ impl Visitor for HtmlFormatter {
 mod tests {
     use crate::ast::visit::Visitor;
     use crate::ast::{
-        JsonObjectElement, JsonValue, MultilineString, MultilineStringKind, SourceInfo, Template,
-        TemplateElement, Whitespace,
+        Expr, ExprKind, Filter, FilterValue, JsonObjectElement, JsonValue, MultilineString,
+        MultilineStringKind, Placeholder, Query, QueryValue, SourceInfo, Template, TemplateElement,
+        Variable, Whitespace,
     };
-    use crate::format::html::HtmlFormatter;
+    use crate::format::html::{HtmlFormatter, HtmlOptions, format};
+    use crate::parser::parse_hurl_file;
     use crate::reader::Pos;
     use crate::types::ToSource;
 
@@ -461,4 +760,200 @@ mod tests {
         fmt.push_untrusted("<?xml version=\"1.0\"?>");
         assert_eq!(fmt.buffer, "&lt;?xml version=\"1.0\"?&gt;");
     }
+
+    #[test]
+    fn test_query_and_filter_data() {
+        // status count
+        let query = Query {
+            source_info: SourceInfo::new(Pos::new(4, 1), Pos::new(4, 7)),
+            value: QueryValue::Status,
+        };
+        let mut fmt = HtmlFormatter::new();
+        fmt.visit_query(&query);
+        assert_eq!(
+            fmt.buffer,
+            "<span class=\"query-type\" data-identifier=\"status\" data-start=\"4:1\" data-end=\"4:7\">status</span>"
+        );
+
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(4, 8), Pos::new(4, 13)),
+            value: FilterValue::Count,
+        };
+        let mut fmt = HtmlFormatter::new();
+        fmt.visit_filter(&filter);
+        assert_eq!(
+            fmt.buffer,
+            "<span class=\"filter-type\" data-identifier=\"count\" data-start=\"4:8\" data-end=\"4:13\">count</span>"
+        );
+    }
+
+    #[test]
+    fn test_placeholder_data() {
+        // {{ name }}
+        let placeholder = Placeholder {
+            space0: Whitespace {
+                value: " ".to_string(),
+                source_info: SourceInfo::new(Pos::new(1, 3), Pos::new(1, 4)),
+            },
+            expr: Expr {
+                source_info: SourceInfo::new(Pos::new(1, 4), Pos::new(1, 8)),
+                kind: ExprKind::Variable(Variable {
+                    name: "name".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 4), Pos::new(1, 8)),
+                }),
+            },
+            space1: Whitespace {
+                value: " ".to_string(),
+                source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 9)),
+            },
+        };
+        let mut fmt = HtmlFormatter::new();
+        fmt.visit_placeholder(&placeholder);
+        assert_eq!(
+            fmt.buffer,
+            "<span class=\"expr\" data-identifier=\"name\" data-start=\"1:1\" data-end=\"1:11\">{{ name }}</span>"
+        );
+    }
+
+    #[test]
+    fn test_placeholder_data_without_source() {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+        let placeholder = Placeholder {
+            space0: Whitespace {
+                value: String::new(),
+                source_info,
+            },
+            expr: Expr {
+                source_info,
+                kind: ExprKind::Variable(Variable {
+                    name: "name".to_string(),
+                    source_info,
+                }),
+            },
+            space1: Whitespace {
+                value: String::new(),
+                source_info,
+            },
+        };
+        let mut fmt = HtmlFormatter::new();
+        fmt.visit_placeholder(&placeholder);
+        assert_eq!(
+            fmt.buffer,
+            "<span class=\"expr\" data-identifier=\"name\" data-start=\"1:1\" data-end=\"1:3\">{{name}}</span>"
+        );
+    }
+
+    #[test]
+    fn test_placeholders_in_templates() {
+        let content = "POST {{host}}/users/{{id}}\n\
+                       X-Name: a {{name}} b\n\
+                       {\"users\": [{\"id\": {{id}}, \"name\": \"{{ name }}\"}]}\n";
+        let file = parse_hurl_file(content).unwrap();
+        assert_eq!(
+            format(&file, &HtmlOptions::default()),
+            "<pre><code class=\"language-hurl\"><span class=\"entry\"><span class=\"request\">\
+             <span class=\"method\">POST</span> <span class=\"url\">\
+             <span class=\"expr\" data-identifier=\"host\" data-start=\"1:6\" data-end=\"1:14\">{{host}}</span>/users/\
+             <span class=\"expr\" data-identifier=\"id\" data-start=\"1:21\" data-end=\"1:27\">{{id}}</span></span>\n\
+             <span class=\"string\">X-Name</span>: <span class=\"string\">a \
+             <span class=\"expr\" data-identifier=\"name\" data-start=\"2:11\" data-end=\"2:19\">{{name}}</span> b</span>\n\
+             <span class=\"json\">{\"users\": [{\"id\": \
+             <span class=\"expr\" data-identifier=\"id\" data-start=\"3:19\" data-end=\"3:25\">{{id}}</span>, \"name\": \"\
+             <span class=\"expr\" data-identifier=\"name\" data-start=\"3:36\" data-end=\"3:46\">{{ name }}</span>\"}]}</span>\n\
+             </span></span></code></pre>"
+        );
+    }
+
+    #[test]
+    fn test_placeholders_in_multiline_strings() {
+        let content = "POST https://example.org\n\
+                       ```graphql\n\
+                       query { user(id: \"{{id}}\") }\n\
+                       variables {\"id\": {{id}}}\n\
+                       ```\n\
+                       HTTP 200\n\
+                       ```raw\n\
+                       {{id}}\n\
+                       ```\n";
+        let file = parse_hurl_file(content).unwrap();
+        assert_eq!(
+            format(&file, &HtmlOptions::default()),
+            "<pre><code class=\"language-hurl\"><span class=\"entry\"><span class=\"request\">\
+             <span class=\"method\">POST</span> <span class=\"url\">https://example.org</span>\n\
+             <span class=\"multiline\">```graphql\n\
+             query { user(id: \"<span class=\"expr\" data-identifier=\"id\" data-start=\"3:19\" data-end=\"3:25\">{{id}}</span>\") }\n\
+             variables {\"id\": <span class=\"expr\" data-identifier=\"id\" data-start=\"4:18\" data-end=\"4:24\">{{id}}</span>}\n\
+             ```</span>\n\
+             </span><span class=\"response\"><span class=\"version\">HTTP</span> <span class=\"number\">200</span>\n\
+             <span class=\"multiline\">```raw\n\
+             {{id}}\n\
+             ```</span>\n\
+             </span></span></code></pre>"
+        );
+    }
+
+    #[test]
+    fn test_sections() {
+        let content = "# Get <home> page\nGET http://localhost:8000/\n\nPOST http://localhost:8000/login\n# End\n";
+        let file = parse_hurl_file(content).unwrap();
+        let mut fmt = HtmlFormatter::new();
+        assert_eq!(
+            fmt.format_sections(&file),
+            "<section aria-labelledby=\"entry-1\">\n\
+             <h2 id=\"entry-1\">Entry 1: Get &lt;home&gt; page</h2>\n\
+             <details open>\n\
+             <summary>Source</summary>\n\
+             <pre><code class=\"language-hurl\"><span class=\"entry\"><span class=\"request\"><span class=\"comment\"># Get &lt;home&gt; page</span>\n\
+             <span class=\"method\">GET</span> <span class=\"url\">http://localhost:8000/</span>\n\
+             </span></span></code></pre>\n\
+             </details>\n\
+             </section>\n\
+             <section aria-labelledby=\"entry-2\">\n\
+             <h2 id=\"entry-2\">Entry 2: POST http://localhost:8000/login</h2>\n\
+             <details open>\n\
+             <summary>Source</summary>\n\
+             <pre><code class=\"language-hurl\"><span class=\"entry\"><span class=\"request\">\n\
+             <span class=\"method\">POST</span> <span class=\"url\">http://localhost:8000/login</span>\n\
+             </span></span></code></pre>\n\
+             </details>\n\
+             </section>\n\
+             <pre><code class=\"language-hurl\"><span class=\"comment\"># End</span>\n\
+             </code></pre>\n"
+        );
+    }
+
+    #[test]
+    fn test_line_numbers_and_entry_anchors() {
+        let content = "GET http://localhost:8000/
+
+POST http://localhost:8000/login
+";
+        let file = parse_hurl_file(content).unwrap();
+        let options = HtmlOptions {
+            line_numbers: true,
+            entry_anchors: true,
+            ..HtmlOptions::default()
+        };
+        assert_eq!(
+            format(&file, &options),
+            "<pre><code class=\"language-hurl\">\
+             <a class=\"line-number\" id=\"line-1\" href=\"#line-1\">1</a>\
+             <span class=\"entry\" id=\"entry-1\"><span class=\"request\"><span class=\"method\">GET</span> <span class=\"url\">http://localhost:8000/</span>\n\
+             </span></span><a class=\"line-number\" id=\"line-2\" href=\"#line-2\">2</a>\
+             <span class=\"entry\" id=\"entry-2\"><span class=\"request\">\n\
+             <a class=\"line-number\" id=\"line-3\" href=\"#line-3\">3</a>\
+             <span class=\"method\">POST</span> <span class=\"url\">http://localhost:8000/login</span>\n\
+             </span></span></code></pre>"
+        );
+
+        // Default options keep the output without line numbers nor anchors.
+        assert_eq!(
+            format(&file, &HtmlOptions::default()),
+            "<pre><code class=\"language-hurl\">\
+             <span class=\"entry\"><span class=\"request\"><span class=\"method\">GET</span> <span class=\"url\">http://localhost:8000/</span>\n\
+             </span></span><span class=\"entry\"><span class=\"request\">\n\
+             <span class=\"method\">POST</span> <span class=\"url\">http://localhost:8000/login</span>\n\
+             </span></span></code></pre>"
+        );
+    }
 }
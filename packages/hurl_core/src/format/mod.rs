@@ -15,6 +15,8 @@
  * limitations under the License.
  *
  */
-pub use self::html::{format as format_html, hurl_css};
+pub use self::html::{HtmlOptions, escape_html, format as format_html, hurl_css};
+pub use self::term::format as format_term;
 
 mod html;
+mod term;
@@ -15,31 +15,34 @@
  * limitations under the License.
  *
  */
-use hurl_core::ast::visit::Visitor;
-use hurl_core::ast::{
+use crate::ast::visit::Visitor;
+use crate::ast::{
     Comment, CookiePath, FilterValue, HurlFile, JsonValue, Method, MultilineString, Number,
-    Placeholder, PredicateFuncValue, QueryValue, Regex, StatusValue, Template, U64, VersionValue,
-    Whitespace,
+    Placeholder, PredicateFuncValue, QueryValue, Regex, StatusValue, Template, TemplateElement,
+    U64, VersionValue, Whitespace,
 };
-use hurl_core::text::{Format, Style, StyledString};
-use hurl_core::types::{DurationUnit, SourceString, ToSource};
+use crate::text::{Format, Style, StyledString};
+use crate::types::{DurationUnit, SourceString, ToSource};
 
-/// Format a Hurl `file` to text, using ANSI escape code if `color` is true.
+/// Returns a terminal string of the Hurl file `file`, using ANSI escape codes if `color` is true.
+///
+/// Tokens are classified as in the HTML export: placeholders of templates are highlighted on their
+/// own, so they stand out from the surrounding strings.
 pub fn format(file: &HurlFile, color: bool) -> String {
     let format = if color { Format::Ansi } else { Format::Plain };
-    let mut fmt = TextFormatter::new();
+    let mut fmt = TermFormatter::new();
     fmt.format(file, format)
 }
 
-/// A formatter for text and terminal export.
-struct TextFormatter {
+/// A terminal formatter for Hurl content.
+struct TermFormatter {
     buffer: StyledString,
 }
 
-impl TextFormatter {
-    /// Creates a new text formatter supporting ANSI escape code coloring.
+impl TermFormatter {
+    /// Creates a new terminal formatter supporting ANSI escape code coloring.
     fn new() -> Self {
-        TextFormatter {
+        TermFormatter {
             buffer: StyledString::new(),
         }
     }
@@ -49,9 +52,28 @@ impl TextFormatter {
         self.visit_hurl_file(file);
         self.buffer.to_string(format)
     }
+
+    /// Pushes the source of a `template` with a `style`, its placeholders being highlighted.
+    fn push_template(&mut self, template: &Template, style: Style) {
+        let delimiter = template.delimiter.map(|d| d.to_string());
+        if let Some(delimiter) = &delimiter {
+            self.buffer.push_with(delimiter, style);
+        }
+        for element in &template.elements {
+            match element {
+                TemplateElement::String { source, .. } => {
+                    self.buffer.push_with(source.as_str(), style);
+                }
+                TemplateElement::Placeholder(placeholder) => self.visit_placeholder(placeholder),
+            }
+        }
+        if let Some(delimiter) = &delimiter {
+            self.buffer.push_with(delimiter, style);
+        }
+    }
 }
 
-impl Visitor for TextFormatter {
+impl Visitor for TermFormatter {
     fn visit_base64_value(&mut self, _value: &[u8], source: &SourceString) {
         self.buffer.push_with(source.as_str(), Style::new().green());
     }
@@ -78,8 +100,7 @@ impl Visitor for TextFormatter {
     }
 
     fn visit_filename(&mut self, filename: &Template) {
-        let value = filename.to_source();
-        self.buffer.push_with(value.as_str(), Style::new().green());
+        self.push_template(filename, Style::new().green());
     }
 
     fn visit_filter_kind(&mut self, kind: &FilterValue) {
@@ -130,7 +151,7 @@ impl Visitor for TextFormatter {
 
     fn visit_placeholder(&mut self, placeholder: &Placeholder) {
         let value = placeholder.to_source();
-        self.buffer.push_with(value.as_str(), Style::new().green());
+        self.buffer.push_with(value.as_str(), Style::new().purple());
     }
 
     fn visit_predicate_kind(&mut self, kind: &PredicateFuncValue) {
@@ -162,13 +183,11 @@ impl Visitor for TextFormatter {
     }
 
     fn visit_template(&mut self, template: &Template) {
-        let value = template.to_source();
-        self.buffer.push_with(value.as_str(), Style::new().green());
+        self.push_template(template, Style::new().green());
     }
 
     fn visit_url(&mut self, url: &Template) {
-        let value = url.to_source();
-        self.buffer.push_with(value.as_str(), Style::new().green());
+        self.push_template(url, Style::new().green());
     }
 
     fn visit_u64(&mut self, n: &U64) {
@@ -201,14 +220,13 @@ impl Visitor for TextFormatter {
 
 #[cfg(test)]
 mod tests {
-    use crate::format::text::TextFormatter;
-    use hurl_core::parser::parse_hurl_file;
-    use hurl_core::text::Format;
+    use super::*;
+    use crate::parser::parse_hurl_file;
 
     #[test]
     fn format_hurl_file() {
         // For the crate colored to output ANSI escape code in test environment.
-        hurl_core::text::init_crate_colored();
+        crate::text::init_crate_colored();
 
         let src = r#"
 GET https://foo.com
@@ -222,7 +240,7 @@ HTTP 200
 jsonpath "$.name" == "toto"
 "#;
         let file = parse_hurl_file(src).unwrap();
-        let mut fmt = TextFormatter::new();
+        let mut fmt = TermFormatter::new();
         let dst = fmt.format(&file, Format::Plain);
         assert_eq!(src, dst);
 
@@ -242,4 +260,19 @@ HTTP 200
 "#
         );
     }
+
+    #[test]
+    fn format_placeholders() {
+        crate::text::init_crate_colored();
+
+        let src = "GET https://{{host}}/api\nx-id: \"{{id}}\"\n";
+        let file = parse_hurl_file(src).unwrap();
+        let mut fmt = TermFormatter::new();
+        assert_eq!(fmt.format(&file, Format::Plain), src);
+        assert_eq!(
+            fmt.format(&file, Format::Ansi),
+            "\u{1b}[33mGET\u{1b}[0m \u{1b}[32mhttps://\u{1b}[0m\u{1b}[35m{{host}}\u{1b}[0m\u{1b}[32m/api\u{1b}[0m\n\
+             \u{1b}[32mx-id\u{1b}[0m: \u{1b}[32m\"\u{1b}[0m\u{1b}[35m{{id}}\u{1b}[0m\u{1b}[32m\"\u{1b}[0m\n"
+        );
+    }
 }
@@ -33,11 +33,14 @@ pub struct ParseError {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ParseErrorKind {
+    BodyTooLarge { max: usize },
+    DefaultsSectionName { name: String },
     DuplicateSection,
     EscapeChar,
     Expecting { value: String },
     FileContentType,
     Filename,
+    FileTooLarge { max: usize },
     GraphQlVariables,
     HexDigit,
     InvalidCookieAttribute,
@@ -48,6 +51,7 @@ pub enum ParseErrorKind {
     Method { name: String },
     Multiline,
     MultilineLanguageHint(String),
+    NestingTooDeep { max: usize },
     OddNumberOfHexDigits,
     Predicate,
     PredicateValue,
@@ -59,6 +63,7 @@ pub enum ParseErrorKind {
     Space,
     Status,
     TemplateVariable,
+    TooManyEntries { max: usize },
     Unicode,
     UrlIllegalCharacter(char),
     UrlInvalidStart,
@@ -84,63 +89,24 @@ impl ParseError {
             kind,
         }
     }
-}
 
-impl DisplaySourceError for ParseError {
-    fn source_info(&self) -> SourceInfo {
-        SourceInfo {
-            start: self.pos,
-            end: self.pos,
-        }
-    }
-
-    fn description(&self) -> String {
-        match self.kind {
-            ParseErrorKind::DuplicateSection => "Parsing section".to_string(),
-            ParseErrorKind::EscapeChar => "Parsing escape character".to_string(),
-            ParseErrorKind::Expecting { .. } => "Parsing literal".to_string(),
-            ParseErrorKind::FileContentType => "Parsing file content type".to_string(),
-            ParseErrorKind::Filename => "Parsing filename".to_string(),
-            ParseErrorKind::GraphQlVariables => "Parsing GraphQL variables".to_string(),
-            ParseErrorKind::HexDigit => "Parsing hexadecimal number".to_string(),
-            ParseErrorKind::InvalidCookieAttribute => "Parsing cookie attribute".to_string(),
-            ParseErrorKind::InvalidOption(_) => "Parsing option".to_string(),
-            ParseErrorKind::InvalidDurationUnit(_) => "Parsing duration".to_string(),
-            ParseErrorKind::Json(_) => "Parsing JSON".to_string(),
-            ParseErrorKind::JsonPathExpr => "Parsing JSONPath expression".to_string(),
-            ParseErrorKind::Method { .. } => "Parsing method".to_string(),
-            ParseErrorKind::Multiline => "Parsing multiline".to_string(),
-            ParseErrorKind::MultilineLanguageHint(..) => "Parsing multiline".to_string(),
-            ParseErrorKind::OddNumberOfHexDigits => "Parsing hex bytearray".to_string(),
-            ParseErrorKind::Predicate => "Parsing predicate".to_string(),
-            ParseErrorKind::PredicateValue => "Parsing predicate value".to_string(),
-            ParseErrorKind::RegexExpr { .. } => "Parsing regex".to_string(),
-            ParseErrorKind::RequestSection => "Parsing section".to_string(),
-            ParseErrorKind::RequestSectionName { .. } => "Parsing request section name".to_string(),
-            ParseErrorKind::ResponseSection => "Parsing section".to_string(),
-            ParseErrorKind::ResponseSectionName { .. } => {
-                "Parsing response section name".to_string()
+    /// Returns the message of this error, without its position in the source.
+    pub fn message(&self) -> String {
+        match &self.kind {
+            ParseErrorKind::BodyTooLarge { max } => format!(
+                "the body exceeds the maximum size of {max} characters, use a file body (file,<filename>;) instead"
+            ),
+            ParseErrorKind::DefaultsSectionName { .. } => {
+                "the section is not valid in [Defaults]. Valid values are Options".to_string()
             }
-            ParseErrorKind::Space => "Parsing space".to_string(),
-            ParseErrorKind::Status => "Parsing status code".to_string(),
-            ParseErrorKind::TemplateVariable => "Parsing template variable".to_string(),
-            ParseErrorKind::Unicode => "Parsing unicode literal".to_string(),
-            ParseErrorKind::UrlIllegalCharacter(_) => "Parsing URL".to_string(),
-            ParseErrorKind::UrlInvalidStart => "Parsing URL".to_string(),
-            ParseErrorKind::Variable(_) => "Parsing variable".to_string(),
-            ParseErrorKind::Version => "Parsing version".to_string(),
-            ParseErrorKind::XPathExpr => "Parsing XPath expression".to_string(),
-            ParseErrorKind::Xml => "Parsing XML".to_string(),
-        }
-    }
-
-    fn fixme(&self, content: &[&str]) -> StyledString {
-        let message = match &self.kind {
             ParseErrorKind::DuplicateSection => "the section is already defined".to_string(),
             ParseErrorKind::EscapeChar => "the escaping sequence is not valid".to_string(),
             ParseErrorKind::Expecting { value } => format!("expecting '{value}'"),
             ParseErrorKind::FileContentType => "expecting a content type".to_string(),
             ParseErrorKind::Filename => "expecting a filename".to_string(),
+            ParseErrorKind::FileTooLarge { max } => {
+                format!("the file exceeds the maximum size of {max} bytes")
+            }
             ParseErrorKind::GraphQlVariables => {
                 "GraphQL variables is not a valid JSON object".to_string()
             }
@@ -156,19 +122,31 @@ impl DisplaySourceError for ParseError {
             }
             ParseErrorKind::InvalidOption(name) => {
                 let valid_values = [
+                    "auth-type",
                     "aws-sigv4",
                     "cacert",
                     "cert",
+                    "chunked",
+                    "client-cert-password",
+                    "client-cert-type",
+                    "clock-skew",
                     "compressed",
                     "connect-timeout",
                     "connect-to",
+                    "cookie-file",
                     "delay",
                     "digest",
+                    "dns-servers",
+                    "expect-continue",
+                    "export-capture",
+                    "foreach",
                     "header",
+                    "hmac-signature",
                     "http1.0",
                     "http1.1",
                     "http2",
                     "http3",
+                    "http3-fallback",
                     "insecure",
                     "ipv4",
                     "ipv6",
@@ -178,21 +156,35 @@ impl DisplaySourceError for ParseError {
                     "location-trusted",
                     "max-redirs",
                     "max-time",
+                    "multipart-boundary",
                     "negotiate",
                     "netrc",
                     "netrc-file",
                     "netrc-optional",
                     "no-proxy",
                     "ntlm",
+                    "oauth2-client-id",
+                    "oauth2-client-secret",
+                    "oauth2-token-url",
+                    "only-if",
                     "output",
+                    "paginate",
+                    "paginate-max",
                     "path-as-is",
                     "pinnedpubkey",
                     "proxy",
+                    "proxy-header",
+                    "proxy-user",
                     "repeat",
                     "resolve",
                     "retry",
                     "retry-interval",
+                    "retry-on-capture-failure",
                     "skip",
+                    "skip-if",
+                    "soft-asserts",
+                    "nested-form-params",
+                    "trailer",
                     "unix-socket",
                     "user",
                     "variable",
@@ -224,6 +216,9 @@ impl DisplaySourceError for ParseError {
             }
             ParseErrorKind::Multiline => "the multiline is not valid".to_string(),
             ParseErrorKind::MultilineLanguageHint(name) => format!("Invalid language hint {name}"),
+            ParseErrorKind::NestingTooDeep { max } => {
+                format!("the JSON value exceeds the maximum nesting depth of {max}")
+            }
             ParseErrorKind::OddNumberOfHexDigits => {
                 "expecting an even number of hex digits".to_string()
             }
@@ -251,6 +246,9 @@ impl DisplaySourceError for ParseError {
             ParseErrorKind::Space => "expecting a space".to_string(),
             ParseErrorKind::Status => "HTTP status code is not valid".to_string(),
             ParseErrorKind::TemplateVariable => "expecting a variable".to_string(),
+            ParseErrorKind::TooManyEntries { max } => {
+                format!("the file exceeds the maximum number of {max} entries")
+            }
             ParseErrorKind::Unicode => "Invalid unicode literal".to_string(),
             ParseErrorKind::UrlIllegalCharacter(c) => format!("illegal character <{c}>"),
             ParseErrorKind::UrlInvalidStart => "expecting http://, https:// or {{".to_string(),
@@ -260,9 +258,67 @@ impl DisplaySourceError for ParseError {
             }
             ParseErrorKind::XPathExpr => "expecting a XPath expression".to_string(),
             ParseErrorKind::Xml => "invalid XML".to_string(),
-        };
+        }
+    }
+}
+
+impl DisplaySourceError for ParseError {
+    fn source_info(&self) -> SourceInfo {
+        SourceInfo {
+            start: self.pos,
+            end: self.pos,
+        }
+    }
 
-        let message = error::add_carets(&message, self.source_info(), content);
+    fn description(&self) -> String {
+        match self.kind {
+            ParseErrorKind::BodyTooLarge { .. } => "Parsing body".to_string(),
+            ParseErrorKind::DefaultsSectionName { .. } => {
+                "Parsing defaults section name".to_string()
+            }
+            ParseErrorKind::DuplicateSection => "Parsing section".to_string(),
+            ParseErrorKind::EscapeChar => "Parsing escape character".to_string(),
+            ParseErrorKind::Expecting { .. } => "Parsing literal".to_string(),
+            ParseErrorKind::FileContentType => "Parsing file content type".to_string(),
+            ParseErrorKind::Filename => "Parsing filename".to_string(),
+            ParseErrorKind::FileTooLarge { .. } => "Parsing file".to_string(),
+            ParseErrorKind::GraphQlVariables => "Parsing GraphQL variables".to_string(),
+            ParseErrorKind::HexDigit => "Parsing hexadecimal number".to_string(),
+            ParseErrorKind::InvalidCookieAttribute => "Parsing cookie attribute".to_string(),
+            ParseErrorKind::InvalidOption(_) => "Parsing option".to_string(),
+            ParseErrorKind::InvalidDurationUnit(_) => "Parsing duration".to_string(),
+            ParseErrorKind::Json(_) => "Parsing JSON".to_string(),
+            ParseErrorKind::JsonPathExpr => "Parsing JSONPath expression".to_string(),
+            ParseErrorKind::Method { .. } => "Parsing method".to_string(),
+            ParseErrorKind::Multiline => "Parsing multiline".to_string(),
+            ParseErrorKind::MultilineLanguageHint(..) => "Parsing multiline".to_string(),
+            ParseErrorKind::NestingTooDeep { .. } => "Parsing JSON".to_string(),
+            ParseErrorKind::OddNumberOfHexDigits => "Parsing hex bytearray".to_string(),
+            ParseErrorKind::Predicate => "Parsing predicate".to_string(),
+            ParseErrorKind::PredicateValue => "Parsing predicate value".to_string(),
+            ParseErrorKind::RegexExpr { .. } => "Parsing regex".to_string(),
+            ParseErrorKind::RequestSection => "Parsing section".to_string(),
+            ParseErrorKind::RequestSectionName { .. } => "Parsing request section name".to_string(),
+            ParseErrorKind::ResponseSection => "Parsing section".to_string(),
+            ParseErrorKind::ResponseSectionName { .. } => {
+                "Parsing response section name".to_string()
+            }
+            ParseErrorKind::Space => "Parsing space".to_string(),
+            ParseErrorKind::Status => "Parsing status code".to_string(),
+            ParseErrorKind::TemplateVariable => "Parsing template variable".to_string(),
+            ParseErrorKind::TooManyEntries { .. } => "Parsing entry".to_string(),
+            ParseErrorKind::Unicode => "Parsing unicode literal".to_string(),
+            ParseErrorKind::UrlIllegalCharacter(_) => "Parsing URL".to_string(),
+            ParseErrorKind::UrlInvalidStart => "Parsing URL".to_string(),
+            ParseErrorKind::Variable(_) => "Parsing variable".to_string(),
+            ParseErrorKind::Version => "Parsing version".to_string(),
+            ParseErrorKind::XPathExpr => "Parsing XPath expression".to_string(),
+            ParseErrorKind::Xml => "Parsing XML".to_string(),
+        }
+    }
+
+    fn fixme(&self, content: &[&str]) -> StyledString {
+        let message = error::add_carets(&self.message(), self.source_info(), content);
         let mut s = StyledString::new();
         s.push_with(&message, Style::new().red().bold());
         s
@@ -387,4 +443,24 @@ mod tests {
    |"#
         );
     }
+
+    #[test]
+    fn test_too_many_entries_error() {
+        let content = "GET http://a.fr\nGET http://b.fr";
+        let filename = "test.hurl";
+        let error = ParseError {
+            pos: Pos::new(2, 1),
+            recoverable: false,
+            kind: ParseErrorKind::TooManyEntries { max: 1 },
+        };
+        assert_eq!(
+            error.render(filename, content, None, OutputFormat::Terminal(false)),
+            r#"Parsing entry
+  --> test.hurl:2:1
+   |
+ 2 | GET http://b.fr
+   | ^ the file exceeds the maximum number of 1 entries
+   |"#
+        );
+    }
 }
@@ -15,36 +15,251 @@
  * limitations under the License.
  *
  */
-use crate::ast::{Expr, ExprKind, SourceInfo, Variable};
+use crate::ast::{
+    BinaryExpr, BinaryOperator, Expr, ExprKind, FilteredExpr, FunctionArgument, FunctionCall,
+    FunctionName, IfExpr, Number, ParenthesizedExpr, SourceInfo, Variable,
+};
+use crate::combinator::ParseError as ParseErrorTrait;
+use crate::parser::filter::filters;
+use crate::parser::number::number;
+use crate::parser::primitives::{literal, one_or_more_spaces, try_literal, zero_or_more_spaces};
+use crate::parser::string::quoted_template;
 use crate::parser::{ParseError, ParseErrorKind, ParseResult, function};
-use crate::reader::Reader;
+use crate::reader::{Pos, Reader};
+use crate::types::ToSource;
+
+const COMPARISON_OPERATORS: [BinaryOperator; 6] = [
+    BinaryOperator::Equal,
+    BinaryOperator::NotEqual,
+    BinaryOperator::LessOrEqual,
+    BinaryOperator::GreaterOrEqual,
+    BinaryOperator::Less,
+    BinaryOperator::Greater,
+];
+
+const ADDITIVE_OPERATORS: [BinaryOperator; 2] = [BinaryOperator::Add, BinaryOperator::Subtract];
+
+const MULTIPLICATIVE_OPERATORS: [BinaryOperator; 3] = [
+    BinaryOperator::Multiply,
+    BinaryOperator::Divide,
+    BinaryOperator::Modulo,
+];
 
 /// Parse an expression
 ///
-/// Currently, an expression can only be found inside a placeholder
+/// Currently, an expression can only be found inside a placeholder.
+/// Binary operators must be surrounded by spaces, as `-` is a valid character of a variable name.
 pub fn parse(reader: &mut Reader) -> ParseResult<Expr> {
+    let mut expr = expr(reader)?;
+    bare_number_as_variable(&mut expr);
+    Ok(expr)
+}
+
+/// A placeholder made of a single number is the name of a variable, so that `{{123}}` is still
+/// the value of the variable `123`. Numbers are literals everywhere else, like in `{{1 + count}}`.
+fn bare_number_as_variable(expr: &mut Expr) {
+    if let ExprKind::Number(number) = &expr.kind {
+        let name = number.to_source().to_string();
+        let source_info = expr.source_info;
+        expr.kind = ExprKind::Variable(Variable { name, source_info });
+    }
+}
+
+fn expr(reader: &mut Reader) -> ParseResult<Expr> {
+    let start = reader.cursor();
+    if try_keyword("if", reader) && matches!(reader.peek(), Some(' ') | Some('\t')) {
+        return if_expr(reader, start.pos);
+    }
+    reader.seek(start);
+    comparison_expr(reader)
+}
+
+/// Parses the rest of an `if` expression, `start` being the position of the `if` keyword.
+fn if_expr(reader: &mut Reader, start: Pos) -> ParseResult<Expr> {
+    let space0 = one_or_more_spaces(reader)?;
+    let condition = comparison_expr(reader).map_err(|e| e.to_non_recoverable())?;
+    let space1 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    keyword("then", reader)?;
+    let space2 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let then_expr = expr(reader).map_err(|e| e.to_non_recoverable())?;
+    let space3 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    keyword("else", reader)?;
+    let space4 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let else_expr = expr(reader).map_err(|e| e.to_non_recoverable())?;
+    let kind = ExprKind::If(Box::new(IfExpr {
+        space0,
+        condition,
+        space1,
+        space2,
+        then_expr,
+        space3,
+        space4,
+        else_expr,
+    }));
+    let source_info = SourceInfo::new(start, reader.cursor().pos);
+    Ok(Expr { source_info, kind })
+}
+
+fn comparison_expr(reader: &mut Reader) -> ParseResult<Expr> {
+    binary_expr(reader, &COMPARISON_OPERATORS, additive_expr)
+}
+
+fn additive_expr(reader: &mut Reader) -> ParseResult<Expr> {
+    binary_expr(reader, &ADDITIVE_OPERATORS, multiplicative_expr)
+}
+
+fn multiplicative_expr(reader: &mut Reader) -> ParseResult<Expr> {
+    binary_expr(reader, &MULTIPLICATIVE_OPERATORS, primary_expr)
+}
+
+/// Parses a left-associative chain of binary `operators`, each operand being parsed by `operand`.
+fn binary_expr(
+    reader: &mut Reader,
+    operators: &[BinaryOperator],
+    operand: fn(&mut Reader) -> ParseResult<Expr>,
+) -> ParseResult<Expr> {
     let start = reader.cursor().pos;
+    let mut left = operand(reader)?;
+    loop {
+        let save = reader.cursor();
+        let Ok(space0) = one_or_more_spaces(reader) else {
+            reader.seek(save);
+            break;
+        };
+        let Some(operator) = operators
+            .iter()
+            .find(|op| try_literal(op.symbol(), reader).is_ok())
+        else {
+            reader.seek(save);
+            break;
+        };
+        let space1 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+        let right = operand(reader).map_err(|e| e.to_non_recoverable())?;
+        let kind = ExprKind::Binary(Box::new(BinaryExpr {
+            left,
+            space0,
+            operator: *operator,
+            space1,
+            right,
+        }));
+        let source_info = SourceInfo::new(start, reader.cursor().pos);
+        left = Expr { source_info, kind };
+    }
+    Ok(left)
+}
+
+fn primary_expr(reader: &mut Reader) -> ParseResult<Expr> {
+    let start = reader.cursor();
+    let kind = match reader.peek() {
+        Some('(') => parenthesized_expr(reader)?,
+        Some('"') => ExprKind::String(Box::new(quoted_template(reader)?)),
+        _ => match number_literal(reader) {
+            Some(number) => ExprKind::Number(number),
+            None => {
+                let word = reader.read_while(is_variable_char);
+                match word.as_str() {
+                    "true" => ExprKind::Bool(true),
+                    "false" => ExprKind::Bool(false),
+                    "null" => ExprKind::Null,
+                    _ if !word.is_empty() && reader.peek() == Some('(') => {
+                        ExprKind::Call(function_call(reader, &word)?)
+                    }
+                    _ => {
+                        reader.seek(start);
+                        function_or_variable(reader)?
+                    }
+                }
+            }
+        },
+    };
+    let source_info = SourceInfo::new(start.pos, reader.cursor().pos);
+    let expr = Expr { source_info, kind };
+
+    let filters_start = reader.cursor();
+    let filters = filters(reader)?;
+    if filters.is_empty() {
+        return Ok(expr);
+    }
+    let source = reader.read_from(filters_start.index).to_source();
+    let kind = ExprKind::Filtered(Box::new(FilteredExpr {
+        expr,
+        filters,
+        source,
+    }));
+    let source_info = SourceInfo::new(start.pos, reader.cursor().pos);
+    Ok(Expr { source_info, kind })
+}
+
+fn parenthesized_expr(reader: &mut Reader) -> ParseResult<ExprKind> {
+    literal("(", reader)?;
+    let space0 = zero_or_more_spaces(reader)?;
+    let expr = expr(reader).map_err(|e| e.to_non_recoverable())?;
+    let space1 = zero_or_more_spaces(reader)?;
+    literal(")", reader)?;
+    Ok(ExprKind::Parenthesized(Box::new(ParenthesizedExpr {
+        space0,
+        expr,
+        space1,
+    })))
+}
+
+/// Parses the arguments of a call to the function `name`, like `(1, 100)`.
+fn function_call(reader: &mut Reader, name: &str) -> ParseResult<FunctionCall> {
+    literal("(", reader)?;
+    let mut arguments = vec![];
+    if reader.peek() != Some(')') {
+        loop {
+            let space0 = zero_or_more_spaces(reader)?;
+            let expr = expr(reader).map_err(|e| e.to_non_recoverable())?;
+            let space1 = zero_or_more_spaces(reader)?;
+            arguments.push(FunctionArgument {
+                space0,
+                expr,
+                space1,
+            });
+            if try_literal(",", reader).is_err() {
+                break;
+            }
+        }
+    }
+    literal(")", reader)?;
+    Ok(FunctionCall {
+        name: FunctionName::new(name),
+        arguments,
+    })
+}
+
+/// Parses a number, unless it's the beginning of a variable name like `1st_id`.
+fn number_literal(reader: &mut Reader) -> Option<Number> {
+    let save = reader.cursor();
+    match number(reader) {
+        Ok(number) if !reader.peek().is_some_and(is_variable_char) => Some(number),
+        _ => {
+            reader.seek(save);
+            None
+        }
+    }
+}
+
+fn function_or_variable(reader: &mut Reader) -> ParseResult<ExprKind> {
     let save_state = reader.cursor();
-    let kind = match function::parse(reader) {
-        Ok(function) => ExprKind::Function(function),
+    match function::parse(reader) {
+        Ok(function) => Ok(ExprKind::Function(function)),
         Err(e) => {
             if e.recoverable {
                 reader.seek(save_state);
                 let variable = variable_name(reader)?;
-                ExprKind::Variable(variable)
+                Ok(ExprKind::Variable(variable))
             } else {
-                return Err(e);
+                Err(e)
             }
         }
-    };
-    let end = reader.cursor().pos;
-    let source_info = SourceInfo::new(start, end);
-    Ok(Expr { source_info, kind })
+    }
 }
 
 fn variable_name(reader: &mut Reader) -> ParseResult<Variable> {
     let start = reader.cursor();
-    let name = reader.read_while(|c| c.is_alphanumeric() || c == '_' || c == '-');
+    let name = reader.read_while(is_variable_char);
     if name.is_empty() {
         return Err(ParseError::new(
             start.pos,
@@ -58,6 +273,29 @@ fn variable_name(reader: &mut Reader) -> ParseResult<Variable> {
     })
 }
 
+fn is_variable_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-'
+}
+
+/// Tries to parse the word `keyword`, returns `true` on success.
+///
+/// The reader is not rewound on failure.
+fn try_keyword(keyword: &str, reader: &mut Reader) -> bool {
+    reader.read_while(is_variable_char) == keyword
+}
+
+fn keyword(keyword: &str, reader: &mut Reader) -> ParseResult<()> {
+    let start = reader.cursor();
+    if try_keyword(keyword, reader) {
+        Ok(())
+    } else {
+        let kind = ParseErrorKind::Expecting {
+            value: keyword.to_string(),
+        };
+        Err(ParseError::new(start.pos, false, kind))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -83,4 +321,212 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_literals() {
+        let mut reader = Reader::new("true");
+        assert_eq!(parse(&mut reader).unwrap().kind, ExprKind::Bool(true));
+
+        let mut reader = Reader::new("null");
+        assert_eq!(parse(&mut reader).unwrap().kind, ExprKind::Null);
+
+        let mut reader = Reader::new("-1.5");
+        let expr = expr(&mut reader).unwrap();
+        assert!(matches!(expr.kind, ExprKind::Number(Number::Float(_))));
+        assert_eq!(expr.to_source().as_str(), "-1.5");
+
+        let mut reader = Reader::new("\"Hello\"");
+        let expr = parse(&mut reader).unwrap();
+        assert!(matches!(expr.kind, ExprKind::String(_)));
+        assert_eq!(expr.to_source().as_str(), "\"Hello\"");
+
+        // A bare number is a variable name, numbers are literals everywhere else.
+        let mut reader = Reader::new("123");
+        assert!(matches!(
+            parse(&mut reader).unwrap().kind,
+            ExprKind::Variable(Variable { name, .. }) if name == "123"
+        ));
+        let mut reader = Reader::new("1 + count");
+        let ExprKind::Binary(binary) = parse(&mut reader).unwrap().kind else {
+            panic!("expecting a binary expression");
+        };
+        assert!(matches!(
+            binary.left.kind,
+            ExprKind::Number(Number::Integer(_))
+        ));
+
+        // Variable names can start with a digit or a keyword.
+        let mut reader = Reader::new("1st_id");
+        assert!(matches!(
+            parse(&mut reader).unwrap().kind,
+            ExprKind::Variable(Variable { name, .. }) if name == "1st_id"
+        ));
+        let mut reader = Reader::new("true_value");
+        assert!(matches!(
+            parse(&mut reader).unwrap().kind,
+            ExprKind::Variable(Variable { name, .. }) if name == "true_value"
+        ));
+        let mut reader = Reader::new("if");
+        assert!(matches!(
+            parse(&mut reader).unwrap().kind,
+            ExprKind::Variable(Variable { name, .. }) if name == "if"
+        ));
+    }
+
+    #[test]
+    fn test_binary_expr() {
+        let mut reader = Reader::new("count + 1 * 2");
+        let expr = parse(&mut reader).unwrap();
+        assert_eq!(
+            expr.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 14))
+        );
+        let ExprKind::Binary(binary) = expr.kind else {
+            panic!("expecting a binary expression");
+        };
+        assert_eq!(binary.operator, BinaryOperator::Add);
+        assert_eq!(binary.left.to_source().as_str(), "count");
+        assert!(matches!(
+            &binary.right.kind,
+            ExprKind::Binary(right) if right.operator == BinaryOperator::Multiply
+        ));
+
+        let mut reader = Reader::new("(count - 1) <= max");
+        let expr = parse(&mut reader).unwrap();
+        assert!(matches!(
+            &expr.kind,
+            ExprKind::Binary(binary) if binary.operator == BinaryOperator::LessOrEqual
+        ));
+        assert_eq!(expr.to_source().as_str(), "(count - 1) <= max");
+
+        // Without spaces, `-` is part of the variable name.
+        let mut reader = Reader::new("count-1");
+        assert!(matches!(
+            parse(&mut reader).unwrap().kind,
+            ExprKind::Variable(Variable { name, .. }) if name == "count-1"
+        ));
+
+        // Trailing spaces are left to the placeholder.
+        let mut reader = Reader::new("count  }}");
+        let expr = parse(&mut reader).unwrap();
+        assert_eq!(expr.to_source().as_str(), "count");
+        assert_eq!(reader.cursor().pos, Pos::new(1, 6));
+    }
+
+    #[test]
+    fn test_binary_expr_error() {
+        let mut reader = Reader::new("count +1");
+        let error = parse(&mut reader).unwrap_err();
+        assert_eq!(error.pos, Pos::new(1, 8));
+        assert_eq!(error.kind, ParseErrorKind::Space);
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_function_call() {
+        let mut reader = Reader::new("uuidv7()");
+        let expr = parse(&mut reader).unwrap();
+        assert_eq!(
+            expr.kind,
+            ExprKind::Call(FunctionCall {
+                name: FunctionName::Uuidv7,
+                arguments: vec![],
+            })
+        );
+        assert_eq!(reader.cursor().pos, Pos::new(1, 9));
+
+        let mut reader = Reader::new("randomInt(1, max - 1)");
+        let expr = parse(&mut reader).unwrap();
+        let ExprKind::Call(call) = &expr.kind else {
+            panic!("expecting a function call");
+        };
+        assert_eq!(call.name, FunctionName::RandomInt);
+        assert_eq!(call.arguments.len(), 2);
+        assert_eq!(call.arguments[1].space0.as_str(), " ");
+        assert_eq!(call.arguments[1].expr.to_source().as_str(), "max - 1");
+        assert_eq!(expr.to_source().as_str(), "randomInt(1, max - 1)");
+
+        let mut reader = Reader::new("slugify( title )");
+        let expr = parse(&mut reader).unwrap();
+        let ExprKind::Call(call) = &expr.kind else {
+            panic!("expecting a function call");
+        };
+        assert_eq!(call.name, FunctionName::Custom("slugify".to_string()));
+        assert_eq!(expr.to_source().as_str(), "slugify( title )");
+
+        // Without parentheses, `now` is a variable.
+        let mut reader = Reader::new("now");
+        assert!(matches!(
+            parse(&mut reader).unwrap().kind,
+            ExprKind::Variable(Variable { name, .. }) if name == "now"
+        ));
+    }
+
+    #[test]
+    fn test_function_call_error() {
+        let mut reader = Reader::new("randomInt(1, 2");
+        let error = parse(&mut reader).unwrap_err();
+        assert_eq!(error.pos, Pos::new(1, 15));
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expecting {
+                value: ")".to_string()
+            }
+        );
+        assert!(!error.recoverable);
+
+        let mut reader = Reader::new("randomInt(1,)");
+        let error = parse(&mut reader).unwrap_err();
+        assert_eq!(error.pos, Pos::new(1, 13));
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_filtered_expr() {
+        let mut reader = Reader::new("user jsonpath \"$.name\" }}");
+        let expr = parse(&mut reader).unwrap();
+        let ExprKind::Filtered(filtered) = &expr.kind else {
+            panic!("expecting a filtered expression");
+        };
+        assert_eq!(filtered.expr.to_source().as_str(), "user");
+        assert_eq!(filtered.filters.len(), 1);
+        assert_eq!(expr.to_source().as_str(), "user jsonpath \"$.name\"");
+        assert_eq!(reader.cursor().pos, Pos::new(1, 23));
+
+        let mut reader = Reader::new("index toInt + 1");
+        let expr = parse(&mut reader).unwrap();
+        let ExprKind::Binary(binary) = &expr.kind else {
+            panic!("expecting a binary expression");
+        };
+        assert!(matches!(binary.left.kind, ExprKind::Filtered { .. }));
+        assert_eq!(expr.to_source().as_str(), "index toInt + 1");
+    }
+
+    #[test]
+    fn test_if_expr() {
+        let mut reader = Reader::new("if count > 1 then \"items\" else \"item\"");
+        let expr = parse(&mut reader).unwrap();
+        assert!(reader.is_eof());
+        let ExprKind::If(if_expr) = &expr.kind else {
+            panic!("expecting an if expression");
+        };
+        assert_eq!(if_expr.condition.to_source().as_str(), "count > 1");
+        assert_eq!(if_expr.then_expr.to_source().as_str(), "\"items\"");
+        assert_eq!(if_expr.else_expr.to_source().as_str(), "\"item\"");
+        assert_eq!(
+            expr.to_source().as_str(),
+            "if count > 1 then \"items\" else \"item\""
+        );
+
+        let mut reader = Reader::new("if count > 1 \"items\"");
+        let error = parse(&mut reader).unwrap_err();
+        assert_eq!(error.pos, Pos::new(1, 14));
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expecting {
+                value: "then".to_string()
+            }
+        );
+        assert!(!error.recoverable);
+    }
 }
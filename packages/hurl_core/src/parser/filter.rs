@@ -15,8 +15,8 @@
  * limitations under the License.
  *
  */
-use crate::ast::{Filter, FilterValue, IntegerValue, SourceInfo, Whitespace};
-use crate::combinator::{ParseError as ParseErrorTrait, choice};
+use crate::ast::{Filter, FilterValue, HexCase, IntegerValue, SourceInfo, Whitespace};
+use crate::combinator::{ParseError as ParseErrorTrait, choice, optional};
 use crate::parser::number::integer;
 use crate::parser::primitives::{one_or_more_spaces, try_literal, zero_or_more_spaces};
 use crate::parser::query::regex_value;
@@ -53,10 +53,15 @@ pub fn filter(reader: &mut Reader) -> ParseResult<Filter> {
     let start = reader.cursor();
     let value = choice(
         &[
+            add_days_filter,
+            add_seconds_filter,
             base64_decode_filter,
             base64_encode_filter,
             base64_url_safe_decode_filter,
             base64_url_safe_encode_filter,
+            brotli_decompress_filter,
+            bytes_slice_filter,
+            canonicalize_filter,
             charset_decode_filter,
             count_filter,
             days_after_now_filter,
@@ -65,12 +70,18 @@ pub fn filter(reader: &mut Reader) -> ParseResult<Filter> {
             first_filter,
             format_filter,
             date_format_filter,
+            from_hex_filter,
+            gunzip_filter,
             html_decode_filter,
             html_encode_filter,
+            jq_filter,
             jsonpath_filter,
+            jwt_header_filter,
+            jwt_payload_filter,
             last_filter,
             location_filter,
             nth_filter,
+            parse_json_filter,
             regex_filter,
             replace_regex_filter,
             replace_filter,
@@ -79,13 +90,18 @@ pub fn filter(reader: &mut Reader) -> ParseResult<Filter> {
             to_float_filter,
             to_hex_filter,
             to_int_filter,
+            to_json_filter,
             to_string_filter,
+            to_timestamp_filter,
             url_decode_filter,
             url_encode_filter,
             url_query_param_filter,
             utf8_decode_filter,
             utf8_encode_filter,
+            xml_escape_filter,
+            xml_unescape_filter,
             xpath_filter,
+            zstd_decompress_filter,
         ],
         reader,
     )
@@ -107,6 +123,20 @@ pub fn filter(reader: &mut Reader) -> ParseResult<Filter> {
     Ok(Filter { source_info, value })
 }
 
+fn add_days_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("addDays", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let days = integer_value(reader)?;
+    Ok(FilterValue::AddDays { space0, days })
+}
+
+fn add_seconds_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("addSeconds", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let seconds = integer_value(reader)?;
+    Ok(FilterValue::AddSeconds { space0, seconds })
+}
+
 fn base64_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("base64Decode", reader)?;
     Ok(FilterValue::Base64Decode)
@@ -127,6 +157,30 @@ fn base64_url_safe_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue
     Ok(FilterValue::Base64UrlSafeEncode)
 }
 
+fn brotli_decompress_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("brotliDecompress", reader)?;
+    Ok(FilterValue::BrotliDecompress)
+}
+
+fn bytes_slice_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("bytesSlice", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let start = integer_value(reader)?;
+    let space1 = one_or_more_spaces(reader)?;
+    let end = integer_value(reader)?;
+    Ok(FilterValue::BytesSlice {
+        space0,
+        start,
+        space1,
+        end,
+    })
+}
+
+fn canonicalize_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("canonicalize", reader)?;
+    Ok(FilterValue::Canonicalize)
+}
+
 fn count_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("count", reader)?;
     Ok(FilterValue::Count)
@@ -175,6 +229,16 @@ fn date_format_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::DateFormat { space0, fmt })
 }
 
+fn from_hex_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("fromHex", reader)?;
+    Ok(FilterValue::FromHex)
+}
+
+fn gunzip_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("gunzip", reader)?;
+    Ok(FilterValue::Gunzip)
+}
+
 fn html_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("htmlEscape", reader)?;
     Ok(FilterValue::HtmlEscape)
@@ -185,6 +249,13 @@ fn html_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::HtmlUnescape)
 }
 
+fn jq_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("jq", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let expr = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::Jq { space0, expr })
+}
+
 fn jsonpath_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("jsonpath", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -192,6 +263,16 @@ fn jsonpath_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::JsonPath { space0, expr })
 }
 
+fn jwt_header_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("jwtHeader", reader)?;
+    Ok(FilterValue::JwtHeader)
+}
+
+fn jwt_payload_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("jwtPayload", reader)?;
+    Ok(FilterValue::JwtPayload)
+}
+
 fn last_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("last", reader)?;
     Ok(FilterValue::Last)
@@ -209,6 +290,11 @@ fn nth_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::Nth { space0, n })
 }
 
+fn parse_json_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("parseJson", reader)?;
+    Ok(FilterValue::ParseJson)
+}
+
 fn integer_value(reader: &mut Reader) -> ParseResult<IntegerValue> {
     let start = reader.cursor();
     match integer(reader) {
@@ -282,7 +368,37 @@ fn to_float_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
 
 fn to_hex_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("toHex", reader)?;
-    Ok(FilterValue::ToHex)
+    // Case and separator are optional: `toHex`, `toHex upper`, `toHex ":"` or `toHex upper ":"`.
+    let case = optional(
+        |reader| {
+            let space = one_or_more_spaces(reader)?;
+            let case = hex_case(reader)?;
+            Ok((space, case))
+        },
+        reader,
+    )?;
+    let separator = optional(
+        |reader| {
+            let space = one_or_more_spaces(reader)?;
+            let separator = quoted_template(reader)?;
+            Ok((space, separator))
+        },
+        reader,
+    )?;
+    Ok(FilterValue::ToHex { case, separator })
+}
+
+fn hex_case(reader: &mut Reader) -> ParseResult<HexCase> {
+    if try_literal("lower", reader).is_ok() {
+        Ok(HexCase::Lower)
+    } else if try_literal("upper", reader).is_ok() {
+        Ok(HexCase::Upper)
+    } else {
+        let kind = ParseErrorKind::Expecting {
+            value: "lower or upper".to_string(),
+        };
+        Err(ParseError::new(reader.cursor().pos, true, kind))
+    }
 }
 
 fn to_int_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
@@ -290,11 +406,21 @@ fn to_int_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::ToInt)
 }
 
+fn to_json_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("toJson", reader)?;
+    Ok(FilterValue::ToJson)
+}
+
 fn to_string_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("toString", reader)?;
     Ok(FilterValue::ToString)
 }
 
+fn to_timestamp_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("toTimestamp", reader)?;
+    Ok(FilterValue::ToTimestamp)
+}
+
 fn url_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("urlEncode", reader)?;
     Ok(FilterValue::UrlEncode)
@@ -322,6 +448,16 @@ fn utf8_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::Utf8Encode)
 }
 
+fn xml_escape_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("xmlEscape", reader)?;
+    Ok(FilterValue::XmlEscape)
+}
+
+fn xml_unescape_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("xmlUnescape", reader)?;
+    Ok(FilterValue::XmlUnescape)
+}
+
 fn xpath_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("xpath", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -329,11 +465,18 @@ fn xpath_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::XPath { space0, expr })
 }
 
+fn zstd_decompress_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("zstdDecompress", reader)?;
+    Ok(FilterValue::ZstdDecompress)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ast::{I64, Template, TemplateElement};
     use crate::parser::ParseErrorKind;
     use crate::reader::Pos;
+    use crate::types::ToSource;
 
     #[test]
     fn test_count() {
@@ -347,6 +490,108 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_add_days() {
+        let mut reader = Reader::new("addDays -7");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 11)),
+                value: FilterValue::AddDays {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 9)),
+                    },
+                    days: IntegerValue::Literal(I64::new(-7, "-7".to_source())),
+                },
+            }
+        );
+
+        let mut reader = Reader::new("addSeconds {{ttl}}");
+        let filter = filter(&mut reader).unwrap();
+        assert!(matches!(
+            filter.value,
+            FilterValue::AddSeconds {
+                seconds: IntegerValue::Placeholder(_),
+                ..
+            }
+        ));
+        assert!(reader.is_eof());
+    }
+
+    #[test]
+    fn test_bytes_slice() {
+        let mut reader = Reader::new("bytesSlice 2 -1");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 16)),
+                value: FilterValue::BytesSlice {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(1, 11), Pos::new(1, 12)),
+                    },
+                    start: IntegerValue::Literal(I64::new(2, "2".to_source())),
+                    space1: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(1, 13), Pos::new(1, 14)),
+                    },
+                    end: IntegerValue::Literal(I64::new(-1, "-1".to_source())),
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_to_hex() {
+        let mut reader = Reader::new("toHex count");
+        assert_eq!(
+            filter(&mut reader).unwrap().value,
+            FilterValue::ToHex {
+                case: None,
+                separator: None,
+            }
+        );
+        assert_eq!(reader.cursor().pos, Pos::new(1, 6));
+
+        let mut reader = Reader::new("toHex upper \":\"");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 16)),
+                value: FilterValue::ToHex {
+                    case: Some((
+                        Whitespace {
+                            value: " ".to_string(),
+                            source_info: SourceInfo::new(Pos::new(1, 6), Pos::new(1, 7)),
+                        },
+                        HexCase::Upper,
+                    )),
+                    separator: Some((
+                        Whitespace {
+                            value: " ".to_string(),
+                            source_info: SourceInfo::new(Pos::new(1, 12), Pos::new(1, 13)),
+                        },
+                        Template::new(
+                            Some('"'),
+                            vec![TemplateElement::String {
+                                value: ":".to_string(),
+                                source: ":".to_source(),
+                            }],
+                            SourceInfo::new(Pos::new(1, 13), Pos::new(1, 16)),
+                        ),
+                    )),
+                },
+            }
+        );
+
+        let mut reader = Reader::new("toHex \" \" == \"ab cd\"");
+        assert_eq!(
+            filter(&mut reader).unwrap().source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10))
+        );
+    }
+
     #[test]
     fn test_error() {
         let mut reader = Reader::new("xcount");
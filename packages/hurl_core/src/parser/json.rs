@@ -276,7 +276,12 @@ fn expression_value(reader: &mut Reader) -> ParseResult<JsonValue> {
 }
 
 fn list_value(reader: &mut Reader) -> ParseResult<JsonValue> {
+    let start = reader.cursor();
     try_literal("[", reader)?;
+    nested(start.pos, reader, list_value_content)
+}
+
+fn list_value_content(reader: &mut Reader) -> ParseResult<JsonValue> {
     let space0 = whitespace(reader);
     let mut elements = vec![];
 
@@ -325,7 +330,12 @@ fn list_element(reader: &mut Reader) -> ParseResult<JsonListElement> {
 }
 
 pub fn object_value(reader: &mut Reader) -> ParseResult<JsonValue> {
+    let start = reader.cursor();
     try_literal("{", reader)?;
+    nested(start.pos, reader, object_value_content)
+}
+
+fn object_value_content(reader: &mut Reader) -> ParseResult<JsonValue> {
     let space0 = whitespace(reader);
     let mut elements = vec![];
     if reader.peek() != Some('}') {
@@ -363,6 +373,26 @@ pub fn object_value(reader: &mut Reader) -> ParseResult<JsonValue> {
     Ok(JsonValue::Object { space0, elements })
 }
 
+/// Parses the content of a JSON array or object with the function `f`, one level deeper than the
+/// current nesting depth. Fails if the maximum nesting depth of the parser limits is exceeded,
+/// `pos` being the position of the opening bracket.
+fn nested(
+    pos: Pos,
+    reader: &mut Reader,
+    f: fn(&mut Reader) -> ParseResult<JsonValue>,
+) -> ParseResult<JsonValue> {
+    let depth = reader.depth();
+    let max = reader.limits().max_nesting;
+    if depth >= max {
+        let kind = ParseErrorKind::NestingTooDeep { max };
+        return Err(ParseError::new(pos, false, kind));
+    }
+    reader.set_depth(depth + 1);
+    let result = f(reader);
+    reader.set_depth(depth);
+    result
+}
+
 fn key(reader: &mut Reader) -> ParseResult<Template> {
     let name = string_template(reader).map_err(|e| e.to_non_recoverable())?;
     Ok(name)
@@ -409,6 +439,7 @@ fn whitespace(reader: &mut Reader) -> String {
 mod tests {
     use super::*;
     use crate::ast::*;
+    use crate::parser::ParserLimits;
     use crate::reader::CharPos;
     use crate::types::ToSource;
 
@@ -895,6 +926,44 @@ mod tests {
         assert!(!error.recoverable);
     }
 
+    #[test]
+    fn test_nesting_too_deep() {
+        let limits = ParserLimits {
+            max_nesting: 2,
+            ..ParserLimits::default()
+        };
+
+        let mut reader = Reader::new("[{\"a\": 1}, [2]]");
+        reader.set_limits(limits);
+        assert!(parse(&mut reader).is_ok());
+        assert_eq!(reader.depth(), 0);
+
+        let mut reader = Reader::new("[1, {\"a\": [2]}]");
+        reader.set_limits(limits);
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 11
+            }
+        );
+        assert_eq!(error.kind, ParseErrorKind::NestingTooDeep { max: 2 });
+        assert!(!error.recoverable);
+
+        // The default limits don't overflow the stack on deeply nested values.
+        let mut reader = Reader::new(&"[".repeat(100_000));
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 129
+            }
+        );
+        assert_eq!(error.kind, ParseErrorKind::NestingTooDeep { max: 128 });
+    }
+
     #[test]
     fn test_list_element() {
         let mut reader = Reader::new("true");
@@ -0,0 +1,58 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+/// Default maximum size of a Hurl file, in bytes.
+pub const DEFAULT_MAX_FILE_SIZE: usize = 100_000_000;
+
+/// Default maximum number of entries of a Hurl file.
+pub const DEFAULT_MAX_ENTRIES: usize = 100_000;
+
+/// Default maximum size of a body, in characters.
+pub const DEFAULT_MAX_BODY_SIZE: usize = 10_000_000;
+
+/// Default maximum nesting depth of JSON objects and arrays (the same recursion limit as
+/// `serde_json`).
+pub const DEFAULT_MAX_NESTING: usize = 128;
+
+/// Limits of the Hurl parser.
+///
+/// When the parser is given an input that is not really a Hurl file (like a large JSON file with a
+/// `.hurl` extension), limits make the parsing fail with a clear error, instead of using an
+/// unbounded amount of memory or overflowing the stack.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParserLimits {
+    /// Maximum size of a Hurl file, in bytes, checked before the file is parsed.
+    pub max_file_size: usize,
+    /// Maximum number of entries of a Hurl file.
+    pub max_entries: usize,
+    /// Maximum size of a request or response body, in characters.
+    pub max_body_size: usize,
+    /// Maximum nesting depth of JSON objects and arrays.
+    pub max_nesting: usize,
+}
+
+impl Default for ParserLimits {
+    fn default() -> Self {
+        ParserLimits {
+            max_file_size: DEFAULT_MAX_FILE_SIZE,
+            max_entries: DEFAULT_MAX_ENTRIES,
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+            max_nesting: DEFAULT_MAX_NESTING,
+        }
+    }
+}
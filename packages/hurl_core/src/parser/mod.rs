@@ -17,8 +17,22 @@
  */
 pub type ParseResult<T> = Result<T, ParseError>;
 
+/// Parses a Hurl file content `s`, with the default parser limits.
 pub fn parse_hurl_file(s: &str) -> ParseResult<HurlFile> {
+    parse_hurl_file_with_limits(s, ParserLimits::default())
+}
+
+/// Parses a Hurl file content `s`, failing if one of the parser `limits` is exceeded.
+///
+/// The size of the file is checked first, so that a file too large is not loaded in the reader.
+pub fn parse_hurl_file_with_limits(s: &str, limits: ParserLimits) -> ParseResult<HurlFile> {
+    let max = limits.max_file_size;
+    if s.len() > max {
+        let kind = ParseErrorKind::FileTooLarge { max };
+        return Err(ParseError::new(Pos::new(1, 1), false, kind));
+    }
     let mut reader = Reader::new(s);
+    reader.set_limits(limits);
     parsers::hurl_file(&mut reader)
 }
 
@@ -27,9 +41,10 @@ pub use self::json::{
     boolean_value as parse_json_boolean, null_value as parse_json_null,
     number_value as parse_json_number, parse as parse_json,
 };
-pub use self::template::templatize;
+pub use self::limits::ParserLimits;
+pub use self::template::{EncodedString, templatize};
 use crate::ast::HurlFile;
-use crate::reader::Reader;
+use crate::reader::{Pos, Reader};
 
 mod base64;
 mod bytes;
@@ -43,6 +58,7 @@ mod filter;
 mod function;
 mod json;
 mod key_string;
+mod limits;
 mod multiline;
 mod number;
 mod option;
@@ -17,8 +17,9 @@
  */
 use super::placeholder;
 use crate::ast::{
-    BooleanOption, CountOption, DurationOption, EntryOption, NaturalOption, OptionKind, SourceInfo,
-    VariableDefinition, VariableValue, VerbosityOption, is_variable_reserved,
+    AuthTypeOption, BooleanOption, ClientCertTypeOption, CountOption, DurationOption, EntryOption,
+    NaturalOption, OptionKind, SourceInfo, VariableDefinition, VariableValue, VerbosityOption,
+    is_variable_reserved,
 };
 use crate::combinator::{choice, non_recover};
 use crate::parser::duration::duration;
@@ -46,21 +47,33 @@ pub fn parse(reader: &mut Reader) -> ParseResult<EntryOption> {
     try_literal(":", reader)?;
     let space2 = zero_or_more_spaces(reader)?;
     let kind = match option.as_str() {
+        "auth-type" => option_auth_type(reader)?,
         "aws-sigv4" => option_aws_sigv4(reader)?,
         "cacert" => option_cacert(reader)?,
         "cert" => option_cert(reader)?,
+        "chunked" => option_chunked(reader)?,
+        "client-cert-password" => option_client_cert_password(reader)?,
+        "client-cert-type" => option_client_cert_type(reader)?,
+        "clock-skew" => option_clock_skew(reader)?,
         "compressed" => option_compressed(reader)?,
         "connect-to" => option_connect_to(reader)?,
         "connect-timeout" => option_connect_timeout(reader)?,
+        "cookie-file" => option_cookie_file(reader)?,
         "delay" => option_delay(reader)?,
         "digest" => option_digest(reader)?,
+        "dns-servers" => option_dns_servers(reader)?,
+        "expect-continue" => option_expect_continue(reader)?,
+        "export-capture" => option_export_capture(reader)?,
         "fail-with-body" => option_fail_with_boddy(reader)?,
+        "foreach" => option_foreach(reader)?,
         "insecure" => option_insecure(reader)?,
         "header" => option_header(reader)?,
+        "hmac-signature" => option_hmac_signature(reader)?,
         "http1.0" => option_http_10(reader)?,
         "http1.1" => option_http_11(reader)?,
         "http2" => option_http_2(reader)?,
         "http3" => option_http_3(reader)?,
+        "http3-fallback" => option_http_3_fallback(reader)?,
         "ipv4" => option_ipv4(reader)?,
         "ipv6" => option_ipv6(reader)?,
         "key" => option_key(reader)?,
@@ -69,21 +82,35 @@ pub fn parse(reader: &mut Reader) -> ParseResult<EntryOption> {
         "location-trusted" => option_follow_location_trusted(reader)?,
         "max-redirs" => option_max_redirect(reader)?,
         "max-time" => option_max_time(reader)?,
+        "multipart-boundary" => option_multipart_boundary(reader)?,
         "negotiate" => option_negotiate(reader)?,
         "netrc" => option_netrc(reader)?,
         "netrc-file" => option_netrc_file(reader)?,
         "netrc-optional" => option_netrc_optional(reader)?,
         "no-header" => option_no_header(reader)?,
         "ntlm" => option_ntlm(reader)?,
+        "oauth2-client-id" => option_oauth2_client_id(reader)?,
+        "oauth2-client-secret" => option_oauth2_client_secret(reader)?,
+        "oauth2-token-url" => option_oauth2_token_url(reader)?,
+        "only-if" => option_only_if(reader)?,
         "output" => option_output(reader)?,
+        "paginate" => option_paginate(reader)?,
+        "paginate-max" => option_paginate_max(reader)?,
         "path-as-is" => option_path_as_is(reader)?,
         "pinnedpubkey" => option_pinned_pub_key(reader)?,
         "proxy" => option_proxy(reader)?,
+        "proxy-header" => option_proxy_header(reader)?,
+        "proxy-user" => option_proxy_user(reader)?,
         "repeat" => option_repeat(reader)?,
         "resolve" => option_resolve(reader)?,
         "retry" => option_retry(reader)?,
         "retry-interval" => option_retry_interval(reader)?,
+        "retry-on-capture-failure" => option_retry_on_capture_failure(reader)?,
         "skip" => option_skip(reader)?,
+        "skip-if" => option_skip_if(reader)?,
+        "soft-asserts" => option_soft_asserts(reader)?,
+        "nested-form-params" => option_nested_form_params(reader)?,
+        "trailer" => option_trailer(reader)?,
         "unix-socket" => option_unix_socket(reader)?,
         "user" => option_user(reader)?,
         "variable" => option_variable(reader)?,
@@ -110,6 +137,23 @@ pub fn parse(reader: &mut Reader) -> ParseResult<EntryOption> {
     })
 }
 
+fn option_auth_type(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let start = reader.cursor();
+    let name = reader.read_while(|c| c.is_ascii_alphabetic());
+    match name.as_str() {
+        "digest" => Ok(OptionKind::AuthType(AuthTypeOption::Digest)),
+        "ntlm" => Ok(OptionKind::AuthType(AuthTypeOption::Ntlm)),
+        "negotiate" => Ok(OptionKind::AuthType(AuthTypeOption::Negotiate)),
+        _ => {
+            reader.seek(start);
+            let kind = ParseErrorKind::Expecting {
+                value: "digest|ntlm|negotiate".to_string(),
+            };
+            Err(ParseError::new(start.pos, false, kind))
+        }
+    }
+}
+
 fn option_aws_sigv4(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = unquoted_template(reader)?;
     Ok(OptionKind::AwsSigV4(value))
@@ -125,6 +169,38 @@ fn option_cert(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::ClientCert(value))
 }
 
+fn option_chunked(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::Chunked(value))
+}
+
+fn option_client_cert_password(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::ClientCertPassword(value))
+}
+
+fn option_client_cert_type(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let start = reader.cursor();
+    let name = reader.read_while(|c| c.is_ascii_alphanumeric());
+    match name.as_str() {
+        "PEM" => Ok(OptionKind::ClientCertType(ClientCertTypeOption::Pem)),
+        "DER" => Ok(OptionKind::ClientCertType(ClientCertTypeOption::Der)),
+        "P12" => Ok(OptionKind::ClientCertType(ClientCertTypeOption::P12)),
+        _ => {
+            reader.seek(start);
+            let kind = ParseErrorKind::Expecting {
+                value: "PEM|DER|P12".to_string(),
+            };
+            Err(ParseError::new(start.pos, false, kind))
+        }
+    }
+}
+
+fn option_clock_skew(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(duration_option, reader)?;
+    Ok(OptionKind::ClockSkew(value))
+}
+
 fn option_compressed(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(boolean_option, reader)?;
     Ok(OptionKind::Compressed(value))
@@ -140,6 +216,11 @@ fn option_connect_timeout(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::ConnectTimeout(value))
 }
 
+fn option_cookie_file(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = filename::parse(reader)?;
+    Ok(OptionKind::CookieFile(value))
+}
+
 fn option_delay(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = duration_option(reader)?;
     Ok(OptionKind::Delay(value))
@@ -150,11 +231,31 @@ fn option_digest(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::Digest(value))
 }
 
+fn option_dns_servers(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::DnsServers(value))
+}
+
+fn option_expect_continue(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::ExpectContinue(value))
+}
+
+fn option_export_capture(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = variable_name(reader)?;
+    Ok(OptionKind::ExportCapture(value))
+}
+
 fn option_fail_with_boddy(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(boolean_option, reader)?;
     Ok(OptionKind::FailWithBody(value))
 }
 
+fn option_foreach(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(placeholder::parse, reader)?;
+    Ok(OptionKind::Foreach(value))
+}
+
 fn option_follow_location(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(boolean_option, reader)?;
     Ok(OptionKind::FollowLocation(value))
@@ -170,6 +271,11 @@ fn option_header(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::Header(value))
 }
 
+fn option_hmac_signature(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::HmacSignature(value))
+}
+
 fn option_http_10(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(boolean_option, reader)?;
     Ok(OptionKind::Http10(value))
@@ -190,6 +296,11 @@ fn option_http_3(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::Http3(value))
 }
 
+fn option_http_3_fallback(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::Http3Fallback(value))
+}
+
 fn option_insecure(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(boolean_option, reader)?;
     Ok(OptionKind::Insecure(value))
@@ -225,6 +336,11 @@ fn option_max_time(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::MaxTime(value))
 }
 
+fn option_multipart_boundary(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::MultipartBoundary(value))
+}
+
 fn option_negotiate(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = boolean_option(reader)?;
     Ok(OptionKind::Negotiate(value))
@@ -255,11 +371,41 @@ fn option_ntlm(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::Ntlm(value))
 }
 
+fn option_oauth2_client_id(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::OAuth2ClientId(value))
+}
+
+fn option_oauth2_client_secret(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::OAuth2ClientSecret(value))
+}
+
+fn option_oauth2_token_url(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::OAuth2TokenUrl(value))
+}
+
+fn option_only_if(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::OnlyIf(value))
+}
+
 fn option_output(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = filename::parse(reader)?;
     Ok(OptionKind::Output(value))
 }
 
+fn option_paginate(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = variable_name(reader)?;
+    Ok(OptionKind::Paginate(value))
+}
+
+fn option_paginate_max(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(count_option, reader)?;
+    Ok(OptionKind::PaginateMax(value))
+}
+
 fn option_path_as_is(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(boolean_option, reader)?;
     Ok(OptionKind::PathAsIs(value))
@@ -275,6 +421,16 @@ fn option_proxy(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::Proxy(value))
 }
 
+fn option_proxy_header(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::ProxyHeader(value))
+}
+
+fn option_proxy_user(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::ProxyUser(value))
+}
+
 fn option_repeat(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(count_option, reader)?;
     Ok(OptionKind::Repeat(value))
@@ -295,11 +451,36 @@ fn option_retry_interval(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::RetryInterval(value))
 }
 
+fn option_retry_on_capture_failure(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::RetryOnCaptureFailure(value))
+}
+
 fn option_skip(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(boolean_option, reader)?;
     Ok(OptionKind::Skip(value))
 }
 
+fn option_skip_if(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::SkipIf(value))
+}
+
+fn option_soft_asserts(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::SoftAsserts(value))
+}
+
+fn option_nested_form_params(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::NestedFormParams(value))
+}
+
+fn option_trailer(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::Trailer(value))
+}
+
 fn option_user(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = unquoted_template(reader)?;
     Ok(OptionKind::User(value))
@@ -690,6 +871,186 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_option_cookie_file() {
+        let mut reader = Reader::new("cookies.txt");
+
+        assert_eq!(
+            option_cookie_file(&mut reader).unwrap(),
+            OptionKind::CookieFile(Template::new(
+                None,
+                vec![TemplateElement::String {
+                    value: "cookies.txt".to_string(),
+                    source: "cookies.txt".to_source()
+                }],
+                SourceInfo {
+                    start: Pos { line: 1, column: 1 },
+                    end: Pos {
+                        line: 1,
+                        column: 12,
+                    },
+                },
+            )),
+        );
+    }
+
+    #[test]
+    fn test_option_hmac_signature() {
+        let mut reader = Reader::new("sha256:X-Signature:secret:{method}\\n{path}");
+
+        assert_eq!(
+            option_hmac_signature(&mut reader).unwrap(),
+            OptionKind::HmacSignature(Template::new(
+                None,
+                vec![TemplateElement::String {
+                    value: "sha256:X-Signature:secret:{method}\n{path}".to_string(),
+                    source: "sha256:X-Signature:secret:{method}\\n{path}".to_source()
+                }],
+                SourceInfo {
+                    start: Pos { line: 1, column: 1 },
+                    end: Pos {
+                        line: 1,
+                        column: 43,
+                    },
+                },
+            )),
+        );
+    }
+
+    #[test]
+    fn test_option_trailer() {
+        let mut reader = Reader::new("X-Checksum: {{checksum}}");
+
+        let option = option_trailer(&mut reader).unwrap();
+        assert_eq!(option.identifier(), "trailer");
+        assert_eq!(option.to_string(), "trailer: X-Checksum: {{checksum}}");
+        assert!(reader.is_eof());
+    }
+
+    #[test]
+    fn test_option_oauth2_token_url() {
+        let mut reader = Reader::new("https://auth.example.org/token");
+
+        assert_eq!(
+            option_oauth2_token_url(&mut reader).unwrap(),
+            OptionKind::OAuth2TokenUrl(Template::new(
+                None,
+                vec![TemplateElement::String {
+                    value: "https://auth.example.org/token".to_string(),
+                    source: "https://auth.example.org/token".to_source()
+                }],
+                SourceInfo {
+                    start: Pos { line: 1, column: 1 },
+                    end: Pos {
+                        line: 1,
+                        column: 31,
+                    },
+                },
+            )),
+        );
+    }
+
+    #[test]
+    fn test_option_foreach() {
+        let mut reader = Reader::new("{{ users }}");
+        let option = option_foreach(&mut reader).unwrap();
+        assert_eq!(option.to_string(), "foreach: users");
+
+        let mut reader = Reader::new("users");
+        let error = option_foreach(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_option_skip_if() {
+        let mut reader = Reader::new("{{failed}}");
+        let option = option_skip_if(&mut reader).unwrap();
+        assert_eq!(option.to_string(), "skip-if: failed");
+
+        let mut reader = Reader::new("true");
+        assert_eq!(
+            option_only_if(&mut reader).unwrap(),
+            OptionKind::OnlyIf(BooleanOption::Literal(true))
+        );
+
+        let mut reader = Reader::new("xxx");
+        let error = option_skip_if(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_option_auth_type() {
+        let mut reader = Reader::new("ntlm");
+        assert_eq!(
+            option_auth_type(&mut reader).unwrap(),
+            OptionKind::AuthType(AuthTypeOption::Ntlm)
+        );
+
+        let mut reader = Reader::new("auth-type: basic");
+        let error = parse(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 12
+            }
+        );
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expecting {
+                value: "digest|ntlm|negotiate".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_option_client_cert_type() {
+        let mut reader = Reader::new("P12");
+        assert_eq!(
+            option_client_cert_type(&mut reader).unwrap(),
+            OptionKind::ClientCertType(ClientCertTypeOption::P12)
+        );
+
+        let mut reader = Reader::new("client-cert-type: JKS");
+        let error = parse(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 19
+            }
+        );
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expecting {
+                value: "PEM|DER|P12".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_option_paginate() {
+        let mut reader = Reader::new("paginate: next_page_url");
+        let option = parse(&mut reader).unwrap();
+        assert_eq!(
+            option.kind,
+            OptionKind::Paginate("next_page_url".to_string())
+        );
+
+        let mut reader = Reader::new("paginate-max: -1");
+        let option = parse(&mut reader).unwrap();
+        assert_eq!(
+            option.kind,
+            OptionKind::PaginateMax(CountOption::Literal(Count::Infinite))
+        );
+
+        let mut reader = Reader::new("paginate-max: xxx");
+        let error = parse(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+    }
+
     #[test]
     fn test_option_retry_error() {
         let mut reader = Reader::new("retry: ###");
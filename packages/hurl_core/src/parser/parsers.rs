@@ -17,39 +17,114 @@
  */
 use crate::ast::VersionValue::VersionAny;
 use crate::ast::{
-    Body, Entry, HurlFile, Method, Request, Response, Section, SourceInfo, Status, StatusValue,
-    Version, VersionValue,
+    Body, Defaults, Entry, HurlFile, Include, Method, Request, Response, Section, SourceInfo,
+    Status, StatusValue, Version, VersionValue,
 };
-use crate::combinator::{optional, zero_or_more};
+use crate::combinator::{ParseError as ParseErrorTrait, optional, zero_or_more};
 use crate::parser::bytes::bytes;
 use crate::parser::primitives::{
     eof, key_value, line_terminator, one_or_more_spaces, optional_line_terminators, try_literal,
     zero_or_more_spaces,
 };
-use crate::parser::sections::{request_sections, response_sections};
-use crate::parser::string::unquoted_template;
+use crate::parser::sections::{defaults_sections, request_sections, response_sections};
+use crate::parser::string::{quoted_template, unquoted_template};
 use crate::parser::{ParseError, ParseErrorKind, ParseResult};
 use crate::reader::Reader;
 
 pub fn hurl_file(reader: &mut Reader) -> ParseResult<HurlFile> {
-    let entries = zero_or_more(entry, reader)?;
+    let defaults = optional(defaults, reader)?;
+    let entries = entries(reader)?;
+    let includes = zero_or_more(include, reader)?;
     let line_terminators = optional_line_terminators(reader)?;
     eof(reader)?;
     Ok(HurlFile {
+        defaults,
         entries,
+        includes,
         line_terminators,
     })
 }
 
+fn defaults(reader: &mut Reader) -> ParseResult<Defaults> {
+    let line_terminators = optional_line_terminators(reader)?;
+    let space0 = zero_or_more_spaces(reader)?;
+    let start = reader.cursor();
+    try_literal("[Defaults]", reader)?;
+    let source_info = SourceInfo::new(start.pos, reader.cursor().pos);
+    let line_terminator0 = line_terminator(reader)?;
+    let headers = zero_or_more(key_value, reader)?;
+    let sections = defaults_sections(reader)?;
+
+    check_duplicated_sections(&sections)?;
+
+    Ok(Defaults {
+        line_terminators,
+        space0,
+        line_terminator0,
+        headers,
+        sections,
+        source_info,
+    })
+}
+
+/// Parses the entries of a Hurl file, failing if there are more entries than the parser limits.
+fn entries(reader: &mut Reader) -> ParseResult<Vec<Entry>> {
+    let max = reader.limits().max_entries;
+    let mut entries = Vec::new();
+    loop {
+        let initial_state = reader.cursor();
+        if reader.is_eof() {
+            return Ok(entries);
+        }
+        match entry(reader) {
+            Ok(e) => {
+                if entries.len() == max {
+                    let kind = ParseErrorKind::TooManyEntries { max };
+                    // Reports the error on the method of the first entry exceeding the limit.
+                    let pos = e.request.space0.source_info.end;
+                    return Err(ParseError::new(pos, false, kind));
+                }
+                entries.push(e);
+            }
+            Err(e) if e.is_recoverable() => {
+                reader.seek(initial_state);
+                return Ok(entries);
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
 fn entry(reader: &mut Reader) -> ParseResult<Entry> {
+    let includes = zero_or_more(include, reader)?;
     let req = request(reader)?;
     let resp = optional(response, reader)?;
     Ok(Entry {
+        includes,
         request: req,
         response: resp,
     })
 }
 
+fn include(reader: &mut Reader) -> ParseResult<Include> {
+    let start = reader.cursor();
+    let line_terminators = optional_line_terminators(reader)?;
+    let space0 = zero_or_more_spaces(reader)?;
+    try_literal("include", reader)?;
+    let space1 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let filename = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    let line_terminator0 = line_terminator(reader)?;
+    let source_info = SourceInfo::new(start.pos, reader.cursor().pos);
+    Ok(Include {
+        line_terminators,
+        space0,
+        space1,
+        filename,
+        line_terminator0,
+        source_info,
+    })
+}
+
 fn request(reader: &mut Reader) -> ParseResult<Request> {
     let start = reader.cursor();
     let line_terminators = optional_line_terminators(reader)?;
@@ -206,7 +281,13 @@ fn body(reader: &mut Reader) -> ParseResult<Body> {
     //  let start = reader.state.clone();
     let line_terminators = optional_line_terminators(reader)?;
     let space0 = zero_or_more_spaces(reader)?;
+    let start = reader.cursor();
     let value = bytes(reader)?;
+    let max = reader.limits().max_body_size;
+    if (reader.cursor().index - start.index).0 > max {
+        let kind = ParseErrorKind::BodyTooLarge { max };
+        return Err(ParseError::new(start.pos, false, kind));
+    }
     let line_terminator0 = line_terminator(reader)?;
     Ok(Body {
         line_terminators,
@@ -223,6 +304,7 @@ mod tests {
         Bytes, Comment, JsonListElement, JsonValue, LineTerminator, MultilineString,
         MultilineStringKind, Template, TemplateElement, Whitespace,
     };
+    use crate::parser::{ParserLimits, parse_hurl_file_with_limits};
     use crate::reader::{CharPos, Pos};
     use crate::types::ToSource;
 
@@ -233,6 +315,91 @@ mod tests {
         assert_eq!(hurl_file.entries.len(), 1);
     }
 
+    #[test]
+    fn test_hurl_file_too_many_entries() {
+        let limits = ParserLimits {
+            max_entries: 2,
+            ..ParserLimits::default()
+        };
+        let mut reader = Reader::new("GET http://a.fr\nGET http://b.fr\n");
+        reader.set_limits(limits);
+        assert_eq!(hurl_file(&mut reader).unwrap().entries.len(), 2);
+
+        let mut reader =
+            Reader::new("GET http://a.fr\nGET http://b.fr\n\n# Third\nGET http://c.fr\n");
+        reader.set_limits(limits);
+        let error = hurl_file(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 5, column: 1 });
+        assert_eq!(error.kind, ParseErrorKind::TooManyEntries { max: 2 });
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_hurl_file_too_large() {
+        let limits = ParserLimits {
+            max_file_size: 16,
+            ..ParserLimits::default()
+        };
+        let content = "GET http://a.fr\n";
+        assert!(parse_hurl_file_with_limits(content, limits).is_ok());
+
+        let content = "GET http://a.fr\nGET http://b.fr\n";
+        let error = parse_hurl_file_with_limits(content, limits).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 1 });
+        assert_eq!(error.kind, ParseErrorKind::FileTooLarge { max: 16 });
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_hurl_file_with_defaults() {
+        let mut reader = Reader::new(
+            "[Defaults]\nAccept: application/json\n[Options]\nlocation: true\n\nGET http://google.fr",
+        );
+        let hurl_file = hurl_file(&mut reader).unwrap();
+        let defaults = hurl_file.defaults.unwrap();
+        assert_eq!(defaults.headers.len(), 1);
+        assert_eq!(defaults.options().len(), 1);
+        assert_eq!(hurl_file.entries.len(), 1);
+    }
+
+    #[test]
+    fn test_hurl_file_with_includes() {
+        let mut reader = Reader::new(
+            "include \"login.hurl\"\nGET http://google.fr\n\ninclude \"logout.hurl\"\n",
+        );
+        let hurl_file = hurl_file(&mut reader).unwrap();
+        assert_eq!(hurl_file.entries.len(), 1);
+        assert_eq!(hurl_file.entries[0].includes.len(), 1);
+        assert_eq!(
+            hurl_file.entries[0].includes[0].filename.to_string(),
+            "login.hurl"
+        );
+        assert_eq!(hurl_file.includes.len(), 1);
+        assert_eq!(hurl_file.includes[0].filename.to_string(), "logout.hurl");
+    }
+
+    #[test]
+    fn test_include_error() {
+        let mut reader = Reader::new("include login.hurl\n");
+        let error = include(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 9 });
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_defaults_error() {
+        let mut reader = Reader::new("[Defaults]\n[Asserts]\nstatus == 200\n");
+        let error = defaults(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 2, column: 2 });
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::DefaultsSectionName {
+                name: String::from("Asserts")
+            }
+        );
+        assert!(!error.recoverable);
+    }
+
     #[test]
     fn test_entry() {
         let mut reader = Reader::new("GET http://google.fr");
@@ -541,6 +708,24 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_body_too_large() {
+        let limits = ParserLimits {
+            max_body_size: 8,
+            ..ParserLimits::default()
+        };
+        let mut reader = Reader::new("[1,2,3] ");
+        reader.set_limits(limits);
+        assert!(body(&mut reader).is_ok());
+
+        let mut reader = Reader::new("\n  [1,2,3,4]");
+        reader.set_limits(limits);
+        let error = body(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 2, column: 3 });
+        assert_eq!(error.kind, ParseErrorKind::BodyTooLarge { max: 8 });
+        assert!(!error.recoverable);
+    }
+
     #[test]
     fn test_body_json() {
         let mut reader = Reader::new("[1,2,3] ");
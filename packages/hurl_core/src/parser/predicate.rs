@@ -16,11 +16,12 @@
  *
  */
 use crate::ast::{
-    Predicate, PredicateFunc, PredicateFuncValue, PredicateValue, SourceInfo, Whitespace,
+    Ignoring, Predicate, PredicateFunc, PredicateFuncValue, PredicateValue, SourceInfo, Whitespace,
 };
 use crate::combinator::choice;
 use crate::parser::predicate_value::predicate_value;
 use crate::parser::primitives::{one_or_more_spaces, try_literal, zero_or_more_spaces};
+use crate::parser::string::quoted_template;
 use crate::parser::{ParseError, ParseErrorKind, ParseResult};
 use crate::reader::Reader;
 
@@ -73,6 +74,7 @@ fn predicate_func_value(reader: &mut Reader) -> ParseResult<PredicateFuncValue>
         &[
             equal_predicate,
             not_equal_predicate,
+            constant_time_equal_predicate,
             greater_or_equal_predicate,
             greater_predicate,
             less_or_equal_predicate,
@@ -80,6 +82,7 @@ fn predicate_func_value(reader: &mut Reader) -> ParseResult<PredicateFuncValue>
             start_with_predicate,
             end_with_predicate,
             contain_predicate,
+            include_json_predicate,
             include_predicate,
             match_predicate,
             integer_predicate,
@@ -128,7 +131,46 @@ fn equal_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     try_literal("==", reader)?;
     let space0 = zero_or_more_spaces(reader)?;
     let value = predicate_value(reader)?;
-    Ok(PredicateFuncValue::Equal { space0, value })
+    let ignoring = ignoring(reader)?;
+    Ok(PredicateFuncValue::Equal {
+        space0,
+        value,
+        ignoring,
+    })
+}
+
+/// Parses the optional list of JSONPath expressions excluded from an equality predicate
+/// (`ignoring "$.id" "$.createdAt"`).
+fn ignoring(reader: &mut Reader) -> ParseResult<Option<Ignoring>> {
+    let save = reader.cursor();
+    let space0 = zero_or_more_spaces(reader)?;
+    if space0.value.is_empty() || try_literal("ignoring", reader).is_err() {
+        reader.seek(save);
+        return Ok(None);
+    }
+    let mut paths = vec![];
+    loop {
+        let save = reader.cursor();
+        let space = zero_or_more_spaces(reader)?;
+        if space.value.is_empty() {
+            break;
+        }
+        match quoted_template(reader) {
+            Ok(path) => paths.push((space, path)),
+            Err(e) if e.recoverable => {
+                reader.seek(save);
+                break;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    if paths.is_empty() {
+        let kind = ParseErrorKind::Expecting {
+            value: "JSONPath expression".to_string(),
+        };
+        return Err(ParseError::new(reader.cursor().pos, false, kind));
+    }
+    Ok(Some(Ignoring { space0, paths }))
 }
 
 fn not_equal_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
@@ -138,6 +180,21 @@ fn not_equal_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     Ok(PredicateFuncValue::NotEqual { space0, value })
 }
 
+fn constant_time_equal_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("constantTimeEquals", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let save = reader.cursor();
+    let value = predicate_value(reader)?;
+    if !value.is_string() && !value.is_bytearray() && !value.is_expression() {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    Ok(PredicateFuncValue::ConstantTimeEqual { space0, value })
+}
+
 fn greater_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     try_literal(">", reader)?;
     let space0 = zero_or_more_spaces(reader)?;
@@ -246,6 +303,26 @@ fn include_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     Ok(PredicateFuncValue::Include { space0, value })
 }
 
+fn include_json_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("includesJson", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let save = reader.cursor();
+    let value = predicate_value(reader)?;
+    if !matches!(
+        value,
+        PredicateValue::String(_)
+            | PredicateValue::MultilineString(_)
+            | PredicateValue::Placeholder(_)
+    ) {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    Ok(PredicateFuncValue::IncludeJson { space0, value })
+}
+
 fn match_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     try_literal("matches", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -342,7 +419,7 @@ mod tests {
     use crate::ast::{
         Expr, ExprKind, Float, I64, Number, Placeholder, Template, TemplateElement, Variable,
     };
-    use crate::reader::Pos;
+    use crate::reader::{CharPos, Pos};
     use crate::types::ToSource;
 
     #[test]
@@ -393,6 +470,7 @@ mod tests {
                             source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 8)),
                         },
                         value: PredicateValue::Bool(true),
+                        ignoring: None,
                     },
                 },
             }
@@ -419,6 +497,7 @@ mod tests {
                     value: String::from("  "),
                     source_info: SourceInfo::new(Pos::new(1, 3), Pos::new(1, 5)),
                 },
+                ignoring: None,
             }
         );
 
@@ -431,6 +510,7 @@ mod tests {
                     value: String::from(" "),
                     source_info: SourceInfo::new(Pos::new(1, 3), Pos::new(1, 4)),
                 },
+                ignoring: None,
             }
         );
 
@@ -443,6 +523,7 @@ mod tests {
                     value: String::from(" "),
                     source_info: SourceInfo::new(Pos::new(1, 3), Pos::new(1, 4)),
                 },
+                ignoring: None,
             },
         );
 
@@ -462,6 +543,7 @@ mod tests {
                     value: String::from(" "),
                     source_info: SourceInfo::new(Pos::new(1, 3), Pos::new(1, 4)),
                 },
+                ignoring: None,
             }
         );
     }
@@ -493,6 +575,7 @@ mod tests {
                     value: String::from(" "),
                     source_info: SourceInfo::new(Pos::new(1, 3), Pos::new(1, 4)),
                 },
+                ignoring: None,
             }
         );
     }
@@ -518,4 +601,110 @@ mod tests {
         let result = date_predicate(&mut reader);
         assert_eq!(result.unwrap(), PredicateFuncValue::IsDate);
     }
+
+    #[test]
+    fn test_include_json_predicate() {
+        let mut reader = Reader::new("includesJson \"{\\\"id\\\": 1}\"");
+        let result = predicate_func_value(&mut reader).unwrap();
+        assert!(matches!(
+            result,
+            PredicateFuncValue::IncludeJson {
+                value: PredicateValue::String(_),
+                ..
+            }
+        ));
+
+        let mut reader = Reader::new("includes 1");
+        let result = predicate_func_value(&mut reader).unwrap();
+        assert!(matches!(result, PredicateFuncValue::Include { .. }));
+
+        let mut reader = Reader::new("includesJson 1");
+        let error = predicate_func_value(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 14,
+            }
+        );
+        assert!(!error.recoverable);
+        assert_eq!(error.kind, ParseErrorKind::PredicateValue);
+    }
+
+    #[test]
+    fn test_equal_ignoring_predicate() {
+        let mut reader = Reader::new("== file,user.json; ignoring \"$.id\"  \"$.createdAt\"");
+        let result = equal_predicate(&mut reader).unwrap();
+        let PredicateFuncValue::Equal {
+            value: PredicateValue::File(_),
+            ignoring: Some(ignoring),
+            ..
+        } = result
+        else {
+            panic!("unexpected predicate {result:?}");
+        };
+        assert_eq!(ignoring.space0.value, " ");
+        let paths = ignoring
+            .paths
+            .iter()
+            .map(|(space, path)| (space.value.as_str(), path.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![(" ", "$.id".to_string()), ("  ", "$.createdAt".to_string())]
+        );
+        assert_eq!(reader.cursor().index, CharPos(49));
+
+        let mut reader = Reader::new("== 1 ignoring");
+        let error = equal_predicate(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 14,
+            }
+        );
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expecting {
+                value: "JSONPath expression".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_constant_time_equal_predicate() {
+        let mut reader = Reader::new("constantTimeEquals {{token}}");
+        let result = predicate_func_value(&mut reader).unwrap();
+        assert!(matches!(
+            result,
+            PredicateFuncValue::ConstantTimeEqual {
+                value: PredicateValue::Placeholder(_),
+                ..
+            }
+        ));
+
+        let mut reader = Reader::new("constantTimeEquals hex,0a1b;");
+        let result = predicate_func_value(&mut reader).unwrap();
+        assert!(matches!(
+            result,
+            PredicateFuncValue::ConstantTimeEqual {
+                value: PredicateValue::Hex(_),
+                ..
+            }
+        ));
+
+        let mut reader = Reader::new("constantTimeEquals 1");
+        let error = predicate_func_value(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 20,
+            }
+        );
+        assert!(!error.recoverable);
+        assert_eq!(error.kind, ParseErrorKind::PredicateValue);
+    }
 }
@@ -15,9 +15,12 @@
  * limitations under the License.
  *
  */
-use crate::ast::{CertificateAttributeName, Query, QueryValue, RegexValue, SourceInfo};
-use crate::combinator::{ParseError as ParseErrorTrait, choice};
+use crate::ast::{
+    CertificateAttributeName, Query, QueryValue, RegexValue, SourceInfo, TimingsAttributeName,
+};
+use crate::combinator::{ParseError as ParseErrorTrait, choice, optional};
 use crate::parser::cookiepath::cookiepath;
+use crate::parser::number::natural;
 use crate::parser::primitives::{literal, one_or_more_spaces, regex, try_literal};
 use crate::parser::string::{quoted_oneline_string, quoted_template};
 use crate::parser::{ParseError, ParseErrorKind, ParseResult};
@@ -37,6 +40,7 @@ fn query_value(reader: &mut Reader) -> ParseResult<QueryValue> {
     choice(
         &[
             status_query,
+            version_negotiated_query,
             version_query,
             url_query,
             header_query,
@@ -44,16 +48,27 @@ fn query_value(reader: &mut Reader) -> ParseResult<QueryValue> {
             body_query,
             xpath_query,
             jsonpath_query,
+            csv_query,
             regex_query,
             variable_query,
+            duration_mean_query,
+            duration_percentile_query,
             duration_query,
             bytes_query,
             rawbytes_query,
+            sha1_query,
             sha256_query,
+            sha512_query,
             md5_query,
+            crc32_query,
             certificate_query,
             ip_query,
+            proxy_status_query,
+            hsts_upgraded_query,
+            continue_received_query,
+            early_hints_query,
             redirects_query,
+            timings_query,
         ],
         reader,
     )
@@ -64,6 +79,11 @@ fn status_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Status)
 }
 
+fn version_negotiated_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("versionNegotiated", reader)?;
+    Ok(QueryValue::VersionNegotiated)
+}
+
 fn version_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("version", reader)?;
     Ok(QueryValue::Version)
@@ -101,7 +121,16 @@ fn cookie_query(reader: &mut Reader) -> ParseResult<QueryValue> {
 
 fn body_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("body", reader)?;
-    Ok(QueryValue::Body)
+    // The charset is optional: `body` or `body "latin1"`.
+    let charset = optional(
+        |reader| {
+            let space = one_or_more_spaces(reader).map_err(|e| e.to_recoverable())?;
+            let charset = quoted_template(reader)?;
+            Ok((space, charset))
+        },
+        reader,
+    )?;
+    Ok(QueryValue::Body { charset })
 }
 
 fn xpath_query(reader: &mut Reader) -> ParseResult<QueryValue> {
@@ -132,6 +161,13 @@ fn jsonpath_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Jsonpath { space0, expr })
 }
 
+fn csv_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("csv", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let expr = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(QueryValue::Csv { space0, expr })
+}
+
 fn regex_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("regex", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -173,6 +209,25 @@ fn duration_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Duration)
 }
 
+fn duration_mean_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("durationMean", reader)?;
+    Ok(QueryValue::DurationMean)
+}
+
+fn duration_percentile_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("durationPercentile", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let start = reader.cursor();
+    let percentile = natural(reader).map_err(|e| e.to_non_recoverable())?;
+    if percentile.as_u64() > 100 {
+        let kind = ParseErrorKind::Expecting {
+            value: "percentile between 0 and 100".to_string(),
+        };
+        return Err(ParseError::new(start.pos, false, kind));
+    }
+    Ok(QueryValue::DurationPercentile { space0, percentile })
+}
+
 fn bytes_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("bytes", reader)?;
     Ok(QueryValue::Bytes)
@@ -183,16 +238,31 @@ fn rawbytes_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::RawBytes)
 }
 
+fn sha1_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("sha1", reader)?;
+    Ok(QueryValue::Sha1)
+}
+
 fn sha256_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("sha256", reader)?;
     Ok(QueryValue::Sha256)
 }
 
+fn sha512_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("sha512", reader)?;
+    Ok(QueryValue::Sha512)
+}
+
 fn md5_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("md5", reader)?;
     Ok(QueryValue::Md5)
 }
 
+fn crc32_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("crc32", reader)?;
+    Ok(QueryValue::Crc32)
+}
+
 fn certificate_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("certificate", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -208,11 +278,41 @@ fn ip_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Ip)
 }
 
+fn proxy_status_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("proxyStatus", reader)?;
+    Ok(QueryValue::ProxyStatus)
+}
+
+fn hsts_upgraded_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("hstsUpgraded", reader)?;
+    Ok(QueryValue::HstsUpgraded)
+}
+
+fn continue_received_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("continueReceived", reader)?;
+    Ok(QueryValue::ContinueReceived)
+}
+
+fn early_hints_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("earlyHints", reader)?;
+    Ok(QueryValue::EarlyHints)
+}
+
 fn redirects_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("redirects", reader)?;
     Ok(QueryValue::Redirects)
 }
 
+fn timings_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("timings", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let field = timings_field(reader)?;
+    Ok(QueryValue::Timings {
+        space0,
+        attribute_name: field,
+    })
+}
+
 fn certificate_field(reader: &mut Reader) -> ParseResult<CertificateAttributeName> {
     literal("\"", reader)?;
     if try_literal(r#"Subject""#, reader).is_ok() {
@@ -238,12 +338,35 @@ fn certificate_field(reader: &mut Reader) -> ParseResult<CertificateAttributeNam
     }
 }
 
+fn timings_field(reader: &mut Reader) -> ParseResult<TimingsAttributeName> {
+    literal("\"", reader)?;
+    if try_literal(r#"namelookup""#, reader).is_ok() {
+        Ok(TimingsAttributeName::NameLookup)
+    } else if try_literal(r#"connect""#, reader).is_ok() {
+        Ok(TimingsAttributeName::Connect)
+    } else if try_literal(r#"appconnect""#, reader).is_ok() {
+        Ok(TimingsAttributeName::AppConnect)
+    } else if try_literal(r#"pretransfer""#, reader).is_ok() {
+        Ok(TimingsAttributeName::PreTransfer)
+    } else if try_literal(r#"starttransfer""#, reader).is_ok() {
+        Ok(TimingsAttributeName::StartTransfer)
+    } else if try_literal(r#"total""#, reader).is_ok() {
+        Ok(TimingsAttributeName::Total)
+    } else {
+        let value =
+            "Field <namelookup>, <connect>, <appconnect>, <pretransfer>, <starttransfer> or <total>".to_string();
+        let kind = ParseErrorKind::Expecting { value };
+        let cur = reader.cursor();
+        Err(ParseError::new(cur.pos, false, kind))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::ast::{
         CookieAttribute, CookieAttributeName, CookiePath, Filter, FilterValue, Template,
-        TemplateElement, Whitespace,
+        TemplateElement, U64, Whitespace,
     };
     use crate::parser::filter::filters;
     use crate::reader::{CharPos, Pos};
@@ -273,6 +396,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_version_negotiated_query() {
+        let mut reader = Reader::new("versionNegotiated");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 18)),
+                value: QueryValue::VersionNegotiated,
+            }
+        );
+
+        let mut reader = Reader::new("version");
+        assert_eq!(query(&mut reader).unwrap().value, QueryValue::Version);
+    }
+
+    #[test]
+    fn test_proxy_status_query() {
+        let mut reader = Reader::new("proxyStatus");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 12)),
+                value: QueryValue::ProxyStatus,
+            }
+        );
+    }
+
+    #[test]
+    fn test_hsts_upgraded_query() {
+        let mut reader = Reader::new("hstsUpgraded");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 13)),
+                value: QueryValue::HstsUpgraded,
+            }
+        );
+    }
+
+    #[test]
+    fn test_continue_received_query() {
+        let mut reader = Reader::new("continueReceived");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 17)),
+                value: QueryValue::ContinueReceived,
+            }
+        );
+    }
+
+    #[test]
+    fn test_early_hints_query() {
+        let mut reader = Reader::new("earlyHints");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 11)),
+                value: QueryValue::EarlyHints,
+            }
+        );
+    }
+
     #[test]
     fn test_header_query() {
         let mut reader = Reader::new("header \"Foo\"");
@@ -413,6 +599,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_body_query() {
+        let mut reader = Reader::new("body");
+        assert_eq!(
+            body_query(&mut reader).unwrap(),
+            QueryValue::Body { charset: None }
+        );
+
+        let mut reader = Reader::new("body \"latin1\"");
+        assert_eq!(
+            body_query(&mut reader).unwrap(),
+            QueryValue::Body {
+                charset: Some((
+                    Whitespace {
+                        value: String::from(" "),
+                        source_info: SourceInfo::new(Pos::new(1, 5), Pos::new(1, 6)),
+                    },
+                    Template::new(
+                        Some('"'),
+                        vec![TemplateElement::String {
+                            value: "latin1".to_string(),
+                            source: "latin1".to_source(),
+                        }],
+                        SourceInfo::new(Pos::new(1, 6), Pos::new(1, 14))
+                    )
+                ))
+            }
+        );
+
+        // A filter is not a charset.
+        let mut reader = Reader::new("body decode \"utf-8\"");
+        assert_eq!(
+            body_query(&mut reader).unwrap(),
+            QueryValue::Body { charset: None }
+        );
+        assert_eq!(reader.cursor().index, CharPos(4));
+    }
+
+    #[test]
+    fn test_csv_query() {
+        let mut reader = Reader::new("csv \"$[2].price\"");
+        assert_eq!(
+            csv_query(&mut reader).unwrap(),
+            QueryValue::Csv {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 4), Pos::new(1, 5)),
+                },
+                expr: Template::new(
+                    Some('"'),
+                    vec![TemplateElement::String {
+                        value: "$[2].price".to_string(),
+                        source: "$[2].price".to_source(),
+                    }],
+                    SourceInfo::new(Pos::new(1, 5), Pos::new(1, 17))
+                )
+            }
+        );
+    }
+
     #[test]
     fn test_query_with_filters() {
         let mut reader = Reader::new("body urlDecode ");
@@ -420,7 +666,7 @@ mod tests {
             query(&mut reader).unwrap(),
             Query {
                 source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 5)),
-                value: QueryValue::Body,
+                value: QueryValue::Body { charset: None },
             }
         );
         assert_eq!(
@@ -462,4 +708,68 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_duration_queries() {
+        let mut reader = Reader::new("duration");
+        assert_eq!(query(&mut reader).unwrap().value, QueryValue::Duration);
+
+        let mut reader = Reader::new("durationMean");
+        assert_eq!(query(&mut reader).unwrap().value, QueryValue::DurationMean);
+
+        let mut reader = Reader::new("durationPercentile 95");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 22)),
+                value: QueryValue::DurationPercentile {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(1, 19), Pos::new(1, 20)),
+                    },
+                    percentile: U64::new(95, "95".to_source()),
+                },
+            }
+        );
+
+        let mut reader = Reader::new("durationPercentile 101");
+        let error = query(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 20));
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_timings_query() {
+        let mut reader = Reader::new(r#"timings "connect""#);
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 18)),
+                value: QueryValue::Timings {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 9)),
+                    },
+                    attribute_name: TimingsAttributeName::Connect,
+                },
+            }
+        );
+
+        let mut reader = Reader::new(r#"timings "starttransfer""#);
+        assert_eq!(
+            query(&mut reader).unwrap().value,
+            QueryValue::Timings {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 9)),
+                },
+                attribute_name: TimingsAttributeName::StartTransfer,
+            }
+        );
+
+        let mut reader = Reader::new(r#"timings "dns""#);
+        let error = query(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 10));
+        assert!(!error.recoverable);
+    }
 }
@@ -15,13 +15,18 @@
  * limitations under the License.
  *
  */
+use std::iter::Peekable;
+use std::vec::IntoIter;
+
 use crate::ast::{
-    Assert, Capture, Cookie, FilenameParam, FilenameValue, MultipartParam, Section, SectionValue,
-    SourceInfo, Whitespace,
+    Assert, Capture, CaptureFallback, CaptureType, CaptureTypeAnnotation, Cookie, FilenameParam,
+    FilenameValue, MultipartParam, NestedParam, NestedValue, Section, SectionValue, SourceInfo,
+    Whitespace,
 };
-use crate::combinator::{optional, recover, zero_or_more};
+use crate::combinator::{ParseError as ParseErrorTrait, optional, recover, zero_or_more};
 use crate::parser::filter::filters;
 use crate::parser::predicate::predicate;
+use crate::parser::predicate_value::predicate_value;
 use crate::parser::primitives::{
     key_value, line_terminator, literal, one_or_more_spaces, optional_line_terminators,
     try_literal, zero_or_more_spaces,
@@ -41,6 +46,11 @@ pub fn response_sections(reader: &mut Reader) -> ParseResult<Vec<Section>> {
     Ok(sections)
 }
 
+pub fn defaults_sections(reader: &mut Reader) -> ParseResult<Vec<Section>> {
+    let sections = zero_or_more(defaults_section, reader)?;
+    Ok(sections)
+}
+
 fn request_section(reader: &mut Reader) -> ParseResult<Section> {
     let line_terminators = optional_line_terminators(reader)?;
     let space0 = zero_or_more_spaces(reader)?;
@@ -103,6 +113,32 @@ fn response_section(reader: &mut Reader) -> ParseResult<Section> {
     })
 }
 
+fn defaults_section(reader: &mut Reader) -> ParseResult<Section> {
+    let line_terminators = optional_line_terminators(reader)?;
+    let space0 = zero_or_more_spaces(reader)?;
+    let start = reader.cursor();
+    let name = section_name(reader)?;
+    let source_info = SourceInfo::new(start.pos, reader.cursor().pos);
+
+    let line_terminator0 = line_terminator(reader)?;
+    let value = match name.as_str() {
+        "Options" => section_value_options(reader)?,
+        _ => {
+            let kind = ParseErrorKind::DefaultsSectionName { name: name.clone() };
+            let pos = Pos::new(start.pos.line, start.pos.column + 1);
+            return Err(ParseError::new(pos, false, kind));
+        }
+    };
+
+    Ok(Section {
+        line_terminators,
+        space0,
+        line_terminator0,
+        value,
+        source_info,
+    })
+}
+
 fn section_name(reader: &mut Reader) -> ParseResult<String> {
     let pos = reader.cursor().pos;
     try_literal("[", reader)?;
@@ -138,9 +174,43 @@ fn section_value_multipart_form_data(
     short: bool,
 ) -> ParseResult<SectionValue> {
     let items = zero_or_more(multipart_param, reader)?;
+    let items = nest_multipart_params(&mut items.into_iter().peekable(), None);
     Ok(SectionValue::MultipartFormData(items, short))
 }
 
+/// Moves the multipart params following a nested param, and indented deeper than it, into this
+/// nested param.
+///
+/// `parent_indent` is the indentation of the enclosing nested param, `None` at the top level.
+fn nest_multipart_params(
+    params: &mut Peekable<IntoIter<MultipartParam>>,
+    parent_indent: Option<usize>,
+) -> Vec<MultipartParam> {
+    let mut nested = vec![];
+    while let Some(param) =
+        params.next_if(|p| parent_indent.is_none_or(|indent| multipart_indent(p) > indent))
+    {
+        match param {
+            MultipartParam::NestedParam(mut param) => {
+                let indent = param.space0.value.chars().count();
+                param.params = nest_multipart_params(params, Some(indent));
+                nested.push(MultipartParam::NestedParam(param));
+            }
+            param => nested.push(param),
+        }
+    }
+    nested
+}
+
+fn multipart_indent(param: &MultipartParam) -> usize {
+    let space0 = match param {
+        MultipartParam::Param(param) => &param.space0,
+        MultipartParam::FilenameParam(param) => &param.space0,
+        MultipartParam::NestedParam(param) => &param.space0,
+    };
+    space0.value.chars().count()
+}
+
 fn section_value_cookies(reader: &mut Reader) -> ParseResult<SectionValue> {
     let items = zero_or_more(cookie, reader)?;
     Ok(SectionValue::Cookies(items))
@@ -185,17 +255,85 @@ fn cookie(reader: &mut Reader) -> ParseResult<Cookie> {
 fn multipart_param(reader: &mut Reader) -> ParseResult<MultipartParam> {
     let save = reader.cursor();
     match file_param(reader) {
-        Ok(f) => Ok(MultipartParam::FilenameParam(f)),
-        Err(e) => {
-            if e.recoverable {
-                reader.seek(save);
-                let param = key_value(reader)?;
-                Ok(MultipartParam::Param(param))
-            } else {
-                Err(e)
-            }
-        }
+        Ok(f) => return Ok(MultipartParam::FilenameParam(f)),
+        Err(e) if !e.recoverable => return Err(e),
+        Err(_) => reader.seek(save),
     }
+    match nested_param(reader) {
+        Ok(n) => return Ok(MultipartParam::NestedParam(n)),
+        Err(e) if !e.recoverable => return Err(e),
+        Err(_) => reader.seek(save),
+    }
+    let param = key_value(reader)?;
+    Ok(MultipartParam::Param(param))
+}
+
+/// Parses a nested multipart param. Its parts are parsed as the following params, and moved
+/// into it afterwards by [`nest_multipart_params`].
+fn nested_param(reader: &mut Reader) -> ParseResult<NestedParam> {
+    let line_terminators = optional_line_terminators(reader)?;
+    let space0 = zero_or_more_spaces(reader)?;
+    let key = recover(key_string::parse, reader)?;
+    let space1 = zero_or_more_spaces(reader)?;
+    recover(|reader1| literal(":", reader1), reader)?;
+    let space2 = zero_or_more_spaces(reader)?;
+    let value = nested_value(reader)?;
+    let line_terminator0 = line_terminator(reader)?;
+    Ok(NestedParam {
+        line_terminators,
+        space0,
+        key,
+        space1,
+        space2,
+        value,
+        line_terminator0,
+        params: vec![],
+    })
+}
+
+fn nested_value(reader: &mut Reader) -> ParseResult<NestedValue> {
+    try_literal("multipart,", reader)?;
+    let space0 = zero_or_more_spaces(reader)?;
+    let start = reader.cursor();
+    let Ok(subtype) = key_string::parse(reader) else {
+        let kind = ParseErrorKind::Expecting {
+            value: "multipart subtype".to_string(),
+        };
+        return Err(ParseError::new(start.pos, false, kind));
+    };
+    let space1 = zero_or_more_spaces(reader)?;
+    literal(";", reader)?;
+    let save = reader.cursor();
+    if line_terminator(reader).is_ok() {
+        reader.seek(save);
+        let space2 = Whitespace {
+            value: String::new(),
+            source_info: SourceInfo::new(save.pos, save.pos),
+        };
+        return Ok(NestedValue {
+            space0,
+            subtype,
+            space1,
+            space2,
+            headers: None,
+        });
+    }
+    reader.seek(save);
+    let space2 = zero_or_more_spaces(reader)?;
+    let start = reader.cursor();
+    let Ok(headers) = unquoted_template(reader) else {
+        let kind = ParseErrorKind::Expecting {
+            value: "part headers".to_string(),
+        };
+        return Err(ParseError::new(start.pos, false, kind));
+    };
+    Ok(NestedValue {
+        space0,
+        subtype,
+        space1,
+        space2,
+        headers: Some(headers),
+    })
 }
 
 fn file_param(reader: &mut Reader) -> ParseResult<FilenameParam> {
@@ -270,6 +408,8 @@ fn capture(reader: &mut Reader) -> ParseResult<Capture> {
     let space2 = zero_or_more_spaces(reader)?;
     let q = query(reader)?;
     let filters = filters(reader)?;
+    let type_annotation = optional(capture_type_annotation, reader)?;
+    let fallback = optional(capture_fallback, reader)?;
     let (redacted, space3) = if let Some(ws) = optional(redacted, reader)? {
         (true, ws)
     } else {
@@ -290,12 +430,55 @@ fn capture(reader: &mut Reader) -> ParseResult<Capture> {
         space2,
         query: q,
         filters,
+        type_annotation,
+        fallback,
         space3,
         redacted,
         line_terminator0,
     })
 }
 
+fn capture_type_annotation(
+    reader: &mut Reader,
+) -> ParseResult<(Whitespace, CaptureTypeAnnotation)> {
+    let space = zero_or_more_spaces(reader)?;
+    try_literal("as", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let start = reader.cursor();
+    let value = if try_literal("boolean", reader).is_ok() {
+        CaptureType::Boolean
+    } else if try_literal("float", reader).is_ok() {
+        CaptureType::Float
+    } else if try_literal("integer", reader).is_ok() {
+        CaptureType::Integer
+    } else if try_literal("string", reader).is_ok() {
+        CaptureType::String
+    } else {
+        let kind = ParseErrorKind::Expecting {
+            value: "boolean, float, integer or string".to_string(),
+        };
+        return Err(ParseError::new(start.pos, false, kind));
+    };
+    let source_info = SourceInfo::new(start.pos, reader.cursor().pos);
+    let annotation = CaptureTypeAnnotation {
+        space0,
+        value,
+        source_info,
+    };
+    Ok((space, annotation))
+}
+
+fn capture_fallback(reader: &mut Reader) -> ParseResult<(Whitespace, CaptureFallback)> {
+    let space = zero_or_more_spaces(reader)?;
+    if try_literal("optional", reader).is_ok() {
+        return Ok((space, CaptureFallback::Optional));
+    }
+    try_literal("default", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let value = predicate_value(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok((space, CaptureFallback::Default { space0, value }))
+}
+
 fn redacted(reader: &mut Reader) -> ParseResult<Whitespace> {
     let space = zero_or_more_spaces(reader)?;
     try_literal("redact", reader)?;
@@ -413,6 +596,7 @@ mod tests {
                                     }],
                                     SourceInfo::new(Pos::new(2, 22), Pos::new(2, 41))
                                 )),
+                                ignoring: None,
                             },
                         },
                     },
@@ -605,6 +789,69 @@ mod tests {
         assert_eq!(reader.cursor().index, CharPos(41));
     }
 
+    #[test]
+    fn test_nested_value() {
+        let mut reader = Reader::new("multipart,mixed;");
+        let value = nested_value(&mut reader).unwrap();
+        assert_eq!(value.subtype.to_string(), "mixed");
+        assert!(value.headers.is_none());
+
+        let mut reader =
+            Reader::new("multipart,related; Content-ID: <root>; X-Part: {{id}} # comment");
+        let value = nested_value(&mut reader).unwrap();
+        assert_eq!(value.subtype.to_string(), "related");
+        assert_eq!(
+            value.headers.unwrap().to_string(),
+            "Content-ID: <root>; X-Part: {{id}}"
+        );
+
+        let mut reader = Reader::new("multipart,;");
+        let error = nested_value(&mut reader).unwrap_err();
+        assert_eq!(error.pos, Pos::new(1, 11));
+        assert!(!error.recoverable);
+
+        let mut reader = Reader::new("mixed;");
+        let error = nested_value(&mut reader).unwrap_err();
+        assert!(error.recoverable);
+    }
+
+    #[test]
+    fn test_multipart_section_nested_params() {
+        let mut reader = Reader::new(
+            "[Multipart]\n\
+             field1: value1\n\
+             parts: multipart,mixed; Content-ID: <parts>\n\
+             \x20 file1: file,a.txt;\n\
+             \x20 alternatives: multipart,alternative;\n\
+             \x20   text: file,b.txt; text/plain\n\
+             \x20   html: file,b.html; text/html\n\
+             \x20 file2: file,c.txt;\n\
+             field2: value2\n",
+        );
+        let section = request_section(&mut reader).unwrap();
+        let SectionValue::MultipartFormData(params, true) = section.value else {
+            panic!("expecting a multipart section");
+        };
+        assert_eq!(params.len(), 3);
+        let MultipartParam::NestedParam(parts) = &params[1] else {
+            panic!("expecting a nested param");
+        };
+        assert_eq!(parts.key.to_string(), "parts");
+        assert_eq!(
+            parts.value.headers.as_ref().unwrap().to_string(),
+            "Content-ID: <parts>"
+        );
+        assert_eq!(parts.params.len(), 3);
+        let MultipartParam::NestedParam(alternatives) = &parts.params[1] else {
+            panic!("expecting a nested param");
+        };
+        assert_eq!(alternatives.value.subtype.to_string(), "alternative");
+        assert_eq!(alternatives.params.len(), 2);
+        assert!(
+            matches!(&params[2], MultipartParam::Param(param) if param.key.to_string() == "field2")
+        );
+    }
+
     #[test]
     fn test_capture() {
         let mut reader = Reader::new("url: header \"Location\"");
@@ -661,6 +908,8 @@ mod tests {
                     }
                 },
                 filters: vec![],
+                type_annotation: None,
+                fallback: None,
                 space3: Whitespace {
                     value: String::new(),
                     source_info: SourceInfo::new(Pos::new(1, 23), Pos::new(1, 23)),
@@ -733,6 +982,8 @@ mod tests {
                     }
                 },
                 filters: vec![],
+                type_annotation: None,
+                fallback: None,
                 space3: Whitespace {
                     value: "    ".to_string(),
                     source_info: SourceInfo::new(Pos::new(1, 20), Pos::new(1, 24)),
@@ -781,6 +1032,77 @@ mod tests {
         assert_eq!(reader.cursor().index, CharPos(43));
     }
 
+    #[test]
+    fn test_capture_with_type_annotation() {
+        let mut reader = Reader::new("id: jsonpath \"$.id\" as integer default 0");
+        let capture0 = capture(&mut reader).unwrap();
+        assert_eq!(
+            capture0.type_annotation,
+            Some((
+                Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 20), Pos::new(1, 21)),
+                },
+                CaptureTypeAnnotation {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(1, 23), Pos::new(1, 24)),
+                    },
+                    value: CaptureType::Integer,
+                    source_info: SourceInfo::new(Pos::new(1, 24), Pos::new(1, 31)),
+                }
+            ))
+        );
+        assert!(capture0.fallback.is_some());
+
+        let mut reader = Reader::new("id: jsonpath \"$.id\" as number");
+        let error = capture(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 24));
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_capture_with_fallback() {
+        let mut reader = Reader::new("id: jsonpath \"$.id\" optional redact");
+        let capture0 = capture(&mut reader).unwrap();
+        assert_eq!(
+            capture0.fallback,
+            Some((
+                Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 20), Pos::new(1, 21)),
+                },
+                CaptureFallback::Optional
+            ))
+        );
+        assert!(capture0.redacted);
+
+        let mut reader = Reader::new("count: jsonpath \"$.count\" toInt default 0");
+        let capture0 = capture(&mut reader).unwrap();
+        assert_eq!(capture0.filters.len(), 1);
+        assert_eq!(
+            capture0.fallback,
+            Some((
+                Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 32), Pos::new(1, 33)),
+                },
+                CaptureFallback::Default {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(1, 40), Pos::new(1, 41)),
+                    },
+                    value: PredicateValue::Number(Number::Integer(I64::new(0, "0".to_source()))),
+                }
+            ))
+        );
+        assert!(!capture0.redacted);
+
+        let mut reader = Reader::new("count: jsonpath \"$.count\" default");
+        let error = capture(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+    }
+
     #[test]
     fn test_capture_with_filter_error() {
         let mut reader = Reader::new("token: header \"Location\" regex ");
@@ -877,6 +1199,7 @@ mod tests {
                             5,
                             "5".to_source()
                         ))),
+                        ignoring: None,
                     },
                 },
             }
@@ -88,6 +88,12 @@ pub fn templatize(encoded_string: EncodedString) -> ParseResult<Vec<TemplateElem
                     let space0 = zero_or_more_spaces(&mut reader)?;
                     let expr = expr::parse(&mut reader)?;
                     let space1 = zero_or_more_spaces(&mut reader)?;
+                    if !reader.is_eof() {
+                        let kind = error::ParseErrorKind::Expecting {
+                            value: "}}".to_string(),
+                        };
+                        return Err(error::ParseError::new(reader.cursor().pos, false, kind));
+                    }
                     let placeholder = Placeholder {
                         space0,
                         expr,
@@ -19,6 +19,8 @@
 
 use std::ops::{Add, AddAssign, Sub};
 
+use crate::parser::ParserLimits;
+
 /// The `Reader` implements methods to read a stream of text. A reader manages
 /// an internal `cursor` : it's the current read index position within the reader's internal buffer.
 ///
@@ -49,6 +51,10 @@ use std::ops::{Add, AddAssign, Sub};
 pub struct Reader {
     buf: Vec<char>,
     cursor: Cursor,
+    /// Limits of the parser using this reader.
+    limits: ParserLimits,
+    /// Current nesting depth of the parsed values, checked against the parser limits.
+    depth: usize,
 }
 
 /// Represents a line and column position in a reader.
@@ -119,6 +125,8 @@ impl Reader {
                 index: CharPos(0),
                 pos: Pos { line: 1, column: 1 },
             },
+            limits: ParserLimits::default(),
+            depth: 0,
         }
     }
 
@@ -133,6 +141,8 @@ impl Reader {
                 index: CharPos(0),
                 pos,
             },
+            limits: ParserLimits::default(),
+            depth: 0,
         }
     }
 
@@ -146,6 +156,26 @@ impl Reader {
         self.cursor = to;
     }
 
+    /// Returns the limits of the parser using this reader.
+    pub fn limits(&self) -> &ParserLimits {
+        &self.limits
+    }
+
+    /// Sets the limits of the parser using this reader.
+    pub fn set_limits(&mut self, limits: ParserLimits) {
+        self.limits = limits;
+    }
+
+    /// Returns the current nesting depth of the parsed values.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Sets the current nesting depth of the parsed values.
+    pub fn set_depth(&mut self, depth: usize) {
+        self.depth = depth;
+    }
+
     /// Returns true if the reader has read all the buffer, false otherwise.
     pub fn is_eof(&self) -> bool {
         self.cursor.index.0 == self.buf.len()
@@ -0,0 +1,75 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::reader::Pos;
+
+use crate::position::contains;
+use crate::symbols::{Symbol, Symbols};
+
+/// Returns the definition of the variable used at `pos`.
+///
+/// A variable can be defined several times in a file: the last definition before the variable use
+/// is returned, or the first definition if the variable is only defined after its use (for
+/// instance when the file is run with `--repeat`).
+pub fn definition(symbols: &Symbols, pos: Pos) -> Option<&Symbol> {
+    let reference = symbols
+        .references
+        .iter()
+        .find(|s| contains(s.source_info, pos))?;
+    let start = reference.source_info.start;
+    let mut definitions = symbols
+        .definitions
+        .iter()
+        .filter(|d| d.name == reference.name);
+    let before = definitions.clone().rfind(|d| {
+        (d.source_info.start.line, d.source_info.start.column) < (start.line, start.column)
+    });
+    before.or_else(|| definitions.next())
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::parser::parse_hurl_file;
+
+    use super::*;
+
+    #[test]
+    fn test_definition() {
+        let content = r#"GET https://example.org/{{id}}
+HTTP 200
+[Captures]
+id: jsonpath "$.id"
+
+GET https://example.org/{{unknown}}
+[Options]
+variable: id=2
+
+GET https://example.org/{{id}}
+"#;
+        let file = parse_hurl_file(content).unwrap();
+        let symbols = Symbols::new(&file);
+
+        // Used before any definition: goes to the first one.
+        let def = definition(&symbols, Pos::new(1, 27)).unwrap();
+        assert_eq!(def.source_info.start, Pos::new(4, 1));
+        let def = definition(&symbols, Pos::new(10, 27)).unwrap();
+        assert_eq!(def.source_info.start, Pos::new(8, 11));
+
+        assert_eq!(definition(&symbols, Pos::new(6, 27)), None);
+        assert_eq!(definition(&symbols, Pos::new(1, 2)), None);
+    }
+}
@@ -0,0 +1,64 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::error::DisplaySourceError;
+use hurl_core::parser::ParseError;
+use hurl_core::reader::Pos;
+use lsp_types::{Diagnostic, DiagnosticSeverity, Range};
+
+use crate::position::to_lsp_position;
+
+/// Returns the LSP diagnostic of a parsing `error` of `text`.
+///
+/// The diagnostic spans the char where the parser has stopped.
+pub fn diagnostic(text: &str, error: &ParseError) -> Diagnostic {
+    let pos = error.source_info().start;
+    let start = to_lsp_position(text, pos);
+    let end = to_lsp_position(text, Pos::new(pos.line, pos.column + 1));
+    let message = format!("{}: {}", error.description(), error.message());
+    Diagnostic {
+        range: Range::new(start, end),
+        severity: Some(DiagnosticSeverity::ERROR),
+        source: Some("hurl".to_string()),
+        message,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::parser::parse_hurl_file;
+    use lsp_types::Position;
+
+    use super::*;
+
+    #[test]
+    fn test_diagnostic() {
+        let text = "GET https://example.org\nHTTP 200\n[Asserts]\nstatus === 200\n";
+        let error = parse_hurl_file(text).unwrap_err();
+        let diagnostic = diagnostic(text, &error);
+        assert_eq!(
+            diagnostic.range,
+            Range::new(Position::new(3, 9), Position::new(3, 10))
+        );
+        assert_eq!(diagnostic.severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(
+            diagnostic.message,
+            "Parsing predicate value: invalid predicate value"
+        );
+    }
+}
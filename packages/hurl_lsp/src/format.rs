@@ -0,0 +1,75 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::fs;
+use std::path::Path;
+
+use hurl_core::ast::HurlFile;
+use hurlfmt::linter::{self, LintConfig};
+use lsp_types::{Position, Range, TextEdit};
+
+const CONFIG_FILENAME: &str = ".hurlfmt.toml";
+
+/// Returns the edits pretty-printing the Hurl file `file` of `text`, given formatting rules
+/// `config`.
+///
+/// The whole document is replaced by a single edit, if it's not already formatted.
+pub fn format(text: &str, file: &HurlFile, config: &LintConfig) -> Vec<TextEdit> {
+    let formatted = linter::pretty_hurl_file(file, config);
+    if formatted == text {
+        return vec![];
+    }
+    let end = Position::new(text.lines().count() as u32 + 1, 0);
+    let range = Range::new(Position::new(0, 0), end);
+    vec![TextEdit::new(range, formatted)]
+}
+
+/// Returns the formatting rules of the nearest `.hurlfmt.toml` configuration file, starting from
+/// `dir`, or the default rules if there is no valid configuration file.
+pub fn lint_config(dir: &Path) -> LintConfig {
+    dir.ancestors()
+        .map(|dir| dir.join(CONFIG_FILENAME))
+        .find(|path| path.is_file())
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|content| LintConfig::parse(&content).ok())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::parser::parse_hurl_file;
+
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let text = "GET https://example.org\nAccept:   */*\nUser-Agent: hurl\n";
+        let file = parse_hurl_file(text).unwrap();
+        let edits = format(text, &file, &LintConfig::default());
+        assert_eq!(
+            edits,
+            vec![TextEdit::new(
+                Range::new(Position::new(0, 0), Position::new(4, 0)),
+                "GET https://example.org\nAccept    : */*\nUser-Agent: hurl\n".to_string()
+            )]
+        );
+
+        let text = &edits[0].new_text;
+        let file = parse_hurl_file(text).unwrap();
+        assert!(format(text, &file, &LintConfig::default()).is_empty());
+    }
+}
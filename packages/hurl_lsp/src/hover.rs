@@ -0,0 +1,561 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::reader::Pos;
+use lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind};
+
+use crate::position::{contains, to_lsp_range};
+use crate::symbols::Symbols;
+
+/// Returns the hover documentation of the option or filter at `pos` in `text`.
+pub fn hover(text: &str, symbols: &Symbols, pos: Pos) -> Option<Hover> {
+    let (symbol, value) = symbols
+        .options
+        .iter()
+        .find(|s| contains(s.source_info, pos))
+        .and_then(|s| Some((s, option_doc(&s.name)?)))
+        .or_else(|| {
+            symbols
+                .filters
+                .iter()
+                .find(|s| contains(s.source_info, pos))
+                .and_then(|s| Some((s, filter_doc(&s.name)?)))
+        })?;
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value,
+        }),
+        range: Some(to_lsp_range(text, symbol.source_info)),
+    })
+}
+
+/// Returns the Markdown documentation of the option `name`.
+fn option_doc(name: &str) -> Option<String> {
+    let (_, description) = OPTIONS.iter().find(|(n, _)| *n == name)?;
+    Some(format!("**{name}** (option)\n\n{description}."))
+}
+
+/// Returns the Markdown documentation of the filter `name`.
+fn filter_doc(name: &str) -> Option<String> {
+    let (_, description, input, output) = FILTERS.iter().find(|(n, ..)| *n == name)?;
+    Some(format!(
+        "**{name}** (filter)\n\n{description}\n\nInput: {input}, output: {output}"
+    ))
+}
+
+/// Names and descriptions of the request options.
+const OPTIONS: &[(&str, &str)] = &[
+    (
+        "auth-type",
+        "Authentication method used with user credentials: digest, ntlm or negotiate",
+    ),
+    (
+        "aws-sigv4",
+        "Use AWS V4 signature authentication in the transfer",
+    ),
+    (
+        "cacert",
+        "CA certificate to verify peer against (PEM format)",
+    ),
+    ("cert", "Client certificate file and password"),
+    ("chunked", "Send the body with a chunked transfer encoding"),
+    ("client-cert-password", "Client certificate password"),
+    (
+        "client-cert-type",
+        "Client certificate format: PEM, DER or P12 (PKCS#12 bundle)",
+    ),
+    ("clock-skew", "Tolerance of date comparisons in asserts"),
+    (
+        "compressed",
+        "Request compressed response (using deflate or gzip)",
+    ),
+    ("connect-timeout", "Maximum time allowed for connection"),
+    (
+        "connect-to",
+        "For a request to the given HOST1:PORT1 pair, connect to HOST2:PORT2 instead",
+    ),
+    (
+        "cookie-file",
+        "Read cookies from FILE (using the Netscape cookie file format)",
+    ),
+    ("delay", "Sets delay before each request (aka sleep)"),
+    ("digest", "Tell Hurl to use HTTP Digest authentication"),
+    (
+        "dns-servers",
+        "Use the given comma separated list of DNS servers instead of the system default",
+    ),
+    ("expect-continue", "Send an Expect: 100-continue header"),
+    (
+        "export-capture",
+        "Export the capture token to the files run after this one",
+    ),
+    (
+        "fail-with-body",
+        "Output body response if there are any errors",
+    ),
+    ("foreach", "Run this request once per item of a list"),
+    ("header", "Pass custom header(s) to server"),
+    ("hmac-signature", "Sign the request"),
+    ("http1.0", "Tell Hurl to use HTTP version 1.0"),
+    ("http1.1", "Tell Hurl to use HTTP version 1.1"),
+    ("http2", "Tell Hurl to use HTTP version 2"),
+    ("http3", "Tell Hurl to use HTTP version 3"),
+    (
+        "http3-fallback",
+        "Retry over HTTP/2 if HTTP/3 can not be negotiated",
+    ),
+    ("insecure", "Allow insecure SSL connections"),
+    (
+        "ipv4",
+        "Tell Hurl to use IPv4 addresses only when resolving host names, and not for example try IPv6",
+    ),
+    (
+        "ipv6",
+        "Tell Hurl to use IPv6 addresses only when resolving host names, and not for example try IPv4",
+    ),
+    ("key", "Private key file name"),
+    (
+        "limit-rate",
+        "Specify the maximum transfer rate in bytes/second, for both downloads and uploads",
+    ),
+    ("location", "Follow redirects"),
+    (
+        "location-trusted",
+        "Follow redirects but allows sending the name + password to all hosts that the site may redirect to",
+    ),
+    (
+        "max-redirs",
+        "Maximum number of redirects allowed, -1 for unlimited redirects",
+    ),
+    ("max-time", "Maximum time allowed for the transfer"),
+    (
+        "multipart-boundary",
+        "Use this boundary for the multipart form data",
+    ),
+    (
+        "negotiate",
+        "Tell Hurl to use Negotiate (SPNEGO) authentication",
+    ),
+    (
+        "nested-form-params",
+        "Encode repeated form params names as arrays and percent-encode nested keys",
+    ),
+    ("netrc", "Must read .netrc for username and password"),
+    ("netrc-file", "Specify FILE for .netrc"),
+    ("netrc-optional", "Use either .netrc or the URL"),
+    ("no-header", "Remove header(s) sent to server"),
+    ("ntlm", "Tell Hurl to use NTLM authentication"),
+    (
+        "oauth2-client-id",
+        "OAuth 2.0 client id, used to get an access token with client credentials grant",
+    ),
+    (
+        "oauth2-client-secret",
+        "OAuth 2.0 client secret, used to get an access token with client credentials grant",
+    ),
+    (
+        "oauth2-token-url",
+        "Get an OAuth 2.0 access token from this URL and send it in an Authorization header",
+    ),
+    ("only-if", "Run this request only if the condition is true"),
+    ("output", "Write to FILE instead of stdout"),
+    (
+        "paginate",
+        "Run this request again while the capture VAR holds the URL of a next page",
+    ),
+    (
+        "paginate-max",
+        "Maximum number of pages run for a paginated request, -1 for no limit",
+    ),
+    (
+        "path-as-is",
+        "Tell Hurl to not handle sequences of /../ or /./ in the given URL path",
+    ),
+    ("pinnedpubkey", "Public key to verify peer against"),
+    ("proxy", "Use proxy on given PROTOCOL/HOST/PORT"),
+    (
+        "proxy-header",
+        "Extra header to include in the request when sending to a proxy",
+    ),
+    (
+        "proxy-user",
+        "Specify the user name and password to use for proxy authentication",
+    ),
+    (
+        "repeat",
+        "Repeat this request NUM times, -1 for infinite loop",
+    ),
+    (
+        "resolve",
+        "Provide a custom address for a specific HOST and PORT pair",
+    ),
+    (
+        "retry",
+        "Maximum number of retries, 0 for no retries, -1 for unlimited retries",
+    ),
+    ("retry-interval", "Interval in milliseconds before a retry"),
+    (
+        "retry-on-capture-failure",
+        "Retry entries when a capture is null or has no value",
+    ),
+    ("skip", "Skip this request"),
+    ("skip-if", "Skip this request if the condition is true"),
+    (
+        "soft-asserts",
+        "Report all failed asserts of an entry and keep running",
+    ),
+    ("trailer", "Send a trailer after the chunked body"),
+    (
+        "unix-socket",
+        "(HTTP) Connect through this Unix domain socket, instead of using the network",
+    ),
+    ("user", "Add basic Authentication header to each request"),
+    ("variable", "Define a variable"),
+    ("verbose", "Turn on verbose output for this request"),
+    ("verbosity", "Set verbosity level for debug log"),
+    (
+        "very-verbose",
+        "Turn on very verbose output, including HTTP response and libcurl logs (alias to --verbosity debug)",
+    ),
+];
+
+/// Names, descriptions, input and output types of the filters.
+const FILTERS: &[(&str, &str, &str, &str)] = &[
+    (
+        "addDays",
+        "Adds a number of days to a date, accepts negative numbers.",
+        "date",
+        "date",
+    ),
+    (
+        "addSeconds",
+        "Adds a number of seconds to a date, accepts negative numbers.",
+        "date",
+        "date",
+    ),
+    (
+        "base64Decode",
+        "Decodes a Base64 encoded string into bytes.",
+        "string",
+        "bytes",
+    ),
+    (
+        "base64Encode",
+        "Encodes bytes into Base64 encoded string.",
+        "bytes",
+        "string",
+    ),
+    (
+        "base64UrlSafeDecode",
+        "Decodes a Base64 encoded string into bytes (using Base64 URL safe encoding).",
+        "string",
+        "bytes",
+    ),
+    (
+        "base64UrlSafeEncode",
+        "Encodes bytes into Base64 encoded string (using Base64 URL safe encoding).",
+        "bytes",
+        "string",
+    ),
+    (
+        "brotliDecompress",
+        "Decompresses Brotli compressed bytes.",
+        "bytes",
+        "bytes",
+    ),
+    (
+        "bytesSlice",
+        "Returns the bytes between a start byte offset (inclusive) and an end byte offset (exclusive) of bytes or string.",
+        "bytes",
+        "bytes",
+    ),
+    (
+        "canonicalize",
+        "Converts a XML string to its canonical form (see Canonical XML).",
+        "string",
+        "string",
+    ),
+    (
+        "charsetDecode",
+        "Decodes bytes to string using a charset encoding.",
+        "bytes",
+        "string",
+    ),
+    (
+        "count",
+        "Counts the number of items in a collection.",
+        "collection",
+        "number",
+    ),
+    (
+        "dateFormat",
+        "Formats a date to a string given a specification format.",
+        "date",
+        "string",
+    ),
+    (
+        "daysAfterNow",
+        "Returns the number of days between now and a date in the future.",
+        "date",
+        "number",
+    ),
+    (
+        "daysBeforeNow",
+        "Returns the number of days between now and a date in the past.",
+        "date",
+        "number",
+    ),
+    (
+        "decode",
+        "Decodes bytes to string using a charset encoding (alias of `charsetDecode`).",
+        "bytes",
+        "string",
+    ),
+    (
+        "first",
+        "Returns the first element from a collection.",
+        "collection",
+        "any",
+    ),
+    (
+        "format",
+        "Formats a date to a string given a specification format (deprecated alias of `dateFormat`).",
+        "date",
+        "string",
+    ),
+    (
+        "fromHex",
+        "Converts an hexadecimal string to bytes.",
+        "string",
+        "bytes",
+    ),
+    (
+        "gunzip",
+        "Decompresses GZip compressed bytes.",
+        "bytes",
+        "bytes",
+    ),
+    (
+        "htmlEscape",
+        "Converts the characters `&`, `<` and `>` to HTML-safe sequence.",
+        "string",
+        "string",
+    ),
+    (
+        "htmlUnescape",
+        "Converts all named and numeric character references (e.g. `&gt;`, `&#62;`, `&#x3e;`) to the corresponding Unicode characters.",
+        "string",
+        "string",
+    ),
+    ("jq", "Evaluates a jq expression.", "string", "any"),
+    (
+        "jsonpath",
+        "Evaluates a JSONPath expression.",
+        "string",
+        "any",
+    ),
+    (
+        "jwtHeader",
+        "Decodes the header of a JWT to a JSON value.",
+        "string",
+        "object",
+    ),
+    (
+        "jwtPayload",
+        "Decodes the payload (claims) of a JWT to a JSON value.",
+        "string",
+        "object",
+    ),
+    (
+        "last",
+        "Returns the last element from a collection.",
+        "collection",
+        "any",
+    ),
+    (
+        "location",
+        "Returns the target location URL of a redirection.",
+        "response",
+        "string",
+    ),
+    (
+        "nth",
+        "Returns the element from a collection at a zero-based index, accepts negative indices for indexing from the end of the collection.",
+        "collection",
+        "any",
+    ),
+    (
+        "parseJson",
+        "Parses a JSON string to a structured value.",
+        "string",
+        "any",
+    ),
+    (
+        "regex",
+        "Extracts regex capture group. Pattern must have at least one capture group.",
+        "string",
+        "string",
+    ),
+    (
+        "replace",
+        "Replaces all occurrences of old string with new string.",
+        "string",
+        "string",
+    ),
+    (
+        "replaceRegex",
+        "Replaces all occurrences of a pattern with new string.",
+        "string",
+        "string",
+    ),
+    (
+        "split",
+        "Splits to a list of strings around occurrences of the specified delimiter.",
+        "string",
+        "string",
+    ),
+    (
+        "toDate",
+        "Converts a string to a date given a specification format.",
+        "string",
+        "date",
+    ),
+    (
+        "toFloat",
+        "Converts value to float number.",
+        "string",
+        "number",
+    ),
+    (
+        "toHex",
+        "Converts bytes to hexadecimal string, with optional uppercase digits and separator.",
+        "bytes",
+        "string",
+    ),
+    (
+        "toInt",
+        "Converts value to integer number.",
+        "string",
+        "number",
+    ),
+    (
+        "toJson",
+        "Serializes value to a JSON string.",
+        "any",
+        "string",
+    ),
+    ("toString", "Converts value to string.", "any", "string"),
+    (
+        "toTimestamp",
+        "Converts a date to the number of seconds since the Unix epoch.",
+        "date",
+        "number",
+    ),
+    (
+        "urlDecode",
+        "Replaces %xx escapes with their single-character equivalent.",
+        "string",
+        "string",
+    ),
+    (
+        "urlEncode",
+        "Percent-encodes all the characters which are not included in unreserved chars (see RFC3986) with the exception of forward slash (/).",
+        "string",
+        "string",
+    ),
+    (
+        "urlQueryParam",
+        "Returns the value of a query parameter in a URL.",
+        "string",
+        "string",
+    ),
+    (
+        "utf8Decode",
+        "Decodes bytes to string using UTF-8 encoding.",
+        "bytes",
+        "string",
+    ),
+    (
+        "utf8Encode",
+        "Encodes a string to bytes using UTF-8 encoding.",
+        "string",
+        "bytes",
+    ),
+    (
+        "xmlEscape",
+        "Converts the characters `&`, `<`, `>`, `\"` and `'` to XML predefined entities.",
+        "string",
+        "string",
+    ),
+    (
+        "xmlUnescape",
+        "Converts XML predefined entities and numeric character references (e.g. `&lt;`, `&#62;`) to the corresponding Unicode characters.",
+        "string",
+        "string",
+    ),
+    ("xpath", "Evaluates a XPath expression.", "string", "string"),
+    (
+        "zstdDecompress",
+        "Decompresses Zstandard compressed bytes.",
+        "bytes",
+        "bytes",
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::parser::parse_hurl_file;
+
+    use super::*;
+
+    fn hover_value(content: &str, pos: Pos) -> Option<String> {
+        let file = parse_hurl_file(content).unwrap();
+        let symbols = Symbols::new(&file);
+        hover(content, &symbols, pos).map(|h| match h.contents {
+            HoverContents::Markup(markup) => markup.value,
+            _ => unreachable!(),
+        })
+    }
+
+    #[test]
+    fn test_hover() {
+        let content = r#"GET https://example.org
+[Options]
+insecure: true
+HTTP 200
+[Asserts]
+header "Date" toDate "%a, %d %b %Y %H:%M:%S GMT" daysBeforeNow < 1
+"#;
+        assert_eq!(
+            hover_value(content, Pos::new(3, 4)).unwrap(),
+            "**insecure** (option)\n\nAllow insecure SSL connections."
+        );
+        assert_eq!(
+            hover_value(content, Pos::new(6, 52)).unwrap(),
+            "**daysBeforeNow** (filter)\n\nReturns the number of days between now and a date in the past.\n\nInput: date, output: number"
+        );
+        assert_eq!(hover_value(content, Pos::new(1, 2)), None);
+        assert_eq!(hover_value(content, Pos::new(3, 10)), None);
+    }
+
+    #[test]
+    fn test_docs_are_sorted() {
+        assert!(OPTIONS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(FILTERS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+}
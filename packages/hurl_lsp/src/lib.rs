@@ -0,0 +1,26 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! A language server for Hurl files, providing diagnostics, go-to-definition of variables, hover
+//! documentation of options and filters, and formatting.
+pub mod definition;
+pub mod diagnostic;
+pub mod format;
+pub mod hover;
+pub mod position;
+pub mod server;
+pub mod symbols;
@@ -0,0 +1,47 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::{env, process};
+
+use hurl_lsp::server;
+use lsp_server::Connection;
+
+const EXIT_OK: i32 = 0;
+const EXIT_ERROR: i32 = 1;
+
+/// Executes `hurl-lsp` entry point: the server talks to its client through stdin and stdout.
+fn main() {
+    if env::args()
+        .skip(1)
+        .any(|arg| arg == "--version" || arg == "-V")
+    {
+        println!("hurl-lsp {}", env!("CARGO_PKG_VERSION"));
+        process::exit(EXIT_OK);
+    }
+
+    let (connection, io_threads) = Connection::stdio();
+    if let Err(error) = server::run(&connection) {
+        eprintln!("error: {error}");
+        process::exit(EXIT_ERROR);
+    }
+    // The connection must be dropped to close the writer thread.
+    drop(connection);
+    if let Err(error) = io_threads.join() {
+        eprintln!("error: {error}");
+        process::exit(EXIT_ERROR);
+    }
+}
@@ -0,0 +1,99 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Conversions between Hurl positions and LSP positions.
+//!
+//! Hurl positions are 1-based lines and columns, columns being counted in chars, whereas LSP
+//! positions are 0-based lines and characters, characters being counted in UTF-16 code units.
+use hurl_core::ast::SourceInfo;
+use hurl_core::reader::Pos;
+use lsp_types::{Position, Range};
+
+/// Converts a Hurl position `pos` in `text` to a LSP position.
+pub fn to_lsp_position(text: &str, pos: Pos) -> Position {
+    let line_index = pos.line.saturating_sub(1);
+    let line = text.lines().nth(line_index).unwrap_or_default();
+    let character = line
+        .chars()
+        .take(pos.column.saturating_sub(1))
+        .map(char::len_utf16)
+        .sum::<usize>();
+    Position::new(line_index as u32, character as u32)
+}
+
+/// Converts a Hurl `source_info` in `text` to a LSP range.
+pub fn to_lsp_range(text: &str, source_info: SourceInfo) -> Range {
+    Range::new(
+        to_lsp_position(text, source_info.start),
+        to_lsp_position(text, source_info.end),
+    )
+}
+
+/// Converts a LSP `position` in `text` to a Hurl position.
+pub fn to_hurl_pos(text: &str, position: Position) -> Pos {
+    let line_index = position.line as usize;
+    let line = text.lines().nth(line_index).unwrap_or_default();
+    let mut units = 0;
+    let mut column = 1;
+    for c in line.chars() {
+        if units >= position.character as usize {
+            break;
+        }
+        units += c.len_utf16();
+        column += 1;
+    }
+    Pos::new(line_index + 1, column)
+}
+
+/// Returns `true` if `pos` is in the range of `source_info`, the end being exclusive.
+pub fn contains(source_info: SourceInfo, pos: Pos) -> bool {
+    let start = (source_info.start.line, source_info.start.column);
+    let end = (source_info.end.line, source_info.end.column);
+    let pos = (pos.line, pos.column);
+    start <= pos && pos < end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_lsp_position() {
+        let text = "GET https://example.org\nx-emoji: 🦀 value\n";
+        assert_eq!(to_lsp_position(text, Pos::new(1, 1)), Position::new(0, 0));
+        assert_eq!(to_lsp_position(text, Pos::new(1, 5)), Position::new(0, 4));
+        // The crab emoji is one char, but two UTF-16 code units.
+        assert_eq!(to_lsp_position(text, Pos::new(2, 12)), Position::new(1, 12));
+    }
+
+    #[test]
+    fn test_to_hurl_pos() {
+        let text = "GET https://example.org\nx-emoji: 🦀 value\n";
+        assert_eq!(to_hurl_pos(text, Position::new(0, 4)), Pos::new(1, 5));
+        assert_eq!(to_hurl_pos(text, Position::new(1, 12)), Pos::new(2, 12));
+    }
+
+    #[test]
+    fn test_contains() {
+        let source_info = SourceInfo::new(Pos::new(2, 3), Pos::new(2, 7));
+        assert!(!contains(source_info, Pos::new(2, 2)));
+        assert!(contains(source_info, Pos::new(2, 3)));
+        assert!(contains(source_info, Pos::new(2, 6)));
+        assert!(!contains(source_info, Pos::new(2, 7)));
+        assert!(!contains(source_info, Pos::new(1, 4)));
+    }
+}
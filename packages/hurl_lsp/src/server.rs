@@ -0,0 +1,346 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::collections::HashMap;
+use std::env;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+use hurl_core::ast::HurlFile;
+use hurl_core::parser::{self, ParseError};
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+    Notification as LspNotification, PublishDiagnostics,
+};
+use lsp_types::request::{Formatting, GotoDefinition, HoverRequest, Request as LspRequest};
+use lsp_types::{
+    DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+    DocumentFormattingParams, GotoDefinitionParams, GotoDefinitionResponse, Hover, HoverParams,
+    HoverProviderCapability, Location, OneOf, PublishDiagnosticsParams, ServerCapabilities,
+    TextDocumentSyncCapability, TextDocumentSyncKind, TextEdit, Uri,
+};
+
+use crate::diagnostic::diagnostic;
+use crate::position::{to_hurl_pos, to_lsp_range};
+use crate::symbols::Symbols;
+use crate::{definition, format, hover};
+
+/// An open Hurl document, with its parsed file.
+struct Document {
+    text: String,
+    file: Result<HurlFile, ParseError>,
+}
+
+impl Document {
+    fn new(text: String) -> Self {
+        let file = parser::parse_hurl_file(&text);
+        Document { text, file }
+    }
+}
+
+/// A language server for Hurl files, communicating with a client through a `connection`.
+pub struct Server<'a> {
+    connection: &'a Connection,
+    documents: HashMap<Uri, Document>,
+}
+
+/// Returns the capabilities of the Hurl language server.
+pub fn capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        definition_provider: Some(OneOf::Left(true)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        document_formatting_provider: Some(OneOf::Left(true)),
+        ..Default::default()
+    }
+}
+
+/// Initializes the session with the client of `connection` and serves its requests until the
+/// client shuts the server down.
+pub fn run(connection: &Connection) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let capabilities = serde_json::to_value(capabilities())?;
+    connection.initialize(capabilities)?;
+    let mut server = Server::new(connection);
+    for message in &connection.receiver {
+        if let Message::Request(request) = &message
+            && connection.handle_shutdown(request)?
+        {
+            return Ok(());
+        }
+        server.handle_message(message)?;
+    }
+    Ok(())
+}
+
+impl<'a> Server<'a> {
+    /// Creates a new server, without any open document.
+    pub fn new(connection: &'a Connection) -> Self {
+        Server {
+            connection,
+            documents: HashMap::new(),
+        }
+    }
+
+    /// Handles a `message` of the client.
+    pub fn handle_message(&mut self, message: Message) -> Result<(), Box<dyn Error + Send + Sync>> {
+        match message {
+            Message::Request(request) => {
+                let response = self.handle_request(request);
+                self.connection.sender.send(Message::Response(response))?;
+            }
+            Message::Notification(notification) => self.handle_notification(notification)?,
+            Message::Response(_) => {}
+        }
+        Ok(())
+    }
+
+    fn handle_request(&self, request: Request) -> Response {
+        match request.method.as_str() {
+            GotoDefinition::METHOD => {
+                respond::<GotoDefinition>(request, |params| self.definition(params))
+            }
+            HoverRequest::METHOD => respond::<HoverRequest>(request, |params| self.hover(params)),
+            Formatting::METHOD => respond::<Formatting>(request, |params| self.format(params)),
+            method => Response::new_err(
+                request.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("Unsupported request {method}"),
+            ),
+        }
+    }
+
+    fn handle_notification(
+        &mut self,
+        notification: Notification,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: DidOpenTextDocumentParams =
+                    serde_json::from_value(notification.params)?;
+                let uri = params.text_document.uri;
+                self.documents
+                    .insert(uri.clone(), Document::new(params.text_document.text));
+                self.publish_diagnostics(uri)?;
+            }
+            DidChangeTextDocument::METHOD => {
+                let params: DidChangeTextDocumentParams =
+                    serde_json::from_value(notification.params)?;
+                let uri = params.text_document.uri;
+                // With full text synchronization, the last change holds the whole document.
+                if let Some(change) = params.content_changes.into_iter().next_back() {
+                    self.documents
+                        .insert(uri.clone(), Document::new(change.text));
+                    self.publish_diagnostics(uri)?;
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: DidCloseTextDocumentParams =
+                    serde_json::from_value(notification.params)?;
+                self.documents.remove(&params.text_document.uri);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Sends the diagnostics of the document `uri` to the client.
+    fn publish_diagnostics(&self, uri: Uri) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let Some(document) = self.documents.get(&uri) else {
+            return Ok(());
+        };
+        let diagnostics = match &document.file {
+            Ok(_) => vec![],
+            Err(error) => vec![diagnostic(&document.text, error)],
+        };
+        let params = PublishDiagnosticsParams::new(uri, diagnostics, None);
+        let notification = Notification::new(PublishDiagnostics::METHOD.to_string(), params);
+        self.connection
+            .sender
+            .send(Message::Notification(notification))?;
+        Ok(())
+    }
+
+    fn definition(&self, params: GotoDefinitionParams) -> Option<GotoDefinitionResponse> {
+        let params = params.text_document_position_params;
+        let uri = params.text_document.uri;
+        let document = self.documents.get(&uri)?;
+        let file = document.file.as_ref().ok()?;
+        let symbols = Symbols::new(file);
+        let pos = to_hurl_pos(&document.text, params.position);
+        let definition = definition::definition(&symbols, pos)?;
+        let range = to_lsp_range(&document.text, definition.source_info);
+        Some(GotoDefinitionResponse::Scalar(Location::new(uri, range)))
+    }
+
+    fn hover(&self, params: HoverParams) -> Option<Hover> {
+        let params = params.text_document_position_params;
+        let document = self.documents.get(&params.text_document.uri)?;
+        let file = document.file.as_ref().ok()?;
+        let symbols = Symbols::new(file);
+        let pos = to_hurl_pos(&document.text, params.position);
+        hover::hover(&document.text, &symbols, pos)
+    }
+
+    fn format(&self, params: DocumentFormattingParams) -> Option<Vec<TextEdit>> {
+        let uri = params.text_document.uri;
+        let document = self.documents.get(&uri)?;
+        let file = document.file.as_ref().ok()?;
+        let dir = document_dir(&uri)
+            .or_else(|| env::current_dir().ok())
+            .unwrap_or_default();
+        let config = format::lint_config(&dir);
+        Some(format::format(&document.text, file, &config))
+    }
+}
+
+/// Returns the response to a `request` of type `R`, computed by `handler`.
+fn respond<R: LspRequest>(
+    request: Request,
+    handler: impl FnOnce(R::Params) -> R::Result,
+) -> Response {
+    match serde_json::from_value::<R::Params>(request.params) {
+        Ok(params) => Response::new_ok(request.id, handler(params)),
+        Err(error) => Response::new_err(
+            request.id,
+            ErrorCode::InvalidParams as i32,
+            error.to_string(),
+        ),
+    }
+}
+
+/// Returns the directory of a document `uri`, if the document is a local file.
+fn document_dir(uri: &Uri) -> Option<PathBuf> {
+    if uri.scheme()?.as_str() != "file" {
+        return None;
+    }
+    let path = uri.path().as_estr().decode().into_string().ok()?;
+    Path::new(path.as_ref()).parent().map(Path::to_path_buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_server::RequestId;
+    use lsp_types::{
+        Position, Range, TextDocumentIdentifier, TextDocumentItem, TextDocumentPositionParams,
+    };
+
+    use super::*;
+
+    fn uri() -> Uri {
+        "file:///tmp/test.hurl".parse().unwrap()
+    }
+
+    fn open(server: &mut Server, text: &str) {
+        let params = DidOpenTextDocumentParams {
+            text_document: TextDocumentItem::new(uri(), "hurl".to_string(), 1, text.to_string()),
+        };
+        let notification = Notification::new(DidOpenTextDocument::METHOD.to_string(), params);
+        server
+            .handle_message(Message::Notification(notification))
+            .unwrap();
+    }
+
+    fn position_params(line: u32, character: u32) -> TextDocumentPositionParams {
+        TextDocumentPositionParams::new(
+            TextDocumentIdentifier::new(uri()),
+            Position::new(line, character),
+        )
+    }
+
+    #[test]
+    fn test_publish_diagnostics() {
+        let (server_connection, client_connection) = Connection::memory();
+        let mut server = Server::new(&server_connection);
+        open(
+            &mut server,
+            "GET https://example.org\nHTTP 200\n[Asserts]\nstatus === 200\n",
+        );
+
+        let Message::Notification(notification) = client_connection.receiver.recv().unwrap() else {
+            panic!("expecting a notification");
+        };
+        let params: PublishDiagnosticsParams = serde_json::from_value(notification.params).unwrap();
+        assert_eq!(params.uri, uri());
+        assert_eq!(params.diagnostics.len(), 1);
+        assert_eq!(
+            params.diagnostics[0].range,
+            Range::new(Position::new(3, 9), Position::new(3, 10))
+        );
+    }
+
+    #[test]
+    fn test_definition() {
+        let (server_connection, client_connection) = Connection::memory();
+        let mut server = Server::new(&server_connection);
+        open(
+            &mut server,
+            "GET https://example.org\nHTTP 200\n[Captures]\nid: jsonpath \"$.id\"\nGET https://example.org/{{id}}\n",
+        );
+        let _ = client_connection.receiver.recv().unwrap();
+
+        let params = GotoDefinitionParams {
+            text_document_position_params: position_params(4, 27),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+        let request = Request::new(
+            RequestId::from(1),
+            GotoDefinition::METHOD.to_string(),
+            params,
+        );
+        server.handle_message(Message::Request(request)).unwrap();
+
+        let Message::Response(response) = client_connection.receiver.recv().unwrap() else {
+            panic!("expecting a response");
+        };
+        let result: GotoDefinitionResponse =
+            serde_json::from_value(response.result.unwrap()).unwrap();
+        assert_eq!(
+            result,
+            GotoDefinitionResponse::Scalar(Location::new(
+                uri(),
+                Range::new(Position::new(3, 0), Position::new(3, 2))
+            ))
+        );
+    }
+
+    #[test]
+    fn test_unsupported_request() {
+        let (server_connection, client_connection) = Connection::memory();
+        let mut server = Server::new(&server_connection);
+        let request = Request::new(RequestId::from(1), "foo".to_string(), ());
+        server.handle_message(Message::Request(request)).unwrap();
+
+        let Message::Response(response) = client_connection.receiver.recv().unwrap() else {
+            panic!("expecting a response");
+        };
+        assert_eq!(
+            response.error.unwrap().code,
+            ErrorCode::MethodNotFound as i32
+        );
+    }
+
+    #[test]
+    fn test_document_dir() {
+        assert_eq!(
+            document_dir(&"file:///tmp/my%20tests/test.hurl".parse().unwrap()),
+            Some(PathBuf::from("/tmp/my tests"))
+        );
+        assert_eq!(document_dir(&"untitled:Untitled-1".parse().unwrap()), None);
+    }
+}
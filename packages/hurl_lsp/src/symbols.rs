@@ -0,0 +1,212 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::visit::{self, Visitor};
+use hurl_core::ast::{
+    Capture, EntryOption, ExprKind, Filter, HurlFile, JsonValue, MultilineString,
+    MultilineStringKind, Placeholder, SourceInfo, Template, TemplateElement, VariableDefinition,
+};
+use hurl_core::reader::Pos;
+
+/// A named symbol of a Hurl file, with its position.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Symbol {
+    pub name: String,
+    pub source_info: SourceInfo,
+}
+
+/// The symbols of a Hurl file used by the language server features.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Symbols {
+    /// Variables definitions, from `variable` options and captures, in the file order.
+    pub definitions: Vec<Symbol>,
+    /// Variables used in placeholders.
+    pub references: Vec<Symbol>,
+    /// Options names.
+    pub options: Vec<Symbol>,
+    /// Filters names.
+    pub filters: Vec<Symbol>,
+}
+
+impl Symbols {
+    /// Collects the symbols of a Hurl `file`.
+    pub fn new(file: &HurlFile) -> Self {
+        let mut symbols = Symbols::default();
+        symbols.visit_hurl_file(file);
+        symbols
+    }
+
+    fn push_template(&mut self, template: &Template) {
+        for element in &template.elements {
+            if let TemplateElement::Placeholder(placeholder) = element {
+                self.visit_placeholder(placeholder);
+            }
+        }
+    }
+
+    fn push_json(&mut self, value: &JsonValue) {
+        match value {
+            JsonValue::Placeholder(placeholder) => self.visit_placeholder(placeholder),
+            JsonValue::String(template) => self.push_template(template),
+            JsonValue::List { elements, .. } => {
+                elements.iter().for_each(|e| self.push_json(&e.value));
+            }
+            JsonValue::Object { elements, .. } => {
+                for element in elements {
+                    self.push_template(&element.name);
+                    self.push_json(&element.value);
+                }
+            }
+            JsonValue::Number(_) | JsonValue::Boolean(_) | JsonValue::Null => {}
+        }
+    }
+}
+
+/// Returns the source info of an identifier `name` starting at `start`.
+fn identifier_source_info(start: Pos, name: &str) -> SourceInfo {
+    let end = Pos::new(start.line, start.column + name.chars().count());
+    SourceInfo::new(start, end)
+}
+
+impl Visitor for Symbols {
+    fn visit_capture(&mut self, capture: &Capture) {
+        self.definitions.push(Symbol {
+            name: capture.name.to_string(),
+            source_info: capture.name.source_info,
+        });
+        visit::walk_capture(self, capture);
+    }
+
+    fn visit_entry_option(&mut self, option: &EntryOption) {
+        let name = option.kind.identifier();
+        self.options.push(Symbol {
+            name: name.to_string(),
+            source_info: identifier_source_info(option.space0.source_info.end, name),
+        });
+        visit::walk_entry_option(self, option);
+    }
+
+    fn visit_filename(&mut self, filename: &Template) {
+        self.push_template(filename);
+    }
+
+    fn visit_filter(&mut self, filter: &Filter) {
+        let name = filter.value.identifier();
+        self.filters.push(Symbol {
+            name: name.to_string(),
+            source_info: identifier_source_info(filter.source_info.start, name),
+        });
+        visit::walk_filter(self, filter);
+    }
+
+    fn visit_json_body(&mut self, json: &JsonValue) {
+        self.push_json(json);
+    }
+
+    fn visit_multiline_string(&mut self, string: &MultilineString) {
+        match &string.kind {
+            MultilineStringKind::Text(template)
+            | MultilineStringKind::Json(template)
+            | MultilineStringKind::Xml(template)
+            | MultilineStringKind::Raw(template) => self.push_template(template),
+            MultilineStringKind::GraphQl(graphql) => {
+                self.push_template(&graphql.value);
+                if let Some(variables) = &graphql.variables {
+                    self.push_json(&variables.value);
+                }
+            }
+        }
+    }
+
+    fn visit_placeholder(&mut self, placeholder: &Placeholder) {
+        if let ExprKind::Variable(variable) = &placeholder.expr.kind {
+            self.references.push(Symbol {
+                name: variable.name.clone(),
+                source_info: variable.source_info,
+            });
+        }
+    }
+
+    fn visit_template(&mut self, template: &Template) {
+        self.push_template(template);
+    }
+
+    fn visit_url(&mut self, url: &Template) {
+        self.push_template(url);
+    }
+
+    fn visit_variable_def(&mut self, def: &VariableDefinition) {
+        self.definitions.push(Symbol {
+            name: def.name.clone(),
+            source_info: identifier_source_info(def.source_info.start, &def.name),
+        });
+        visit::walk_variable_def(self, def);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::parser::parse_hurl_file;
+
+    use super::*;
+
+    fn symbol(name: &str, start: (usize, usize), end: (usize, usize)) -> Symbol {
+        Symbol {
+            name: name.to_string(),
+            source_info: SourceInfo::new(Pos::new(start.0, start.1), Pos::new(end.0, end.1)),
+        }
+    }
+
+    #[test]
+    fn test_symbols() {
+        let content = r#"GET https://{{host}}/users
+[Options]
+variable: id=1
+HTTP 200
+[Captures]
+name: jsonpath "$.name" toString
+"#;
+        let file = parse_hurl_file(content).unwrap();
+        let symbols = Symbols::new(&file);
+        assert_eq!(
+            symbols.definitions,
+            vec![
+                symbol("id", (3, 11), (3, 13)),
+                symbol("name", (6, 1), (6, 5))
+            ]
+        );
+        assert_eq!(symbols.references, vec![symbol("host", (1, 15), (1, 19))]);
+        assert_eq!(symbols.options, vec![symbol("variable", (3, 1), (3, 9))]);
+        assert_eq!(symbols.filters, vec![symbol("toString", (6, 25), (6, 33))]);
+    }
+
+    #[test]
+    fn test_symbols_json_body() {
+        let content = r#"POST https://example.org
+{"id": {{id}}, "name": "{{name}}"}
+"#;
+        let file = parse_hurl_file(content).unwrap();
+        let symbols = Symbols::new(&file);
+        assert_eq!(
+            symbols.references,
+            vec![
+                symbol("id", (2, 10), (2, 12)),
+                symbol("name", (2, 27), (2, 31))
+            ]
+        );
+    }
+}
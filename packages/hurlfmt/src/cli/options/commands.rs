@@ -52,11 +52,21 @@ pub fn in_place() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn input_dir() -> clap::Arg {
+    clap::Arg::new("input_dir")
+        .long("dir")
+        .value_name("DIR")
+        .help("Export all Hurl files of DIR (html output only)")
+        .conflicts_with("in_place")
+        .conflicts_with("check")
+        .num_args(1)
+}
+
 pub fn input_format() -> clap::Arg {
     clap::Arg::new("input_format")
         .long("in")
         .value_name("FORMAT")
-        .help("Specify input format: hurl or curl [default: hurl]")
+        .help("Specify input format: hurl, curl or json [default: hurl]")
         .num_args(1)
 }
 
@@ -77,15 +87,31 @@ pub fn output() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn output_dir() -> clap::Arg {
+    clap::Arg::new("output_dir")
+        .long("output-dir")
+        .value_name("DIR")
+        .help("Write exported HTML pages to DIR")
+        .conflicts_with("output")
+        .num_args(1)
+}
+
 pub fn output_format() -> clap::Arg {
     clap::Arg::new("output_format")
         .long("out")
         .value_name("FORMAT")
-        .help("Specify output format: hurl, json or html [default: hurl]")
+        .help("Specify output format: hurl, json, html, md, python or js [default: hurl]")
         .conflicts_with("check")
         .num_args(1)
 }
 
+pub fn pretty() -> clap::Arg {
+    clap::Arg::new("pretty")
+        .long("pretty")
+        .help("Pretty-print Hurl files: align colons, sort options and reflow long JSON bodies")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn standalone() -> clap::Arg {
     clap::Arg::new("standalone")
         .long("standalone")
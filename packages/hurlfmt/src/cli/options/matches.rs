@@ -46,6 +46,7 @@ pub fn input_format(arg_matches: &ArgMatches) -> Result<InputFormat, OptionsErro
     {
         "hurl" => Ok(InputFormat::Hurl),
         "curl" => Ok(InputFormat::Curl),
+        "json" => Ok(InputFormat::Json),
         v => Err(OptionsError::Error(format!("Invalid input format {v}"))),
     }
 }
@@ -58,6 +59,9 @@ pub fn output_format(arg_matches: &ArgMatches) -> Result<OutputFormat, OptionsEr
         "hurl" => Ok(OutputFormat::Hurl),
         "json" => Ok(OutputFormat::Json),
         "html" => Ok(OutputFormat::Html),
+        "js" => Ok(OutputFormat::Js),
+        "md" => Ok(OutputFormat::Markdown),
+        "python" => Ok(OutputFormat::Python),
         v => Err(OptionsError::Error(format!("Invalid output format {v}"))),
     }
 }
@@ -96,7 +100,8 @@ pub fn input_files(arg_matches: &ArgMatches) -> Result<Vec<Input>, OptionsError>
             files.push(file);
         }
     }
-    if files.is_empty() && !io::stdin().is_terminal() {
+    // When exporting a directory, we don't read Hurl content from standard input.
+    if files.is_empty() && !has_input_dir(arg_matches) && !io::stdin().is_terminal() {
         let input = match Input::from_stdin() {
             Ok(input) => input,
             Err(err) => return Err(OptionsError::Error(err.to_string())),
@@ -106,10 +111,70 @@ pub fn input_files(arg_matches: &ArgMatches) -> Result<Vec<Input>, OptionsError>
     Ok(files)
 }
 
+/// Returns the input directory of the Hurl files to export to HTML.
+pub fn input_dir(arg_matches: &ArgMatches) -> Result<Option<PathBuf>, OptionsError> {
+    let Some(dir) = get_string(arg_matches, "input_dir") else {
+        return Ok(None);
+    };
+    let dir = Path::new(&dir);
+    if !dir.is_dir() {
+        return Err(OptionsError::Error(format!(
+            "error: Cannot access '{}': No such directory",
+            dir.display()
+        )));
+    }
+    if get_string(arg_matches, "output_format") != Some("html".to_string()) {
+        return Err(OptionsError::Error(
+            "use --dir option only with html output".to_string(),
+        ));
+    }
+    if get_string(arg_matches, "input_files").is_some() {
+        return Err(OptionsError::Error(
+            "use --dir option without input files".to_string(),
+        ));
+    }
+    if get_string(arg_matches, "output_dir").is_none() {
+        return Err(OptionsError::Error(
+            "use --dir option with --output-dir option".to_string(),
+        ));
+    }
+    Ok(Some(dir.to_path_buf()))
+}
+
+fn has_input_dir(arg_matches: &ArgMatches) -> bool {
+    get_string(arg_matches, "input_dir").is_some()
+}
+
+/// Returns the output directory of the exported HTML pages.
+pub fn output_dir(arg_matches: &ArgMatches) -> Result<Option<PathBuf>, OptionsError> {
+    let Some(dir) = get_string(arg_matches, "output_dir") else {
+        return Ok(None);
+    };
+    if !has_input_dir(arg_matches) {
+        return Err(OptionsError::Error(
+            "use --output-dir option only with --dir option".to_string(),
+        ));
+    }
+    Ok(Some(Path::new(&dir).to_path_buf()))
+}
+
 pub fn output_file(arg_matches: &ArgMatches) -> Option<PathBuf> {
     get_string(arg_matches, "output").map(|s| Path::new(&s).to_path_buf())
 }
 
+pub fn pretty(arg_matches: &ArgMatches) -> Result<bool, OptionsError> {
+    if has_flag(arg_matches, "pretty") {
+        match get_string(arg_matches, "output_format").as_deref() {
+            None | Some("hurl") => Ok(true),
+            _ => Err(OptionsError::Error(
+                "use --pretty option only with hurl output".to_string(),
+            )),
+        }
+    } else {
+        Ok(false)
+    }
+}
+
 pub fn standalone(arg_matches: &ArgMatches) -> Result<bool, OptionsError> {
     if has_flag(arg_matches, "standalone") {
         if get_string(arg_matches, "output_format") != Some("html".to_string()) {
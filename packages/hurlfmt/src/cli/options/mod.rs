@@ -18,21 +18,30 @@
 mod commands;
 mod matches;
 
-use std::env;
 use std::path::PathBuf;
+use std::{env, fs};
 
 use clap::ArgMatches;
 use hurl_core::input::Input;
 
+use crate::linter::LintConfig;
+
+/// Name of the `hurlfmt` configuration file, searched in the current directory and its parents.
+const CONFIG_FILENAME: &str = ".hurlfmt.toml";
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Options {
     pub check: bool,
     pub color: Option<bool>,
     pub in_place: bool,
+    pub input_dir: Option<PathBuf>,
     pub input_files: Vec<Input>,
     pub input_format: InputFormat,
+    pub lint_config: LintConfig,
+    pub output_dir: Option<PathBuf>,
     pub output_file: Option<PathBuf>,
     pub output_format: OutputFormat,
+    pub pretty: bool,
     pub standalone: bool,
 }
 
@@ -40,6 +49,7 @@ pub struct Options {
 pub enum InputFormat {
     Curl,
     Hurl,
+    Json,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -47,6 +57,9 @@ pub enum OutputFormat {
     Hurl,
     Json,
     Html,
+    Js,
+    Markdown,
+    Python,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -73,17 +86,20 @@ pub fn parse() -> Result<Options, OptionsError> {
         .arg(commands::check())
         .arg(commands::color())
         .arg(commands::in_place())
+        .arg(commands::input_dir())
         .arg(commands::input_files())
         .arg(commands::input_format())
         .arg(commands::no_color())
         .arg(commands::output())
+        .arg(commands::output_dir())
         .arg(commands::output_format())
+        .arg(commands::pretty())
         .arg(commands::standalone());
 
     let arg_matches = command.try_get_matches_from_mut(env::args_os())?;
     let opts = parse_matches(&arg_matches)?;
 
-    if opts.input_files.is_empty() {
+    if opts.input_files.is_empty() && opts.input_dir.is_none() {
         let help = command.render_help().to_string();
         return Err(OptionsError::Error(help));
     }
@@ -94,19 +110,54 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<Options, OptionsError> {
     let check = matches::check(arg_matches);
     let color = matches::color(arg_matches);
     let in_place = matches::in_place(arg_matches)?;
+    let input_dir = matches::input_dir(arg_matches)?;
     let input_files = matches::input_files(arg_matches)?;
     let input_format = matches::input_format(arg_matches)?;
+    let lint_config = lint_config()?;
+    let output_dir = matches::output_dir(arg_matches)?;
     let output_file = matches::output_file(arg_matches);
     let output_format = matches::output_format(arg_matches)?;
+    let pretty = matches::pretty(arg_matches)?;
     let standalone = matches::standalone(arg_matches)?;
     Ok(Options {
         check,
         color,
         in_place,
+        input_dir,
         input_files,
         input_format,
+        lint_config,
+        output_dir,
         output_file,
         output_format,
+        pretty,
         standalone,
     })
 }
+
+/// Returns the formatting rules of the nearest `.hurlfmt.toml` configuration file, starting from
+/// the current directory, or the default rules if there is no configuration file.
+fn lint_config() -> Result<LintConfig, OptionsError> {
+    let Ok(current_dir) = env::current_dir() else {
+        return Ok(LintConfig::default());
+    };
+    let Some(path) = current_dir
+        .ancestors()
+        .map(|dir| dir.join(CONFIG_FILENAME))
+        .find(|path| path.is_file())
+    else {
+        return Ok(LintConfig::default());
+    };
+    let content = fs::read_to_string(&path).map_err(|e| {
+        OptionsError::Error(format!(
+            "error: Cannot read config file '{}': {e}",
+            path.display()
+        ))
+    })?;
+    LintConfig::parse(&content).map_err(|message| {
+        OptionsError::Error(format!(
+            "error: Invalid config file '{}': {message}",
+            path.display()
+        ))
+    })
+}
@@ -0,0 +1,531 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Export of Hurl files to JavaScript modules, using the [Fetch API](https://developer.mozilla.org/en-US/docs/Web/API/Fetch_API).
+//!
+//! Scripts use top-level `await` and some Node.js APIs (like `Buffer` or `readFile`), they can be
+//! run with `node script.mjs`.
+use std::collections::BTreeSet;
+
+use base64::Engine;
+use base64::engine::general_purpose;
+use hurl_core::ast::{
+    Bytes, ExprKind, Function, HurlFile, JsonValue, KeyValue, MultilineStringKind, MultipartParam,
+    Placeholder, Template, TemplateElement, VariableValue,
+};
+
+use super::{BasicAuth, CodeRequest, Statement, hex, literal, statements};
+
+/// Indentation of the options of a request call.
+const INDENT: usize = 2;
+
+/// Helper building a multipart form data body from a list of `[name, value, filename]`.
+const FORM_DATA_HELPER: &str = r#"function formData(entries) {
+  const form = new FormData();
+  for (const [name, value, filename] of entries) {
+    form.append(name, value, filename);
+  }
+  return form;
+}
+"#;
+
+/// Returns a JavaScript module running the requests of a `hurl_file`.
+pub fn format(hurl_file: &HurlFile) -> String {
+    let mut script = Script::default();
+    let mut code = String::new();
+    let mut previous_is_variable = false;
+    for statement in statements(hurl_file) {
+        match statement {
+            Statement::Variable(variable) => {
+                if !previous_is_variable {
+                    code.push('\n');
+                }
+                previous_is_variable = true;
+                let value = script.variable_value(&variable.value);
+                code.push_str(&format!(
+                    "variables[{}] = {value};\n",
+                    quote(&variable.name)
+                ));
+                script.defined.insert(variable.name.clone());
+            }
+            Statement::Request(request) => {
+                previous_is_variable = false;
+                code.push('\n');
+                code.push_str(&script.request(&request));
+            }
+        }
+    }
+    script.prelude() + &code
+}
+
+/// State of a JavaScript module being generated.
+#[derive(Default)]
+struct Script {
+    /// Modules imported by the script.
+    imports: BTreeSet<&'static str>,
+    /// Names of the variables used by the requests.
+    used: BTreeSet<String>,
+    /// Names of the variables defined by the script.
+    defined: BTreeSet<String>,
+    /// Whether certificates are not verified.
+    insecure: bool,
+    /// Whether the multipart form data helper is used.
+    form_data: bool,
+}
+
+impl Script {
+    /// Returns the imports and declarations of this script.
+    fn prelude(&self) -> String {
+        let mut s = String::new();
+        for import in &self.imports {
+            s.push_str(import);
+            s.push('\n');
+        }
+        if !self.imports.is_empty() {
+            s.push('\n');
+        }
+        if self.insecure {
+            s.push_str("// Certificates are not verified (insecure option).\n");
+            s.push_str("process.env.NODE_TLS_REJECT_UNAUTHORIZED = \"0\";\n\n");
+        }
+        if self.form_data {
+            s.push_str(FORM_DATA_HELPER);
+            s.push('\n');
+        }
+        if !self.used.is_empty() || !self.defined.is_empty() {
+            let undefined = self.used.difference(&self.defined).collect::<Vec<_>>();
+            if undefined.is_empty() {
+                s.push_str("const variables = {};\n");
+            } else {
+                s.push_str("// Values of the variables used by the requests.\n");
+                s.push_str("const variables = {\n");
+                for name in undefined {
+                    s.push_str(&format!("  {}: \"\",\n", quote(name)));
+                }
+                s.push_str("};\n");
+            }
+            s.push('\n');
+        }
+        s.push_str("let response;\n");
+        s
+    }
+
+    /// Returns the JavaScript call sending a `request`.
+    fn request(&mut self, request: &CodeRequest) -> String {
+        let mut url = self.string(request.url);
+        if !request.query.is_empty() {
+            let separator = if request.url.to_string().contains('?') {
+                "&"
+            } else {
+                "?"
+            };
+            let params = self.pairs(request.query);
+            url = format!("{url} + \"{separator}\" + new URLSearchParams({params})");
+        }
+
+        let mut options = vec![];
+        if request.method != "GET" {
+            options.push(format!("method: {}", quote(&request.method)));
+        }
+        let mut headers = request
+            .headers
+            .iter()
+            .map(|h| (self.string(&h.key), self.string(&h.value)))
+            .collect::<Vec<_>>();
+        if let Some(content_type) = request.content_type {
+            headers.push((quote("Content-Type"), quote(content_type)));
+        }
+        if !request.cookies.is_empty() {
+            let cookies = request
+                .cookies
+                .iter()
+                .map(|c| {
+                    let name = self.string(&c.name);
+                    let value = self.string(&c.value);
+                    format!("{name} + \"=\" + {value}")
+                })
+                .collect::<Vec<_>>();
+            let cookies = format!("[{}].join(\"; \")", cookies.join(", "));
+            headers.push((quote("Cookie"), cookies));
+        }
+        match &request.basic_auth {
+            Some(BasicAuth::KeyValue(kv)) => {
+                let user = self.string(&kv.key);
+                let password = self.string(&kv.value);
+                let value = format!("\"Basic \" + btoa({user} + \":\" + {password})");
+                headers.push((quote("Authorization"), value));
+            }
+            Some(BasicAuth::User(user)) => {
+                let user = self.string(user);
+                headers.push((quote("Authorization"), format!("\"Basic \" + btoa({user})")));
+            }
+            None => {}
+        }
+        if !headers.is_empty() {
+            options.push(format!("headers: {}", object(&headers)));
+        }
+        if !request.form.is_empty() {
+            let params = self.pairs(request.form);
+            options.push(format!("body: new URLSearchParams({params})"));
+        }
+        if !request.multipart.is_empty() {
+            options.push(format!("body: {}", self.multipart(request.multipart)));
+        }
+        if let Some(body) = request.body {
+            options.push(format!("body: {}", self.body(body)));
+        }
+        if !request.follow_location {
+            options.push("redirect: \"manual\"".to_string());
+        }
+        if let Some(timeout) = request.timeout {
+            options.push(format!("signal: AbortSignal.timeout({timeout})"));
+        }
+        if request.insecure {
+            self.insecure = true;
+        }
+
+        let mut s = if options.is_empty() {
+            format!("response = await fetch({url});\n")
+        } else {
+            let mut s = format!("response = await fetch({url}, {{\n");
+            for option in options {
+                s.push_str(&format!("{}{option},\n", " ".repeat(INDENT)));
+            }
+            s.push_str("});\n");
+            s
+        };
+        if let Some(status) = request.status {
+            s.push_str(&format!("if (response.status !== {status}) {{\n"));
+            s.push_str(&format!(
+                "{}throw new Error(`Unexpected status ${{response.status}}`);\n",
+                " ".repeat(INDENT)
+            ));
+            s.push_str("}\n");
+        }
+        s
+    }
+
+    /// Returns the JavaScript value of a request `body`.
+    fn body(&mut self, body: &Bytes) -> String {
+        match body {
+            Bytes::Json(value) => format!("JSON.stringify({})", self.json(value, INDENT)),
+            Bytes::Xml(value) => quote(value),
+            Bytes::MultilineString(multiline) => match &multiline.kind {
+                MultilineStringKind::GraphQl(graphql) => {
+                    let query = self.string(&graphql.value);
+                    let indent = " ".repeat(INDENT * 2);
+                    let mut s = format!("JSON.stringify({{\n{indent}\"query\": {query},\n");
+                    if let Some(variables) = &graphql.variables {
+                        let variables = self.json(&variables.value, INDENT * 2);
+                        s.push_str(&format!("{indent}\"variables\": {variables},\n"));
+                    }
+                    s.push_str(&format!("{}}})", " ".repeat(INDENT)));
+                    s
+                }
+                MultilineStringKind::Text(template)
+                | MultilineStringKind::Json(template)
+                | MultilineStringKind::Xml(template)
+                | MultilineStringKind::Raw(template) => self.string(template),
+            },
+            Bytes::OnelineString(template) => self.string(template),
+            Bytes::Base64(base64) => {
+                let value = general_purpose::STANDARD.encode(&base64.value);
+                format!("Buffer.from({}, \"base64\")", quote(&value))
+            }
+            Bytes::Hex(value) => format!("Buffer.from({}, \"hex\")", quote(&hex(&value.value))),
+            Bytes::File(file) => self.read_file(&file.filename),
+        }
+    }
+
+    /// Returns the multipart form data `params` as a JavaScript `FormData`.
+    fn multipart(&mut self, params: &[MultipartParam]) -> String {
+        self.form_data = true;
+        let params = params
+            .iter()
+            .filter_map(|param| match param {
+                MultipartParam::Param(kv) => {
+                    let name = self.string(&kv.key);
+                    let value = self.string(&kv.value);
+                    Some(format!("[{name}, {value}]"))
+                }
+                MultipartParam::FilenameParam(param) => {
+                    let name = self.string(&param.key);
+                    let filename = self.string(&param.value.filename);
+                    let content = self.read_file(&param.value.filename);
+                    let blob = match &param.value.content_type {
+                        Some(content_type) => {
+                            let content_type = self.string(content_type);
+                            format!("new Blob([{content}], {{ type: {content_type} }})")
+                        }
+                        None => format!("new Blob([{content}])"),
+                    };
+                    Some(format!("[{name}, {blob}, {filename}]"))
+                }
+                MultipartParam::NestedParam(_) => None,
+            })
+            .collect::<Vec<_>>();
+        format!("formData([{}])", params.join(", "))
+    }
+
+    /// Returns the reading of the file `filename`.
+    fn read_file(&mut self, filename: &Template) -> String {
+        self.imports
+            .insert("import { readFile } from \"node:fs/promises\";");
+        format!("await readFile({})", self.string(filename))
+    }
+
+    /// Returns a list of `params` as a JavaScript array of `[name, value]`.
+    fn pairs(&mut self, params: &[KeyValue]) -> String {
+        let pairs = params
+            .iter()
+            .map(|p| {
+                let name = self.string(&p.key);
+                let value = self.string(&p.value);
+                format!("[{name}, {value}]")
+            })
+            .collect::<Vec<_>>();
+        format!("[{}]", pairs.join(", "))
+    }
+
+    /// Returns a JSON `value` as a JavaScript literal, indented at `indent`.
+    fn json(&mut self, value: &JsonValue, indent: usize) -> String {
+        let padding = " ".repeat(indent + INDENT);
+        match value {
+            JsonValue::Placeholder(placeholder) => self.expr(placeholder),
+            JsonValue::Number(value) => value.clone(),
+            JsonValue::String(template) => self.string(template),
+            JsonValue::Boolean(value) => value.to_string(),
+            JsonValue::Null => "null".to_string(),
+            JsonValue::List { elements, .. } if elements.is_empty() => "[]".to_string(),
+            JsonValue::List { elements, .. } => {
+                let mut s = "[\n".to_string();
+                for element in elements {
+                    let value = self.json(&element.value, indent + INDENT);
+                    s.push_str(&format!("{padding}{value},\n"));
+                }
+                s.push_str(&format!("{}]", " ".repeat(indent)));
+                s
+            }
+            JsonValue::Object { elements, .. } if elements.is_empty() => "{}".to_string(),
+            JsonValue::Object { elements, .. } => {
+                let mut s = "{\n".to_string();
+                for element in elements {
+                    let name = self.string(&element.name);
+                    let value = self.json(&element.value, indent + INDENT);
+                    s.push_str(&format!("{padding}{name}: {value},\n"));
+                }
+                s.push_str(&format!("{}}}", " ".repeat(indent)));
+                s
+            }
+        }
+    }
+
+    /// Returns the value of a variable definition as a JavaScript literal.
+    fn variable_value(&mut self, value: &VariableValue) -> String {
+        match value {
+            VariableValue::Null => "null".to_string(),
+            VariableValue::Bool(value) => value.to_string(),
+            VariableValue::Number(number) => number.to_string(),
+            VariableValue::String(template) => self.string(template),
+        }
+    }
+
+    /// Returns a `template` as a JavaScript string: a template literal if the template has
+    /// placeholders, a string literal otherwise.
+    fn string(&mut self, template: &Template) -> String {
+        if let Some(value) = literal(template) {
+            return quote(&value);
+        }
+        let mut s = "`".to_string();
+        for element in &template.elements {
+            match element {
+                TemplateElement::String { value, .. } => {
+                    for c in value.chars() {
+                        match c {
+                            '`' => s.push_str("\\`"),
+                            '$' => s.push_str("\\$"),
+                            c => s.push_str(&escape_char(c)),
+                        }
+                    }
+                }
+                TemplateElement::Placeholder(placeholder) => {
+                    s.push_str("${");
+                    s.push_str(&self.expr(placeholder));
+                    s.push('}');
+                }
+            }
+        }
+        s.push('`');
+        s
+    }
+
+    /// Returns the JavaScript expression of a `placeholder`.
+    ///
+    /// Expressions other than variables and functions are looked up in the `variables` object.
+    fn expr(&mut self, placeholder: &Placeholder) -> String {
+        match &placeholder.expr.kind {
+            ExprKind::Function(Function::NewUuid) => "crypto.randomUUID()".to_string(),
+            ExprKind::Function(Function::NewDate) => "new Date().toISOString()".to_string(),
+            ExprKind::Variable(variable) => self.variable(&variable.name),
+            _ => self.variable(&placeholder.expr.to_string()),
+        }
+    }
+
+    /// Returns the lookup of the variable `name`.
+    fn variable(&mut self, name: &str) -> String {
+        let lookup = format!("variables[{}]", quote(name));
+        self.used.insert(name.to_string());
+        lookup
+    }
+}
+
+/// Returns a list of `(name, value)` JavaScript expressions as an object.
+fn object(pairs: &[(String, String)]) -> String {
+    let pairs = pairs
+        .iter()
+        .map(|(name, value)| format!("{name}: {value}"))
+        .collect::<Vec<_>>();
+    format!("{{ {} }}", pairs.join(", "))
+}
+
+/// Returns `s` as a JavaScript string literal.
+fn quote(s: &str) -> String {
+    let escaped = s.chars().map(escape_char).collect::<String>();
+    format!("\"{escaped}\"")
+}
+
+/// Escapes a char `c` to be used in a JavaScript string.
+fn escape_char(c: char) -> String {
+    match c {
+        '\\' => "\\\\".to_string(),
+        '"' => "\\\"".to_string(),
+        '\n' => "\\n".to_string(),
+        '\r' => "\\r".to_string(),
+        '\t' => "\\t".to_string(),
+        c if c.is_control() => format!("\\u{:04x}", c as u32),
+        c => c.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::parser::parse_hurl_file;
+
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let content = r#"[Defaults]
+Accept: application/json
+[Options]
+variable: host=localhost:8000
+
+GET http://{{host}}/users
+[Query]
+page: 1
+[Options]
+location: true
+max-time: 1500ms
+HTTP 200
+
+POST http://{{host}}/users
+Authorization: Bearer {{token}}
+{
+    "name": "Bob",
+    "tags": ["a", "b"],
+    "id": "{{newUuid}}",
+    "admin": false
+}
+HTTP 201
+
+POST http://{{host}}/upload
+[Multipart]
+name: Bob
+file: file,data.txt; text/plain
+[BasicAuth]
+bob: secret
+"#;
+        let hurl_file = parse_hurl_file(content).unwrap();
+        assert_eq!(
+            format(&hurl_file),
+            r#"import { readFile } from "node:fs/promises";
+
+function formData(entries) {
+  const form = new FormData();
+  for (const [name, value, filename] of entries) {
+    form.append(name, value, filename);
+  }
+  return form;
+}
+
+// Values of the variables used by the requests.
+const variables = {
+  "token": "",
+};
+
+let response;
+
+variables["host"] = "localhost:8000";
+
+response = await fetch(`http://${variables["host"]}/users` + "?" + new URLSearchParams([["page", "1"]]), {
+  headers: { "Accept": "application/json" },
+  signal: AbortSignal.timeout(1500),
+});
+if (response.status !== 200) {
+  throw new Error(`Unexpected status ${response.status}`);
+}
+
+response = await fetch(`http://${variables["host"]}/users`, {
+  method: "POST",
+  headers: { "Accept": "application/json", "Authorization": `Bearer ${variables["token"]}`, "Content-Type": "application/json" },
+  body: JSON.stringify({
+    "name": "Bob",
+    "tags": [
+      "a",
+      "b",
+    ],
+    "id": `${crypto.randomUUID()}`,
+    "admin": false,
+  }),
+  redirect: "manual",
+});
+if (response.status !== 201) {
+  throw new Error(`Unexpected status ${response.status}`);
+}
+
+response = await fetch(`http://${variables["host"]}/upload`, {
+  method: "POST",
+  headers: { "Accept": "application/json", "Authorization": "Basic " + btoa("bob" + ":" + "secret") },
+  body: formData([["name", "Bob"], ["file", new Blob([await readFile("data.txt")], { type: "text/plain" }), "data.txt"]]),
+  redirect: "manual",
+});
+"#
+        );
+    }
+
+    #[test]
+    fn test_string() {
+        let mut script = Script::default();
+        let content = "GET http://localhost/{{id}}/${a}\n";
+        let hurl_file = parse_hurl_file(content).unwrap();
+        assert_eq!(
+            script.string(&hurl_file.entries[0].request.url),
+            r#"`http://localhost/${variables["id"]}/\${a}`"#
+        );
+        assert_eq!(quote("a\"b\\c\n"), r#""a\"b\\c\n""#);
+    }
+}
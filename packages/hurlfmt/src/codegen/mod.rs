@@ -0,0 +1,215 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Export of Hurl files to scripts in other languages: Python with [`requests`] and JavaScript
+//! with [`fetch`].
+//!
+//! Requests are exported with their headers, query params, bodies, cookies and authentication.
+//! Variables are read from a `variables` dictionary declared at the top of the script, asserts
+//! (except the response status), captures and nested multipart parts are not exported.
+//!
+//! [`requests`]: https://requests.readthedocs.io
+//! [`fetch`]: https://developer.mozilla.org/en-US/docs/Web/API/Fetch_API
+use hurl_core::ast::{
+    BooleanOption, Bytes, Cookie, DurationOption, Entry, HurlFile, KeyValue, MultilineString,
+    MultilineStringKind, MultipartParam, OptionKind, StatusValue, Template, TemplateElement,
+    VariableDefinition,
+};
+use hurl_core::types::DurationUnit;
+
+pub use self::js::format as format_js;
+pub use self::python::format as format_python;
+
+mod js;
+mod python;
+
+/// A statement of an exported script: a variable definition or an HTTP request.
+enum Statement<'a> {
+    Variable(&'a VariableDefinition),
+    Request(Box<CodeRequest<'a>>),
+}
+
+/// An HTTP request of a Hurl file, with the file defaults applied, as exported to code.
+struct CodeRequest<'a> {
+    method: String,
+    url: &'a Template,
+    query: &'a [KeyValue],
+    headers: Vec<&'a KeyValue>,
+    /// Content type set implicitly by Hurl, if no `Content-Type` header is explicitly set.
+    content_type: Option<&'static str>,
+    cookies: &'a [Cookie],
+    /// User credentials `user:password`, from a `[BasicAuth]` section or a `user` option.
+    basic_auth: Option<BasicAuth<'a>>,
+    form: &'a [KeyValue],
+    multipart: &'a [MultipartParam],
+    body: Option<&'a Bytes>,
+    follow_location: bool,
+    insecure: bool,
+    /// Maximum time allowed for the transfer, in milliseconds.
+    timeout: Option<u64>,
+    /// Expected response status.
+    status: Option<u64>,
+}
+
+/// Credentials of a basic authentication.
+enum BasicAuth<'a> {
+    /// A user and a password.
+    KeyValue(&'a KeyValue),
+    /// A `user:password` string.
+    User(&'a Template),
+}
+
+/// Returns the statements of a `hurl_file`, in their execution order.
+fn statements(hurl_file: &HurlFile) -> Vec<Statement<'_>> {
+    let mut statements = vec![];
+    let default_options = hurl_file
+        .defaults
+        .as_ref()
+        .map(|d| d.options())
+        .unwrap_or_default();
+    for option in default_options {
+        if let OptionKind::Variable(variable) = &option.kind {
+            statements.push(Statement::Variable(variable));
+        }
+    }
+    for entry in &hurl_file.entries {
+        for option in entry.request.options() {
+            if let OptionKind::Variable(variable) = &option.kind {
+                statements.push(Statement::Variable(variable));
+            }
+        }
+        let request = code_request(hurl_file, entry);
+        statements.push(Statement::Request(Box::new(request)));
+    }
+    statements
+}
+
+/// Returns the exported request of an `entry` of `hurl_file`.
+fn code_request<'a>(hurl_file: &'a HurlFile, entry: &'a Entry) -> CodeRequest<'a> {
+    let request = &entry.request;
+    let (default_headers, default_options) = match &hurl_file.defaults {
+        Some(defaults) => (defaults.headers.as_slice(), defaults.options()),
+        None => (&[][..], &[][..]),
+    };
+
+    // Default headers are overridden by the headers of the request with the same name.
+    let mut headers = default_headers
+        .iter()
+        .filter(|d| {
+            !request
+                .headers
+                .iter()
+                .any(|h| h.key.to_string().eq_ignore_ascii_case(&d.key.to_string()))
+        })
+        .collect::<Vec<_>>();
+    headers.extend(request.headers.iter());
+    let has_content_type = headers
+        .iter()
+        .any(|h| h.key.to_string().eq_ignore_ascii_case("Content-Type"));
+
+    let body = request.body.as_ref().map(|b| &b.value);
+    let content_type = if has_content_type {
+        None
+    } else {
+        body.and_then(implicit_content_type)
+    };
+
+    let mut code_request = CodeRequest {
+        method: request.method.to_string(),
+        url: &request.url,
+        query: request.querystring_params(),
+        headers,
+        content_type,
+        cookies: request.cookies(),
+        basic_auth: request.basic_auth().map(BasicAuth::KeyValue),
+        form: request.form_params(),
+        multipart: request.multipart_form_data(),
+        body,
+        follow_location: false,
+        insecure: false,
+        timeout: None,
+        status: None,
+    };
+
+    // Options with placeholders can't be evaluated and are ignored.
+    for option in default_options.iter().chain(request.options()) {
+        match &option.kind {
+            OptionKind::FollowLocation(BooleanOption::Literal(value))
+            | OptionKind::FollowLocationTrusted(BooleanOption::Literal(value)) => {
+                code_request.follow_location = *value;
+            }
+            OptionKind::Insecure(BooleanOption::Literal(value)) => {
+                code_request.insecure = *value;
+            }
+            OptionKind::MaxTime(DurationOption::Literal(duration)) => {
+                let value = duration.value.as_u64();
+                let millis = match duration.unit.unwrap_or(DurationUnit::MilliSecond) {
+                    DurationUnit::MilliSecond => value,
+                    DurationUnit::Second => value * 1000,
+                    DurationUnit::Minute => value * 60 * 1000,
+                    DurationUnit::Hour => value * 60 * 60 * 1000,
+                };
+                code_request.timeout = Some(millis);
+            }
+            OptionKind::User(user) if code_request.basic_auth.is_none() => {
+                code_request.basic_auth = Some(BasicAuth::User(user));
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(response) = &entry.response
+        && let StatusValue::Specific(status) = response.status.value
+    {
+        code_request.status = Some(status);
+    }
+    code_request
+}
+
+/// Returns the content type set by Hurl for a request `body`.
+fn implicit_content_type(body: &Bytes) -> Option<&'static str> {
+    match body {
+        Bytes::Json(_)
+        | Bytes::MultilineString(MultilineString {
+            kind: MultilineStringKind::Json(_) | MultilineStringKind::GraphQl(_),
+            ..
+        }) => Some("application/json"),
+        Bytes::Xml(_)
+        | Bytes::MultilineString(MultilineString {
+            kind: MultilineStringKind::Xml(_),
+            ..
+        }) => Some("application/xml"),
+        _ => None,
+    }
+}
+
+/// Returns the text of a `template` if it has no placeholder.
+fn literal(template: &Template) -> Option<String> {
+    let mut s = String::new();
+    for element in &template.elements {
+        match element {
+            TemplateElement::String { value, .. } => s.push_str(value),
+            TemplateElement::Placeholder(_) => return None,
+        }
+    }
+    Some(s)
+}
+
+/// Returns the hexadecimal encoding of `bytes`.
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
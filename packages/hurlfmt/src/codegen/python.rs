@@ -0,0 +1,545 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Export of Hurl files to Python scripts, using the [`requests`](https://requests.readthedocs.io)
+//! library.
+use std::collections::BTreeSet;
+
+use base64::Engine;
+use base64::engine::general_purpose;
+use hurl_core::ast::{
+    Bytes, ExprKind, Function, HurlFile, JsonValue, KeyValue, MultilineString, MultilineStringKind,
+    MultipartParam, Placeholder, Template, TemplateElement, VariableValue,
+};
+
+use super::{BasicAuth, CodeRequest, Statement, hex, literal, statements};
+
+/// Indentation of the arguments of a request call.
+const INDENT: usize = 4;
+
+/// Returns a Python script running the requests of a `hurl_file`.
+pub fn format(hurl_file: &HurlFile) -> String {
+    let mut script = Script::default();
+    let mut code = String::new();
+    let mut previous_is_variable = false;
+    for statement in statements(hurl_file) {
+        match statement {
+            Statement::Variable(variable) => {
+                if !previous_is_variable {
+                    code.push('\n');
+                }
+                previous_is_variable = true;
+                let value = script.variable_value(&variable.value);
+                code.push_str(&format!("variables[{}] = {value}\n", quote(&variable.name)));
+                script.defined.insert(variable.name.clone());
+            }
+            Statement::Request(request) => {
+                previous_is_variable = false;
+                code.push('\n');
+                code.push_str(&script.request(&request));
+            }
+        }
+    }
+    script.prelude() + &code
+}
+
+/// State of a Python script being generated.
+#[derive(Default)]
+struct Script {
+    /// Modules imported by the script.
+    imports: BTreeSet<&'static str>,
+    /// Names of the variables used by the requests.
+    used: BTreeSet<String>,
+    /// Names of the variables defined by the script.
+    defined: BTreeSet<String>,
+}
+
+impl Script {
+    /// Returns the imports and declarations of this script.
+    fn prelude(&self) -> String {
+        let mut s = String::new();
+        for import in &self.imports {
+            s.push_str(import);
+            s.push('\n');
+        }
+        if !self.imports.is_empty() {
+            s.push('\n');
+        }
+        s.push_str("import requests\n\n");
+        if !self.used.is_empty() || !self.defined.is_empty() {
+            let undefined = self.used.difference(&self.defined).collect::<Vec<_>>();
+            if undefined.is_empty() {
+                s.push_str("variables = {}\n");
+            } else {
+                s.push_str("# Values of the variables used by the requests.\n");
+                s.push_str("variables = {\n");
+                for name in undefined {
+                    s.push_str(&format!("    {}: \"\",\n", quote(name)));
+                }
+                s.push_str("}\n");
+            }
+            s.push('\n');
+        }
+        s.push_str("session = requests.Session()\n");
+        s
+    }
+
+    /// Returns the Python call sending a `request`.
+    fn request(&mut self, request: &CodeRequest) -> String {
+        let method = request.method.to_lowercase();
+        let mut args = vec![];
+        let (function, default_redirects) = match method.as_str() {
+            "head" => (method.as_str(), false),
+            "get" | "post" | "put" | "patch" | "delete" | "options" => (method.as_str(), true),
+            _ => {
+                args.push(quote(&request.method));
+                ("request", true)
+            }
+        };
+        args.push(self.string(request.url));
+        if !request.query.is_empty() {
+            args.push(format!("params={}", self.pairs(request.query)));
+        }
+        let mut headers = request
+            .headers
+            .iter()
+            .map(|h| (self.string(&h.key), self.string(&h.value)))
+            .collect::<Vec<_>>();
+        let has_json_arg = matches!(
+            request.body,
+            Some(Bytes::Json(_))
+                | Some(Bytes::MultilineString(MultilineString {
+                    kind: MultilineStringKind::GraphQl(_),
+                    ..
+                }))
+        );
+        // The `json` argument already sets the JSON content type.
+        if let Some(content_type) = request.content_type
+            && !has_json_arg
+        {
+            headers.push((quote("Content-Type"), quote(content_type)));
+        }
+        if !headers.is_empty() {
+            args.push(format!("headers={}", dict(&headers)));
+        }
+        if !request.cookies.is_empty() {
+            let cookies = request
+                .cookies
+                .iter()
+                .map(|c| (self.string(&c.name), self.string(&c.value)))
+                .collect::<Vec<_>>();
+            args.push(format!("cookies={}", dict(&cookies)));
+        }
+        match &request.basic_auth {
+            Some(BasicAuth::KeyValue(kv)) => {
+                let user = self.string(&kv.key);
+                let password = self.string(&kv.value);
+                args.push(format!("auth=({user}, {password})"));
+            }
+            Some(BasicAuth::User(user)) => match literal(user) {
+                Some(user) => {
+                    let (user, password) = user.split_once(':').unwrap_or((&user, ""));
+                    args.push(format!("auth=({}, {})", quote(user), quote(password)));
+                }
+                None => {
+                    let user = self.string(user);
+                    args.push(format!("auth=tuple({user}.split(\":\", 1))"));
+                }
+            },
+            None => {}
+        }
+        if !request.form.is_empty() {
+            args.push(format!("data={}", self.pairs(request.form)));
+        }
+        if !request.multipart.is_empty() {
+            args.push(format!("files={}", self.multipart(request.multipart)));
+        }
+        if let Some(body) = request.body {
+            args.push(self.body(body));
+        }
+        if let Some(timeout) = request.timeout {
+            args.push(format!("timeout={}", seconds(timeout)));
+        }
+        if request.insecure {
+            args.push("verify=False".to_string());
+        }
+        if request.follow_location != default_redirects {
+            let value = if request.follow_location {
+                "True"
+            } else {
+                "False"
+            };
+            args.push(format!("allow_redirects={value}"));
+        }
+
+        let mut s = format!("response = session.{function}(\n");
+        for arg in args {
+            s.push_str(&format!("{}{arg},\n", " ".repeat(INDENT)));
+        }
+        s.push_str(")\n");
+        if let Some(status) = request.status {
+            s.push_str(&format!("assert response.status_code == {status}\n"));
+        }
+        s
+    }
+
+    /// Returns the keyword argument of a request `body`.
+    fn body(&mut self, body: &Bytes) -> String {
+        match body {
+            Bytes::Json(value) => format!("json={}", self.json(value, INDENT)),
+            Bytes::Xml(value) => format!("data={}", encoded(&quote(value), value)),
+            Bytes::MultilineString(multiline) => match &multiline.kind {
+                MultilineStringKind::GraphQl(graphql) => {
+                    let query = self.string(&graphql.value);
+                    let indent = " ".repeat(INDENT * 2);
+                    let mut s = format!("json={{\n{indent}\"query\": {query},\n");
+                    if let Some(variables) = &graphql.variables {
+                        let variables = self.json(&variables.value, INDENT * 2);
+                        s.push_str(&format!("{indent}\"variables\": {variables},\n"));
+                    }
+                    s.push_str(&format!("{}}}", " ".repeat(INDENT)));
+                    s
+                }
+                MultilineStringKind::Text(template)
+                | MultilineStringKind::Json(template)
+                | MultilineStringKind::Xml(template)
+                | MultilineStringKind::Raw(template) => format!("data={}", self.text(template)),
+            },
+            Bytes::OnelineString(template) => format!("data={}", self.text(template)),
+            Bytes::Base64(base64) => {
+                self.imports.insert("import base64");
+                let value = general_purpose::STANDARD.encode(&base64.value);
+                format!("data=base64.b64decode({})", quote(&value))
+            }
+            Bytes::Hex(value) => format!("data=bytes.fromhex({})", quote(&hex(&value.value))),
+            Bytes::File(file) => format!("data=open({}, \"rb\")", self.string(&file.filename)),
+        }
+    }
+
+    /// Returns the multipart form data `params` as a Python dictionary of files.
+    fn multipart(&mut self, params: &[MultipartParam]) -> String {
+        let params = params
+            .iter()
+            .filter_map(|param| match param {
+                MultipartParam::Param(kv) => {
+                    let value = self.string(&kv.value);
+                    Some((self.string(&kv.key), format!("(None, {value})")))
+                }
+                MultipartParam::FilenameParam(param) => {
+                    let filename = self.string(&param.value.filename);
+                    let file = format!("open({filename}, \"rb\")");
+                    let value = match &param.value.content_type {
+                        Some(content_type) => {
+                            let content_type = self.string(content_type);
+                            format!("({filename}, {file}, {content_type})")
+                        }
+                        None => file,
+                    };
+                    Some((self.string(&param.key), value))
+                }
+                MultipartParam::NestedParam(_) => None,
+            })
+            .collect::<Vec<_>>();
+        dict(&params)
+    }
+
+    /// Returns a list of `params` as a Python dictionary, or as a list of tuples if a name is
+    /// repeated.
+    fn pairs(&mut self, params: &[KeyValue]) -> String {
+        let names = params
+            .iter()
+            .map(|p| p.key.to_string())
+            .collect::<BTreeSet<_>>();
+        let pairs = params
+            .iter()
+            .map(|p| (self.string(&p.key), self.string(&p.value)))
+            .collect::<Vec<_>>();
+        if names.len() == pairs.len() {
+            dict(&pairs)
+        } else {
+            let pairs = pairs
+                .iter()
+                .map(|(name, value)| format!("({name}, {value})"))
+                .collect::<Vec<_>>();
+            format!("[{}]", pairs.join(", "))
+        }
+    }
+
+    /// Returns a JSON `value` as a Python literal, indented at `indent`.
+    fn json(&mut self, value: &JsonValue, indent: usize) -> String {
+        let padding = " ".repeat(indent + INDENT);
+        match value {
+            JsonValue::Placeholder(placeholder) => self.expr(placeholder),
+            JsonValue::Number(value) => value.clone(),
+            JsonValue::String(template) => self.string(template),
+            JsonValue::Boolean(true) => "True".to_string(),
+            JsonValue::Boolean(false) => "False".to_string(),
+            JsonValue::Null => "None".to_string(),
+            JsonValue::List { elements, .. } if elements.is_empty() => "[]".to_string(),
+            JsonValue::List { elements, .. } => {
+                let mut s = "[\n".to_string();
+                for element in elements {
+                    let value = self.json(&element.value, indent + INDENT);
+                    s.push_str(&format!("{padding}{value},\n"));
+                }
+                s.push_str(&format!("{}]", " ".repeat(indent)));
+                s
+            }
+            JsonValue::Object { elements, .. } if elements.is_empty() => "{}".to_string(),
+            JsonValue::Object { elements, .. } => {
+                let mut s = "{\n".to_string();
+                for element in elements {
+                    let name = self.string(&element.name);
+                    let value = self.json(&element.value, indent + INDENT);
+                    s.push_str(&format!("{padding}{name}: {value},\n"));
+                }
+                s.push_str(&format!("{}}}", " ".repeat(indent)));
+                s
+            }
+        }
+    }
+
+    /// Returns the value of a variable definition as a Python literal.
+    fn variable_value(&mut self, value: &VariableValue) -> String {
+        match value {
+            VariableValue::Null => "None".to_string(),
+            VariableValue::Bool(true) => "True".to_string(),
+            VariableValue::Bool(false) => "False".to_string(),
+            VariableValue::Number(number) => number.to_string(),
+            VariableValue::String(template) => self.string(template),
+        }
+    }
+
+    /// Returns a text `template` as a Python string, encoded to UTF-8 bytes unless it's ASCII.
+    fn text(&mut self, template: &Template) -> String {
+        let s = self.string(template);
+        match literal(template) {
+            Some(value) => encoded(&s, &value),
+            None => format!("{s}.encode()"),
+        }
+    }
+
+    /// Returns a `template` as a Python string: an f-string if the template has placeholders, a
+    /// string literal otherwise.
+    fn string(&mut self, template: &Template) -> String {
+        if let Some(value) = literal(template) {
+            return quote(&value);
+        }
+        let mut s = "f\"".to_string();
+        for element in &template.elements {
+            match element {
+                TemplateElement::String { value, .. } => {
+                    let value = escape(value).replace('{', "{{").replace('}', "}}");
+                    s.push_str(&value);
+                }
+                TemplateElement::Placeholder(placeholder) => {
+                    s.push('{');
+                    s.push_str(&self.expr(placeholder));
+                    s.push('}');
+                }
+            }
+        }
+        s.push('"');
+        s
+    }
+
+    /// Returns the Python expression of a `placeholder`.
+    ///
+    /// Expressions other than variables and functions are looked up in the `variables` dictionary.
+    fn expr(&mut self, placeholder: &Placeholder) -> String {
+        match &placeholder.expr.kind {
+            ExprKind::Function(Function::NewUuid) => {
+                self.imports.insert("import uuid");
+                "uuid.uuid4()".to_string()
+            }
+            ExprKind::Function(Function::NewDate) => {
+                self.imports
+                    .insert("from datetime import datetime, timezone");
+                "datetime.now(timezone.utc).isoformat()".to_string()
+            }
+            ExprKind::Variable(variable) => self.variable(&variable.name),
+            _ => self.variable(&placeholder.expr.to_string()),
+        }
+    }
+
+    /// Returns the lookup of the variable `name`, usable in an f-string.
+    fn variable(&mut self, name: &str) -> String {
+        let name = name.replace(['\'', '"', '\\', '{', '}'], "");
+        let lookup = format!("variables['{name}']");
+        self.used.insert(name);
+        lookup
+    }
+}
+
+/// Returns a list of `(name, value)` Python expressions as a dictionary.
+fn dict(pairs: &[(String, String)]) -> String {
+    let pairs = pairs
+        .iter()
+        .map(|(name, value)| format!("{name}: {value}"))
+        .collect::<Vec<_>>();
+    format!("{{{}}}", pairs.join(", "))
+}
+
+/// Returns a Python string literal `quoted` of a `value`, encoded to bytes unless it's ASCII.
+fn encoded(quoted: &str, value: &str) -> String {
+    if value.is_ascii() {
+        quoted.to_string()
+    } else {
+        format!("{quoted}.encode()")
+    }
+}
+
+/// Returns a duration in `millis` as a number of seconds.
+fn seconds(millis: u64) -> String {
+    if millis.is_multiple_of(1000) {
+        (millis / 1000).to_string()
+    } else {
+        (millis as f64 / 1000.0).to_string()
+    }
+}
+
+/// Returns `s` as a Python string literal.
+fn quote(s: &str) -> String {
+    format!("\"{}\"", escape(s))
+}
+
+/// Escapes `s` to be used in a double-quoted Python string.
+fn escape(s: &str) -> String {
+    let mut escaped = String::new();
+    for c in s.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::parser::parse_hurl_file;
+
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let content = r#"[Defaults]
+Accept: application/json
+[Options]
+variable: host=localhost:8000
+
+GET http://{{host}}/users
+[Query]
+page: 1
+[Options]
+location: true
+max-time: 1500ms
+HTTP 200
+
+POST http://{{host}}/users
+Authorization: Bearer {{token}}
+{
+    "name": "Bob",
+    "tags": ["a", "b"],
+    "id": "{{newUuid}}",
+    "admin": false
+}
+HTTP 201
+
+PUT http://{{host}}/users/1
+[Form]
+name: Bob
+[BasicAuth]
+bob: secret
+
+PURGE http://{{host}}/cache
+"#;
+        let hurl_file = parse_hurl_file(content).unwrap();
+        assert_eq!(
+            format(&hurl_file),
+            r#"import uuid
+
+import requests
+
+# Values of the variables used by the requests.
+variables = {
+    "token": "",
+}
+
+session = requests.Session()
+
+variables["host"] = "localhost:8000"
+
+response = session.get(
+    f"http://{variables['host']}/users",
+    params={"page": "1"},
+    headers={"Accept": "application/json"},
+    timeout=1.5,
+)
+assert response.status_code == 200
+
+response = session.post(
+    f"http://{variables['host']}/users",
+    headers={"Accept": "application/json", "Authorization": f"Bearer {variables['token']}"},
+    json={
+        "name": "Bob",
+        "tags": [
+            "a",
+            "b",
+        ],
+        "id": f"{uuid.uuid4()}",
+        "admin": False,
+    },
+    allow_redirects=False,
+)
+assert response.status_code == 201
+
+response = session.put(
+    f"http://{variables['host']}/users/1",
+    headers={"Accept": "application/json"},
+    auth=("bob", "secret"),
+    data={"name": "Bob"},
+    allow_redirects=False,
+)
+
+response = session.request(
+    "PURGE",
+    f"http://{variables['host']}/cache",
+    headers={"Accept": "application/json"},
+    allow_redirects=False,
+)
+"#
+        );
+    }
+
+    #[test]
+    fn test_string() {
+        let mut script = Script::default();
+        let content = "GET http://localhost/{{id}}/{a}\n";
+        let hurl_file = parse_hurl_file(content).unwrap();
+        assert_eq!(
+            script.string(&hurl_file.entries[0].request.url),
+            r#"f"http://localhost/{variables['id']}/{{a}}""#
+        );
+        assert_eq!(quote("a\"b\\c\n"), r#""a\"b\\c\n""#);
+    }
+}
@@ -19,7 +19,7 @@
 use hurl_core::input::Input;
 use hurl_core::parser::{self, ParseError};
 
-use crate::linter;
+use crate::linter::{self, LintConfig};
 
 /// Represents a check error.
 pub enum CheckError {
@@ -36,10 +36,10 @@ pub enum CheckError {
 }
 
 /// Run the check command for a list of input files
-pub fn run(input_files: &[Input]) -> Vec<CheckError> {
+pub fn run(input_files: &[Input], pretty: bool, config: &LintConfig) -> Vec<CheckError> {
     let mut errors = vec![];
     for input_file in input_files {
-        if let Err(e) = run_check(input_file) {
+        if let Err(e) = run_check(input_file, pretty, config) {
             errors.push(e);
         }
     }
@@ -47,7 +47,7 @@ pub fn run(input_files: &[Input]) -> Vec<CheckError> {
 }
 
 /// Run the check command for one input file
-fn run_check(input_file: &Input) -> Result<(), CheckError> {
+fn run_check(input_file: &Input, pretty: bool, config: &LintConfig) -> Result<(), CheckError> {
     let content = input_file.read_to_string().map_err(|e| CheckError::IO {
         filename: input_file.to_string(),
         message: e.to_string(),
@@ -57,7 +57,11 @@ fn run_check(input_file: &Input) -> Result<(), CheckError> {
         input_file: input_file.clone(),
         error,
     })?;
-    let formatted = linter::lint_hurl_file(&hurl_file);
+    let formatted = if pretty {
+        linter::pretty_hurl_file(&hurl_file, config)
+    } else {
+        linter::lint_hurl_file(&hurl_file, config)
+    };
     if formatted == content {
         Ok(())
     } else {
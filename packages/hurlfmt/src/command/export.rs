@@ -16,11 +16,13 @@
  *
  */
 
+use hurl_core::format::HtmlOptions;
 use hurl_core::input::Input;
 use hurl_core::parser::{self, ParseError};
 
 use crate::cli::options::{InputFormat, OutputFormat};
-use crate::{curl, format, linter};
+use crate::linter::{self, LintConfig};
+use crate::{codegen, curl, format, json};
 
 /// Represents an export error.
 pub enum ExportError {
@@ -34,6 +36,7 @@ pub enum ExportError {
         error: ParseError,
     },
     Curl(String),
+    Json(String),
 }
 
 /// Run the export command for a list of input files
@@ -41,12 +44,24 @@ pub fn run(
     input_files: &[Input],
     input_format: &InputFormat,
     output_format: &OutputFormat,
+    pretty: bool,
+    config: &LintConfig,
     standalone: bool,
     color: bool,
 ) -> Vec<Result<String, ExportError>> {
     input_files
         .iter()
-        .map(|input_file| run_export(input_file, input_format, output_format, standalone, color))
+        .map(|input_file| {
+            run_export(
+                input_file,
+                input_format,
+                output_format,
+                pretty,
+                config,
+                standalone,
+                color,
+            )
+        })
         .collect()
 }
 
@@ -55,6 +70,8 @@ fn run_export(
     input_file: &Input,
     input_format: &InputFormat,
     output_format: &OutputFormat,
+    pretty: bool,
+    config: &LintConfig,
     standalone: bool,
     color: bool,
 ) -> Result<String, ExportError> {
@@ -63,10 +80,14 @@ fn run_export(
         message: e.to_string(),
     })?;
 
-    // Parse input curl or Hurl file
+    // Parse input curl, JSON or Hurl file
     let input = match input_format {
         InputFormat::Hurl => content.to_string(),
         InputFormat::Curl => curl::parse(&content).map_err(ExportError::Curl)?,
+        InputFormat::Json => {
+            let hurl_file = json::parse(&content).map_err(ExportError::Json)?;
+            linter::lint_hurl_file(&hurl_file, config)
+        }
     };
     let hurl_file = parser::parse_hurl_file(&input).map_err(|error| ExportError::Parse {
         content: input.clone(),
@@ -76,12 +97,25 @@ fn run_export(
 
     let output = match output_format {
         OutputFormat::Hurl => {
-            let formatted = linter::lint_hurl_file(&hurl_file);
+            let formatted = if pretty {
+                linter::pretty_hurl_file(&hurl_file, config)
+            } else {
+                linter::lint_hurl_file(&hurl_file, config)
+            };
             let hurl_file = parser::parse_hurl_file(&formatted).unwrap();
-            format::format_text(&hurl_file, color)
+            hurl_core::format::format_term(&hurl_file, color)
         }
         OutputFormat::Json => format::format_json(&hurl_file),
-        OutputFormat::Html => hurl_core::format::format_html(&hurl_file, standalone),
+        OutputFormat::Html => {
+            let options = HtmlOptions {
+                standalone,
+                ..HtmlOptions::default()
+            };
+            hurl_core::format::format_html(&hurl_file, &options)
+        }
+        OutputFormat::Js => codegen::format_js(&hurl_file),
+        OutputFormat::Markdown => format::format_markdown(&hurl_file),
+        OutputFormat::Python => codegen::format_python(&hurl_file),
     };
     Ok(output)
 }
@@ -0,0 +1,319 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use hurl_core::format::{HtmlOptions, escape_html};
+use hurl_core::input::Input;
+use hurl_core::parser;
+
+use crate::command::export::ExportError;
+
+/// Metadata of an exported Hurl file, listed in the index page.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Page {
+    /// Path of the Hurl file, relative to the input directory (with `/` separators).
+    name: String,
+    /// Number of entries of the Hurl file.
+    entries_count: usize,
+    /// Optional title of the Hurl file, from its front matter.
+    title: Option<String>,
+}
+
+impl Page {
+    /// Returns the link to the HTML page, relative to the output directory.
+    ///
+    /// Each path segment of the file name is percent-encoded: only the unreserved characters of
+    /// an URL (RFC 3986) are kept as is.
+    fn href(&self) -> String {
+        let mut href = String::new();
+        for byte in self.name.bytes() {
+            match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                    href.push(byte as char);
+                }
+                _ => href.push_str(&format!("%{byte:02X}")),
+            }
+        }
+        format!("{href}.html")
+    }
+}
+
+/// Run the export command for all the Hurl files of `input_dir`.
+///
+/// Each Hurl file is exported to a standalone HTML page in `output_dir`, mirroring the input
+/// directory tree (`foo/bar.hurl` is exported to `foo/bar.hurl.html`). An index page `index.html`
+/// links to every exported page.
+pub fn run(input_dir: &Path, output_dir: &Path) -> Vec<ExportError> {
+    let files = match hurl_files(input_dir) {
+        Ok(files) => files,
+        Err(e) => {
+            return vec![ExportError::IO {
+                filename: input_dir.display().to_string(),
+                message: e.to_string(),
+            }];
+        }
+    };
+
+    let mut errors = vec![];
+    let mut pages = vec![];
+    for file in files.iter() {
+        match export_file(input_dir, file, output_dir) {
+            Ok(page) => pages.push(page),
+            Err(e) => errors.push(e),
+        }
+    }
+
+    let index_path = output_dir.join("index.html");
+    if let Err(e) = write_file(&index_path, &index(&pages)) {
+        errors.push(e);
+    }
+    errors
+}
+
+/// Returns the sorted list of Hurl files of `dir`, recursively.
+///
+/// Symbolic links to directories are followed, but each directory is only visited once, so a
+/// symbolic link loop doesn't recurse endlessly.
+fn hurl_files(dir: &Path) -> Result<Vec<PathBuf>, std::io::Error> {
+    let mut files = vec![];
+    let mut visited = HashSet::new();
+    collect_hurl_files(dir, &mut visited, &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+/// Appends the Hurl files of `dir` to `files`, recursively, skipping directories already `visited`.
+fn collect_hurl_files(
+    dir: &Path,
+    visited: &mut HashSet<PathBuf>,
+    files: &mut Vec<PathBuf>,
+) -> Result<(), std::io::Error> {
+    if !visited.insert(fs::canonicalize(dir)?) {
+        return Ok(());
+    }
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_hurl_files(&path, visited, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "hurl") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Exports the Hurl `file` to a standalone HTML page in `output_dir`.
+fn export_file(input_dir: &Path, file: &Path, output_dir: &Path) -> Result<Page, ExportError> {
+    let input_file = Input::from(file);
+    let content = input_file.read_to_string().map_err(|e| ExportError::IO {
+        filename: input_file.to_string(),
+        message: e.to_string(),
+    })?;
+    let hurl_file = parser::parse_hurl_file(&content).map_err(|error| ExportError::Parse {
+        content: content.clone(),
+        input_file: input_file.clone(),
+        error,
+    })?;
+
+    let relative_path = file.strip_prefix(input_dir).unwrap_or(file);
+    let name = relative_path
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+    let page = Page {
+        name,
+        entries_count: hurl_file.entries.len(),
+        title: front_matter_title(&content),
+    };
+
+    let options = HtmlOptions {
+        standalone: true,
+        ..HtmlOptions::default()
+    };
+    let html = hurl_core::format::format_html(&hurl_file, &options);
+    write_file(&output_dir.join(format!("{}.html", page.name)), &html)?;
+    Ok(page)
+}
+
+/// Returns the title of a Hurl file from its front matter: the first line of the file, if this
+/// line is a comment.
+///
+/// For instance, the title of this file is "Login and fetch user profile":
+///
+/// ```hurl
+/// # Login and fetch user profile
+/// POST https://example.org/login
+/// ```
+fn front_matter_title(content: &str) -> Option<String> {
+    let line = content
+        .trim_start_matches('\u{feff}')
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())?;
+    let title = line.strip_prefix('#')?.trim();
+    if title.is_empty() {
+        None
+    } else {
+        Some(title.to_string())
+    }
+}
+
+/// Returns the HTML index page, linking to each exported `pages`.
+fn index(pages: &[Page]) -> String {
+    let mut rows = String::new();
+    for page in pages.iter() {
+        let href = escape_html(&page.href());
+        let name = escape_html(&page.name);
+        let title = page.title.as_deref().map(escape_html).unwrap_or_default();
+        let entries_count = page.entries_count;
+        rows.push_str(&format!(
+            r#"                <tr><td><a href="{href}">{name}</a></td><td>{title}</td><td>{entries_count}</td></tr>
+"#
+        ));
+    }
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+    <head>
+        <meta charset="utf-8">
+        <title>Hurl Files</title>
+    </head>
+    <body>
+        <table>
+            <thead>
+                <tr><th>File</th><th>Title</th><th>Entries</th></tr>
+            </thead>
+            <tbody>
+{rows}            </tbody>
+        </table>
+    </body>
+</html>
+"#
+    )
+}
+
+/// Writes `content` to `path`, creating parent directories if needed.
+fn write_file(path: &Path, content: &str) -> Result<(), ExportError> {
+    let to_error = |e: std::io::Error| ExportError::IO {
+        filename: path.display().to_string(),
+        message: e.to_string(),
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(to_error)?;
+    }
+    fs::write(path, content).map_err(to_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_front_matter_title() {
+        assert_eq!(
+            front_matter_title("# Login and fetch user profile\nGET https://foo.com\n"),
+            Some("Login and fetch user profile".to_string())
+        );
+        assert_eq!(
+            front_matter_title("\n\n  #   Health check  \n# Some comment\nGET https://foo.com\n"),
+            Some("Health check".to_string())
+        );
+        assert_eq!(
+            front_matter_title("GET https://foo.com\n# Not a title\n"),
+            None
+        );
+        assert_eq!(front_matter_title("#\nGET https://foo.com\n"), None);
+        assert_eq!(front_matter_title(""), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_hurl_files_with_symlink_loop() {
+        let dir = std::env::temp_dir().join(format!(
+            "hurlfmt-hurl-files-symlink-loop-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        let sub_dir = dir.join("api");
+        fs::create_dir_all(&sub_dir).unwrap();
+        fs::write(dir.join("health.hurl"), "GET https://foo.com\n").unwrap();
+        fs::write(sub_dir.join("login.hurl"), "GET https://foo.com\n").unwrap();
+        std::os::unix::fs::symlink(&dir, sub_dir.join("loop")).unwrap();
+
+        let files = hurl_files(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            files.unwrap(),
+            vec![dir.join("api/login.hurl"), dir.join("health.hurl")]
+        );
+    }
+
+    #[test]
+    fn test_page_href() {
+        let page = Page {
+            name: "api/100%/get \"user\"#1?&<é>.hurl".to_string(),
+            entries_count: 1,
+            title: None,
+        };
+        assert_eq!(
+            page.href(),
+            "api/100%25/get%20%22user%22%231%3F%26%3C%C3%A9%3E.hurl.html"
+        );
+    }
+
+    #[test]
+    fn test_index() {
+        let pages = vec![
+            Page {
+                name: "auth/login.hurl".to_string(),
+                entries_count: 3,
+                title: Some("Login <admin>".to_string()),
+            },
+            Page {
+                name: "health.hurl".to_string(),
+                entries_count: 1,
+                title: None,
+            },
+        ];
+        assert_eq!(
+            index(&pages),
+            r#"<!DOCTYPE html>
+<html>
+    <head>
+        <meta charset="utf-8">
+        <title>Hurl Files</title>
+    </head>
+    <body>
+        <table>
+            <thead>
+                <tr><th>File</th><th>Title</th><th>Entries</th></tr>
+            </thead>
+            <tbody>
+                <tr><td><a href="auth/login.hurl.html">auth/login.hurl</a></td><td>Login &lt;admin&gt;</td><td>3</td></tr>
+                <tr><td><a href="health.hurl.html">health.hurl</a></td><td></td><td>1</td></tr>
+            </tbody>
+        </table>
+    </body>
+</html>
+"#
+        );
+    }
+}
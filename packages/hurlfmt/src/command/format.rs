@@ -23,7 +23,7 @@ use std::path::{Path, PathBuf};
 use hurl_core::input::Input;
 use hurl_core::parser::{self, ParseError};
 
-use crate::linter;
+use crate::linter::{self, LintConfig};
 
 /// Represents a check error.
 pub enum FormatError {
@@ -39,10 +39,10 @@ pub enum FormatError {
 }
 
 /// Run the format command for a list of input files
-pub fn run(input_files: &[PathBuf]) -> Vec<FormatError> {
+pub fn run(input_files: &[PathBuf], pretty: bool, config: &LintConfig) -> Vec<FormatError> {
     let mut errors = vec![];
     for input_file in input_files {
-        if let Err(e) = run_format(input_file) {
+        if let Err(e) = run_format(input_file, pretty, config) {
             errors.push(e);
         }
     }
@@ -50,7 +50,7 @@ pub fn run(input_files: &[PathBuf]) -> Vec<FormatError> {
 }
 
 /// Run the format command for one input file
-fn run_format(input_file: &Path) -> Result<(), FormatError> {
+fn run_format(input_file: &Path, pretty: bool, config: &LintConfig) -> Result<(), FormatError> {
     let content =
         fs::read_to_string(input_file.display().to_string()).map_err(|e| FormatError::IO {
             filename: input_file.display().to_string(),
@@ -61,7 +61,11 @@ fn run_format(input_file: &Path) -> Result<(), FormatError> {
         input_file: Input::new(input_file.display().to_string().as_str()),
         error,
     })?;
-    let formatted = linter::lint_hurl_file(&hurl_file);
+    let formatted = if pretty {
+        linter::pretty_hurl_file(&hurl_file, config)
+    } else {
+        linter::lint_hurl_file(&hurl_file, config)
+    };
 
     let mut file = match std::fs::File::create(input_file) {
         Err(e) => {
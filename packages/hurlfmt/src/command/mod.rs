@@ -17,4 +17,5 @@
  */
 pub mod check;
 pub mod export;
+pub mod export_dir;
 pub mod format;
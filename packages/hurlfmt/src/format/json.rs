@@ -18,12 +18,13 @@
 use base64::Engine;
 use base64::engine::general_purpose;
 use hurl_core::ast::{
-    Assert, Base64, Body, BooleanOption, Bytes, Capture, CertificateAttributeName, Comment, Cookie,
-    CountOption, Duration, DurationOption, Entry, EntryOption, File, FilenameParam, Filter,
-    FilterValue, Hex, HurlFile, JsonListElement, JsonValue, KeyValue, MultilineString,
-    MultilineStringKind, MultipartParam, NaturalOption, OptionKind, Placeholder, Predicate,
-    PredicateFuncValue, PredicateValue, Query, QueryValue, Regex, RegexValue, Request, Response,
-    StatusValue, VersionValue,
+    Assert, Base64, Body, BooleanOption, Bytes, Capture, CaptureFallback, CertificateAttributeName,
+    Comment, Cookie, CountOption, Defaults, Duration, DurationOption, Entry, EntryOption, File,
+    FilenameParam, Filter, FilterValue, Hex, HurlFile, Include, IntegerValue, JsonListElement,
+    JsonValue, KeyValue, MultilineString, MultilineStringKind, MultipartParam, NaturalOption,
+    NestedParam, OptionKind, Placeholder, Predicate, PredicateFuncValue, PredicateValue, Query,
+    QueryValue, Regex, RegexValue, Request, Response, StatusValue, TimingsAttributeName,
+    VersionValue,
 };
 use hurl_core::types::{Count, ToSource};
 
@@ -39,16 +40,36 @@ pub trait ToJson {
 
 impl ToJson for HurlFile {
     fn to_json(&self) -> JValue {
-        JValue::Object(vec![(
+        let mut attributes = vec![];
+        if let Some(defaults) = &self.defaults {
+            attributes.push(("defaults".to_string(), defaults.to_json()));
+        }
+        attributes.push((
             "entries".to_string(),
             JValue::List(self.entries.iter().map(|e| e.to_json()).collect()),
-        )])
+        ));
+        add_includes(&mut attributes, &self.includes);
+        JValue::Object(attributes)
+    }
+}
+
+impl ToJson for Defaults {
+    fn to_json(&self) -> JValue {
+        let mut attributes = vec![];
+        add_headers(&mut attributes, &self.headers);
+        if !self.options().is_empty() {
+            let options = self.options().iter().map(|c| c.to_json()).collect();
+            attributes.push(("options".to_string(), JValue::List(options)));
+        }
+        JValue::Object(attributes)
     }
 }
 
 impl ToJson for Entry {
     fn to_json(&self) -> JValue {
-        let mut attributes = vec![("request".to_string(), self.request.to_json())];
+        let mut attributes = vec![];
+        add_includes(&mut attributes, &self.includes);
+        attributes.push(("request".to_string(), self.request.to_json()));
         if let Some(response) = &self.response {
             attributes.push(("response".to_string(), response.to_json()));
         }
@@ -140,6 +161,16 @@ impl ToJson for Response {
     }
 }
 
+fn add_includes(attributes: &mut Vec<(String, JValue)>, includes: &[Include]) {
+    if !includes.is_empty() {
+        let includes = includes
+            .iter()
+            .map(|i| JValue::String(i.filename.to_string()))
+            .collect();
+        attributes.push(("includes".to_string(), JValue::List(includes)));
+    }
+}
+
 fn add_headers(attributes: &mut Vec<(String, JValue)>, headers: &[KeyValue]) {
     if !headers.is_empty() {
         let headers = JValue::List(headers.iter().map(|h| h.to_json()).collect());
@@ -275,7 +306,26 @@ impl ToJson for MultipartParam {
         match self {
             MultipartParam::Param(param) => param.to_json(),
             MultipartParam::FilenameParam(param) => param.to_json(),
+            MultipartParam::NestedParam(param) => param.to_json(),
+        }
+    }
+}
+
+impl ToJson for NestedParam {
+    fn to_json(&self) -> JValue {
+        let mut attributes = vec![
+            ("name".to_string(), JValue::String(self.key.to_string())),
+            (
+                "multipart".to_string(),
+                JValue::String(self.value.subtype.to_string()),
+            ),
+        ];
+        if let Some(headers) = &self.value.headers {
+            attributes.push(("headers".to_string(), JValue::String(headers.to_string())));
         }
+        let parts = self.params.iter().map(|p| p.to_json()).collect();
+        attributes.push(("parts".to_string(), JValue::List(parts)));
+        JValue::Object(attributes)
     }
 }
 
@@ -311,44 +361,70 @@ impl ToJson for Cookie {
 impl ToJson for EntryOption {
     fn to_json(&self) -> JValue {
         let value = match &self.kind {
+            OptionKind::AuthType(value) => JValue::String(value.to_string()),
             OptionKind::AwsSigV4(value) => JValue::String(value.to_string()),
             OptionKind::CaCertificate(filename) => JValue::String(filename.to_string()),
+            OptionKind::Chunked(value) => value.to_json(),
             OptionKind::ClientCert(filename) => JValue::String(filename.to_string()),
+            OptionKind::ClientCertPassword(value) => JValue::String(value.to_string()),
+            OptionKind::ClientCertType(value) => JValue::String(value.to_string()),
             OptionKind::ClientKey(filename) => JValue::String(filename.to_string()),
+            OptionKind::ClockSkew(value) => value.to_json(),
             OptionKind::Compressed(value) => value.to_json(),
             OptionKind::ConnectTo(value) => JValue::String(value.to_string()),
             OptionKind::ConnectTimeout(value) => value.to_json(),
+            OptionKind::CookieFile(filename) => JValue::String(filename.to_string()),
             OptionKind::Delay(value) => value.to_json(),
             OptionKind::Digest(value) => value.to_json(),
+            OptionKind::DnsServers(value) => JValue::String(value.to_string()),
+            OptionKind::ExpectContinue(value) => value.to_json(),
+            OptionKind::ExportCapture(value) => JValue::String(value.clone()),
             OptionKind::FailWithBody(value) => value.to_json(),
             OptionKind::FollowLocation(value) => value.to_json(),
             OptionKind::FollowLocationTrusted(value) => value.to_json(),
+            OptionKind::Foreach(value) => value.to_json(),
             OptionKind::Header(value) => JValue::String(value.to_string()),
+            OptionKind::HmacSignature(value) => JValue::String(value.to_string()),
             OptionKind::Http10(value) => value.to_json(),
             OptionKind::Http11(value) => value.to_json(),
             OptionKind::Http2(value) => value.to_json(),
             OptionKind::Http3(value) => value.to_json(),
+            OptionKind::Http3Fallback(value) => value.to_json(),
             OptionKind::Insecure(value) => value.to_json(),
             OptionKind::IpV4(value) => value.to_json(),
             OptionKind::IpV6(value) => value.to_json(),
             OptionKind::LimitRate(value) => value.to_json(),
             OptionKind::MaxRedirect(value) => value.to_json(),
             OptionKind::MaxTime(value) => value.to_json(),
+            OptionKind::MultipartBoundary(value) => JValue::String(value.to_string()),
             OptionKind::Negotiate(value) => value.to_json(),
             OptionKind::NetRc(value) => value.to_json(),
             OptionKind::NetRcFile(filename) => JValue::String(filename.to_string()),
             OptionKind::NetRcOptional(value) => value.to_json(),
             OptionKind::NoHeader(value) => JValue::String(value.to_string()),
             OptionKind::Ntlm(value) => value.to_json(),
+            OptionKind::OAuth2ClientId(value) => JValue::String(value.to_string()),
+            OptionKind::OAuth2ClientSecret(value) => JValue::String(value.to_string()),
+            OptionKind::OAuth2TokenUrl(value) => JValue::String(value.to_string()),
+            OptionKind::OnlyIf(value) => value.to_json(),
             OptionKind::Output(filename) => JValue::String(filename.to_string()),
+            OptionKind::Paginate(value) => JValue::String(value.clone()),
+            OptionKind::PaginateMax(value) => value.to_json(),
             OptionKind::PathAsIs(value) => value.to_json(),
             OptionKind::PinnedPublicKey(value) => JValue::String(value.to_string()),
             OptionKind::Proxy(value) => JValue::String(value.to_string()),
+            OptionKind::ProxyHeader(value) => JValue::String(value.to_string()),
+            OptionKind::ProxyUser(value) => JValue::String(value.to_string()),
             OptionKind::Repeat(value) => value.to_json(),
             OptionKind::Resolve(value) => JValue::String(value.to_string()),
             OptionKind::Retry(value) => value.to_json(),
             OptionKind::RetryInterval(value) => value.to_json(),
+            OptionKind::RetryOnCaptureFailure(value) => value.to_json(),
             OptionKind::Skip(value) => value.to_json(),
+            OptionKind::SkipIf(value) => value.to_json(),
+            OptionKind::SoftAsserts(value) => value.to_json(),
+            OptionKind::NestedFormParams(value) => value.to_json(),
+            OptionKind::Trailer(value) => JValue::String(value.to_string()),
             OptionKind::UnixSocket(value) => JValue::String(value.to_string()),
             OptionKind::User(value) => JValue::String(value.to_string()),
             OptionKind::Variable(value) => {
@@ -440,6 +516,20 @@ impl ToJson for Capture {
             let filters = JValue::List(self.filters.iter().map(|(_, f)| f.to_json()).collect());
             attributes.push(("filters".to_string(), filters));
         }
+        if let Some((_, annotation)) = &self.type_annotation {
+            let value = JValue::String(annotation.value.identifier().to_string());
+            attributes.push(("type".to_string(), value));
+        }
+        match &self.fallback {
+            Some((_, CaptureFallback::Optional)) => {
+                attributes.push(("optional".to_string(), JValue::Boolean(true)));
+            }
+            Some((_, CaptureFallback::Default { value, .. })) => {
+                let (value, _) = json_predicate_value(value);
+                attributes.push(("default".to_string(), value));
+            }
+            None => {}
+        }
         if self.redacted {
             attributes.push(("redact".to_string(), JValue::Boolean(true)));
         }
@@ -475,6 +565,9 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
         QueryValue::Jsonpath { expr, .. } => {
             attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
         }
+        QueryValue::Csv { expr, .. } => {
+            attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
+        }
         QueryValue::Header { name, .. } => {
             attributes.push(("name".to_string(), JValue::String(name.to_string())));
         }
@@ -484,6 +577,11 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
         QueryValue::Xpath { expr, .. } => {
             attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
         }
+        QueryValue::Body {
+            charset: Some((_, charset)),
+        } => {
+            attributes.push(("charset".to_string(), JValue::String(charset.to_string())));
+        }
         QueryValue::Regex { value, .. } => {
             attributes.push(("expr".to_string(), value.to_json()));
         }
@@ -496,6 +594,18 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
         } => {
             attributes.push(("expr".to_string(), field.to_json()));
         }
+        QueryValue::Timings {
+            attribute_name: field,
+            ..
+        } => {
+            attributes.push(("expr".to_string(), field.to_json()));
+        }
+        QueryValue::DurationPercentile { percentile, .. } => {
+            attributes.push((
+                "percentile".to_string(),
+                JValue::Number(percentile.to_string()),
+            ));
+        }
         _ => {}
     };
     attributes
@@ -526,6 +636,12 @@ impl ToJson for CertificateAttributeName {
     }
 }
 
+impl ToJson for TimingsAttributeName {
+    fn to_json(&self) -> JValue {
+        JValue::String(self.identifier().to_string())
+    }
+}
+
 impl ToJson for Predicate {
     fn to_json(&self) -> JValue {
         let mut attributes = vec![];
@@ -536,10 +652,25 @@ impl ToJson for Predicate {
         attributes.push(("type".to_string(), JValue::String(identifier.to_string())));
 
         match &self.predicate_func.value {
-            PredicateFuncValue::Equal { value, .. } => add_predicate_value(&mut attributes, value),
+            PredicateFuncValue::Equal {
+                value, ignoring, ..
+            } => {
+                add_predicate_value(&mut attributes, value);
+                if let Some(ignoring) = ignoring {
+                    let paths = ignoring
+                        .paths
+                        .iter()
+                        .map(|(_, path)| JValue::String(path.to_string()))
+                        .collect();
+                    attributes.push(("ignoring".to_string(), JValue::List(paths)));
+                }
+            }
             PredicateFuncValue::NotEqual { value, .. } => {
                 add_predicate_value(&mut attributes, value);
             }
+            PredicateFuncValue::ConstantTimeEqual { value, .. } => {
+                add_predicate_value(&mut attributes, value);
+            }
             PredicateFuncValue::GreaterThan { value, .. } => {
                 add_predicate_value(&mut attributes, value);
             }
@@ -564,6 +695,9 @@ impl ToJson for Predicate {
             PredicateFuncValue::Include { value, .. } => {
                 add_predicate_value(&mut attributes, value);
             }
+            PredicateFuncValue::IncludeJson { value, .. } => {
+                add_predicate_value(&mut attributes, value);
+            }
             PredicateFuncValue::Match { value, .. } => {
                 add_predicate_value(&mut attributes, value);
             }
@@ -611,7 +745,7 @@ fn json_predicate_value(predicate_value: &PredicateValue) -> (JValue, Option<Str
             let base64_string = general_purpose::STANDARD.encode(value.value.clone());
             (JValue::String(base64_string), Some("base64".to_string()))
         }
-        PredicateValue::Placeholder(value) => (JValue::String(value.to_string()), None),
+        PredicateValue::Placeholder(value) => (value.to_json(), None),
         PredicateValue::Regex(value) => {
             (JValue::String(value.to_string()), Some("regex".to_string()))
         }
@@ -659,7 +793,17 @@ impl ToJson for FilterValue {
         attributes.push((att_name, att_value));
 
         match self {
-            FilterValue::Decode { encoding, .. } => {
+            FilterValue::AddDays { days, .. } => {
+                attributes.push(("days".to_string(), days.to_json()));
+            }
+            FilterValue::AddSeconds { seconds, .. } => {
+                attributes.push(("seconds".to_string(), seconds.to_json()));
+            }
+            FilterValue::BytesSlice { start, end, .. } => {
+                attributes.push(("start".to_string(), start.to_json()));
+                attributes.push(("end".to_string(), end.to_json()));
+            }
+            FilterValue::CharsetDecode { encoding, .. } | FilterValue::Decode { encoding, .. } => {
                 attributes.push(("encoding".to_string(), JValue::String(encoding.to_string())));
             }
             FilterValue::Format { fmt, .. } => {
@@ -668,11 +812,14 @@ impl ToJson for FilterValue {
             FilterValue::DateFormat { fmt, .. } => {
                 attributes.push(("fmt".to_string(), JValue::String(fmt.to_string())));
             }
+            FilterValue::Jq { expr, .. } => {
+                attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
+            }
             FilterValue::JsonPath { expr, .. } => {
                 attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
             }
             FilterValue::Nth { n, .. } => {
-                attributes.push(("n".to_string(), JValue::Number(n.to_string())));
+                attributes.push(("n".to_string(), n.to_json()));
             }
             FilterValue::Regex { value, .. } => {
                 attributes.push(("expr".to_string(), value.to_json()));
@@ -706,6 +853,16 @@ impl ToJson for FilterValue {
             FilterValue::ToDate { fmt, .. } => {
                 attributes.push(("fmt".to_string(), JValue::String(fmt.to_string())));
             }
+            FilterValue::ToHex { case, separator } => {
+                if let Some((_, case)) = case {
+                    let case = JValue::String(case.identifier().to_string());
+                    attributes.push(("case".to_string(), case));
+                }
+                if let Some((_, separator)) = separator {
+                    let separator = JValue::String(separator.to_string());
+                    attributes.push(("separator".to_string(), separator));
+                }
+            }
             FilterValue::UrlQueryParam { param, .. } => {
                 attributes.push(("param".to_string(), JValue::String(param.to_string())));
             }
@@ -718,6 +875,15 @@ impl ToJson for FilterValue {
     }
 }
 
+impl ToJson for IntegerValue {
+    fn to_json(&self) -> JValue {
+        match self {
+            IntegerValue::Literal(value) => JValue::Number(value.to_string()),
+            IntegerValue::Placeholder(placeholder) => placeholder.to_json(),
+        }
+    }
+}
+
 impl ToJson for Placeholder {
     fn to_json(&self) -> JValue {
         JValue::String(format!("{{{{{self}}}}}"))
@@ -913,6 +1079,8 @@ pub mod tests {
             space2: whitespace(),
             query: header_query(),
             filters: vec![],
+            type_annotation: None,
+            fallback: None,
             space3: whitespace(),
             redacted: false,
             line_terminator0: line_terminator(),
@@ -943,6 +1111,7 @@ pub mod tests {
                         value,
                         value.to_string().to_source(),
                     ))),
+                    ignoring: None,
                 },
             },
         }
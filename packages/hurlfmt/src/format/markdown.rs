@@ -0,0 +1,226 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Export of Hurl files to Markdown documentation pages.
+//!
+//! Each entry is rendered as a section: a heading with the request method and URL, the comments
+//! before the request as prose, then the request and the response (headers and sections) in
+//! `hurl` code blocks. Bodies are rendered in their own code blocks, tagged with their language.
+use hurl_core::ast::{Body, Bytes, Entry, HurlFile, LineTerminator, Request, Response};
+use hurl_core::types::ToSource;
+
+use crate::linter::{self, LintConfig};
+
+/// Returns a Markdown document of the Hurl file `hurl_file`.
+pub fn format(hurl_file: &HurlFile) -> String {
+    let config = LintConfig::default();
+    let mut sections = vec![];
+    if let Some(defaults) = &hurl_file.defaults {
+        let file = HurlFile {
+            defaults: Some(defaults.clone()),
+            entries: vec![],
+            includes: vec![],
+            line_terminators: vec![],
+        };
+        let source = linter::lint_hurl_file(&file, &config);
+        sections.push(format!("## Defaults\n\n{}", fenced("hurl", &source)));
+    }
+    for entry in &hurl_file.entries {
+        sections.push(format_entry(entry, &config));
+    }
+    sections.join("\n")
+}
+
+/// Returns the Markdown section of an `entry`.
+fn format_entry(entry: &Entry, config: &LintConfig) -> String {
+    let request = &entry.request;
+    let mut s = format!("## {} {}\n\n", request.method, request.url.to_source());
+    s.push_str(&prose(&request.line_terminators));
+
+    // Comments before the request and its body are already rendered as prose.
+    let head = Request {
+        line_terminators: vec![],
+        body: None,
+        ..request.clone()
+    };
+    s.push_str(&fenced("hurl", &linter::lint_request(&head, config)));
+    if let Some(body) = &request.body {
+        s.push_str("\nRequest body:\n\n");
+        s.push_str(&format_body(body));
+    }
+
+    if let Some(response) = &entry.response {
+        s.push_str("\nResponse:\n\n");
+        s.push_str(&prose(&response.line_terminators));
+        let head = Response {
+            line_terminators: vec![],
+            body: None,
+            ..response.clone()
+        };
+        s.push_str(&fenced("hurl", &linter::lint_response(&head, config)));
+        if let Some(body) = &response.body {
+            s.push_str("\nResponse body:\n\n");
+            s.push_str(&format_body(body));
+        }
+    }
+    s
+}
+
+/// Returns the comments of `line_terminators` as Markdown paragraphs.
+///
+/// Consecutive comments are joined in the same paragraph; paragraphs are separated by empty lines
+/// or empty comments.
+fn prose(line_terminators: &[LineTerminator]) -> String {
+    let mut paragraphs = vec![];
+    let mut lines = vec![];
+    for lt in line_terminators {
+        match lt.comment.as_ref().map(|c| c.value.trim()) {
+            Some(line) if !line.is_empty() => lines.push(line),
+            _ => {
+                if !lines.is_empty() {
+                    paragraphs.push(lines.join("\n"));
+                    lines.clear();
+                }
+            }
+        }
+    }
+    if !lines.is_empty() {
+        paragraphs.push(lines.join("\n"));
+    }
+    paragraphs.iter().map(|p| format!("{p}\n\n")).collect()
+}
+
+/// Returns a request or response `body` as a Markdown code block, preceded by its comments.
+fn format_body(body: &Body) -> String {
+    let mut s = prose(&body.line_terminators);
+    let block = match &body.value {
+        Bytes::Json(value) => fenced("json", &format!("{}\n", value.to_source())),
+        Bytes::Xml(value) => fenced("xml", &format!("{value}\n")),
+        Bytes::MultilineString(multiline) => {
+            let lang = match multiline.lang() {
+                "raw" => "",
+                lang => lang,
+            };
+            fenced(lang, multiline.kind.to_source().as_str())
+        }
+        bytes => fenced("hurl", &format!("{}\n", linter::lint_bytes(bytes))),
+    };
+    s.push_str(&block);
+    s
+}
+
+/// Returns a fenced code block of `content`, tagged with a `lang`.
+///
+/// The fence is made of more backticks than any run of backticks in `content`, so the block can't
+/// be closed early.
+fn fenced(lang: &str, content: &str) -> String {
+    let max_run = content.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(max_run.max(2) + 1);
+    let newline = if content.ends_with('\n') { "" } else { "\n" };
+    format!("{fence}{lang}\n{content}{newline}{fence}\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::parser::parse_hurl_file;
+
+    use super::*;
+
+    #[test]
+    fn test_format_markdown() {
+        let content = r#"# Create a new user.
+#
+# The user id is returned in the response.
+POST http://localhost:8000/users
+Content-Type: application/json
+[Query]
+dry-run: false
+# The user to create:
+{
+  "name": "Bob"
+}
+HTTP 201
+[Asserts]
+jsonpath "$.id" exists
+
+GET http://localhost:8000/users/1
+HTTP 200
+```
+Bob
+```
+"#;
+        let hurl_file = parse_hurl_file(content).unwrap();
+        assert_eq!(
+            format(&hurl_file),
+            r#"## POST http://localhost:8000/users
+
+Create a new user.
+
+The user id is returned in the response.
+
+```hurl
+POST http://localhost:8000/users
+Content-Type: application/json
+[Query]
+dry-run: false
+```
+
+Request body:
+
+The user to create:
+
+```json
+{
+  "name": "Bob"
+}
+```
+
+Response:
+
+```hurl
+HTTP 201
+[Asserts]
+jsonpath "$.id" exists
+```
+
+## GET http://localhost:8000/users/1
+
+```hurl
+GET http://localhost:8000/users/1
+```
+
+Response:
+
+```hurl
+HTTP 200
+```
+
+Response body:
+
+```
+Bob
+```
+"#
+        );
+    }
+
+    #[test]
+    fn test_fenced() {
+        assert_eq!(fenced("json", "{}"), "```json\n{}\n```\n");
+        assert_eq!(fenced("", "a ``` fence\n"), "````\na ``` fence\n````\n");
+    }
+}
@@ -16,8 +16,9 @@
  *
  */
 pub use self::json::format as format_json;
-pub use self::text::format as format_text;
+pub use self::markdown::format as format_markdown;
+pub(crate) use self::serialize_json::format_char as escape_json_char;
 
 mod json;
+mod markdown;
 mod serialize_json;
-mod text;
@@ -64,7 +64,7 @@ impl JValue {
     }
 }
 
-fn format_char(c: char) -> String {
+pub(crate) fn format_char(c: char) -> String {
     if c == '"' {
         "\\\"".to_string()
     } else if c == '\\' {
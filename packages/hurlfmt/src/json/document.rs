@@ -0,0 +1,257 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Reading of a JSON Hurl file to a JSON AST.
+//!
+//! The JSON parser of Hurl parses the placeholders of a string from its escaped source, so a
+//! placeholder like `"{{\"user-\" + id}}"` can not be read by it. Strings are decoded here before
+//! being split into literal parts and placeholders.
+use hurl_core::ast::{
+    JsonListElement, JsonObjectElement, JsonValue, SourceInfo, Template, TemplateElement,
+};
+use hurl_core::parser::{
+    self, ParseError, ParseErrorKind, ParseResult, parse_json_boolean, parse_json_null,
+    parse_json_number,
+};
+use hurl_core::reader::{CharPos, Pos, Reader};
+use hurl_core::types::ToSource;
+
+use crate::format::escape_json_char;
+
+/// Reads the JSON document `s`.
+pub fn parse(s: &str) -> Result<JsonValue, String> {
+    let mut reader = Reader::new(s.trim());
+    let error = |pos: Pos| {
+        format!(
+            "Can not parse JSON at line {}, column {}",
+            pos.line, pos.column
+        )
+    };
+    let value = value(&mut reader).map_err(|e| error(e.pos))?;
+    whitespace(&mut reader);
+    if !reader.is_eof() {
+        return Err(error(reader.cursor().pos));
+    }
+    Ok(value)
+}
+
+fn value(reader: &mut Reader) -> ParseResult<JsonValue> {
+    match reader.peek() {
+        Some('"') => Ok(JsonValue::String(string(reader)?)),
+        Some('[') => nested(reader, list),
+        Some('{') => nested(reader, object),
+        Some('n') => parse_json_null(reader),
+        Some('t' | 'f') => parse_json_boolean(reader),
+        _ => parse_json_number(reader),
+    }
+}
+
+/// Calls `f` one nesting level deeper, failing if `reader` nesting limit is reached.
+fn nested(
+    reader: &mut Reader,
+    f: fn(&mut Reader) -> ParseResult<JsonValue>,
+) -> ParseResult<JsonValue> {
+    let depth = reader.depth();
+    let max = reader.limits().max_nesting;
+    if depth >= max {
+        let kind = ParseErrorKind::NestingTooDeep { max };
+        return Err(ParseError::new(reader.cursor().pos, false, kind));
+    }
+    reader.set_depth(depth + 1);
+    let result = f(reader);
+    reader.set_depth(depth);
+    result
+}
+
+fn list(reader: &mut Reader) -> ParseResult<JsonValue> {
+    expect('[', reader)?;
+    let space0 = whitespace(reader);
+    let mut elements = vec![];
+    if reader.peek() != Some(']') {
+        loop {
+            let value = value(reader)?;
+            let space1 = whitespace(reader);
+            elements.push(JsonListElement {
+                space0: String::new(),
+                value,
+                space1,
+            });
+            if reader.peek() != Some(',') {
+                break;
+            }
+            reader.read();
+            whitespace(reader);
+        }
+    }
+    expect(']', reader)?;
+    Ok(JsonValue::List { space0, elements })
+}
+
+fn object(reader: &mut Reader) -> ParseResult<JsonValue> {
+    expect('{', reader)?;
+    let space0 = whitespace(reader);
+    let mut elements = vec![];
+    if reader.peek() != Some('}') {
+        loop {
+            let name = string(reader)?;
+            let space1 = whitespace(reader);
+            expect(':', reader)?;
+            let space2 = whitespace(reader);
+            let value = value(reader)?;
+            let space3 = whitespace(reader);
+            elements.push(JsonObjectElement {
+                space0: String::new(),
+                name,
+                space1,
+                space2,
+                value,
+                space3,
+            });
+            if reader.peek() != Some(',') {
+                break;
+            }
+            reader.read();
+            whitespace(reader);
+        }
+    }
+    expect('}', reader)?;
+    Ok(JsonValue::Object { space0, elements })
+}
+
+/// Reads a JSON string to a template, its placeholders being parsed from the decoded string.
+fn string(reader: &mut Reader) -> ParseResult<Template> {
+    expect('"', reader)?;
+    let start = reader.cursor().pos;
+    let mut chars = vec![];
+    loop {
+        let pos = reader.cursor().pos;
+        let c = match reader.read() {
+            Some('"') => break,
+            Some('\\') => escaped_char(reader)?,
+            Some(c) if !c.is_control() => c,
+            _ => return Err(expecting("char", pos)),
+        };
+        chars.push((c, c.to_string(), pos));
+    }
+    let end = reader.cursor().pos;
+    let source_info = SourceInfo::new(start, end);
+    let encoded_string = parser::EncodedString { source_info, chars };
+    let elements = parser::templatize(encoded_string)?
+        .into_iter()
+        .map(|element| match element {
+            TemplateElement::String { value, .. } => {
+                let source = value.chars().map(escape_json_char).collect::<String>();
+                TemplateElement::String {
+                    value,
+                    source: source.to_source(),
+                }
+            }
+            TemplateElement::Placeholder(_) => element,
+        })
+        .collect();
+    Ok(Template::new(Some('"'), elements, source_info))
+}
+
+fn escaped_char(reader: &mut Reader) -> ParseResult<char> {
+    let pos = reader.cursor().pos;
+    let c = match reader.read() {
+        Some('"') => '"',
+        Some('\\') => '\\',
+        Some('/') => '/',
+        Some('b') => '\x08',
+        Some('f') => '\x0c',
+        Some('n') => '\n',
+        Some('r') => '\r',
+        Some('t') => '\t',
+        Some('u') => {
+            let mut cp = hex_value(reader)?;
+            // Characters outside the basic multilingual plane are encoded as a surrogate pair.
+            if (0xd800..0xdc00).contains(&cp) && reader.peek_n(2) == "\\u" {
+                reader.read_n(CharPos(2));
+                let low = hex_value(reader)?;
+                cp = 0x10000 + ((cp - 0xd800) << 10) + (low.wrapping_sub(0xdc00) & 0x3ff);
+            }
+            return char::from_u32(cp).ok_or(ParseError::new(pos, false, ParseErrorKind::Unicode));
+        }
+        _ => return Err(ParseError::new(pos, false, ParseErrorKind::EscapeChar)),
+    };
+    Ok(c)
+}
+
+fn hex_value(reader: &mut Reader) -> ParseResult<u32> {
+    let pos = reader.cursor().pos;
+    let digits = reader.read_n(CharPos(4));
+    match u32::from_str_radix(&digits, 16) {
+        Ok(value) if digits.len() == 4 => Ok(value),
+        _ => Err(ParseError::new(pos, false, ParseErrorKind::Unicode)),
+    }
+}
+
+fn whitespace(reader: &mut Reader) -> String {
+    reader.read_while(|c| [' ', '\t', '\n', '\r'].contains(&c))
+}
+
+fn expect(c: char, reader: &mut Reader) -> ParseResult<()> {
+    let pos = reader.cursor().pos;
+    if reader.read() == Some(c) {
+        Ok(())
+    } else {
+        Err(expecting(&c.to_string(), pos))
+    }
+}
+
+fn expecting(value: &str, pos: Pos) -> ParseError {
+    let kind = ParseErrorKind::Expecting {
+        value: value.to_string(),
+    };
+    ParseError::new(pos, false, kind)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_string() {
+        let JsonValue::String(template) = parse(r#""a\"bé😀""#).unwrap() else {
+            panic!("expecting a string");
+        };
+        assert_eq!(template.to_string(), "a\"bé😀");
+        assert_eq!(template.to_source().as_str(), r#""a\"bé😀""#);
+    }
+
+    #[test]
+    fn test_parse_placeholder_with_string() {
+        let JsonValue::String(template) = parse(r#""x-{{\"user-\" + id}}""#).unwrap() else {
+            panic!("expecting a string");
+        };
+        assert_eq!(template.elements.len(), 2);
+        assert_eq!(template.to_string(), "x-{{\"user-\" + id}}");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse(r#"{"a":[1,2}"#).unwrap_err(),
+            "Can not parse JSON at line 1, column 10"
+        );
+        assert_eq!(
+            parse(r#"{"a":1} x"#).unwrap_err(),
+            "Can not parse JSON at line 1, column 9"
+        );
+    }
+}
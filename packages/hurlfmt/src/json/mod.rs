@@ -0,0 +1,647 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Import of the JSON representation of a Hurl file (as produced by `hurlfmt --out json`).
+//!
+//! The JSON document is deserialized to a [`HurlFile`], that can then be printed to Hurl text by
+//! the linter and formatted to any output format.
+use base64::Engine;
+use base64::engine::general_purpose;
+use hurl_core::ast::{
+    Base64, Body, Bytes, Comment, Cookie, Defaults, Entry, File, FilenameParam, FilenameValue,
+    Float, GraphQl, HurlFile, I64, Include, JsonValue, KeyValue, LineTerminator, Method,
+    MultilineString, MultilineStringKind, MultipartParam, NestedParam, NestedValue, Number,
+    Request, Response, Section, SectionValue, SourceInfo, Status, StatusValue, Template, U64,
+    Version, VersionValue, Whitespace,
+};
+use hurl_core::reader::Pos;
+use hurl_core::types::ToSource;
+
+mod document;
+mod option;
+mod query;
+mod template;
+
+/// Deserializes a JSON Hurl file `s` to a [`HurlFile`].
+pub fn parse(s: &str) -> Result<HurlFile, String> {
+    let value = document::parse(s)?;
+    if !matches!(value, JsonValue::Object { .. }) {
+        return Err("JSON Hurl file must be an object".to_string());
+    }
+    let defaults = match get(&value, "defaults") {
+        Some(defaults) => {
+            Some(parse_defaults(defaults).map_err(|message| format!("defaults: {message}"))?)
+        }
+        None => None,
+    };
+    let mut blank_line = defaults.is_some();
+    let mut entries = vec![];
+    for (i, entry) in get_list(&value, "entries")?.into_iter().enumerate() {
+        let entry = parse_entry(entry, blank_line)
+            .map_err(|message| format!("entry {}: {message}", i + 1))?;
+        entries.push(entry);
+        blank_line = true;
+    }
+    let includes = parse_includes(&value, blank_line)?;
+    Ok(HurlFile {
+        defaults,
+        entries,
+        includes,
+        line_terminators: vec![],
+    })
+}
+
+fn parse_defaults(defaults: &JsonValue) -> Result<Defaults, String> {
+    let headers = parse_key_values(defaults, "headers")?;
+    let mut sections = vec![];
+    let options = get_list(defaults, "options")?
+        .into_iter()
+        .map(option::parse_option)
+        .collect::<Result<Vec<_>, _>>()?;
+    if !options.is_empty() {
+        sections.push(section(SectionValue::Options(options)));
+    }
+    Ok(Defaults {
+        line_terminators: vec![],
+        space0: empty_whitespace(),
+        line_terminator0: line_terminator(),
+        headers,
+        sections,
+        source_info: source_info(),
+    })
+}
+
+/// Deserializes an `entry`, separated from the previous node of the file by a blank line if
+/// `blank_line` is true.
+fn parse_entry(entry: &JsonValue, blank_line: bool) -> Result<Entry, String> {
+    let includes = parse_includes(entry, blank_line)?;
+    let Some(request) = get(entry, "request") else {
+        return Err("missing request".to_string());
+    };
+    // The blank line separating this entry is put before its first include, if any.
+    let request = parse_request(request, blank_line && includes.is_empty())?;
+    let response = match get(entry, "response") {
+        Some(response) => Some(parse_response(response)?),
+        None => None,
+    };
+    Ok(Entry {
+        includes,
+        request,
+        response,
+    })
+}
+
+fn parse_includes(value: &JsonValue, blank_line: bool) -> Result<Vec<Include>, String> {
+    let mut includes = vec![];
+    for filename in get_list(value, "includes")? {
+        let line_terminators = if blank_line && includes.is_empty() {
+            vec![line_terminator()]
+        } else {
+            vec![]
+        };
+        includes.push(Include {
+            line_terminators,
+            space0: empty_whitespace(),
+            space1: one_whitespace(),
+            filename: template::quoted(as_string(filename)?),
+            line_terminator0: line_terminator(),
+            source_info: source_info(),
+        });
+    }
+    Ok(includes)
+}
+
+fn parse_request(request: &JsonValue, blank_line: bool) -> Result<Request, String> {
+    let mut line_terminators = vec![];
+    if blank_line {
+        line_terminators.push(line_terminator());
+    }
+    for comment in get_list(request, "comments")? {
+        let comment = Comment {
+            value: as_string(comment)?.to_string(),
+            source_info: source_info(),
+        };
+        line_terminators.push(LineTerminator {
+            comment: Some(comment),
+            ..line_terminator()
+        });
+    }
+    let method = Method::new(&get_string(request, "method")?.to_string());
+    let url = template::value(get_string(request, "url")?);
+    let headers = parse_key_values(request, "headers")?;
+
+    let mut sections = vec![];
+    let params = parse_key_values(request, "query_string_params")?;
+    if !params.is_empty() {
+        sections.push(section(SectionValue::QueryParams(params, true)));
+    }
+    let params = parse_key_values(request, "form_params")?;
+    if !params.is_empty() {
+        sections.push(section(SectionValue::FormParams(params, true)));
+    }
+    let params = get_list(request, "multipart_form_data")?
+        .into_iter()
+        .map(parse_multipart_param)
+        .collect::<Result<Vec<_>, _>>()?;
+    if !params.is_empty() {
+        sections.push(section(SectionValue::MultipartFormData(params, true)));
+    }
+    let cookies = get_list(request, "cookies")?
+        .into_iter()
+        .map(parse_cookie)
+        .collect::<Result<Vec<_>, _>>()?;
+    if !cookies.is_empty() {
+        sections.push(section(SectionValue::Cookies(cookies)));
+    }
+    let options = get_list(request, "options")?
+        .into_iter()
+        .map(option::parse_option)
+        .collect::<Result<Vec<_>, _>>()?;
+    if !options.is_empty() {
+        sections.push(section(SectionValue::Options(options)));
+    }
+
+    let body = match get(request, "body") {
+        Some(body) => Some(parse_body(body)?),
+        None => None,
+    };
+    Ok(Request {
+        line_terminators,
+        space0: empty_whitespace(),
+        method,
+        space1: one_whitespace(),
+        url,
+        line_terminator0: line_terminator(),
+        headers,
+        sections,
+        body,
+        source_info: source_info(),
+    })
+}
+
+fn parse_response(response: &JsonValue) -> Result<Response, String> {
+    let version = match get(response, "version") {
+        Some(version) => match as_string(version)?.to_string().as_str() {
+            "HTTP/1.0" => VersionValue::Version1,
+            "HTTP/1.1" => VersionValue::Version11,
+            "HTTP/2" => VersionValue::Version2,
+            "HTTP/3" => VersionValue::Version3,
+            "HTTP" => VersionValue::VersionAny,
+            version => return Err(format!("unknown version {version}")),
+        },
+        None => VersionValue::VersionAny,
+    };
+    let status = match get(response, "status") {
+        Some(status) => StatusValue::Specific(as_u64(status)?.as_u64()),
+        None => StatusValue::Any,
+    };
+    let headers = parse_key_values(response, "headers")?;
+
+    let mut sections = vec![];
+    let captures = get_list(response, "captures")?
+        .into_iter()
+        .map(query::parse_capture)
+        .collect::<Result<Vec<_>, _>>()?;
+    if !captures.is_empty() {
+        sections.push(section(SectionValue::Captures(captures)));
+    }
+    let asserts = get_list(response, "asserts")?
+        .into_iter()
+        .map(query::parse_assert)
+        .collect::<Result<Vec<_>, _>>()?;
+    if !asserts.is_empty() {
+        sections.push(section(SectionValue::Asserts(asserts)));
+    }
+
+    let body = match get(response, "body") {
+        Some(body) => Some(parse_body(body)?),
+        None => None,
+    };
+    Ok(Response {
+        line_terminators: vec![],
+        version: Version {
+            value: version,
+            source_info: source_info(),
+        },
+        space0: one_whitespace(),
+        status: Status {
+            value: status,
+            source_info: source_info(),
+        },
+        space1: empty_whitespace(),
+        line_terminator0: line_terminator(),
+        headers,
+        sections,
+        body,
+        source_info: source_info(),
+    })
+}
+
+/// Deserializes the list of `{"name": ..., "value": ...}` objects of the attribute `name`.
+fn parse_key_values(value: &JsonValue, name: &str) -> Result<Vec<KeyValue>, String> {
+    get_list(value, name)?
+        .into_iter()
+        .map(parse_key_value)
+        .collect()
+}
+
+fn parse_key_value(key_value: &JsonValue) -> Result<KeyValue, String> {
+    Ok(KeyValue {
+        line_terminators: vec![],
+        space0: empty_whitespace(),
+        key: template::key(get_string(key_value, "name")?),
+        space1: empty_whitespace(),
+        space2: one_whitespace(),
+        value: template::value(get_string(key_value, "value")?),
+        line_terminator0: line_terminator(),
+    })
+}
+
+fn parse_cookie(cookie: &JsonValue) -> Result<Cookie, String> {
+    Ok(Cookie {
+        line_terminators: vec![],
+        space0: empty_whitespace(),
+        name: template::key(get_string(cookie, "name")?),
+        space1: empty_whitespace(),
+        space2: one_whitespace(),
+        value: template::value(get_string(cookie, "value")?),
+        line_terminator0: line_terminator(),
+    })
+}
+
+fn parse_multipart_param(param: &JsonValue) -> Result<MultipartParam, String> {
+    let key = template::key(get_string(param, "name")?);
+    if let Some(subtype) = get(param, "multipart") {
+        let headers = match get(param, "headers") {
+            Some(headers) => Some(template::value(as_string(headers)?)),
+            None => None,
+        };
+        let params = get_list(param, "parts")?
+            .into_iter()
+            .map(parse_multipart_param)
+            .collect::<Result<Vec<_>, _>>()?;
+        return Ok(MultipartParam::NestedParam(NestedParam {
+            line_terminators: vec![],
+            space0: empty_whitespace(),
+            key,
+            space1: empty_whitespace(),
+            space2: one_whitespace(),
+            value: NestedValue {
+                space0: empty_whitespace(),
+                subtype: template::key(as_string(subtype)?),
+                space1: empty_whitespace(),
+                space2: one_whitespace(),
+                headers,
+            },
+            line_terminator0: line_terminator(),
+            params,
+        }));
+    }
+    let Some(filename) = get(param, "filename") else {
+        return Ok(MultipartParam::Param(parse_key_value(param)?));
+    };
+    let content_type = match get(param, "content_type") {
+        Some(content_type) => Some(template::value(as_string(content_type)?)),
+        None => None,
+    };
+    Ok(MultipartParam::FilenameParam(FilenameParam {
+        line_terminators: vec![],
+        space0: empty_whitespace(),
+        key,
+        space1: empty_whitespace(),
+        space2: one_whitespace(),
+        value: FilenameValue {
+            space0: empty_whitespace(),
+            filename: template::filename(as_string(filename)?),
+            space1: empty_whitespace(),
+            space2: one_whitespace(),
+            content_type,
+        },
+        line_terminator0: line_terminator(),
+    }))
+}
+
+fn parse_body(body: &JsonValue) -> Result<Body, String> {
+    Ok(Body {
+        line_terminators: vec![],
+        space0: empty_whitespace(),
+        value: parse_bytes(body)?,
+        line_terminator0: line_terminator(),
+    })
+}
+
+fn parse_bytes(body: &JsonValue) -> Result<Bytes, String> {
+    if let Some(encoding) = get(body, "encoding") {
+        let encoding = as_string(encoding)?.to_string();
+        if encoding != "base64" {
+            return Err(format!("unknown body encoding {encoding}"));
+        }
+        let source = get_string(body, "value")?.to_string();
+        let value = general_purpose::STANDARD
+            .decode(&source)
+            .map_err(|_| format!("invalid base64 body {source}"))?;
+        return Ok(Bytes::Base64(Base64 {
+            space0: empty_whitespace(),
+            value,
+            source: source.to_source(),
+            space1: empty_whitespace(),
+        }));
+    }
+    let kind = get_string(body, "type")?.to_string();
+    if kind == "file" {
+        return Ok(Bytes::File(File {
+            space0: empty_whitespace(),
+            filename: template::filename(get_string(body, "filename")?),
+            space1: empty_whitespace(),
+        }));
+    }
+    let Some(value) = get(body, "value") else {
+        return Err("missing body value".to_string());
+    };
+    let bytes = match (kind.as_str(), value) {
+        // Multiline JSON bodies end with a newline, contrary to JSON literal bodies.
+        ("json", JsonValue::String(template)) if template.to_string().ends_with('\n') => {
+            multiline(MultilineStringKind::Json(template::multiline(template)))
+        }
+        ("json", _) => Bytes::Json(value.clone()),
+        ("xml", _) => {
+            // Multiline XML bodies end with a newline, contrary to XML literal bodies.
+            let value = as_string(value)?;
+            if value.to_string().ends_with('\n') {
+                multiline(MultilineStringKind::Xml(template::multiline(value)))
+            } else {
+                Bytes::Xml(value.to_string())
+            }
+        }
+        ("text", _) => {
+            let value = as_string(value)?;
+            if value.to_string().contains('\n') {
+                multiline(MultilineStringKind::Text(template::multiline(value)))
+            } else {
+                Bytes::OnelineString(template::oneline(value))
+            }
+        }
+        ("raw", _) => multiline(MultilineStringKind::Raw(template::multiline(as_string(
+            value,
+        )?))),
+        ("graphql", _) => multiline(MultilineStringKind::GraphQl(GraphQl {
+            value: template::multiline(as_string(value)?),
+            variables: None,
+        })),
+        _ => return Err(format!("unknown body type {kind}")),
+    };
+    Ok(bytes)
+}
+
+fn multiline(kind: MultilineStringKind) -> Bytes {
+    Bytes::MultilineString(MultilineString {
+        space: empty_whitespace(),
+        newline: Whitespace {
+            value: "\n".to_string(),
+            source_info: source_info(),
+        },
+        kind,
+    })
+}
+
+fn section(value: SectionValue) -> Section {
+    Section {
+        line_terminators: vec![],
+        space0: empty_whitespace(),
+        line_terminator0: line_terminator(),
+        value,
+        source_info: source_info(),
+    }
+}
+
+/// Returns the value of the attribute `name` of the JSON object `value`.
+fn get<'a>(value: &'a JsonValue, name: &str) -> Option<&'a JsonValue> {
+    match value {
+        JsonValue::Object { elements, .. } => elements
+            .iter()
+            .find(|e| e.name.to_string() == name)
+            .map(|e| &e.value),
+        _ => None,
+    }
+}
+
+/// Returns the mandatory string attribute `name` of the JSON object `value`.
+fn get_string<'a>(value: &'a JsonValue, name: &str) -> Result<&'a Template, String> {
+    match get(value, name) {
+        Some(value) => as_string(value),
+        None => Err(format!("missing attribute {name}")),
+    }
+}
+
+/// Returns the elements of the list attribute `name` of the JSON object `value`, an empty list
+/// if the attribute is not present.
+fn get_list<'a>(value: &'a JsonValue, name: &str) -> Result<Vec<&'a JsonValue>, String> {
+    match get(value, name) {
+        None => Ok(vec![]),
+        Some(JsonValue::List { elements, .. }) => Ok(elements.iter().map(|e| &e.value).collect()),
+        Some(_) => Err(format!("attribute {name} must be a list")),
+    }
+}
+
+fn as_string(value: &JsonValue) -> Result<&Template, String> {
+    match value {
+        JsonValue::String(template) => Ok(template),
+        _ => Err(format!("expecting a string, got {}", value.to_source())),
+    }
+}
+
+fn as_u64(value: &JsonValue) -> Result<U64, String> {
+    match value {
+        JsonValue::Number(n) => match n.parse() {
+            Ok(v) => Ok(U64::new(v, n.to_source())),
+            Err(_) => Err(format!("expecting a positive integer, got {n}")),
+        },
+        _ => Err(format!("expecting a number, got {}", value.to_source())),
+    }
+}
+
+fn as_i64(value: &JsonValue) -> Result<I64, String> {
+    match value {
+        JsonValue::Number(n) => match n.parse() {
+            Ok(v) => Ok(I64::new(v, n.to_source())),
+            Err(_) => Err(format!("expecting an integer, got {n}")),
+        },
+        _ => Err(format!("expecting a number, got {}", value.to_source())),
+    }
+}
+
+/// Returns the number of the JSON number `s`, keeping its source.
+fn parse_number(s: &str) -> Option<Number> {
+    if let Ok(value) = s.parse::<i64>() {
+        return Some(Number::Integer(I64::new(value, s.to_source())));
+    }
+    if s.contains(['.', 'e', 'E']) {
+        let value = s.parse::<f64>().ok()?;
+        return Some(Number::Float(Float::new(value, s.to_source())));
+    }
+    if s.trim_start_matches('-')
+        .chars()
+        .all(|c| c.is_ascii_digit())
+        && !s.is_empty()
+    {
+        return Some(Number::BigInteger(s.to_string()));
+    }
+    None
+}
+
+fn empty_whitespace() -> Whitespace {
+    Whitespace {
+        value: String::new(),
+        source_info: source_info(),
+    }
+}
+
+fn one_whitespace() -> Whitespace {
+    Whitespace {
+        value: " ".to_string(),
+        source_info: source_info(),
+    }
+}
+
+fn line_terminator() -> LineTerminator {
+    LineTerminator {
+        space0: empty_whitespace(),
+        comment: None,
+        newline: Whitespace {
+            value: "\n".to_string(),
+            source_info: source_info(),
+        },
+    }
+}
+
+/// Returns the source info of the deserialized nodes, that have no position in a Hurl file.
+fn source_info() -> SourceInfo {
+    SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::{self, LintConfig};
+
+    /// Deserializes the JSON Hurl file `s` and prints it as Hurl.
+    fn parse_to_hurl(s: &str) -> String {
+        let hurl_file = parse(s).unwrap();
+        linter::lint_hurl_file(&hurl_file, &LintConfig::default())
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        assert_eq!(parse_to_hurl(r#"{"entries":[]}"#), "");
+    }
+
+    #[test]
+    fn test_parse_request() {
+        let json = r#"{"entries":[
+            {"request":{"method":"POST","url":"http://localhost:8000/{{path}}",
+                "headers":[{"name":"x-custom","value":"a # b"}],
+                "form_params":[{"name":"first name","value":"Bob"}],
+                "options":[{"name":"insecure","value":true},{"value":2,"unit":"s","name":"delay"},
+                    {"name":"variable","value":"id=1"}],
+                "comments":[" Create user"]}},
+            {"request":{"method":"GET","url":"http://localhost:8000/file"}}
+        ]}"#;
+        assert_eq!(
+            parse_to_hurl(json),
+            r#"# Create user
+POST http://localhost:8000/{{path}}
+x-custom: a \# b
+[Options]
+insecure: true
+delay: 2s
+variable: id=1
+[Form]
+first\u{20}name: Bob
+
+GET http://localhost:8000/file
+"#
+        );
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let json = r#"{"entries":[{"request":{"method":"GET","url":"http://localhost:8000/api"},
+            "response":{"version":"HTTP/1.1","status":200,
+                "captures":[{"name":"token","query":{"type":"header","name":"X-Token"},"redact":true}],
+                "asserts":[
+                    {"query":{"type":"jsonpath","expr":"$.name"},"filters":[{"type":"nth","n":0}],"predicate":{"type":"==","value":"Bob"}},
+                    {"query":{"type":"body"},"predicate":{"not":true,"type":"matches","value":"^a/b$","encoding":"regex"}},
+                    {"query":{"type":"jsonpath","expr":"$.count"},"predicate":{"type":"==","value":"{{count}}"}},
+                    {"query":{"type":"cookie","expr":"LSID[Max-Age]"},"predicate":{"type":"exists"}}
+                ],
+                "body":{"type":"json","value":{"name":"Bob"}}}}]}"#;
+        assert_eq!(
+            parse_to_hurl(json),
+            r#"GET http://localhost:8000/api
+HTTP/1.1 200
+[Captures]
+token: header "X-Token" redact
+[Asserts]
+jsonpath "$.name" nth 0 == "Bob"
+body not matches /^a\/b$/
+jsonpath "$.count" == {{count}}
+cookie "LSID[Max-Age]" exists
+{"name":"Bob"}
+"#
+        );
+    }
+
+    #[test]
+    fn test_parse_defaults_and_includes() {
+        let json = r#"{"defaults":{"headers":[{"name":"User-Agent","value":"hurl"}]},
+            "entries":[{"includes":["common/login.hurl"],
+                "request":{"method":"GET","url":"http://localhost:8000/api"}}],
+            "includes":["common/logout.hurl"]}"#;
+        assert_eq!(
+            parse_to_hurl(json),
+            r#"[Defaults]
+User-Agent: hurl
+
+include "common/login.hurl"
+GET http://localhost:8000/api
+
+include "common/logout.hurl"
+"#
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse(r#"{"entries":[{}]}"#).unwrap_err(),
+            "entry 1: missing request"
+        );
+        assert_eq!(
+            parse(
+                r#"{"entries":[{"request":{"method":"GET","url":"http://localhost:8000",
+                "options":[{"name":"foo","value":true}]}}]}"#
+            )
+            .unwrap_err(),
+            "entry 1: option foo: unknown option"
+        );
+        assert!(
+            parse(r#"{"entries":"#)
+                .unwrap_err()
+                .starts_with("Can not parse JSON at line 1")
+        );
+    }
+}
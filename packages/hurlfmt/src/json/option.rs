@@ -0,0 +1,267 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Deserialization of the entry options of a JSON Hurl file.
+use std::str::FromStr;
+
+use hurl_core::ast::{
+    AuthTypeOption, BooleanOption, ClientCertTypeOption, CountOption, Duration, DurationOption,
+    EntryOption, JsonValue, NaturalOption, OptionKind, Placeholder, Template, TemplateElement,
+    VariableDefinition, VariableValue, VerbosityOption,
+};
+use hurl_core::types::{Count, DurationUnit, ToSource};
+
+use super::{
+    as_string, as_u64, empty_whitespace, get, get_string, line_terminator, one_whitespace,
+    parse_number, source_info, template,
+};
+
+/// Deserializes an `option`, exported as `{"name": ..., "value": ...}`, with an optional `unit`
+/// attribute for durations.
+pub fn parse_option(option: &JsonValue) -> Result<EntryOption, String> {
+    let name = get_string(option, "name")?.to_string();
+    let Some(value) = get(option, "value") else {
+        return Err(format!("missing value for option {name}"));
+    };
+    let kind = parse_option_kind(&name, value, get(option, "unit"))
+        .map_err(|message| format!("option {name}: {message}"))?;
+    Ok(EntryOption {
+        line_terminators: vec![],
+        space0: empty_whitespace(),
+        space1: empty_whitespace(),
+        space2: one_whitespace(),
+        kind,
+        line_terminator0: line_terminator(),
+    })
+}
+
+fn parse_option_kind(
+    name: &str,
+    value: &JsonValue,
+    unit: Option<&JsonValue>,
+) -> Result<OptionKind, String> {
+    let kind = match name {
+        "auth-type" => OptionKind::AuthType(parse_auth_type(value)?),
+        "aws-sigv4" => OptionKind::AwsSigV4(parse_value(value)?),
+        "cacert" => OptionKind::CaCertificate(parse_filename(value)?),
+        "chunked" => OptionKind::Chunked(parse_boolean(value)?),
+        "cert" => OptionKind::ClientCert(parse_filename(value)?),
+        "client-cert-password" => OptionKind::ClientCertPassword(parse_value(value)?),
+        "client-cert-type" => OptionKind::ClientCertType(parse_client_cert_type(value)?),
+        "key" => OptionKind::ClientKey(parse_filename(value)?),
+        "clock-skew" => OptionKind::ClockSkew(parse_duration(value, unit)?),
+        "compressed" => OptionKind::Compressed(parse_boolean(value)?),
+        "connect-to" => OptionKind::ConnectTo(parse_value(value)?),
+        "connect-timeout" => OptionKind::ConnectTimeout(parse_duration(value, unit)?),
+        "cookie-file" => OptionKind::CookieFile(parse_filename(value)?),
+        "delay" => OptionKind::Delay(parse_duration(value, unit)?),
+        "digest" => OptionKind::Digest(parse_boolean(value)?),
+        "dns-servers" => OptionKind::DnsServers(parse_value(value)?),
+        "expect-continue" => OptionKind::ExpectContinue(parse_boolean(value)?),
+        "export-capture" => OptionKind::ExportCapture(as_string(value)?.to_string()),
+        "fail-with-body" => OptionKind::FailWithBody(parse_boolean(value)?),
+        "location" => OptionKind::FollowLocation(parse_boolean(value)?),
+        "location-trusted" => OptionKind::FollowLocationTrusted(parse_boolean(value)?),
+        "foreach" => match parse_placeholder(value) {
+            Some(placeholder) => OptionKind::Foreach(placeholder),
+            None => return Err("expecting a placeholder".to_string()),
+        },
+        "header" => OptionKind::Header(parse_value(value)?),
+        "hmac-signature" => OptionKind::HmacSignature(parse_value(value)?),
+        "http1.0" => OptionKind::Http10(parse_boolean(value)?),
+        "http1.1" => OptionKind::Http11(parse_boolean(value)?),
+        "http2" => OptionKind::Http2(parse_boolean(value)?),
+        "http3" => OptionKind::Http3(parse_boolean(value)?),
+        "http3-fallback" => OptionKind::Http3Fallback(parse_boolean(value)?),
+        "insecure" => OptionKind::Insecure(parse_boolean(value)?),
+        "ipv4" => OptionKind::IpV4(parse_boolean(value)?),
+        "ipv6" => OptionKind::IpV6(parse_boolean(value)?),
+        "limit-rate" => OptionKind::LimitRate(parse_natural(value)?),
+        "max-redirs" => OptionKind::MaxRedirect(parse_count(value)?),
+        "max-time" => OptionKind::MaxTime(parse_duration(value, unit)?),
+        "multipart-boundary" => OptionKind::MultipartBoundary(parse_value(value)?),
+        "negotiate" => OptionKind::Negotiate(parse_boolean(value)?),
+        "netrc" => OptionKind::NetRc(parse_boolean(value)?),
+        "netrc-file" => OptionKind::NetRcFile(parse_filename(value)?),
+        "netrc-optional" => OptionKind::NetRcOptional(parse_boolean(value)?),
+        "no-header" => OptionKind::NoHeader(parse_value(value)?),
+        "ntlm" => OptionKind::Ntlm(parse_boolean(value)?),
+        "oauth2-client-id" => OptionKind::OAuth2ClientId(parse_value(value)?),
+        "oauth2-client-secret" => OptionKind::OAuth2ClientSecret(parse_value(value)?),
+        "oauth2-token-url" => OptionKind::OAuth2TokenUrl(parse_value(value)?),
+        "only-if" => OptionKind::OnlyIf(parse_boolean(value)?),
+        "output" => OptionKind::Output(parse_filename(value)?),
+        "paginate" => OptionKind::Paginate(as_string(value)?.to_string()),
+        "paginate-max" => OptionKind::PaginateMax(parse_count(value)?),
+        "path-as-is" => OptionKind::PathAsIs(parse_boolean(value)?),
+        "pinnedpubkey" => OptionKind::PinnedPublicKey(parse_value(value)?),
+        "proxy" => OptionKind::Proxy(parse_value(value)?),
+        "proxy-header" => OptionKind::ProxyHeader(parse_value(value)?),
+        "proxy-user" => OptionKind::ProxyUser(parse_value(value)?),
+        "repeat" => OptionKind::Repeat(parse_count(value)?),
+        "resolve" => OptionKind::Resolve(parse_value(value)?),
+        "retry" => OptionKind::Retry(parse_count(value)?),
+        "retry-interval" => OptionKind::RetryInterval(parse_duration(value, unit)?),
+        "retry-on-capture-failure" => OptionKind::RetryOnCaptureFailure(parse_boolean(value)?),
+        "skip" => OptionKind::Skip(parse_boolean(value)?),
+        "skip-if" => OptionKind::SkipIf(parse_boolean(value)?),
+        "soft-asserts" => OptionKind::SoftAsserts(parse_boolean(value)?),
+        "nested-form-params" => OptionKind::NestedFormParams(parse_boolean(value)?),
+        "trailer" => OptionKind::Trailer(parse_value(value)?),
+        "unix-socket" => OptionKind::UnixSocket(parse_value(value)?),
+        "user" => OptionKind::User(parse_value(value)?),
+        "variable" => OptionKind::Variable(parse_variable(as_string(value)?)?),
+        "verbose" => OptionKind::Verbose(parse_boolean(value)?),
+        "verbosity" => OptionKind::Verbosity(parse_verbosity(value)?),
+        "very-verbose" => OptionKind::VeryVerbose(parse_boolean(value)?),
+        _ => return Err("unknown option".to_string()),
+    };
+    Ok(kind)
+}
+
+fn parse_value(value: &JsonValue) -> Result<Template, String> {
+    Ok(template::value(as_string(value)?))
+}
+
+fn parse_filename(value: &JsonValue) -> Result<Template, String> {
+    Ok(template::filename(as_string(value)?))
+}
+
+/// Returns the placeholder of a JSON string made of a unique placeholder `"{{...}}"`.
+fn parse_placeholder(value: &JsonValue) -> Option<Placeholder> {
+    match value {
+        JsonValue::String(value) => template::placeholder(value).cloned(),
+        _ => None,
+    }
+}
+
+fn parse_boolean(value: &JsonValue) -> Result<BooleanOption, String> {
+    if let JsonValue::Boolean(value) = value {
+        return Ok(BooleanOption::Literal(*value));
+    }
+    match parse_placeholder(value) {
+        Some(placeholder) => Ok(BooleanOption::Placeholder(placeholder)),
+        None => Err(format!("expecting a boolean, got {}", value.to_source())),
+    }
+}
+
+fn parse_natural(value: &JsonValue) -> Result<NaturalOption, String> {
+    match parse_placeholder(value) {
+        Some(placeholder) => Ok(NaturalOption::Placeholder(placeholder)),
+        None => Ok(NaturalOption::Literal(as_u64(value)?)),
+    }
+}
+
+fn parse_count(value: &JsonValue) -> Result<CountOption, String> {
+    if let Some(placeholder) = parse_placeholder(value) {
+        return Ok(CountOption::Placeholder(placeholder));
+    }
+    // Infinite counts are exported as -1.
+    if let JsonValue::Number(n) = value
+        && n == "-1"
+    {
+        return Ok(CountOption::Literal(Count::Infinite));
+    }
+    let count = as_u64(value)?.as_u64() as usize;
+    Ok(CountOption::Literal(Count::Finite(count)))
+}
+
+fn parse_duration(value: &JsonValue, unit: Option<&JsonValue>) -> Result<DurationOption, String> {
+    if let Some(placeholder) = parse_placeholder(value) {
+        return Ok(DurationOption::Placeholder(placeholder));
+    }
+    let unit = match unit {
+        Some(unit) => Some(DurationUnit::from_str(&as_string(unit)?.to_string())?),
+        None => None,
+    };
+    Ok(DurationOption::Literal(Duration::new(as_u64(value)?, unit)))
+}
+
+fn parse_auth_type(value: &JsonValue) -> Result<AuthTypeOption, String> {
+    match as_string(value)?.to_string().as_str() {
+        "digest" => Ok(AuthTypeOption::Digest),
+        "ntlm" => Ok(AuthTypeOption::Ntlm),
+        "negotiate" => Ok(AuthTypeOption::Negotiate),
+        value => Err(format!("unknown authentication type {value}")),
+    }
+}
+
+fn parse_client_cert_type(value: &JsonValue) -> Result<ClientCertTypeOption, String> {
+    match as_string(value)?.to_string().as_str() {
+        "PEM" => Ok(ClientCertTypeOption::Pem),
+        "DER" => Ok(ClientCertTypeOption::Der),
+        "P12" => Ok(ClientCertTypeOption::P12),
+        value => Err(format!("unknown certificate type {value}")),
+    }
+}
+
+fn parse_verbosity(value: &JsonValue) -> Result<VerbosityOption, String> {
+    match as_string(value)?.to_string().as_str() {
+        "brief" => Ok(VerbosityOption::Brief),
+        "verbose" => Ok(VerbosityOption::Verbose),
+        "debug" => Ok(VerbosityOption::Debug),
+        value => Err(format!("unknown verbosity {value}")),
+    }
+}
+
+/// Deserializes a variable definition, exported as `name=value` with the value in Hurl source
+/// format.
+fn parse_variable(definition: &Template) -> Result<VariableDefinition, String> {
+    let Some(TemplateElement::String { value: first, .. }) = definition.elements.first() else {
+        return Err(format!("invalid variable {definition}"));
+    };
+    let Some((name, first)) = first.split_once('=') else {
+        return Err(format!("invalid variable {definition}"));
+    };
+    let mut elements = vec![];
+    if !first.is_empty() {
+        elements.push(TemplateElement::String {
+            value: first.to_string(),
+            source: first.to_source(),
+        });
+    }
+    elements.extend(
+        definition.elements[1..]
+            .iter()
+            .map(|element| match element {
+                TemplateElement::String { value, .. } => TemplateElement::String {
+                    value: value.clone(),
+                    source: value.to_source(),
+                },
+                TemplateElement::Placeholder(_) => element.clone(),
+            }),
+    );
+    let value = match elements.as_slice() {
+        [TemplateElement::String { value, .. }] => match value.as_str() {
+            "null" => VariableValue::Null,
+            "true" => VariableValue::Bool(true),
+            "false" => VariableValue::Bool(false),
+            value => match parse_number(value) {
+                Some(number) => VariableValue::Number(number),
+                None => VariableValue::String(Template::new(None, elements, source_info())),
+            },
+        },
+        _ => VariableValue::String(Template::new(None, elements, source_info())),
+    };
+    Ok(VariableDefinition {
+        source_info: source_info(),
+        name: name.to_string(),
+        space0: empty_whitespace(),
+        space1: empty_whitespace(),
+        value,
+    })
+}
@@ -0,0 +1,558 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Deserialization of the captures and asserts of a JSON Hurl file: queries, filters and
+//! predicates.
+use base64::Engine;
+use base64::engine::general_purpose;
+use hurl_core::ast::{
+    Assert, Base64, Capture, CaptureFallback, CaptureType, CaptureTypeAnnotation,
+    CertificateAttributeName, CookieAttribute, CookieAttributeName, CookiePath, File, Filter,
+    FilterValue, HexCase, Ignoring, IntegerValue, JsonValue, Predicate, PredicateFunc,
+    PredicateFuncValue, PredicateValue, Query, QueryValue, Regex, RegexValue, Template,
+    TemplateElement, TimingsAttributeName, Whitespace,
+};
+use hurl_core::types::ToSource;
+
+use super::{
+    as_i64, as_string, as_u64, empty_whitespace, get, get_list, get_string, line_terminator,
+    one_whitespace, parse_number, source_info, template,
+};
+
+pub fn parse_capture(capture: &JsonValue) -> Result<Capture, String> {
+    let name = template::key(get_string(capture, "name")?);
+    let query = parse_query(capture)?;
+    let filters = parse_filters(capture)?;
+    let type_annotation = match get(capture, "type") {
+        Some(value) => {
+            let value = match as_string(value)?.to_string().as_str() {
+                "boolean" => CaptureType::Boolean,
+                "float" => CaptureType::Float,
+                "integer" => CaptureType::Integer,
+                "string" => CaptureType::String,
+                value => return Err(format!("unknown capture type {value}")),
+            };
+            let annotation = CaptureTypeAnnotation {
+                space0: one_whitespace(),
+                value,
+                source_info: source_info(),
+            };
+            Some((one_whitespace(), annotation))
+        }
+        None => None,
+    };
+    let fallback = if let Some(JsonValue::Boolean(true)) = get(capture, "optional") {
+        Some((one_whitespace(), CaptureFallback::Optional))
+    } else if let Some(value) = get(capture, "default") {
+        let fallback = CaptureFallback::Default {
+            space0: one_whitespace(),
+            value: parse_predicate_value(value, None)?,
+        };
+        Some((one_whitespace(), fallback))
+    } else {
+        None
+    };
+    let redacted = matches!(get(capture, "redact"), Some(JsonValue::Boolean(true)));
+    Ok(Capture {
+        line_terminators: vec![],
+        space0: empty_whitespace(),
+        name,
+        space1: empty_whitespace(),
+        space2: one_whitespace(),
+        query,
+        filters,
+        type_annotation,
+        fallback,
+        space3: empty_whitespace(),
+        redacted,
+        line_terminator0: line_terminator(),
+    })
+}
+
+pub fn parse_assert(assert: &JsonValue) -> Result<Assert, String> {
+    let query = parse_query(assert)?;
+    let filters = parse_filters(assert)?;
+    let Some(predicate) = get(assert, "predicate") else {
+        return Err("missing predicate".to_string());
+    };
+    Ok(Assert {
+        line_terminators: vec![],
+        space0: empty_whitespace(),
+        query,
+        filters,
+        space1: one_whitespace(),
+        predicate: parse_predicate(predicate)?,
+        line_terminator0: line_terminator(),
+    })
+}
+
+fn parse_query(value: &JsonValue) -> Result<Query, String> {
+    let Some(query) = get(value, "query") else {
+        return Err("missing query".to_string());
+    };
+    let value = match get_string(query, "type")?.to_string().as_str() {
+        "status" => QueryValue::Status,
+        "version" => QueryValue::Version,
+        "versionNegotiated" => QueryValue::VersionNegotiated,
+        "url" => QueryValue::Url,
+        "header" => QueryValue::Header {
+            space0: one_whitespace(),
+            name: template::quoted(get_string(query, "name")?),
+        },
+        "cookie" => QueryValue::Cookie {
+            space0: one_whitespace(),
+            expr: parse_cookie_path(get_string(query, "expr")?)?,
+        },
+        "body" => {
+            let charset = match get(query, "charset") {
+                Some(charset) => Some((one_whitespace(), template::quoted(as_string(charset)?))),
+                None => None,
+            };
+            QueryValue::Body { charset }
+        }
+        "xpath" => QueryValue::Xpath {
+            space0: one_whitespace(),
+            expr: template::quoted(get_string(query, "expr")?),
+        },
+        "jsonpath" => QueryValue::Jsonpath {
+            space0: one_whitespace(),
+            expr: template::quoted(get_string(query, "expr")?),
+        },
+        "csv" => QueryValue::Csv {
+            space0: one_whitespace(),
+            expr: template::quoted(get_string(query, "expr")?),
+        },
+        "regex" => QueryValue::Regex {
+            space0: one_whitespace(),
+            value: parse_regex_value(get_argument(query, "expr")?)?,
+        },
+        "variable" => QueryValue::Variable {
+            space0: one_whitespace(),
+            name: template::quoted(get_string(query, "name")?),
+        },
+        "duration" => QueryValue::Duration,
+        "durationMean" => QueryValue::DurationMean,
+        "durationPercentile" => QueryValue::DurationPercentile {
+            space0: one_whitespace(),
+            percentile: as_u64(get_argument(query, "percentile")?)?,
+        },
+        "bytes" => QueryValue::Bytes,
+        "rawbytes" => QueryValue::RawBytes,
+        "sha1" => QueryValue::Sha1,
+        "sha256" => QueryValue::Sha256,
+        "sha512" => QueryValue::Sha512,
+        "md5" => QueryValue::Md5,
+        "crc32" => QueryValue::Crc32,
+        "certificate" => QueryValue::Certificate {
+            space0: one_whitespace(),
+            attribute_name: parse_certificate_attribute(get_string(query, "expr")?)?,
+        },
+        "ip" => QueryValue::Ip,
+        "proxyStatus" => QueryValue::ProxyStatus,
+        "hstsUpgraded" => QueryValue::HstsUpgraded,
+        "continueReceived" => QueryValue::ContinueReceived,
+        "earlyHints" => QueryValue::EarlyHints,
+        "redirects" => QueryValue::Redirects,
+        "timings" => QueryValue::Timings {
+            space0: one_whitespace(),
+            attribute_name: parse_timings_attribute(get_string(query, "expr")?)?,
+        },
+        query => return Err(format!("unknown query {query}")),
+    };
+    Ok(Query {
+        source_info: source_info(),
+        value,
+    })
+}
+
+/// Deserializes a cookie path, exported as `NAME[ATTRIBUTE]`.
+fn parse_cookie_path(expr: &Template) -> Result<CookiePath, String> {
+    let mut name = expr.clone();
+    let mut attribute = None;
+    if let Some(TemplateElement::String { value, .. }) = name.elements.last_mut()
+        && value.ends_with(']')
+        && let Some((prefix, attribute_name)) = value[..value.len() - 1].rsplit_once('[')
+    {
+        let attribute_name = attribute_name.to_string();
+        let attribute_name = match attribute_name.to_lowercase().as_str() {
+            "value" => CookieAttributeName::Value(attribute_name),
+            "expires" => CookieAttributeName::Expires(attribute_name),
+            "max-age" => CookieAttributeName::MaxAge(attribute_name),
+            "domain" => CookieAttributeName::Domain(attribute_name),
+            "path" => CookieAttributeName::Path(attribute_name),
+            "secure" => CookieAttributeName::Secure(attribute_name),
+            "httponly" => CookieAttributeName::HttpOnly(attribute_name),
+            "samesite" => CookieAttributeName::SameSite(attribute_name),
+            _ => return Err(format!("unknown cookie attribute {attribute_name}")),
+        };
+        attribute = Some(CookieAttribute {
+            space0: empty_whitespace(),
+            name: attribute_name,
+            space1: empty_whitespace(),
+        });
+        *value = prefix.to_string();
+    }
+    name.elements.retain(|e| match e {
+        TemplateElement::String { value, .. } => !value.is_empty(),
+        TemplateElement::Placeholder(_) => true,
+    });
+    // The cookie path is quoted as a whole, its name is escaped as a quoted string without its
+    // own delimiters.
+    let mut name = template::quoted(&name);
+    name.delimiter = None;
+    Ok(CookiePath { name, attribute })
+}
+
+fn parse_certificate_attribute(expr: &Template) -> Result<CertificateAttributeName, String> {
+    let attributes = [
+        CertificateAttributeName::Subject,
+        CertificateAttributeName::Issuer,
+        CertificateAttributeName::StartDate,
+        CertificateAttributeName::ExpireDate,
+        CertificateAttributeName::SerialNumber,
+        CertificateAttributeName::SubjectAltName,
+        CertificateAttributeName::Value,
+    ];
+    let expr = expr.to_string();
+    attributes
+        .into_iter()
+        .find(|a| a.identifier() == expr)
+        .ok_or_else(|| format!("unknown certificate attribute {expr}"))
+}
+
+fn parse_timings_attribute(expr: &Template) -> Result<TimingsAttributeName, String> {
+    let attributes = [
+        TimingsAttributeName::NameLookup,
+        TimingsAttributeName::Connect,
+        TimingsAttributeName::AppConnect,
+        TimingsAttributeName::PreTransfer,
+        TimingsAttributeName::StartTransfer,
+        TimingsAttributeName::Total,
+    ];
+    let expr = expr.to_string();
+    attributes
+        .into_iter()
+        .find(|a| a.identifier() == expr)
+        .ok_or_else(|| format!("unknown timings attribute {expr}"))
+}
+
+fn parse_filters(value: &JsonValue) -> Result<Vec<(Whitespace, Filter)>, String> {
+    get_list(value, "filters")?
+        .into_iter()
+        .map(|filter| {
+            let filter = Filter {
+                source_info: source_info(),
+                value: parse_filter_value(filter)?,
+            };
+            Ok((one_whitespace(), filter))
+        })
+        .collect()
+}
+
+fn parse_filter_value(filter: &JsonValue) -> Result<FilterValue, String> {
+    let value = match get_string(filter, "type")?.to_string().as_str() {
+        "addDays" => FilterValue::AddDays {
+            space0: one_whitespace(),
+            days: get_integer(filter, "days")?,
+        },
+        "addSeconds" => FilterValue::AddSeconds {
+            space0: one_whitespace(),
+            seconds: get_integer(filter, "seconds")?,
+        },
+        "base64Decode" => FilterValue::Base64Decode,
+        "base64Encode" => FilterValue::Base64Encode,
+        "base64UrlSafeDecode" => FilterValue::Base64UrlSafeDecode,
+        "base64UrlSafeEncode" => FilterValue::Base64UrlSafeEncode,
+        "brotliDecompress" => FilterValue::BrotliDecompress,
+        "bytesSlice" => FilterValue::BytesSlice {
+            space0: one_whitespace(),
+            start: get_integer(filter, "start")?,
+            space1: one_whitespace(),
+            end: get_integer(filter, "end")?,
+        },
+        "canonicalize" => FilterValue::Canonicalize,
+        "charsetDecode" => FilterValue::CharsetDecode {
+            space0: one_whitespace(),
+            encoding: get_quoted(filter, "encoding")?,
+        },
+        "count" => FilterValue::Count,
+        "daysAfterNow" => FilterValue::DaysAfterNow,
+        "daysBeforeNow" => FilterValue::DaysBeforeNow,
+        "decode" => FilterValue::Decode {
+            space0: one_whitespace(),
+            encoding: get_quoted(filter, "encoding")?,
+        },
+        "first" => FilterValue::First,
+        "format" => FilterValue::Format {
+            space0: one_whitespace(),
+            fmt: get_quoted(filter, "fmt")?,
+        },
+        "dateFormat" => FilterValue::DateFormat {
+            space0: one_whitespace(),
+            fmt: get_quoted(filter, "fmt")?,
+        },
+        "fromHex" => FilterValue::FromHex,
+        "gunzip" => FilterValue::Gunzip,
+        "htmlEscape" => FilterValue::HtmlEscape,
+        "htmlUnescape" => FilterValue::HtmlUnescape,
+        "jq" => FilterValue::Jq {
+            space0: one_whitespace(),
+            expr: get_quoted(filter, "expr")?,
+        },
+        "jsonpath" => FilterValue::JsonPath {
+            space0: one_whitespace(),
+            expr: get_quoted(filter, "expr")?,
+        },
+        "jwtHeader" => FilterValue::JwtHeader,
+        "jwtPayload" => FilterValue::JwtPayload,
+        "last" => FilterValue::Last,
+        "location" => FilterValue::Location,
+        "nth" => FilterValue::Nth {
+            space0: one_whitespace(),
+            n: get_integer(filter, "n")?,
+        },
+        "parseJson" => FilterValue::ParseJson,
+        "regex" => FilterValue::Regex {
+            space0: one_whitespace(),
+            value: parse_regex_value(get_argument(filter, "expr")?)?,
+        },
+        "replace" => FilterValue::Replace {
+            space0: one_whitespace(),
+            old_value: get_quoted(filter, "old_value")?,
+            space1: one_whitespace(),
+            new_value: get_quoted(filter, "new_value")?,
+        },
+        "replaceRegex" => FilterValue::ReplaceRegex {
+            space0: one_whitespace(),
+            pattern: parse_regex_value(get_argument(filter, "pattern")?)?,
+            space1: one_whitespace(),
+            new_value: get_quoted(filter, "new_value")?,
+        },
+        "split" => FilterValue::Split {
+            space0: one_whitespace(),
+            sep: get_quoted(filter, "sep")?,
+        },
+        "toDate" => FilterValue::ToDate {
+            space0: one_whitespace(),
+            fmt: get_quoted(filter, "fmt")?,
+        },
+        "toFloat" => FilterValue::ToFloat,
+        "toHex" => {
+            let case = match get(filter, "case") {
+                Some(case) => {
+                    let case = match as_string(case)?.to_string().as_str() {
+                        "lower" => HexCase::Lower,
+                        "upper" => HexCase::Upper,
+                        case => return Err(format!("unknown hexadecimal case {case}")),
+                    };
+                    Some((one_whitespace(), case))
+                }
+                None => None,
+            };
+            let separator = match get(filter, "separator") {
+                Some(separator) => {
+                    Some((one_whitespace(), template::quoted(as_string(separator)?)))
+                }
+                None => None,
+            };
+            FilterValue::ToHex { case, separator }
+        }
+        "toInt" => FilterValue::ToInt,
+        "toJson" => FilterValue::ToJson,
+        "toString" => FilterValue::ToString,
+        "toTimestamp" => FilterValue::ToTimestamp,
+        "urlDecode" => FilterValue::UrlDecode,
+        "urlEncode" => FilterValue::UrlEncode,
+        "urlQueryParam" => FilterValue::UrlQueryParam {
+            space0: one_whitespace(),
+            param: get_quoted(filter, "param")?,
+        },
+        "utf8Decode" => FilterValue::Utf8Decode,
+        "utf8Encode" => FilterValue::Utf8Encode,
+        "xmlEscape" => FilterValue::XmlEscape,
+        "xmlUnescape" => FilterValue::XmlUnescape,
+        "xpath" => FilterValue::XPath {
+            space0: one_whitespace(),
+            expr: get_quoted(filter, "expr")?,
+        },
+        "zstdDecompress" => FilterValue::ZstdDecompress,
+        filter => return Err(format!("unknown filter {filter}")),
+    };
+    Ok(value)
+}
+
+fn parse_predicate(predicate: &JsonValue) -> Result<Predicate, String> {
+    let not = matches!(get(predicate, "not"), Some(JsonValue::Boolean(true)));
+    let space0 = if not {
+        one_whitespace()
+    } else {
+        empty_whitespace()
+    };
+    let identifier = get_string(predicate, "type")?.to_string();
+    let value = match get(predicate, "value") {
+        Some(value) => {
+            let encoding = match get(predicate, "encoding") {
+                Some(encoding) => Some(as_string(encoding)?.to_string()),
+                None => None,
+            };
+            Some(parse_predicate_value(value, encoding.as_deref())?)
+        }
+        None => None,
+    };
+    let value = match (identifier.as_str(), value) {
+        ("exists", None) => PredicateFuncValue::Exist,
+        ("isBoolean", None) => PredicateFuncValue::IsBoolean,
+        ("isCollection", None) => PredicateFuncValue::IsCollection,
+        ("isDate", None) => PredicateFuncValue::IsDate,
+        ("isEmpty", None) => PredicateFuncValue::IsEmpty,
+        ("isFloat", None) => PredicateFuncValue::IsFloat,
+        ("isInteger", None) => PredicateFuncValue::IsInteger,
+        ("isIpv4", None) => PredicateFuncValue::IsIpv4,
+        ("isIpv6", None) => PredicateFuncValue::IsIpv6,
+        ("isIsoDate", None) => PredicateFuncValue::IsIsoDate,
+        ("isList", None) => PredicateFuncValue::IsList,
+        ("isNumber", None) => PredicateFuncValue::IsNumber,
+        ("isObject", None) => PredicateFuncValue::IsObject,
+        ("isString", None) => PredicateFuncValue::IsString,
+        ("isUuid", None) => PredicateFuncValue::IsUuid,
+        (identifier, None) => return Err(format!("missing value for predicate {identifier}")),
+        ("==", Some(value)) => {
+            let paths = get_list(predicate, "ignoring")?
+                .into_iter()
+                .map(|path| Ok((one_whitespace(), template::quoted(as_string(path)?))))
+                .collect::<Result<Vec<_>, String>>()?;
+            let ignoring = if paths.is_empty() {
+                None
+            } else {
+                Some(Ignoring {
+                    space0: one_whitespace(),
+                    paths,
+                })
+            };
+            PredicateFuncValue::Equal {
+                space0: one_whitespace(),
+                value,
+                ignoring,
+            }
+        }
+        (identifier, Some(value)) => {
+            let space0 = one_whitespace();
+            match identifier {
+                "!=" => PredicateFuncValue::NotEqual { space0, value },
+                "constantTimeEquals" => PredicateFuncValue::ConstantTimeEqual { space0, value },
+                ">" => PredicateFuncValue::GreaterThan { space0, value },
+                ">=" => PredicateFuncValue::GreaterThanOrEqual { space0, value },
+                "<" => PredicateFuncValue::LessThan { space0, value },
+                "<=" => PredicateFuncValue::LessThanOrEqual { space0, value },
+                "startsWith" => PredicateFuncValue::StartWith { space0, value },
+                "endsWith" => PredicateFuncValue::EndWith { space0, value },
+                "contains" => PredicateFuncValue::Contain { space0, value },
+                "includes" => PredicateFuncValue::Include { space0, value },
+                "includesJson" => PredicateFuncValue::IncludeJson { space0, value },
+                "matches" => PredicateFuncValue::Match { space0, value },
+                identifier => return Err(format!("unknown predicate {identifier}")),
+            }
+        }
+    };
+    Ok(Predicate {
+        not,
+        space0,
+        predicate_func: PredicateFunc {
+            source_info: source_info(),
+            value,
+        },
+    })
+}
+
+/// Deserializes a predicate value, exported with an optional `encoding` (`base64` for bytes,
+/// `regex` for regular expressions).
+fn parse_predicate_value(
+    value: &JsonValue,
+    encoding: Option<&str>,
+) -> Result<PredicateValue, String> {
+    match (encoding, value) {
+        (Some("base64"), _) => {
+            let source = as_string(value)?.to_string();
+            let bytes = general_purpose::STANDARD
+                .decode(&source)
+                .map_err(|_| format!("invalid base64 value {source}"))?;
+            Ok(PredicateValue::Base64(Base64 {
+                space0: empty_whitespace(),
+                value: bytes,
+                source: source.to_source(),
+                space1: empty_whitespace(),
+            }))
+        }
+        (Some("regex"), _) => Ok(PredicateValue::Regex(parse_regex(as_string(value)?)?)),
+        (Some(encoding), _) => Err(format!("unknown predicate encoding {encoding}")),
+        (None, JsonValue::String(value)) => match template::placeholder(value) {
+            Some(placeholder) => Ok(PredicateValue::Placeholder(placeholder.clone())),
+            None => Ok(PredicateValue::String(template::quoted(value))),
+        },
+        (None, JsonValue::Object { .. }) => Ok(PredicateValue::File(File {
+            space0: empty_whitespace(),
+            filename: template::filename(get_string(value, "filename")?),
+            space1: empty_whitespace(),
+        })),
+        (None, JsonValue::Boolean(value)) => Ok(PredicateValue::Bool(*value)),
+        (None, JsonValue::Null) => Ok(PredicateValue::Null),
+        (None, JsonValue::Number(n)) => match parse_number(n) {
+            Some(number) => Ok(PredicateValue::Number(number)),
+            None => Err(format!("invalid number {n}")),
+        },
+        (None, value) => Err(format!("invalid predicate value {}", value.to_source())),
+    }
+}
+
+/// Deserializes a regex argument, exported as a string or as a `{"type": "regex", "value": ...}`
+/// object for regex literals.
+fn parse_regex_value(value: &JsonValue) -> Result<RegexValue, String> {
+    match value {
+        JsonValue::String(value) => Ok(RegexValue::Template(template::quoted(value))),
+        _ => Ok(RegexValue::Regex(parse_regex(get_string(value, "value")?)?)),
+    }
+}
+
+fn parse_regex(value: &Template) -> Result<Regex, String> {
+    let value = value.to_string();
+    let inner = regex::Regex::new(&value).map_err(|_| format!("invalid regex {value}"))?;
+    Ok(Regex {
+        inner,
+        source: format!("/{}/", value.replace('/', "\\/")).to_source(),
+    })
+}
+
+/// Returns the mandatory argument `name` of the query or filter `value`.
+fn get_argument<'a>(value: &'a JsonValue, name: &str) -> Result<&'a JsonValue, String> {
+    get(value, name).ok_or_else(|| format!("missing attribute {name}"))
+}
+
+/// Returns the string argument `name` of the query or filter `value` as a quoted template.
+fn get_quoted(value: &JsonValue, name: &str) -> Result<Template, String> {
+    Ok(template::quoted(get_string(value, name)?))
+}
+
+/// Returns the integer argument `name` of the filter `value`, a number or a placeholder.
+fn get_integer(value: &JsonValue, name: &str) -> Result<IntegerValue, String> {
+    let value = get_argument(value, name)?;
+    if let JsonValue::String(template) = value
+        && let Some(placeholder) = template::placeholder(template)
+    {
+        return Ok(IntegerValue::Placeholder(placeholder.clone()));
+    }
+    Ok(IntegerValue::Literal(as_i64(value)?))
+}
@@ -0,0 +1,209 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Conversion of JSON strings to the different Hurl template flavors.
+//!
+//! Placeholders `{{...}}` are kept verbatim, only the literal parts of a string are escaped.
+use hurl_core::ast::{Placeholder, SourceInfo, Template, TemplateElement};
+use hurl_core::reader::Pos;
+use hurl_core::types::ToSource;
+
+/// Returns the JSON string `value` as a Hurl key string (header names, param names, capture
+/// names etc...).
+pub fn key(value: &Template) -> Template {
+    escape_template(value, None, |c| match c {
+        c if c.is_alphanumeric() || ['_', '-', '.', '[', ']', '@', '$'].contains(&c) => {
+            c.to_string()
+        }
+        '#' | ':' | '\\' | '/' => format!("\\{c}"),
+        c => escape_control(c).unwrap_or_else(|| unicode(c)),
+    })
+}
+
+/// Returns the JSON string `value` as a Hurl value string (URL, header values, param values
+/// etc...).
+pub fn value(value: &Template) -> Template {
+    escape_template(value, None, |c| match c {
+        '#' | '\\' => format!("\\{c}"),
+        c => escape_control(c).unwrap_or_else(|| c.to_string()),
+    })
+}
+
+/// Returns the JSON string `value` as a Hurl filename.
+pub fn filename(value: &Template) -> Template {
+    escape_template(value, None, |c| match c {
+        '#' | ';' | ' ' | '{' | '}' | '\\' => format!("\\{c}"),
+        c => escape_control(c).unwrap_or_else(|| c.to_string()),
+    })
+}
+
+/// Returns the JSON string `value` as a Hurl quoted string.
+pub fn quoted(value: &Template) -> Template {
+    escape_template(value, Some('"'), |c| match c {
+        '"' | '\\' => format!("\\{c}"),
+        c => escape_control(c).unwrap_or_else(|| c.to_string()),
+    })
+}
+
+/// Returns the JSON string `value` as a Hurl oneline string.
+pub fn oneline(value: &Template) -> Template {
+    escape_template(value, Some('`'), |c| match c {
+        '`' | '#' | '\\' => format!("\\{c}"),
+        c => escape_control(c).unwrap_or_else(|| c.to_string()),
+    })
+}
+
+/// Returns the JSON string `value` as the content of a Hurl multiline string.
+///
+/// Multiline strings content is not escaped, but always ends with a newline.
+pub fn multiline(value: &Template) -> Template {
+    let mut template = escape_template(value, None, |c| c.to_string());
+    if !value.to_string().ends_with('\n') {
+        template.elements.push(TemplateElement::String {
+            value: "\n".to_string(),
+            source: "\n".to_source(),
+        });
+    }
+    template
+}
+
+/// Returns the placeholder of the JSON string `value` if this string consists of a unique
+/// placeholder.
+pub fn placeholder(value: &Template) -> Option<&Placeholder> {
+    match value.elements.as_slice() {
+        [TemplateElement::Placeholder(placeholder)] => Some(placeholder),
+        _ => None,
+    }
+}
+
+/// Returns the JSON string `value` as a Hurl template with a `delimiter`, the literal parts of
+/// `value` being escaped with `escape`.
+fn escape_template(
+    value: &Template,
+    delimiter: Option<char>,
+    escape: impl Fn(char) -> String,
+) -> Template {
+    let elements = value
+        .elements
+        .iter()
+        .map(|element| match element {
+            TemplateElement::String { value, .. } => TemplateElement::String {
+                value: value.clone(),
+                source: value.chars().map(&escape).collect::<String>().to_source(),
+            },
+            TemplateElement::Placeholder(placeholder) => {
+                TemplateElement::Placeholder(placeholder.clone())
+            }
+        })
+        .collect();
+    let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+    Template::new(delimiter, elements, source_info)
+}
+
+fn escape_control(c: char) -> Option<String> {
+    let s = match c {
+        '\x08' => "\\b",
+        '\x0c' => "\\f",
+        '\n' => "\\n",
+        '\r' => "\\r",
+        '\t' => "\\t",
+        c if c.is_control() => return Some(unicode(c)),
+        _ => return None,
+    };
+    Some(s.to_string())
+}
+
+fn unicode(c: char) -> String {
+    format!("\\u{{{:x}}}", c as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::JsonValue;
+
+    use super::*;
+    use crate::json::document;
+
+    /// Returns the template of the JSON string `s`.
+    fn json_string(s: &str) -> Template {
+        match document::parse(s).unwrap() {
+            JsonValue::String(template) => template,
+            value => panic!("expecting a JSON string, got {value:?}"),
+        }
+    }
+
+    #[test]
+    fn test_key() {
+        assert_eq!(
+            key(&json_string(r#""Content-Type""#)).to_source().as_str(),
+            "Content-Type"
+        );
+        assert_eq!(
+            key(&json_string(r#""a:b c""#)).to_source().as_str(),
+            "a\\:b\\u{20}c"
+        );
+        assert_eq!(
+            key(&json_string(r#""x-{{name}}""#)).to_source().as_str(),
+            "x-{{name}}"
+        );
+    }
+
+    #[test]
+    fn test_value() {
+        assert_eq!(
+            value(&json_string(r#""a #1""#)).to_source().as_str(),
+            "a \\#1"
+        );
+        assert_eq!(
+            value(&json_string(r#""{{host}}/a\\b""#))
+                .to_source()
+                .as_str(),
+            "{{host}}/a\\\\b"
+        );
+    }
+
+    #[test]
+    fn test_quoted() {
+        assert_eq!(
+            quoted(&json_string(r#""$.name""#)).to_source().as_str(),
+            "\"$.name\""
+        );
+        assert_eq!(
+            quoted(&json_string(r#""say \"{{hi}}\"\n""#))
+                .to_source()
+                .as_str(),
+            "\"say \\\"{{hi}}\\\"\\n\""
+        );
+    }
+
+    #[test]
+    fn test_multiline() {
+        assert_eq!(
+            multiline(&json_string(r#""line1\nline2""#))
+                .to_source()
+                .as_str(),
+            "line1\nline2\n"
+        );
+    }
+
+    #[test]
+    fn test_placeholder() {
+        assert!(placeholder(&json_string(r#""{{count}}""#)).is_some());
+        assert!(placeholder(&json_string(r#""{{a}}{{b}}""#)).is_none());
+        assert!(placeholder(&json_string(r#""count""#)).is_none());
+    }
+}
@@ -16,7 +16,9 @@
  *
  */
 pub mod cli;
+pub mod codegen;
 pub mod command;
 pub mod curl;
 pub mod format;
+pub mod json;
 pub mod linter;
@@ -0,0 +1,191 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{Section, SectionValue};
+use serde::Deserialize;
+
+/// Default order of the sections of requests and responses.
+const DEFAULT_SECTION_ORDER: [&str; 8] = [
+    "Options",
+    "Query",
+    "BasicAuth",
+    "Form",
+    "Multipart",
+    "Cookies",
+    "Captures",
+    "Asserts",
+];
+
+/// Formatting rules of `hurlfmt`, read from a `.hurlfmt.toml` configuration file:
+///
+/// ```toml
+/// # Indentation of reflowed JSON bodies
+/// indent-width = 4
+/// # JSON bodies with longer lines are reflowed
+/// max-line-length = 80
+/// # Order of the sections, unlisted sections keep their default order
+/// section-order = ["Query", "Options", "Asserts", "Captures"]
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintConfig {
+    /// Number of spaces used to indent each level of a reflowed JSON body.
+    pub indent_width: usize,
+    /// Maximum length of JSON body lines, above which JSON bodies are reflowed. If not set, JSON
+    /// bodies are only reflowed in pretty mode.
+    pub max_line_length: Option<usize>,
+    /// Canonical names of the request and response sections, in their formatted order.
+    section_order: Vec<&'static str>,
+}
+
+/// Content of a `.hurlfmt.toml` configuration file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+struct ConfigFile {
+    indent_width: Option<usize>,
+    max_line_length: Option<usize>,
+    section_order: Vec<String>,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        LintConfig {
+            indent_width: 2,
+            max_line_length: None,
+            section_order: DEFAULT_SECTION_ORDER.to_vec(),
+        }
+    }
+}
+
+impl LintConfig {
+    /// Parses a `.hurlfmt.toml` configuration file `content`.
+    pub fn parse(content: &str) -> Result<LintConfig, String> {
+        let file = toml::from_str::<ConfigFile>(content).map_err(|e| e.to_string())?;
+        let mut config = LintConfig::default();
+        if let Some(indent_width) = file.indent_width {
+            config.indent_width = indent_width;
+        }
+        if let Some(max_line_length) = file.max_line_length {
+            if max_line_length == 0 {
+                return Err("max-line-length must be greater than 0".to_string());
+            }
+            config.max_line_length = Some(max_line_length);
+        }
+        config.section_order = section_order(&file.section_order)?;
+        Ok(config)
+    }
+
+    /// Returns the rank of a `section` in the configured section order.
+    pub(crate) fn section_rank(&self, section: &Section) -> usize {
+        let name = section_name(section.identifier()).unwrap_or_default();
+        self.section_order
+            .iter()
+            .position(|n| *n == name)
+            .unwrap_or(self.section_order.len())
+    }
+}
+
+/// Returns the canonical names of the section `identifiers`, completed by the missing sections in
+/// their default order.
+fn section_order(identifiers: &[String]) -> Result<Vec<&'static str>, String> {
+    let mut order = vec![];
+    for identifier in identifiers {
+        let Some(name) = section_name(identifier) else {
+            return Err(format!("unknown section `{identifier}` in section-order"));
+        };
+        if order.contains(&name) {
+            return Err(format!(
+                "duplicated section `{identifier}` in section-order"
+            ));
+        }
+        order.push(name);
+    }
+    for name in DEFAULT_SECTION_ORDER {
+        if !order.contains(&name) {
+            order.push(name);
+        }
+    }
+    Ok(order)
+}
+
+/// Returns the canonical name of a section `identifier`, long names (like `QueryStringParams`)
+/// being equivalent to their short names (like `Query`).
+fn section_name(identifier: &str) -> Option<&'static str> {
+    let name = match identifier {
+        "QueryStringParams" => "Query",
+        "FormParams" => "Form",
+        "MultipartFormData" => "Multipart",
+        identifier => identifier,
+    };
+    DEFAULT_SECTION_ORDER.into_iter().find(|n| *n == name)
+}
+
+/// Returns `true` if a `section` is formatted, empty `[BasicAuth]` sections being removed.
+pub(crate) fn is_formatted(section: &Section) -> bool {
+    !matches!(section.value, SectionValue::BasicAuth(None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(LintConfig::parse("").unwrap(), LintConfig::default());
+
+        let content = r#"
+# Formatting rules
+indent-width = 4
+max-line-length = 80  # in characters
+section-order = ["QueryStringParams", "Options", "Asserts"]
+"#;
+        assert_eq!(
+            LintConfig::parse(content).unwrap(),
+            LintConfig {
+                indent_width: 4,
+                max_line_length: Some(80),
+                section_order: vec![
+                    "Query",
+                    "Options",
+                    "Asserts",
+                    "BasicAuth",
+                    "Form",
+                    "Multipart",
+                    "Cookies",
+                    "Captures",
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(LintConfig::parse("indent = 2").is_err());
+        assert!(LintConfig::parse("indent-width = -1").is_err());
+        assert_eq!(
+            LintConfig::parse("max-line-length = 0").unwrap_err(),
+            "max-line-length must be greater than 0"
+        );
+        assert_eq!(
+            LintConfig::parse("section-order = [\"Query\", \"Body\"]").unwrap_err(),
+            "unknown section `Body` in section-order"
+        );
+        assert_eq!(
+            LintConfig::parse("section-order = [\"Form\", \"FormParams\"]").unwrap_err(),
+            "duplicated section `FormParams` in section-order"
+        );
+    }
+}
@@ -15,6 +15,12 @@
  * limitations under the License.
  *
  */
+pub use config::LintConfig;
+pub use pretty::pretty_hurl_file;
 pub use rewrite::lint_hurl_file;
+pub(crate) use rewrite::{lint_bytes, lint_request, lint_response};
 
+mod config;
+mod pretty;
+mod reflow;
 mod rewrite;
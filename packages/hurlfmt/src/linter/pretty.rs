@@ -0,0 +1,272 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::collections::HashMap;
+
+use hurl_core::ast::{HurlFile, KeyValue, MultipartParam, OptionKind, Section, SectionValue};
+use hurl_core::parser;
+use hurl_core::reader::Pos;
+
+use super::config::LintConfig;
+use super::reflow::reflow_json_bodies;
+use super::rewrite::lint_file;
+
+/// Default maximum length of a JSON body line in pretty mode, above which the JSON body is
+/// reflowed.
+const MAX_LINE_LENGTH: usize = 100;
+
+/// Pretty-prints a parsed `HurlFile` to a string, given formatting rules `config`.
+///
+/// On top of the lint normalization, the colons of key/value lines are aligned within each block,
+/// the `[Options]` sections are sorted and long JSON bodies are reflowed, one member per line.
+/// Pretty-printing is idempotent: a pretty-printed file is left unchanged.
+pub fn pretty_hurl_file(hurl_file: &HurlFile, config: &LintConfig) -> String {
+    let mut hurl_file = hurl_file.clone();
+    if let Some(defaults) = &mut hurl_file.defaults {
+        defaults.sections.iter_mut().for_each(sort_options);
+    }
+    for entry in &mut hurl_file.entries {
+        entry.request.sections.iter_mut().for_each(sort_options);
+    }
+    let max_line_length = config.max_line_length.unwrap_or(MAX_LINE_LENGTH);
+    reflow_json_bodies(&mut hurl_file, max_line_length, config.indent_width);
+
+    // The linted file is parsed again, so we get the position of the colons in the linted text.
+    let linted = lint_file(&hurl_file, config);
+    let Ok(linted_file) = parser::parse_hurl_file(&linted) else {
+        return linted;
+    };
+    align_colons(&linted, &colon_blocks(&linted_file))
+}
+
+/// Sorts the options of an `[Options]` `section` by name.
+///
+/// Variables are kept first, in their original order, as the other options can use them.
+fn sort_options(section: &mut Section) {
+    if let SectionValue::Options(options) = &mut section.value {
+        options.sort_by_key(|option| match &option.kind {
+            OptionKind::Variable(_) => "",
+            kind => kind.identifier(),
+        });
+    }
+}
+
+/// Returns the positions of the colons of the key/value lines of a `hurl_file`, grouped by block
+/// (headers or section) of consecutive lines.
+fn colon_blocks(hurl_file: &HurlFile) -> Vec<Vec<Pos>> {
+    let mut blocks = vec![];
+    if let Some(defaults) = &hurl_file.defaults {
+        blocks.push(key_values_colons(&defaults.headers));
+        blocks.extend(defaults.sections.iter().flat_map(section_colons));
+    }
+    for entry in &hurl_file.entries {
+        blocks.push(key_values_colons(&entry.request.headers));
+        blocks.extend(entry.request.sections.iter().flat_map(section_colons));
+        if let Some(response) = &entry.response {
+            blocks.push(key_values_colons(&response.headers));
+            blocks.extend(response.sections.iter().flat_map(section_colons));
+        }
+    }
+    blocks
+}
+
+fn key_values_colons(key_values: &[KeyValue]) -> Vec<Pos> {
+    key_values
+        .iter()
+        .map(|kv| kv.space1.source_info.start)
+        .collect()
+}
+
+fn section_colons(section: &Section) -> Vec<Vec<Pos>> {
+    let colons = match &section.value {
+        SectionValue::QueryParams(params, _) | SectionValue::FormParams(params, _) => {
+            key_values_colons(params)
+        }
+        SectionValue::BasicAuth(auth) => key_values_colons(auth.as_slice()),
+        SectionValue::MultipartFormData(params, _) => return multipart_colons(params),
+        SectionValue::Cookies(cookies) => cookies
+            .iter()
+            .map(|cookie| cookie.space1.source_info.start)
+            .collect(),
+        SectionValue::Captures(captures) => captures
+            .iter()
+            .map(|capture| capture.space1.source_info.start)
+            .collect(),
+        SectionValue::Options(options) => options
+            .iter()
+            .map(|option| option.space1.source_info.start)
+            .collect(),
+        SectionValue::Asserts(_) => vec![],
+    };
+    vec![colons]
+}
+
+/// Returns the positions of the colons of multipart `params`, the parts of each nested param
+/// being a block of their own.
+fn multipart_colons(params: &[MultipartParam]) -> Vec<Vec<Pos>> {
+    let mut colons = vec![];
+    let mut blocks = vec![];
+    for param in params {
+        let pos = match param {
+            MultipartParam::Param(kv) => kv.space1.source_info.start,
+            MultipartParam::FilenameParam(param) => param.space1.source_info.start,
+            MultipartParam::NestedParam(param) => {
+                blocks.extend(multipart_colons(&param.params));
+                param.space1.source_info.start
+            }
+        };
+        colons.push(pos);
+    }
+    blocks.insert(0, colons);
+    blocks
+}
+
+/// Aligns the colons of each block of `text`, given their positions in `blocks`.
+///
+/// Spaces are inserted before the colons, up to the column of the rightmost colon of the block.
+fn align_colons(text: &str, blocks: &[Vec<Pos>]) -> String {
+    let mut paddings = HashMap::new();
+    for block in blocks {
+        let Some(max) = block.iter().map(|pos| pos.column).max() else {
+            continue;
+        };
+        for pos in block.iter().filter(|pos| pos.column < max) {
+            paddings.insert(pos.line, (pos.column, max - pos.column));
+        }
+    }
+
+    let mut s = String::new();
+    for (index, line) in text.split_inclusive('\n').enumerate() {
+        let Some((column, count)) = paddings.get(&(index + 1)) else {
+            s.push_str(line);
+            continue;
+        };
+        let offset = line
+            .char_indices()
+            .nth(column - 1)
+            .map(|(offset, _)| offset)
+            .unwrap_or(line.len());
+        s.push_str(&line[..offset]);
+        s.push_str(&" ".repeat(*count));
+        s.push_str(&line[offset..]);
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pretty(content: &str) -> String {
+        let hurl_file = parser::parse_hurl_file(content).unwrap();
+        pretty_hurl_file(&hurl_file, &LintConfig::default())
+    }
+
+    #[test]
+    fn test_align_colons() {
+        let content = r#"POST https://example.org
+Content-Type:   application/json
+Accept: */*
+X-Très-Long-Header: foo
+[Options]
+verbose: true
+variable: id=1
+cacert: ca.pem
+variable: name=user{{id}}
+[FormParams]
+user: bob
+password: secret
+HTTP 200
+[Captures]
+token: jsonpath "$.token"
+id: header "Id"
+[Asserts]
+status == 200
+"#;
+        let expected = r#"POST https://example.org
+Content-Type      : application/json
+Accept            : */*
+X-Très-Long-Header: foo
+[Options]
+variable: id=1
+variable: name=user{{id}}
+cacert  : ca.pem
+verbose : true
+[FormParams]
+user    : bob
+password: secret
+HTTP 200
+[Captures]
+token: jsonpath "$.token"
+id   : header "Id"
+[Asserts]
+status == 200
+"#;
+        assert_eq!(pretty(content), expected);
+        assert_eq!(pretty(expected), expected);
+    }
+
+    #[test]
+    fn test_align_colons_nested_multipart() {
+        let content = r#"POST https://example.org
+[Multipart]
+key: value
+attachments: multipart,mixed;
+    a: file,a.txt;
+    long_name: file,b.txt;
+upload: file,c.txt;
+"#;
+        let expected = r#"POST https://example.org
+[Multipart]
+key        : value
+attachments: multipart,mixed;
+  a        : file,a.txt;
+  long_name: file,b.txt;
+upload     : file,c.txt;
+"#;
+        assert_eq!(pretty(content), expected);
+        assert_eq!(pretty(expected), expected);
+    }
+
+    #[test]
+    fn test_reflow_json() {
+        // Short JSON bodies are kept as is.
+        let content = "POST https://example.org\n{\"id\":1,  \"name\": \"bob\"}\n";
+        assert_eq!(pretty(content), content);
+
+        let content = r#"POST https://example.org
+{"id": 1, "name": "bob", "tags": ["admin", "dev"], "empty": {}, "address": {"city": "Paris", "zip": "{{zip}}"}}
+"#;
+        let expected = r#"POST https://example.org
+{
+  "id": 1,
+  "name": "bob",
+  "tags": [
+    "admin",
+    "dev"
+  ],
+  "empty": {},
+  "address": {
+    "city": "Paris",
+    "zip": "{{zip}}"
+  }
+}
+"#;
+        assert_eq!(pretty(content), expected);
+        assert_eq!(pretty(expected), expected);
+    }
+}
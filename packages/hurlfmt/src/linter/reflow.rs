@@ -0,0 +1,87 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{Body, Bytes, HurlFile, JsonValue};
+use hurl_core::types::ToSource;
+
+/// Reflows the JSON bodies of a `hurl_file` having a line longer than `max_line_length`, with
+/// each list element and object member on its own line, indented by `indent_width` spaces.
+pub(super) fn reflow_json_bodies(
+    hurl_file: &mut HurlFile,
+    max_line_length: usize,
+    indent_width: usize,
+) {
+    for entry in &mut hurl_file.entries {
+        let request_body = entry.request.body.as_mut();
+        let response_body = entry.response.as_mut().and_then(|r| r.body.as_mut());
+        for body in request_body.into_iter().chain(response_body) {
+            reflow_json(body, max_line_length, indent_width);
+        }
+    }
+}
+
+/// Reflows a JSON `body` if one of its lines is too long.
+fn reflow_json(body: &mut Body, max_line_length: usize, indent_width: usize) {
+    let Bytes::Json(value) = &mut body.value else {
+        return;
+    };
+    let source = value.to_source();
+    if source
+        .as_str()
+        .lines()
+        .any(|line| line.chars().count() > max_line_length)
+    {
+        indent_json(value, &" ".repeat(indent_width), 0);
+    }
+}
+
+/// Rewrites the whitespaces of a JSON `value`, with each list element and object member on its
+/// own line, indented by `indent` per `level`.
+fn indent_json(value: &mut JsonValue, indent: &str, level: usize) {
+    let newline = |level: usize| format!("\n{}", indent.repeat(level));
+    match value {
+        JsonValue::List { space0, elements } => {
+            space0.clear();
+            let count = elements.len();
+            for (index, element) in elements.iter_mut().enumerate() {
+                element.space0 = newline(level + 1);
+                element.space1 = if index == count - 1 {
+                    newline(level)
+                } else {
+                    String::new()
+                };
+                indent_json(&mut element.value, indent, level + 1);
+            }
+        }
+        JsonValue::Object { space0, elements } => {
+            space0.clear();
+            let count = elements.len();
+            for (index, element) in elements.iter_mut().enumerate() {
+                element.space0 = newline(level + 1);
+                element.space1 = String::new();
+                element.space2 = " ".to_string();
+                element.space3 = if index == count - 1 {
+                    newline(level)
+                } else {
+                    String::new()
+                };
+                indent_json(&mut element.value, indent, level + 1);
+            }
+        }
+        _ => {}
+    }
+}
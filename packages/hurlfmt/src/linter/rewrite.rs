@@ -16,19 +16,111 @@
  *
  */
 use hurl_core::ast::{
-    Assert, Base64, Body, BooleanOption, Bytes, Capture, CertificateAttributeName, Comment, Cookie,
-    CookiePath, CountOption, Duration, DurationOption, Entry, EntryOption, File, FilenameParam,
-    FilenameValue, FilterValue, Hex, HurlFile, I64, IntegerValue, JsonValue, KeyValue,
-    LineTerminator, Method, MultilineString, MultipartParam, NaturalOption, Number, OptionKind,
-    Placeholder, Predicate, PredicateFuncValue, PredicateValue, Query, QueryValue, Regex,
-    RegexValue, Request, Response, Section, SectionValue, StatusValue, Template, U64,
-    VariableDefinition, VariableValue, VerbosityOption, VersionValue,
+    Assert, AuthTypeOption, Base64, Body, BooleanOption, Bytes, Capture, CaptureFallback,
+    CertificateAttributeName, ClientCertTypeOption, Comment, Cookie, CookiePath, CountOption,
+    Defaults, Duration, DurationOption, Entry, EntryOption, File, FilenameParam, FilenameValue,
+    FilterValue, Hex, HurlFile, I64, Include, IntegerValue, JsonValue, KeyValue, LineTerminator,
+    Method, MultilineString, MultipartParam, NaturalOption, NestedParam, NestedValue, Number,
+    OptionKind, Placeholder, Predicate, PredicateFuncValue, PredicateValue, Query, QueryValue,
+    Regex, RegexValue, Request, Response, Section, SectionValue, StatusValue, Template,
+    TimingsAttributeName, U64, VariableDefinition, VariableValue, VerbosityOption, VersionValue,
 };
 use hurl_core::types::{Count, DurationUnit, ToSource};
 
-/// Lint a parsed `HurlFile` to a string.
-pub fn lint_hurl_file(file: &HurlFile) -> String {
-    file.lint()
+use super::config::{self, LintConfig};
+use super::reflow::reflow_json_bodies;
+
+/// Lint a parsed `HurlFile` to a string, given formatting rules `config`.
+pub fn lint_hurl_file(file: &HurlFile, config: &LintConfig) -> String {
+    match config.max_line_length {
+        Some(max_line_length) => {
+            let mut file = file.clone();
+            reflow_json_bodies(&mut file, max_line_length, config.indent_width);
+            lint_file(&file, config)
+        }
+        None => lint_file(file, config),
+    }
+}
+
+/// Lint a parsed `HurlFile` to a string, with its sections ordered by `config`.
+pub(super) fn lint_file(file: &HurlFile, config: &LintConfig) -> String {
+    let mut s = String::new();
+    if let Some(defaults) = &file.defaults {
+        s.push_str(&defaults.lint());
+    }
+    file.entries
+        .iter()
+        .for_each(|e| s.push_str(&lint_entry(e, config)));
+    file.includes.iter().for_each(|i| s.push_str(&i.lint()));
+    file.line_terminators
+        .iter()
+        .for_each(|lt| s.push_str(&lint_lt(lt, false)));
+    s
+}
+
+fn lint_entry(entry: &Entry, config: &LintConfig) -> String {
+    let mut s = String::new();
+    entry.includes.iter().for_each(|i| s.push_str(&i.lint()));
+    s.push_str(&lint_request(&entry.request, config));
+    if let Some(response) = &entry.response {
+        s.push_str(&lint_response(response, config));
+    }
+    s
+}
+
+/// Lint a `request` to a string, with its sections ordered by `config`.
+pub(crate) fn lint_request(request: &Request, config: &LintConfig) -> String {
+    let mut s = String::new();
+    request
+        .line_terminators
+        .iter()
+        .for_each(|lt| s.push_str(&lint_lt(lt, false)));
+    s.push_str(&request.method.lint());
+    s.push(' ');
+    s.push_str(&request.url.lint());
+    s.push_str(&lint_lt(&request.line_terminator0, true));
+
+    request.headers.iter().for_each(|h| s.push_str(&h.lint()));
+    s.push_str(&lint_sections(&request.sections, config));
+    if let Some(body) = &request.body {
+        s.push_str(&body.lint());
+    }
+    s
+}
+
+/// Lint a `response` to a string, with its sections ordered by `config`.
+pub(crate) fn lint_response(response: &Response, config: &LintConfig) -> String {
+    let mut s = String::new();
+    response
+        .line_terminators
+        .iter()
+        .for_each(|lt| s.push_str(&lint_lt(lt, false)));
+    s.push_str(&response.version.value.lint());
+    s.push(' ');
+    s.push_str(&response.status.value.lint());
+    s.push_str(&lint_lt(&response.line_terminator0, true));
+
+    response.headers.iter().for_each(|h| s.push_str(&h.lint()));
+    s.push_str(&lint_sections(&response.sections, config));
+    if let Some(body) = &response.body {
+        s.push_str(&body.lint());
+    }
+    s
+}
+
+/// Lint the `bytes` of a request or a response body to a string.
+pub(crate) fn lint_bytes(bytes: &Bytes) -> String {
+    bytes.lint()
+}
+
+/// Lint the `sections` of a request or a response, reordered as configured by `config`.
+fn lint_sections(sections: &[Section], config: &LintConfig) -> String {
+    let mut sections = sections
+        .iter()
+        .filter(|section| config::is_formatted(section))
+        .collect::<Vec<_>>();
+    sections.sort_by_key(|section| config.section_rank(section));
+    sections.iter().map(|section| section.lint()).collect()
 }
 
 /// Lint something (usually a Hurl AST node) to a string.
@@ -125,6 +217,14 @@ impl Lint for Capture {
                 .join(" ");
             s.push_str(&filters);
         }
+        if let Some((_, annotation)) = &self.type_annotation {
+            s.push_str(" as ");
+            s.push_str(annotation.value.identifier());
+        }
+        if let Some((_, fallback)) = &self.fallback {
+            s.push(' ');
+            s.push_str(&fallback.lint());
+        }
         if self.redacted {
             s.push(' ');
             s.push_str("redact");
@@ -134,12 +234,27 @@ impl Lint for Capture {
     }
 }
 
+impl Lint for CaptureFallback {
+    fn lint(&self) -> String {
+        match self {
+            CaptureFallback::Optional => "optional".to_string(),
+            CaptureFallback::Default { value, .. } => format!("default {}", value.lint()),
+        }
+    }
+}
+
 impl Lint for CertificateAttributeName {
     fn lint(&self) -> String {
         self.to_source().to_string()
     }
 }
 
+impl Lint for TimingsAttributeName {
+    fn lint(&self) -> String {
+        self.to_source().to_string()
+    }
+}
+
 impl Lint for Cookie {
     fn lint(&self) -> String {
         let mut s = String::new();
@@ -182,13 +297,18 @@ impl Lint for CountOption {
     }
 }
 
-impl Lint for Entry {
+impl Lint for Defaults {
     fn lint(&self) -> String {
         let mut s = String::new();
-        s.push_str(&self.request.lint());
-        if let Some(response) = &self.response {
-            s.push_str(&response.lint());
-        }
+        self.line_terminators
+            .iter()
+            .for_each(|lt| s.push_str(&lint_lt(lt, false)));
+        s.push_str("[Defaults]");
+        s.push_str(&lint_lt(&self.line_terminator0, true));
+        self.headers.iter().for_each(|h| s.push_str(&h.lint()));
+        self.sections
+            .iter()
+            .for_each(|section| s.push_str(&section.lint()));
         s
     }
 }
@@ -249,6 +369,20 @@ impl Lint for FilterValue {
         let mut s = String::new();
         s.push_str(self.identifier());
         match self {
+            FilterValue::AddDays { days, .. } => {
+                s.push(' ');
+                s.push_str(&days.lint());
+            }
+            FilterValue::AddSeconds { seconds, .. } => {
+                s.push(' ');
+                s.push_str(&seconds.lint());
+            }
+            FilterValue::BytesSlice { start, end, .. } => {
+                s.push(' ');
+                s.push_str(&start.lint());
+                s.push(' ');
+                s.push_str(&end.lint());
+            }
             FilterValue::CharsetDecode { encoding, .. } => {
                 s.push(' ');
                 s.push_str(&encoding.lint());
@@ -265,6 +399,10 @@ impl Lint for FilterValue {
                 s.push(' ');
                 s.push_str(&fmt.lint());
             }
+            FilterValue::Jq { expr, .. } => {
+                s.push(' ');
+                s.push_str(&expr.lint());
+            }
             FilterValue::JsonPath { expr, .. } => {
                 s.push(' ');
                 s.push_str(&expr.lint());
@@ -303,6 +441,16 @@ impl Lint for FilterValue {
                 s.push(' ');
                 s.push_str(&fmt.lint());
             }
+            FilterValue::ToHex { case, separator } => {
+                if let Some((_, case)) = case {
+                    s.push(' ');
+                    s.push_str(case.identifier());
+                }
+                if let Some((_, separator)) = separator {
+                    s.push(' ');
+                    s.push_str(&separator.lint());
+                }
+            }
             FilterValue::UrlQueryParam { param, .. } => {
                 s.push(' ');
                 s.push_str(&param.lint());
@@ -315,22 +463,33 @@ impl Lint for FilterValue {
             | FilterValue::Base64Encode
             | FilterValue::Base64UrlSafeDecode
             | FilterValue::Base64UrlSafeEncode
+            | FilterValue::BrotliDecompress
+            | FilterValue::Canonicalize
             | FilterValue::Count
             | FilterValue::DaysAfterNow
             | FilterValue::DaysBeforeNow
             | FilterValue::First
+            | FilterValue::FromHex
+            | FilterValue::Gunzip
             | FilterValue::HtmlEscape
             | FilterValue::HtmlUnescape
+            | FilterValue::JwtHeader
+            | FilterValue::JwtPayload
             | FilterValue::Last
             | FilterValue::Location
+            | FilterValue::ParseJson
             | FilterValue::ToFloat
-            | FilterValue::ToHex
             | FilterValue::ToInt
+            | FilterValue::ToJson
             | FilterValue::ToString
+            | FilterValue::ToTimestamp
             | FilterValue::UrlDecode
             | FilterValue::UrlEncode
             | FilterValue::Utf8Decode
-            | FilterValue::Utf8Encode => {}
+            | FilterValue::Utf8Encode
+            | FilterValue::XmlEscape
+            | FilterValue::XmlUnescape
+            | FilterValue::ZstdDecompress => {}
         }
         s
     }
@@ -346,13 +505,15 @@ impl Lint for Hex {
     }
 }
 
-impl Lint for HurlFile {
+impl Lint for Include {
     fn lint(&self) -> String {
         let mut s = String::new();
-        self.entries.iter().for_each(|e| s.push_str(&e.lint()));
         self.line_terminators
             .iter()
             .for_each(|lt| s.push_str(&lint_lt(lt, false)));
+        s.push_str("include ");
+        s.push_str(&self.filename.lint());
+        s.push_str(&lint_lt(&self.line_terminator0, true));
         s
     }
 }
@@ -426,6 +587,7 @@ impl Lint for MultipartParam {
         match self {
             MultipartParam::Param(param) => s.push_str(&param.lint()),
             MultipartParam::FilenameParam(param) => s.push_str(&param.lint()),
+            MultipartParam::NestedParam(param) => s.push_str(&param.lint()),
         }
         s
     }
@@ -446,6 +608,46 @@ impl Lint for NaturalOption {
     }
 }
 
+impl Lint for NestedParam {
+    fn lint(&self) -> String {
+        let mut s = String::new();
+        self.line_terminators
+            .iter()
+            .for_each(|lt| s.push_str(&lint_lt(lt, false)));
+        s.push_str(&self.key.lint());
+        s.push(':');
+        s.push(' ');
+        s.push_str(&self.value.lint());
+        s.push_str(&lint_lt(&self.line_terminator0, true));
+        // Parts of a nested param are indented below it, blank lines are kept empty.
+        for param in &self.params {
+            for line in param.lint().split_inclusive('\n') {
+                if line.trim().is_empty() {
+                    s.push_str(line);
+                } else {
+                    s.push_str("  ");
+                    s.push_str(line);
+                }
+            }
+        }
+        s
+    }
+}
+
+impl Lint for NestedValue {
+    fn lint(&self) -> String {
+        let mut s = String::new();
+        s.push_str("multipart,");
+        s.push_str(&self.subtype.lint());
+        s.push(';');
+        if let Some(headers) = &self.headers {
+            s.push(' ');
+            s.push_str(&headers.lint());
+        }
+        s
+    }
+}
+
 impl Lint for Number {
     fn lint(&self) -> String {
         self.to_source().to_string()
@@ -459,48 +661,74 @@ impl Lint for OptionKind {
         s.push(':');
         s.push(' ');
         let value = match self {
+            OptionKind::AuthType(value) => value.lint(),
             OptionKind::AwsSigV4(value) => value.lint(),
             OptionKind::CaCertificate(value) => value.lint(),
+            OptionKind::Chunked(value) => value.lint(),
             OptionKind::ClientCert(value) => value.lint(),
+            OptionKind::ClientCertPassword(value) => value.lint(),
+            OptionKind::ClientCertType(value) => value.lint(),
             OptionKind::ClientKey(value) => value.lint(),
+            OptionKind::ClockSkew(value) => lint_duration_option(value, DurationUnit::MilliSecond),
             OptionKind::Compressed(value) => value.lint(),
             OptionKind::ConnectTo(value) => value.lint(),
             OptionKind::ConnectTimeout(value) => {
                 lint_duration_option(value, DurationUnit::MilliSecond)
             }
+            OptionKind::CookieFile(value) => value.lint(),
             OptionKind::Delay(value) => lint_duration_option(value, DurationUnit::MilliSecond),
             OptionKind::Digest(value) => value.lint(),
+            OptionKind::DnsServers(value) => value.lint(),
+            OptionKind::ExpectContinue(value) => value.lint(),
+            OptionKind::ExportCapture(value) => value.clone(),
             OptionKind::FailWithBody(value) => value.lint(),
             OptionKind::Header(value) => value.lint(),
+            OptionKind::HmacSignature(value) => value.lint(),
             OptionKind::Http10(value) => value.lint(),
             OptionKind::Http11(value) => value.lint(),
             OptionKind::Http2(value) => value.lint(),
             OptionKind::Http3(value) => value.lint(),
+            OptionKind::Http3Fallback(value) => value.lint(),
             OptionKind::Insecure(value) => value.lint(),
             OptionKind::IpV4(value) => value.lint(),
             OptionKind::IpV6(value) => value.lint(),
             OptionKind::FollowLocation(value) => value.lint(),
             OptionKind::FollowLocationTrusted(value) => value.lint(),
+            OptionKind::Foreach(value) => value.lint(),
             OptionKind::LimitRate(value) => value.lint(),
             OptionKind::MaxRedirect(value) => value.lint(),
             OptionKind::MaxTime(value) => lint_duration_option(value, DurationUnit::MilliSecond),
+            OptionKind::MultipartBoundary(value) => value.lint(),
             OptionKind::Negotiate(value) => value.lint(),
             OptionKind::NetRc(value) => value.lint(),
             OptionKind::NetRcFile(value) => value.lint(),
             OptionKind::NetRcOptional(value) => value.lint(),
             OptionKind::NoHeader(value) => value.lint(),
             OptionKind::Ntlm(value) => value.lint(),
+            OptionKind::OAuth2ClientId(value) => value.lint(),
+            OptionKind::OAuth2ClientSecret(value) => value.lint(),
+            OptionKind::OAuth2TokenUrl(value) => value.lint(),
+            OptionKind::OnlyIf(value) => value.lint(),
             OptionKind::Output(value) => value.lint(),
+            OptionKind::Paginate(value) => value.clone(),
+            OptionKind::PaginateMax(value) => value.lint(),
             OptionKind::PathAsIs(value) => value.lint(),
             OptionKind::PinnedPublicKey(value) => value.lint(),
             OptionKind::Proxy(value) => value.lint(),
+            OptionKind::ProxyHeader(value) => value.lint(),
+            OptionKind::ProxyUser(value) => value.lint(),
             OptionKind::Repeat(value) => value.lint(),
             OptionKind::Resolve(value) => value.lint(),
             OptionKind::Retry(value) => value.lint(),
             OptionKind::RetryInterval(value) => {
                 lint_duration_option(value, DurationUnit::MilliSecond)
             }
+            OptionKind::RetryOnCaptureFailure(value) => value.lint(),
             OptionKind::Skip(value) => value.lint(),
+            OptionKind::SkipIf(value) => value.lint(),
+            OptionKind::SoftAsserts(value) => value.lint(),
+            OptionKind::NestedFormParams(value) => value.lint(),
+            OptionKind::Trailer(value) => value.lint(),
             OptionKind::UnixSocket(value) => value.lint(),
             OptionKind::User(value) => value.lint(),
             OptionKind::Variable(value) => value.lint(),
@@ -520,6 +748,7 @@ impl Lint for Query {
         match &self.value {
             QueryValue::Status => {}
             QueryValue::Version => {}
+            QueryValue::VersionNegotiated => {}
             QueryValue::Url => {}
             QueryValue::Header { name, .. } => {
                 s.push(' ');
@@ -529,7 +758,12 @@ impl Lint for Query {
                 s.push(' ');
                 s.push_str(&expr.lint());
             }
-            QueryValue::Body => {}
+            QueryValue::Body { charset } => {
+                if let Some((_, charset)) = charset {
+                    s.push(' ');
+                    s.push_str(&charset.lint());
+                }
+            }
             QueryValue::Xpath { expr, .. } => {
                 s.push(' ');
                 s.push_str(&expr.lint());
@@ -538,6 +772,10 @@ impl Lint for Query {
                 s.push(' ');
                 s.push_str(&expr.lint());
             }
+            QueryValue::Csv { expr, .. } => {
+                s.push(' ');
+                s.push_str(&expr.lint());
+            }
             QueryValue::Regex { value, .. } => {
                 s.push(' ');
                 s.push_str(&value.lint());
@@ -547,16 +785,32 @@ impl Lint for Query {
                 s.push_str(&name.lint());
             }
             QueryValue::Duration => {}
+            QueryValue::DurationMean => {}
+            QueryValue::DurationPercentile { percentile, .. } => {
+                s.push(' ');
+                s.push_str(&percentile.to_string());
+            }
             QueryValue::Bytes => {}
             QueryValue::RawBytes => {}
+            QueryValue::Sha1 => {}
             QueryValue::Sha256 => {}
+            QueryValue::Sha512 => {}
             QueryValue::Md5 => {}
+            QueryValue::Crc32 => {}
             QueryValue::Certificate { attribute_name, .. } => {
                 s.push(' ');
                 s.push_str(&attribute_name.lint());
             }
             QueryValue::Ip => {}
+            QueryValue::ProxyStatus => {}
+            QueryValue::HstsUpgraded => {}
+            QueryValue::ContinueReceived => {}
+            QueryValue::EarlyHints => {}
             QueryValue::Redirects => {}
+            QueryValue::Timings { attribute_name, .. } => {
+                s.push(' ');
+                s.push_str(&attribute_name.lint());
+            }
         }
         s
     }
@@ -585,14 +839,27 @@ impl Lint for PredicateFuncValue {
         let mut s = String::new();
         s.push_str(self.identifier());
         match self {
-            PredicateFuncValue::Equal { value, .. } => {
+            PredicateFuncValue::Equal {
+                value, ignoring, ..
+            } => {
                 s.push(' ');
                 s.push_str(&value.lint());
+                if let Some(ignoring) = ignoring {
+                    s.push_str(" ignoring");
+                    for (_, path) in &ignoring.paths {
+                        s.push(' ');
+                        s.push_str(&path.lint());
+                    }
+                }
             }
             PredicateFuncValue::NotEqual { value, .. } => {
                 s.push(' ');
                 s.push_str(&value.lint());
             }
+            PredicateFuncValue::ConstantTimeEqual { value, .. } => {
+                s.push(' ');
+                s.push_str(&value.lint());
+            }
             PredicateFuncValue::GreaterThan { value, .. } => {
                 s.push(' ');
                 s.push_str(&value.lint());
@@ -625,6 +892,10 @@ impl Lint for PredicateFuncValue {
                 s.push(' ');
                 s.push_str(&value.lint());
             }
+            PredicateFuncValue::IncludeJson { value, .. } => {
+                s.push(' ');
+                s.push_str(&value.lint());
+            }
             PredicateFuncValue::Match { value, .. } => {
                 s.push(' ');
                 s.push_str(&value.lint());
@@ -681,71 +952,6 @@ impl Lint for RegexValue {
     }
 }
 
-impl Lint for Request {
-    fn lint(&self) -> String {
-        let mut s = String::new();
-        self.line_terminators
-            .iter()
-            .for_each(|lt| s.push_str(&lint_lt(lt, false)));
-        s.push_str(&self.method.lint());
-        s.push(' ');
-        s.push_str(&self.url.lint());
-        s.push_str(&lint_lt(&self.line_terminator0, true));
-
-        self.headers.iter().for_each(|h| s.push_str(&h.lint()));
-
-        // We rewrite our file and reorder the various section.
-        if let Some(section) = get_option_section(self) {
-            s.push_str(&section.lint());
-        }
-        if let Some(section) = get_query_params_section(self) {
-            s.push_str(&section.lint());
-        }
-        if let Some(section) = get_basic_auth_section(self) {
-            s.push_str(&section.lint());
-        }
-        if let Some(section) = get_form_params_section(self) {
-            s.push_str(&section.lint());
-        }
-        if let Some(section) = get_multipart_section(self) {
-            s.push_str(&section.lint());
-        }
-        if let Some(section) = get_cookies_section(self) {
-            s.push_str(&section.lint());
-        }
-        if let Some(body) = &self.body {
-            s.push_str(&body.lint());
-        }
-        s
-    }
-}
-
-impl Lint for Response {
-    fn lint(&self) -> String {
-        let mut s = String::new();
-        self.line_terminators
-            .iter()
-            .for_each(|lt| s.push_str(&lint_lt(lt, false)));
-        s.push_str(&self.version.value.lint());
-        s.push(' ');
-        s.push_str(&self.status.value.lint());
-        s.push_str(&lint_lt(&self.line_terminator0, true));
-
-        self.headers.iter().for_each(|h| s.push_str(&h.lint()));
-
-        if let Some(section) = get_captures_section(self) {
-            s.push_str(&section.lint());
-        }
-        if let Some(section) = get_asserts_section(self) {
-            s.push_str(&section.lint());
-        }
-        if let Some(body) = &self.body {
-            s.push_str(&body.lint());
-        }
-        s
-    }
-}
-
 impl Lint for Section {
     fn lint(&self) -> String {
         let mut s = String::new();
@@ -835,82 +1041,22 @@ impl Lint for VersionValue {
     }
 }
 
-impl Lint for VerbosityOption {
+impl Lint for AuthTypeOption {
     fn lint(&self) -> String {
         self.to_string()
     }
 }
 
-fn get_asserts_section(response: &Response) -> Option<&Section> {
-    for s in &response.sections {
-        if let SectionValue::Asserts(_) = s.value {
-            return Some(s);
-        }
-    }
-    None
-}
-
-fn get_captures_section(response: &Response) -> Option<&Section> {
-    for s in &response.sections {
-        if let SectionValue::Captures(_) = s.value {
-            return Some(s);
-        }
-    }
-    None
-}
-
-fn get_cookies_section(request: &Request) -> Option<&Section> {
-    for s in &request.sections {
-        if let SectionValue::Cookies(_) = s.value {
-            return Some(s);
-        }
-    }
-    None
-}
-
-fn get_form_params_section(request: &Request) -> Option<&Section> {
-    for s in &request.sections {
-        if let SectionValue::FormParams(_, _) = s.value {
-            return Some(s);
-        }
-    }
-    None
-}
-
-fn get_option_section(request: &Request) -> Option<&Section> {
-    for s in &request.sections {
-        if let SectionValue::Options(_) = s.value {
-            return Some(s);
-        }
-    }
-    None
-}
-
-fn get_multipart_section(request: &Request) -> Option<&Section> {
-    for s in &request.sections {
-        if let SectionValue::MultipartFormData(_, _) = s.value {
-            return Some(s);
-        }
-    }
-    None
-}
-
-fn get_query_params_section(request: &Request) -> Option<&Section> {
-    for s in &request.sections {
-        if let SectionValue::QueryParams(_, _) = s.value {
-            return Some(s);
-        }
+impl Lint for ClientCertTypeOption {
+    fn lint(&self) -> String {
+        self.to_string()
     }
-    None
 }
 
-fn get_basic_auth_section(request: &Request) -> Option<&Section> {
-    for s in &request.sections {
-        if let SectionValue::BasicAuth(Some(_)) = s.value {
-            return Some(s);
-        }
+impl Lint for VerbosityOption {
+    fn lint(&self) -> String {
+        self.to_string()
     }
-    None
 }
 
 fn lint_duration_option(option: &DurationOption, default_unit: DurationUnit) -> String {
@@ -930,7 +1076,7 @@ fn lint_duration(duration: &Duration, default_unit: DurationUnit) -> String {
 
 #[cfg(test)]
 mod tests {
-    use crate::linter::lint_hurl_file;
+    use crate::linter::{LintConfig, lint_hurl_file};
     use hurl_core::parser;
 
     #[test]
@@ -945,7 +1091,7 @@ mod tests {
  location : true
 HTTP   200"#;
         let file = parser::parse_hurl_file(src).unwrap();
-        let linted = lint_hurl_file(&file);
+        let linted = lint_hurl_file(&file, &LintConfig::default());
         assert_eq!(
             linted,
             r#"
@@ -957,6 +1103,50 @@ location: true
 [Form]
 bar: baz
 HTTP 200
+"#
+        );
+    }
+
+    #[test]
+    fn test_lint_hurl_file_with_config() {
+        let src = r#"POST https://foo.com
+[Options]
+location: true
+[Query]
+id: 1
+{"id": 1, "name": "bob"}
+HTTP 200
+[Asserts]
+status == 200
+[Captures]
+name: jsonpath "$.name"
+"#;
+        let file = parser::parse_hurl_file(src).unwrap();
+        let config = LintConfig::parse(
+            r#"
+indent-width = 4
+max-line-length = 20
+section-order = ["Query", "Asserts"]
+"#,
+        )
+        .unwrap();
+        let linted = lint_hurl_file(&file, &config);
+        assert_eq!(
+            linted,
+            r#"POST https://foo.com
+[Query]
+id: 1
+[Options]
+location: true
+{
+    "id": 1,
+    "name": "bob"
+}
+HTTP 200
+[Asserts]
+status == 200
+[Captures]
+name: jsonpath "$.name"
 "#
         );
     }
@@ -16,12 +16,13 @@
  *
  */
 use hurl_core::ast::{
-    Assert, Base64, Body, Bytes, Capture, Comment, Cookie, CookieAttribute, CookieAttributeName,
-    CookiePath, DurationOption, Entry, EntryOption, File, FilenameParam, Filter, FilterValue,
-    GraphQl, Hex, HurlFile, KeyValue, LineTerminator, MultilineString, MultilineStringAttribute,
-    MultilineStringKind, MultipartParam, OptionKind, Predicate, PredicateFunc, PredicateFuncValue,
-    PredicateValue, Query, QueryValue, RegexValue, Request, Response, Section, SectionValue,
-    SourceInfo, Template, VariableDefinition, Whitespace,
+    Assert, Base64, Body, Bytes, Capture, CaptureFallback, CaptureTypeAnnotation, Comment, Cookie,
+    CookieAttribute, CookieAttributeName, CookiePath, Defaults, DurationOption, Entry, EntryOption,
+    File, FilenameParam, Filter, FilterValue, GraphQl, Hex, HurlFile, Ignoring, Include, KeyValue,
+    LineTerminator, MultilineString, MultilineStringAttribute, MultilineStringKind, MultipartParam,
+    OptionKind, Predicate, PredicateFunc, PredicateFuncValue, PredicateValue, Query, QueryValue,
+    RegexValue, Request, Response, Section, SectionValue, SourceInfo, Template, VariableDefinition,
+    Whitespace,
 };
 use hurl_core::reader::Pos;
 use hurl_core::typing::{Duration, DurationUnit};
@@ -29,15 +30,56 @@ use hurl_core::typing::{Duration, DurationUnit};
 /// Returns a new linted instance from this `hurl_file`.
 pub fn lint_hurl_file(hurl_file: &HurlFile) -> HurlFile {
     HurlFile {
+        defaults: hurl_file.defaults.as_ref().map(lint_defaults),
         entries: hurl_file.entries.iter().map(lint_entry).collect(),
+        includes: hurl_file.includes.iter().map(lint_include).collect(),
         line_terminators: hurl_file.line_terminators.clone(),
     }
 }
 
+fn lint_defaults(defaults: &Defaults) -> Defaults {
+    let line_terminators = defaults.line_terminators.clone();
+    let space0 = empty_whitespace();
+    let line_terminator0 = lint_line_terminator(&defaults.line_terminator0);
+    let headers = defaults.headers.iter().map(lint_key_value).collect();
+    let sections = defaults.sections.iter().map(lint_section).collect();
+    let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+    Defaults {
+        line_terminators,
+        space0,
+        line_terminator0,
+        headers,
+        sections,
+        source_info,
+    }
+}
+
 fn lint_entry(entry: &Entry) -> Entry {
+    let includes = entry.includes.iter().map(lint_include).collect();
     let request = lint_request(&entry.request);
     let response = entry.response.as_ref().map(lint_response);
-    Entry { request, response }
+    Entry {
+        includes,
+        request,
+        response,
+    }
+}
+
+fn lint_include(include: &Include) -> Include {
+    let line_terminators = include.line_terminators.clone();
+    let space0 = empty_whitespace();
+    let space1 = one_whitespace();
+    let filename = include.filename.clone();
+    let line_terminator0 = lint_line_terminator(&include.line_terminator0);
+    let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+    Include {
+        line_terminators,
+        space0,
+        space1,
+        filename,
+        line_terminator0,
+        source_info,
+    }
 }
 
 fn lint_request(request: &Request) -> Request {
@@ -175,6 +217,14 @@ fn lint_capture(capture: &Capture) -> Capture {
         .iter()
         .map(|(_, f)| (one_whitespace(), lint_filter(f)))
         .collect();
+    let type_annotation = capture
+        .type_annotation
+        .as_ref()
+        .map(|(_, annotation)| (one_whitespace(), lint_capture_type_annotation(annotation)));
+    let fallback = capture
+        .fallback
+        .as_ref()
+        .map(|(_, fallback)| (one_whitespace(), lint_capture_fallback(fallback)));
     let space3 = if capture.redacted {
         one_whitespace()
     } else {
@@ -188,12 +238,32 @@ fn lint_capture(capture: &Capture) -> Capture {
         space2: one_whitespace(),
         query: lint_query(&capture.query),
         filters,
+        type_annotation,
+        fallback,
         space3,
         redacted: capture.redacted,
         line_terminator0: lint_line_terminator(&capture.line_terminator0),
     }
 }
 
+fn lint_capture_type_annotation(annotation: &CaptureTypeAnnotation) -> CaptureTypeAnnotation {
+    CaptureTypeAnnotation {
+        space0: one_whitespace(),
+        value: annotation.value,
+        source_info: annotation.source_info,
+    }
+}
+
+fn lint_capture_fallback(fallback: &CaptureFallback) -> CaptureFallback {
+    match fallback {
+        CaptureFallback::Optional => CaptureFallback::Optional,
+        CaptureFallback::Default { value, .. } => CaptureFallback::Default {
+            space0: one_whitespace(),
+            value: lint_predicate_value(value),
+        },
+    }
+}
+
 fn lint_query(query: &Query) -> Query {
     Query {
         source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
@@ -205,6 +275,7 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
     match query_value {
         QueryValue::Status => QueryValue::Status,
         QueryValue::Version => QueryValue::Version,
+        QueryValue::VersionNegotiated => QueryValue::VersionNegotiated,
         QueryValue::Url => QueryValue::Url,
         QueryValue::Header { name, .. } => QueryValue::Header {
             name: name.clone(),
@@ -223,7 +294,11 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
                 },
             }
         }
-        QueryValue::Body => QueryValue::Body,
+        QueryValue::Body { charset } => QueryValue::Body {
+            charset: charset
+                .as_ref()
+                .map(|(_, charset)| (one_whitespace(), charset.clone())),
+        },
         QueryValue::Xpath { expr, .. } => QueryValue::Xpath {
             expr: expr.clone(),
             space0: one_whitespace(),
@@ -232,6 +307,10 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
             expr: expr.clone(),
             space0: one_whitespace(),
         },
+        QueryValue::Csv { expr, .. } => QueryValue::Csv {
+            expr: expr.clone(),
+            space0: one_whitespace(),
+        },
         QueryValue::Regex { value, .. } => QueryValue::Regex {
             value: lint_regex_value(value),
             space0: one_whitespace(),
@@ -241,9 +320,17 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
             space0: one_whitespace(),
         },
         QueryValue::Duration => QueryValue::Duration,
+        QueryValue::DurationMean => QueryValue::DurationMean,
+        QueryValue::DurationPercentile { percentile, .. } => QueryValue::DurationPercentile {
+            percentile: percentile.clone(),
+            space0: one_whitespace(),
+        },
         QueryValue::Bytes => QueryValue::Bytes,
+        QueryValue::Sha1 => QueryValue::Sha1,
         QueryValue::Sha256 => QueryValue::Sha256,
+        QueryValue::Sha512 => QueryValue::Sha512,
         QueryValue::Md5 => QueryValue::Md5,
+        QueryValue::Crc32 => QueryValue::Crc32,
         QueryValue::Certificate {
             attribute_name: field,
             ..
@@ -252,7 +339,18 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
             space0: one_whitespace(),
         },
         QueryValue::Ip => QueryValue::Ip,
+        QueryValue::ProxyStatus => QueryValue::ProxyStatus,
+        QueryValue::HstsUpgraded => QueryValue::HstsUpgraded,
+        QueryValue::ContinueReceived => QueryValue::ContinueReceived,
+        QueryValue::EarlyHints => QueryValue::EarlyHints,
         QueryValue::Redirects => QueryValue::Redirects,
+        QueryValue::Timings {
+            attribute_name: field,
+            ..
+        } => QueryValue::Timings {
+            attribute_name: *field,
+            space0: one_whitespace(),
+        },
     }
 }
 
@@ -308,14 +406,30 @@ fn lint_predicate_func(predicate_func: &PredicateFunc) -> PredicateFunc {
 
 fn lint_predicate_func_value(predicate_func_value: &PredicateFuncValue) -> PredicateFuncValue {
     match predicate_func_value {
-        PredicateFuncValue::Equal { value, .. } => PredicateFuncValue::Equal {
+        PredicateFuncValue::Equal {
+            value, ignoring, ..
+        } => PredicateFuncValue::Equal {
             space0: one_whitespace(),
             value: lint_predicate_value(value),
+            ignoring: ignoring.as_ref().map(|ignoring| Ignoring {
+                space0: one_whitespace(),
+                paths: ignoring
+                    .paths
+                    .iter()
+                    .map(|(_, path)| (one_whitespace(), path.clone()))
+                    .collect(),
+            }),
         },
         PredicateFuncValue::NotEqual { value, .. } => PredicateFuncValue::NotEqual {
             space0: one_whitespace(),
             value: lint_predicate_value(value),
         },
+        PredicateFuncValue::ConstantTimeEqual { value, .. } => {
+            PredicateFuncValue::ConstantTimeEqual {
+                space0: one_whitespace(),
+                value: lint_predicate_value(value),
+            }
+        }
         PredicateFuncValue::GreaterThan { value, .. } => PredicateFuncValue::GreaterThan {
             space0: one_whitespace(),
             value: lint_predicate_value(value),
@@ -344,6 +458,11 @@ fn lint_predicate_func_value(predicate_func_value: &PredicateFuncValue) -> Predi
             value: lint_predicate_value(value),
         },
 
+        PredicateFuncValue::IncludeJson { value, .. } => PredicateFuncValue::IncludeJson {
+            space0: one_whitespace(),
+            value: lint_predicate_value(value),
+        },
+
         PredicateFuncValue::Match { value, .. } => PredicateFuncValue::Match {
             space0: one_whitespace(),
             value: lint_predicate_value(value),
@@ -673,11 +792,15 @@ mod tests {
     #[test]
     fn test_hurl_file() {
         let hurl_file = HurlFile {
+            defaults: None,
             entries: vec![],
+            includes: vec![],
             line_terminators: vec![],
         };
         let hurl_file_linted = HurlFile {
+            defaults: None,
             entries: vec![],
+            includes: vec![],
             line_terminators: vec![],
         };
         assert_eq!(lint_hurl_file(&hurl_file), hurl_file_linted);
@@ -686,11 +809,15 @@ mod tests {
     #[test]
     fn test_entry() {
         let entry = HurlFile {
+            defaults: None,
             entries: vec![],
+            includes: vec![],
             line_terminators: vec![],
         };
         let entry_linted = HurlFile {
+            defaults: None,
             entries: vec![],
+            includes: vec![],
             line_terminators: vec![],
         };
         assert_eq!(lint_hurl_file(&entry), entry_linted);
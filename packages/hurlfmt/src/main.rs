@@ -16,13 +16,13 @@
  *
  */
 use std::io::{self, IsTerminal, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process;
 
-use hurl_core::input::{Input, InputKind};
+use hurl_core::input::InputKind;
 use hurl_core::text;
 use hurlfmt::cli::Logger;
-use hurlfmt::cli::options::{InputFormat, OptionsError, OutputFormat};
+use hurlfmt::cli::options::{Options, OptionsError};
 use hurlfmt::command::check::CheckError;
 use hurlfmt::command::export::ExportError;
 use hurlfmt::command::format::FormatError;
@@ -58,24 +58,18 @@ fn main() {
     let logger = Logger::new(color);
 
     if opts.check {
-        process_check_command(&opts.input_files, opts.output_file, &logger);
+        process_check_command(&opts, &logger);
     } else if opts.in_place {
-        process_format_command(&opts.input_files, &logger);
+        process_format_command(&opts, &logger);
+    } else if let (Some(input_dir), Some(output_dir)) = (&opts.input_dir, &opts.output_dir) {
+        process_export_dir_command(input_dir, output_dir, &logger);
     } else {
-        process_export_command(
-            &opts.input_files,
-            opts.output_file,
-            &logger,
-            &opts.input_format,
-            &opts.output_format,
-            opts.standalone,
-            color,
-        );
+        process_export_command(&opts, &logger, color);
     }
 }
 
-fn process_check_command(input_files: &[Input], output_file: Option<PathBuf>, logger: &Logger) {
-    let errors = command::check::run(input_files);
+fn process_check_command(opts: &Options, logger: &Logger) {
+    let errors = command::check::run(&opts.input_files, opts.pretty, &opts.lint_config);
     if errors.is_empty() {
         process::exit(EXIT_OK);
     } else {
@@ -111,7 +105,7 @@ fn process_check_command(input_files: &[Input], output_file: Option<PathBuf>, lo
                 if count > 1 { "s" } else { "" }
             ));
         }
-        write_output(&output_all, output_file, logger);
+        write_output(&output_all, opts.output_file.clone(), logger);
         if invalid_input {
             process::exit(EXIT_INVALID_INPUT);
         } else {
@@ -120,9 +114,9 @@ fn process_check_command(input_files: &[Input], output_file: Option<PathBuf>, lo
     }
 }
 
-fn process_format_command(input_files: &[Input], logger: &Logger) {
+fn process_format_command(opts: &Options, logger: &Logger) {
     let mut input_files2 = vec![];
-    for input_file in input_files {
+    for input_file in &opts.input_files {
         if let InputKind::File(path) = input_file.kind() {
             input_files2.push(path.clone());
         } else {
@@ -131,7 +125,7 @@ fn process_format_command(input_files: &[Input], logger: &Logger) {
         }
     }
 
-    let errors = command::format::run(&input_files2);
+    let errors = command::format::run(&input_files2, opts.pretty, &opts.lint_config);
     if errors.is_empty() {
         process::exit(EXIT_OK);
     } else {
@@ -155,18 +149,18 @@ fn process_format_command(input_files: &[Input], logger: &Logger) {
     }
 }
 
-fn process_export_command(
-    input_files: &[Input],
-    output_file: Option<PathBuf>,
-    logger: &Logger,
-    input_format: &InputFormat,
-    output_format: &OutputFormat,
-    standalone: bool,
-    color: bool,
-) {
+fn process_export_command(opts: &Options, logger: &Logger, color: bool) {
     let mut error = false;
     let mut output_all = String::new();
-    let results = command::export::run(input_files, input_format, output_format, standalone, color);
+    let results = command::export::run(
+        &opts.input_files,
+        &opts.input_format,
+        &opts.output_format,
+        opts.pretty,
+        &opts.lint_config,
+        opts.standalone,
+        color,
+    );
     for result in &results {
         match result {
             Ok(output) => output_all.push_str(output),
@@ -187,11 +181,12 @@ fn process_export_command(
                         logger.error_parsing(content, input_file, error);
                     }
                     ExportError::Curl(s) => logger.error(&format!("error curl {s} d")),
+                    ExportError::Json(s) => logger.error(&format!("error json {s}")),
                 }
             }
         }
     }
-    write_output(&output_all, output_file, logger);
+    write_output(&output_all, opts.output_file.clone(), logger);
 
     if error {
         process::exit(EXIT_INVALID_INPUT);
@@ -200,6 +195,30 @@ fn process_export_command(
     }
 }
 
+fn process_export_dir_command(input_dir: &Path, output_dir: &Path, logger: &Logger) {
+    let errors = command::export_dir::run(input_dir, output_dir);
+    if errors.is_empty() {
+        process::exit(EXIT_OK);
+    }
+    for e in &errors {
+        match e {
+            ExportError::IO { filename, message } => {
+                logger.error(&format!("Issue exporting {filename} - {message}"));
+            }
+            ExportError::Parse {
+                content,
+                input_file,
+                error,
+            } => {
+                logger.error_parsing(content, input_file, error);
+            }
+            ExportError::Curl(s) => logger.error(&format!("error curl {s}")),
+            ExportError::Json(s) => logger.error(&format!("error json {s}")),
+        }
+    }
+    process::exit(EXIT_INVALID_INPUT);
+}
+
 fn write_output(content: &str, filename: Option<PathBuf>, logger: &Logger) {
     let content = if !content.ends_with('\n') {
         format!("{content}\n")